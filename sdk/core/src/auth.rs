@@ -20,6 +20,18 @@ impl AccessToken {
     }
 }
 
+impl From<String> for AccessToken {
+    fn from(access_token: String) -> Self {
+        Self::new(access_token)
+    }
+}
+
+impl From<&'static str> for AccessToken {
+    fn from(access_token: &'static str) -> Self {
+        Self::new(access_token)
+    }
+}
+
 /// Represents an Azure service bearer access token with expiry information.
 #[derive(Debug, Clone)]
 pub struct TokenResponse {
@@ -42,4 +54,190 @@ impl TokenResponse {
 pub trait TokenCredential: Send + Sync {
     /// Gets a `TokenResponse` for the specified resource
     async fn get_token(&self, resource: &str) -> crate::Result<TokenResponse>;
+
+    /// Gets a `TokenResponse` for the specified resource that satisfies the `claims` a service asked
+    /// for in a claims challenge, such as one issued by Continuous Access Evaluation.
+    ///
+    /// Credentials that cannot request additional claims return an `ErrorKind::Credential` error,
+    /// since the service would reject a token without them again.
+    async fn get_token_with_claims(
+        &self,
+        resource: &str,
+        claims: &str,
+    ) -> crate::Result<TokenResponse> {
+        let _ = (resource, claims);
+        Err(crate::Error::message(
+            crate::error::ErrorKind::Credential,
+            "the credential cannot request a token with additional claims",
+        ))
+    }
+
+    /// Drops any cached token, so that the next request for one acquires a new token.
+    ///
+    /// Called when a service rejects a token before it expires, such as after it was revoked.
+    /// Credentials that do not cache tokens have nothing to do.
+    async fn clear_cache(&self) -> crate::Result<()> {
+        Ok(())
+    }
+}
+
+/// A `WWW-Authenticate` challenge with the `Bearer` scheme, sent with a `401 Unauthorized` response
+/// to a request whose token the service no longer accepts.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct BearerChallenge {
+    params: Vec<(String, String)>,
+}
+
+impl BearerChallenge {
+    /// Parse the `Bearer` challenge out of a `WWW-Authenticate` header value.
+    ///
+    /// Returns `None` if the header holds no challenge with the `Bearer` scheme.
+    pub fn parse(header: &str) -> Option<Self> {
+        let mut rest = header.trim_start();
+        loop {
+            let (scheme, params) = rest.split_at(rest.find(' ').unwrap_or(rest.len()));
+            let (params, next) = parse_auth_params(params);
+            if scheme.eq_ignore_ascii_case("bearer") {
+                return Some(Self { params });
+            }
+            rest = next?;
+        }
+    }
+
+    /// Get the value of an auth parameter of the challenge, such as `authorization_uri`.
+    pub fn param(&self, name: &str) -> Option<&str> {
+        self.params
+            .iter()
+            .find(|(key, _)| key.eq_ignore_ascii_case(name))
+            .map(|(_, value)| value.as_str())
+    }
+
+    /// Get the `error` parameter, such as `insufficient_claims` for a claims challenge.
+    pub fn error(&self) -> Option<&str> {
+        self.param("error")
+    }
+
+    /// Get the claims the service asks the next token to satisfy, decoded from the base64 `claims`
+    /// parameter, if the challenge is a claims challenge.
+    pub fn claims(&self) -> Option<String> {
+        let claims = self.param("claims")?;
+        let decoded = base64::decode(claims)
+            .or_else(|_| base64::decode_config(claims, base64::URL_SAFE_NO_PAD))
+            .ok()?;
+        String::from_utf8(decoded).ok()
+    }
+}
+
+/// Parse the comma separated `key=value` auth parameters at the start of `input`, up to the next
+/// challenge, and return them with the rest of the header starting at that challenge.
+fn parse_auth_params(mut input: &str) -> (Vec<(String, String)>, Option<&str>) {
+    let mut params = Vec::new();
+    loop {
+        input = input.trim_start_matches(|c: char| c == ',' || c.is_whitespace());
+        if input.is_empty() {
+            return (params, None);
+        }
+        let key_end = input
+            .find(|c: char| c == '=' || c == ',' || c.is_whitespace())
+            .unwrap_or(input.len());
+        if !input[key_end..].starts_with('=') {
+            // A token not followed by `=` is the scheme of the next challenge.
+            return (params, Some(input));
+        }
+        let key = &input[..key_end];
+        input = &input[key_end + 1..];
+        let value = if let Some(quoted) = input.strip_prefix('"') {
+            let end = quoted.find('"').unwrap_or(quoted.len());
+            input = quoted.get(end + 1..).unwrap_or("");
+            &quoted[..end]
+        } else {
+            let end = input.find(',').unwrap_or(input.len());
+            let value = input[..end].trim_end();
+            input = &input[end..];
+            value
+        };
+        params.push((key.to_owned(), value.to_owned()));
+    }
+}
+
+/// A credential that always returns the same, already acquired token.
+///
+/// Useful when the token is managed outside the process, such as one injected by a sidecar or
+/// fetched ahead of time in CI, and in tests. The token is returned for every resource and is
+/// never refreshed, so requests fail once it expires.
+#[derive(Debug, Clone)]
+pub struct StaticTokenCredential {
+    token: TokenResponse,
+}
+
+impl StaticTokenCredential {
+    /// Create a credential that returns `token`, which expires at `expires_on`.
+    pub fn new(token: impl Into<AccessToken>, expires_on: OffsetDateTime) -> Self {
+        Self {
+            token: TokenResponse::new(token.into(), expires_on),
+        }
+    }
+}
+
+#[cfg_attr(target_arch = "wasm32", async_trait::async_trait(?Send))]
+#[cfg_attr(not(target_arch = "wasm32"), async_trait::async_trait)]
+impl TokenCredential for StaticTokenCredential {
+    async fn get_token(&self, _resource: &str) -> crate::Result<TokenResponse> {
+        Ok(self.token.clone())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn static_credential_returns_its_token() {
+        let expires_on = OffsetDateTime::now_utc() + time::Duration::hours(1);
+        let credential = StaticTokenCredential::new("token", expires_on);
+        let token =
+            futures::executor::block_on(credential.get_token("https://management.azure.com/"))
+                .unwrap();
+        assert_eq!(token.token.secret(), "token");
+        assert_eq!(token.expires_on, expires_on);
+    }
+
+    #[test]
+    fn parse_claims_challenge() {
+        let challenge = BearerChallenge::parse(
+            r#"Bearer realm="", authorization_uri="https://login.microsoftonline.com/common/oauth2/authorize", error="insufficient_claims", claims="eyJhY2Nlc3NfdG9rZW4iOnsibmJmIjp7ImVzc2VudGlhbCI6dHJ1ZX19fQ==""#,
+        )
+        .unwrap();
+        assert_eq!(challenge.param("realm"), Some(""));
+        assert_eq!(challenge.error(), Some("insufficient_claims"));
+        assert_eq!(
+            challenge.claims().as_deref(),
+            Some(r#"{"access_token":{"nbf":{"essential":true}}}"#)
+        );
+    }
+
+    #[test]
+    fn parse_bearer_among_other_challenges() {
+        let challenge = BearerChallenge::parse(
+            r#"Basic realm="simple", Bearer error=invalid_token, PoP nonce="x""#,
+        )
+        .unwrap();
+        assert_eq!(challenge.error(), Some("invalid_token"));
+        assert_eq!(challenge.param("nonce"), None);
+        assert_eq!(challenge.claims(), None);
+        assert_eq!(BearerChallenge::parse(r#"Basic realm="simple""#), None);
+    }
+
+    #[test]
+    fn default_credential_methods() {
+        let credential = StaticTokenCredential::new("token", OffsetDateTime::now_utc());
+        futures::executor::block_on(async {
+            credential.clear_cache().await.unwrap();
+            let error = credential
+                .get_token_with_claims("https://management.azure.com/", "{}")
+                .await
+                .unwrap_err();
+            assert_eq!(error.kind(), &crate::error::ErrorKind::Credential);
+        });
+    }
 }
@@ -0,0 +1,365 @@
+//! Reads coalesced into Azure Resource Manager `$batch` requests.
+
+use crate::client::{from_json_body, unexpected_status, validate_ids, ServiceClient};
+use crate::error::{Error, ErrorKind};
+use crate::headers;
+use crate::lro::{PollClock, PollOptions};
+use crate::{query_param, Method, Request, StatusCode, Url};
+use futures::future::BoxFuture;
+use std::collections::HashMap;
+
+/// The most requests Azure Resource Manager accepts in one batch. [`RequestBuilder::send`]
+/// splits larger batches into several.
+pub const MAX_REQUESTS: usize = 500;
+
+const API_VERSION: &str = "2020-06-01";
+
+/// A read that can be sent as one of the requests of a batch, implemented by every `GET`
+/// request builder of a generated client.
+pub trait BatchRead {
+    /// The URL the read is sent to, as built by the builder's `url`.
+    fn batch_url(&self) -> crate::Result<Url>;
+}
+
+#[derive(serde::Serialize)]
+struct BatchRequestBody<'a> {
+    requests: Vec<SubRequest<'a>>,
+}
+
+#[derive(serde::Serialize)]
+#[serde(rename_all = "camelCase")]
+struct SubRequest<'a> {
+    name: String,
+    http_method: &'static str,
+    url: &'a str,
+}
+
+#[derive(serde::Deserialize)]
+struct BatchResponseBody {
+    #[serde(default)]
+    responses: Vec<SubResponse>,
+}
+
+#[derive(serde::Deserialize)]
+#[serde(rename_all = "camelCase")]
+struct SubResponse {
+    name: Option<String>,
+    http_status_code: u16,
+    #[serde(default)]
+    headers: HashMap<String, String>,
+    content: Option<serde_json::Value>,
+}
+
+/// The response to one of the requests of a batch.
+#[derive(Clone, Debug)]
+pub struct Response {
+    status: StatusCode,
+    headers: HashMap<String, String>,
+    content: Option<serde_json::Value>,
+}
+
+impl Response {
+    pub fn status(&self) -> StatusCode {
+        self.status
+    }
+
+    /// Whether the request succeeded, with a `2xx` status.
+    pub fn is_success(&self) -> bool {
+        self.status.is_success()
+    }
+
+    /// The headers of the response, such as `ETag`, by name as the service reported them.
+    pub fn headers(&self) -> &HashMap<String, String> {
+        &self.headers
+    }
+
+    /// The value of the header `name`, compared case-insensitively.
+    pub fn header(&self, name: &str) -> Option<&str> {
+        self.headers
+            .iter()
+            .find(|(key, _)| key.eq_ignore_ascii_case(name))
+            .map(|(_, value)| value.as_str())
+    }
+
+    /// Deserialize the body into `T`, the `models` type the operation returns.
+    ///
+    /// An unsuccessful status fails with an `ErrorKind::HttpResponse` error carrying the status
+    /// and the error code from the body, like a request sent on its own does.
+    pub fn into_body<T>(self) -> crate::Result<T>
+    where
+        T: serde::de::DeserializeOwned,
+    {
+        let content = self.into_value()?;
+        serde_json::from_value(content).map_err(|error| {
+            Error::full(
+                ErrorKind::DataConversion,
+                error,
+                "failed to deserialize the response of a batched request",
+            )
+        })
+    }
+
+    /// Return the body as untyped JSON, failing like `into_body` on an unsuccessful status.
+    pub fn into_value(self) -> crate::Result<serde_json::Value> {
+        if !self.status.is_success() {
+            let error_code = self
+                .content
+                .as_ref()
+                .and_then(|content| content.pointer("/error/code"))
+                .and_then(serde_json::Value::as_str)
+                .map(str::to_owned);
+            let message = self
+                .content
+                .as_ref()
+                .and_then(|content| content.pointer("/error/message"))
+                .and_then(serde_json::Value::as_str)
+                .unwrap_or_default()
+                .to_owned();
+            let status = self.status;
+            return Err(Error::with_message(
+                ErrorKind::HttpResponse { status, error_code },
+                || format!("batched request failed with status {}: {}", status, message),
+            ));
+        }
+        Ok(self.content.unwrap_or(serde_json::Value::Null))
+    }
+}
+
+/// Items of a batch, each with the position of its request in the batch.
+pub type Indexed<T> = Vec<(usize, T)>;
+
+/// The responses to all requests of a batch, which can succeed or fail one by one.
+///
+/// Azure Resource Manager answers a batch as a whole even when some of its requests fail, so a
+/// batch is often a partial success. `split` and `partition` separate the requests that
+/// succeeded from those that failed, keeping the position of each request in the batch so
+/// failures can be retried or reported.
+#[derive(Clone, Debug)]
+pub struct BatchResponse {
+    responses: Vec<Response>,
+}
+
+impl BatchResponse {
+    /// The response to every request, in the order the requests were added.
+    pub fn responses(&self) -> &[Response] {
+        &self.responses
+    }
+
+    pub fn into_responses(self) -> Vec<Response> {
+        self.responses
+    }
+
+    pub fn len(&self) -> usize {
+        self.responses.len()
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.responses.is_empty()
+    }
+
+    /// Whether every request of the batch succeeded.
+    pub fn all_succeeded(&self) -> bool {
+        self.responses.iter().all(Response::is_success)
+    }
+
+    /// Whether some requests of the batch succeeded and others failed.
+    pub fn is_partial_success(&self) -> bool {
+        self.responses.iter().any(Response::is_success) && !self.all_succeeded()
+    }
+
+    /// Separate the responses of the requests that succeeded from those that failed, each with
+    /// the position of its request in the batch.
+    pub fn partition(self) -> (Indexed<Response>, Indexed<Response>) {
+        self.responses
+            .into_iter()
+            .enumerate()
+            .partition(|(_, response)| response.is_success())
+    }
+
+    /// Deserialize the body of every response into `T`, in the order the requests were added;
+    /// see [`Response::into_body`].
+    pub fn into_bodies<T>(self) -> Vec<crate::Result<T>>
+    where
+        T: serde::de::DeserializeOwned,
+    {
+        self.responses
+            .into_iter()
+            .map(Response::into_body)
+            .collect()
+    }
+
+    /// Deserialize the bodies of the requests that succeeded into `T`, and turn the others into
+    /// errors, each with the position of its request in the batch.
+    ///
+    /// A failed request gives an `ErrorKind::HttpResponse` error carrying its status and error
+    /// code, a body that does not match `T` an `ErrorKind::DataConversion` error. All requests
+    /// of the batch must return `T`.
+    pub fn split<T>(self) -> (Indexed<T>, Indexed<Error>)
+    where
+        T: serde::de::DeserializeOwned,
+    {
+        let mut successes = Vec::new();
+        let mut failures = Vec::new();
+        for (index, response) in self.responses.into_iter().enumerate() {
+            match response.into_body() {
+                Ok(body) => successes.push((index, body)),
+                Err(err) => failures.push((index, err)),
+            }
+        }
+        (successes, failures)
+    }
+}
+
+impl IntoIterator for BatchResponse {
+    type Item = Response;
+    type IntoIter = std::vec::IntoIter<Response>;
+
+    fn into_iter(self) -> Self::IntoIter {
+        self.responses.into_iter()
+    }
+}
+
+/// Sends many reads as Azure Resource Manager `$batch` requests, instead of one round trip
+/// each.
+///
+/// Generated clients create it with their `batch` method. The batch is sent with the client's
+/// token and settings; see [`RequestBuilder::send`].
+#[derive(Clone, Debug)]
+pub struct RequestBuilder {
+    client: ServiceClient,
+    urls: Vec<Url>,
+}
+
+impl RequestBuilder {
+    pub fn new(client: ServiceClient) -> Self {
+        Self {
+            client,
+            urls: Vec::new(),
+        }
+    }
+
+    /// Add `request` to the batch. Its response is at the same position in the result of
+    /// `send` as the request is in the batch.
+    ///
+    /// Only the URL of `request` is sent; headers it would set, such as `If-None-Match`, and
+    /// its `claims` are not. Fails if the URL cannot be built, such as for an empty argument, or
+    /// if its ids are invalid and `ClientBuilder::validate_ids` is enabled.
+    pub fn push(&mut self, request: &impl BatchRead) -> crate::Result<()> {
+        let url = request.batch_url()?;
+        if self.client.validates_ids() {
+            validate_ids(&url)?;
+        }
+        self.urls.push(url);
+        Ok(())
+    }
+
+    /// The number of requests in the batch.
+    pub fn len(&self) -> usize {
+        self.urls.len()
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.urls.is_empty()
+    }
+
+    /// Send the batch and return the response to every request, in the order the requests were
+    /// added.
+    ///
+    /// Batches of more than [`MAX_REQUESTS`] requests are sent as several `$batch` requests,
+    /// one after another. A batch the service answers with `202 Accepted` is polled until it
+    /// has finished. The call fails as a whole only if a `$batch` request itself fails; a
+    /// request of the batch that fails, such as with `404 Not Found`, is reported by its
+    /// `Response`; see [`BatchResponse::split`].
+    ///
+    /// The returned future is `'static` because it owns a clone of the builder, so it can be
+    /// spawned or stored.
+    pub fn send(&self) -> BoxFuture<'static, crate::Result<BatchResponse>> {
+        let this = self.clone();
+        Box::pin(async move { this.send_ref().await })
+    }
+
+    /// Send the batch without consuming or cloning the builder; see [`send`](Self::send).
+    pub async fn send_ref(&self) -> crate::Result<BatchResponse> {
+        let mut responses = Vec::with_capacity(self.urls.len());
+        for urls in self.urls.chunks(MAX_REQUESTS) {
+            responses.extend(self.send_chunk(urls).await?);
+        }
+        Ok(BatchResponse { responses })
+    }
+
+    async fn send_chunk(&self, urls: &[Url]) -> crate::Result<Vec<Response>> {
+        let mut url = Url::parse(&format!("{}/batch", self.client.endpoint()))?;
+        url.query_pairs_mut()
+            .append_pair(query_param::API_VERSION, API_VERSION);
+        let mut req = Request::new(url, Method::Post);
+        let token_response = self.client.token(None).await?;
+        req.insert_header(
+            headers::AUTHORIZATION,
+            format!("Bearer {}", token_response.token.secret()),
+        );
+        req.insert_header(headers::CONTENT_TYPE, "application/json");
+        let requests = urls
+            .iter()
+            .enumerate()
+            .map(|(index, url)| SubRequest {
+                name: index.to_string(),
+                http_method: "GET",
+                url: url.as_str(),
+            })
+            .collect();
+        req.set_body(crate::to_json(&BatchRequestBody { requests })?);
+        let mut rsp = self.client.send(&mut req, "batch").await?;
+        let mut clock = PollClock::new(PollOptions::default());
+        while rsp.status() == StatusCode::Accepted {
+            let location = match rsp.headers().get_optional_str(&headers::LOCATION) {
+                Some(location) => Url::parse(self.client.endpoint())?.join(location)?,
+                None => return Err(unexpected_status(&rsp)),
+            };
+            clock.tick("batch", rsp.retry_after(), rsp.status()).await?;
+            let mut req = Request::new(location, Method::Get);
+            let token_response = self.client.token(None).await?;
+            req.insert_header(
+                headers::AUTHORIZATION,
+                format!("Bearer {}", token_response.token.secret()),
+            );
+            req.set_body(crate::EMPTY_BODY);
+            rsp = self.client.send(&mut req, "batch").await?;
+        }
+        let status = rsp.status();
+        let bytes = rsp.into_body().collect().await?;
+        let body: BatchResponseBody = from_json_body("batch", status, &bytes)?;
+        let mut responses: Vec<Option<Response>> = vec![None; urls.len()];
+        for (position, response) in body.responses.into_iter().enumerate() {
+            let index = match &response.name {
+                Some(name) => name.parse().unwrap_or(position),
+                None => position,
+            };
+            let status = StatusCode::try_from(response.http_status_code).map_err(|_| {
+                Error::with_message(ErrorKind::DataConversion, || {
+                    format!(
+                        "batch response has an invalid status {}",
+                        response.http_status_code
+                    )
+                })
+            })?;
+            if let Some(slot) = responses.get_mut(index) {
+                *slot = Some(Response {
+                    status,
+                    headers: response.headers,
+                    content: response.content,
+                });
+            }
+        }
+        responses
+            .into_iter()
+            .enumerate()
+            .map(|(index, response)| {
+                response.ok_or_else(|| {
+                    Error::with_message(ErrorKind::DataConversion, || {
+                        format!("batch response has no response for request {}", index)
+                    })
+                })
+            })
+            .collect()
+    }
+}
@@ -0,0 +1,24 @@
+//! Synchronous access to generated clients, for callers that do not run an async runtime.
+//!
+//! With the `blocking` feature of a generated crate enabled, every request builder has a `call`
+//! method that sends the request and waits for the result with [`block_on`]. `call` must not be
+//! used from within an async runtime.
+
+use crate::error::{Error, ErrorKind};
+use std::future::Future;
+
+thread_local! {
+    static RUNTIME: std::io::Result<tokio::runtime::Runtime> =
+        tokio::runtime::Builder::new_current_thread().enable_all().build();
+}
+
+/// Run `future` to completion on a current-thread runtime, which is created once per thread
+/// and reused.
+pub fn block_on<T>(future: impl Future<Output = crate::Result<T>>) -> crate::Result<T> {
+    RUNTIME.with(|runtime| match runtime {
+        Ok(runtime) => runtime.block_on(future),
+        Err(err) => Err(Error::with_message(ErrorKind::Other, || {
+            format!("failed to start the blocking runtime: {}", err)
+        })),
+    })
+}
@@ -0,0 +1,107 @@
+use super::decode_path_segment;
+use crate::error::{Error, ErrorKind};
+use crate::Url;
+
+/// A naming rule: the path segment that precedes an id, the argument name of the id, a
+/// description of the valid ids and the check itself.
+type Rule = (&'static str, &'static str, &'static str, fn(&str) -> bool);
+
+/// The Azure Resource Manager naming rules `validate_ids` knows, by the collection segment that
+/// precedes the id in a resource path.
+///
+/// Add a rule when a generated crate wants the ids of its own resources checked as well; ids
+/// in collections without a rule are not checked.
+const RULES: [Rule; 3] = [
+    (
+        "subscriptions",
+        "subscription_id",
+        "a GUID such as `00000000-0000-0000-0000-000000000000`",
+        is_subscription_id,
+    ),
+    (
+        "resourceGroups",
+        "resource_group_name",
+        "1 to 90 letters, digits, underscores, hyphens, periods and parentheses, not ending in a period",
+        is_resource_group_name,
+    ),
+    (
+        "automationAccounts",
+        "automation_account_name",
+        "6 to 50 letters, digits and hyphens, starting with a letter and ending with a letter or digit",
+        is_automation_account_name,
+    ),
+];
+
+fn is_subscription_id(value: &str) -> bool {
+    value.len() == 36 && uuid::Uuid::parse_str(value).is_ok()
+}
+
+fn is_resource_group_name(value: &str) -> bool {
+    (1..=90).contains(&value.chars().count())
+        && !value.ends_with('.')
+        && value
+            .chars()
+            .all(|c| c.is_alphanumeric() || "_-.()".contains(c))
+}
+
+fn is_automation_account_name(value: &str) -> bool {
+    (6..=50).contains(&value.len())
+        && value.starts_with(|c: char| c.is_ascii_alphabetic())
+        && value.ends_with(|c: char| c.is_ascii_alphanumeric())
+        && value.chars().all(|c| c.is_ascii_alphanumeric() || c == '-')
+}
+
+/// Check the ids in the path of `url`, such as the subscription id and the resource group name,
+/// against the Azure naming rules; see `ClientBuilder::validate_ids`.
+pub fn validate_ids(url: &Url) -> crate::Result<()> {
+    let segments: Vec<&str> = url
+        .path_segments()
+        .map(Iterator::collect)
+        .unwrap_or_default();
+    for (collection, argument, expected, is_valid) in RULES {
+        let value = match segments.iter().position(|segment| *segment == collection) {
+            Some(index) => match segments.get(index + 1) {
+                Some(value) => decode_path_segment(argument, value)?,
+                None => continue,
+            },
+            None => continue,
+        };
+        if !is_valid(&value) {
+            return Err(Error::with_message(ErrorKind::Other, || {
+                format!("invalid `{}` {:?}: expected {}", argument, value, expected)
+            }));
+        }
+    }
+    Ok(())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn validate(path: &str) -> crate::Result<()> {
+        validate_ids(&Url::parse(&format!("https://management.azure.com{}", path)).unwrap())
+    }
+
+    #[test]
+    fn valid_ids() {
+        validate("/subscriptions/00000000-0000-0000-0000-000000000000/resourceGroups/my-rg.1(a)/providers/Microsoft.Automation/automationAccounts/account-1/runbooks/x").unwrap();
+        validate("/providers/Microsoft.Automation/operations").unwrap();
+    }
+
+    #[test]
+    fn invalid_ids() {
+        let err = validate("/subscriptions/my-subscription").unwrap_err();
+        assert!(err.to_string().starts_with("invalid `subscription_id`"));
+        let err = validate(
+            "/subscriptions/00000000-0000-0000-0000-000000000000/resourceGroups/rg./providers",
+        )
+        .unwrap_err();
+        assert!(err.to_string().starts_with("invalid `resource_group_name`"));
+        let err = validate("/subscriptions/00000000-0000-0000-0000-000000000000/resourceGroups/rg/providers/Microsoft.Automation/automationAccounts/1account")
+            .unwrap_err();
+        assert!(err
+            .to_string()
+            .starts_with("invalid `automation_account_name`"));
+    }
+}
@@ -0,0 +1,872 @@
+//! The runtime shared by the generated service clients.
+//!
+//! A generated crate wraps a [`ServiceClient`] in its own `Client`, which implements
+//! [`GeneratedClient`] so that [`ClientBuilder`] can build it, and calls the helpers of this
+//! module from its request builders. The settings of [`ClientBuilder`] apply to every request
+//! sent through the client.
+
+mod ids;
+mod path;
+mod response;
+mod stream;
+
+pub use ids::validate_ids;
+pub use path::{append_query_params, carry_query_params, decode_path_segment, path_segment};
+pub use response::{
+    from_json_body, not_found_as_none, rate_limit_remaining, unexpected_status,
+    warn_on_ignored_next_link,
+};
+pub use stream::{checkpoint_stream, item_stream, map_concurrent};
+
+pub(crate) use response::response_request_ids;
+
+use crate::auth::{BearerChallenge, TokenCredential, TokenResponse};
+use crate::error::{Error, ErrorKind, ResultExt};
+use crate::headers::{self, Headers};
+use crate::{
+    Body, ClientOptions, Context, HttpClient, Method, OperationName, Pipeline, Policy, Request,
+    RequestTimeoutPolicy, Response, RetryBudget, RetryOptions, StatusCode, TelemetryOptions,
+    TransportOptions, Url,
+};
+use std::marker::PhantomData;
+use std::sync::Arc;
+use std::time::{Duration, Instant};
+
+/// Generates the `x-ms-client-request-id` stamped on requests that do not carry one.
+pub type ClientRequestIdGenerator = Arc<dyn Fn() -> String + Send + Sync>;
+
+/// Inspects or changes every request right before it enters the pipeline; see
+/// [`ClientBuilder::on_request`].
+pub type RequestHook = Arc<dyn Fn(&mut Request) + Send + Sync>;
+
+/// A client of a generated crate, which [`ClientBuilder`] builds around a [`ServiceClient`].
+pub trait GeneratedClient: From<ServiceClient> {
+    /// The endpoint requests are sent to unless [`ClientBuilder::endpoint`] sets another.
+    const DEFAULT_ENDPOINT: &'static str;
+    /// The name of the generated crate, reported in the `User-Agent` header. Pass
+    /// `option_env!("CARGO_PKG_NAME")`.
+    const CRATE_NAME: Option<&'static str>;
+    /// The version of the generated crate, reported in the `User-Agent` header. Pass
+    /// `option_env!("CARGO_PKG_VERSION")`.
+    const CRATE_VERSION: Option<&'static str>;
+}
+
+/// The endpoint, credential, pipeline and settings behind a generated client.
+///
+/// Cloning is cheap and clones share the pipeline and the credential, and so its token cache.
+#[derive(Clone)]
+pub struct ServiceClient {
+    endpoint: String,
+    credential: Arc<dyn TokenCredential>,
+    scopes: Vec<String>,
+    pipeline: Pipeline,
+    request_compression: bool,
+    client_request_id_generator: ClientRequestIdGenerator,
+    retry_budget: Option<RetryBudget>,
+    dry_run: bool,
+    on_request: Option<RequestHook>,
+    slow_request_threshold: Option<Duration>,
+    reauthenticate_on_challenge: bool,
+    validate_ids: bool,
+    accept_language: Option<String>,
+}
+
+impl std::fmt::Debug for ServiceClient {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.debug_struct("ServiceClient")
+            .field("endpoint", &self.endpoint)
+            .field("scopes", &self.scopes)
+            .field("pipeline", &self.pipeline)
+            .field("request_compression", &self.request_compression)
+            .field("retry_budget", &self.retry_budget)
+            .field("dry_run", &self.dry_run)
+            .field("slow_request_threshold", &self.slow_request_threshold)
+            .field(
+                "reauthenticate_on_challenge",
+                &self.reauthenticate_on_challenge,
+            )
+            .field("validate_ids", &self.validate_ids)
+            .field("accept_language", &self.accept_language)
+            .finish_non_exhaustive()
+    }
+}
+
+impl ServiceClient {
+    /// Create a client that sends requests to `endpoint` through `pipeline`, with tokens for
+    /// `scopes` from `credential` and every other setting off.
+    pub fn new(
+        endpoint: impl Into<String>,
+        credential: Arc<dyn TokenCredential>,
+        scopes: Vec<String>,
+        pipeline: Pipeline,
+    ) -> Self {
+        Self {
+            endpoint: endpoint.into(),
+            credential,
+            scopes,
+            pipeline,
+            request_compression: false,
+            client_request_id_generator: Arc::new(|| uuid::Uuid::new_v4().to_string()),
+            retry_budget: None,
+            dry_run: false,
+            on_request: None,
+            slow_request_threshold: None,
+            reauthenticate_on_challenge: false,
+            validate_ids: false,
+            accept_language: None,
+        }
+    }
+
+    pub fn endpoint(&self) -> &str {
+        self.endpoint.as_str()
+    }
+
+    pub fn token_credential(&self) -> &dyn TokenCredential {
+        self.credential.as_ref()
+    }
+
+    pub fn scopes(&self) -> Vec<&str> {
+        self.scopes.iter().map(String::as_str).collect()
+    }
+
+    /// Whether [`ClientBuilder::validate_ids`] is enabled.
+    pub fn validates_ids(&self) -> bool {
+        self.validate_ids
+    }
+
+    /// Get a token for the client's scopes, asking the credential for `claims` if a request
+    /// builder sets them.
+    pub async fn token(&self, claims: Option<&str>) -> crate::Result<TokenResponse> {
+        let scopes = self.scopes().join(" ");
+        match claims {
+            Some(claims) => self.credential.get_token_with_claims(&scopes, claims).await,
+            None => self.credential.get_token(&scopes).await,
+        }
+    }
+
+    /// Send `request` for `operation`, such as `"dsc_node::get"`, through the pipeline.
+    pub async fn send(
+        &self,
+        request: &mut Request,
+        operation: &'static str,
+    ) -> crate::Result<Response> {
+        self.send_with_context(request, operation, Context::default())
+            .await
+    }
+
+    /// Like [`send`](Self::send), with `context` for the pipeline policies, such as an
+    /// [`Idempotent`](crate::Idempotent) marker.
+    ///
+    /// The response carries the `x-ms-client-request-id` the request was sent with and the
+    /// time the round trip took; see [`Response::elapsed`].
+    pub async fn send_with_context(
+        &self,
+        request: &mut Request,
+        operation: &'static str,
+        mut context: Context,
+    ) -> crate::Result<Response> {
+        context.insert(OperationName(operation));
+        if let Some(retry_budget) = &self.retry_budget {
+            context.insert(retry_budget.clone());
+        }
+        if self.validate_ids {
+            validate_ids(request.url())?;
+        }
+        if self.dry_run
+            && matches!(
+                request.method(),
+                Method::Put | Method::Patch | Method::Delete
+            )
+        {
+            return Ok(dry_run_response(request, operation));
+        }
+        if self.request_compression {
+            compress_body(request)?;
+        }
+        let client_request_id = match request
+            .headers()
+            .get_optional_string(&headers::CLIENT_REQUEST_ID)
+        {
+            Some(client_request_id) => client_request_id,
+            None => {
+                let client_request_id = (self.client_request_id_generator)();
+                request.insert_header(headers::CLIENT_REQUEST_ID, client_request_id.clone());
+                client_request_id
+            }
+        };
+        if let Some(accept_language) = &self.accept_language {
+            if request
+                .headers()
+                .get_optional_str(&headers::ACCEPT_LANGUAGE)
+                .is_none()
+            {
+                request.insert_header(headers::ACCEPT_LANGUAGE, accept_language.clone());
+            }
+        }
+        if let Some(on_request) = &self.on_request {
+            on_request(request);
+        }
+        let start = Instant::now();
+        let mut rsp = self.pipeline.send(&mut context, request).await;
+        if self.reauthenticate_on_challenge {
+            if let Some(challenge) = rsp.as_ref().err().and_then(bearer_challenge) {
+                self.reauthenticate(request, &challenge).await?;
+                if let Some(on_request) = &self.on_request {
+                    on_request(request);
+                }
+                rsp = self.pipeline.send(&mut context, request).await;
+            }
+        }
+        let elapsed = start.elapsed();
+        if let Some(threshold) = self.slow_request_threshold {
+            warn_if_slow(operation, &rsp, elapsed, threshold, &client_request_id);
+        }
+        let rsp = rsp
+            .or_else(|err| response::not_modified_as_response(request, err))
+            .map_err(|err| {
+                let ids = match err.as_http_error() {
+                    Some(http_error) => response::request_ids(
+                        http_error.request_id(),
+                        http_error.correlation_request_id(),
+                    ),
+                    None => return err,
+                };
+                err.context(format!("request failed ({})", ids))
+            })?;
+        let (status, mut headers, body) = rsp.deconstruct();
+        headers.insert(headers::CLIENT_REQUEST_ID, client_request_id);
+        Ok(Response::new(status, headers, Box::pin(body)).with_elapsed(elapsed))
+    }
+
+    /// Replace the token of `request`, which the service rejected with `challenge`, with a
+    /// newly acquired one.
+    async fn reauthenticate(
+        &self,
+        request: &mut Request,
+        challenge: &BearerChallenge,
+    ) -> crate::Result<()> {
+        self.credential.clear_cache().await?;
+        let scopes = self.scopes().join(" ");
+        let token_response = match challenge.claims() {
+            Some(claims) => {
+                self.credential
+                    .get_token_with_claims(&scopes, &claims)
+                    .await?
+            }
+            None => self.credential.get_token(&scopes).await?,
+        };
+        request.insert_header(
+            headers::AUTHORIZATION,
+            format!("Bearer {}", token_response.token.secret()),
+        );
+        Ok(())
+    }
+
+    /// Check that the credential can get a token for the configured scopes.
+    ///
+    /// Requests a token once and discards it. Useful as a preflight before starting work, so an
+    /// authentication problem is reported up front instead of from the first operation.
+    pub async fn check_credential(&self) -> crate::Result<()> {
+        self.token_credential()
+            .get_token(&self.scopes().join(" "))
+            .await?;
+        Ok(())
+    }
+
+    /// Create a client that sends requests to `endpoint`, sharing the credential, pipeline and
+    /// settings of this one.
+    ///
+    /// For failing over to another region without rebuilding authentication state: the
+    /// credential, and so its token cache, is shared rather than recreated. Scopes that start
+    /// with the current endpoint, such as the default scope derived from it, are rewritten to
+    /// start with `endpoint`; other scopes are kept as they are.
+    #[must_use]
+    pub fn with_endpoint(&self, endpoint: impl Into<String>) -> Self {
+        let endpoint = endpoint.into();
+        let scopes = self
+            .scopes
+            .iter()
+            .map(|scope| match scope.strip_prefix(self.endpoint.as_str()) {
+                Some(suffix) => format!("{}{}", endpoint, suffix),
+                None => scope.clone(),
+            })
+            .collect();
+        Self {
+            endpoint,
+            scopes,
+            ..self.clone()
+        }
+    }
+
+    /// Check that every scope is for the host of the endpoint.
+    ///
+    /// Scopes that are not URLs, such as bare application ids, are not checked. Fails with an
+    /// `ErrorKind::Credential` error naming the scope and the endpoint otherwise.
+    pub fn validate_scopes(&self) -> crate::Result<()> {
+        let endpoint = Url::parse(self.endpoint())?;
+        let endpoint_host = endpoint.host_str().map(str::to_ascii_lowercase);
+        for scope in &self.scopes {
+            let scope_host = match Url::parse(scope) {
+                Ok(scope) => scope.host_str().map(str::to_ascii_lowercase),
+                Err(_) => continue,
+            };
+            if scope_host != endpoint_host {
+                return Err(Error::with_message(ErrorKind::Credential, || {
+                    format!(
+                        "scope `{}` is not for endpoint `{}`; tokens for it are rejected by the endpoint. Set matching `scopes` or `endpoint`, or turn off `validate_scopes` if this is intended",
+                        scope, self.endpoint
+                    )
+                }));
+            }
+        }
+        Ok(())
+    }
+}
+
+/// Answer a request that `dry_run` keeps from being sent with a synthetic `200 OK` echoing its
+/// body.
+fn dry_run_response(request: &Request, operation: &'static str) -> Response {
+    let body = match request.body() {
+        Body::Bytes(body) => body.clone(),
+        _ => bytes::Bytes::new(),
+    };
+    log::info!(
+        "dry run: not sending {} {} ({}): {}",
+        request.method(),
+        request.url(),
+        operation,
+        String::from_utf8_lossy(&body)
+    );
+    let mut headers = Headers::new();
+    if let Some(content_type) = request
+        .headers()
+        .get_optional_string(&headers::CONTENT_TYPE)
+    {
+        headers.insert(headers::CONTENT_TYPE, content_type);
+    }
+    Response::new(
+        StatusCode::Ok,
+        headers,
+        Box::pin(futures::stream::once(futures::future::ready(Ok(body)))),
+    )
+}
+
+fn compress_body(request: &mut Request) -> crate::Result<()> {
+    use std::io::Write;
+    let body = match request.body() {
+        Body::Bytes(body) if !body.is_empty() => body.clone(),
+        _ => return Ok(()),
+    };
+    let mut encoder = flate2::write::GzEncoder::new(Vec::new(), flate2::Compression::default());
+    encoder
+        .write_all(&body)
+        .context(ErrorKind::Io, "failed to compress request body")?;
+    let compressed = encoder
+        .finish()
+        .context(ErrorKind::Io, "failed to compress request body")?;
+    request.insert_header(headers::CONTENT_ENCODING, "gzip");
+    request.set_body(bytes::Bytes::from(compressed));
+    Ok(())
+}
+
+/// The `Bearer` challenge of a `401 Unauthorized` error, if it has one.
+fn bearer_challenge(err: &Error) -> Option<BearerChallenge> {
+    match err.kind() {
+        ErrorKind::HttpResponse {
+            status: StatusCode::Unauthorized,
+            ..
+        } => BearerChallenge::parse(err.as_http_error()?.www_authenticate()?),
+        _ => None,
+    }
+}
+
+/// Log a warning if a request took longer than `threshold`; see
+/// [`ClientBuilder::slow_request_threshold`].
+fn warn_if_slow(
+    operation: &'static str,
+    rsp: &crate::Result<Response>,
+    elapsed: Duration,
+    threshold: Duration,
+    client_request_id: &str,
+) {
+    if elapsed <= threshold {
+        return;
+    }
+    let status = match rsp {
+        Ok(rsp) => rsp.status().to_string(),
+        Err(err) => match err.kind() {
+            ErrorKind::HttpResponse { status, .. } => status.to_string(),
+            kind => kind.to_string(),
+        },
+    };
+    log::warn!(
+        "slow request: operation={} status={} elapsed_ms={} threshold_ms={} client_request_id={}",
+        operation,
+        status,
+        elapsed.as_millis(),
+        threshold.as_millis(),
+        client_request_id
+    );
+}
+
+/// Builds the `Client` `C` of a generated crate.
+///
+/// Generated crates name it `ClientBuilder`, for their own `Client`.
+pub struct ClientBuilder<C> {
+    credential: Arc<dyn TokenCredential>,
+    endpoint: Option<String>,
+    scopes: Option<Vec<String>>,
+    scope_suffix: Option<String>,
+    audience: Option<String>,
+    options: ClientOptions,
+    timeout: Option<Duration>,
+    per_call_policies: Vec<Arc<dyn Policy>>,
+    per_retry_policies: Vec<Arc<dyn Policy>>,
+    request_compression: bool,
+    recording_policy: Option<Arc<dyn Policy>>,
+    har_recorder: Option<Arc<dyn Policy>>,
+    client_request_id_generator: Option<ClientRequestIdGenerator>,
+    retry_budget: Option<RetryBudget>,
+    dry_run: bool,
+    validate_scopes: bool,
+    on_request: Option<RequestHook>,
+    slow_request_threshold: Option<Duration>,
+    reauthenticate_on_challenge: bool,
+    validate_ids: bool,
+    accept_language: Option<String>,
+    client: PhantomData<fn() -> C>,
+}
+
+impl<C> Clone for ClientBuilder<C> {
+    fn clone(&self) -> Self {
+        Self {
+            credential: self.credential.clone(),
+            endpoint: self.endpoint.clone(),
+            scopes: self.scopes.clone(),
+            scope_suffix: self.scope_suffix.clone(),
+            audience: self.audience.clone(),
+            options: self.options.clone(),
+            timeout: self.timeout,
+            per_call_policies: self.per_call_policies.clone(),
+            per_retry_policies: self.per_retry_policies.clone(),
+            request_compression: self.request_compression,
+            recording_policy: self.recording_policy.clone(),
+            har_recorder: self.har_recorder.clone(),
+            client_request_id_generator: self.client_request_id_generator.clone(),
+            retry_budget: self.retry_budget.clone(),
+            dry_run: self.dry_run,
+            validate_scopes: self.validate_scopes,
+            on_request: self.on_request.clone(),
+            slow_request_threshold: self.slow_request_threshold,
+            reauthenticate_on_challenge: self.reauthenticate_on_challenge,
+            validate_ids: self.validate_ids,
+            accept_language: self.accept_language.clone(),
+            client: PhantomData,
+        }
+    }
+}
+
+impl<C> std::fmt::Debug for ClientBuilder<C> {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.debug_struct("ClientBuilder")
+            .field("endpoint", &self.endpoint)
+            .field("scopes", &self.scopes)
+            .field("scope_suffix", &self.scope_suffix)
+            .field("audience", &self.audience)
+            .field("options", &self.options)
+            .field("timeout", &self.timeout)
+            .field("request_compression", &self.request_compression)
+            .field("dry_run", &self.dry_run)
+            .field("validate_scopes", &self.validate_scopes)
+            .field("validate_ids", &self.validate_ids)
+            .field("accept_language", &self.accept_language)
+            .finish_non_exhaustive()
+    }
+}
+
+impl<C: GeneratedClient> ClientBuilder<C> {
+    /// Create a new instance of `ClientBuilder`.
+    ///
+    /// To use a token acquired outside the process, pass an
+    /// [`StaticTokenCredential`](crate::auth::StaticTokenCredential).
+    #[must_use]
+    pub fn new(credential: Arc<dyn TokenCredential>) -> Self {
+        Self {
+            credential,
+            endpoint: None,
+            scopes: None,
+            scope_suffix: None,
+            audience: None,
+            options: ClientOptions::default().retry(RetryOptions::default().idempotent_only(true)),
+            timeout: None,
+            per_call_policies: Vec::new(),
+            per_retry_policies: Vec::new(),
+            request_compression: false,
+            recording_policy: None,
+            har_recorder: None,
+            client_request_id_generator: None,
+            retry_budget: None,
+            dry_run: false,
+            validate_scopes: true,
+            on_request: None,
+            slow_request_threshold: None,
+            reauthenticate_on_challenge: false,
+            validate_ids: false,
+            accept_language: None,
+            client: PhantomData,
+        }
+    }
+
+    /// Set the endpoint.
+    #[must_use]
+    pub fn endpoint(mut self, endpoint: impl Into<String>) -> Self {
+        self.endpoint = Some(endpoint.into());
+        self
+    }
+
+    /// Set the scopes.
+    ///
+    /// The scopes are passed to the credential verbatim and take precedence over `audience`
+    /// and `scope_suffix`.
+    #[must_use]
+    pub fn scopes(mut self, scopes: &[&str]) -> Self {
+        self.scopes = Some(scopes.iter().map(|scope| (*scope).to_owned()).collect());
+        self
+    }
+
+    /// Set the suffix appended to the endpoint to form the default scope.
+    ///
+    /// Defaults to `"/"`. Use `"/.default"` for credentials that expect the `.default` form, or
+    /// `""` for the bare endpoint. Ignored when `scopes` is set.
+    #[must_use]
+    pub fn scope_suffix(mut self, scope_suffix: impl Into<String>) -> Self {
+        self.scope_suffix = Some(scope_suffix.into());
+        self
+    }
+
+    /// Set the audience to request tokens for, when it differs from the endpoint, such as
+    /// `https://management.core.windows.net/` for Azure Resource Manager behind a private
+    /// endpoint.
+    ///
+    /// The default scope becomes `{audience}/.default`, with any trailing `/` of `audience`
+    /// trimmed, instead of one derived from the endpoint. Takes precedence over `scope_suffix`;
+    /// ignored when `scopes` is set. `try_build` checks the scope like any other, so turn off
+    /// `validate_scopes` for an audience on another host than the endpoint.
+    #[must_use]
+    pub fn audience(mut self, audience: impl Into<String>) -> Self {
+        self.audience = Some(audience.into());
+        self
+    }
+
+    /// Set the retry options.
+    ///
+    /// `GET`, `PUT` and `DELETE` requests are retried after connection errors. `POST` and
+    /// `PATCH` requests are not, since the service may already have applied them; call
+    /// `idempotent(true)` on their request builders to opt in. Retries on throttling and server
+    /// error status codes apply to all methods.
+    #[must_use]
+    pub fn retry(mut self, retry: impl Into<RetryOptions>) -> Self {
+        self.options = self.options.retry(retry.into().idempotent_only(true));
+        self
+    }
+
+    /// Set the transport options.
+    #[must_use]
+    pub fn transport(mut self, transport: impl Into<TransportOptions>) -> Self {
+        self.options = self.options.transport(transport);
+        self
+    }
+
+    /// Identify the calling application in the `User-Agent` header.
+    ///
+    /// `application_id` is placed before the SDK's own `azsdk-rust-*` token, so service-side
+    /// telemetry can attribute requests to the application. Keep it short and free of spaces,
+    /// such as `contoso-inventory/1.2`.
+    #[must_use]
+    pub fn application_id(mut self, application_id: impl Into<String>) -> Self {
+        self.options = self
+            .options
+            .telemetry(TelemetryOptions::default().application_id(application_id.into()));
+        self
+    }
+
+    /// Send requests through `http_client`.
+    ///
+    /// Pass the same `HttpClient` to every client in an application, including clients from
+    /// other crates, so they share one connection pool. Clients built without a shared
+    /// `HttpClient` each open their own pool, which wastes sockets and file descriptors under
+    /// load.
+    #[must_use]
+    pub fn http_client(self, http_client: Arc<dyn HttpClient>) -> Self {
+        self.transport(http_client)
+    }
+
+    /// Record request metrics with meters from `meter_provider`.
+    ///
+    /// Every request reports `requests_total`, `request_duration_seconds` and `errors_total`,
+    /// labeled by `operation` (such as `dsc_node::get`) and `status`.
+    #[cfg(feature = "metrics")]
+    #[must_use]
+    pub fn meter_provider(
+        mut self,
+        meter_provider: Arc<dyn opentelemetry::metrics::MeterProvider + Send + Sync>,
+    ) -> Self {
+        self.options = self.options.meter_provider(meter_provider);
+        self
+    }
+
+    /// Set the timeout for each request attempt.
+    ///
+    /// An attempt that does not receive a response within `timeout` fails with an IO error. The
+    /// timeout applies to every retry separately, so the retry policy can still retry an
+    /// attempt that timed out.
+    #[must_use]
+    pub fn timeout(mut self, timeout: Duration) -> Self {
+        self.timeout = Some(timeout);
+        self
+    }
+
+    /// Add a policy that runs once per call.
+    ///
+    /// Per-call policies run in the order they were added, before the telemetry and retry
+    /// policies. The `Authorization` header is set by the request builder before the pipeline
+    /// runs, so every policy already sees it.
+    #[must_use]
+    pub fn per_call_policy(mut self, policy: Arc<dyn Policy>) -> Self {
+        self.per_call_policies.push(policy);
+        self
+    }
+
+    /// Add a policy that runs once per attempt.
+    ///
+    /// Per-retry policies run in the order they were added, after the retry policy and before
+    /// the transport, so they run again for every retry. The `Authorization` header is set by
+    /// the request builder before the pipeline runs, so every policy already sees it.
+    #[must_use]
+    pub fn per_retry_policy(mut self, policy: Arc<dyn Policy>) -> Self {
+        self.per_retry_policies.push(policy);
+        self
+    }
+
+    /// Set whether request bodies are gzip compressed.
+    ///
+    /// When enabled, the JSON body of every request that carries one is compressed and sent
+    /// with `Content-Encoding: gzip`. Requests without a body are sent unchanged. Defaults to
+    /// `false`.
+    #[must_use]
+    pub fn request_compression(mut self, request_compression: bool) -> Self {
+        self.request_compression = request_compression;
+        self
+    }
+
+    /// Record every request and response to `sink`, one JSON line per exchange, with the
+    /// `Authorization` header removed.
+    ///
+    /// The recording policy runs after all other per-retry policies, so it sees each attempt
+    /// exactly as it is sent. Recordings can be replayed by passing a
+    /// [`PlaybackTransport`](crate::record::PlaybackTransport) to `transport`.
+    #[cfg(feature = "record")]
+    #[must_use]
+    pub fn record(mut self, sink: impl std::io::Write + Send + 'static) -> Self {
+        self.recording_policy = Some(Arc::new(crate::record::RecordingPolicy::new(sink)));
+        self
+    }
+
+    /// Record every request and response with `recorder`, for writing them as an HTTP Archive
+    /// (HAR) that browser developer tools can open.
+    ///
+    /// Keep a clone of `recorder` and call its `finish` once the traffic of interest has been
+    /// sent; the archive is written to its sink then, or when the last clone is dropped. The
+    /// recorder runs after all other per-retry policies, including the one installed by
+    /// `record`, so it sees each attempt exactly as it is sent. The values of the
+    /// `Authorization` header are redacted; bodies are recorded verbatim and may hold secrets,
+    /// such as credential passwords.
+    #[cfg(feature = "har")]
+    #[must_use]
+    pub fn record_har(mut self, recorder: crate::har::HarRecorder) -> Self {
+        self.har_recorder = Some(Arc::new(recorder));
+        self
+    }
+
+    /// Set the generator for the `x-ms-client-request-id` header.
+    ///
+    /// Every request that does not already carry the header gets a fresh id from the
+    /// generator. Defaults to a random UUID.
+    #[must_use]
+    pub fn client_request_id_generator(
+        mut self,
+        client_request_id_generator: ClientRequestIdGenerator,
+    ) -> Self {
+        self.client_request_id_generator = Some(client_request_id_generator);
+        self
+    }
+
+    /// Bound the rate of retries across all requests sent by the client.
+    ///
+    /// Each retry takes a token from `retry_budget`. Once it is exhausted, failed attempts are
+    /// returned to the caller instead of being retried, which keeps many concurrent operations
+    /// from amplifying the load on the service during an outage. Pass clones of the same budget
+    /// to several builders to share it between clients.
+    #[must_use]
+    pub fn retry_budget(mut self, retry_budget: RetryBudget) -> Self {
+        self.retry_budget = Some(retry_budget);
+        self
+    }
+
+    /// Set whether mutating requests are logged instead of sent.
+    ///
+    /// When enabled, `PUT`, `PATCH` and `DELETE` requests never reach the service. Each one is
+    /// logged at `info` level with its method, URL and body, and answered with a synthetic
+    /// `200 OK` whose body is the request body, so a "what would change" preview runs the same
+    /// code as a real run. The body is logged verbatim and may hold secrets, such as credential
+    /// passwords. `GET` and `POST` requests, including `POST` actions such as regenerating a
+    /// key, are still sent. Defaults to `false`.
+    #[must_use]
+    pub fn dry_run(mut self, dry_run: bool) -> Self {
+        self.dry_run = dry_run;
+        self
+    }
+
+    /// Set whether `try_build` checks that the scopes are for the endpoint's host.
+    ///
+    /// Defaults to `true`. Turn it off for cross-resource setups where the token is
+    /// deliberately issued for another host.
+    #[must_use]
+    pub fn validate_scopes(mut self, validate_scopes: bool) -> Self {
+        self.validate_scopes = validate_scopes;
+        self
+    }
+
+    /// Call `on_request` with every request just before it is handed to the pipeline.
+    ///
+    /// The hook runs after the `Authorization` header has been inserted and the body
+    /// compressed, and once per call rather than once per retry, so it sees the request as the
+    /// per-call policies will. It runs again before a request is resent with a new token; see
+    /// `reauthenticate_on_challenge`. It can add or replace headers, such as for custom
+    /// signing, or capture the payload. It does not run for requests that `dry_run` answers
+    /// without sending.
+    #[must_use]
+    pub fn on_request(mut self, on_request: RequestHook) -> Self {
+        self.on_request = Some(on_request);
+        self
+    }
+
+    /// Log a warning for every request whose round trip takes longer than `threshold`.
+    ///
+    /// The round trip is measured across the whole pipeline, including retries, until the
+    /// response headers arrive; reading the body is not counted. The warning is logged at
+    /// `warn` level with the operation name, such as `dsc_node::get`, the status or error, the
+    /// elapsed time and the `x-ms-client-request-id`. Off by default.
+    #[must_use]
+    pub fn slow_request_threshold(mut self, threshold: Duration) -> Self {
+        self.slow_request_threshold = Some(threshold);
+        self
+    }
+
+    /// Set whether a request rejected with `401 Unauthorized` and a `Bearer` challenge is
+    /// resent once with a new token.
+    ///
+    /// A token can be rejected before it expires, such as when it was revoked or when
+    /// Continuous Access Evaluation asks for additional claims. When enabled, the credential's
+    /// cached token is cleared with `clear_cache`, a new one is acquired, with
+    /// `get_token_with_claims` if the challenge carries claims, and the request is sent once
+    /// more. A second `401` is returned to the caller. The credentials of `azure_identity` that
+    /// can request claims, such as `ClientSecretCredential`, do so; others fail with an
+    /// `ErrorKind::Credential` error when the challenge carries claims. Defaults to `false`.
+    #[must_use]
+    pub fn reauthenticate_on_challenge(mut self, reauthenticate_on_challenge: bool) -> Self {
+        self.reauthenticate_on_challenge = reauthenticate_on_challenge;
+        self
+    }
+
+    /// Set whether the ids in the path of every request are checked before it is sent.
+    ///
+    /// When enabled, an id that breaks the Azure naming rules known to [`validate_ids`], such
+    /// as a subscription id that is not a GUID, fails with an `ErrorKind::Other` error naming
+    /// the argument, instead of reaching the service and coming back as an unexplained
+    /// `404 Not Found`. Catches copy-paste mistakes, such as a subscription name passed as its
+    /// id, in tools that take ids from the command line. Requests answered by `dry_run` are
+    /// checked too. Defaults to `false`.
+    #[must_use]
+    pub fn validate_ids(mut self, validate_ids: bool) -> Self {
+        self.validate_ids = validate_ids;
+        self
+    }
+
+    /// Send `accept_language` as the `Accept-Language` header of every request, such as
+    /// `fr-FR`, to have the service localize its error messages.
+    ///
+    /// The localized text is what [`HttpError::error_message`](crate::error::HttpError::error_message)
+    /// returns for a failed request; error codes stay in English. A request builder's
+    /// `accept_language` overrides it for that request. Services that do not localize a message
+    /// answer in English. Unset by default, which leaves the language to the service.
+    #[must_use]
+    pub fn accept_language(mut self, accept_language: impl Into<String>) -> Self {
+        self.accept_language = Some(accept_language.into());
+        self
+    }
+
+    /// Convert the builder into a client, failing early on a scope that does not match the
+    /// endpoint.
+    ///
+    /// Catches misconfigurations such as an Azure China endpoint with a public cloud scope,
+    /// which `build` accepts and which would otherwise only fail at the first request with an
+    /// opaque AADSTS error. See [`ServiceClient::validate_scopes`] and
+    /// [`validate_scopes`](Self::validate_scopes).
+    pub fn try_build(self) -> crate::Result<C> {
+        let validate_scopes = self.validate_scopes;
+        let client = self.build_service_client();
+        if validate_scopes {
+            client.validate_scopes()?;
+        }
+        Ok(C::from(client))
+    }
+
+    /// Convert the builder into a client.
+    #[must_use]
+    pub fn build(self) -> C {
+        C::from(self.build_service_client())
+    }
+
+    fn build_service_client(mut self) -> ServiceClient {
+        let endpoint = self
+            .endpoint
+            .unwrap_or_else(|| C::DEFAULT_ENDPOINT.to_owned());
+        let scope_suffix = self.scope_suffix.as_deref().unwrap_or("/");
+        let audience = self.audience;
+        let scopes = self.scopes.unwrap_or_else(|| match audience {
+            Some(audience) => vec![format!("{}/.default", audience.trim_end_matches('/'))],
+            None => vec![format!("{}{}", endpoint, scope_suffix)],
+        });
+        if let Some(timeout) = self.timeout {
+            self.per_retry_policies
+                .push(Arc::new(RequestTimeoutPolicy::new(timeout)));
+        }
+        if let Some(recording_policy) = self.recording_policy {
+            self.per_retry_policies.push(recording_policy);
+        }
+        if let Some(har_recorder) = self.har_recorder {
+            self.per_retry_policies.push(har_recorder);
+        }
+        let pipeline = Pipeline::new(
+            C::CRATE_NAME,
+            C::CRATE_VERSION,
+            self.options,
+            self.per_call_policies,
+            self.per_retry_policies,
+        );
+        let mut client = ServiceClient::new(endpoint, self.credential, scopes, pipeline);
+        client.request_compression = self.request_compression;
+        if let Some(client_request_id_generator) = self.client_request_id_generator {
+            client.client_request_id_generator = client_request_id_generator;
+        }
+        client.retry_budget = self.retry_budget;
+        client.dry_run = self.dry_run;
+        client.on_request = self.on_request;
+        client.slow_request_threshold = self.slow_request_threshold;
+        client.reauthenticate_on_challenge = self.reauthenticate_on_challenge;
+        client.validate_ids = self.validate_ids;
+        client.accept_language = self.accept_language;
+        client
+    }
+}
@@ -0,0 +1,206 @@
+use crate::error::{Error, ErrorKind};
+use crate::{query_param, Url};
+use std::borrow::Cow;
+use url::form_urlencoded;
+
+/// Percent-encode `value` for use as a single URL path segment, naming the argument `name` in
+/// the error if the value is empty or cannot identify a resource.
+pub fn path_segment<'a>(name: &str, value: &'a str) -> crate::Result<Cow<'a, str>> {
+    fn is_allowed(b: u8) -> bool {
+        b.is_ascii_alphanumeric() || b"-._~!$&'()*+,;=:@".contains(&b)
+    }
+    if value.is_empty() {
+        return Err(Error::with_message(ErrorKind::Other, || {
+            format!("required argument `{}` is empty", name)
+        }));
+    }
+    if value == "." || value == ".." {
+        return Err(Error::with_message(ErrorKind::DataConversion, || {
+            format!(
+                "invalid value {:?} for argument `{}`: a path segment must not be \".\" or \"..\"",
+                value, name
+            )
+        }));
+    }
+    if value.bytes().all(is_allowed) {
+        return Ok(Cow::Borrowed(value));
+    }
+    let mut encoded = String::with_capacity(value.len() * 3);
+    for b in value.bytes() {
+        if is_allowed(b) {
+            encoded.push(char::from(b));
+        } else {
+            encoded.push_str(&format!("%{:02X}", b));
+        }
+    }
+    Ok(Cow::Owned(encoded))
+}
+
+/// Decode a percent-encoded URL path segment, naming the component `name` in the error if it is
+/// malformed or cannot identify a resource.
+pub fn decode_path_segment(name: &str, value: &str) -> crate::Result<String> {
+    let invalid = || {
+        Error::with_message(ErrorKind::DataConversion, || {
+            format!("invalid value {:?} for `{}`", value, name)
+        })
+    };
+    let mut decoded = Vec::with_capacity(value.len());
+    let mut bytes = value.bytes();
+    while let Some(b) = bytes.next() {
+        if b == b'%' {
+            let hex = [
+                bytes.next().ok_or_else(invalid)?,
+                bytes.next().ok_or_else(invalid)?,
+            ];
+            let hex = std::str::from_utf8(&hex).map_err(|_| invalid())?;
+            decoded.push(u8::from_str_radix(hex, 16).map_err(|_| invalid())?);
+        } else {
+            decoded.push(b);
+        }
+    }
+    let decoded = String::from_utf8(decoded).map_err(|_| invalid())?;
+    if decoded.is_empty() {
+        return Err(invalid());
+    }
+    path_segment(name, &decoded)?;
+    Ok(decoded)
+}
+
+/// Append caller supplied query parameters to `url`, replacing parameters of the same name
+/// except `api-version`, which is never changed.
+///
+/// The parameters that are kept are left as they were encoded, so that a `$filter` is still sent
+/// as `$filter` rather than `%24filter`; only the appended ones are encoded here.
+pub fn append_query_params(url: &mut Url, query_params: &[(String, String)]) {
+    let query_params: Vec<&(String, String)> = query_params
+        .iter()
+        .filter(|(key, _)| key != query_param::API_VERSION)
+        .collect();
+    if query_params.is_empty() {
+        return;
+    }
+    let replaced = |key: &str| query_params.iter().any(|(name, _)| name == key);
+    extend_query(url, query_params.iter().copied(), replaced);
+}
+
+/// Carry the query parameters of the request for the first page, `first`, over to `next`, the
+/// link to a later page.
+///
+/// Parameters the link leaves out, such as a `$select` or `$top` the service does not repeat,
+/// are appended, except for the `$skip` and `$skiptoken` that position the first page. The
+/// caller supplied `query_params` then replace those of the link, as they did on the first
+/// page; see [`append_query_params`].
+pub fn carry_query_params(next: &mut Url, first: &Url, query_params: &[(String, String)]) {
+    let missing: Vec<(String, String)> = first
+        .query_pairs()
+        .filter(|(key, _)| key != query_param::SKIP_TOKEN && key != query_param::SKIP)
+        .filter(|(key, _)| !next.query_pairs().any(|(next_key, _)| next_key == *key))
+        .map(|(key, value)| (key.into_owned(), value.into_owned()))
+        .collect();
+    if !missing.is_empty() {
+        extend_query(next, missing.iter(), |_| false);
+    }
+    append_query_params(next, query_params);
+}
+
+/// Drop the pairs of the query of `url` whose name is `replaced` and append `pairs`. The pairs
+/// that are kept are copied as they are, without decoding and encoding them again.
+fn extend_query<'a>(
+    url: &mut Url,
+    pairs: impl Iterator<Item = &'a (String, String)>,
+    replaced: impl Fn(&str) -> bool,
+) {
+    let is_replaced = |pair: &str| {
+        form_urlencoded::parse(pair.as_bytes())
+            .next()
+            .map_or(false, |(key, _)| replaced(&key))
+    };
+    let mut query: Vec<String> = url
+        .query()
+        .unwrap_or_default()
+        .split('&')
+        .filter(|pair| !pair.is_empty() && !is_replaced(pair))
+        .map(ToOwned::to_owned)
+        .collect();
+    query.push(
+        form_urlencoded::Serializer::new(String::new())
+            .extend_pairs(pairs)
+            .finish(),
+    );
+    url.set_query(Some(&query.join("&")));
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn path_segment_encodes_reserved_characters() {
+        assert_eq!(path_segment("name", "runbook-1").unwrap(), "runbook-1");
+        assert_eq!(path_segment("name", "a/b c").unwrap(), "a%2Fb%20c");
+        assert_eq!(path_segment("name", "a?b#c").unwrap(), "a%3Fb%23c");
+    }
+
+    #[test]
+    fn path_segment_rejects_empty_and_dot_segments() {
+        let err = path_segment("automation_account_name", "").unwrap_err();
+        assert_eq!(
+            err.to_string(),
+            "required argument `automation_account_name` is empty"
+        );
+        assert!(path_segment("name", ".").is_err());
+        assert!(path_segment("name", "..").is_err());
+    }
+
+    #[test]
+    fn decode_path_segment_round_trips() {
+        let encoded = path_segment("name", "a/b c").unwrap();
+        assert_eq!(decode_path_segment("name", &encoded).unwrap(), "a/b c");
+        assert!(decode_path_segment("name", "%2").is_err());
+        assert!(decode_path_segment("name", "%2E").is_err());
+    }
+
+    #[test]
+    fn append_query_params_replaces_all_but_api_version() {
+        let mut url = Url::parse("https://example.com/x?api-version=1&$top=5").unwrap();
+        append_query_params(
+            &mut url,
+            &[
+                ("$top".to_owned(), "10".to_owned()),
+                ("api-version".to_owned(), "2".to_owned()),
+                ("extra".to_owned(), "yes".to_owned()),
+            ],
+        );
+        assert_eq!(url.query(), Some("api-version=1&%24top=10&extra=yes"));
+    }
+
+    #[test]
+    fn append_query_params_leaves_the_existing_query_intact() {
+        let mut url = Url::parse(
+            "https://example.com/x?api-version=1&$filter=name%20eq%20'a'&$expand=x,y&flag",
+        )
+        .unwrap();
+        let query = url.query().unwrap().to_owned();
+        assert!(query.contains("$filter=") && query.contains("$expand=x,y"));
+        append_query_params(&mut url, &[]);
+        assert_eq!(url.query(), Some(query.as_str()));
+        append_query_params(&mut url, &[("extra".to_owned(), "a b".to_owned())]);
+        assert_eq!(url.query(), Some(format!("{}&extra=a+b", query).as_str()));
+    }
+
+    #[test]
+    fn carry_query_params_to_an_absolute_next_link() {
+        let first = Url::parse(
+            "https://example.com/x?api-version=1&$filter=a&$select=name&$skiptoken=0&mode=1",
+        )
+        .unwrap();
+        let mut next =
+            Url::parse("https://example.com/x?api-version=1&$filter=a&$skiptoken=2&mode=2")
+                .unwrap();
+        carry_query_params(&mut next, &first, &[("mode".to_owned(), "3".to_owned())]);
+        assert_eq!(
+            next.query(),
+            Some("api-version=1&$filter=a&$skiptoken=2&%24select=name&mode=3")
+        );
+    }
+}
@@ -0,0 +1,191 @@
+use crate::error::{DeserializationError, Error, ErrorKind};
+use crate::headers::{self, Headers};
+use crate::{Request, Response, StatusCode};
+
+/// The smallest value among the `x-ms-ratelimit-remaining-*` headers, such as
+/// `x-ms-ratelimit-remaining-subscription-reads`, since that quota is exhausted first.
+pub fn rate_limit_remaining(headers: &Headers) -> Option<u32> {
+    headers
+        .iter()
+        .filter(|(name, _)| name.as_str().starts_with("x-ms-ratelimit-remaining-"))
+        .filter_map(|(_, value)| value.as_str().trim().parse().ok())
+        .min()
+}
+
+/// Describe the request ids the service returned, for inclusion in error messages.
+pub(crate) fn request_ids(
+    request_id: Option<&str>,
+    correlation_request_id: Option<&str>,
+) -> String {
+    format!(
+        "x-ms-request-id: {}, x-ms-correlation-request-id: {}",
+        request_id.unwrap_or("<none>"),
+        correlation_request_id.unwrap_or("<none>")
+    )
+}
+
+/// The request ids in the headers of `rsp`; see [`request_ids`].
+pub(crate) fn response_request_ids(rsp: &Response) -> String {
+    let headers = rsp.headers();
+    request_ids(
+        headers.get_optional_str(&headers::REQUEST_ID),
+        headers.get_optional_str(&headers::CORRELATION_REQUEST_ID),
+    )
+}
+
+/// The error for a response whose status the operation does not expect.
+pub fn unexpected_status(rsp: &Response) -> Error {
+    let ids = response_request_ids(rsp);
+    Error::with_message(
+        ErrorKind::HttpResponse {
+            status: rsp.status(),
+            error_code: None,
+        },
+        || format!("unexpected status {} ({})", rsp.status(), ids),
+    )
+}
+
+/// Map a `404 Not Found` error to `Ok(None)`, for lookups where a missing resource is an
+/// expected outcome.
+pub fn not_found_as_none<T>(result: crate::Result<T>) -> crate::Result<Option<T>> {
+    match result {
+        Ok(value) => Ok(Some(value)),
+        Err(err)
+            if matches!(
+                err.kind(),
+                ErrorKind::HttpResponse {
+                    status: StatusCode::NotFound,
+                    ..
+                }
+            ) =>
+        {
+            Ok(None)
+        }
+        Err(err) => Err(err),
+    }
+}
+
+/// The pipeline reports a `304 Not Modified` answer to a conditional request as an error; turn
+/// it back into a bodiless response with the headers the service sent, such as the `ETag`.
+pub(crate) fn not_modified_as_response(request: &Request, err: Error) -> crate::Result<Response> {
+    match err.as_http_error() {
+        Some(http_error)
+            if http_error.status() == StatusCode::NotModified
+                && request
+                    .headers()
+                    .get_optional_str(&headers::IF_NONE_MATCH)
+                    .is_some() =>
+        {
+            Ok(Response::new(
+                StatusCode::NotModified,
+                http_error.headers(),
+                Box::pin(futures::stream::empty()),
+            ))
+        }
+        _ => Err(err),
+    }
+}
+
+/// Log a warning when a list operation that cannot page is answered with a link to a next page,
+/// in the body or in the `continuation` taken from the headers.
+///
+/// The response models of these operations have no next link, so a service that starts paging
+/// their results would otherwise have every page after the first dropped silently.
+pub fn warn_on_ignored_next_link(
+    operation: &'static str,
+    continuation: Option<&str>,
+    bytes: &[u8],
+) {
+    if continuation.is_some() {
+        log::warn!(
+            "{} returned a continuation header for a next page, which this operation does not follow; only the first page of results is returned",
+            operation
+        );
+        return;
+    }
+    #[derive(serde::Deserialize)]
+    struct NextLink {
+        #[serde(rename = "nextLink", alias = "@odata.nextLink")]
+        next_link: Option<String>,
+    }
+    if let Ok(NextLink {
+        next_link: Some(next_link),
+    }) = crate::from_json(bytes)
+    {
+        if !next_link.is_empty() {
+            log::warn!(
+                "{} returned a link to a next page, which this operation does not follow; only the first page of results is returned",
+                operation
+            );
+        }
+    }
+}
+
+/// Deserialize the response body of `operation`, failing with a clear error instead of a
+/// cryptic end-of-input one when the service answered `status`, such as `204 No Content`,
+/// without a body.
+///
+/// Other failures are wrapped in a [`DeserializationError`] naming the operation, which tells a
+/// body that does not match the models apart from one that is not JSON.
+pub fn from_json_body<T>(
+    operation: &'static str,
+    status: StatusCode,
+    bytes: &[u8],
+) -> crate::Result<T>
+where
+    T: serde::de::DeserializeOwned,
+{
+    if bytes.iter().all(u8::is_ascii_whitespace) {
+        return Err(Error::with_message(ErrorKind::DataConversion, || {
+            format!(
+                "expected a response body from {} but the service returned {} without one",
+                operation, status
+            )
+        }));
+    }
+    crate::from_json(bytes).map_err(|error| {
+        Error::new(
+            ErrorKind::DataConversion,
+            DeserializationError::new(operation, bytes, error),
+        )
+    })
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn rate_limit_remaining_is_the_smallest_quota() {
+        let mut headers = Headers::new();
+        assert_eq!(rate_limit_remaining(&headers), None);
+        headers.insert("x-ms-ratelimit-remaining-subscription-reads", "11999");
+        headers.insert("x-ms-ratelimit-remaining-tenant-reads", " 42 ");
+        headers.insert("x-ms-request-id", "7");
+        assert_eq!(rate_limit_remaining(&headers), Some(42));
+    }
+
+    #[test]
+    fn from_json_body_names_an_empty_body() {
+        let err = from_json_body::<serde_json::Value>("dsc_node::get", StatusCode::NoContent, b" ")
+            .unwrap_err();
+        assert_eq!(err.kind(), &ErrorKind::DataConversion);
+        assert!(err.to_string().contains("dsc_node::get"));
+        let value: serde_json::Value =
+            from_json_body("dsc_node::get", StatusCode::Ok, br#"{"id":"x"}"#).unwrap();
+        assert_eq!(value["id"], "x");
+    }
+
+    #[test]
+    fn not_found_is_none() {
+        let not_found: crate::Result<()> = Err(Error::message(
+            ErrorKind::HttpResponse {
+                status: StatusCode::NotFound,
+                error_code: None,
+            },
+            "missing",
+        ));
+        assert!(not_found_as_none(not_found).unwrap().is_none());
+        assert_eq!(not_found_as_none(Ok(1)).unwrap(), Some(1));
+    }
+}
@@ -0,0 +1,83 @@
+use crate::error::Error;
+use crate::{Continuable, Pageable};
+use futures::stream::BoxStream;
+use futures::StreamExt;
+use std::future::Future;
+
+/// Pair the items of each page of a `Pageable` with the continuation token of the page that
+/// follows it.
+pub fn checkpoint_stream<P, T>(
+    pages: Pageable<P, Error>,
+    items: fn(P) -> Vec<T>,
+) -> BoxStream<'static, crate::Result<(Vec<T>, Option<String>)>>
+where
+    P: Continuable<Continuation = String> + Send + 'static,
+    T: Send + 'static,
+{
+    pages
+        .map(move |page| {
+            page.map(|page| {
+                let continuation = page.continuation();
+                (items(page), continuation)
+            })
+        })
+        .boxed()
+}
+
+/// Flatten a `Pageable` into its items. The stream is lazy, so once `max_items` items have been
+/// yielded no further page is requested.
+pub fn item_stream<P, T>(
+    pages: Pageable<P, Error>,
+    max_items: Option<usize>,
+    items: fn(P) -> Vec<T>,
+) -> BoxStream<'static, crate::Result<T>>
+where
+    P: Send + 'static,
+    T: Send + 'static,
+{
+    let items = pages.flat_map(move |page| {
+        futures::stream::iter(match page {
+            Ok(page) => items(page).into_iter().map(Ok).collect(),
+            Err(err) => vec![Err(err)],
+        })
+    });
+    match max_items {
+        Some(max_items) => items.take(max_items).boxed(),
+        None => items.boxed(),
+    }
+}
+
+/// Run `f` on every item with at most `limit` calls in flight, returning each item with its
+/// result in the order of `items`.
+///
+/// The shape of inventory tooling, such as fetching something for each of many resources,
+/// without flooding the service or stopping at the first failure: every item gets its own
+/// result. Share a [`RetryBudget`](crate::RetryBudget) between the calls (see
+/// `ClientBuilder::retry_budget`) to keep throttling from multiplying retries.
+///
+/// ```no_run
+/// # async fn example() {
+/// use azure_core::client::map_concurrent;
+/// # async fn get_node(node_id: &str) -> azure_core::Result<String> { Ok(node_id.to_owned()) }
+/// let nodes = map_concurrent(["node-1", "node-2"], 8, |node_id| get_node(node_id)).await;
+/// # }
+/// ```
+pub async fn map_concurrent<I, T, F, Fut>(
+    items: impl IntoIterator<Item = I>,
+    limit: usize,
+    f: F,
+) -> Vec<(I, crate::Result<T>)>
+where
+    I: Clone,
+    F: Fn(I) -> Fut,
+    Fut: Future<Output = crate::Result<T>>,
+{
+    futures::stream::iter(items)
+        .map(|item| {
+            let result = f(item.clone());
+            async move { (item, result.await) }
+        })
+        .buffered(limit.max(1))
+        .collect()
+        .await
+}
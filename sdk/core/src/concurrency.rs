@@ -0,0 +1,130 @@
+//! Optimistic concurrency with ETags.
+
+use crate::error::{Error, ErrorKind};
+use crate::headers::{self, Headers};
+use crate::StatusCode;
+
+/// The ETag of a resource as it was read, for making a later change to it conditional on it
+/// being unchanged.
+///
+/// Take it from the `concurrency_token` of a `get` response and pass it to `if_match` of the
+/// group's `update` or `create_or_update`. If another client changed the resource in between,
+/// the change fails with a [`PreconditionFailed`] error instead of overwriting theirs.
+#[derive(Clone, Debug, PartialEq, Eq, Hash)]
+pub struct ConcurrencyToken(String);
+
+impl ConcurrencyToken {
+    pub fn new(etag: impl Into<String>) -> Self {
+        Self(etag.into())
+    }
+
+    /// Read the token from the `ETag` header of a response, if it has one.
+    pub fn from_headers(headers: &Headers) -> Option<Self> {
+        headers.get_optional_string(&headers::ETAG).map(Self)
+    }
+
+    pub fn etag(&self) -> &str {
+        &self.0
+    }
+
+    /// Whether `etag` names the same version of the resource, comparing weakly so that a `W/`
+    /// prefix added by a proxy on either side is ignored.
+    pub fn matches(&self, etag: &str) -> bool {
+        fn opaque(etag: &str) -> &str {
+            etag.strip_prefix("W/").unwrap_or(etag)
+        }
+        opaque(&self.0) == opaque(etag)
+    }
+}
+
+impl std::fmt::Display for ConcurrencyToken {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.write_str(&self.0)
+    }
+}
+
+/// The error a change sent with `if_match` fails with when the resource no longer has that
+/// ETag.
+///
+/// Reach it with `downcast_ref::<PreconditionFailed>()` on the [`Error`], whose kind stays
+/// `HttpResponse` with status `412 Precondition Failed`.
+#[derive(Debug)]
+pub struct PreconditionFailed {
+    current: Option<ConcurrencyToken>,
+    error: Error,
+}
+
+impl PreconditionFailed {
+    /// The resource's current ETag, if the service sent it with the `412` response. Without it,
+    /// `get` the resource again to find out.
+    pub fn current(&self) -> Option<&ConcurrencyToken> {
+        self.current.as_ref()
+    }
+}
+
+impl std::fmt::Display for PreconditionFailed {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match &self.current {
+            Some(current) => write!(
+                f,
+                "the resource was changed since it was read; its ETag is now {}: {}",
+                current, self.error
+            ),
+            None => write!(
+                f,
+                "the resource was changed since it was read: {}",
+                self.error
+            ),
+        }
+    }
+}
+
+impl std::error::Error for PreconditionFailed {
+    fn source(&self) -> Option<&(dyn std::error::Error + 'static)> {
+        Some(&self.error)
+    }
+}
+
+/// Wrap a `412 Precondition Failed` error in [`PreconditionFailed`], leaving other errors as
+/// they are.
+pub fn precondition_failed(err: Error) -> Error {
+    match err.kind() {
+        ErrorKind::HttpResponse {
+            status: StatusCode::PreconditionFailed,
+            ..
+        } => {
+            let kind = err.kind().clone();
+            let current = err
+                .as_http_error()
+                .and_then(|e| e.etag())
+                .map(ConcurrencyToken::new);
+            Error::new(
+                kind,
+                PreconditionFailed {
+                    current,
+                    error: err,
+                },
+            )
+        }
+        _ => err,
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn matches_ignores_weak_prefix() {
+        let token = ConcurrencyToken::new("W/\"1\"");
+        assert!(token.matches("\"1\""));
+        assert!(token.matches("W/\"1\""));
+        assert!(!token.matches("\"2\""));
+    }
+
+    #[test]
+    fn other_errors_are_kept() {
+        let err = precondition_failed(Error::message(ErrorKind::Other, "boom"));
+        assert!(err.downcast_ref::<PreconditionFailed>().is_none());
+    }
+}
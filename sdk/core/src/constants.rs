@@ -36,4 +36,6 @@ pub mod content_type {
 /// Constants related to query parameters
 pub mod query_param {
     pub const API_VERSION: &str = "api-version";
+    pub const SKIP: &str = "$skip";
+    pub const SKIP_TOKEN: &str = "$skiptoken";
 }
@@ -2,6 +2,13 @@ use std::any::{Any, TypeId};
 use std::collections::HashMap;
 use std::sync::Arc;
 
+/// The name of the client operation a request is sent for, such as `"dsc_node::get"`.
+///
+/// Generated clients insert it into the [`Context`] of every request so policies can label
+/// what they observe by operation.
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash)]
+pub struct OperationName(pub &'static str);
+
 /// Pipeline execution context.
 #[derive(Clone, Debug)]
 pub struct Context {
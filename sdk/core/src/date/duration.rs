@@ -0,0 +1,49 @@
+//! Serde support for ISO 8601 durations, parsed with [`parse_duration`] and written with
+//! [`to_duration`].
+
+use serde::{self, Deserialize, Deserializer, Serializer};
+use std::time::Duration;
+
+use crate::date::*;
+use serde::de;
+
+pub fn deserialize<'de, D>(deserializer: D) -> Result<Duration, D::Error>
+where
+    D: Deserializer<'de>,
+{
+    let s = String::deserialize(deserializer)?;
+    parse_duration(&s).map_err(de::Error::custom)
+}
+
+pub fn serialize<S>(duration: &Duration, serializer: S) -> Result<S::Ok, S::Error>
+where
+    S: Serializer,
+{
+    serializer.serialize_str(&to_duration(duration))
+}
+
+pub mod option {
+    use crate::date::*;
+    use serde::{Deserialize, Deserializer, Serializer};
+    use std::time::Duration;
+
+    pub fn deserialize<'de, D>(deserializer: D) -> Result<Option<Duration>, D::Error>
+    where
+        D: Deserializer<'de>,
+    {
+        let s: Option<String> = Option::deserialize(deserializer)?;
+        s.map(|s| parse_duration(&s).map_err(serde::de::Error::custom))
+            .transpose()
+    }
+
+    pub fn serialize<S>(duration: &Option<Duration>, serializer: S) -> Result<S::Ok, S::Error>
+    where
+        S: Serializer,
+    {
+        if let Some(duration) = duration {
+            serializer.serialize_str(&to_duration(duration))
+        } else {
+            serializer.serialize_none()
+        }
+    }
+}
@@ -14,6 +14,8 @@ use time::{
 // Serde modules
 pub use time::serde::rfc3339;
 pub use time::serde::timestamp;
+pub mod duration;
+pub mod iso8601;
 pub mod rfc1123;
 
 /// RFC 3339: Date and Time on the Internet: Timestamps
@@ -41,6 +43,113 @@ pub fn to_rfc3339(date: &OffsetDateTime) -> String {
     date.format(&Rfc3339).unwrap()
 }
 
+/// ISO 8601 date and time, as services actually send it.
+///
+/// Accepts everything [`parse_rfc3339`] does, and in addition:
+///
+/// * more than nine fractional second digits, which are truncated to nanoseconds
+/// * no offset, which is taken to be UTC
+/// * a space instead of `T` between the date and the time, and lowercase `t` and `z`
+///
+/// 2017-03-24T19:07:58.0000000
+pub fn parse_iso8601(s: &str) -> crate::Result<OffsetDateTime> {
+    if let Ok(date) = OffsetDateTime::parse(s, &Rfc3339) {
+        return Ok(date);
+    }
+    OffsetDateTime::parse(&normalize_iso8601(s), &Rfc3339)
+        .with_context(ErrorKind::DataConversion, || {
+            format!("unable to parse iso8601 date '{s}")
+        })
+}
+
+fn normalize_iso8601(s: &str) -> String {
+    let s = s.trim();
+    let (date, time) = match s.find(['T', 't', ' ']) {
+        Some(index) => (&s[..index], &s[index + 1..]),
+        None => return s.to_owned(),
+    };
+    let offset_start = time.find(['Z', 'z', '+', '-']).unwrap_or(time.len());
+    let (time, offset) = time.split_at(offset_start);
+    let time = match time.split_once('.') {
+        Some((whole, fraction)) if fraction.len() > 9 => format!("{whole}.{}", &fraction[..9]),
+        _ => time.to_owned(),
+    };
+    let offset = match offset {
+        "" | "z" => "Z",
+        offset => offset,
+    };
+    format!("{date}T{time}{offset}")
+}
+
+/// ISO 8601 duration, such as `PT1H30M` or `P1DT12H`.
+///
+/// In Azure REST API specifications it is specified as `"format": "duration"`.
+///
+/// Years and months have no fixed length and are rejected. Weeks are seven days.
+pub fn parse_duration(s: &str) -> crate::Result<Duration> {
+    let error = || {
+        crate::Error::with_message(ErrorKind::DataConversion, || {
+            format!("unable to parse iso8601 duration '{s}")
+        })
+    };
+    let rest = s.strip_prefix('P').ok_or_else(error)?;
+    let (date, time) = rest.split_once('T').unwrap_or((rest, ""));
+    if date.is_empty() && time.is_empty() {
+        return Err(error());
+    }
+    let mut seconds = 0f64;
+    for (part, units) in [
+        (date, &[('W', 604_800.0), ('D', 86_400.0)][..]),
+        (time, &[('H', 3_600.0), ('M', 60.0), ('S', 1.0)][..]),
+    ] {
+        let mut number = String::new();
+        for c in part.chars() {
+            if c.is_ascii_digit() || c == '.' || c == ',' {
+                number.push(if c == ',' { '.' } else { c });
+                continue;
+            }
+            let unit = units
+                .iter()
+                .find(|(designator, _)| *designator == c)
+                .ok_or_else(error)?;
+            let value: f64 = number.parse().map_err(|_| error())?;
+            seconds += value * unit.1;
+            number.clear();
+        }
+        if !number.is_empty() {
+            return Err(error());
+        }
+    }
+    // `Duration::MAX` rounds up as an `f64`, so a duration equal to it is out of range too.
+    if !(seconds.is_finite() && seconds < Duration::MAX.as_secs_f64()) {
+        return Err(error());
+    }
+    Ok(Duration::from_secs_f64(seconds))
+}
+
+/// ISO 8601 duration in the form `PT[n]H[n]M[n]S`.
+///
+/// PT1H30M
+pub fn to_duration(duration: &Duration) -> String {
+    let total = duration.as_secs();
+    let (hours, minutes, seconds) = (total / 3_600, total / 60 % 60, total % 60);
+    let nanos = duration.subsec_nanos();
+    let mut s = String::from("PT");
+    if hours > 0 {
+        s.push_str(&format!("{hours}H"));
+    }
+    if minutes > 0 {
+        s.push_str(&format!("{minutes}M"));
+    }
+    if nanos > 0 {
+        let fraction = format!("{nanos:09}");
+        s.push_str(&format!("{seconds}.{}S", fraction.trim_end_matches('0')));
+    } else if seconds > 0 || s == "PT" {
+        s.push_str(&format!("{seconds}S"));
+    }
+    s
+}
+
 /// RFC 1123: Requirements for Internet Hosts - Application and Support
 ///
 /// <https://www.rfc-editor.org/rfc/rfc1123>
@@ -236,4 +345,79 @@ mod tests {
 
         Ok(())
     }
+
+    #[test]
+    fn test_parse_iso8601_known_formats() -> crate::Result<()> {
+        let expected = datetime!(2017-03-24 19:07:58.123456789 UTC);
+        for s in [
+            "2017-03-24T19:07:58.123456789Z",
+            "2017-03-24T19:07:58.1234567891234Z",
+            "2017-03-24T19:07:58.123456789",
+            "2017-03-24 19:07:58.123456789z",
+            "2017-03-24t19:07:58.123456789+00:00",
+        ] {
+            assert_eq!(parse_iso8601(s)?, expected, "{s}");
+        }
+        assert_eq!(
+            parse_iso8601("2017-03-24T19:07:58.0000000")?,
+            datetime!(2017-03-24 19:07:58 UTC)
+        );
+        assert_eq!(
+            parse_iso8601("2017-03-24T19:07:58.12345678901-07:00")?,
+            datetime!(2017-03-24 19:07:58.123456789 -7)
+        );
+        assert!(parse_iso8601("2017-03-24").is_err());
+        Ok(())
+    }
+
+    #[test]
+    fn test_parse_duration() -> crate::Result<()> {
+        assert_eq!(parse_duration("PT2H")?, Duration::from_secs(7_200));
+        assert_eq!(parse_duration("PT1H30M")?, Duration::from_secs(5_400));
+        assert_eq!(parse_duration("P1DT12H")?, Duration::from_secs(129_600));
+        assert_eq!(parse_duration("P1W")?, Duration::from_secs(604_800));
+        assert_eq!(parse_duration("PT0.5S")?, Duration::from_millis(500));
+        for s in ["", "P", "PT", "P1M", "PT1", "1H", "PT1X"] {
+            assert!(parse_duration(s).is_err(), "{s}");
+        }
+        let huge = format!("PT{}S", "9".repeat(400));
+        for s in ["P99999999999999999999W", "PT18446744073709551616S", &huge] {
+            let error = parse_duration(s).unwrap_err();
+            assert_eq!(error.kind(), &ErrorKind::DataConversion, "{s}");
+        }
+        Ok(())
+    }
+
+    #[test]
+    fn test_to_duration() -> crate::Result<()> {
+        for s in ["PT0S", "PT2H", "PT1H30M", "PT36H0.25S", "PT59S"] {
+            assert_eq!(to_duration(&parse_duration(s)?), s);
+        }
+        Ok(())
+    }
+
+    #[derive(Serialize, Deserialize)]
+    struct ExampleReport {
+        #[serde(default, with = "crate::date::iso8601::option")]
+        start_time: Option<time::OffsetDateTime>,
+        #[serde(default, with = "crate::date::duration::option")]
+        duration: Option<Duration>,
+    }
+
+    #[test]
+    fn test_serde_iso8601_and_duration() -> crate::Result<()> {
+        let report: ExampleReport = serde_json::from_str(
+            r#"{"start_time": "2017-03-24T19:07:58.0000000", "duration": "PT1H"}"#,
+        )?;
+        assert_eq!(report.start_time, Some(datetime!(2017-03-24 19:07:58 UTC)));
+        assert_eq!(report.duration, Some(Duration::from_secs(3_600)));
+        assert_eq!(
+            serde_json::to_string(&report)?,
+            r#"{"start_time":"2017-03-24T19:07:58Z","duration":"PT1H"}"#
+        );
+        let report: ExampleReport = serde_json::from_str("{}")?;
+        assert_eq!(report.start_time, None);
+        assert_eq!(report.duration, None);
+        Ok(())
+    }
 }
@@ -0,0 +1,132 @@
+use super::Error;
+use std::borrow::Cow;
+
+/// The number of bytes of the body kept on either side of the position deserialization failed at
+const SNIPPET_RADIUS: usize = 40;
+
+/// A response body that could not be deserialized into the type an operation returns
+///
+/// Distinguishes a body that is not JSON at all from JSON whose shape does not match the expected
+/// type, which usually means the service has changed since the models were generated.
+#[derive(Debug)]
+pub struct DeserializationError {
+    operation: Cow<'static, str>,
+    shape_mismatch: bool,
+    snippet: String,
+    error: Error,
+}
+
+impl DeserializationError {
+    /// Create an error for the `body` that `operation` failed to deserialize with `error`.
+    pub fn new<O>(operation: O, body: &[u8], error: Error) -> Self
+    where
+        O: Into<Cow<'static, str>>,
+    {
+        let shape_mismatch = serde_json::from_slice::<serde::de::IgnoredAny>(body).is_ok();
+        let offset = error
+            .downcast_ref::<serde_json::Error>()
+            .map_or(0, |error| offset(body, error.line(), error.column()));
+        let start = offset.saturating_sub(SNIPPET_RADIUS);
+        let end = body.len().min(offset + SNIPPET_RADIUS);
+        Self {
+            operation: operation.into(),
+            shape_mismatch,
+            snippet: String::from_utf8_lossy(&body[start..end]).into_owned(),
+            error,
+        }
+    }
+
+    /// Get the name of the operation whose response could not be deserialized.
+    pub fn operation(&self) -> &str {
+        &self.operation
+    }
+
+    /// Whether the body is valid JSON that does not match the expected type, as opposed to not being JSON at all.
+    pub fn is_shape_mismatch(&self) -> bool {
+        self.shape_mismatch
+    }
+
+    /// Get the part of the body around the position deserialization failed at, truncated to a few dozen bytes.
+    ///
+    /// The snippet is response data and may hold secrets, such as variable values, so it is not part of the
+    /// `Display` output and has to be logged explicitly.
+    pub fn snippet(&self) -> &str {
+        &self.snippet
+    }
+}
+
+impl std::fmt::Display for DeserializationError {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        let reason = if self.shape_mismatch {
+            "the body does not match the expected type"
+        } else {
+            "the body is not valid JSON"
+        };
+        write!(
+            f,
+            "failed to deserialize the response of {}: {}: {}",
+            self.operation, reason, self.error
+        )
+    }
+}
+
+impl std::error::Error for DeserializationError {
+    fn source(&self) -> Option<&(dyn std::error::Error + 'static)> {
+        Some(&self.error)
+    }
+}
+
+/// The byte offset of the 1-based `line` and `column` in `body`.
+fn offset(body: &[u8], line: usize, column: usize) -> usize {
+    let line_start: usize = body
+        .split(|b| *b == b'\n')
+        .take(line.saturating_sub(1))
+        .map(|line| line.len() + 1)
+        .sum();
+    body.len().min(line_start + column.saturating_sub(1))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use serde::Deserialize;
+
+    #[derive(Debug, Deserialize)]
+    #[allow(dead_code)]
+    struct Node {
+        name: String,
+    }
+
+    fn deserialization_error(body: &[u8]) -> DeserializationError {
+        let error = crate::from_json::<_, Node>(body).unwrap_err();
+        DeserializationError::new("dsc_node::get", body, error)
+    }
+
+    #[test]
+    fn shape_mismatch() {
+        let error = deserialization_error(b"{\n  \"id\": \"a\",\n  \"name\": 42\n}");
+        assert!(error.is_shape_mismatch());
+        assert_eq!(error.operation(), "dsc_node::get");
+        assert!(error.snippet().contains("\"name\": 42"));
+        assert!(error
+            .to_string()
+            .starts_with("failed to deserialize the response of dsc_node::get: the body does not match the expected type"));
+    }
+
+    #[test]
+    fn invalid_json() {
+        let error = deserialization_error(b"<html>Service Unavailable</html>");
+        assert!(!error.is_shape_mismatch());
+        assert!(error.snippet().starts_with("<html>"));
+        assert!(!error.to_string().contains("Service Unavailable"));
+    }
+
+    #[cfg(not(feature = "simd-json"))]
+    #[test]
+    fn snippet_is_truncated() {
+        let body = format!("{{\"padding\": \"{}\", \"name\": 42}}", "x".repeat(500));
+        let error = deserialization_error(body.as_bytes());
+        assert!(error.snippet().len() <= 2 * SNIPPET_RADIUS);
+        assert!(error.snippet().contains("42"));
+    }
+}
@@ -50,6 +50,50 @@ impl HttpError {
     pub fn error_message(&self) -> Option<&str> {
         self.details.message.as_deref()
     }
+
+    /// Get the `x-ms-request-id` the service assigned to the failed request, if any.
+    pub fn request_id(&self) -> Option<&str> {
+        self.headers
+            .get(headers::REQUEST_ID.as_str())
+            .map(String::as_str)
+    }
+
+    /// Get the `x-ms-correlation-request-id` of the failed request, if any.
+    pub fn correlation_request_id(&self) -> Option<&str> {
+        self.headers
+            .get(headers::CORRELATION_REQUEST_ID.as_str())
+            .map(String::as_str)
+    }
+
+    /// Get the `ETag` of the resource as the service holds it, if the failed response carried one.
+    pub fn etag(&self) -> Option<&str> {
+        self.headers.get(headers::ETAG.as_str()).map(String::as_str)
+    }
+
+    /// Get the `WWW-Authenticate` challenge of a `401 Unauthorized` response, if any.
+    pub fn www_authenticate(&self) -> Option<&str> {
+        self.headers
+            .get(headers::WWW_AUTHENTICATE.as_str())
+            .map(String::as_str)
+    }
+
+    /// Get how long the service asked the client to wait before retrying, from the `Retry-After`
+    /// header of the failed response, if any.
+    pub fn retry_after(&self) -> Option<std::time::Duration> {
+        headers::parse_retry_after(
+            self.headers.get(headers::RETRY_AFTER.as_str())?,
+            self.headers.get(headers::DATE.as_str()).map(String::as_str),
+        )
+    }
+
+    /// Get all the headers of the failed response.
+    pub fn headers(&self) -> headers::Headers {
+        let mut headers = headers::Headers::new();
+        for (name, value) in &self.headers {
+            headers.insert(name.clone(), value.clone());
+        }
+        headers
+    }
 }
 
 impl std::fmt::Display for HttpError {
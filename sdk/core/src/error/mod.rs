@@ -1,8 +1,10 @@
 use crate::StatusCode;
 use std::borrow::Cow;
 use std::fmt::{Debug, Display};
+mod deserialization_error;
 mod http_error;
 mod macros;
+pub use deserialization_error::DeserializationError;
 pub use http_error::HttpError;
 
 /// A convenience alias for `Result` where the error type is hard coded to `Error`
@@ -0,0 +1,423 @@
+//! Recording of HTTP exchanges as an HTTP Archive.
+//!
+//! [`HarRecorder`] collects every request/response pair that passes through a pipeline and writes
+//! them as a [HAR 1.2](http://www.softwareishard.com/blog/har-12-spec/) document, which browser
+//! developer tools and most HTTP debugging tools can open. Unlike the line-based recordings of the
+//! `record` feature, an archive is a single JSON document, so it is written once, by
+//! [`HarRecorder::finish`].
+//!
+//! The values of the `Authorization` and `Proxy-Authorization` headers are replaced with
+//! `REDACTED`. Bodies are recorded verbatim and may hold secrets, such as credential passwords.
+
+use crate::date;
+use crate::error::{ErrorKind, ResultExt};
+use crate::headers::{self, Headers};
+use crate::policies::{Policy, PolicyResult};
+use crate::{Body, Context, Request, Response};
+use serde::Serialize;
+use std::io::Write;
+use std::sync::{Arc, Mutex, PoisonError};
+use time::OffsetDateTime;
+
+const REDACTED: &str = "REDACTED";
+
+#[derive(Serialize)]
+#[serde(rename_all = "camelCase")]
+struct Archive<'a> {
+    log: Log<'a>,
+}
+
+#[derive(Serialize)]
+#[serde(rename_all = "camelCase")]
+struct Log<'a> {
+    version: &'static str,
+    creator: Creator,
+    entries: &'a [Entry],
+}
+
+#[derive(Serialize)]
+#[serde(rename_all = "camelCase")]
+struct Creator {
+    name: &'static str,
+    version: &'static str,
+}
+
+#[derive(Serialize)]
+#[serde(rename_all = "camelCase")]
+struct Entry {
+    started_date_time: String,
+    time: f64,
+    request: HarRequest,
+    response: HarResponse,
+    cache: serde_json::Map<String, serde_json::Value>,
+    timings: Timings,
+}
+
+#[derive(Serialize)]
+#[serde(rename_all = "camelCase")]
+struct HarRequest {
+    method: String,
+    url: String,
+    http_version: &'static str,
+    cookies: Vec<NameValue>,
+    headers: Vec<NameValue>,
+    query_string: Vec<NameValue>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    post_data: Option<PostData>,
+    headers_size: i64,
+    body_size: i64,
+}
+
+#[derive(Serialize)]
+#[serde(rename_all = "camelCase")]
+struct HarResponse {
+    status: u16,
+    status_text: String,
+    http_version: &'static str,
+    cookies: Vec<NameValue>,
+    headers: Vec<NameValue>,
+    content: Content,
+    #[serde(rename = "redirectURL")]
+    redirect_url: String,
+    headers_size: i64,
+    body_size: i64,
+}
+
+#[derive(Serialize)]
+#[serde(rename_all = "camelCase")]
+struct NameValue {
+    name: String,
+    value: String,
+}
+
+#[derive(Serialize)]
+#[serde(rename_all = "camelCase")]
+struct PostData {
+    mime_type: String,
+    text: String,
+}
+
+#[derive(Serialize)]
+#[serde(rename_all = "camelCase")]
+struct Content {
+    size: i64,
+    mime_type: String,
+    text: String,
+}
+
+#[derive(Serialize)]
+#[serde(rename_all = "camelCase")]
+struct Timings {
+    send: f64,
+    wait: f64,
+    receive: f64,
+}
+
+fn to_name_values(headers: &Headers) -> Vec<NameValue> {
+    let mut headers: Vec<NameValue> = headers
+        .iter()
+        .map(|(name, value)| {
+            let redact = *name == headers::AUTHORIZATION || name.as_str() == "proxy-authorization";
+            NameValue {
+                name: name.as_str().to_owned(),
+                value: if redact {
+                    REDACTED.to_owned()
+                } else {
+                    value.as_str().to_owned()
+                },
+            }
+        })
+        .collect();
+    headers.sort_by(|a, b| a.name.cmp(&b.name));
+    headers
+}
+
+fn mime_type(headers: &Headers) -> String {
+    headers
+        .get_optional_string(&headers::CONTENT_TYPE)
+        .unwrap_or_default()
+}
+
+struct State {
+    sink: Box<dyn Write + Send>,
+    entries: Vec<Entry>,
+    finished: bool,
+}
+
+impl State {
+    fn finish(&mut self) -> crate::Result<()> {
+        if self.finished {
+            return Ok(());
+        }
+        self.finished = true;
+        let archive = Archive {
+            log: Log {
+                version: "1.2",
+                creator: Creator {
+                    name: "azure_core",
+                    version: env!("CARGO_PKG_VERSION"),
+                },
+                entries: &self.entries,
+            },
+        };
+        let json = serde_json::to_vec_pretty(&archive)?;
+        self.entries.clear();
+        self.sink
+            .write_all(&json)
+            .and_then(|_| self.sink.flush())
+            .context(ErrorKind::Io, "failed to write HTTP archive")
+    }
+}
+
+impl Drop for State {
+    fn drop(&mut self) {
+        if let Err(error) = self.finish() {
+            log::warn!("failed to write HTTP archive when it was dropped: {error}");
+        }
+    }
+}
+
+/// Records each request/response pair that passes through it, for writing them as an HTTP
+/// Archive with [`finish`](Self::finish).
+///
+/// Install it as the last per-retry policy so it records every attempt exactly as the transport
+/// sends it, and keep a clone to call `finish` on once the traffic of interest has been sent.
+/// Entries are kept in memory until then. If `finish` is never called, the archive is written
+/// when the last clone is dropped, and a write error is only logged. The response body is read in
+/// full before it is handed back to the caller.
+#[derive(Clone)]
+pub struct HarRecorder {
+    state: Arc<Mutex<State>>,
+}
+
+impl HarRecorder {
+    pub fn new(sink: impl Write + Send + 'static) -> Self {
+        Self {
+            state: Arc::new(Mutex::new(State {
+                sink: Box::new(sink),
+                entries: Vec::new(),
+                finished: false,
+            })),
+        }
+    }
+
+    /// The number of exchanges recorded so far.
+    pub fn len(&self) -> usize {
+        self.lock().entries.len()
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.len() == 0
+    }
+
+    /// Write the archive of all recorded exchanges to the sink and flush it.
+    ///
+    /// The archive is written once: exchanges sent afterwards are not recorded, and later calls
+    /// do nothing.
+    pub fn finish(&self) -> crate::Result<()> {
+        self.lock().finish()
+    }
+
+    fn lock(&self) -> std::sync::MutexGuard<'_, State> {
+        self.state.lock().unwrap_or_else(PoisonError::into_inner)
+    }
+}
+
+impl std::fmt::Debug for HarRecorder {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.debug_struct("HarRecorder")
+            .field("entries", &self.len())
+            .finish_non_exhaustive()
+    }
+}
+
+#[cfg_attr(target_arch = "wasm32", async_trait::async_trait(?Send))]
+#[cfg_attr(not(target_arch = "wasm32"), async_trait::async_trait)]
+impl Policy for HarRecorder {
+    async fn send(
+        &self,
+        ctx: &Context,
+        request: &mut Request,
+        next: &[Arc<dyn Policy>],
+    ) -> PolicyResult {
+        let started = OffsetDateTime::now_utc();
+        let response = next[0].send(ctx, request, &next[1..]).await?;
+        let waited = OffsetDateTime::now_utc();
+        let (status, response_headers, body) = response.deconstruct();
+        let body = body.collect().await?;
+        let received = OffsetDateTime::now_utc();
+
+        let request_body = match request.body() {
+            Body::Bytes(bytes) if !bytes.is_empty() => Some(bytes.clone()),
+            _ => None,
+        };
+        let entry = Entry {
+            started_date_time: date::to_rfc3339(&started),
+            time: (received - started).as_seconds_f64() * 1000.0,
+            request: HarRequest {
+                method: request.method().to_string(),
+                url: request.url().to_string(),
+                http_version: "HTTP/1.1",
+                cookies: Vec::new(),
+                headers: to_name_values(request.headers()),
+                query_string: request
+                    .url()
+                    .query_pairs()
+                    .map(|(name, value)| NameValue {
+                        name: name.into_owned(),
+                        value: value.into_owned(),
+                    })
+                    .collect(),
+                body_size: request_body.as_ref().map_or(0, |body| body.len() as i64),
+                post_data: request_body.map(|body| PostData {
+                    mime_type: mime_type(request.headers()),
+                    text: String::from_utf8_lossy(&body).into_owned(),
+                }),
+                headers_size: -1,
+            },
+            response: HarResponse {
+                status: status as u16,
+                status_text: status.canonical_reason().to_owned(),
+                http_version: "HTTP/1.1",
+                cookies: Vec::new(),
+                headers: to_name_values(&response_headers),
+                content: Content {
+                    size: body.len() as i64,
+                    mime_type: mime_type(&response_headers),
+                    text: String::from_utf8_lossy(&body).into_owned(),
+                },
+                redirect_url: response_headers
+                    .get_optional_string(&headers::LOCATION)
+                    .unwrap_or_default(),
+                headers_size: -1,
+                body_size: body.len() as i64,
+            },
+            cache: serde_json::Map::new(),
+            timings: Timings {
+                send: 0.0,
+                wait: (waited - started).as_seconds_f64() * 1000.0,
+                receive: (received - waited).as_seconds_f64() * 1000.0,
+            },
+        };
+        {
+            let mut state = self.lock();
+            if !state.finished {
+                state.entries.push(entry);
+            }
+        }
+
+        Ok(Response::new(
+            status,
+            response_headers,
+            Box::pin(futures::stream::once(async move { Ok(body) })),
+        ))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{Method, StatusCode};
+    use bytes::Bytes;
+
+    #[derive(Debug)]
+    struct JsonTransport;
+
+    #[async_trait::async_trait]
+    impl Policy for JsonTransport {
+        async fn send(
+            &self,
+            _ctx: &Context,
+            _request: &mut Request,
+            _next: &[Arc<dyn Policy>],
+        ) -> PolicyResult {
+            let mut headers = Headers::new();
+            headers.insert(headers::CONTENT_TYPE, "application/json");
+            Ok(Response::new(
+                StatusCode::Ok,
+                headers,
+                Box::pin(futures::stream::once(async {
+                    Ok(Bytes::from_static(br#"{"name":"a"}"#))
+                })),
+            ))
+        }
+    }
+
+    #[derive(Clone, Default)]
+    struct SharedSink(Arc<Mutex<Vec<u8>>>);
+
+    impl Write for SharedSink {
+        fn write(&mut self, buf: &[u8]) -> std::io::Result<usize> {
+            self.0.lock().unwrap().write(buf)
+        }
+
+        fn flush(&mut self) -> std::io::Result<()> {
+            Ok(())
+        }
+    }
+
+    fn send(recorder: &HarRecorder) {
+        let mut request = Request::new(
+            "https://management.azure.com/accounts/a?api-version=1"
+                .parse()
+                .unwrap(),
+            Method::Put,
+        );
+        request.insert_header(headers::AUTHORIZATION, "Bearer secret");
+        request.insert_header(headers::CONTENT_TYPE, "application/json");
+        request.set_body(Bytes::from_static(br#"{"location":"westus"}"#));
+        let next: Vec<Arc<dyn Policy>> = vec![Arc::new(JsonTransport)];
+        let body = futures::executor::block_on(async {
+            let response = recorder
+                .send(&Context::new(), &mut request, &next)
+                .await
+                .unwrap();
+            response.into_body().collect().await.unwrap()
+        });
+        assert_eq!(body, Bytes::from_static(br#"{"name":"a"}"#));
+    }
+
+    #[test]
+    fn finish_writes_an_archive_once() {
+        let sink = SharedSink::default();
+        let recorder = HarRecorder::new(sink.clone());
+        send(&recorder);
+        assert_eq!(recorder.len(), 1);
+        assert!(sink.0.lock().unwrap().is_empty());
+        recorder.finish().unwrap();
+        send(&recorder);
+        recorder.finish().unwrap();
+
+        let archive: serde_json::Value = serde_json::from_slice(&sink.0.lock().unwrap()).unwrap();
+        let entries = archive["log"]["entries"].as_array().unwrap();
+        assert_eq!(archive["log"]["version"], "1.2");
+        assert_eq!(entries.len(), 1);
+        let entry = &entries[0];
+        assert_eq!(entry["request"]["method"], "PUT");
+        assert_eq!(entry["request"]["queryString"][0]["name"], "api-version");
+        assert_eq!(
+            entry["request"]["postData"]["text"],
+            r#"{"location":"westus"}"#
+        );
+        assert_eq!(entry["response"]["status"], 200);
+        assert_eq!(entry["response"]["content"]["text"], r#"{"name":"a"}"#);
+        assert_eq!(entry["response"]["content"]["mimeType"], "application/json");
+        let authorization = entry["request"]["headers"]
+            .as_array()
+            .unwrap()
+            .iter()
+            .find(|header| header["name"] == "authorization")
+            .unwrap();
+        assert_eq!(authorization["value"], REDACTED);
+    }
+
+    #[test]
+    fn archive_is_written_when_dropped() {
+        let sink = SharedSink::default();
+        let recorder = HarRecorder::new(sink.clone());
+        send(&recorder);
+        drop(recorder);
+        let archive: serde_json::Value = serde_json::from_slice(&sink.0.lock().unwrap()).unwrap();
+        assert_eq!(archive["log"]["entries"].as_array().unwrap().len(), 1);
+    }
+}
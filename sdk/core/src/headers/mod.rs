@@ -264,11 +264,13 @@ impl From<&String> for HeaderValue {
 
 pub const ACCEPT: HeaderName = HeaderName::from_static("accept");
 pub const ACCEPT_ENCODING: HeaderName = HeaderName::from_static("accept-encoding");
+pub const ACCEPT_LANGUAGE: HeaderName = HeaderName::from_static("accept-language");
 pub const ACL: HeaderName = HeaderName::from_static("x-ms-acl");
 pub const ACCOUNT_KIND: HeaderName = HeaderName::from_static("x-ms-account-kind");
 pub const ACTIVITY_ID: HeaderName = HeaderName::from_static("x-ms-activity-id");
 pub const APP: HeaderName = HeaderName::from_static("x-ms-app");
 pub const AUTHORIZATION: HeaderName = HeaderName::from_static("authorization");
+pub const AZURE_ASYNCOPERATION: HeaderName = HeaderName::from_static("azure-asyncoperation");
 pub const APPEND_POSITION: HeaderName = HeaderName::from_static("x-ms-blob-condition-appendpos");
 pub const BLOB_ACCESS_TIER: HeaderName = HeaderName::from_static("x-ms-access-tier");
 pub const BLOB_CONTENT_LENGTH: HeaderName = HeaderName::from_static("x-ms-blob-content-length");
@@ -296,6 +298,8 @@ pub const COPY_SOURCE: HeaderName = HeaderName::from_static("x-ms-copy-source");
 pub const COPY_STATUS: HeaderName = HeaderName::from_static("x-ms-copy-status");
 pub const COPY_STATUS_DESCRIPTION: HeaderName =
     HeaderName::from_static("x-ms-copy-status-description");
+pub const CORRELATION_REQUEST_ID: HeaderName =
+    HeaderName::from_static("x-ms-correlation-request-id");
 pub const CREATION_TIME: HeaderName = HeaderName::from_static("x-ms-creation-time");
 pub const DATE: HeaderName = HeaderName::from_static("date");
 pub const DELETE_SNAPSHOTS: HeaderName = HeaderName::from_static("x-ms-delete-snapshots");
@@ -327,6 +331,8 @@ pub const LEASE_STATUS: HeaderName = HeaderName::from_static("x-ms-lease-status"
 pub const LEASE_TIME: HeaderName = HeaderName::from_static("x-ms-lease-time");
 pub const LINK: HeaderName = HeaderName::from_static("link");
 pub const LOCATION: HeaderName = HeaderName::from_static("location");
+pub const LONG_RUNNING_OPERATION: HeaderName =
+    HeaderName::from_static("x-ms-long-running-operation");
 pub const MAX_ITEM_COUNT: HeaderName = HeaderName::from_static("x-ms-max-item-count");
 pub const META_PREFIX: HeaderName = HeaderName::from_static("x-ms-meta-");
 pub const MS_DATE: HeaderName = HeaderName::from_static("x-ms-date");
@@ -340,6 +346,10 @@ pub const RANGE: HeaderName = HeaderName::from_static("range");
 pub const RANGE_GET_CONTENT_CRC64: HeaderName =
     HeaderName::from_static("x-ms-range-get-content-crc64");
 pub const RANGE_GET_CONTENT_MD5: HeaderName = HeaderName::from_static("x-ms-range-get-content-md5");
+pub const REPEATABILITY_FIRST_SENT: HeaderName =
+    HeaderName::from_static("repeatability-first-sent");
+pub const REPEATABILITY_REQUEST_ID: HeaderName =
+    HeaderName::from_static("repeatability-request-id");
 pub const REQUEST_ID: HeaderName = HeaderName::from_static("x-ms-request-id");
 pub const REQUEST_SERVER_ENCRYPTED: HeaderName =
     HeaderName::from_static("x-ms-request-server-encrypted");
@@ -106,3 +106,30 @@ pub fn content_type_from_headers(headers: &Headers) -> crate::Result<String> {
 pub fn item_count_from_headers(headers: &Headers) -> crate::Result<u32> {
     headers.get_as(&ITEM_COUNT)
 }
+
+/// Get how long the service asked the client to wait from the `Retry-After` header, if any.
+///
+/// See [`parse_retry_after`].
+pub fn retry_after_from_headers_optional(headers: &Headers) -> Option<std::time::Duration> {
+    parse_retry_after(
+        headers.get_optional_str(&RETRY_AFTER)?,
+        headers.get_optional_str(&DATE),
+    )
+}
+
+/// Parse a `Retry-After` value, which is either a number of seconds or an HTTP date.
+///
+/// A date is taken relative to `date`, the `Date` of the response, so that a skewed local clock
+/// does not stretch or shorten the wait, falling back to the current time if the response has no
+/// `Date`. A date in the past is a wait of zero. Returns `None` if the value is neither form.
+pub fn parse_retry_after(retry_after: &str, date: Option<&str>) -> Option<std::time::Duration> {
+    let retry_after = retry_after.trim();
+    if let Ok(seconds) = retry_after.parse::<u64>() {
+        return Some(std::time::Duration::from_secs(seconds));
+    }
+    let retry_at = date::parse_rfc1123(retry_after).ok()?;
+    let now = date
+        .and_then(|date| date::parse_rfc1123(date.trim()).ok())
+        .unwrap_or_else(OffsetDateTime::now_utc);
+    Some((retry_at - now).try_into().unwrap_or_default())
+}
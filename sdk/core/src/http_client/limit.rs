@@ -0,0 +1,167 @@
+use crate::HttpClient;
+use async_trait::async_trait;
+use futures::StreamExt;
+use std::sync::{Arc, Mutex, PoisonError};
+use std::task::{Poll, Waker};
+
+/// An `HttpClient` that lets at most a fixed number of requests be in flight at once
+///
+/// A request counts against the limit from when it is sent until its response body has been read
+/// or dropped, so a caller that holds on to unread bodies holds on to their permits too.
+#[derive(Debug)]
+pub(crate) struct LimitedHttpClient {
+    http_client: Arc<dyn HttpClient>,
+    semaphore: Arc<Semaphore>,
+}
+
+impl LimitedHttpClient {
+    pub(crate) fn new(http_client: Arc<dyn HttpClient>, max_concurrent_requests: usize) -> Self {
+        Self {
+            http_client,
+            semaphore: Arc::new(Semaphore::new(max_concurrent_requests.max(1))),
+        }
+    }
+}
+
+#[cfg_attr(target_arch = "wasm32", async_trait(?Send))]
+#[cfg_attr(not(target_arch = "wasm32"), async_trait)]
+impl HttpClient for LimitedHttpClient {
+    async fn execute_request(&self, request: &crate::Request) -> crate::Result<crate::Response> {
+        let permit = self.semaphore.acquire().await;
+        let (status, headers, body) = self
+            .http_client
+            .execute_request(request)
+            .await?
+            .deconstruct();
+        let body = body.map(move |chunk| {
+            let _permit = &permit;
+            chunk
+        });
+        Ok(crate::Response::new(status, headers, Box::pin(body)))
+    }
+}
+
+#[derive(Debug)]
+struct Semaphore {
+    state: Mutex<SemaphoreState>,
+}
+
+#[derive(Debug)]
+struct SemaphoreState {
+    permits: usize,
+    waiters: Vec<Waker>,
+}
+
+impl Semaphore {
+    fn new(permits: usize) -> Self {
+        Self {
+            state: Mutex::new(SemaphoreState {
+                permits,
+                waiters: Vec::new(),
+            }),
+        }
+    }
+
+    async fn acquire(self: &Arc<Self>) -> Permit {
+        futures::future::poll_fn(|cx| {
+            let mut state = self.state.lock().unwrap_or_else(PoisonError::into_inner);
+            if state.permits > 0 {
+                state.permits -= 1;
+                Poll::Ready(Permit(self.clone()))
+            } else {
+                state.waiters.push(cx.waker().clone());
+                Poll::Pending
+            }
+        })
+        .await
+    }
+}
+
+/// A permit to send one request, returned to the semaphore when dropped
+#[derive(Debug)]
+struct Permit(Arc<Semaphore>);
+
+impl Drop for Permit {
+    fn drop(&mut self) {
+        let waiters = {
+            let mut state = self.0.state.lock().unwrap_or_else(PoisonError::into_inner);
+            state.permits += 1;
+            std::mem::take(&mut state.waiters)
+        };
+        // Waking every waiter rather than the first keeps a permit from being lost to a waiter
+        // that was dropped after registering; the ones that lose the race register again.
+        for waiter in waiters {
+            waiter.wake();
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::headers::Headers;
+    use crate::{Method, Request, Response, StatusCode};
+    use std::sync::atomic::{AtomicUsize, Ordering};
+
+    /// Return `Pending` once, so that other futures polled alongside run before this one finishes.
+    async fn yield_now() {
+        let mut yielded = false;
+        futures::future::poll_fn(|cx| {
+            if yielded {
+                Poll::Ready(())
+            } else {
+                yielded = true;
+                cx.waker().wake_by_ref();
+                Poll::Pending
+            }
+        })
+        .await
+    }
+
+    #[derive(Debug, Default)]
+    struct TrackingClient {
+        in_flight: AtomicUsize,
+        max_in_flight: AtomicUsize,
+    }
+
+    #[async_trait]
+    impl HttpClient for TrackingClient {
+        async fn execute_request(&self, _request: &Request) -> crate::Result<Response> {
+            let in_flight = self.in_flight.fetch_add(1, Ordering::SeqCst) + 1;
+            self.max_in_flight.fetch_max(in_flight, Ordering::SeqCst);
+            yield_now().await;
+            self.in_flight.fetch_sub(1, Ordering::SeqCst);
+            Ok(Response::new(
+                StatusCode::Ok,
+                Headers::new(),
+                Box::pin(futures::stream::empty()),
+            ))
+        }
+    }
+
+    #[test]
+    fn limits_requests_in_flight() {
+        let tracking = Arc::new(TrackingClient::default());
+        let client = LimitedHttpClient::new(tracking.clone(), 2);
+        let request = Request::new("http://localhost".parse().unwrap(), Method::Get);
+        let responses =
+            futures::executor::block_on(futures::future::join_all((0..8).map(|_| async {
+                let response = client.execute_request(&request).await.unwrap();
+                response.into_body().collect().await.unwrap()
+            })));
+        assert_eq!(responses.len(), 8);
+        assert_eq!(tracking.max_in_flight.load(Ordering::SeqCst), 2);
+    }
+
+    #[test]
+    fn unread_bodies_hold_their_permits() {
+        let client = LimitedHttpClient::new(Arc::new(TrackingClient::default()), 1);
+        let request = Request::new("http://localhost".parse().unwrap(), Method::Get);
+        futures::executor::block_on(async {
+            let response = client.execute_request(&request).await.unwrap();
+            assert!(futures::poll!(Box::pin(client.execute_request(&request))).is_pending());
+            drop(response);
+            client.execute_request(&request).await.unwrap();
+        });
+    }
+}
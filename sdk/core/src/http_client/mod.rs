@@ -1,3 +1,4 @@
+mod limit;
 mod noop;
 #[cfg(all(
     not(target_arch = "wasm32"),
@@ -31,7 +32,29 @@ pub fn new_http_client() -> Arc<dyn HttpClient> {
     http_client
 }
 
+/// Construct a new `HttpClient` configured by `options`
+pub fn new_http_client_with_options(
+    options: &HttpClientOptions,
+) -> crate::Result<Arc<dyn HttpClient>> {
+    #[allow(unused)]
+    let http_client: Arc<dyn HttpClient> = Arc::new(NoopClient);
+    #[cfg(all(
+        not(target_arch = "wasm32"),
+        any(feature = "enable_reqwest", feature = "enable_reqwest_rustls")
+    ))]
+    let http_client = new_reqwest_client_with_options(options)?;
+    Ok(match options.max_concurrent_requests {
+        Some(max_concurrent_requests) => Arc::new(limit::LimitedHttpClient::new(
+            http_client,
+            max_concurrent_requests,
+        )),
+        None => http_client,
+    })
+}
+
 use crate::error::ErrorKind;
+use crate::json::{DefaultJson, JsonFormat};
+use crate::HttpClientOptions;
 use async_trait::async_trait;
 use bytes::Bytes;
 use serde::Serialize;
@@ -70,5 +93,5 @@ pub fn to_json<T>(value: &T) -> crate::Result<Bytes>
 where
     T: ?Sized + Serialize,
 {
-    Ok(Bytes::from(serde_json::to_vec(value)?))
+    DefaultJson::to_json(value)
 }
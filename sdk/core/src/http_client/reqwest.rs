@@ -1,14 +1,50 @@
 use crate::error::{ErrorKind, ResultExt};
-use crate::{Body, HttpClient, PinnedStream};
+use crate::{Body, HttpClient, HttpClientOptions, HttpVersion, PinnedStream};
 
 use async_trait::async_trait;
 use futures::TryStreamExt;
 use std::{collections::HashMap, str::FromStr};
 
 /// Construct a new `HttpClient` with the `reqwest` backend.
+///
+/// The client is configured by [`HttpClientOptions::default`], so like
+/// [`new_reqwest_client_with_options`] it only reads the proxy environment variables with the
+/// `env_proxy` feature.
 pub fn new_reqwest_client() -> std::sync::Arc<dyn HttpClient> {
-    log::debug!("instantiating an http client using the reqwest backend");
-    std::sync::Arc::new(::reqwest::Client::new())
+    new_reqwest_client_with_options(&HttpClientOptions::default())
+        .expect("failed to build `reqwest` client")
+}
+
+/// Construct a new `HttpClient` with the `reqwest` backend, configured by `options`.
+///
+/// [`HttpClientOptions::max_concurrent_requests`] is not applied here; see
+/// [`new_http_client_with_options`](crate::http_client::new_http_client_with_options).
+pub fn new_reqwest_client_with_options(
+    options: &HttpClientOptions,
+) -> crate::Result<std::sync::Arc<dyn HttpClient>> {
+    log::debug!("instantiating an http client using the reqwest backend with {options:?}");
+    let builder = ::reqwest::Client::builder();
+    let builder = match options.http_version {
+        HttpVersion::Negotiate => builder,
+        HttpVersion::Http1Only => builder.http1_only(),
+        HttpVersion::Http2 => builder.http2_prior_knowledge(),
+    };
+    let builder = match &options.proxy {
+        Some(proxy_options) => {
+            let mut proxy = ::reqwest::Proxy::all(proxy_options.url().as_str())
+                .context(ErrorKind::Other, "invalid proxy url")?;
+            if let Some((username, password)) = proxy_options.credentials() {
+                proxy = proxy.basic_auth(username, password);
+            }
+            builder.proxy(proxy)
+        }
+        None if options.env_proxy => builder,
+        None => builder.no_proxy(),
+    };
+    let client = builder
+        .build()
+        .context(ErrorKind::Other, "failed to build `reqwest` client")?;
+    Ok(std::sync::Arc::new(client))
 }
 
 #[async_trait]
@@ -102,3 +138,67 @@ fn try_from_status(status: ::reqwest::StatusCode) -> crate::Result<crate::Status
         })
     })
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{Method, ProxyOptions, Request};
+    use tokio::io::{AsyncReadExt, AsyncWriteExt};
+
+    /// Accept one connection, answer it with an empty `200 OK` and return the request head it
+    /// received.
+    async fn proxy_stub(listener: tokio::net::TcpListener) -> String {
+        let (mut stream, _) = listener.accept().await.unwrap();
+        let mut head = Vec::new();
+        let mut buf = [0; 1024];
+        while !head.ends_with(b"\r\n\r\n") {
+            let read = stream.read(&mut buf).await.unwrap();
+            assert!(read > 0, "connection closed before the request head ended");
+            head.extend_from_slice(&buf[..read]);
+        }
+        stream
+            .write_all(b"HTTP/1.1 200 OK\r\ncontent-length: 0\r\nconnection: close\r\n\r\n")
+            .await
+            .unwrap();
+        String::from_utf8(head).unwrap()
+    }
+
+    #[tokio::test]
+    async fn requests_go_through_the_proxy() {
+        let listener = tokio::net::TcpListener::bind("127.0.0.1:0").await.unwrap();
+        let proxy_url = format!("http://{}", listener.local_addr().unwrap());
+        let proxy = tokio::spawn(proxy_stub(listener));
+
+        let options = HttpClientOptions::default()
+            .proxy(ProxyOptions::new(proxy_url.parse().unwrap()).basic_auth("user", "pass"));
+        let client = new_reqwest_client_with_options(&options).unwrap();
+        let request = Request::new(
+            "http://management.azure.invalid/subscriptions"
+                .parse()
+                .unwrap(),
+            Method::Get,
+        );
+        let response = client.execute_request(&request).await.unwrap();
+        assert_eq!(response.status(), crate::StatusCode::Ok);
+
+        let head = proxy.await.unwrap().to_ascii_lowercase();
+        assert!(
+            head.starts_with("get http://management.azure.invalid/subscriptions http/1.1\r\n"),
+            "{head}"
+        );
+        // base64 of `user:pass`
+        assert!(
+            head.contains("proxy-authorization: basic dxnlcjpwyxnz\r\n"),
+            "{head}"
+        );
+    }
+
+    #[test]
+    fn proxy_password_is_not_logged() {
+        let proxy = ProxyOptions::new("http://proxy.contoso.com:8080".parse().unwrap())
+            .basic_auth("user", "secret");
+        let debug = format!("{:?}", HttpClientOptions::default().proxy(proxy));
+        assert!(debug.contains("proxy.contoso.com"));
+        assert!(!debug.contains("secret"));
+    }
+}
@@ -0,0 +1,125 @@
+//! JSON serialization of request and response bodies.
+//!
+//! Bodies are serialized with `serde_json` by default. With the `simd-json` feature,
+//! [`from_json`] parses with `simd-json` instead, which is considerably faster on large bodies on
+//! CPUs with SIMD support. Serialization always uses `serde_json`, as request bodies are small.
+
+use bytes::Bytes;
+use serde::de::DeserializeOwned;
+use serde::Serialize;
+
+/// A JSON implementation for request and response bodies.
+pub trait JsonFormat {
+    /// Serialize `value` to JSON.
+    fn to_json<T>(value: &T) -> crate::Result<Bytes>
+    where
+        T: ?Sized + Serialize;
+
+    /// Deserialize a `T` from the JSON in `body`.
+    fn from_json<T>(body: &[u8]) -> crate::Result<T>
+    where
+        T: DeserializeOwned;
+}
+
+/// JSON through `serde_json`.
+#[derive(Debug, Clone, Copy, Default)]
+pub struct SerdeJson;
+
+impl JsonFormat for SerdeJson {
+    fn to_json<T>(value: &T) -> crate::Result<Bytes>
+    where
+        T: ?Sized + Serialize,
+    {
+        Ok(Bytes::from(serde_json::to_vec(value)?))
+    }
+
+    fn from_json<T>(body: &[u8]) -> crate::Result<T>
+    where
+        T: DeserializeOwned,
+    {
+        Ok(serde_json::from_slice(body)?)
+    }
+}
+
+/// JSON parsed by `simd-json` and serialized by `serde_json`.
+#[cfg(feature = "simd-json")]
+#[derive(Debug, Clone, Copy, Default)]
+pub struct SimdJson;
+
+#[cfg(feature = "simd-json")]
+impl JsonFormat for SimdJson {
+    fn to_json<T>(value: &T) -> crate::Result<Bytes>
+    where
+        T: ?Sized + Serialize,
+    {
+        SerdeJson::to_json(value)
+    }
+
+    fn from_json<T>(body: &[u8]) -> crate::Result<T>
+    where
+        T: DeserializeOwned,
+    {
+        // simd-json parses in place, so it needs a buffer of its own.
+        let mut body = body.to_vec();
+        simd_json::serde::from_slice(&mut body)
+            .map_err(|error| crate::Error::new(crate::error::ErrorKind::DataConversion, error))
+    }
+}
+
+/// The [`JsonFormat`] selected by the crate features.
+#[cfg(not(feature = "simd-json"))]
+pub type DefaultJson = SerdeJson;
+
+/// The [`JsonFormat`] selected by the crate features.
+#[cfg(feature = "simd-json")]
+pub type DefaultJson = SimdJson;
+
+/// Deserialize a `T` from the JSON in `body` with the [`DefaultJson`] implementation.
+pub fn from_json<S, T>(body: S) -> crate::Result<T>
+where
+    S: AsRef<[u8]>,
+    T: DeserializeOwned,
+{
+    DefaultJson::from_json(body.as_ref())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::error::ErrorKind;
+    use serde::Deserialize;
+
+    #[derive(Debug, PartialEq, Serialize, Deserialize)]
+    struct Node {
+        name: String,
+        tags: Vec<String>,
+    }
+
+    fn round_trip<J: JsonFormat>() {
+        let node = Node {
+            name: "web".to_owned(),
+            tags: vec!["a".to_owned(), "b\"c".to_owned()],
+        };
+        let json = J::to_json(&node).unwrap();
+        assert_eq!(J::from_json::<Node>(&json).unwrap(), node);
+        let error = J::from_json::<Node>(b"{\"name\": 1}").unwrap_err();
+        assert_eq!(error.kind(), &ErrorKind::DataConversion);
+    }
+
+    #[test]
+    fn serde_json_round_trips() {
+        round_trip::<SerdeJson>();
+    }
+
+    #[cfg(feature = "simd-json")]
+    #[test]
+    fn simd_json_round_trips() {
+        round_trip::<SimdJson>();
+    }
+
+    #[test]
+    fn from_json_uses_the_default_format() {
+        let node: Node = from_json(r#"{"name": "db", "tags": []}"#).unwrap();
+        assert_eq!(node.name, "db");
+    }
+}
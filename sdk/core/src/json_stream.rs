@@ -0,0 +1,345 @@
+//! Incremental parsing of a JSON array nested in a response body.
+
+use crate::error::{Error, ErrorKind};
+use crate::ResponseBody;
+use futures::stream::BoxStream;
+use futures::StreamExt;
+use serde::de::DeserializeOwned;
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+enum Phase {
+    /// Before the opening brace of the top level object.
+    Start,
+    /// Between members of the top level object.
+    Key,
+    /// Between elements of the array.
+    Items,
+    Done,
+}
+
+#[derive(Debug, PartialEq, Eq)]
+enum Step {
+    /// A complete array element spans `buffer[start..end]`.
+    Item(usize, usize),
+    NeedMore,
+    Done,
+}
+
+/// Progress through a JSON value that has not been received in full yet, kept between chunks so
+/// that every byte of the value is scanned once.
+#[derive(Debug, Clone, Copy)]
+struct ValueScan {
+    /// The next byte to scan.
+    index: usize,
+    /// The number of objects and arrays opened and not closed yet.
+    depth: usize,
+    /// Whether `index` is inside a string.
+    in_string: bool,
+    /// Whether the byte before `index` is a backslash escaping the next one.
+    escaped: bool,
+}
+
+struct ArrayItems {
+    body: ResponseBody,
+    buffer: Vec<u8>,
+    field: &'static str,
+    phase: Phase,
+    eof: bool,
+    /// The scan of the value being received, if one is incomplete.
+    scan: Option<ValueScan>,
+}
+
+impl ArrayItems {
+    fn step(&mut self) -> crate::Result<Step> {
+        loop {
+            let buffer = &self.buffer;
+            let start = skip_whitespace(buffer, 0);
+            if self.phase == Phase::Done {
+                return Ok(Step::Done);
+            }
+            if start == buffer.len() {
+                return Ok(Step::NeedMore);
+            }
+            match (self.phase, buffer[start]) {
+                (Phase::Start, b'{') => {
+                    self.buffer.drain(..=start);
+                    self.phase = Phase::Key;
+                }
+                (Phase::Key, b'}') | (Phase::Items, b']') => {
+                    self.phase = Phase::Done;
+                }
+                (Phase::Key | Phase::Items, b',') => {
+                    self.buffer.drain(..=start);
+                }
+                (Phase::Key, b'"') => {
+                    let key_end = match string_end(buffer, start) {
+                        Some(end) => end,
+                        None => return Ok(Step::NeedMore),
+                    };
+                    let colon = skip_whitespace(buffer, key_end);
+                    if colon == buffer.len() {
+                        return Ok(Step::NeedMore);
+                    }
+                    if buffer[colon] != b':' {
+                        return Err(unexpected(buffer[colon]));
+                    }
+                    let value = skip_whitespace(buffer, colon + 1);
+                    if value == buffer.len() {
+                        return Ok(Step::NeedMore);
+                    }
+                    let key: String = serde_json::from_slice(&buffer[start..key_end])?;
+                    if key == self.field && buffer[value] == b'[' {
+                        self.buffer.drain(..=value);
+                        self.phase = Phase::Items;
+                    } else {
+                        let value_end = match value_end(buffer, value, self.eof, &mut self.scan) {
+                            Some(end) => end,
+                            None => return Ok(Step::NeedMore),
+                        };
+                        self.buffer.drain(..value_end);
+                        if key == self.field {
+                            // `null` or another non-array value has no items.
+                            self.phase = Phase::Done;
+                        }
+                    }
+                }
+                (Phase::Items, _) => {
+                    return Ok(match value_end(buffer, start, self.eof, &mut self.scan) {
+                        Some(end) => Step::Item(start, end),
+                        None => Step::NeedMore,
+                    });
+                }
+                (_, byte) => return Err(unexpected(byte)),
+            }
+        }
+    }
+
+    async fn next_item<T: DeserializeOwned>(&mut self) -> Option<crate::Result<T>> {
+        loop {
+            let step = match self.step() {
+                Ok(step) => step,
+                Err(error) => {
+                    self.phase = Phase::Done;
+                    return Some(Err(error));
+                }
+            };
+            match step {
+                Step::Item(start, end) => {
+                    let item =
+                        serde_json::from_slice(&self.buffer[start..end]).map_err(Error::from);
+                    self.buffer.drain(..end);
+                    return Some(item);
+                }
+                Step::Done => return None,
+                Step::NeedMore if self.eof => {
+                    self.phase = Phase::Done;
+                    return Some(Err(Error::message(
+                        ErrorKind::DataConversion,
+                        "unexpected end of JSON response body",
+                    )));
+                }
+                Step::NeedMore => match self.body.next().await {
+                    Some(Ok(chunk)) => self.buffer.extend_from_slice(&chunk),
+                    Some(Err(error)) => {
+                        self.phase = Phase::Done;
+                        return Some(Err(error));
+                    }
+                    None => self.eof = true,
+                },
+            }
+        }
+    }
+}
+
+fn unexpected(byte: u8) -> Error {
+    Error::with_message(ErrorKind::DataConversion, || {
+        format!(
+            "unexpected character '{}' in JSON response body",
+            char::from(byte)
+        )
+    })
+}
+
+fn skip_whitespace(buffer: &[u8], mut index: usize) -> usize {
+    while index < buffer.len() && buffer[index].is_ascii_whitespace() {
+        index += 1;
+    }
+    index
+}
+
+/// The end of the string starting with the quote at `start`, if the buffer holds all of it.
+fn string_end(buffer: &[u8], start: usize) -> Option<usize> {
+    let mut index = start + 1;
+    while index < buffer.len() {
+        match buffer[index] {
+            b'\\' => index += 2,
+            b'"' => return Some(index + 1),
+            _ => index += 1,
+        }
+    }
+    None
+}
+
+/// The end of the JSON value starting at `start`, if the buffer holds all of it.
+///
+/// An incomplete value leaves its progress in `scan`, where the scan resumes once more of the
+/// value has been received. `start` must not move in between.
+fn value_end(
+    buffer: &[u8],
+    start: usize,
+    eof: bool,
+    scan: &mut Option<ValueScan>,
+) -> Option<usize> {
+    let progress = scan.get_or_insert(ValueScan {
+        index: start,
+        depth: 0,
+        in_string: false,
+        escaped: false,
+    });
+    let end = match buffer[start] {
+        b'"' | b'{' | b'[' => loop {
+            let byte = *buffer.get(progress.index)?;
+            progress.index += 1;
+            if progress.in_string {
+                if progress.escaped {
+                    progress.escaped = false;
+                } else if byte == b'\\' {
+                    progress.escaped = true;
+                } else if byte == b'"' {
+                    progress.in_string = false;
+                    if progress.depth == 0 {
+                        break progress.index;
+                    }
+                }
+                continue;
+            }
+            match byte {
+                b'"' => progress.in_string = true,
+                b'{' | b'[' => progress.depth += 1,
+                b'}' | b']' => {
+                    progress.depth -= 1;
+                    if progress.depth == 0 {
+                        break progress.index;
+                    }
+                }
+                _ => {}
+            }
+        },
+        _ => {
+            let end = buffer[progress.index..]
+                .iter()
+                .position(|byte| matches!(byte, b',' | b'}' | b']') || byte.is_ascii_whitespace())
+                .map(|offset| progress.index + offset)
+                .or(if eof { Some(buffer.len()) } else { None });
+            progress.index = buffer.len();
+            end?
+        }
+    };
+    *scan = None;
+    Some(end)
+}
+
+impl ResponseBody {
+    /// Stream the elements of the array in member `field` of the top level JSON object,
+    /// deserializing each as soon as it has been received.
+    ///
+    /// Unlike collecting the body and deserializing it in one go, only the element being parsed
+    /// is held in memory. Other members of the object, and anything after the array, are
+    /// skipped. A missing or `null` member yields no items.
+    pub fn json_array_items<T>(self, field: &'static str) -> BoxStream<'static, crate::Result<T>>
+    where
+        T: DeserializeOwned + Send + 'static,
+    {
+        let items = ArrayItems {
+            body: self,
+            buffer: Vec::new(),
+            field,
+            phase: Phase::Start,
+            eof: false,
+            scan: None,
+        };
+        futures::stream::unfold(items, |mut items| async move {
+            let item = items.next_item().await?;
+            Some((item, items))
+        })
+        .boxed()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::headers::Headers;
+    use crate::{Response, StatusCode};
+    use bytes::Bytes;
+    use serde::Deserialize;
+
+    #[derive(Debug, Deserialize, PartialEq)]
+    struct Node {
+        name: String,
+    }
+
+    fn body(chunks: &[&'static str]) -> ResponseBody {
+        let chunks: Vec<crate::Result<Bytes>> = chunks
+            .iter()
+            .map(|chunk| Ok(Bytes::from_static(chunk.as_bytes())))
+            .collect();
+        Response::new(
+            StatusCode::Ok,
+            Headers::new(),
+            Box::pin(futures::stream::iter(chunks)),
+        )
+        .into_body()
+    }
+
+    fn items(chunks: &[&'static str]) -> Vec<crate::Result<Node>> {
+        futures::executor::block_on(body(chunks).json_array_items("value").collect())
+    }
+
+    fn names(chunks: &[&'static str]) -> Vec<String> {
+        items(chunks)
+            .into_iter()
+            .map(|node| node.unwrap().name)
+            .collect()
+    }
+
+    #[test]
+    fn items_split_across_chunks() {
+        let json = r#"{"count": 2, "meta": {"a": [1, "]"]}, "value": [{"name": "a\"}"}, {"name": "b"}], "nextLink": "x"}"#;
+        let expected = vec!["a\"}".to_owned(), "b".to_owned()];
+        assert_eq!(names(&[json]), expected);
+        for split in 1..json.len() {
+            let (first, second) = json.split_at(split);
+            let first: &'static str = Box::leak(first.to_owned().into_boxed_str());
+            let second: &'static str = Box::leak(second.to_owned().into_boxed_str());
+            assert_eq!(names(&[first, second]), expected, "split at {split}");
+        }
+    }
+
+    #[test]
+    fn items_split_into_single_bytes() {
+        let json = r#"{"meta": {"a": [1, "]\\"]}, "value": [{"name": "a\"}"}, {"name": "b", "tags": [[], {}]}]}"#;
+        let chunks: Vec<&'static str> = (0..json.len())
+            .map(|index| &*Box::leak(json[index..=index].to_owned().into_boxed_str()))
+            .collect();
+        assert_eq!(names(&chunks), ["a\"}", "b"]);
+    }
+
+    #[test]
+    fn missing_or_null_field_yields_nothing() {
+        assert!(names(&[r#"{"nextLink": null}"#]).is_empty());
+        assert!(names(&[r#"{"value": null, "nextLink": "x"}"#]).is_empty());
+        assert!(names(&[r#"{"value": []}"#]).is_empty());
+    }
+
+    #[test]
+    fn truncated_body_is_an_error() {
+        let items = items(&[r#"{"value": [{"name": "a"}, {"name""#]);
+        assert_eq!(items.len(), 2);
+        assert_eq!(items[0].as_ref().unwrap().name, "a");
+        assert_eq!(
+            items[1].as_ref().unwrap_err().kind(),
+            &ErrorKind::DataConversion
+        );
+    }
+}
@@ -21,6 +21,7 @@ mod context;
 pub mod date;
 pub mod error;
 mod http_client;
+mod json_stream;
 mod models;
 mod options;
 mod pageable;
@@ -31,7 +32,13 @@ mod response;
 mod seekable_stream;
 
 pub mod auth;
+pub mod batch;
+pub mod client;
+pub mod concurrency;
 pub mod headers;
+pub mod json;
+pub mod lro;
+pub mod multipart;
 pub mod parsing;
 pub mod prelude;
 pub mod request_options;
@@ -40,16 +47,23 @@ pub mod util;
 
 use uuid::Uuid;
 
+#[cfg(feature = "blocking")]
+pub mod blocking;
+#[cfg(feature = "har")]
+pub mod har;
+#[cfg(feature = "record")]
+pub mod record;
 #[cfg(feature = "xml")]
 pub mod xml;
 
 pub use bytes_stream::*;
 pub use constants::*;
-pub use context::Context;
+pub use context::{Context, OperationName};
 pub use error::{Error, Result};
 #[doc(inline)]
 pub use headers::Header;
-pub use http_client::{new_http_client, to_json, HttpClient};
+pub use http_client::{new_http_client, new_http_client_with_options, to_json, HttpClient};
+pub use json::from_json;
 pub use models::*;
 pub use options::*;
 pub use pageable::*;
@@ -63,6 +77,8 @@ pub use sleep::sleep;
 // re-export important types at crate level
 pub use http_types::Method;
 pub use http_types::StatusCode;
+#[cfg(feature = "metrics")]
+pub use opentelemetry;
 pub use url::Url;
 
 /// A unique identifier for a request.
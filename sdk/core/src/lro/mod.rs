@@ -0,0 +1,57 @@
+//! Helpers for long-running operations.
+
+mod poller;
+
+pub use poller::{FinalGet, FinalStateVia, PollClock, PollOptions, PollTimeout, Poller};
+
+use crate::headers::LONG_RUNNING_OPERATION;
+use crate::{Response, StatusCode};
+
+/// Whether `response` is the start of a long-running operation that has to be polled to
+/// completion.
+///
+/// The `x-ms-long-running-operation` response header decides when the service sends it, whatever
+/// the status. Otherwise `201 Created` and `202 Accepted` are taken to mean that the operation is
+/// still in progress, and any other status that it has already finished.
+pub fn is_long_running(response: &Response) -> bool {
+    match response.headers().get_optional_str(&LONG_RUNNING_OPERATION) {
+        Some(long_running) => long_running.trim().eq_ignore_ascii_case("true"),
+        None => matches!(
+            response.status(),
+            StatusCode::Created | StatusCode::Accepted
+        ),
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::headers::Headers;
+
+    fn response(status: StatusCode, long_running: Option<&'static str>) -> Response {
+        let mut headers = Headers::new();
+        if let Some(long_running) = long_running {
+            headers.insert(LONG_RUNNING_OPERATION, long_running);
+        }
+        Response::new(status, headers, Box::pin(futures::stream::empty()))
+    }
+
+    #[test]
+    fn header_present() {
+        assert!(is_long_running(&response(StatusCode::Ok, Some("true"))));
+        assert!(is_long_running(&response(StatusCode::Ok, Some(" True "))));
+        assert!(!is_long_running(&response(
+            StatusCode::Accepted,
+            Some("false")
+        )));
+        assert!(!is_long_running(&response(StatusCode::Created, Some("no"))));
+    }
+
+    #[test]
+    fn header_absent() {
+        assert!(is_long_running(&response(StatusCode::Accepted, None)));
+        assert!(is_long_running(&response(StatusCode::Created, None)));
+        assert!(!is_long_running(&response(StatusCode::Ok, None)));
+        assert!(!is_long_running(&response(StatusCode::NoContent, None)));
+    }
+}
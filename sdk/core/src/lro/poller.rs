@@ -0,0 +1,573 @@
+use super::is_long_running;
+use crate::client::{from_json_body, unexpected_status, ServiceClient};
+use crate::error::{Error, ErrorKind};
+use crate::headers;
+use crate::{Method, Request, Response, StatusCode, Url};
+use futures::future::{AbortHandle, Abortable, Aborted, BoxFuture};
+use std::sync::{Arc, Mutex, MutexGuard, PoisonError};
+use std::time::{Duration, Instant};
+
+/// Poll interval for long-running operations whose responses carry no `Retry-After` header.
+const DEFAULT_POLL_INTERVAL: Duration = Duration::from_secs(10);
+/// The longest [`PollOptions::backoff`] stretches the wait between two polls to.
+const MAX_POLL_INTERVAL: Duration = Duration::from_secs(300);
+
+/// How to poll an operation until it finishes: how long to wait between polls, whether to back
+/// off, and when to give up.
+///
+/// Taken by [`Poller::wait_with`] and by the polling helpers of generated clients. The default
+/// waits as long as each response's `Retry-After` header asks, ten seconds if it has none,
+/// without backing off and without a deadline. A `Duration` converts into options with that
+/// interval.
+#[derive(Clone, Debug, PartialEq)]
+pub struct PollOptions {
+    interval: Option<Duration>,
+    timeout: Option<Duration>,
+    backoff: f64,
+}
+
+impl Default for PollOptions {
+    fn default() -> Self {
+        Self {
+            interval: None,
+            timeout: None,
+            backoff: 1.0,
+        }
+    }
+}
+
+impl PollOptions {
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Wait `interval` between polls, instead of as long as the `Retry-After` header asks.
+    #[must_use]
+    pub fn interval(mut self, interval: Duration) -> Self {
+        self.interval = Some(interval);
+        self
+    }
+
+    /// Give up once `timeout` has passed since polling started, failing with a [`PollTimeout`]
+    /// error that carries the last state observed.
+    #[must_use]
+    pub fn timeout(mut self, timeout: Duration) -> Self {
+        self.timeout = Some(timeout);
+        self
+    }
+
+    /// Multiply the wait by `factor` after every poll, up to five minutes between polls.
+    /// Factors below `1.0` are treated as `1.0`, which keeps the wait constant.
+    #[must_use]
+    pub fn backoff(mut self, factor: f64) -> Self {
+        self.backoff = factor;
+        self
+    }
+
+    /// The wait before poll number `polls + 1`, given the `Retry-After` of the last response.
+    fn delay(&self, polls: u32, retry_after: Option<Duration>) -> Duration {
+        let base = self
+            .interval
+            .or(retry_after)
+            .unwrap_or(DEFAULT_POLL_INTERVAL);
+        let max = MAX_POLL_INTERVAL.max(base);
+        let secs = base.as_secs_f64() * self.backoff.max(1.0).powi(polls.min(64) as i32);
+        if secs.is_finite() && secs < max.as_secs_f64() {
+            Duration::from_secs_f64(secs)
+        } else {
+            max
+        }
+    }
+}
+
+impl From<Duration> for PollOptions {
+    fn from(interval: Duration) -> Self {
+        Self::new().interval(interval)
+    }
+}
+
+/// The error polling fails with once the [`PollOptions::timeout`] has passed before the
+/// operation finished.
+///
+/// Reach it with `downcast_ref` on the [`Error`], whose kind is `ErrorKind::Other`. `T` is the
+/// last state observed: the `StatusCode` for a [`Poller`], and the resource for the polling
+/// helpers of generated clients, such as the job being waited for.
+#[derive(Debug)]
+pub struct PollTimeout<T> {
+    operation: &'static str,
+    timeout: Duration,
+    last: T,
+}
+
+impl<T> PollTimeout<T> {
+    /// The last state observed before giving up.
+    pub fn last(&self) -> &T {
+        &self.last
+    }
+
+    pub fn into_last(self) -> T {
+        self.last
+    }
+
+    pub fn timeout(&self) -> Duration {
+        self.timeout
+    }
+}
+
+impl<T> std::fmt::Display for PollTimeout<T> {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(
+            f,
+            "polling for {} timed out after {:?}",
+            self.operation, self.timeout
+        )
+    }
+}
+
+impl<T: std::fmt::Debug> std::error::Error for PollTimeout<T> {}
+
+/// Paces a polling loop according to its [`PollOptions`].
+#[derive(Debug)]
+pub struct PollClock {
+    options: PollOptions,
+    start: Instant,
+    polls: u32,
+}
+
+impl PollClock {
+    pub fn new(options: PollOptions) -> Self {
+        Self {
+            options,
+            start: Instant::now(),
+            polls: 0,
+        }
+    }
+
+    /// Wait before the next poll, or fail with a [`PollTimeout`] carrying `last` if the timeout
+    /// has passed. The wait is cut short at the deadline, so the last poll happens right at it.
+    pub async fn tick<T>(
+        &mut self,
+        operation: &'static str,
+        retry_after: Option<Duration>,
+        last: T,
+    ) -> crate::Result<()>
+    where
+        T: std::fmt::Debug + Send + Sync + 'static,
+    {
+        let mut delay = self.options.delay(self.polls, retry_after);
+        if let Some(timeout) = self.options.timeout {
+            let remaining = timeout.saturating_sub(self.start.elapsed());
+            if remaining.is_zero() {
+                return Err(Error::new(
+                    ErrorKind::Other,
+                    PollTimeout {
+                        operation,
+                        timeout,
+                        last,
+                    },
+                ));
+            }
+            delay = delay.min(remaining);
+        }
+        self.polls = self.polls.saturating_add(1);
+        crate::sleep(delay).await;
+        Ok(())
+    }
+}
+
+/// Where a long-running operation leaves its result once it has finished, as given by the
+/// `final-state-via` of its specification.
+///
+/// It decides whether a [`Poller`] makes a final `GET` once an `Azure-AsyncOperation` status
+/// monitor reports `Succeeded`, unless [`Poller::final_get`] says otherwise.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum FinalStateVia {
+    /// The status monitor's final payload is the result, so no final `GET` is made.
+    AzureAsyncOperation,
+    /// The result is fetched from the `Location` of the first response, if it had one.
+    Location,
+    /// The result is fetched from the URL of the request that started the operation, as for a
+    /// `PUT` or `PATCH` of the resource.
+    OriginalUri,
+}
+
+/// Whether a [`Poller`] fetches the result with a final `GET` once an `Azure-AsyncOperation`
+/// status monitor reports `Succeeded`.
+///
+/// Operations polled through `Location` alone finish with the result, so this only matters for
+/// those that answer with an `Azure-AsyncOperation` header.
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq)]
+pub enum FinalGet {
+    /// Follow the operation's [`FinalStateVia`]. The default.
+    #[default]
+    Auto,
+    /// Always make the final `GET`: on `Location` if the first response had one, on the URL of
+    /// the original request otherwise.
+    Always,
+    /// Never make it, and take the status monitor's final payload as the result, saving a
+    /// round-trip.
+    Never,
+}
+
+/// The body of an `Azure-AsyncOperation` status monitor.
+#[derive(serde::Deserialize)]
+struct AsyncOperationStatus {
+    status: String,
+    #[serde(default)]
+    error: Option<AsyncOperationError>,
+}
+
+#[derive(serde::Deserialize)]
+struct AsyncOperationError {
+    #[serde(default)]
+    code: Option<String>,
+    #[serde(default)]
+    message: Option<String>,
+}
+
+/// A long-running operation, polled through its `Azure-AsyncOperation` or `Location` header
+/// until it has finished.
+///
+/// A response starts a long-running operation if it carries `x-ms-long-running-operation:
+/// true`, or, without that header, if it is `201 Created` or `202 Accepted`; see
+/// [`is_long_running`]. Any other successful response means the operation has already
+/// finished.
+///
+/// `wait` polls the `Azure-AsyncOperation` status monitor if the first response named one,
+/// until it reports `Succeeded`, then makes a final `GET` for the result if `final_get` asks for
+/// one. Without a status monitor, it polls `Location` until it answers `200 OK` or
+/// `204 No Content`. Either way, it waits as long as each response's `Retry-After` header asks
+/// (ten seconds if absent). The poller is a cheap handle to shared state, so clone it before
+/// calling `wait` to keep a handle for `cancel`, such as for a cancel button in an interactive
+/// tool.
+///
+/// Cancellation is client-side only: `cancel` stops polling but leaves the operation running on
+/// the service.
+#[derive(Clone)]
+pub struct Poller {
+    client: ServiceClient,
+    operation: &'static str,
+    original_uri: Url,
+    final_state_via: FinalStateVia,
+    final_get: FinalGet,
+    state: Arc<Mutex<PollerState>>,
+}
+
+struct PollerState {
+    status: StatusCode,
+    location: Option<Url>,
+    async_operation: Option<Url>,
+    retry_after: Option<Duration>,
+    body: Option<bytes::Bytes>,
+    finished: bool,
+    cancelled: bool,
+    waiters: Vec<AbortHandle>,
+}
+
+impl std::fmt::Debug for Poller {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.debug_struct("Poller")
+            .field("operation", &self.operation)
+            .field("original_uri", &self.original_uri)
+            .field("final_state_via", &self.final_state_via)
+            .field("final_get", &self.final_get)
+            .field("status", &self.status())
+            .finish_non_exhaustive()
+    }
+}
+
+impl Poller {
+    /// Start polling the operation `operation`, such as `"automation_account::delete"`, that
+    /// the request to `original_uri` started with `rsp`.
+    ///
+    /// Fails like `wait` if `rsp` is unsuccessful or a long-running response without a header
+    /// to poll.
+    pub fn new(
+        client: ServiceClient,
+        rsp: &Response,
+        original_uri: Url,
+        operation: &'static str,
+        final_state_via: FinalStateVia,
+    ) -> crate::Result<Self> {
+        let poller = Self {
+            client,
+            operation,
+            original_uri,
+            final_state_via,
+            final_get: FinalGet::default(),
+            state: Arc::new(Mutex::new(PollerState {
+                status: rsp.status(),
+                location: None,
+                async_operation: None,
+                retry_after: None,
+                body: None,
+                finished: false,
+                cancelled: false,
+                waiters: Vec::new(),
+            })),
+        };
+        poller.update(rsp)?;
+        Ok(poller)
+    }
+
+    /// Set whether to fetch the result with a final `GET` once an `Azure-AsyncOperation` status
+    /// monitor reports `Succeeded`.
+    ///
+    /// Defaults to [`FinalGet::Auto`], which follows the operation's `final-state-via`. Use
+    /// [`FinalGet::Never`] to save the round-trip when the status monitor's payload is enough,
+    /// or [`FinalGet::Always`] when a service puts the resource only behind the final `GET`. The
+    /// knob applies to `wait` calls made through this handle and its later clones.
+    #[must_use]
+    pub fn final_get(mut self, final_get: FinalGet) -> Self {
+        self.final_get = final_get;
+        self
+    }
+
+    fn lock(&self) -> MutexGuard<'_, PollerState> {
+        self.state.lock().unwrap_or_else(PoisonError::into_inner)
+    }
+
+    /// Record the status of `rsp`, returning whether the operation has finished.
+    ///
+    /// Whether polling is needed is decided by [`is_long_running`], so every operation polled
+    /// through a `Poller` agrees on it. A `202 Accepted` that does not repeat the `Location`
+    /// header keeps polling the `Location` recorded before it.
+    fn update(&self, rsp: &Response) -> crate::Result<bool> {
+        let headers = rsp.headers();
+        let mut state = self.lock();
+        state.status = rsp.status();
+        if !rsp.status().is_success() {
+            return Err(unexpected_status(rsp));
+        }
+        if !is_long_running(rsp) {
+            state.finished = true;
+            return Ok(true);
+        }
+        let endpoint = Url::parse(self.client.endpoint())?;
+        let location = headers.get_optional_str(&headers::LOCATION);
+        let async_operation = headers.get_optional_str(&headers::AZURE_ASYNCOPERATION);
+        if location.is_none()
+            && async_operation.is_none()
+            && state.async_operation.is_none()
+            && state.location.is_none()
+        {
+            let ids = crate::client::response_request_ids(rsp);
+            return Err(Error::with_message(
+                ErrorKind::HttpResponse {
+                    status: rsp.status(),
+                    error_code: None,
+                },
+                || {
+                    format!(
+                        "long-running operation without a Location header to poll ({})",
+                        ids
+                    )
+                },
+            ));
+        }
+        if let Some(location) = location {
+            state.location = Some(endpoint.join(location)?);
+        }
+        if let Some(async_operation) = async_operation {
+            state.async_operation = Some(endpoint.join(async_operation)?);
+        }
+        state.retry_after = rsp.retry_after();
+        Ok(false)
+    }
+
+    /// The URL of the final `GET` to make once the status monitor reports `Succeeded`, if any.
+    fn final_get_url(&self, location: Option<&Url>) -> Option<Url> {
+        match (self.final_get, self.final_state_via) {
+            (FinalGet::Never, _) | (FinalGet::Auto, FinalStateVia::AzureAsyncOperation) => None,
+            (FinalGet::Auto, FinalStateVia::Location) => location.cloned(),
+            (FinalGet::Auto, FinalStateVia::OriginalUri) => Some(self.original_uri.clone()),
+            (FinalGet::Always, _) => Some(
+                location
+                    .cloned()
+                    .unwrap_or_else(|| self.original_uri.clone()),
+            ),
+        }
+    }
+
+    /// The status of the last response received for the operation: `202 Accepted` while it is
+    /// in progress, `200 OK` or `204 No Content` once it has finished.
+    pub fn status(&self) -> StatusCode {
+        self.lock().status
+    }
+
+    /// The body the operation finished with, once `wait` has resolved: that of the final `GET`
+    /// if one was made, or the status monitor's final payload if not.
+    ///
+    /// `None` while the operation is in progress, and for an operation that had already
+    /// finished when it was started, whose body is on that first response.
+    pub fn final_body(&self) -> Option<bytes::Bytes> {
+        self.lock().body.clone()
+    }
+
+    /// Poll until the operation finishes, with the default [`PollOptions`].
+    ///
+    /// Resolves to `Ok(())` once the operation has finished. Any unsuccessful status, a failed
+    /// poll, or a long-running response with neither `Azure-AsyncOperation` nor `Location`
+    /// fails with an `ErrorKind::HttpResponse` error carrying the status. A status monitor
+    /// reporting `Failed` or `Canceled` fails with an `ErrorKind::Other` error carrying the
+    /// service's error code and message. Once `cancel` has been called, fails right away with
+    /// an `ErrorKind::Other` error. Polling has no deadline; use `wait_with` to set one.
+    pub fn wait(&self) -> BoxFuture<'static, crate::Result<()>> {
+        self.wait_with(PollOptions::default())
+    }
+
+    /// Poll until the operation finishes, paced by `options`.
+    ///
+    /// Like `wait`, but once `options` times out, fails with a `PollTimeout<StatusCode>` error
+    /// carrying the status of the last response.
+    pub fn wait_with(&self, options: PollOptions) -> BoxFuture<'static, crate::Result<()>> {
+        let this = self.clone();
+        let (abort_handle, abort_registration) = AbortHandle::new_pair();
+        Box::pin(async move {
+            {
+                let mut state = this.lock();
+                if state.cancelled {
+                    return Err(this.cancelled());
+                }
+                state.waiters.push(abort_handle);
+            }
+            match Abortable::new(this.poll(options), abort_registration).await {
+                Ok(result) => result,
+                Err(Aborted) => Err(this.cancelled()),
+            }
+        })
+    }
+
+    async fn get(&self, url: Url) -> crate::Result<Response> {
+        let mut req = Request::new(url, Method::Get);
+        let token_response = self.client.token(None).await?;
+        req.insert_header(
+            headers::AUTHORIZATION,
+            format!("Bearer {}", token_response.token.secret()),
+        );
+        req.set_body(crate::EMPTY_BODY);
+        self.client.send(&mut req, self.operation).await
+    }
+
+    async fn poll(&self, options: PollOptions) -> crate::Result<()> {
+        let mut clock = PollClock::new(options);
+        loop {
+            let (url, monitor, retry_after, status) = {
+                let state = self.lock();
+                if state.finished {
+                    return Ok(());
+                }
+                match (&state.async_operation, &state.location) {
+                    (Some(async_operation), _) => (
+                        async_operation.clone(),
+                        true,
+                        state.retry_after,
+                        state.status,
+                    ),
+                    (None, Some(location)) => {
+                        (location.clone(), false, state.retry_after, state.status)
+                    }
+                    (None, None) => return Ok(()),
+                }
+            };
+            clock.tick(self.operation, retry_after, status).await?;
+            let mut rsp = self.get(url).await?;
+            if monitor {
+                let status = rsp.status();
+                self.lock().status = status;
+                if !status.is_success() {
+                    return Err(unexpected_status(&rsp));
+                }
+                let retry_after = rsp.retry_after();
+                let bytes = rsp.into_body().collect().await?;
+                let monitor: AsyncOperationStatus = from_json_body(self.operation, status, &bytes)?;
+                match monitor.status.as_str() {
+                    "Succeeded" => {
+                        let final_url = {
+                            let mut state = self.lock();
+                            state.async_operation = None;
+                            self.final_get_url(state.location.as_ref())
+                        };
+                        match final_url {
+                            Some(final_url) => rsp = self.get(final_url).await?,
+                            None => {
+                                let mut state = self.lock();
+                                state.body = Some(bytes);
+                                state.finished = true;
+                                return Ok(());
+                            }
+                        }
+                    }
+                    "Failed" | "Canceled" | "Cancelled" => {
+                        let operation = self.operation;
+                        let error = monitor.error.unwrap_or(AsyncOperationError {
+                            code: None,
+                            message: None,
+                        });
+                        return Err(Error::with_message(ErrorKind::Other, || {
+                            format!(
+                                "{} ended as {}: {}: {}",
+                                operation,
+                                monitor.status,
+                                error.code.as_deref().unwrap_or("no error code"),
+                                error.message.as_deref().unwrap_or("no error message")
+                            )
+                        }));
+                    }
+                    _ => {
+                        self.lock().retry_after = retry_after;
+                        continue;
+                    }
+                }
+            }
+            if self.update(&rsp)? {
+                let bytes = rsp.into_body().collect().await?;
+                self.lock().body = Some(bytes);
+                return Ok(());
+            }
+        }
+    }
+
+    /// Stop polling and return the status of the last response received.
+    ///
+    /// Pending and later calls to `wait` fail with an `ErrorKind::Other` error. The operation
+    /// itself keeps running on the service.
+    pub async fn cancel(&self) -> StatusCode {
+        let mut state = self.lock();
+        state.cancelled = true;
+        for waiter in state.waiters.drain(..) {
+            waiter.abort();
+        }
+        state.status
+    }
+
+    fn cancelled(&self) -> Error {
+        let operation = self.operation;
+        Error::with_message(ErrorKind::Other, || {
+            format!("polling for {} was cancelled", operation)
+        })
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn delay_follows_retry_after_and_backs_off() {
+        let options = PollOptions::default();
+        assert_eq!(options.delay(0, None), DEFAULT_POLL_INTERVAL);
+        assert_eq!(
+            options.delay(3, Some(Duration::from_secs(2))),
+            Duration::from_secs(2)
+        );
+        let options = PollOptions::new()
+            .interval(Duration::from_secs(1))
+            .backoff(2.0);
+        assert_eq!(
+            options.delay(3, Some(Duration::from_secs(30))),
+            Duration::from_secs(8)
+        );
+        assert_eq!(options.delay(64, None), MAX_POLL_INTERVAL);
+    }
+}
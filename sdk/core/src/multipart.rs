@@ -0,0 +1,167 @@
+//! `multipart/form-data` request bodies.
+
+use bytes::{BufMut, Bytes, BytesMut};
+use rand::distributions::{Alphanumeric, DistString};
+
+/// A `multipart/form-data` body made of named parts.
+///
+/// Send [`MultipartForm::to_bytes`] as the request body together with
+/// [`MultipartForm::content_type`] as the `content-type` header, which carries the boundary that
+/// separates the parts.
+#[derive(Debug, Clone)]
+pub struct MultipartForm {
+    boundary: String,
+    parts: Vec<Part>,
+}
+
+#[derive(Debug, Clone)]
+struct Part {
+    name: String,
+    file_name: Option<String>,
+    content_type: Option<String>,
+    content: Bytes,
+}
+
+impl MultipartForm {
+    /// Create an empty form with a random boundary.
+    pub fn new() -> Self {
+        Self::with_boundary(format!(
+            "----AzureSdkFormBoundary{}",
+            Alphanumeric.sample_string(&mut rand::thread_rng(), 16)
+        ))
+    }
+
+    /// Create an empty form with the given boundary.
+    ///
+    /// The boundary must not occur in the content of any part.
+    pub fn with_boundary(boundary: impl Into<String>) -> Self {
+        Self {
+            boundary: boundary.into(),
+            parts: Vec::new(),
+        }
+    }
+
+    /// Add a plain text field.
+    pub fn text(mut self, name: impl Into<String>, value: impl Into<String>) -> Self {
+        self.parts.push(Part {
+            name: name.into(),
+            file_name: None,
+            content_type: None,
+            content: Bytes::from(value.into()),
+        });
+        self
+    }
+
+    /// Add a file with the given file name and content type.
+    pub fn file(
+        mut self,
+        name: impl Into<String>,
+        file_name: impl Into<String>,
+        content_type: impl Into<String>,
+        content: impl Into<Bytes>,
+    ) -> Self {
+        self.parts.push(Part {
+            name: name.into(),
+            file_name: Some(file_name.into()),
+            content_type: Some(content_type.into()),
+            content: content.into(),
+        });
+        self
+    }
+
+    /// The boundary that separates the parts.
+    pub fn boundary(&self) -> &str {
+        &self.boundary
+    }
+
+    /// The value of the `content-type` header to send with this body.
+    pub fn content_type(&self) -> String {
+        format!("multipart/form-data; boundary={}", self.boundary)
+    }
+
+    /// Encode the form.
+    pub fn to_bytes(&self) -> Bytes {
+        let mut body = BytesMut::new();
+        for part in &self.parts {
+            body.put_slice(format!("--{}\r\n", self.boundary).as_bytes());
+            body.put_slice(
+                format!(
+                    "Content-Disposition: form-data; name=\"{}\"",
+                    escape(&part.name)
+                )
+                .as_bytes(),
+            );
+            if let Some(file_name) = &part.file_name {
+                body.put_slice(format!("; filename=\"{}\"", escape(file_name)).as_bytes());
+            }
+            body.put_slice(b"\r\n");
+            if let Some(content_type) = &part.content_type {
+                body.put_slice(format!("Content-Type: {content_type}\r\n").as_bytes());
+            }
+            body.put_slice(b"\r\n");
+            body.put_slice(&part.content);
+            body.put_slice(b"\r\n");
+        }
+        body.put_slice(format!("--{}--\r\n", self.boundary).as_bytes());
+        body.freeze()
+    }
+}
+
+impl Default for MultipartForm {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+fn escape(value: &str) -> String {
+    value
+        .replace('\\', "\\\\")
+        .replace('"', "\\\"")
+        .replace(['\r', '\n'], " ")
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn parts_are_separated_by_the_boundary() {
+        let form = MultipartForm::with_boundary("xyz")
+            .text("name", "numpy")
+            .file(
+                "content",
+                "numpy.whl",
+                "application/octet-stream",
+                Bytes::from_static(b"PK\x03\x04"),
+            );
+        assert_eq!(form.content_type(), "multipart/form-data; boundary=xyz");
+        assert_eq!(
+            form.to_bytes(),
+            Bytes::from_static(
+                b"--xyz\r\n\
+                  Content-Disposition: form-data; name=\"name\"\r\n\
+                  \r\n\
+                  numpy\r\n\
+                  --xyz\r\n\
+                  Content-Disposition: form-data; name=\"content\"; filename=\"numpy.whl\"\r\n\
+                  Content-Type: application/octet-stream\r\n\
+                  \r\n\
+                  PK\x03\x04\r\n\
+                  --xyz--\r\n"
+            )
+        );
+    }
+
+    #[test]
+    fn random_boundaries_differ() {
+        let boundary = MultipartForm::new().boundary().to_owned();
+        assert!(boundary.starts_with("----AzureSdkFormBoundary"));
+        assert_ne!(boundary, MultipartForm::new().boundary());
+    }
+
+    #[test]
+    fn quotes_in_names_are_escaped() {
+        let form = MultipartForm::with_boundary("b").text("a\"b", "");
+        assert!(String::from_utf8_lossy(&form.to_bytes()).contains("name=\"a\\\"b\""));
+    }
+}
@@ -1,4 +1,6 @@
-use crate::policies::{ExponentialRetryPolicy, FixedRetryPolicy, NoRetryPolicy, Policy};
+use crate::policies::{
+    ExponentialRetryPolicy, FixedRetryPolicy, IdempotentOnly, NoRetryPolicy, Policy,
+};
 use crate::{http_client, TimeoutPolicy};
 use crate::{HttpClient, RetryPolicy};
 use std::fmt::Debug;
@@ -31,6 +33,9 @@ pub struct ClientOptions {
     pub(crate) transport: TransportOptions,
     /// Transport options.
     pub timeout: TimeoutPolicy,
+    /// Metrics options.
+    #[cfg(feature = "metrics")]
+    pub(crate) metrics: MetricsOptions,
 }
 
 impl ClientOptions {
@@ -42,9 +47,23 @@ impl ClientOptions {
             telemetry: TelemetryOptions::default(),
             transport,
             timeout: TimeoutPolicy::default(),
+            #[cfg(feature = "metrics")]
+            metrics: MetricsOptions::default(),
         }
     }
 
+    /// Record request metrics with meters from `meter_provider`.
+    ///
+    /// See [`MetricsPolicy`](crate::MetricsPolicy) for the instruments that are recorded.
+    #[cfg(feature = "metrics")]
+    pub fn meter_provider(
+        mut self,
+        meter_provider: Arc<dyn opentelemetry::metrics::MeterProvider + Send + Sync>,
+    ) -> Self {
+        self.metrics.meter_provider = Some(meter_provider);
+        self
+    }
+
     /// A mutable reference to per-call policies.
     pub fn per_call_policies_mut(&mut self) -> &mut Vec<Arc<dyn Policy>> {
         &mut self.per_call_policies
@@ -111,6 +130,10 @@ impl Default for RetryMode {
 pub struct RetryOptions {
     /// The algorithm to use for calculating retry delays.
     mode: RetryMode,
+
+    /// Whether requests with a method that is not idempotent are only retried after IO errors
+    /// when marked [`Idempotent`](crate::Idempotent).
+    idempotent_only: bool,
 }
 
 impl RetryOptions {
@@ -118,6 +141,7 @@ impl RetryOptions {
     pub fn exponential(options: ExponentialRetryOptions) -> Self {
         Self {
             mode: RetryMode::Exponential(options),
+            idempotent_only: false,
         }
     }
 
@@ -125,6 +149,7 @@ impl RetryOptions {
     pub fn fixed(options: FixedRetryOptions) -> Self {
         Self {
             mode: RetryMode::Fixed(options),
+            idempotent_only: false,
         }
     }
 
@@ -132,6 +157,7 @@ impl RetryOptions {
     pub fn custom<T: RetryPolicy + 'static>(policy: Arc<T>) -> Self {
         Self {
             mode: RetryMode::Custom(policy),
+            idempotent_only: false,
         }
     }
 
@@ -139,22 +165,48 @@ impl RetryOptions {
     pub fn none() -> Self {
         Self {
             mode: RetryMode::None,
+            idempotent_only: false,
         }
     }
 
+    /// Only retry a request with a method that is not idempotent, such as `POST` or `PATCH`,
+    /// after an IO error when it is marked [`Idempotent`](crate::Idempotent).
+    ///
+    /// The service may already have applied such a request when the connection failed, so
+    /// retrying it could repeat its side effect. The default is `false`, which retries every
+    /// request after an IO error. Custom retry policies are not affected.
+    pub fn idempotent_only(mut self, idempotent_only: bool) -> Self {
+        self.idempotent_only = idempotent_only;
+        self
+    }
+
     pub(crate) fn to_policy(&self) -> Arc<dyn Policy> {
         match &self.mode {
-            RetryMode::Exponential(options) => Arc::new(ExponentialRetryPolicy::new(
-                options.initial_delay,
-                options.max_retries,
-                options.max_total_elapsed,
-                options.max_delay,
-            )),
-            RetryMode::Fixed(options) => Arc::new(FixedRetryPolicy::new(
-                options.delay,
-                options.max_retries,
-                options.max_total_elapsed,
-            )),
+            RetryMode::Exponential(options) => {
+                let policy = ExponentialRetryPolicy::new(
+                    options.initial_delay,
+                    options.max_retries,
+                    options.max_total_elapsed,
+                    options.max_delay,
+                );
+                if self.idempotent_only {
+                    Arc::new(IdempotentOnly::new(policy))
+                } else {
+                    Arc::new(policy)
+                }
+            }
+            RetryMode::Fixed(options) => {
+                let policy = FixedRetryPolicy::new(
+                    options.delay,
+                    options.max_retries,
+                    options.max_total_elapsed,
+                );
+                if self.idempotent_only {
+                    Arc::new(IdempotentOnly::new(policy))
+                } else {
+                    Arc::new(policy)
+                }
+            }
             RetryMode::Custom(c) => c.clone(),
             RetryMode::None => Arc::new(NoRetryPolicy::default()),
         }
@@ -278,6 +330,166 @@ impl TelemetryOptions {
     }
 }
 
+/// Metrics options.
+#[cfg(feature = "metrics")]
+#[derive(Clone, Default)]
+pub(crate) struct MetricsOptions {
+    pub(crate) meter_provider: Option<Arc<dyn opentelemetry::metrics::MeterProvider + Send + Sync>>,
+}
+
+#[cfg(feature = "metrics")]
+impl Debug for MetricsOptions {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.debug_struct("MetricsOptions")
+            .field("meter_provider", &self.meter_provider.is_some())
+            .finish()
+    }
+}
+
+/// The HTTP version the default `HttpClient` speaks.
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq)]
+pub enum HttpVersion {
+    /// Let the TLS handshake decide: HTTP/2 if the TLS backend and the server agree on it, otherwise
+    /// HTTP/1.1.
+    ///
+    /// This is the default. `rustls` negotiates HTTP/2; `native-tls` only does with `reqwest`'s
+    /// `native-tls-alpn` feature enabled.
+    #[default]
+    Negotiate,
+    /// Only speak HTTP/1.1, which needs a connection per concurrent request.
+    Http1Only,
+    /// Speak HTTP/2 without negotiating it first, multiplexing concurrent requests over a single
+    /// connection per host.
+    ///
+    /// Every server the client talks to must support HTTP/2, which Azure Resource Manager does.
+    Http2,
+}
+
+/// Options for the default `HttpClient`, for tuning it to workloads that send many requests in
+/// parallel.
+///
+/// # Examples
+///
+/// ```
+/// use azure_core::{ClientOptions, HttpClientOptions, HttpVersion, TransportOptions};
+/// let transport = TransportOptions::with_http_options(
+///     HttpClientOptions::default()
+///         .http_version(HttpVersion::Http2)
+///         .max_concurrent_requests(64usize),
+/// )?;
+/// let options = ClientOptions::new(transport);
+/// # Ok::<(), azure_core::Error>(())
+/// ```
+#[derive(Clone, Debug)]
+pub struct HttpClientOptions {
+    /// The HTTP version to speak.
+    ///
+    /// The default is [`HttpVersion::Negotiate`].
+    pub http_version: HttpVersion,
+
+    /// The maximum number of requests in flight at once, from sending a request until its response
+    /// body has been read or dropped. Further requests wait for one to finish.
+    ///
+    /// Over HTTP/2 this bounds the number of concurrent streams the client opens. The default is no
+    /// limit, which leaves HTTP/2 bounded only by the limit the server advertises.
+    pub max_concurrent_requests: Option<usize>,
+
+    /// The proxy to send every request through.
+    ///
+    /// The default is no explicit proxy. Setting one overrides [`HttpClientOptions::env_proxy`].
+    pub proxy: Option<ProxyOptions>,
+
+    /// Whether to use the proxy configured by the `HTTP_PROXY`, `HTTPS_PROXY`, `ALL_PROXY` and
+    /// `NO_PROXY` environment variables when no [`HttpClientOptions::proxy`] is set.
+    ///
+    /// The default is `true` if the `env_proxy` feature is enabled and `false` otherwise.
+    pub env_proxy: bool,
+}
+
+// Not derivable with the `env_proxy` feature, which changes the default of `env_proxy`.
+#[allow(clippy::derivable_impls)]
+impl Default for HttpClientOptions {
+    fn default() -> Self {
+        Self {
+            http_version: HttpVersion::default(),
+            max_concurrent_requests: None,
+            proxy: None,
+            env_proxy: cfg!(feature = "env_proxy"),
+        }
+    }
+}
+
+impl HttpClientOptions {
+    setters! {
+        http_version: HttpVersion => http_version,
+        max_concurrent_requests: usize => Some(max_concurrent_requests),
+        proxy: ProxyOptions => Some(proxy),
+        env_proxy: bool => env_proxy,
+    }
+}
+
+/// An HTTP proxy for the default `HttpClient` to send requests through.
+///
+/// # Examples
+///
+/// ```
+/// use azure_core::{HttpClientOptions, ProxyOptions, TransportOptions};
+/// let proxy = ProxyOptions::new("http://proxy.contoso.com:8080".parse()?).basic_auth("user", "password");
+/// let transport = TransportOptions::with_http_options(HttpClientOptions::default().proxy(proxy))?;
+/// # Ok::<(), Box<dyn std::error::Error>>(())
+/// ```
+#[derive(Clone)]
+pub struct ProxyOptions {
+    url: crate::Url,
+    basic_auth: Option<(String, String)>,
+}
+
+impl ProxyOptions {
+    /// Creates options for the proxy at `url`, such as `http://proxy.contoso.com:8080`.
+    ///
+    /// Both `http` and `https` requests are sent through it.
+    pub fn new(url: crate::Url) -> Self {
+        Self {
+            url,
+            basic_auth: None,
+        }
+    }
+
+    /// Authenticate to the proxy with basic authentication.
+    #[must_use]
+    pub fn basic_auth(mut self, username: impl Into<String>, password: impl Into<String>) -> Self {
+        self.basic_auth = Some((username.into(), password.into()));
+        self
+    }
+
+    /// Get the URL of the proxy.
+    pub fn url(&self) -> &crate::Url {
+        &self.url
+    }
+
+    /// Get the username and password set with `basic_auth`, if any.
+    pub fn credentials(&self) -> Option<(&str, &str)> {
+        self.basic_auth
+            .as_ref()
+            .map(|(username, password)| (username.as_str(), password.as_str()))
+    }
+}
+
+impl Debug for ProxyOptions {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.debug_struct("ProxyOptions")
+            .field("url", &self.url.as_str())
+            .field(
+                "basic_auth",
+                &self
+                    .basic_auth
+                    .as_ref()
+                    .map(|(username, _)| (username, "<REDACTED>")),
+            )
+            .finish()
+    }
+}
+
 /// Transport options.
 #[derive(Clone, Debug)]
 pub struct TransportOptions {
@@ -295,11 +507,27 @@ enum TransportOptionsImpl {
 
 impl TransportOptions {
     /// Creates a new `TransportOptions` using the given `HttpClient`.
+    ///
+    /// The `HttpClient` owns the connection pool. Clients that are built from separate
+    /// `TransportOptions::default()` values each open their own pool, which wastes sockets and
+    /// file descriptors under load; create one `HttpClient` and pass clones of the `Arc` to every
+    /// client instead.
     pub fn new(http_client: Arc<dyn HttpClient>) -> Self {
         let inner = TransportOptionsImpl::Http { http_client };
         Self { inner }
     }
 
+    /// Creates a new `TransportOptions` using a new default `HttpClient` configured by `options`.
+    ///
+    /// As with [`TransportOptions::new`], share the resulting options between clients rather than
+    /// calling this for each of them, so that they share one connection pool and, with
+    /// [`HttpClientOptions::max_concurrent_requests`], one limit.
+    pub fn with_http_options(options: HttpClientOptions) -> crate::Result<Self> {
+        Ok(Self::new(http_client::new_http_client_with_options(
+            &options,
+        )?))
+    }
+
     /// Creates a new `TransportOptions` using the custom policy.
     ///
     /// This policy is expected to be the last policy in the pipeline.
@@ -322,9 +550,59 @@ impl TransportOptions {
     }
 }
 
+impl From<Arc<dyn HttpClient>> for TransportOptions {
+    fn from(http_client: Arc<dyn HttpClient>) -> Self {
+        Self::new(http_client)
+    }
+}
+
 impl Default for TransportOptions {
     /// Creates an instance of the `TransportOptions` using the default `HttpClient`.
     fn default() -> Self {
         Self::new(http_client::new_http_client())
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::headers::Headers;
+    use crate::{Context, Method, Pipeline, Request, Response, StatusCode};
+    use std::sync::atomic::{AtomicUsize, Ordering};
+
+    #[derive(Debug, Default)]
+    struct CountingClient {
+        requests: AtomicUsize,
+    }
+
+    #[async_trait::async_trait]
+    impl HttpClient for CountingClient {
+        async fn execute_request(&self, _request: &Request) -> crate::Result<Response> {
+            self.requests.fetch_add(1, Ordering::SeqCst);
+            Ok(Response::new(
+                StatusCode::Ok,
+                Headers::new(),
+                Box::pin(futures::stream::empty()),
+            ))
+        }
+    }
+
+    #[test]
+    fn shared_http_client_is_used_by_every_pipeline() {
+        let http_client = Arc::new(CountingClient::default());
+        let pipelines: Vec<Pipeline> = (0..2)
+            .map(|_| {
+                let shared: Arc<dyn HttpClient> = http_client.clone();
+                let options = ClientOptions::new(shared.into());
+                Pipeline::new(None, None, options, Vec::new(), Vec::new())
+            })
+            .collect();
+
+        for pipeline in &pipelines {
+            let mut request = Request::new("http://localhost".parse().unwrap(), Method::Get);
+            futures::executor::block_on(pipeline.send(&mut Context::new(), &mut request)).unwrap();
+        }
+
+        assert_eq!(http_client.requests.load(Ordering::SeqCst), 2);
+    }
+}
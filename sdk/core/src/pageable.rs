@@ -1,3 +1,6 @@
+use crate::error::{ErrorKind, ResultExt};
+use crate::headers::{self, Headers};
+use crate::{query_param, Url};
 use futures::stream::unfold;
 use futures::Stream;
 
@@ -115,3 +118,266 @@ enum State<T> {
     Continuation(T),
     Done,
 }
+
+/// Prefix that marks a continuation read from the `x-ms-continuation` response header, which is
+/// sent back as a request header rather than in the URL. It is checked before the continuation is
+/// parsed as a URL, since the prefix would otherwise parse as a URL scheme.
+const CONTINUATION_HEADER_PREFIX: &str = "x-ms-continuation:";
+
+/// Build the URL of the next page from the URL of the first page and a continuation value.
+///
+/// Services return the continuation either as a link to the next page, absolute or relative to
+/// the host (`nextLink`), or as a bare token that has to be sent as the `$skiptoken` query
+/// parameter of the original request. A token from the `x-ms-continuation` header, as returned by
+/// `continuation_from_headers`, leaves `url` unchanged; send it with `continuation_header`.
+///
+/// An absolute link is used as is. A relative link replaces the path and query of `url`, but the
+/// query parameters of `url` that the link does not set, such as `$filter` and `$select`, are
+/// carried over, so that later pages are filtered like the first one. `$skip` is not carried over,
+/// since the link already points past the items it skipped. A bare token replaces any
+/// `$skiptoken` already in the query of `url`.
+pub fn next_page_url(url: &Url, continuation: &str) -> crate::Result<Url> {
+    if continuation_header(continuation).is_some() {
+        return Ok(url.clone());
+    }
+    if let Ok(next) = Url::parse(continuation) {
+        return Ok(next);
+    }
+    let mut next = url.clone();
+    if continuation.starts_with('/') {
+        next.set_path("");
+        next.set_query(None);
+        let mut next = next
+            .join(continuation)
+            .with_context(ErrorKind::DataConversion, || {
+                format!("invalid next page link: {continuation}")
+            })?;
+        let missing: Vec<(String, String)> = url
+            .query_pairs()
+            .filter(|(name, _)| name != query_param::SKIP_TOKEN && name != query_param::SKIP)
+            .filter(|(name, _)| !next.query_pairs().any(|(next_name, _)| next_name == *name))
+            .map(|(name, value)| (name.into_owned(), value.into_owned()))
+            .collect();
+        if !missing.is_empty() {
+            next.query_pairs_mut().extend_pairs(missing);
+        }
+        return Ok(next);
+    }
+    let pairs: Vec<(String, String)> = url
+        .query_pairs()
+        .filter(|(name, _)| name != query_param::SKIP_TOKEN)
+        .map(|(name, value)| (name.into_owned(), value.into_owned()))
+        .collect();
+    next.query_pairs_mut()
+        .clear()
+        .extend_pairs(pairs)
+        .append_pair(query_param::SKIP_TOKEN, continuation);
+    Ok(next)
+}
+
+/// Get the continuation for the next page from the headers of a response, for services that page
+/// through headers instead of a next link in the body.
+///
+/// The target of a `Link` header entry with `rel="next"` is preferred over the token in
+/// `x-ms-continuation`. Either one can be passed to `next_page_url`; a token from
+/// `x-ms-continuation` is marked so that `continuation_header` returns it for the next request.
+/// Returns `None` if neither header names a next page.
+pub fn continuation_from_headers(headers: &Headers) -> Option<String> {
+    let next_link = headers
+        .get_optional_str(&headers::LINK)
+        .and_then(|link| link_values(link).find_map(|(target, is_next)| is_next.then_some(target)))
+        .filter(|target| !target.is_empty())
+        .map(ToOwned::to_owned);
+    next_link.or_else(|| {
+        headers
+            .get_optional_str(&headers::CONTINUATION)
+            .map(str::trim)
+            .filter(|continuation| !continuation.is_empty())
+            .map(|continuation| format!("{CONTINUATION_HEADER_PREFIX}{continuation}"))
+    })
+}
+
+/// Get the token to send in the `x-ms-continuation` header of the next request, if the
+/// continuation came from that header of the previous response.
+pub fn continuation_header(continuation: &str) -> Option<&str> {
+    continuation.strip_prefix(CONTINUATION_HEADER_PREFIX)
+}
+
+/// Iterate over the entries of a `Link` header, yielding the target of each with whether its
+/// `rel` parameter includes `next`.
+///
+/// The `<...>` target is read before the entry is split into parameters, since a URL can hold
+/// commas and semicolons, such as in `$select=id,name`. Quoted parameter values can too.
+fn link_values(mut link: &str) -> impl Iterator<Item = (&str, bool)> {
+    std::iter::from_fn(move || {
+        let (target, rest) = link
+            .trim_start_matches(|c: char| c == ',' || c.is_whitespace())
+            .strip_prefix('<')?
+            .split_once('>')?;
+        link = rest;
+        let mut is_next = false;
+        while let Some(param) = link.trim_start().strip_prefix(';') {
+            let (name, rest) = param.split_at(param.find(['=', ';', ',']).unwrap_or(param.len()));
+            link = rest;
+            let value = match link.strip_prefix('=').map(str::trim_start) {
+                Some(value) => match value.strip_prefix('"') {
+                    Some(quoted) => {
+                        let end = quoted.find('"').unwrap_or(quoted.len());
+                        link = quoted.get(end + 1..).unwrap_or("");
+                        &quoted[..end]
+                    }
+                    None => {
+                        let end = value.find([';', ',']).unwrap_or(value.len());
+                        link = &value[end..];
+                        value[..end].trim_end()
+                    }
+                },
+                None => "",
+            };
+            is_next |= name.trim().eq_ignore_ascii_case("rel")
+                && value
+                    .split_whitespace()
+                    .any(|rel| rel.eq_ignore_ascii_case("next"));
+        }
+        Some((target, is_next))
+    })
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn first_page() -> Url {
+        "https://management.azure.com/subscriptions/s/nodes?api-version=2020-01-13-preview&$top=10"
+            .parse()
+            .unwrap()
+    }
+
+    #[test]
+    fn absolute_next_link_is_used_as_is() {
+        let next = next_page_url(
+            &first_page(),
+            "https://management.azure.com/subscriptions/s/nodes?api-version=2020-01-13-preview&$skiptoken=abc",
+        )
+        .unwrap();
+        assert_eq!(
+            next.as_str(),
+            "https://management.azure.com/subscriptions/s/nodes?api-version=2020-01-13-preview&$skiptoken=abc"
+        );
+    }
+
+    #[test]
+    fn relative_next_link_replaces_path_and_query() {
+        let next = next_page_url(
+            &first_page(),
+            "/subscriptions/s/nodes?api-version=2020-01-13-preview&$top=10&$skiptoken=abc",
+        )
+        .unwrap();
+        assert_eq!(
+            next.as_str(),
+            "https://management.azure.com/subscriptions/s/nodes?api-version=2020-01-13-preview&$top=10&$skiptoken=abc"
+        );
+    }
+
+    #[test]
+    fn relative_next_link_keeps_the_filter_on_every_page() {
+        let mut url: Url = "https://management.azure.com/subscriptions/s/resourceGroups/rg/providers/Microsoft.Automation/automationAccounts/a/nodes?api-version=2020-01-13-preview&$filter=properties%2FnodeConfiguration%2Fname+eq+%27web%27&$select=id,name&$skip=20"
+            .parse()
+            .unwrap();
+        for token in ["page2", "page3"] {
+            url = next_page_url(
+                &url,
+                &format!("/subscriptions/s/resourceGroups/rg/providers/Microsoft.Automation/automationAccounts/a/nodes?$skiptoken={token}"),
+            )
+            .unwrap();
+            let pairs: Vec<(String, String)> = url.query_pairs().into_owned().collect();
+            let value = |name: &str| -> Vec<&str> {
+                pairs
+                    .iter()
+                    .filter(|(n, _)| n == name)
+                    .map(|(_, v)| v.as_str())
+                    .collect()
+            };
+            assert_eq!(value("$skiptoken"), [token]);
+            assert_eq!(
+                value("$filter"),
+                ["properties/nodeConfiguration/name eq 'web'"]
+            );
+            assert_eq!(value("$select"), ["id,name"]);
+            assert_eq!(value("api-version"), ["2020-01-13-preview"]);
+            assert!(value("$skip").is_empty());
+        }
+    }
+
+    #[test]
+    fn bare_skip_token_is_appended_to_the_original_query() {
+        let next = next_page_url(&first_page(), "abc/def=").unwrap();
+        assert_eq!(
+            next.as_str(),
+            "https://management.azure.com/subscriptions/s/nodes?api-version=2020-01-13-preview&%24top=10&%24skiptoken=abc%2Fdef%3D"
+        );
+        let again = next_page_url(&next, "ghi").unwrap();
+        assert_eq!(
+            again
+                .query_pairs()
+                .filter(|(name, _)| name == "$skiptoken")
+                .count(),
+            1
+        );
+    }
+
+    fn headers(pairs: &[(headers::HeaderName, &'static str)]) -> Headers {
+        let mut headers = Headers::new();
+        for (name, value) in pairs {
+            headers.insert(name.clone(), *value);
+        }
+        headers
+    }
+
+    #[test]
+    fn continuation_from_link_header() {
+        let headers = headers(&[(
+            headers::LINK,
+            r#"<https://management.azure.com/nodes?page=1>; rel="prev", <https://management.azure.com/nodes?page=3>; rel="next last""#,
+        )]);
+        assert_eq!(
+            continuation_from_headers(&headers).as_deref(),
+            Some("https://management.azure.com/nodes?page=3")
+        );
+        assert_eq!(
+            continuation_header("https://management.azure.com/nodes?page=3"),
+            None
+        );
+    }
+
+    #[test]
+    fn continuation_from_link_header_with_commas_in_the_target() {
+        let headers = headers(&[(
+            headers::LINK,
+            r#"<https://management.azure.com/nodes?$select=id,name;x&page=1>; rel="prev"; title="a, b", <https://management.azure.com/nodes?$select=id,name&page=3>; rel=next"#,
+        )]);
+        assert_eq!(
+            continuation_from_headers(&headers).as_deref(),
+            Some("https://management.azure.com/nodes?$select=id,name&page=3")
+        );
+    }
+
+    #[test]
+    fn continuation_from_continuation_header() {
+        let only_prev = headers(&[
+            (headers::LINK, "</nodes?page=1>; rel=prev"),
+            (headers::CONTINUATION, " abc "),
+        ]);
+        let continuation = continuation_from_headers(&only_prev).unwrap();
+        assert_eq!(continuation_header(&continuation), Some("abc"));
+        assert_eq!(
+            next_page_url(&first_page(), &continuation).unwrap(),
+            first_page()
+        );
+        assert_eq!(continuation_from_headers(&headers(&[])), None);
+        assert_eq!(
+            continuation_from_headers(&headers(&[(headers::CONTINUATION, "")])),
+            None
+        );
+    }
+}
@@ -1,3 +1,5 @@
+#[cfg(feature = "metrics")]
+use crate::policies::MetricsPolicy;
 use crate::policies::TransportPolicy;
 use crate::policies::{CustomHeadersPolicy, Policy, TelemetryPolicy};
 use crate::{ClientOptions, Context, Request, Response};
@@ -56,6 +58,12 @@ impl Pipeline {
                 + 3,
         );
 
+        #[cfg(feature = "metrics")]
+        if let Some(meter_provider) = &options.metrics.meter_provider {
+            let metrics_policy = MetricsPolicy::new(meter_provider.as_ref(), crate_name);
+            pipeline.push(Arc::new(metrics_policy));
+        }
+
         pipeline.extend_from_slice(&per_call_policies);
         pipeline.extend_from_slice(&options.per_call_policies);
 
@@ -0,0 +1,129 @@
+use crate::policies::{Policy, PolicyResult};
+use crate::{Context, OperationName, Request};
+use opentelemetry::metrics::{Counter, Histogram, MeterProvider, Unit};
+use opentelemetry::KeyValue;
+use std::sync::Arc;
+use std::time::Instant;
+
+/// Records OpenTelemetry metrics for every call sent through a pipeline.
+///
+/// Three instruments are recorded, each labeled with `operation` (taken from the
+/// [`OperationName`] in the request context, or `"unknown"`) and `status` (the numeric status
+/// code of the final response, or `"error"` when no response was received):
+///
+/// * `requests_total`: a counter of calls.
+/// * `request_duration_seconds`: a histogram of call durations, including retries.
+/// * `errors_total`: a counter of calls that failed or returned a non-success status.
+///
+/// The pipeline installs it first when a meter provider is set with
+/// [`ClientOptions::meter_provider`](crate::ClientOptions::meter_provider).
+#[derive(Clone)]
+pub struct MetricsPolicy {
+    requests: Counter<u64>,
+    duration: Histogram<f64>,
+    errors: Counter<u64>,
+}
+
+impl MetricsPolicy {
+    pub fn new(
+        meter_provider: &(dyn MeterProvider + Send + Sync),
+        crate_name: Option<&'static str>,
+    ) -> Self {
+        let meter = meter_provider.versioned_meter(crate_name.unwrap_or("azure_core"), None, None);
+        Self {
+            requests: meter
+                .u64_counter("requests_total")
+                .with_description("Number of calls sent")
+                .init(),
+            duration: meter
+                .f64_histogram("request_duration_seconds")
+                .with_description("Duration of calls, including retries")
+                .with_unit(Unit::new("s"))
+                .init(),
+            errors: meter
+                .u64_counter("errors_total")
+                .with_description("Number of calls that failed or returned a non-success status")
+                .init(),
+        }
+    }
+}
+
+impl std::fmt::Debug for MetricsPolicy {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.debug_struct("MetricsPolicy").finish_non_exhaustive()
+    }
+}
+
+#[cfg_attr(target_arch = "wasm32", async_trait::async_trait(?Send))]
+#[cfg_attr(not(target_arch = "wasm32"), async_trait::async_trait)]
+impl Policy for MetricsPolicy {
+    async fn send(
+        &self,
+        ctx: &Context,
+        request: &mut Request,
+        next: &[Arc<dyn Policy>],
+    ) -> PolicyResult {
+        let start = Instant::now();
+        let response = next[0].send(ctx, request, &next[1..]).await;
+        let elapsed = start.elapsed().as_secs_f64();
+
+        let operation = ctx.get::<OperationName>().map_or("unknown", |name| name.0);
+        let (status, failed) = match &response {
+            Ok(response) => {
+                let status = response.status();
+                ((status as u16).to_string(), !status.is_success())
+            }
+            Err(_) => ("error".to_owned(), true),
+        };
+        let attributes = [
+            KeyValue::new("operation", operation),
+            KeyValue::new("status", status),
+        ];
+        let cx = opentelemetry::Context::current();
+        self.requests.add(&cx, 1, &attributes);
+        self.duration.record(&cx, elapsed, &attributes);
+        if failed {
+            self.errors.add(&cx, 1, &attributes);
+        }
+
+        response
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::headers::Headers;
+    use crate::{Method, Response, StatusCode};
+    use opentelemetry::metrics::noop::NoopMeterProvider;
+
+    #[derive(Debug)]
+    struct StatusTransport(StatusCode);
+
+    #[async_trait::async_trait]
+    impl Policy for StatusTransport {
+        async fn send(
+            &self,
+            _ctx: &Context,
+            _request: &mut Request,
+            _next: &[Arc<dyn Policy>],
+        ) -> PolicyResult {
+            Ok(Response::new(
+                self.0,
+                Headers::new(),
+                Box::pin(futures::stream::empty()),
+            ))
+        }
+    }
+
+    #[test]
+    fn response_is_passed_through() {
+        let policy = MetricsPolicy::new(&NoopMeterProvider::new(), Some("test"));
+        let next: Vec<Arc<dyn Policy>> = vec![Arc::new(StatusTransport(StatusCode::NotFound))];
+        let mut ctx = Context::new();
+        ctx.insert(OperationName("accounts::get"));
+        let mut request = Request::new("http://localhost".parse().unwrap(), Method::Get);
+        let response = futures::executor::block_on(policy.send(&ctx, &mut request, &next)).unwrap();
+        assert_eq!(response.status(), StatusCode::NotFound);
+    }
+}
@@ -1,10 +1,16 @@
 mod custom_headers_policy;
+#[cfg(feature = "metrics")]
+mod metrics_policy;
+mod request_timeout_policy;
 mod retry_policies;
 mod telemetry_policy;
 mod timeout_policy;
 mod transport;
 
 pub use custom_headers_policy::{CustomHeaders, CustomHeadersPolicy};
+#[cfg(feature = "metrics")]
+pub use metrics_policy::MetricsPolicy;
+pub use request_timeout_policy::RequestTimeoutPolicy;
 pub use retry_policies::*;
 pub use telemetry_policy::*;
 pub use timeout_policy::*;
@@ -0,0 +1,131 @@
+use crate::error::{Error, ErrorKind};
+use crate::policies::{Policy, PolicyResult};
+use crate::sleep::sleep;
+use crate::{Context, Request};
+use futures::future::{select, Either};
+use std::sync::Arc;
+use std::time::Duration;
+
+/// Fails a request attempt that does not produce a response within the given duration.
+///
+/// Unlike [`TimeoutPolicy`](crate::TimeoutPolicy), which only forwards a server side timeout
+/// as a query parameter, this policy bounds the time the client waits for the following
+/// policies to return a response. Only the time to receive the response head is bounded;
+/// streaming the body is not.
+///
+/// Inside a Tokio runtime, with the `tokio` feature enabled, the timeout is a Tokio timer, which
+/// the runtime's timer driver must be enabled for. Elsewhere it is a [`sleep`], whose thread
+/// ends as soon as the response arrives.
+///
+/// When installed as a per-retry policy, the timeout applies to every attempt individually.
+/// The resulting error is of kind [`ErrorKind::Io`], so the retry policy treats it like any
+/// other transient IO failure.
+#[derive(Debug, Clone)]
+pub struct RequestTimeoutPolicy {
+    timeout: Duration,
+}
+
+impl RequestTimeoutPolicy {
+    pub fn new(timeout: Duration) -> Self {
+        Self { timeout }
+    }
+}
+
+#[cfg_attr(target_arch = "wasm32", async_trait::async_trait(?Send))]
+#[cfg_attr(not(target_arch = "wasm32"), async_trait::async_trait)]
+impl Policy for RequestTimeoutPolicy {
+    async fn send(
+        &self,
+        ctx: &Context,
+        request: &mut Request,
+        next: &[Arc<dyn Policy>],
+    ) -> PolicyResult {
+        let response = next[0].send(ctx, request, &next[1..]);
+        #[cfg(feature = "tokio")]
+        if tokio::runtime::Handle::try_current().is_ok() {
+            return tokio::time::timeout(self.timeout, response)
+                .await
+                .unwrap_or_else(|_| Err(self.timed_out()));
+        }
+        match select(response, sleep(self.timeout)).await {
+            Either::Left((response, _)) => response,
+            Either::Right(((), _)) => Err(self.timed_out()),
+        }
+    }
+}
+
+impl RequestTimeoutPolicy {
+    fn timed_out(&self) -> Error {
+        log::debug!("request timed out after {:?}", self.timeout);
+        Error::new(
+            ErrorKind::Io,
+            std::io::Error::new(
+                std::io::ErrorKind::TimedOut,
+                format!("request timed out after {:?}", self.timeout),
+            ),
+        )
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::headers::Headers;
+    use crate::{Method, Response, StatusCode};
+
+    #[derive(Debug)]
+    struct DelayedTransport(Duration);
+
+    #[async_trait::async_trait]
+    impl Policy for DelayedTransport {
+        async fn send(
+            &self,
+            _ctx: &Context,
+            _request: &mut Request,
+            _next: &[Arc<dyn Policy>],
+        ) -> PolicyResult {
+            sleep(self.0).await;
+            Ok(Response::new(
+                StatusCode::Ok,
+                Headers::new(),
+                Box::pin(futures::stream::empty()),
+            ))
+        }
+    }
+
+    fn send_with(timeout: Duration, delay: Duration) -> PolicyResult {
+        let policy = RequestTimeoutPolicy::new(timeout);
+        let next: Vec<Arc<dyn Policy>> = vec![Arc::new(DelayedTransport(delay))];
+        let mut request = Request::new("http://localhost".parse().unwrap(), Method::Get);
+        futures::executor::block_on(policy.send(&Context::new(), &mut request, &next))
+    }
+
+    #[test]
+    fn fast_response_is_returned() {
+        let response = send_with(Duration::from_secs(5), Duration::from_millis(1)).unwrap();
+        assert_eq!(response.status(), StatusCode::Ok);
+    }
+
+    #[test]
+    fn slow_response_times_out() {
+        let error = send_with(Duration::from_millis(10), Duration::from_secs(2)).unwrap_err();
+        assert_eq!(error.kind(), &ErrorKind::Io);
+        let io_error = error.into_downcast::<std::io::Error>().unwrap();
+        assert_eq!(io_error.kind(), std::io::ErrorKind::TimedOut);
+    }
+
+    #[cfg(feature = "tokio")]
+    #[tokio::test]
+    async fn fast_response_does_not_wait_for_the_timeout() {
+        let policy = RequestTimeoutPolicy::new(Duration::from_secs(3600));
+        let next: Vec<Arc<dyn Policy>> = vec![Arc::new(DelayedTransport(Duration::ZERO))];
+        let mut request = Request::new("http://localhost".parse().unwrap(), Method::Get);
+        let start = std::time::Instant::now();
+        let response = policy
+            .send(&Context::new(), &mut request, &next)
+            .await
+            .unwrap();
+        assert_eq!(response.status(), StatusCode::Ok);
+        assert!(start.elapsed() < Duration::from_secs(1));
+    }
+}
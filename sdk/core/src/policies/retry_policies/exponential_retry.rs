@@ -43,6 +43,10 @@ impl RetryPolicy for ExponentialRetryPolicy {
         let sleep_ms = sleep_ms.min(self.max_delay.as_millis().try_into().unwrap_or(u64::MAX));
         Duration::from_millis(sleep_ms)
     }
+
+    fn max_delay(&self) -> Duration {
+        self.max_delay
+    }
 }
 
 #[cfg(test)]
@@ -1,9 +1,12 @@
 mod exponential_retry;
 mod fixed_retry;
 mod no_retry;
+mod retry_budget;
 mod retry_policy;
 
 pub use exponential_retry::*;
 pub use fixed_retry::*;
 pub use no_retry::*;
-pub use retry_policy::RetryPolicy;
+pub use retry_budget::RetryBudget;
+pub(crate) use retry_policy::IdempotentOnly;
+pub use retry_policy::{Idempotent, RetryPolicy};
@@ -0,0 +1,57 @@
+use std::sync::{Arc, Mutex};
+use std::time::Instant;
+
+/// A token bucket that bounds the rate of retries across all requests that share it.
+///
+/// Every retry takes one token from the bucket, which refills at a fixed rate up to its
+/// capacity. When the bucket is empty the retry policy gives up and returns the last error
+/// instead of retrying, so a broad outage does not multiply the load on the service. First
+/// attempts never take tokens.
+///
+/// Clones share the same bucket. The retry policy consults the budget found in the request
+/// [`Context`](crate::Context); requests without one are retried as usual.
+#[derive(Debug, Clone)]
+pub struct RetryBudget {
+    capacity: f64,
+    refill_per_second: f64,
+    bucket: Arc<Mutex<Bucket>>,
+}
+
+#[derive(Debug)]
+struct Bucket {
+    tokens: f64,
+    refilled_at: Instant,
+}
+
+impl RetryBudget {
+    /// Create a full budget of `capacity` retries that refills at `refill_per_second` retries
+    /// per second.
+    pub fn new(capacity: u32, refill_per_second: f64) -> Self {
+        Self {
+            capacity: f64::from(capacity),
+            refill_per_second,
+            bucket: Arc::new(Mutex::new(Bucket {
+                tokens: f64::from(capacity),
+                refilled_at: Instant::now(),
+            })),
+        }
+    }
+
+    /// Take a token for one retry. Returns `false` if the budget is exhausted.
+    pub fn try_acquire(&self) -> bool {
+        let mut bucket = match self.bucket.lock() {
+            Ok(bucket) => bucket,
+            Err(poisoned) => poisoned.into_inner(),
+        };
+        let now = Instant::now();
+        let elapsed = now.duration_since(bucket.refilled_at).as_secs_f64();
+        bucket.tokens = (bucket.tokens + elapsed * self.refill_per_second).min(self.capacity);
+        bucket.refilled_at = now;
+        if bucket.tokens >= 1.0 {
+            bucket.tokens -= 1.0;
+            true
+        } else {
+            false
+        }
+    }
+}
@@ -1,7 +1,7 @@
 use crate::error::{Error, ErrorKind, HttpError};
-use crate::policies::{Policy, PolicyResult, Request};
+use crate::policies::{Policy, PolicyResult, Request, RetryBudget};
 use crate::sleep::sleep;
-use crate::{Context, StatusCode};
+use crate::{Context, Method, StatusCode};
 
 use async_trait::async_trait;
 use time::OffsetDateTime;
@@ -25,13 +25,110 @@ pub trait RetryPolicy: std::fmt::Debug + Send + Sync {
     fn is_expired(&self, duration_since_start: Duration, retry_count: u32) -> bool;
     /// Determine how long before the next retry should be attempted.
     fn sleep_duration(&self, retry_count: u32) -> Duration;
+    /// The longest the policy waits before a retry when the service asks for a longer wait with a
+    /// `Retry-After` header.
+    ///
+    /// The default is no limit other than the time left before the policy expires.
+    fn max_delay(&self) -> Duration {
+        Duration::MAX
+    }
+    /// Determine if a request with a method that is not idempotent is retried after an IO error.
+    ///
+    /// The default is `true`. When this returns `false`, such a request is only retried if it is
+    /// marked [`Idempotent`].
+    fn retries_non_idempotent(&self) -> bool {
+        true
+    }
     /// A Future that will wait until the request can be retried.
     /// `error` is the [`Error`] value the led to a retry attempt.
-    async fn wait(&self, _error: &Error, retry_count: u32) {
-        sleep(self.sleep_duration(retry_count)).await;
+    ///
+    /// If the failed response carried a `Retry-After` header, the wait is the longer of the time
+    /// it asks for, capped at `max_delay`, and `sleep_duration`, so the service is not retried
+    /// before it is ready.
+    async fn wait(&self, error: &Error, retry_count: u32) {
+        let sleep_duration = self.sleep_duration(retry_count);
+        sleep(
+            retry_after(self, error).map_or(sleep_duration, |retry_after| {
+                retry_after.max(sleep_duration)
+            }),
+        )
+        .await;
+    }
+}
+
+/// The wait asked for by the `Retry-After` header of the response that failed with `error`, capped
+/// at the policy's `max_delay`.
+fn retry_after<P>(policy: &P, error: &Error) -> Option<Duration>
+where
+    P: RetryPolicy + ?Sized,
+{
+    error
+        .as_http_error()
+        .and_then(HttpError::retry_after)
+        .map(|retry_after| retry_after.min(policy.max_delay()))
+}
+
+/// Marks a request as safe to retry after a connection error.
+///
+/// Requests with an idempotent method (`GET`, `HEAD`, `OPTIONS`, `TRACE`, `PUT` and `DELETE`)
+/// are retried after IO errors such as a dropped connection or a timed out attempt. A `POST` or
+/// `PATCH` may already have been applied by the service when the connection failed, so
+/// retrying it could repeat a side effect. With
+/// [`RetryOptions::idempotent_only`](crate::RetryOptions::idempotent_only), such requests are
+/// only retried after an IO error when this marker is present in the [`Context`].
+///
+/// Retries on status codes listed in `RETRY_STATUSES` are not affected, since the service
+/// answered and its status says whether the request may be repeated.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct Idempotent;
+
+/// A retry policy that only retries requests with an idempotent method, or marked
+/// [`Idempotent`], after IO errors.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub(crate) struct IdempotentOnly<P> {
+    policy: P,
+}
+
+impl<P> IdempotentOnly<P> {
+    pub(crate) fn new(policy: P) -> Self {
+        Self { policy }
+    }
+}
+
+impl<P> RetryPolicy for IdempotentOnly<P>
+where
+    P: RetryPolicy,
+{
+    fn is_expired(&self, duration_since_start: Duration, retry_count: u32) -> bool {
+        self.policy.is_expired(duration_since_start, retry_count)
+    }
+
+    fn sleep_duration(&self, retry_count: u32) -> Duration {
+        self.policy.sleep_duration(retry_count)
+    }
+
+    fn max_delay(&self) -> Duration {
+        self.policy.max_delay()
+    }
+
+    fn retries_non_idempotent(&self) -> bool {
+        false
     }
 }
 
+fn is_idempotent(ctx: &Context, request: &Request) -> bool {
+    ctx.get::<Idempotent>().is_some()
+        || matches!(
+            request.method(),
+            Method::Get
+                | Method::Head
+                | Method::Options
+                | Method::Trace
+                | Method::Put
+                | Method::Delete
+        )
+}
+
 /// The status codes where a retry should be attempted.
 ///
 /// On all other 4xx and 5xx status codes no retry is attempted.
@@ -104,7 +201,18 @@ where
                     Error::new(error_kind, http_error)
                 }
                 Err(error) => {
-                    if error.kind() == &ErrorKind::Io {
+                    if error.kind() == &ErrorKind::Io
+                        && !self.retries_non_idempotent()
+                        && !is_idempotent(ctx, request)
+                    {
+                        log::debug!(
+                            "io error occurred on a non-idempotent request which will not be retried: {}",
+                            error
+                        );
+                        return Err(error.context(
+                            "io error occurred on a non-idempotent request which will not be retried",
+                        ));
+                    } else if error.kind() == &ErrorKind::Io {
                         log::debug!(
                             "io error occurred when making request which will be retried: {}",
                             error
@@ -125,9 +233,156 @@ where
                 return Err(last_error
                     .context("retry policy expired and the request will no longer be retried"));
             }
+            if let Some(retry_after) = retry_after(self, &last_error) {
+                if self.is_expired(time_since_start + retry_after, retry_count) {
+                    return Err(last_error.context(
+                        "the service asked to wait longer than the retry policy allows and the request will no longer be retried",
+                    ));
+                }
+            }
+            if let Some(budget) = ctx.get::<RetryBudget>() {
+                if !budget.try_acquire() {
+                    log::debug!("retry budget exhausted: {}", last_error);
+                    return Err(last_error
+                        .context("retry budget exhausted and the request will not be retried"));
+                }
+            }
             retry_count += 1;
 
             self.wait(&last_error, retry_count).await;
         }
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::policies::retry_policies::{ExponentialRetryPolicy, FixedRetryPolicy};
+    use std::sync::atomic::{AtomicU32, Ordering};
+
+    #[derive(Debug, Default)]
+    struct FailingTransport {
+        attempts: AtomicU32,
+    }
+
+    #[async_trait]
+    impl Policy for FailingTransport {
+        async fn send(
+            &self,
+            _ctx: &Context,
+            _request: &mut Request,
+            _next: &[Arc<dyn Policy>],
+        ) -> PolicyResult {
+            self.attempts.fetch_add(1, Ordering::SeqCst);
+            Err(Error::new(
+                ErrorKind::Io,
+                std::io::Error::from(std::io::ErrorKind::ConnectionReset),
+            ))
+        }
+    }
+
+    /// Answers every request with `503 Service Unavailable`, asking to retry after an hour.
+    #[derive(Debug, Default)]
+    struct ThrottlingTransport {
+        attempts: AtomicU32,
+    }
+
+    #[async_trait]
+    impl Policy for ThrottlingTransport {
+        async fn send(
+            &self,
+            _ctx: &Context,
+            _request: &mut Request,
+            _next: &[Arc<dyn Policy>],
+        ) -> PolicyResult {
+            self.attempts.fetch_add(1, Ordering::SeqCst);
+            let mut headers = crate::headers::Headers::new();
+            headers.insert(crate::headers::RETRY_AFTER, "3600");
+            Ok(crate::Response::new(
+                StatusCode::ServiceUnavailable,
+                headers,
+                Box::pin(futures::stream::empty()),
+            ))
+        }
+    }
+
+    fn throttled_attempts(policy: impl RetryPolicy) -> u32 {
+        let transport = Arc::new(ThrottlingTransport::default());
+        let next: Vec<Arc<dyn Policy>> = vec![transport.clone()];
+        let mut request = Request::new("http://localhost".parse().unwrap(), Method::Get);
+        let result = futures::executor::block_on(policy.send(&Context::new(), &mut request, &next));
+        assert!(result.is_err());
+        transport.attempts.load(Ordering::SeqCst)
+    }
+
+    #[test]
+    fn retry_after_beyond_the_elapsed_budget_is_not_waited_for() {
+        let policy = FixedRetryPolicy::new(Duration::ZERO, 2, Duration::from_secs(60));
+        assert_eq!(throttled_attempts(policy), 1);
+    }
+
+    #[test]
+    fn retry_after_is_capped_at_the_max_delay() {
+        let policy = ExponentialRetryPolicy::new(
+            Duration::ZERO,
+            1,
+            Duration::from_secs(7_200),
+            Duration::from_secs(1),
+        );
+        assert_eq!(throttled_attempts(policy), 2);
+    }
+
+    fn attempts(method: Method, ctx: &Context) -> u32 {
+        let policy = FixedRetryPolicy::new(Duration::ZERO, 2, Duration::from_secs(60));
+        attempts_with(policy, method, ctx)
+    }
+
+    fn idempotent_only_attempts(method: Method, ctx: &Context) -> u32 {
+        let policy = FixedRetryPolicy::new(Duration::ZERO, 2, Duration::from_secs(60));
+        attempts_with(IdempotentOnly::new(policy), method, ctx)
+    }
+
+    fn attempts_with(policy: impl RetryPolicy, method: Method, ctx: &Context) -> u32 {
+        let transport = Arc::new(FailingTransport::default());
+        let next: Vec<Arc<dyn Policy>> = vec![transport.clone()];
+        let mut request = Request::new("http://localhost".parse().unwrap(), method);
+        let result = futures::executor::block_on(policy.send(ctx, &mut request, &next));
+        assert!(result.is_err());
+        transport.attempts.load(Ordering::SeqCst)
+    }
+
+    #[test]
+    fn all_methods_are_retried_after_io_errors_by_default() {
+        assert_eq!(attempts(Method::Get, &Context::new()), 3);
+        assert_eq!(attempts(Method::Post, &Context::new()), 3);
+        assert_eq!(attempts(Method::Patch, &Context::new()), 3);
+    }
+
+    #[test]
+    fn idempotent_only_retries_idempotent_methods_after_io_errors() {
+        assert_eq!(idempotent_only_attempts(Method::Get, &Context::new()), 3);
+        assert_eq!(idempotent_only_attempts(Method::Put, &Context::new()), 3);
+        assert_eq!(idempotent_only_attempts(Method::Delete, &Context::new()), 3);
+    }
+
+    #[test]
+    fn idempotent_only_does_not_retry_non_idempotent_methods_after_io_errors() {
+        assert_eq!(idempotent_only_attempts(Method::Post, &Context::new()), 1);
+        assert_eq!(idempotent_only_attempts(Method::Patch, &Context::new()), 1);
+    }
+
+    #[test]
+    fn idempotent_marker_enables_retries() {
+        let mut ctx = Context::new();
+        ctx.insert(Idempotent);
+        assert_eq!(idempotent_only_attempts(Method::Post, &ctx), 3);
+    }
+
+    #[test]
+    fn exhausted_retry_budget_fails_fast() {
+        let mut ctx = Context::new();
+        ctx.insert(RetryBudget::new(1, 0.0));
+        assert_eq!(attempts(Method::Get, &ctx), 2);
+        assert_eq!(attempts(Method::Get, &ctx), 1);
+    }
+}
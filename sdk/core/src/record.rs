@@ -0,0 +1,293 @@
+//! Recording and playback of HTTP exchanges.
+//!
+//! [`RecordingPolicy`] writes every request/response pair that passes through a pipeline to a
+//! sink as one JSON object per line. [`PlaybackTransport`] reads those lines back and answers
+//! requests with the recorded responses, so a client can run without network access.
+//!
+//! The `Authorization` header is never written to a recording.
+
+use crate::error::{Error, ErrorKind, ResultExt};
+use crate::headers::{self, Headers};
+use crate::policies::{Policy, PolicyResult};
+use crate::{Body, Context, HttpClient, Request, Response, StatusCode};
+use bytes::Bytes;
+use serde::{Deserialize, Serialize};
+use std::collections::BTreeMap;
+use std::io::{BufRead, Write};
+use std::sync::{Arc, Mutex};
+
+/// A single recorded request/response pair.
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub struct Recording {
+    pub method: String,
+    /// The path and query of the request URL. The host is not recorded, so a recording can be
+    /// played back against any endpoint.
+    pub path: String,
+    pub request_headers: BTreeMap<String, String>,
+    pub request_body: String,
+    pub status: u16,
+    pub response_headers: BTreeMap<String, String>,
+    pub response_body: String,
+}
+
+fn to_map(headers: &Headers) -> BTreeMap<String, String> {
+    headers
+        .iter()
+        .filter(|(name, _)| **name != headers::AUTHORIZATION)
+        .map(|(name, value)| (name.as_str().to_owned(), value.as_str().to_owned()))
+        .collect()
+}
+
+fn request_body(request: &Request) -> String {
+    match request.body() {
+        Body::Bytes(bytes) => String::from_utf8_lossy(bytes).into_owned(),
+        Body::SeekableStream(_) => String::new(),
+    }
+}
+
+/// Writes each request/response pair to a sink, one JSON [`Recording`] per line.
+///
+/// Install it as the last per-retry policy so it records the request exactly as the transport
+/// sends it. The response body is read in full before it is handed back to the caller.
+#[derive(Clone)]
+pub struct RecordingPolicy {
+    sink: Arc<Mutex<dyn Write + Send>>,
+}
+
+impl RecordingPolicy {
+    pub fn new(sink: impl Write + Send + 'static) -> Self {
+        Self {
+            sink: Arc::new(Mutex::new(sink)),
+        }
+    }
+}
+
+impl std::fmt::Debug for RecordingPolicy {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.debug_struct("RecordingPolicy").finish_non_exhaustive()
+    }
+}
+
+#[cfg_attr(target_arch = "wasm32", async_trait::async_trait(?Send))]
+#[cfg_attr(not(target_arch = "wasm32"), async_trait::async_trait)]
+impl Policy for RecordingPolicy {
+    async fn send(
+        &self,
+        ctx: &Context,
+        request: &mut Request,
+        next: &[Arc<dyn Policy>],
+    ) -> PolicyResult {
+        let response = next[0].send(ctx, request, &next[1..]).await?;
+        let (status, response_headers, body) = response.deconstruct();
+        let body = body.collect().await?;
+
+        let recording = Recording {
+            method: request.method().to_string(),
+            path: request.path_and_query(),
+            request_headers: to_map(request.headers()),
+            request_body: request_body(request),
+            status: status as u16,
+            response_headers: to_map(&response_headers),
+            response_body: String::from_utf8_lossy(&body).into_owned(),
+        };
+        let mut line = serde_json::to_vec(&recording)?;
+        line.push(b'\n');
+        {
+            let mut sink = self
+                .sink
+                .lock()
+                .map_err(|_| Error::message(ErrorKind::Io, "recording sink lock is poisoned"))?;
+            sink.write_all(&line)
+                .and_then(|_| sink.flush())
+                .context(ErrorKind::Io, "failed to write recording")?;
+        }
+
+        Ok(Response::new(
+            status,
+            response_headers,
+            Box::pin(futures::stream::once(async move { Ok(body) })),
+        ))
+    }
+}
+
+/// An [`HttpClient`] that answers requests from recordings made by [`RecordingPolicy`].
+///
+/// A request matches a recording when the method, path and body are equal. Each recording is
+/// used once, in the order it was recorded, so repeated identical requests get successive
+/// responses. A request without an unused match fails with [`ErrorKind::MockFramework`].
+#[derive(Debug)]
+pub struct PlaybackTransport {
+    recordings: Mutex<Vec<Option<Recording>>>,
+}
+
+impl PlaybackTransport {
+    pub fn new(recordings: impl IntoIterator<Item = Recording>) -> Self {
+        Self {
+            recordings: Mutex::new(recordings.into_iter().map(Some).collect()),
+        }
+    }
+
+    /// Read recordings written by [`RecordingPolicy`], one JSON object per line.
+    pub fn from_reader(reader: impl BufRead) -> crate::Result<Self> {
+        let mut recordings = Vec::new();
+        for line in reader.lines() {
+            let line = line.context(ErrorKind::Io, "failed to read recording")?;
+            if line.trim().is_empty() {
+                continue;
+            }
+            recordings.push(serde_json::from_str(&line)?);
+        }
+        Ok(Self::new(recordings))
+    }
+}
+
+#[cfg_attr(target_arch = "wasm32", async_trait::async_trait(?Send))]
+#[cfg_attr(not(target_arch = "wasm32"), async_trait::async_trait)]
+impl HttpClient for PlaybackTransport {
+    async fn execute_request(&self, request: &Request) -> crate::Result<Response> {
+        let method = request.method().to_string();
+        let path = request.path_and_query();
+        let body = request_body(request);
+        let recording = {
+            let mut recordings = self.recordings.lock().map_err(|_| {
+                Error::message(ErrorKind::MockFramework, "recordings lock is poisoned")
+            })?;
+            recordings
+                .iter_mut()
+                .find(|recording| {
+                    recording.as_ref().map_or(false, |recording| {
+                        recording.method == method
+                            && recording.path == path
+                            && recording.request_body == body
+                    })
+                })
+                .and_then(Option::take)
+        }
+        .ok_or_else(|| {
+            Error::with_message(ErrorKind::MockFramework, || {
+                format!("no recorded response for {method} {path}")
+            })
+        })?;
+
+        let status = StatusCode::try_from(recording.status).map_err(|_| {
+            Error::with_message(ErrorKind::MockFramework, || {
+                format!("invalid recorded status code {}", recording.status)
+            })
+        })?;
+        let mut headers = Headers::new();
+        for (name, value) in recording.response_headers {
+            headers.insert(name, value);
+        }
+        let body = Bytes::from(recording.response_body);
+        Ok(Response::new(
+            status,
+            headers,
+            Box::pin(futures::stream::once(async move { Ok(body) })),
+        ))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{Method, TransportOptions, TransportPolicy};
+
+    #[derive(Debug)]
+    struct EchoTransport;
+
+    #[async_trait::async_trait]
+    impl Policy for EchoTransport {
+        async fn send(
+            &self,
+            _ctx: &Context,
+            request: &mut Request,
+            _next: &[Arc<dyn Policy>],
+        ) -> PolicyResult {
+            let mut headers = Headers::new();
+            headers.insert("x-ms-request-id", "abc");
+            let body = Bytes::from(request_body(request));
+            Ok(Response::new(
+                StatusCode::Created,
+                headers,
+                Box::pin(futures::stream::once(async move { Ok(body) })),
+            ))
+        }
+    }
+
+    #[derive(Clone, Default)]
+    struct SharedSink(Arc<Mutex<Vec<u8>>>);
+
+    impl Write for SharedSink {
+        fn write(&mut self, buf: &[u8]) -> std::io::Result<usize> {
+            self.0.lock().unwrap().write(buf)
+        }
+
+        fn flush(&mut self) -> std::io::Result<()> {
+            Ok(())
+        }
+    }
+
+    fn put_request() -> Request {
+        let mut request = Request::new(
+            "https://management.azure.com/accounts/a?api-version=1"
+                .parse()
+                .unwrap(),
+            Method::Put,
+        );
+        request.insert_header(headers::AUTHORIZATION, "Bearer secret");
+        request.set_body(Bytes::from_static(br#"{"location":"westus"}"#));
+        request
+    }
+
+    #[test]
+    fn recording_round_trips_through_playback() {
+        let sink = SharedSink::default();
+        let policy = RecordingPolicy::new(sink.clone());
+        let next: Vec<Arc<dyn Policy>> = vec![Arc::new(EchoTransport)];
+        let response = futures::executor::block_on(async {
+            let response = policy
+                .send(&Context::new(), &mut put_request(), &next)
+                .await
+                .unwrap();
+            response.into_body().collect().await.unwrap()
+        });
+        assert_eq!(response, Bytes::from_static(br#"{"location":"westus"}"#));
+
+        let recorded = sink.0.lock().unwrap().clone();
+        assert!(!String::from_utf8_lossy(&recorded).contains("secret"));
+
+        let playback = PlaybackTransport::from_reader(recorded.as_slice()).unwrap();
+        let transport = TransportPolicy::new(TransportOptions::new(Arc::new(playback)));
+        let (status, headers, body) = futures::executor::block_on(async {
+            let response = transport
+                .send(&Context::new(), &mut put_request(), &[])
+                .await
+                .unwrap();
+            let (status, headers, body) = response.deconstruct();
+            (status, headers, body.collect().await.unwrap())
+        });
+        assert_eq!(status, StatusCode::Created);
+        assert_eq!(headers.get_str(&"x-ms-request-id".into()).unwrap(), "abc");
+        assert_eq!(body, Bytes::from_static(br#"{"location":"westus"}"#));
+    }
+
+    #[test]
+    fn playback_uses_each_recording_once() {
+        let playback = PlaybackTransport::new(vec![Recording {
+            method: "GET".to_owned(),
+            path: "/accounts/a".to_owned(),
+            request_headers: BTreeMap::new(),
+            request_body: String::new(),
+            status: 200,
+            response_headers: BTreeMap::new(),
+            response_body: String::new(),
+        }]);
+        let request = Request::new(
+            "https://example.com/accounts/a".parse().unwrap(),
+            Method::Get,
+        );
+        futures::executor::block_on(playback.execute_request(&request)).unwrap();
+        let error = futures::executor::block_on(playback.execute_request(&request)).unwrap_err();
+        assert_eq!(error.kind(), &ErrorKind::MockFramework);
+    }
+}
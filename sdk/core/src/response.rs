@@ -13,6 +13,7 @@ pub struct Response {
     status: StatusCode,
     headers: Headers,
     body: ResponseBody,
+    elapsed: Option<std::time::Duration>,
 }
 
 impl Response {
@@ -21,9 +22,23 @@ impl Response {
             status,
             headers,
             body: ResponseBody::new(stream),
+            elapsed: None,
         }
     }
 
+    /// Record how long it took from sending the request until this response was received.
+    #[must_use]
+    pub fn with_elapsed(mut self, elapsed: std::time::Duration) -> Self {
+        self.elapsed = Some(elapsed);
+        self
+    }
+
+    /// Get how long it took from sending the request until this response was received, if the
+    /// sender recorded it with `with_elapsed`.
+    pub fn elapsed(&self) -> Option<std::time::Duration> {
+        self.elapsed
+    }
+
     /// Get the status code from the response.
     pub fn status(&self) -> StatusCode {
         self.status
@@ -34,6 +49,15 @@ impl Response {
         &self.headers
     }
 
+    /// Get how long the service asked the client to wait before sending another request, from the
+    /// `Retry-After` header.
+    ///
+    /// Both the delta-seconds and the HTTP date forms are understood, the latter relative to the
+    /// `Date` of the response. Returns `None` if the header is missing or malformed.
+    pub fn retry_after(&self) -> Option<std::time::Duration> {
+        crate::headers::retry_after_from_headers_optional(&self.headers)
+    }
+
     /// Deconstruct the HTTP response into its components.
     pub fn deconstruct(self) -> (StatusCode, Headers, ResponseBody) {
         (self.status, self.headers, self.body)
@@ -50,6 +74,7 @@ impl std::fmt::Debug for Response {
         f.debug_struct("Response")
             .field("status", &self.status)
             .field("headers", &self.headers)
+            .field("elapsed", &self.elapsed)
             .field("body", &"<BODY>")
             .finish()
     }
@@ -145,3 +170,56 @@ impl Debug for ResponseBody {
         f.write_str("ResonseBody")
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::headers::{DATE, RETRY_AFTER};
+    use std::time::Duration;
+
+    fn response(headers: &[(crate::headers::HeaderName, &'static str)]) -> Response {
+        let mut response_headers = Headers::new();
+        for (name, value) in headers {
+            response_headers.insert(name.clone(), *value);
+        }
+        Response::new(
+            StatusCode::TooManyRequests,
+            response_headers,
+            Box::pin(futures::stream::empty()),
+        )
+    }
+
+    #[test]
+    fn elapsed_is_recorded_by_the_sender() {
+        assert_eq!(response(&[]).elapsed(), None);
+        let response = response(&[]).with_elapsed(Duration::from_millis(120));
+        assert_eq!(response.elapsed(), Some(Duration::from_millis(120)));
+    }
+
+    #[test]
+    fn retry_after_seconds() {
+        let response = response(&[(RETRY_AFTER, " 30 ")]);
+        assert_eq!(response.retry_after(), Some(Duration::from_secs(30)));
+    }
+
+    #[test]
+    fn retry_after_date_is_relative_to_the_response_date() {
+        let response = response(&[
+            (RETRY_AFTER, "Sun, 06 Nov 1994 08:50:07 GMT"),
+            (DATE, "Sun, 06 Nov 1994 08:49:37 GMT"),
+        ]);
+        assert_eq!(response.retry_after(), Some(Duration::from_secs(30)));
+    }
+
+    #[test]
+    fn retry_after_date_in_the_past_is_no_wait() {
+        let response = response(&[(RETRY_AFTER, "Sun, 06 Nov 1994 08:49:37 GMT")]);
+        assert_eq!(response.retry_after(), Some(Duration::ZERO));
+    }
+
+    #[test]
+    fn retry_after_missing_or_malformed() {
+        assert_eq!(response(&[]).retry_after(), None);
+        assert_eq!(response(&[(RETRY_AFTER, "soon")]).retry_after(), None);
+    }
+}
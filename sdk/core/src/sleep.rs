@@ -1,12 +1,15 @@
 use futures::Future;
 use std::pin::Pin;
+use std::sync::atomic::{AtomicBool, Ordering};
+use std::sync::Arc;
 use std::task::{Context, Poll};
 use std::thread;
-use std::time::Duration;
+use std::time::{Duration, Instant};
 
 pub fn sleep(duration: Duration) -> Sleep {
     Sleep {
         thread: None,
+        state: Arc::new(SleepState::default()),
         duration,
     }
 }
@@ -14,27 +17,83 @@ pub fn sleep(duration: Duration) -> Sleep {
 #[derive(Debug)]
 pub struct Sleep {
     thread: Option<thread::JoinHandle<()>>,
+    state: Arc<SleepState>,
     duration: Duration,
 }
 
+#[derive(Debug, Default)]
+struct SleepState {
+    slept: AtomicBool,
+    cancelled: AtomicBool,
+}
+
 impl Future for Sleep {
     type Output = ();
 
     fn poll(self: Pin<&mut Self>, cx: &mut Context<'_>) -> Poll<Self::Output> {
-        if let Some(thread) = &self.thread {
-            if thread.is_finished() {
+        if self.thread.is_some() {
+            // The thread may still be running when its wake-up is polled, so it reports
+            // having slept through a flag set before waking rather than by finishing.
+            if self.state.slept.load(Ordering::Acquire) {
                 Poll::Ready(())
             } else {
                 Poll::Pending
             }
         } else {
             let waker = cx.waker().clone();
-            let duration = self.duration;
+            let deadline = Instant::now() + self.duration;
+            let state = self.state.clone();
             self.get_mut().thread = Some(thread::spawn(move || {
-                thread::sleep(duration);
+                // Parked rather than asleep, so that dropping the `Sleep` can end the thread
+                // early, such as once the response a timeout was racing has arrived.
+                loop {
+                    if state.cancelled.load(Ordering::Acquire) {
+                        return;
+                    }
+                    let now = Instant::now();
+                    if now >= deadline {
+                        break;
+                    }
+                    thread::park_timeout(deadline - now);
+                }
+                state.slept.store(true, Ordering::Release);
                 waker.wake();
             }));
             Poll::Pending
         }
     }
 }
+
+impl Drop for Sleep {
+    fn drop(&mut self) {
+        if let Some(thread) = &self.thread {
+            self.state.cancelled.store(true, Ordering::Release);
+            thread.thread().unpark();
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn dropping_a_pending_sleep_ends_its_thread() {
+        let mut sleep = sleep(Duration::from_secs(3600));
+        let waker = futures::task::noop_waker();
+        assert_eq!(
+            Pin::new(&mut sleep).poll(&mut Context::from_waker(&waker)),
+            Poll::Pending
+        );
+        let state = sleep.state.clone();
+        drop(sleep);
+        let start = Instant::now();
+        while Arc::strong_count(&state) > 1 {
+            assert!(
+                start.elapsed() < Duration::from_secs(5),
+                "thread still running"
+            );
+            thread::sleep(Duration::from_millis(1));
+        }
+    }
+}
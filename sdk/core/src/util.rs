@@ -32,7 +32,7 @@ mod tests {
     use super::*;
     use serde::Serialize;
 
-    #[derive(Clone, Debug, PartialEq, Serialize, Deserialize, Default)]
+    #[derive(Clone, Debug, PartialEq, Eq, Serialize, Deserialize, Default)]
     pub struct SiteConfig {
         #[serde(
             rename = "appSettings",
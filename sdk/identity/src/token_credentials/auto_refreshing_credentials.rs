@@ -14,6 +14,8 @@ fn is_expired(token: &TokenResponse) -> bool {
 pub struct AutoRefreshingTokenCredential {
     credential: Arc<dyn TokenCredential>,
     current_token: Arc<RwLock<Option<azure_core::Result<TokenResponse>>>>,
+    /// The last token acquired for claims, with the resource and claims it was acquired for
+    claims_token: Arc<RwLock<Option<(String, String, TokenResponse)>>>,
 }
 
 impl std::fmt::Debug for AutoRefreshingTokenCredential {
@@ -30,6 +32,7 @@ impl AutoRefreshingTokenCredential {
         Self {
             credential: provider,
             current_token: Arc::new(RwLock::new(None)),
+            claims_token: Arc::new(RwLock::new(None)),
         }
     }
 }
@@ -72,4 +75,80 @@ impl TokenCredential for AutoRefreshingTokenCredential {
 
         result
     }
+
+    async fn get_token_with_claims(
+        &self,
+        resource: &str,
+        claims: &str,
+    ) -> azure_core::Result<TokenResponse> {
+        if let Some((cached_resource, cached_claims, token)) =
+            self.claims_token.read().await.as_ref()
+        {
+            if cached_resource == resource && cached_claims == claims && !is_expired(token) {
+                return Ok(token.clone());
+            }
+        }
+
+        // A token acquired for the claims also replaces the cached one, which the service may
+        // have rejected for lacking them.
+        let mut guard = self.current_token.write().await;
+        let token = self
+            .credential
+            .get_token_with_claims(resource, claims)
+            .await?;
+        *guard = Some(Ok(token.clone()));
+        *self.claims_token.write().await =
+            Some((resource.to_owned(), claims.to_owned(), token.clone()));
+        Ok(token)
+    }
+
+    async fn clear_cache(&self) -> azure_core::Result<()> {
+        let mut guard = self.current_token.write().await;
+        *guard = None;
+        *self.claims_token.write().await = None;
+        self.credential.clear_cache().await
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use azure_core::auth::AccessToken;
+
+    /// Returns a token naming the resource and claims it was requested for.
+    struct EchoCredential;
+
+    #[async_trait::async_trait]
+    impl TokenCredential for EchoCredential {
+        async fn get_token(&self, resource: &str) -> azure_core::Result<TokenResponse> {
+            Ok(TokenResponse::new(
+                AccessToken::new(resource.to_owned()),
+                OffsetDateTime::now_utc() + Duration::from_secs(3600),
+            ))
+        }
+
+        async fn get_token_with_claims(
+            &self,
+            resource: &str,
+            claims: &str,
+        ) -> azure_core::Result<TokenResponse> {
+            Ok(TokenResponse::new(
+                AccessToken::new(format!("{resource} {claims}")),
+                OffsetDateTime::now_utc() + Duration::from_secs(3600),
+            ))
+        }
+    }
+
+    #[tokio::test]
+    async fn claims_tokens_are_cached_per_resource() {
+        let credential = AutoRefreshingTokenCredential::new(Arc::new(EchoCredential));
+        let claims = r#"{"access_token":{"nbf":{"essential":true}}}"#;
+        for resource in ["https://management.azure.com", "https://vault.azure.net"] {
+            let token = credential
+                .get_token_with_claims(resource, claims)
+                .await
+                .unwrap();
+            assert_eq!(token.token.secret(), format!("{resource} {claims}"));
+        }
+    }
 }
@@ -28,7 +28,7 @@ const BASE64_URL_SAFE: Config = Config::new(CharacterSet::UrlSafe, false);
 
 /// Provides options to configure how the Identity library makes authentication
 /// requests to Azure Active Directory.
-#[derive(Clone, Debug, PartialEq)]
+#[derive(Clone, Debug, PartialEq, Eq)]
 pub struct CertificateCredentialOptions {
     authority_host: String,
     send_certificate_chain: bool,
@@ -148,10 +148,13 @@ fn openssl_error(err: ErrorStack) -> azure_core::error::Error {
     Error::new(ErrorKind::Credential, err)
 }
 
-#[cfg_attr(target_arch = "wasm32", async_trait::async_trait(?Send))]
-#[cfg_attr(not(target_arch = "wasm32"), async_trait::async_trait)]
-impl TokenCredential for ClientCertificateCredential {
-    async fn get_token(&self, resource: &str) -> azure_core::Result<TokenResponse> {
+impl ClientCertificateCredential {
+    /// Request a token for `resource`, satisfying `claims` if given.
+    async fn token(
+        &self,
+        resource: &str,
+        claims: Option<&str>,
+    ) -> azure_core::Result<TokenResponse> {
         let options = self.options();
         let url = &format!(
             "{}/{}/oauth2/v2.0/token",
@@ -163,11 +166,23 @@ impl TokenCredential for ClientCertificateCredential {
             .map_err(|_| Error::message(ErrorKind::Credential, "Base64 decode failed"))?;
         let certificate = Pkcs12::from_der(&certificate)
             .map_err(openssl_error)?
-            .parse(&self.client_certificate_pass)
+            .parse2(&self.client_certificate_pass)
             .map_err(openssl_error)?;
+        let cert = certificate.cert.ok_or_else(|| {
+            Error::message(
+                ErrorKind::Credential,
+                "the PKCS #12 archive holds no certificate",
+            )
+        })?;
+        let pkey = certificate.pkey.ok_or_else(|| {
+            Error::message(
+                ErrorKind::Credential,
+                "the PKCS #12 archive holds no private key",
+            )
+        })?;
 
-        let thumbprint = ClientCertificateCredential::get_thumbprint(&certificate.cert)
-            .map_err(openssl_error)?;
+        let thumbprint =
+            ClientCertificateCredential::get_thumbprint(&cert).map_err(openssl_error)?;
 
         let uuid = uuid::Uuid::new_v4();
         let current_time = OffsetDateTime::now_utc().unix_timestamp();
@@ -176,8 +191,8 @@ impl TokenCredential for ClientCertificateCredential {
 
         let header = match options.send_certificate_chain {
             true => {
-                let base_signature = get_encoded_cert(&certificate.cert)?;
-                let x5c = match certificate.chain {
+                let base_signature = get_encoded_cert(&cert)?;
+                let x5c = match certificate.ca {
                     Some(chain) => {
                         let chain = chain
                             .into_iter()
@@ -204,8 +219,7 @@ impl TokenCredential for ClientCertificateCredential {
         let payload = ClientCertificateCredential::as_jwt_part(payload.as_bytes());
 
         let jwt = format!("{}.{}", header, payload);
-        let signature =
-            ClientCertificateCredential::sign(&jwt, &certificate.pkey).map_err(openssl_error)?;
+        let signature = ClientCertificateCredential::sign(&jwt, &pkey).map_err(openssl_error)?;
         let sig = ClientCertificateCredential::as_jwt_part(&signature);
         let client_assertion = format!("{}.{}", jwt, sig);
 
@@ -220,6 +234,9 @@ impl TokenCredential for ClientCertificateCredential {
                 )
                 .append_pair("client_assertion", client_assertion.as_str())
                 .append_pair("grant_type", "client_credentials");
+            if let Some(claims) = claims {
+                encoded = encoded.append_pair("claims", claims);
+            }
             encoded.finish()
         };
 
@@ -246,3 +263,19 @@ impl TokenCredential for ClientCertificateCredential {
         ))
     }
 }
+
+#[cfg_attr(target_arch = "wasm32", async_trait::async_trait(?Send))]
+#[cfg_attr(not(target_arch = "wasm32"), async_trait::async_trait)]
+impl TokenCredential for ClientCertificateCredential {
+    async fn get_token(&self, resource: &str) -> azure_core::Result<TokenResponse> {
+        self.token(resource, None).await
+    }
+
+    async fn get_token_with_claims(
+        &self,
+        resource: &str,
+        claims: &str,
+    ) -> azure_core::Result<TokenResponse> {
+        self.token(resource, Some(claims)).await
+    }
+}
@@ -95,12 +95,13 @@ impl ClientSecretCredential {
     fn options(&self) -> &TokenCredentialOptions {
         &self.options
     }
-}
 
-#[cfg_attr(target_arch = "wasm32", async_trait::async_trait(?Send))]
-#[cfg_attr(not(target_arch = "wasm32"), async_trait::async_trait)]
-impl TokenCredential for ClientSecretCredential {
-    async fn get_token(&self, resource: &str) -> azure_core::Result<TokenResponse> {
+    /// Request a token for `resource`, satisfying `claims` if given.
+    async fn token(
+        &self,
+        resource: &str,
+        claims: Option<&str>,
+    ) -> azure_core::Result<TokenResponse> {
         let options = self.options();
         let authority_host = options.authority_host();
 
@@ -139,9 +140,13 @@ impl TokenCredential for ClientSecretCredential {
         .set_auth_type(AuthType::RequestBody);
 
         let oauth_http_client = Oauth2HttpClient::new(self.http_client.clone());
-        let token_result = client
+        let mut request = client
             .exchange_client_credentials()
-            .add_scope(Scope::new(format!("{}/.default", resource)))
+            .add_scope(Scope::new(format!("{}/.default", resource)));
+        if let Some(claims) = claims {
+            request = request.add_extra_param("claims", claims);
+        }
+        let token_result = request
             .request_async(|request| oauth_http_client.request(request))
             .await
             .map(|r| {
@@ -156,3 +161,19 @@ impl TokenCredential for ClientSecretCredential {
         Ok(token_result)
     }
 }
+
+#[cfg_attr(target_arch = "wasm32", async_trait::async_trait(?Send))]
+#[cfg_attr(not(target_arch = "wasm32"), async_trait::async_trait)]
+impl TokenCredential for ClientSecretCredential {
+    async fn get_token(&self, resource: &str) -> azure_core::Result<TokenResponse> {
+        self.token(resource, None).await
+    }
+
+    async fn get_token_with_claims(
+        &self,
+        resource: &str,
+        claims: &str,
+    ) -> azure_core::Result<TokenResponse> {
+        self.token(resource, Some(claims)).await
+    }
+}
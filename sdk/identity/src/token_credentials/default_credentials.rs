@@ -119,6 +119,37 @@ impl TokenCredential for DefaultAzureCredentialEnum {
             }
         }
     }
+
+    /// Only the environment credential can request additional claims.
+    async fn get_token_with_claims(
+        &self,
+        resource: &str,
+        claims: &str,
+    ) -> azure_core::Result<TokenResponse> {
+        match self {
+            DefaultAzureCredentialEnum::Environment(credential) => credential
+                .get_token_with_claims(resource, claims)
+                .await
+                .context(
+                    ErrorKind::Credential,
+                    "error getting environment credential",
+                ),
+            DefaultAzureCredentialEnum::ManagedIdentity(credential) => credential
+                .get_token_with_claims(resource, claims)
+                .await
+                .context(
+                    ErrorKind::Credential,
+                    "error getting managed identity credential",
+                ),
+            DefaultAzureCredentialEnum::AzureCli(credential) => credential
+                .get_token_with_claims(resource, claims)
+                .await
+                .context(
+                    ErrorKind::Credential,
+                    "error getting token credential from Azure CLI",
+                ),
+        }
+    }
 }
 
 /// Provides a default `TokenCredential` authentication flow for applications that will be deployed to Azure.
@@ -168,6 +199,28 @@ impl TokenCredential for DefaultAzureCredential {
             )
         }))
     }
+
+    /// Try to fetch a token satisfying `claims` using each of the credential sources until one
+    /// succeeds
+    async fn get_token_with_claims(
+        &self,
+        resource: &str,
+        claims: &str,
+    ) -> azure_core::Result<TokenResponse> {
+        let mut errors = Vec::new();
+        for source in &self.sources {
+            match source.get_token_with_claims(resource, claims).await {
+                Ok(token) => return Ok(token),
+                Err(error) => errors.push(error),
+            }
+        }
+        Err(Error::with_message(ErrorKind::Credential, || {
+            format!(
+                "Multiple errors were encountered while attempting to authenticate:\n{}",
+                format_aggregate_error(&errors)
+            )
+        }))
+    }
 }
 
 fn format_aggregate_error(errors: &[Error]) -> String {
@@ -50,10 +50,13 @@ impl EnvironmentCredential {
     }
 }
 
-#[cfg_attr(target_arch = "wasm32", async_trait::async_trait(?Send))]
-#[cfg_attr(not(target_arch = "wasm32"), async_trait::async_trait)]
-impl TokenCredential for EnvironmentCredential {
-    async fn get_token(&self, resource: &str) -> azure_core::Result<TokenResponse> {
+impl EnvironmentCredential {
+    /// Request a token for `resource`, satisfying `claims` if given.
+    async fn token(
+        &self,
+        resource: &str,
+        claims: Option<&str>,
+    ) -> azure_core::Result<TokenResponse> {
         let tenant_id =
             std::env::var(AZURE_TENANT_ID_ENV_KEY).with_context(ErrorKind::Credential, || {
                 format!(
@@ -82,7 +85,10 @@ impl TokenCredential for EnvironmentCredential {
                 client_secret,
                 self.options.clone(),
             );
-            return credential.get_token(resource).await;
+            return match claims {
+                Some(claims) => credential.get_token_with_claims(resource, claims).await,
+                None => credential.get_token(resource).await,
+            };
         } else if username.is_ok() && password.is_ok() {
             // Could use multiple if-let with #![feature(let_chains)] once stabilised - see https://github.com/rust-lang/rust/issues/53667
             // TODO: username & password credential
@@ -97,3 +103,19 @@ impl TokenCredential for EnvironmentCredential {
         ))
     }
 }
+
+#[cfg_attr(target_arch = "wasm32", async_trait::async_trait(?Send))]
+#[cfg_attr(not(target_arch = "wasm32"), async_trait::async_trait)]
+impl TokenCredential for EnvironmentCredential {
+    async fn get_token(&self, resource: &str) -> azure_core::Result<TokenResponse> {
+        self.token(resource, None).await
+    }
+
+    async fn get_token_with_claims(
+        &self,
+        resource: &str,
+        claims: &str,
+    ) -> azure_core::Result<TokenResponse> {
+        self.token(resource, Some(claims)).await
+    }
+}
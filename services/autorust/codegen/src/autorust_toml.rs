@@ -2,7 +2,7 @@ use crate::Result;
 use crate::{config_parser::Tag, io};
 use camino::Utf8Path;
 use serde::Deserialize;
-use std::collections::HashSet;
+use std::collections::{HashMap, HashSet};
 
 /// `autorust.toml` files are used to configure code generation for a crate
 #[derive(Deserialize, Debug, Default)]
@@ -14,6 +14,11 @@ pub struct PackageConfig {
     /// A section for workarounds that apply to properties
     #[serde(default)]
     pub properties: Properties,
+
+    /// Sections for customizing the code generated for a tag, keyed by the module name of the tag,
+    /// such as `package_2022_08_08`
+    #[serde(default)]
+    pub customize: HashMap<String, Customize>,
 }
 
 const NO_LIMIT: i32 = -1;
@@ -96,11 +101,64 @@ pub struct Properties {
     pub invalid_type: Vec<Vec<String>>,
 }
 
+/// Customizations of the code generated for a tag
+/// Schemas and properties are named as in the spec, operations by their operationId
+#[derive(Deserialize, Debug, Default, Clone)]
+pub struct Customize {
+    /// Schemas that keep the fields of a response they have no property for in an `extra` map,
+    /// so that they are not lost when the model is sent back
+    #[serde(default)]
+    pub keep_unknown_fields: Vec<String>,
+
+    /// Enum properties that also accept values the spec does not list, as `[schema, property]`
+    #[serde(default)]
+    pub extensible_enum: Vec<Vec<String>>,
+
+    /// Properties the spec is missing, as `[schema, property, type, description]`
+    /// The type is a Rust type, such as `ProvisioningState` for a model
+    #[serde(default)]
+    pub add_property: Vec<Vec<String>>,
+
+    /// Properties that get a different type, as `[schema, property, type]`
+    #[serde(default)]
+    pub property_type: Vec<Vec<String>>,
+
+    /// Parameters that get a different type, as `[operation, parameter, type]`
+    /// An optional fourth item is appended to the description of the parameter
+    #[serde(default)]
+    pub parameter_type: Vec<Vec<String>>,
+
+    /// Operations whose responses have a next link the spec does not declare, as `[operation, next link name]`
+    #[serde(default)]
+    pub next_link: Vec<Vec<String>>,
+
+    /// Operations that create a resource and accept `If-None-Match`, so that they do not overwrite an existing one
+    #[serde(default)]
+    pub create_if_none_match: Vec<String>,
+
+    /// Pageable operations that accept a `$top` page size the spec does not declare
+    #[serde(default)]
+    pub page_size: Vec<String>,
+
+    /// `POST` operations that only read, so they are not reported as mutations
+    #[serde(default)]
+    pub read_only: Vec<String>,
+
+    /// Operations that can also send their body as `multipart/form-data`
+    #[serde(default)]
+    pub multipart: Vec<String>,
+}
+
 impl<'a> PackageConfig {
     pub fn default_tag(&self) -> Option<&str> {
         self.tags.default.as_deref()
     }
 
+    /// The customizations for the tag with the module name `rust_mod_name`, if any
+    pub fn customize(&self, rust_mod_name: &str) -> Customize {
+        self.customize.get(rust_mod_name).cloned().unwrap_or_default()
+    }
+
     /// Filter the tags based on the configuration
     pub fn filter_tags(&self, tags: Vec<&'a Tag>) -> Vec<&'a Tag> {
         let mut tags = tags.clone();
@@ -343,4 +401,24 @@ mod tests {
         assert_eq!("innererror", config.properties.boxed[0][2]);
         Ok(())
     }
+
+    #[test]
+    fn customize() -> Result<()> {
+        let config: PackageConfig = toml::from_str(
+            r#"
+            [customize.package_2022_08_08]
+            keep_unknown_fields = ["RunbookProperties"]
+            extensible_enum = [["Identity", "type"]]
+            next_link = [["Operations_List", "nextLink"]]
+            read_only = ["Webhook_GenerateUri"]
+            "#,
+        )?;
+        let customize = config.customize("package_2022_08_08");
+        assert_eq!(vec!["RunbookProperties".to_owned()], customize.keep_unknown_fields);
+        assert_eq!("type", customize.extensible_enum[0][1]);
+        assert_eq!("nextLink", customize.next_link[0][1]);
+        assert_eq!(vec!["Webhook_GenerateUri".to_owned()], customize.read_only);
+        assert!(config.customize("package_2022_02_22").multipart.is_empty());
+        Ok(())
+    }
 }
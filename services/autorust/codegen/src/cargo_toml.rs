@@ -1,8 +1,17 @@
 use crate::Result;
-use crate::{config_parser::Tag, jinja::CargoToml};
+use crate::{config_parser::Tag, io, jinja::CargoToml};
 use camino::Utf8Path;
+use std::fs;
 
-pub fn create(package_name: &str, tags: &[&Tag], default_tag: &Tag, has_xml: bool, path: &Utf8Path) -> Result<()> {
+pub fn create(
+    package_name: &str,
+    tags: &[&Tag],
+    default_tag: &Tag,
+    has_xml: bool,
+    is_mgmt: bool,
+    crate_folder: &Utf8Path,
+    path: &Utf8Path,
+) -> Result<()> {
     let default_feature = &default_tag.rust_feature_name();
 
     // https://docs.rs/about/metadata
@@ -10,16 +19,37 @@ pub fn create(package_name: &str, tags: &[&Tag], default_tag: &Tag, has_xml: boo
 
     let features: Vec<_> = tags.iter().map(|tag| tag.rust_feature_name()).collect();
     let azure_core_features = if has_xml { vec!["xml"] } else { Vec::new() };
+    let benches = list_benches(crate_folder)?;
+    let has_tests = io::join(crate_folder, "tests")?.exists() || !benches.is_empty();
     let cargo_toml = CargoToml {
         package_name,
         default_feature,
         features,
         azure_core_features,
+        is_mgmt,
+        has_tests,
+        benches,
     };
     cargo_toml.create(path)?;
     Ok(())
 }
 
+/// Get the names of the hand-written benchmarks in the `benches` folder of a crate, sorted
+fn list_benches(crate_folder: &Utf8Path) -> Result<Vec<String>> {
+    let benches_folder = io::join(crate_folder, "benches")?;
+    let mut benches = Vec::new();
+    if benches_folder.exists() {
+        for path in fs::read_dir(benches_folder)? {
+            let file_name = path?.file_name();
+            if let Some(name) = file_name.to_str().and_then(|name| name.strip_suffix(".rs")) {
+                benches.push(name.to_owned());
+            }
+        }
+    }
+    benches.sort();
+    Ok(benches)
+}
+
 pub fn get_default_tag<'a>(tags: &[&'a Tag], default_tag: Option<&str>) -> &'a Tag {
     let default_tag = tags.iter().find(|tag| Some(tag.name()) == default_tag);
     let is_preview = default_tag.map(|tag| tag.name().contains("preview")).unwrap_or_default();
@@ -1,11 +1,11 @@
-use crate::{identifier::parse_ident, spec::TypeName, CrateConfig, PropertyName, Spec};
+use crate::{autorust_toml::Customize, identifier::parse_ident, spec::TypeName, CrateConfig, PropertyName, Spec, WebOperation};
 use crate::{Error, Result};
 use camino::Utf8Path;
 use camino::Utf8PathBuf;
 use heck::ToPascalCase;
 use once_cell::sync::Lazy;
 use proc_macro2::{Ident, TokenStream};
-use quote::ToTokens;
+use quote::{quote, ToTokens};
 use regex::Regex;
 use std::{collections::HashSet, convert::TryFrom};
 use syn::{
@@ -25,6 +25,9 @@ pub struct CodeGen<'a> {
     optional_properties: HashSet<PropertyName>,
     fix_case_properties: HashSet<&'a str>,
     invalid_types: HashSet<PropertyName>,
+
+    // customizations of the tag
+    pub customize: Customize,
 }
 
 impl<'a> CodeGen<'a> {
@@ -34,6 +37,7 @@ impl<'a> CodeGen<'a> {
         optional_properties: HashSet<PropertyName>,
         fix_case_properties: HashSet<&'a str>,
         invalid_types: HashSet<PropertyName>,
+        customize: Customize,
     ) -> Result<Self> {
         let spec = Spec::read_files(&crate_config.input_files)?;
         Ok(Self {
@@ -43,6 +47,7 @@ impl<'a> CodeGen<'a> {
             optional_properties,
             fix_case_properties,
             invalid_types,
+            customize,
         })
     }
 
@@ -54,6 +59,38 @@ impl<'a> CodeGen<'a> {
         &self.crate_config.output_folder
     }
 
+    /// Whether the crate is an Azure Resource Manager crate
+    pub fn is_mgmt(&self) -> bool {
+        self.crate_config.run_config.is_mgmt()
+    }
+
+    /// The name of the module the tag is generated into, such as `package_2022_08_08`
+    pub fn tag_mod_name(&self) -> &str {
+        self.output_folder().file_name().unwrap_or_default()
+    }
+
+    /// The path to `include!` the hand-written `file` from, relative to the generated module, if the crate has one.
+    /// Extensions live in `extensions/<tag module>/` next to `src/`.
+    pub fn extension(&self, file: &str) -> Option<String> {
+        let tag = self.tag_mod_name();
+        let path = self.output_folder().join("../../extensions").join(tag).join(file);
+        path.exists().then(|| format!("../../extensions/{tag}/{file}"))
+    }
+
+    /// The operations of the spec, with the next links from the customizations
+    pub fn operations(&self) -> Result<Vec<WebOperation>> {
+        let mut operations = self.spec.operations()?;
+        for operation in &mut operations {
+            let id = operation.id.as_deref().unwrap_or_default();
+            if let Some(next_link) = self.customize.next_link.iter().find(|next_link| next_link[0] == id) {
+                if let Some(pageable) = &mut operation.pageable {
+                    pageable.next_link_name = Some(next_link[1].clone());
+                }
+            }
+        }
+        Ok(operations)
+    }
+
     pub fn should_workaround_case(&self) -> bool {
         if let Some(title) = self.spec.title() {
             self.fix_case_properties.contains(title)
@@ -75,6 +112,25 @@ impl<'a> CodeGen<'a> {
     }
 }
 
+/// Includes the hand-written code for a module of the tag, if the crate has any.
+pub struct ExtensionCode(Option<String>);
+
+impl ExtensionCode {
+    pub fn new(cg: &CodeGen, file: &str) -> Self {
+        Self(cg.extension(file))
+    }
+}
+
+impl ToTokens for ExtensionCode {
+    fn to_tokens(&self, tokens: &mut TokenStream) {
+        if let Some(path) = &self.0 {
+            tokens.extend(quote! {
+                include!(#path);
+            });
+        }
+    }
+}
+
 fn id_models() -> Ident {
     parse_ident("models").unwrap()
 }
@@ -154,6 +210,16 @@ impl TypeNameCode {
     pub fn is_value(&self) -> bool {
         self.type_name == Some(TypeName::Value)
     }
+    pub fn is_reference(&self) -> bool {
+        matches!(self.type_name, Some(TypeName::Reference(_)))
+    }
+    /// The name of the schema the type is generated from, if it is a reference to one
+    pub fn reference_name(&self) -> Option<&str> {
+        match &self.type_name {
+            Some(TypeName::Reference(name)) => Some(name),
+            _ => None,
+        }
+    }
     pub fn is_date_time(&self) -> bool {
         self.type_name == Some(TypeName::DateTime)
     }
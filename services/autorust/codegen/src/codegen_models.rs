@@ -1,5 +1,5 @@
 use crate::{
-    codegen::TypeNameCode,
+    codegen::{ExtensionCode, TypeNameCode},
     identifier::{CamelCaseIdent, SnakeCaseIdent},
     spec::{self, get_schema_array_items, get_type_name_for_schema, get_type_name_for_schema_ref, TypeName},
     CodeGen, PropertyName, ResolvedSchema, Spec,
@@ -13,6 +13,7 @@ use quote::{quote, ToTokens};
 use serde_json::Value;
 use spec::{get_schema_schema_references, openapi, RefKey};
 use std::collections::{HashMap, HashSet};
+use std::convert::TryFrom;
 
 #[derive(Clone)]
 pub struct PropertyGen {
@@ -175,6 +176,27 @@ impl SchemaGen {
         self.properties.iter().collect()
     }
 
+    /// The array properties and their item types
+    pub fn array_properties(&self) -> Result<Vec<(&PropertyGen, TypeName)>> {
+        let mut arrays = Vec::new();
+        for property in self.properties() {
+            if let TypeName::Array(items) = property.schema().type_name()? {
+                arrays.push((property, *items));
+            }
+        }
+        Ok(arrays)
+    }
+
+    /// The array property and its item type, if this is a list result such as `JobListResult`
+    /// A list result is named like one and has a single array property, usually `value`
+    pub fn list_items(&self) -> Result<Option<(&PropertyGen, TypeName)>> {
+        if !self.name().map_or(false, |name| name.contains("ListResult")) {
+            return Ok(None);
+        }
+        let mut arrays = self.array_properties()?;
+        Ok(if arrays.len() == 1 { arrays.pop() } else { None })
+    }
+
     fn default(&self) -> Option<&str> {
         self.schema.common.default.as_ref().and_then(|v| v.as_str())
     }
@@ -376,7 +398,7 @@ pub fn create_models(cg: &CodeGen) -> Result<TokenStream> {
     }
 
     let mut pageable_response_names: HashMap<String, MsPageable> = HashMap::new();
-    for operation in cg.spec.operations()? {
+    for operation in cg.operations()? {
         if let Some(pageable) = operation.pageable.as_ref() {
             for response in operation.responses.values() {
                 if let Some(schema) = &response.schema {
@@ -404,8 +426,10 @@ pub fn create_models(cg: &CodeGen) -> Result<TokenStream> {
 
     // println!("response_names: {:?}", pageable_response_names);
 
+    let schemas = all_schemas_resolved(&cg.spec)?;
+    let hashable = hashable_schemas(cg, &schemas)?;
     let mut schema_names = IndexMap::new();
-    for (ref_key, schema) in &all_schemas_resolved(&cg.spec)? {
+    for (ref_key, schema) in &schemas {
         let doc_file = &ref_key.file_path;
         let schema_name = &ref_key.name;
 
@@ -421,19 +445,111 @@ pub fn create_models(cg: &CodeGen) -> Result<TokenStream> {
         } else if schema.is_array() {
             file.extend(create_vec_alias(schema)?);
         } else if schema.is_local_enum() {
-            let enum_code = create_enum(None, schema, schema_name, false)?;
+            let enum_code = create_enum(None, schema, schema_name, false, false)?;
             file.extend(enum_code.into_token_stream());
         } else if schema.is_basic_type() {
             let (id, value) = create_basic_type_alias(schema_name, schema)?;
             file.extend(quote! { pub type #id = #value;});
         } else {
             let pageable_name = format!("{}", schema_name.to_camel_case_ident()?);
-            file.extend(create_struct(cg, schema, schema_name, pageable_response_names.get(&pageable_name))?);
+            file.extend(create_struct(
+                cg,
+                schema,
+                schema_name,
+                pageable_response_names.get(&pageable_name),
+                &hashable,
+            )?);
         }
     }
+    file.extend(ExtensionCode::new(cg, "models.rs").into_token_stream());
     Ok(file)
 }
 
+/// The names of the schemas that can derive `Eq` and `Hash`.
+/// Starts from all of the schemas and removes the ones that are not hashable, until none are left to remove,
+/// so that schemas that refer to each other stay hashable.
+fn hashable_schemas(cg: &CodeGen, schemas: &[(RefKey, SchemaGen)]) -> Result<HashSet<String>> {
+    let mut hashable: HashSet<String> = schemas.iter().map(|(ref_key, _)| ref_key.name.clone()).collect();
+    loop {
+        let mut removed = false;
+        for (ref_key, schema) in schemas {
+            if hashable.contains(&ref_key.name) && !is_hashable(cg, schema, &ref_key.name, &hashable)? {
+                hashable.remove(&ref_key.name);
+                removed = true;
+            }
+        }
+        if !removed {
+            return Ok(hashable);
+        }
+    }
+}
+
+/// If the type for the schema can derive `Eq` and `Hash`, given the schemas that are hashable.
+fn is_hashable(cg: &CodeGen, schema: &SchemaGen, schema_name: &str, hashable: &HashSet<String>) -> Result<bool> {
+    if schema.is_local_enum() {
+        return Ok(true);
+    }
+    if schema.is_array() || schema.is_basic_type() {
+        return Ok(is_type_name_hashable(&schema.type_name()?, hashable));
+    }
+    if cg.customize.keep_unknown_fields.iter().any(|name| name == schema_name) {
+        return Ok(false);
+    }
+    for schema in schema.all_of() {
+        if !hashable.contains(schema.name()?) {
+            return Ok(false);
+        }
+    }
+    for property in schema.properties() {
+        let property_name = property.xml_name().unwrap_or_else(|| property.name());
+        let prop_nm = PropertyName {
+            file_path: schema.doc_file.clone(),
+            schema_name: schema_name.to_owned(),
+            property_name: property_name.to_owned(),
+        };
+        if cg.should_force_obj(&prop_nm) {
+            return Ok(false);
+        }
+        if property_type(cg, schema_name, property_name).is_some() {
+            continue;
+        }
+        let property_hashable = match &property.schema.ref_key {
+            Some(ref_key) => hashable.contains(&ref_key.name),
+            None => {
+                if property.schema.is_local_enum() {
+                    true
+                } else if property.schema.is_local_struct() {
+                    is_hashable(cg, &property.schema, property_name, hashable)?
+                } else {
+                    is_type_name_hashable(&property.schema.type_name()?, hashable)
+                }
+            }
+        };
+        if !property_hashable {
+            return Ok(false);
+        }
+    }
+    Ok(true)
+}
+
+fn is_type_name_hashable(type_name: &TypeName, hashable: &HashSet<String>) -> bool {
+    match type_name {
+        TypeName::Reference(name) => hashable.contains(name),
+        TypeName::Array(items) => is_type_name_hashable(items, hashable),
+        TypeName::Value | TypeName::Float32 | TypeName::Float64 => false,
+        _ => true,
+    }
+}
+
+/// The type of a property from the `property_type` customization, if any
+fn property_type<'a>(cg: &'a CodeGen, schema_name: &str, property_name: &str) -> Option<&'a str> {
+    cg.customize
+        .property_type
+        .iter()
+        .find(|customize| customize[0] == schema_name && customize[1] == property_name)
+        .map(|customize| customize[2].as_str())
+}
+
 fn create_basic_type_alias(property_name: &str, property: &SchemaGen) -> Result<(Ident, TypeNameCode)> {
     let id = property_name.to_camel_case_ident()?;
     let value = TypeNameCode::new(&property.type_name()?)?;
@@ -459,7 +575,9 @@ fn create_enum(
     property: &SchemaGen,
     property_name: &str,
     lowercase_workaround: bool,
+    extensible: bool,
 ) -> Result<StructFieldCode> {
+    let model_as_string = property.is_model_as_string_enum() || extensible;
     let enum_values = property.enum_values();
     let id = &property_name.to_camel_case_ident()?;
 
@@ -504,7 +622,7 @@ fn create_enum(
     // https://github.com/serde-rs/serde/issues/912
 
     // If `model_as_string` then add the `UnknownValue(String)` field to the enum variants
-    if property.is_model_as_string_enum() {
+    if model_as_string {
         let value_token = quote! {
             #[serde(skip_deserializing)]
             UnknownValue(String)
@@ -518,7 +636,7 @@ fn create_enum(
 
     // If `model_as_string` then set the `serde` `remote` attribute to indicate
     // that the Serializer/Deserializer will be defined elsewhere.
-    let maybe_remote_attr = if property.is_model_as_string_enum() {
+    let maybe_remote_attr = if model_as_string {
         quote! {
             #[serde(remote = #id_str)]
         }
@@ -528,8 +646,9 @@ fn create_enum(
 
     // If `model_as_string` then provide custom `Deserialize` and `Serialize`
     // implementations.
-    let custom_serde_code = if property.is_model_as_string_enum() {
+    let custom_serde_code = if model_as_string {
         let mut serialize_fields = TokenStream::new();
+        let mut as_str_fields = TokenStream::new();
         for (index, enum_value) in enum_values.iter().enumerate() {
             let value = &enum_value.value;
             let nm = value.to_camel_case_ident()?;
@@ -537,6 +656,9 @@ fn create_enum(
             serialize_fields.extend(quote! {
                 Self::#nm => serializer.serialize_unit_variant(#id_str, #variant_index, #value),
             });
+            as_str_fields.extend(quote! {
+                Self::#nm => #value,
+            });
         }
 
         quote! {
@@ -571,6 +693,22 @@ fn create_enum(
                     }
                 }
             }
+
+            impl #id {
+                #[doc = "The value as it is sent on the wire."]
+                pub fn as_str(&self) -> &str {
+                    match self {
+                        #as_str_fields
+                        Self::UnknownValue(s) => s.as_str(),
+                    }
+                }
+            }
+
+            impl std::fmt::Display for #id {
+                fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+                    f.write_str(self.as_str())
+                }
+            }
         }
     } else {
         quote! {}
@@ -599,7 +737,7 @@ fn create_enum(
 
     let code = quote! {
         #doc_comment
-        #[derive(Clone, Debug, PartialEq, Serialize, Deserialize)]
+        #[derive(Clone, Debug, PartialEq, Eq, Hash, Serialize, Deserialize)]
         #maybe_remote_attr
         pub enum #nm {
             #values
@@ -622,7 +760,13 @@ fn create_vec_alias(schema: &SchemaGen) -> Result<TokenStream> {
     Ok(quote! { pub type #typ = Vec<#items_typ>; })
 }
 
-fn create_struct(cg: &CodeGen, schema: &SchemaGen, struct_name: &str, pageable: Option<&MsPageable>) -> Result<TokenStream> {
+fn create_struct(
+    cg: &CodeGen,
+    schema: &SchemaGen,
+    struct_name: &str,
+    pageable: Option<&MsPageable>,
+    hashable: &HashSet<String>,
+) -> Result<TokenStream> {
     let mut code = TokenStream::new();
     let mut mod_code = TokenStream::new();
     let mut props = TokenStream::new();
@@ -666,12 +810,28 @@ fn create_struct(cg: &CodeGen, schema: &SchemaGen, struct_name: &str, pageable:
         };
 
         let lowercase_workaround = cg.should_workaround_case();
+        let extensible = cg
+            .customize
+            .extensible_enum
+            .iter()
+            .any(|customize| customize[0] == struct_name && customize[1] == property_name);
 
         let StructFieldCode {
             mut type_name,
             code: field_code,
-        } = create_struct_field_code(cg, &ns.clone(), &property.schema, property_name, lowercase_workaround)?;
+        } = create_struct_field_code(
+            cg,
+            &ns.clone(),
+            &property.schema,
+            property_name,
+            lowercase_workaround,
+            extensible,
+            hashable,
+        )?;
         mod_code.extend(field_code.into_token_stream());
+        if let Some(property_type) = property_type(cg, struct_name, property_name) {
+            type_name = TypeNameCode::try_from(property_type)?;
+        }
         // uncomment the next two lines to help identify entries that need boxed
         // let prop_nm_str = format!("{} , {} , {}", prop_nm.file_path, prop_nm.schema_name, property_name);
         // props.extend(quote! { #[doc = #prop_nm_str ]});
@@ -695,17 +855,17 @@ fn create_struct(cg: &CodeGen, schema: &SchemaGen, struct_name: &str, pageable:
         #[allow(clippy::collapsible_else_if)]
         if is_required {
             if type_name.is_date_time() {
-                serde_attrs.push(quote! { with = "azure_core::date::rfc3339"});
+                serde_attrs.push(quote! { with = "azure_core::date::iso8601"});
             } else if type_name.is_date_time_rfc1123() {
                 serde_attrs.push(quote! { with = "azure_core::date::rfc1123"});
             }
         } else {
             if type_name.is_date_time() {
                 // Must specify `default` when using `with` for `Option`
-                serde_attrs.push(quote! { default, with = "azure_core::date::rfc3339::option"});
+                serde_attrs.push(quote! { default, with = "azure_core::date::iso8601::option", skip_serializing_if = "Option::is_none"});
             } else if type_name.is_date_time_rfc1123() {
                 // Must specify `default` when using `with` for `Option`
-                serde_attrs.push(quote! { default, with = "azure_core::date::rfc1123::option"});
+                serde_attrs.push(quote! { default, with = "azure_core::date::rfc1123::option", skip_serializing_if = "Option::is_none"});
             } else if type_name.is_vec() {
                 serde_attrs.push(quote! { default, deserialize_with = "azure_core::util::deserialize_null_as_default", skip_serializing_if = "Vec::is_empty"});
             } else {
@@ -755,6 +915,34 @@ fn create_struct(cg: &CodeGen, schema: &SchemaGen, struct_name: &str, pageable:
         }
     }
 
+    for customize in cg.customize.add_property.iter().filter(|customize| customize[0] == struct_name) {
+        let property_name = customize[1].as_str();
+        let field_name = property_name.to_snake_case_ident()?;
+        let type_name = TypeNameCode::try_from(customize[2].as_str())?.optional(true);
+        let description = customize[3].as_str();
+        let rename = if field_name != property_name {
+            quote! { rename = #property_name, }
+        } else {
+            quote! {}
+        };
+        props.extend(quote! {
+            #[doc = #description]
+            #[serde(#rename default, skip_serializing_if = "Option::is_none")]
+            pub #field_name: #type_name,
+        });
+        new_fn_body.extend(quote! { #field_name: None, });
+        field_names.insert(format!("{}", field_name), false);
+    }
+
+    if cg.customize.keep_unknown_fields.iter().any(|name| name == struct_name) {
+        props.extend(quote! {
+            #[doc = "Fields of the response that this version of the crate has no field for, kept so that they are not lost when the model is sent back, such as in a read-modify-write update."]
+            #[serde(flatten, default, skip_serializing_if = "std::collections::HashMap::is_empty")]
+            pub extra: std::collections::HashMap<String, serde_json::Value>,
+        });
+        new_fn_body.extend(quote! { extra: std::collections::HashMap::new(), });
+    }
+
     let default_code = if schema.implement_default() {
         quote! { #[derive(Default)] }
     } else {
@@ -828,9 +1016,29 @@ fn create_struct(cg: &CodeGen, schema: &SchemaGen, struct_name: &str, pageable:
         }
     }
 
+    let eq_code = if is_hashable(cg, schema, struct_name, hashable)? {
+        quote! { Eq, Hash, }
+    } else {
+        quote! {}
+    };
+
+    let items_code = match schema.list_items()? {
+        Some((property, items)) => {
+            let field_name = property.name().to_snake_case_ident()?;
+            let items = TypeNameCode::new(&items)?;
+            quote! {
+                #[doc = "The items in this page."]
+                pub fn items(&self) -> &[#items] {
+                    &self.#field_name
+                }
+            }
+        }
+        None => quote! {},
+    };
+
     let struct_code = quote! {
         #doc_comment
-        #[derive(Clone, Debug, PartialEq, Serialize, Deserialize)]
+        #[derive(Clone, Debug, PartialEq, #eq_code Serialize, Deserialize)]
         #default_code
         pub struct #struct_name_code {
             #props
@@ -845,6 +1053,7 @@ fn create_struct(cg: &CodeGen, schema: &SchemaGen, struct_name: &str, pageable:
                 pub fn new() -> Self {
                     Self::default()
                 }
+                #items_code
             }
         }
     } else {
@@ -855,6 +1064,7 @@ fn create_struct(cg: &CodeGen, schema: &SchemaGen, struct_name: &str, pageable:
                         #new_fn_body
                     }
                 }
+                #items_code
             }
         }
     });
@@ -926,6 +1136,8 @@ fn create_struct_field_code(
     property: &SchemaGen,
     property_name: &str,
     lowercase_workaround: bool,
+    extensible: bool,
+    hashable: &HashSet<String>,
 ) -> Result<StructFieldCode> {
     match &property.ref_key {
         Some(ref_key) => {
@@ -937,11 +1149,11 @@ fn create_struct_field_code(
         }
         None => {
             if property.is_local_enum() {
-                create_enum(Some(namespace), property, property_name, lowercase_workaround)
+                create_enum(Some(namespace), property, property_name, lowercase_workaround, extensible)
             } else if property.is_local_struct() {
                 let id = property_name.to_camel_case_ident()?;
                 let type_name = TypeNameCode::from(vec![namespace.clone(), id]);
-                let code = create_struct(cg, property, property_name, None)?;
+                let code = create_struct(cg, property, property_name, None, hashable)?;
                 Ok(StructFieldCode {
                     type_name,
                     code: Some(TypeCode::Struct(code)),
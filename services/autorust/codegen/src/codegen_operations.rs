@@ -1,6 +1,7 @@
 use crate::{
     codegen::{parse_path_params, PARAM_RE},
-    codegen::{parse_query_params, TypeNameCode},
+    codegen::{parse_query_params, ExtensionCode, TypeNameCode},
+    codegen_models::all_schemas_resolved,
     identifier::{parse_ident, SnakeCaseIdent},
     spec::{get_type_name_for_schema_ref, TypeName, WebOperation, WebParameter, WebVerb},
     status_codes::get_status_code_ident,
@@ -8,37 +9,46 @@ use crate::{
 };
 use crate::{content_type, Result};
 use autorust_openapi::{CollectionFormat, Header, ParameterType, Response, StatusCode};
-use heck::ToPascalCase;
 use heck::ToSnakeCase;
 use indexmap::IndexMap;
 use proc_macro2::{Ident, TokenStream};
 use quote::{quote, ToTokens};
-use std::collections::BTreeSet;
+use std::collections::{BTreeSet, HashMap, HashSet};
+use std::convert::TryFrom;
 
 pub const API_VERSION: &str = "api-version";
 pub const X_MS_VERSION: &str = "x-ms-version";
 
-fn error_variant(operation: &WebOperationGen) -> Result<Ident> {
-    let function = operation.rust_function_name().to_pascal_case();
-    if let Some(module) = operation.rust_module_name() {
-        let module = module.to_pascal_case();
-        parse_ident(&format!("{}_{}", module, function))
-    } else {
-        parse_ident(&function)
-    }
-}
-
-fn error_fqn(operation: &WebOperationGen) -> Result<TokenStream> {
-    let function = parse_ident(&operation.rust_function_name())?;
-    if let Some(module) = operation.rust_module_name() {
-        let module = parse_ident(&module)?;
-        Ok(quote! { #module::#function::Error })
-    } else {
-        Ok(quote! { #function::Error })
+/// Documentation of the module of a tag, which the docs of the request builders link to.
+fn create_tag_docs(api_version: Option<&str>) -> TokenStream {
+    let summary = match api_version {
+        Some(api_version) => format!("Client for the API at api-version {api_version}, with a request builder for every operation."),
+        None => "Client for the API, with a request builder for every operation.".to_owned(),
+    };
+    quote! {
+        #![doc = #summary]
+        #![doc = ""]
+        #![doc = "# Claims"]
+        #![doc = ""]
+        #![doc = "`claims` on a request builder asks the credential for a token that satisfies a JSON claims request, not base64 encoded, such as the claims a conditional access policy requires for step-up authentication. Credentials that cannot request claims fail with an `ErrorKind::Credential` error; see `azure_core::auth::TokenCredential::get_token_with_claims`. A claims challenge from the service is answered without this; see `ClientBuilder::reauthenticate_on_challenge`."]
+        #![doc = ""]
+        #![doc = "# Query parameters"]
+        #![doc = ""]
+        #![doc = "`query_param` on a request builder appends `key=value` to the query string, for query parameters the service added after this crate was generated. A parameter the builder already sets, such as `$filter`, is replaced. `api-version` is fixed by the crate and cannot be overridden."]
+        #![doc = ""]
+        #![doc = "# Responses"]
+        #![doc = ""]
+        #![doc = "The `Response` of every operation has these helpers besides `into_body`:"]
+        #![doc = ""]
+        #![doc = "* `into_value` returns the body as untyped JSON, including any fields the `models` type does not have yet."]
+        #![doc = "* `client_request_id` is the `x-ms-client-request-id` the request was sent with, for correlating client and service logs."]
+        #![doc = "* `rate_limit_remaining` is the remaining request quota reported by the `x-ms-ratelimit-remaining-*` response headers, or `None` if the service sent none."]
+        #![doc = "* `elapsed` is how long the request took, from handing it to the pipeline until the response headers arrived, including retries; reading the body is not counted. It is zero for a response that did not come from the service, such as one `ClientBuilder::dry_run` answered without sending."]
+        #![doc = "* `concurrency_token`, on the responses of operations that read a resource, is its ETag, for passing to `if_match` of a later `update` or `create_or_update` so that it only applies if the resource has not changed since."]
     }
 }
 
-pub fn create_client(modules: &[String], endpoint: Option<&str>) -> Result<TokenStream> {
+pub fn create_client(modules: &[String], endpoint: Option<&str>, is_mgmt: bool) -> Result<TokenStream> {
     let mut clients = TokenStream::new();
     for md in modules {
         let client = format!("{md}_client").to_snake_case_ident()?;
@@ -65,91 +75,125 @@ pub fn create_client(modules: &[String], endpoint: Option<&str>) -> Result<Token
         public_cloud
     };
 
+    // reads of Azure Resource Manager can be batched, and its credentials come from the environment
+    let mgmt_uses = if is_mgmt {
+        quote! {
+            pub use azure_core::batch::{self, BatchRead};
+        }
+    } else {
+        quote! {}
+    };
+    let mgmt_functions = if is_mgmt {
+        quote! {
+            #[doc = "Create a builder that sends many reads as Azure Resource Manager `$batch` requests, instead of one round trip each."]
+            #[doc = ""]
+            #[doc = "Add the `GET` request builders of other operations with `batch::RequestBuilder::push`, such as a `get` for each of many resources. The batch is sent with the client's token and settings; see `batch::RequestBuilder::send`."]
+            #[must_use]
+            pub fn batch(&self) -> batch::RequestBuilder {
+                batch::RequestBuilder::new(self.0.clone())
+            }
+
+            #[doc = "Create a `Client` from the environment, returning it together with the subscription id to pass to its operations."]
+            #[doc = ""]
+            #[doc = "For quick tools and CLIs. The environment variables read are:"]
+            #[doc = ""]
+            #[doc = "* `AZURE_SUBSCRIPTION_ID`: the subscription id. Required."]
+            #[doc = "* `AZURE_RESOURCE_MANAGER_ENDPOINT`: the endpoint, such as `https://management.chinacloudapi.cn`. Defaults to `DEFAULT_ENDPOINT`, the public cloud."]
+            #[doc = "* `AZURE_RESOURCE_MANAGER_AUDIENCE`: the audience to request tokens for, such as `https://management.core.windows.net/`, when it differs from the endpoint; see `ClientBuilder::audience`. Defaults to the endpoint."]
+            #[doc = ""]
+            #[doc = "Tokens come from an `azure_identity::DefaultAzureCredential`, cached until they are about to expire. It tries, in order: a service principal from `AZURE_TENANT_ID` and `AZURE_CLIENT_ID` together with `AZURE_CLIENT_SECRET`, `AZURE_CLIENT_CERTIFICATE_PATH` or `AZURE_USERNAME` and `AZURE_PASSWORD`; a managed identity; and the Azure CLI. The first that yields a token wins, so service principal variables left in the environment take precedence over a CLI login. Use `Client::builder` for anything more specific."]
+            #[cfg(feature = "identity")]
+            pub fn from_env() -> azure_core::Result<(Client, String)> {
+                use azure_core::error::ResultExt;
+                let subscription_id = std::env::var("AZURE_SUBSCRIPTION_ID")
+                    .context(azure_core::error::ErrorKind::Credential, "AZURE_SUBSCRIPTION_ID is not set")?;
+                let credential = azure_identity::AutoRefreshingTokenCredential::new(
+                    std::sync::Arc::new(azure_identity::DefaultAzureCredential::default()),
+                );
+                let mut builder = ClientBuilder::new(std::sync::Arc::new(credential));
+                if let Ok(endpoint) = std::env::var("AZURE_RESOURCE_MANAGER_ENDPOINT") {
+                    builder = builder.endpoint(endpoint);
+                }
+                if let Ok(audience) = std::env::var("AZURE_RESOURCE_MANAGER_AUDIENCE") {
+                    builder = builder.audience(audience);
+                }
+                Ok((builder.build(), subscription_id))
+            }
+        }
+    } else {
+        quote! {}
+    };
+
     let mut code = TokenStream::new();
     code.extend(quote! {
+        pub use azure_core::client::{map_concurrent, ClientRequestIdGenerator, RequestHook};
+        pub use azure_core::concurrency::{ConcurrencyToken, PreconditionFailed};
+        pub use azure_core::lro::{FinalGet, FinalStateVia, PollOptions, PollTimeout, Poller};
+        #mgmt_uses
 
         #[derive(Clone)]
-        pub struct Client {
-            endpoint: String,
-            credential: std::sync::Arc<dyn azure_core::auth::TokenCredential>,
-            scopes: Vec<String>,
-            pipeline: azure_core::Pipeline,
-        }
+        pub struct Client(pub(crate) azure_core::client::ServiceClient);
 
-        #[derive(Clone)]
-        pub struct ClientBuilder {
-            credential: std::sync::Arc<dyn azure_core::auth::TokenCredential>,
-            endpoint: Option<String>,
-            scopes: Option<Vec<String>>,
-            options: azure_core::ClientOptions,
-        }
+        #[doc = "Builds a `Client`; see `azure_core::client::ClientBuilder` for the settings."]
+        pub type ClientBuilder = azure_core::client::ClientBuilder<Client>;
 
         #default_endpoint_code
 
-        impl ClientBuilder {
-            #[doc = "Create a new instance of `ClientBuilder`."]
-            #[must_use]
-            pub fn new(credential: std::sync::Arc<dyn azure_core::auth::TokenCredential>) -> Self {
-                Self {
-                    credential,
-                    endpoint: None,
-                    scopes: None,
-                    options: azure_core::ClientOptions::default(),
-                }
-            }
+        impl azure_core::client::GeneratedClient for Client {
+            const DEFAULT_ENDPOINT: &'static str = DEFAULT_ENDPOINT;
+            const CRATE_NAME: Option<&'static str> = option_env!("CARGO_PKG_NAME");
+            const CRATE_VERSION: Option<&'static str> = option_env!("CARGO_PKG_VERSION");
+        }
 
-            #[doc = "Set the endpoint."]
-            #[must_use]
-            pub fn endpoint(mut self, endpoint: impl Into<String>) -> Self {
-                self.endpoint = Some(endpoint.into());
-                self
+        impl From<azure_core::client::ServiceClient> for Client {
+            fn from(client: azure_core::client::ServiceClient) -> Self {
+                Self(client)
             }
+        }
 
-            #[doc = "Set the scopes."]
-            #[must_use]
-            pub fn scopes(mut self, scopes: &[&str]) -> Self {
-                self.scopes = Some(scopes.iter().map(|scope| (*scope).to_owned()).collect());
-                self
+        impl Client {
+            pub(crate) fn endpoint(&self) -> &str {
+                self.0.endpoint()
             }
-
-            #[doc = "Set the retry options."]
-            #[must_use]
-            pub fn retry(mut self, retry: impl Into<azure_core::RetryOptions>) -> Self {
-                self.options = self.options.retry(retry);
-                self
+            pub(crate) async fn token(&self, claims: Option<&str>) -> azure_core::Result<azure_core::auth::TokenResponse> {
+                self.0.token(claims).await
+            }
+            pub(crate) async fn send(&self, request: &mut azure_core::Request, operation: &'static str) -> azure_core::Result<azure_core::Response> {
+                self.0.send(request, operation).await
+            }
+            pub(crate) async fn send_with_context(
+                &self,
+                request: &mut azure_core::Request,
+                operation: &'static str,
+                context: azure_core::Context,
+            ) -> azure_core::Result<azure_core::Response> {
+                self.0.send_with_context(request, operation, context).await
             }
 
-            #[doc = "Set the transport options."]
-            #[must_use]
-            pub fn transport(mut self, transport: impl Into<azure_core::TransportOptions>) -> Self {
-                self.options = self.options.transport(transport);
-                self
+            #[doc = "Check that the credential can get a token for the configured scopes."]
+            #[doc = ""]
+            #[doc = "Requests a token once and discards it. Useful as a preflight before starting work, so an authentication problem is reported up front instead of from the first operation."]
+            pub async fn check_credential(&self) -> azure_core::Result<()> {
+                self.0.check_credential().await
             }
 
-            #[doc = "Convert the builder into a `Client` instance."]
+            #[doc = "Create a client that sends requests to `endpoint`, sharing the credential, pipeline and settings of this one."]
+            #[doc = ""]
+            #[doc = "For failing over to another region without rebuilding authentication state: the credential, and so its token cache, is shared rather than recreated. Scopes that start with the current endpoint, such as the default scope derived from it, are rewritten to start with `endpoint`; other scopes are kept as they are."]
             #[must_use]
-            pub fn build(self) -> Client {
-                let endpoint = self.endpoint.unwrap_or_else(|| DEFAULT_ENDPOINT.to_owned());
-                let scopes = self.scopes.unwrap_or_else(|| vec![format!("{}/", endpoint)]);
-                Client::new(endpoint, self.credential, scopes, self.options)
+            pub fn with_endpoint(&self, endpoint: impl Into<String>) -> Client {
+                Client(self.0.with_endpoint(endpoint))
             }
-        }
 
-        impl Client {
-            pub(crate) fn endpoint(&self) -> &str {
-                self.endpoint.as_str()
-            }
-            pub(crate) fn token_credential(&self) -> &dyn azure_core::auth::TokenCredential {
-                self.credential.as_ref()
-            }
-            pub(crate) fn scopes(&self) -> Vec<&str> {
-                self.scopes.iter().map(String::as_str).collect()
-            }
-            pub(crate) async fn send(&self, request: &mut azure_core::Request) -> azure_core::Result<azure_core::Response> {
-                let mut context = azure_core::Context::default();
-                self.pipeline.send(&mut context, request).await
+            #[doc = "Check that every scope is for the host of the endpoint."]
+            #[doc = ""]
+            #[doc = "Scopes that are not URLs, such as bare application ids, are not checked. Fails with an `ErrorKind::Credential` error naming the scope and the endpoint otherwise."]
+            pub fn validate_scopes(&self) -> azure_core::Result<()> {
+                self.0.validate_scopes()
             }
 
+            #mgmt_functions
+
             #[doc = "Create a new `ClientBuilder`."]
             #[must_use]
             pub fn builder(credential: std::sync::Arc<dyn azure_core::auth::TokenCredential>) -> ClientBuilder {
@@ -159,7 +203,6 @@ pub fn create_client(modules: &[String], endpoint: Option<&str>) -> Result<Token
             #[doc = "Create a new `Client`."]
             #[must_use]
             pub fn new(endpoint: impl Into<String>, credential: std::sync::Arc<dyn azure_core::auth::TokenCredential>, scopes: Vec<String>, options: azure_core::ClientOptions) -> Self {
-                let endpoint = endpoint.into();
                 let pipeline = azure_core::Pipeline::new(
                     option_env!("CARGO_PKG_NAME"),
                     option_env!("CARGO_PKG_VERSION"),
@@ -167,16 +210,29 @@ pub fn create_client(modules: &[String], endpoint: Option<&str>) -> Result<Token
                     Vec::new(),
                     Vec::new(),
                 );
-                Self {
-                    endpoint,
-                    credential,
-                    scopes,
-                    pipeline,
-                }
+                Self(azure_core::client::ServiceClient::new(endpoint, credential, scopes, pipeline))
             }
 
             #clients
         }
+
+        mod private {
+            pub trait Sealed {}
+        }
+
+        #[doc = "Static facts about an operation, implemented by every `RequestBuilder` so that generic code, such as audit or retry wrappers, can tell operations apart without matching on URLs."]
+        #[doc = ""]
+        #[doc = "The trait is sealed and cannot be implemented outside this crate, so that methods can be added to it later. Pipeline policies, which see requests rather than builders, get the operation's name, such as `dsc_node::get`, from the `azure_core::OperationName` in their context."]
+        pub trait Operation: private::Sealed {
+            #[doc = "The HTTP method the operation is sent with."]
+            fn method() -> azure_core::Method;
+            #[doc = "The path of the operation as declared by the specification, with a placeholder such as `{resourceGroupName}` for each path parameter."]
+            fn path_template() -> &'static str;
+            #[doc = "Whether the operation changes anything on the service. True for every method but `GET`, except for `POST` operations that only read, such as those that list keys."]
+            fn is_mutation() -> bool {
+                !matches!(Self::method(), azure_core::Method::Get | azure_core::Method::Head)
+            }
+        }
     });
     Ok(code)
 }
@@ -184,34 +240,36 @@ pub fn create_client(modules: &[String], endpoint: Option<&str>) -> Result<Token
 pub fn create_operations(cg: &CodeGen) -> Result<TokenStream> {
     let mut file = TokenStream::new();
     file.extend(quote! {
-
         #![allow(unused_mut)]
         #![allow(unused_variables)]
         #![allow(unused_imports)]
         #![allow(clippy::redundant_clone)]
+    });
+    let api_versions = cg.spec.api_versions();
+    file.extend(create_tag_docs(api_versions.into_iter().max()));
+    file.extend(quote! {
         pub mod models;
     });
     let mut operations_code: IndexMap<Option<String>, OperationCode> = IndexMap::new();
     // println!("input_files {:?}", cg.input_files());
 
-    let operations: Vec<_> = cg.spec.operations()?.into_iter().map(WebOperationGen).collect();
+    let operations: Vec<_> = cg.operations()?.into_iter().map(WebOperationGen).collect();
     let module_names: BTreeSet<_> = operations.iter().flat_map(|op| op.rust_module_name()).collect();
     let module_names: Vec<_> = module_names.into_iter().collect();
-    file.extend(create_client(&module_names, cg.spec.endpoint().as_deref())?);
-
-    let mut errors = TokenStream::new();
-    for operation in &operations {
-        let variant = error_variant(operation)?;
-        let fqn = error_fqn(operation)?;
-        errors.extend(quote! {
-            #[error(transparent)]
-            #variant(#[from] #fqn),
-        });
-    }
+    file.extend(create_client(&module_names, cg.spec.endpoint().as_deref(), cg.is_mgmt())?);
+
+    // the groups that can update their resources, so that reading one returns its ETag
+    let updatable_modules: HashSet<_> = operations
+        .iter()
+        .filter(|op| matches!(op.rust_function_name().as_str(), "update" | "create_or_update"))
+        .map(WebOperationGen::rust_module_name)
+        .collect();
+    let schema_arrays = schema_arrays(cg)?;
 
     for operation in operations {
         let module_name = operation.rust_module_name();
-        let code = create_operation_code(cg, &operation)?;
+        let updatable = updatable_modules.contains(&module_name);
+        let code = create_operation_code(cg, &operation, updatable, &schema_arrays)?;
         // append code to existing module if it already exists
         match operations_code.get_mut(&module_name) {
             Some(operation_code) => {
@@ -240,6 +298,7 @@ pub fn create_operations(cg: &CodeGen) -> Result<TokenStream> {
         match module_name {
             Some(module_name) => {
                 let name = parse_ident(&module_name)?;
+                let extension = ExtensionCode::new(cg, &format!("{module_name}.rs"));
                 file.extend(quote! {
                     pub mod #name {
                         use super::models;
@@ -248,6 +307,7 @@ pub fn create_operations(cg: &CodeGen) -> Result<TokenStream> {
                             #builders
                         }
                         #(#module_code)*
+                        #extension
                     }
                 });
             }
@@ -261,13 +321,45 @@ pub fn create_operations(cg: &CodeGen) -> Result<TokenStream> {
             }
         }
     }
+    file.extend(ExtensionCode::new(cg, "mod.rs").into_token_stream());
     Ok(file)
 }
 
+/// The array properties of each schema that has one, by schema name.
+fn schema_arrays(cg: &CodeGen) -> Result<HashMap<String, SchemaArrays>> {
+    let mut schemas = HashMap::new();
+    for (ref_key, schema) in all_schemas_resolved(&cg.spec)? {
+        let mut arrays = HashMap::new();
+        for (property, items) in schema.array_properties()? {
+            arrays.insert(property.name().to_owned(), TypeNameCode::new(&items)?.qualify_models(true));
+        }
+        if !arrays.is_empty() {
+            let list = schema.list_items()?.map(|(property, _)| property.name().to_owned());
+            schemas.insert(ref_key.name.clone(), SchemaArrays { arrays, list });
+        }
+    }
+    Ok(schemas)
+}
+
+struct SchemaArrays {
+    /// The item type of each array property, by property name
+    arrays: HashMap<String, TypeNameCode>,
+    /// The array property of a list result
+    list: Option<String>,
+}
+
+/// The items of a list result, as the name of its array property and the item type.
+#[derive(Clone)]
+struct ListItems {
+    property_name: String,
+    type_name: TypeNameCode,
+}
+
 struct OperationModuleCode {
     module_name: Ident,
     response_code: ResponseCode,
     request_builder_struct_code: RequestBuilderStructCode,
+    request_builder_operation_code: RequestBuilderOperationCode,
     request_builder_setters_code: RequestBuilderSettersCode,
     request_builder_future_code: RequestBuilderIntoFutureCode,
 }
@@ -311,6 +403,18 @@ impl WebOperationGen {
         parse_ident(&self.rust_function_name())
     }
 
+    /// The name the operation is reported by, such as `dsc_node::get`
+    fn operation_name(&self) -> String {
+        match self.rust_module_name() {
+            Some(module_name) => format!("{}::{}", module_name, self.rust_function_name()),
+            None => self.rust_function_name(),
+        }
+    }
+
+    fn id(&self) -> &str {
+        self.0.id.as_deref().unwrap_or_default()
+    }
+
     fn api_version(&self) -> &str {
         self.0.api_version.as_str()
     }
@@ -326,6 +430,7 @@ impl WebOperationGen {
     fn pageable(&self) -> Option<Pageable> {
         self.0.pageable.as_ref().map(|p| Pageable {
             next_link_name: p.next_link_name.clone(),
+            item_name: p.item_name.clone().unwrap_or_else(|| "value".to_owned()),
         })
     }
 
@@ -346,6 +451,141 @@ fn create_function_name(verb: &WebVerb, path: &str) -> String {
     path.join("_")
 }
 
+/// The settings a request builder has besides the parameters of its operation, which depend on the kind of operation.
+#[derive(Clone)]
+struct BuilderExtras {
+    /// The module name of the tag, which docs link to
+    tag: String,
+    /// `super` for operations that are not in a group, `super::super` otherwise
+    root: TokenStream,
+    /// The name the operation is reported by
+    operation_name: String,
+    /// A `get` of a single resource, which can be conditional on its ETag
+    get: bool,
+    /// A `get` in a group that can update its resources, so that the response has the ETag
+    concurrency_token: bool,
+    /// An `update` or `create_or_update`, which can be conditional on the ETag read by `get`
+    if_match: bool,
+    /// A `PUT` that creates a resource, which a service that deduplicates requests can recognize a retry of
+    idempotency_key: bool,
+    /// An operation that can be told not to overwrite an existing resource, for the resource named in its doc
+    create_if_none_match: Option<String>,
+    /// A `POST` or `PATCH`, which is only retried if marked as idempotent
+    idempotent: bool,
+    /// A `PATCH`, which can ask for the updated resource
+    prefer_representation: bool,
+    /// An operation with a body that can be sent untyped instead
+    raw_body: bool,
+    /// An operation whose body can also be sent as `multipart/form-data`
+    multipart: bool,
+    /// An operation that does not change anything on the service, although its method does not say so
+    read_only: bool,
+    /// A `GET` that can be sent as one of the reads of a batch
+    batch_read: bool,
+    /// The items of the response body, if it is a list result
+    list_items: Option<ListItems>,
+    /// A pageable operation with a next link
+    paging: Option<PagingExtras>,
+}
+
+#[derive(Clone)]
+struct PagingExtras {
+    /// The items of a page can be selected
+    select: bool,
+    /// The parent of the list may not exist
+    on_missing_empty: bool,
+    /// The type of the items of a page, if the page has them in an array
+    item_type: Option<TypeNameCode>,
+    item_name: Ident,
+    next_link_name: Ident,
+}
+
+impl BuilderExtras {
+    fn new(
+        cg: &CodeGen,
+        operation: &WebOperationGen,
+        parameters: &FunctionParams,
+        updatable: bool,
+        response_type: Option<&TypeNameCode>,
+        schema_arrays: &HashMap<String, SchemaArrays>,
+    ) -> Result<Self> {
+        let verb = &operation.0.verb;
+        let function_name = operation.rust_function_name();
+        let id = operation.id();
+        let root = if operation.0.in_group() {
+            quote! { super::super }
+        } else {
+            quote! { super }
+        };
+        let has_header = |name: &str| {
+            parameters
+                .params()
+                .iter()
+                .any(|p| p.kind == ParamKind::Header && p.name.eq_ignore_ascii_case(name))
+        };
+        let get = verb == &WebVerb::Get && function_name == "get" && !has_header("If-None-Match");
+        let if_match = matches!(function_name.as_str(), "update" | "create_or_update") && !has_header("If-Match");
+        let create_if_none_match = if cg.customize.create_if_none_match.iter().any(|create| create == id) && !has_header("If-None-Match") {
+            Some(operation.rust_module_name().unwrap_or_default().replace('_', " "))
+        } else {
+            None
+        };
+        let has_path_params = !parse_path_params(&operation.0.path).is_empty();
+        let response_arrays = response_type
+            .and_then(TypeNameCode::reference_name)
+            .and_then(|name| schema_arrays.get(name));
+        let list_items = response_arrays.and_then(|response_arrays| {
+            let property_name = response_arrays.list.clone()?;
+            let type_name = response_arrays.arrays.get(&property_name)?.clone();
+            Some(ListItems { property_name, type_name })
+        });
+        let paging = match operation.pageable() {
+            Some(Pageable {
+                next_link_name: Some(next_link_name),
+                item_name,
+            }) => {
+                let item_type = response_arrays.and_then(|response_arrays| response_arrays.arrays.get(&item_name).cloned());
+                Some(PagingExtras {
+                    select: has_path_params && !parameters.params().iter().any(|p| p.name == "$select"),
+                    on_missing_empty: has_path_params,
+                    item_type,
+                    item_name: item_name.to_snake_case_ident()?,
+                    next_link_name: next_link_name.to_snake_case_ident()?,
+                })
+            }
+            _ => None,
+        };
+        Ok(Self {
+            tag: cg.tag_mod_name().to_owned(),
+            root,
+            operation_name: operation.operation_name(),
+            get,
+            concurrency_token: get && updatable,
+            if_match,
+            idempotency_key: verb == &WebVerb::Put && matches!(function_name.as_str(), "create" | "create_or_update"),
+            create_if_none_match,
+            idempotent: matches!(verb, WebVerb::Post | WebVerb::Patch),
+            prefer_representation: verb == &WebVerb::Patch,
+            raw_body: operation.0.has_body_parameter(),
+            multipart: operation.0.has_body_parameter() && cg.customize.multipart.iter().any(|multipart| multipart == id),
+            read_only: verb == &WebVerb::Post
+                && (function_name.starts_with("list") || cg.customize.read_only.iter().any(|read_only| read_only == id)),
+            batch_read: cg.is_mgmt() && verb == &WebVerb::Get,
+            list_items,
+            paging,
+        })
+    }
+
+    fn if_none_match(&self) -> bool {
+        self.get || self.create_if_none_match.is_some()
+    }
+
+    /// A link to a section of the tag documentation
+    fn see(&self, text: &str, section: &str) -> String {
+        format!("see [{}](crate::{}#{})", text, self.tag, section)
+    }
+}
+
 /// Calls `azure_core::Request::new` and set the authentication.
 struct NewRequestCode {
     auth: AuthCode,
@@ -364,18 +604,18 @@ impl ToTokens for NewRequestCode {
     }
 }
 
-/// Sets the authentication.
+/// Sets the authentication and the language.
 /// Only bearer token authentication is supported right now.
 struct AuthCode {}
 
 impl ToTokens for AuthCode {
     fn to_tokens(&self, tokens: &mut TokenStream) {
         tokens.extend(quote! {
-            let credential = this.client.token_credential();
-            let token_response = credential
-                .get_token(&this.client.scopes().join(" "))
-                .await?;
+            let token_response = self.client.token(self.claims.as_deref()).await?;
             req.insert_header(azure_core::headers::AUTHORIZATION, format!("Bearer {}", token_response.token.secret()));
+            if let Some(accept_language) = &self.accept_language {
+                req.insert_header(azure_core::headers::ACCEPT_LANGUAGE, accept_language.clone());
+            }
         })
     }
 }
@@ -392,13 +632,12 @@ fn verb_to_tokens(verb: &WebVerb) -> TokenStream {
     }
 }
 
-/// Sets all of the request parameters.
-struct SetRequestParamsCode {
-    content_type: String,
+/// Adds the query parameters to the URL.
+struct SetQueryParamsCode {
     params: FunctionParams,
 }
 
-impl ToTokens for SetRequestParamsCode {
+impl ToTokens for SetQueryParamsCode {
     fn to_tokens(&self, tokens: &mut TokenStream) {
         for param in self.params.params() {
             let FunctionParam {
@@ -408,80 +647,104 @@ impl ToTokens for SetRequestParamsCode {
                 collection_format,
                 ..
             } = param;
+            if kind != &ParamKind::Query {
+                continue;
+            }
             let is_vec = param.is_vec();
-            match kind {
-                ParamKind::Path => {} // handled above
-                ParamKind::Query => {
-                    let query_body = if is_vec {
-                        match collection_format {
-                            CollectionFormat::Multi => Some(
-                                if param.is_string(){
-                                    quote! {
-                                        for value in &this.#param_name_var {
-                                            req.url_mut().query_pairs_mut().append_pair(#param_name, value);
-                                        }
-                                    }
-                                } else {
-                                    quote! {
-                                        for value in &this.#param_name_var {
-                                            req.url_mut().query_pairs_mut().append_pair(#param_name, &value.to_string());
-                                        }
-                                    }
-                                }
-                            ),
-                            CollectionFormat::Csv | // TODO #71
-                            CollectionFormat::Ssv |
-                            CollectionFormat::Tsv |
-                            CollectionFormat::Pipes => None,
-                        }
-                    } else {
-                        Some(if param.is_string() {
+            let query_body = if is_vec {
+                match collection_format {
+                    CollectionFormat::Multi => Some(
+                        if param.is_string(){
                             quote! {
-                                req.url_mut().query_pairs_mut().append_pair(#param_name, #param_name_var);
+                                for value in &self.#param_name_var {
+                                    url.query_pairs_mut().append_pair(#param_name, value);
+                                }
                             }
                         } else {
                             quote! {
-                                req.url_mut().query_pairs_mut().append_pair(#param_name, &#param_name_var.to_string());
-                            }
-                        })
-                    };
-                    if let Some(query_body) = query_body {
-                        if !param.optional() || is_vec {
-                            tokens.extend(quote! {
-                                let #param_name_var = &this.#param_name_var;
-                                #query_body
-                            });
-                        } else {
-                            tokens.extend(quote! {
-                                if let Some(#param_name_var) = &this.#param_name_var {
-                                    #query_body
+                                for value in &self.#param_name_var {
+                                    url.query_pairs_mut().append_pair(#param_name, &value.to_string());
                                 }
-                            });
+                            }
                         }
+                    ),
+                    CollectionFormat::Csv | // TODO #71
+                    CollectionFormat::Ssv |
+                    CollectionFormat::Tsv |
+                    CollectionFormat::Pipes => None,
+                }
+            } else {
+                Some(if param.is_string() {
+                    quote! {
+                        url.query_pairs_mut().append_pair(#param_name, #param_name_var);
+                    }
+                } else {
+                    quote! {
+                        url.query_pairs_mut().append_pair(#param_name, &#param_name_var.to_string());
                     }
+                })
+            };
+            if let Some(query_body) = query_body {
+                if is_vec {
+                    tokens.extend(query_body);
+                } else if !param.optional() {
+                    tokens.extend(quote! {
+                        let #param_name_var = &self.#param_name_var;
+                        #query_body
+                    });
+                } else {
+                    tokens.extend(quote! {
+                        if let Some(#param_name_var) = &self.#param_name_var {
+                            #query_body
+                        }
+                    });
                 }
+            }
+        }
+    }
+}
+
+/// Sets the headers and the body of the request.
+struct SetRequestParamsCode {
+    content_type: String,
+    params: FunctionParams,
+    extras: BuilderExtras,
+}
+
+impl ToTokens for SetRequestParamsCode {
+    fn to_tokens(&self, tokens: &mut TokenStream) {
+        for param in self.params.params() {
+            let FunctionParam {
+                name: param_name,
+                variable_name: param_name_var,
+                kind,
+                ..
+            } = param;
+            let is_vec = param.is_vec();
+            match kind {
+                ParamKind::Path | ParamKind::Query => {} // part of the URL
                 ParamKind::Header => {
                     // always use lowercase header names
                     let header_name = param_name.to_lowercase();
                     if !param.optional() || is_vec {
                         if param.is_string() {
                             tokens.extend(quote! {
-                                req.insert_header(#header_name, &this.#param_name_var);
+                                req.insert_header(#header_name, &self.#param_name_var);
                             });
                         } else {
                             tokens.extend(quote! {
-                                req.insert_header(#header_name, &this.#param_name_var.to_string());
+                                req.insert_header(#header_name, &self.#param_name_var.to_string());
                             });
                         }
                     } else if param.is_string() {
                         tokens.extend(quote! {
-                            if let Some(#param_name_var) = &this.#param_name_var {
+                            if let Some(#param_name_var) = &self.#param_name_var {
                                 req.insert_header(#header_name, #param_name_var);
                             }
                         });
                     } else {
                         tokens.extend(quote! {
-                            if let Some(#param_name_var) = &this.#param_name_var {
+                            if let Some(#param_name_var) = &self.#param_name_var {
                                 req.insert_header(#header_name, &#param_name_var.to_string());
                             }
                         });
@@ -496,21 +759,57 @@ impl ToTokens for SetRequestParamsCode {
                     } else {
                         quote! {}
                     };
-
-                    if !param.optional() || is_vec {
+                    let prefer_representation = if self.extras.prefer_representation {
+                        quote! {
+                            if self.prefer_representation {
+                                req.insert_header(azure_core::headers::PREFER, "return=representation");
+                            }
+                        }
+                    } else {
+                        quote! {}
+                    };
+                    let typed_body = if !param.optional() || is_vec {
+                        quote! {
+                            azure_core::to_json(&self.#param_name_var)?
+                        }
+                    } else {
+                        quote! {
+                            if let Some(#param_name_var) = &self.#param_name_var {
+                                azure_core::to_json(#param_name_var)?
+                            } else {
+                                azure_core::EMPTY_BODY
+                            }
+                        }
+                    };
+                    if self.extras.multipart {
                         tokens.extend(quote! {
-                            #set_content_type
-                            let req_body = azure_core::to_json(&this.#param_name_var)?;
+                            #prefer_representation
+                            let req_body = match (&self.raw_body, &self.multipart) {
+                                (Some((body, content_type)), _) => {
+                                    req.insert_header("content-type", content_type.clone());
+                                    body.clone()
+                                }
+                                (None, Some(form)) => {
+                                    req.insert_header("content-type", form.content_type());
+                                    form.to_bytes()
+                                }
+                                (None, None) => {
+                                    #set_content_type
+                                    #typed_body
+                                }
+                            };
                         });
                     } else {
                         tokens.extend(quote! {
-                            let req_body =
-                                if let Some(#param_name_var) = &this.#param_name_var {
-                                    #set_content_type
-                                    azure_core::to_json(#param_name_var)?
-                                } else {
-                                    azure_core::EMPTY_BODY
-                                };
+                            #set_content_type
+                            #prefer_representation
+                            let req_body = match &self.raw_body {
+                                Some((body, content_type)) => {
+                                    req.insert_header("content-type", content_type.clone());
+                                    body.clone()
+                                }
+                                None => #typed_body,
+                            };
                         });
                     }
                 }
@@ -537,8 +836,13 @@ impl ToTokens for SetRequestParamsCode {
 }
 
 // Create code for the web operation
-fn create_operation_code(cg: &CodeGen, operation: &WebOperationGen) -> Result<OperationCode> {
-    let parameters = &FunctionParams::new(operation)?;
+fn create_operation_code(
+    cg: &CodeGen,
+    operation: &WebOperationGen,
+    updatable: bool,
+    schema_arrays: &HashMap<String, SchemaArrays>,
+) -> Result<OperationCode> {
+    let parameters = &FunctionParams::new(cg, operation)?;
 
     let verb = operation.0.verb.clone();
     let auth = AuthCode {};
@@ -558,20 +862,30 @@ fn create_operation_code(cg: &CodeGen, operation: &WebOperationGen) -> Result<Op
         .unwrap_or_else(|| cg.spec.pick_produces().unwrap_or(content_type::APPLICATION_JSON))
         .to_string();
 
-    let request_builder = SetRequestCode::new(operation, parameters, consumes);
+    let response_type = ResponseCode::new(operation, produces.clone(), None)?.response_type();
+    let extras = BuilderExtras::new(cg, operation, parameters, updatable, response_type.as_ref(), schema_arrays)?;
+    let request_builder = SetRequestCode::new(operation, parameters, consumes, &extras);
     let in_operation_group = operation.0.in_group();
-    let client_function_code = ClientFunctionCode::new(operation, parameters, in_operation_group)?;
-    let request_builder_struct_code = RequestBuilderStructCode::new(parameters, in_operation_group);
-    let request_builder_setters_code = RequestBuilderSettersCode::new(parameters);
-    let response_code = ResponseCode::new(operation, produces)?;
+    let client_function_code = ClientFunctionCode::new(operation, parameters, in_operation_group, &extras)?;
+    let request_builder_struct_code = RequestBuilderStructCode::new(parameters, &extras);
+    let request_builder_operation_code = RequestBuilderOperationCode::new(operation, &extras);
+    let request_builder_setters_code = RequestBuilderSettersCode::new(parameters, &extras);
+    let response_code = ResponseCode::new(operation, produces, Some(&extras))?;
     let long_running_operation = operation.0.long_running_operation;
-    let request_builder_future_code =
-        RequestBuilderIntoFutureCode::new(new_request_code, request_builder, response_code.clone(), long_running_operation)?;
+    let request_builder_future_code = RequestBuilderIntoFutureCode::new(
+        new_request_code,
+        request_builder,
+        response_code.clone(),
+        long_running_operation,
+        parameters,
+        &extras,
+    )?;
 
     let module_code = OperationModuleCode {
         module_name: operation.function_name()?,
         response_code,
         request_builder_struct_code,
+        request_builder_operation_code,
         request_builder_setters_code,
         request_builder_future_code,
     };
@@ -582,7 +896,7 @@ fn create_operation_code(cg: &CodeGen, operation: &WebOperationGen) -> Result<Op
     })
 }
 
-/// Set all body and parameters for the request.
+/// Set all headers and the body for the request.
 struct SetRequestCode {
     has_param_api_version: bool,
     has_param_x_ms_version: bool,
@@ -591,10 +905,11 @@ struct SetRequestCode {
     parameters: FunctionParams,
     has_body_parameter: bool,
     is_post: bool,
+    extras: BuilderExtras,
 }
 
 impl SetRequestCode {
-    fn new(operation: &WebOperationGen, parameters: &FunctionParams, consumes: String) -> Self {
+    fn new(operation: &WebOperationGen, parameters: &FunctionParams, consumes: String, extras: &BuilderExtras) -> Self {
         let is_post = operation.0.verb == WebVerb::Post;
         Self {
             has_param_api_version: parameters.has_api_version,
@@ -604,17 +919,33 @@ impl SetRequestCode {
             parameters: parameters.clone(),
             has_body_parameter: operation.0.has_body_parameter(),
             is_post,
+            extras: extras.clone(),
         }
     }
 }
 
 impl ToTokens for SetRequestCode {
     fn to_tokens(&self, tokens: &mut TokenStream) {
-        // api-version param
-        if self.has_param_api_version {
-            let api_version = &self.api_version;
+        if self.extras.idempotency_key {
             tokens.extend(quote! {
-                req.url_mut().query_pairs_mut().append_pair(azure_core::query_param::API_VERSION, #api_version);
+                if let Some((key, first_sent)) = &self.idempotency_key {
+                    req.insert_header(azure_core::headers::REPEATABILITY_REQUEST_ID, key.clone());
+                    req.insert_header(azure_core::headers::REPEATABILITY_FIRST_SENT, azure_core::date::to_rfc1123(first_sent));
+                }
+            });
+        }
+        if self.extras.if_match {
+            tokens.extend(quote! {
+                if let Some(if_match) = &self.if_match {
+                    req.insert_header(azure_core::headers::IF_MATCH, if_match.etag().to_owned());
+                }
+            });
+        }
+        if self.extras.if_none_match() {
+            tokens.extend(quote! {
+                if let Some(if_none_match) = &self.if_none_match {
+                    req.insert_header(azure_core::headers::IF_NONE_MATCH, if_none_match);
+                }
             });
         }
         if self.has_param_x_ms_version {
@@ -628,6 +959,7 @@ impl ToTokens for SetRequestCode {
         let build_request_params = SetRequestParamsCode {
             content_type: self.consumes.clone(),
             params: self.parameters.clone(),
+            extras: self.extras.clone(),
         };
         tokens.extend(build_request_params.into_token_stream());
 
@@ -654,6 +986,7 @@ struct ResponseCode {
     pageable: Option<Pageable>,
     produces: String,
     headers: HeadersCode,
+    extras: Option<BuilderExtras>,
 }
 
 #[derive(Clone)]
@@ -767,6 +1100,7 @@ impl ToTokens for HeaderCode {
 #[derive(Clone)]
 struct Pageable {
     next_link_name: Option<String>,
+    item_name: String,
 }
 
 /// A single status code response of an operation.
@@ -777,7 +1111,7 @@ struct StatusResponseCode {
 }
 
 impl ResponseCode {
-    fn new(operation: &WebOperationGen, produces: String) -> Result<Self> {
+    fn new(operation: &WebOperationGen, produces: String, extras: Option<&BuilderExtras>) -> Result<Self> {
         let success_responses = operation.success_responses();
         let status_responses = success_responses
             .iter()
@@ -804,6 +1138,7 @@ impl ResponseCode {
             pageable: operation.pageable(),
             produces,
             headers: HeadersCode::new(headers)?,
+            extras: extras.cloned(),
         })
     }
 
@@ -830,72 +1165,203 @@ impl ResponseCode {
     fn produces_xml(&self) -> bool {
         self.produces == content_type::APPLICATION_XML
     }
+
+    /// A pageable response whose next link is not part of the response schema
+    fn ignores_next_link(&self) -> bool {
+        matches!(&self.pageable, Some(Pageable { next_link_name: None, .. }))
+    }
 }
 
 impl ToTokens for ResponseCode {
     fn to_tokens(&self, tokens: &mut TokenStream) {
+        let extras = self.extras.as_ref().expect("response code for a request builder");
+        let root = &extras.root;
+        let operation_name = &extras.operation_name;
         tokens.extend(quote! {
             pub struct Response(azure_core::Response);
         });
+
+        let not_modified_guard = if extras.get {
+            quote! {
+                if self.is_not_modified() {
+                    return Err(azure_core::error::ErrorKind::HttpResponse {
+                        status: azure_core::StatusCode::NotModified,
+                        error_code: None,
+                    }
+                    .into_error());
+                }
+            }
+        } else {
+            quote! {}
+        };
+
+        let mut body_fns = TokenStream::new();
         if let Some(response_type) = self.response_type() {
-            let deserialize_body = if response_type.is_bytes() {
+            let into_body = if response_type.is_bytes() {
                 quote! {
-                    let body = bytes;
+                    pub async fn into_body(self) -> azure_core::Result<#response_type> {
+                        #not_modified_guard
+                        self.into_byte_stream().collect().await
+                    }
                 }
             } else if self.produces_xml() {
                 quote! {
-                    let body: #response_type = azure_core::xml::read_xml(&bytes)?;
+                    pub async fn into_body(self) -> azure_core::Result<#response_type> {
+                        #not_modified_guard
+                        let bytes = self.into_byte_stream().collect().await?;
+                        let body: #response_type = azure_core::xml::read_xml(&bytes)?;
+                        Ok(body)
+                    }
                 }
-            } else {
+            } else if self.ignores_next_link() {
                 quote! {
-                    let body: #response_type = serde_json::from_slice(&bytes)?;
+                    pub async fn into_body(self) -> azure_core::Result<#response_type> {
+                        #not_modified_guard
+                        let status = self.0.status();
+                        let continuation = azure_core::continuation_from_headers(self.0.headers());
+                        let bytes = self.into_byte_stream().collect().await?;
+                        azure_core::client::warn_on_ignored_next_link(#operation_name, continuation.as_deref(), &bytes);
+                        let body: #response_type = azure_core::client::from_json_body(#operation_name, status, &bytes)?;
+                        Ok(body)
+                    }
                 }
-            };
-
-            let headers_fn = if self.headers.has_headers() {
-                quote! { pub fn headers(&self) -> Headers { Headers(self.0.headers()) } }
             } else {
-                quote! {}
-            };
-
-            tokens.extend(quote! {
-                impl Response {
+                quote! {
                     pub async fn into_body(self) -> azure_core::Result<#response_type> {
-                        let bytes = self.0.into_body().collect().await?;
-                        #deserialize_body
+                        #not_modified_guard
+                        let status = self.0.status();
+                        let bytes = self.into_byte_stream().collect().await?;
+                        let body: #response_type = azure_core::client::from_json_body(#operation_name, status, &bytes)?;
                         Ok(body)
                     }
-                    pub fn into_raw_response(self) -> azure_core::Response {
-                        self.0
-                    }
-                    pub fn as_raw_response(&self) -> &azure_core::Response {
-                        &self.0
-                    }
-                    #headers_fn
                 }
-                impl From<Response> for azure_core::Response {
-                    fn from(rsp: Response) -> Self {
-                        rsp.into_raw_response()
+            };
+            body_fns.extend(into_body);
+
+            let is_json_model = response_type.is_reference() && !self.produces_xml();
+            if let (true, Some(list_items)) = (is_json_model, &extras.list_items) {
+                let ListItems { property_name, type_name } = list_items;
+                let doc = format!(
+                    "Stream the items of the page as they are received, instead of buffering the whole body like `into_body`. Everything but the `{property_name}` array, including the link to the next page, is skipped."
+                );
+                body_fns.extend(quote! {
+                    #[doc = #doc]
+                    pub fn into_body_streaming(self) -> futures::stream::BoxStream<'static, azure_core::Result<#type_name>> {
+                        self.into_byte_stream().json_array_items(#property_name)
                     }
-                }
-                impl AsRef<azure_core::Response> for Response {
-                    fn as_ref(&self) -> &azure_core::Response {
-                        self.as_raw_response()
+                });
+            }
+            if is_json_model {
+                let doc = format!(
+                    "Return the response body as untyped JSON; {}.",
+                    extras.see("responses", "responses")
+                );
+                body_fns.extend(quote! {
+                    #[doc = #doc]
+                    pub async fn into_value(self) -> azure_core::Result<serde_json::Value> {
+                        #not_modified_guard
+                        let status = self.0.status();
+                        let bytes = self.into_byte_stream().collect().await?;
+                        let body: serde_json::Value = azure_core::client::from_json_body(#operation_name, status, &bytes)?;
+                        Ok(body)
                     }
+                });
+            }
+        }
+        if extras.get {
+            body_fns.extend(quote! {
+                #[doc = "Whether the service answered a request sent with `if_none_match` with `304 Not Modified`. Such a response has no body, but carries the headers the service sent, so `concurrency_token`, `client_request_id` and `elapsed` still report them."]
+                #[doc = ""]
+                #[doc = "Derived from the status code alone, so polling loops can count unchanged responses against transferred bodies to track their cache hit rate."]
+                pub fn is_not_modified(&self) -> bool {
+                    self.0.status() == azure_core::StatusCode::NotModified
                 }
             });
-            tokens.extend(self.headers.to_token_stream());
         }
+
+        let concurrency_token_fn = if extras.concurrency_token {
+            let doc = format!("The resource's ETag; {}.", extras.see("responses", "responses"));
+            quote! {
+                #[doc = #doc]
+                pub fn concurrency_token(&self) -> Option<#root::ConcurrencyToken> {
+                    #root::ConcurrencyToken::from_headers(self.0.headers())
+                }
+            }
+        } else {
+            quote! {}
+        };
+        let headers_fn = if self.headers.has_headers() {
+            quote! { pub fn headers(&self) -> Headers { Headers(self.0.headers()) } }
+        } else {
+            quote! {}
+        };
+        let rate_limit_remaining_doc = format!("The remaining request quota; {}.", extras.see("responses", "responses"));
+        let client_request_id_doc = format!(
+            "The `x-ms-client-request-id` the request was sent with; {}.",
+            extras.see("responses", "responses")
+        );
+        let elapsed_doc = format!("How long the request took; {}.", extras.see("responses", "responses"));
+
+        tokens.extend(quote! {
+            impl Response {
+                #body_fns
+                #[doc = "Stream the response body in chunks as they are received, instead of buffering all of it like `into_body`."]
+                pub fn into_byte_stream(self) -> azure_core::ResponseBody {
+                    self.0.into_body()
+                }
+                pub fn status(&self) -> azure_core::StatusCode {
+                    self.0.status()
+                }
+                pub fn into_raw_response(self) -> azure_core::Response {
+                    self.0
+                }
+                #[doc = "Read the whole response and return its status, headers and body as received, for forwarding it verbatim without going through `models`."]
+                pub async fn into_raw_parts(self) -> azure_core::Result<(azure_core::StatusCode, azure_core::headers::Headers, bytes::Bytes)> {
+                    let (status, headers, body) = self.0.deconstruct();
+                    Ok((status, headers, body.collect().await?))
+                }
+                pub fn as_raw_response(&self) -> &azure_core::Response {
+                    &self.0
+                }
+                #[doc = #rate_limit_remaining_doc]
+                pub fn rate_limit_remaining(&self) -> Option<u32> {
+                    azure_core::client::rate_limit_remaining(self.0.headers())
+                }
+                #[doc = #client_request_id_doc]
+                pub fn client_request_id(&self) -> Option<&str> {
+                    self.0.headers().get_optional_str(&azure_core::headers::CLIENT_REQUEST_ID)
+                }
+                #[doc = #elapsed_doc]
+                pub fn elapsed(&self) -> std::time::Duration {
+                    self.0.elapsed().unwrap_or_default()
+                }
+                #concurrency_token_fn
+                #headers_fn
+            }
+            impl From<Response> for azure_core::Response {
+                fn from(rsp: Response) -> Self {
+                    rsp.into_raw_response()
+                }
+            }
+            impl AsRef<azure_core::Response> for Response {
+                fn as_ref(&self) -> &azure_core::Response {
+                    self.as_raw_response()
+                }
+            }
+        });
+        tokens.extend(self.headers.to_token_stream());
     }
 }
 
-/// The `send` function of the request builder.
+/// The `url` and `send` functions of the request builder, and the functions built on them.
 struct RequestBuilderIntoFutureCode {
     new_request_code: NewRequestCode,
     request_builder: SetRequestCode,
     response_code: ResponseCode,
-    url_args: Vec<Ident>,
+    url_args: Vec<(String, Ident, bool)>,
     long_running_operation: bool,
+    query_params: SetQueryParamsCode,
+    extras: BuilderExtras,
 }
 
 impl RequestBuilderIntoFutureCode {
@@ -904,16 +1370,33 @@ impl RequestBuilderIntoFutureCode {
         request_builder: SetRequestCode,
         response_code: ResponseCode,
         long_running_operation: bool,
+        parameters: &FunctionParams,
+        extras: &BuilderExtras,
     ) -> Result<Self> {
         let params = parse_path_params(&new_request_code.path);
-        let url_args: Result<Vec<_>> = params.iter().map(|s| s.to_snake_case_ident()).collect();
-        let url_args = url_args?;
+        let url_args = params
+            .iter()
+            .map(|name| {
+                let variable_name = name.to_snake_case_ident()?;
+                // path parameters that are not strings, such as enums, are formatted with `Display`
+                let is_string = parameters
+                    .params()
+                    .iter()
+                    .find(|p| p.kind == ParamKind::Path && p.variable_name == variable_name)
+                    .map_or(true, |p| p.is_string());
+                Ok((variable_name.to_string(), variable_name, is_string))
+            })
+            .collect::<Result<Vec<_>>>()?;
         Ok(Self {
             new_request_code,
             request_builder,
             response_code,
             url_args,
             long_running_operation,
+            query_params: SetQueryParamsCode {
+                params: parameters.clone(),
+            },
+            extras: extras.clone(),
         })
     }
 }
@@ -922,14 +1405,51 @@ impl ToTokens for RequestBuilderIntoFutureCode {
     fn to_tokens(&self, tokens: &mut TokenStream) {
         let new_request_code = &self.new_request_code;
         let request_builder = &self.request_builder;
+        let extras = &self.extras;
+        let root = &extras.root;
+        let operation_name = &extras.operation_name;
 
-        let url_args = self.url_args.iter().map(|url_arg| {
-            quote! { &this.#url_arg }
+        let url_args = self.url_args.iter().map(|(name, url_arg, is_string)| {
+            if *is_string {
+                quote! { &azure_core::client::path_segment(#name, &self.#url_arg)? }
+            } else {
+                quote! { &self.#url_arg }
+            }
         });
         let url_str_args = quote! { #(#url_args),* };
 
         let fpath = format!("{{}}{}", &format_path(&new_request_code.path));
 
+        let api_version = if request_builder.has_param_api_version {
+            let api_version = &request_builder.api_version;
+            quote! {
+                url.query_pairs_mut().append_pair(azure_core::query_param::API_VERSION, #api_version);
+            }
+        } else {
+            quote! {}
+        };
+        let query_params = &self.query_params;
+        let select = if extras.paging.as_ref().map_or(false, |paging| paging.select) {
+            quote! {
+                if let Some(select) = &self.select {
+                    url.query_pairs_mut().append_pair("$select", select);
+                }
+            }
+        } else {
+            quote! {}
+        };
+        let url_fn = quote! {
+            #[doc = "Build the URL the request is sent to, including the api-version and any query parameters set on the builder, without sending the request."]
+            pub fn url(&self) -> azure_core::Result<azure_core::Url> {
+                let mut url = azure_core::Url::parse(&format!(#fpath, self.client.endpoint(), #url_str_args))?;
+                #api_version
+                #query_params
+                #select
+                azure_core::client::append_query_params(&mut url, &self.query_params);
+                Ok(url)
+            }
+        };
+
         let mut match_status = TokenStream::new();
         for status_response in &self.response_code.status_responses {
             let status_code_name = &status_response.status_code_name;
@@ -938,12 +1458,52 @@ impl ToTokens for RequestBuilderIntoFutureCode {
             });
         }
         match_status.extend(quote! {
-            status_code => {
-                Err(azure_core::error::Error::from(azure_core::error::ErrorKind::HttpResponse { status: status_code, error_code: None }))
-            }
+            _ => Err(azure_core::client::unexpected_status(&rsp)),
         });
 
-        let into_future = if let Some(response_type) = self.response_code.response_type() {
+        let send_request = if extras.idempotent {
+            let send = quote! {
+                self.client.send_with_context(&mut req, #operation_name, context).await
+            };
+            let send = if extras.if_match {
+                quote! { #send.map_err(azure_core::concurrency::precondition_failed)? }
+            } else {
+                quote! { #send? }
+            };
+            quote! {
+                let mut context = azure_core::Context::new();
+                if self.idempotent {
+                    context.insert(azure_core::Idempotent);
+                }
+                Ok(Response(#send))
+            }
+        } else if extras.if_match {
+            quote! {
+                Ok(Response(self.client.send(&mut req, #operation_name).await.map_err(azure_core::concurrency::precondition_failed)?))
+            }
+        } else {
+            quote! {
+                Ok(Response(self.client.send(&mut req, #operation_name).await?))
+            }
+        };
+
+        let response_type = self.response_code.response_type();
+        let into_future = if let Some(response_type) = &response_type {
+            let get_if_exists = if extras.get {
+                quote! {
+                    #[doc = "Send the request and return the response body, or `None` if the resource does not exist."]
+                    pub fn get_if_exists(self) -> futures::future::BoxFuture<'static, azure_core::Result<Option<#response_type>>> {
+                        Box::pin(async move {
+                            match azure_core::client::not_found_as_none(self.send().await)? {
+                                Some(rsp) => Ok(Some(rsp.into_body().await?)),
+                                None => Ok(None),
+                            }
+                        })
+                    }
+                }
+            } else {
+                quote! {}
+            };
             quote! {
                 #[doc = "Send the request and return the response body."]
                 pub fn into_future(self) -> futures::future::BoxFuture<'static, azure_core::Result<#response_type>> {
@@ -953,34 +1513,170 @@ impl ToTokens for RequestBuilderIntoFutureCode {
                         }
                     )
                 }
+                #[doc = "Send the request and return the response body together with the response headers, such as the `ETag`."]
+                pub fn into_future_with_headers(self) -> futures::future::BoxFuture<'static, azure_core::Result<(#response_type, azure_core::headers::Headers)>> {
+                    Box::pin(async move {
+                        let rsp = self.send().await?;
+                        let headers = rsp.as_raw_response().headers().clone();
+                        Ok((rsp.into_body().await?, headers))
+                    })
+                }
+                #get_if_exists
+                #[doc = "Send the request and return the response body, blocking the current thread."]
+                #[cfg(feature = "blocking")]
+                pub fn call(self) -> azure_core::Result<#response_type> {
+                    azure_core::blocking::block_on(self.into_future())
+                }
             }
         } else {
-            quote! {}
+            quote! {
+                #[doc = "Send the request and return the response, blocking the current thread."]
+                #[cfg(feature = "blocking")]
+                pub fn call(self) -> azure_core::Result<Response> {
+                    azure_core::blocking::block_on(self.send())
+                }
+            }
         };
 
         let send_future = quote! {
             #[doc = "Send the request and returns the response."]
-            pub fn send(self) -> futures::future::BoxFuture<'static, azure_core::Result<Response>> {
-                Box::pin({
-                    let this = self.clone();
-                    async move {
-                        let url = azure_core::Url::parse(&format!(#fpath, this.client.endpoint(), #url_str_args))?;
-                        #new_request_code
-                        #request_builder
-                        req.set_body(req_body);
-                        Ok(Response(this.client.send(&mut req).await?))
-                    }
-                })
+            #[doc = ""]
+            #[doc = "The builder is not consumed, so a polling loop can build it once and call `send` on every iteration; each call fetches a fresh token. The returned future is `'static` because it owns a clone of the builder, so it can be spawned or stored."]
+            pub fn send(&self) -> futures::future::BoxFuture<'static, azure_core::Result<Response>> {
+                let this = self.clone();
+                Box::pin(async move { this.send_ref().await })
+            }
+            #[doc = "Send the request without consuming the builder."]
+            #[doc = ""]
+            #[doc = "Unlike `send`, which clones the builder so that its future is `'static`, the request is built from borrowed fields, so a builder kept across calls, such as in a polling loop, is sent again without being cloned. The future borrows the builder in turn."]
+            pub async fn send_ref(&self) -> azure_core::Result<Response> {
+                let url = self.url()?;
+                #new_request_code
+                #request_builder
+                req.set_body(req_body);
+                #send_request
             }
             #into_future
         };
 
-        let fut = if let Some(pageable) = &self.response_code.pageable {
-            // TODO: Pageable requires the values to be part of the response schema,
-            // however, some schemas do this via the header x-ms-continuation rather than
-            // provide a next_link_name.  For now, those cases get documented that we don't
-            // poll and move on.
-            if pageable.next_link_name.is_none() {
+        let fut = match (&self.response_code.pageable, &extras.paging) {
+            (Some(pageable), Some(paging)) => {
+                let response_type = response_type.expect("pageable response has a body");
+                // the link to the next page may leave out the api-version and the query parameters set on the builder,
+                // which are carried over from the url of the first page
+                let first_page = if paging.on_missing_empty {
+                    quote! {
+                        let rsp = self.client.send(&mut req, #operation_name).await;
+                        if self.on_missing_empty {
+                            match azure_core::client::not_found_as_none(rsp)? {
+                                Some(rsp) => rsp,
+                                None => return Ok(Default::default()),
+                            }
+                        } else {
+                            rsp?
+                        }
+                    }
+                } else {
+                    quote! {
+                        self.client.send(&mut req, #operation_name).await?
+                    }
+                };
+                let next_link_name = &paging.next_link_name;
+                let item_fns = if let Some(item_type) = &paging.item_type {
+                    let item_name = &paging.item_name;
+                    quote! {
+                        #[doc = "Stream the items of every page, stopping early if `max_items` is set."]
+                        pub fn into_item_stream(self) -> futures::stream::BoxStream<'static, azure_core::Result<#item_type>> {
+                            let max_items = self.max_items;
+                            azure_core::client::item_stream(self.into_stream(), max_items, |page| page.#item_name)
+                        }
+                        #[doc = "Stream the items of each page together with the continuation token that resumes after it, or `None` after the last page."]
+                        #[doc = ""]
+                        #[doc = "Save the token once a page has been processed and pass it to `resume_from` to carry on from there later. `max_items` is not applied."]
+                        pub fn into_checkpoint_stream(self) -> futures::stream::BoxStream<'static, azure_core::Result<(Vec<#item_type>, Option<String>)>> {
+                            azure_core::client::checkpoint_stream(self.into_stream(), |page| page.#item_name)
+                        }
+                    }
+                } else {
+                    quote! {}
+                };
+                let call = if let Some(item_type) = &paging.item_type {
+                    quote! {
+                        #[doc = "Fetch every page and return all items, blocking the current thread. Stops early if `max_items` is set."]
+                        #[cfg(feature = "blocking")]
+                        pub fn call(self) -> azure_core::Result<Vec<#item_type>> {
+                            azure_core::blocking::block_on(futures::TryStreamExt::try_collect(self.into_item_stream()))
+                        }
+                    }
+                } else {
+                    quote! {}
+                };
+                let _ = pageable;
+                quote! {
+                    #item_fns
+                    pub fn into_stream(self) -> azure_core::Pageable<#response_type, azure_core::error::Error> {
+                        let make_request = move |continuation: Option<String>| {
+                            let continuation = continuation.or_else(|| self.resume_from.clone());
+                            self.clone().fetch_page(continuation)
+                        };
+                        azure_core::Pageable::new(make_request)
+                    }
+                    #[doc = "Fetch a single page and return its body together with the continuation token for the next page, if any."]
+                    #[doc = ""]
+                    #[doc = "Pass `None` to fetch the first page, or a token returned by a previous call to fetch the page that follows it."]
+                    pub fn page(&self, continuation: Option<String>) -> futures::future::BoxFuture<'static, azure_core::Result<(#response_type, Option<String>)>> {
+                        let this = self.clone();
+                        Box::pin(async move {
+                            let body = this.fetch_page(continuation).await?;
+                            let continuation = azure_core::Continuable::continuation(&body);
+                            Ok((body, continuation))
+                        })
+                    }
+                    fn fetch_page(self, continuation: Option<String>) -> futures::future::BoxFuture<'static, azure_core::Result<#response_type>> {
+                        Box::pin(async move {
+                            let mut url = self.url()?;
+                            let rsp = match continuation {
+                                Some(value) => {
+                                    let first_page_url = url;
+                                    url = azure_core::next_page_url(&first_page_url, &value)?;
+                                    azure_core::client::carry_query_params(&mut url, &first_page_url, &self.query_params);
+                                    #new_request_code
+                                    if let Some(token) = azure_core::continuation_header(&value) {
+                                        req.insert_header(azure_core::headers::CONTINUATION, token.to_owned());
+                                    }
+                                    let req_body = azure_core::EMPTY_BODY;
+                                    req.set_body(req_body);
+                                    self.client.send(&mut req, #operation_name).await?
+                                }
+                                None => {
+                                    #new_request_code
+                                    #request_builder
+                                    req.set_body(req_body);
+                                    #first_page
+                                }
+                            };
+                            let rsp = match rsp.status() {
+                                #match_status
+                            };
+                            let rsp = rsp?;
+                            // the next link may be a header instead of part of the body
+                            let continuation = azure_core::continuation_from_headers(rsp.as_raw_response().headers());
+                            let mut body = rsp.into_body().await?;
+                            if body.#next_link_name.is_none() {
+                                body.#next_link_name = continuation;
+                            }
+                            Ok(body)
+                        })
+                    }
+                    #call
+                }
+            }
+            (Some(_), None) => {
+                // TODO: Pageable requires the values to be part of the response schema,
+                // however, some schemas do this via the header x-ms-continuation rather than
+                // provide a next_link_name.  For now, those cases get documented that we don't
+                // poll and move on.
+                //
                 // most often when this happens, the continuation token is provided
                 // by an HTTP Header x-ms-continuation, which should be extracted
                 // from the response.
@@ -988,79 +1684,92 @@ impl ToTokens for RequestBuilderIntoFutureCode {
                 // Note, this is only *sometimes* this is specified in the spec.
                 //
                 // Ref: https://github.com/Azure/azure-sdk-for-rust/issues/446
-                let mut fut = quote! { #[doc = "only the first response will be fetched as the continuation token is not part of the response schema"]};
+                let mut fut = quote! { #url_fn #[doc = "only the first response will be fetched as the continuation token is not part of the response schema"]};
                 fut.extend(send_future);
                 fut
-            } else {
-                let mut stream_api_version = quote! {};
-
-                // per discussion in SDK meeting, we should always set the
-                // api-version on the request if we have a version.
-                if request_builder.has_param_api_version {
-                    let api_version = &request_builder.api_version;
-                    stream_api_version = quote! {
-                        let has_api_version_already = req.url_mut().query_pairs().any(|(k, _)| k == azure_core::query_param::API_VERSION);
-                        if !has_api_version_already {
-                            req.url_mut().query_pairs_mut().append_pair(azure_core::query_param::API_VERSION, #api_version);
-                        }
-                    };
+            }
+            (None, _) => {
+                if self.long_running_operation {
+                    // TODO:  Long running options should also move to the Pageable stream
+                    // model, however this is not possible at the moment because the
+                    // continuation token is often not returned in the response body, but
+                    // instead a header which we don't include as part of the response
+                    // model.
+                    //
+                    // As is, Pageable requires implementing the Continuable trait on the
+                    // response object.
+                    //
+                    // ref: https://github.com/Azure/azure-sdk-for-rust/issues/741
+                    let mut fut = quote! { #url_fn #[doc = "only the first response will be fetched as long running operations are not supported yet"]};
+                    fut.extend(send_future);
+                    fut
+                } else {
+                    quote! { #url_fn #send_future }
                 }
+            }
+        };
+        let fut = if extras.paging.is_some() && self.response_code.pageable.is_some() {
+            quote! { #url_fn #fut }
+        } else {
+            fut
+        };
+        let _ = root;
+        tokens.extend(fut);
+    }
+}
 
-                let response_type = self.response_code.response_type().expect("pageable response has a body");
-                quote! {
-                    pub fn into_stream(self) -> azure_core::Pageable<#response_type, azure_core::error::Error> {
-                        let make_request = move |continuation: Option<String>| {
-                            let this = self.clone();
-                            async move {
-                                let mut url = azure_core::Url::parse(&format!(#fpath, this.client.endpoint(), #url_str_args))?;
-
-                                let rsp = match continuation {
-                                    Some(value) => {
-                                        url.set_path("");
-                                        url = url.join(&value)?;
-                                        #new_request_code
-                                        #stream_api_version
-                                        let req_body = azure_core::EMPTY_BODY;
-                                        req.set_body(req_body);
-                                        this.client.send(&mut req).await?
-                                    }
-                                    None => {
-                                        #new_request_code
-                                        #request_builder
-                                        req.set_body(req_body);
-                                        this.client.send(&mut req).await?
-                                    }
-                                };
-                                let rsp =
-                                    match rsp.status() {
-                                        #match_status
-                                    };
-                                rsp?.into_body().await
-                            }
-                        };
+/// The implementation of the `Operation` trait, and of `BatchRead` for reads.
+struct RequestBuilderOperationCode {
+    verb: WebVerb,
+    path: String,
+    extras: BuilderExtras,
+}
 
-                        azure_core::Pageable::new(make_request)
-                    }
+impl RequestBuilderOperationCode {
+    fn new(operation: &WebOperationGen, extras: &BuilderExtras) -> Self {
+        Self {
+            verb: operation.0.verb.clone(),
+            path: operation.0.path.clone(),
+            extras: extras.clone(),
+        }
+    }
+}
+
+impl ToTokens for RequestBuilderOperationCode {
+    fn to_tokens(&self, tokens: &mut TokenStream) {
+        let root = &self.extras.root;
+        let verb = verb_to_tokens(&self.verb);
+        let path = &self.path;
+        let is_mutation = if self.extras.read_only {
+            quote! {
+                fn is_mutation() -> bool {
+                    false
                 }
             }
-        } else if self.long_running_operation {
-            // TODO:  Long running options should also move to the Pageable stream
-            // model, however this is not possible at the moment because the
-            // continuation token is often not returned in the response body, but
-            // instead a header which we don't include as part of the response
-            // model.
-            //
-            // As is, Pageable requires implementing the Continuable trait on the
-            // response object.
-            //
-            // ref: https://github.com/Azure/azure-sdk-for-rust/issues/741
-            let mut fut = quote! {#[doc = "only the first response will be fetched as long running operations are not supported yet"]};
-            fut.extend(send_future);
-            fut
         } else {
-            send_future
+            quote! {}
         };
-        tokens.extend(fut);
+        tokens.extend(quote! {
+            impl #root::private::Sealed for RequestBuilder {}
+            impl #root::Operation for RequestBuilder {
+                fn method() -> azure_core::Method {
+                    #verb
+                }
+                fn path_template() -> &'static str {
+                    #path
+                }
+                #is_mutation
+            }
+        });
+        if self.extras.batch_read {
+            tokens.extend(quote! {
+                impl #root::BatchRead for RequestBuilder {
+                    fn batch_url(&self) -> azure_core::Result<azure_core::Url> {
+                        self.url()
+                    }
+                }
+            });
+        }
     }
 }
 
@@ -1070,6 +1779,7 @@ impl ToTokens for OperationModuleCode {
             module_name,
             response_code,
             request_builder_struct_code,
+            request_builder_operation_code,
             request_builder_setters_code,
             request_builder_future_code,
         } = &self;
@@ -1081,6 +1791,8 @@ impl ToTokens for OperationModuleCode {
 
                 #request_builder_struct_code
 
+                #request_builder_operation_code
+
                 impl RequestBuilder {
                     #request_builder_setters_code
                     #request_builder_future_code
@@ -1144,7 +1856,7 @@ struct FunctionParams {
     has_x_ms_version: bool,
 }
 impl FunctionParams {
-    fn new(operation: &WebOperationGen) -> Result<Self> {
+    fn new(cg: &CodeGen, operation: &WebOperationGen) -> Result<Self> {
         let parameters = operation.0.parameters();
         let has_api_version = parameters.iter().any(|p| p.name() == API_VERSION);
         let has_x_ms_version = parameters.iter().any(|p| p.name() == X_MS_VERSION);
@@ -1156,11 +1868,26 @@ impl FunctionParams {
         let mut params = Vec::new();
         for param in parameters.iter().filter(|p| !skip.contains(p.name())) {
             let name = param.name().to_owned();
-            let description = param.description().clone();
+            let mut description = param.description().clone();
             let variable_name = name.to_snake_case_ident()?;
-            let type_name = TypeNameCode::new(&param.type_name()?)?
-                .qualify_models(true)
-                .optional(!param.required());
+            let type_name = match cg
+                .customize
+                .parameter_type
+                .iter()
+                .find(|parameter_type| parameter_type.len() > 2 && parameter_type[0] == operation.id() && parameter_type[1] == name)
+            {
+                Some(parameter_type) => {
+                    if let Some(note) = parameter_type.get(3) {
+                        description = Some(match description {
+                            Some(description) => format!("{description} {note}"),
+                            None => note.clone(),
+                        });
+                    }
+                    TypeNameCode::try_from(parameter_type[2].as_str())?
+                }
+                None => TypeNameCode::new(&param.type_name()?)?.qualify_models(true),
+            };
+            let type_name = type_name.optional(!param.required());
             let kind = ParamKind::from(param.type_());
             let collection_format = param.collection_format().clone();
             params.push(FunctionParam {
@@ -1172,6 +1899,16 @@ impl FunctionParams {
                 collection_format,
             });
         }
+        if cg.customize.page_size.iter().any(|page_size| page_size == operation.id()) && !params.iter().any(|p| p.name == "$top") {
+            params.push(FunctionParam {
+                name: "$top".to_owned(),
+                description: Some("The maximum number of items to return per page.".to_owned()),
+                variable_name: "top".to_snake_case_ident()?,
+                type_name: TypeNameCode::new(&TypeName::Int64)?.optional(true),
+                kind: ParamKind::Query,
+                collection_format: CollectionFormat::Csv,
+            });
+        }
         Ok(Self {
             params,
             has_api_version,
@@ -1198,6 +1935,11 @@ impl FunctionParams {
             .iter()
             .any(|p| p.name.eq_ignore_ascii_case("content-type") && p.kind == ParamKind::Header)
     }
+
+    /// The variable name of the body parameter, if any
+    fn body_param(&self) -> Option<&Ident> {
+        self.params.iter().find(|p| p.kind == ParamKind::Body).map(|p| &p.variable_name)
+    }
 }
 
 #[derive(Clone)]
@@ -1229,10 +1971,11 @@ struct ClientFunctionCode {
     fname: Ident,
     parameters: FunctionParams,
     in_operation_group: bool,
+    extras: BuilderExtras,
 }
 
 impl ClientFunctionCode {
-    fn new(operation: &WebOperationGen, parameters: &FunctionParams, in_operation_group: bool) -> Result<Self> {
+    fn new(operation: &WebOperationGen, parameters: &FunctionParams, in_operation_group: bool, extras: &BuilderExtras) -> Result<Self> {
         let fname = operation.function_name()?;
         let summary = operation.0.summary.clone();
         let description = operation.0.description.clone();
@@ -1242,6 +1985,7 @@ impl ClientFunctionCode {
             fname,
             parameters: parameters.clone(),
             in_operation_group,
+            extras: extras.clone(),
         })
     }
 }
@@ -1277,6 +2021,7 @@ impl ToTokens for ClientFunctionCode {
                 params.push(quote! { #variable_name: None });
             }
         }
+        params.extend(ExtraFieldsCode(&self.extras).initializers());
 
         let summary = DocCommentCode::new(self.summary.clone());
         let description = DocCommentCode::new(self.description.clone());
@@ -1318,6 +2063,75 @@ impl ToTokens for ClientFunctionCode {
     }
 }
 
+/// The fields of a request builder for its `BuilderExtras`.
+struct ExtraFieldsCode<'a>(&'a BuilderExtras);
+
+impl<'a> ExtraFieldsCode<'a> {
+    /// The names, types and initial values of the fields
+    fn fields(&self) -> Vec<(TokenStream, TokenStream, TokenStream)> {
+        let extras = self.0;
+        let root = &extras.root;
+        let mut fields = vec![
+            (quote! { claims }, quote! { Option<String> }, quote! { None }),
+            (quote! { accept_language }, quote! { Option<String> }, quote! { None }),
+        ];
+        if extras.idempotency_key {
+            fields.push((
+                quote! { idempotency_key },
+                quote! { Option<(String, time::OffsetDateTime)> },
+                quote! { None },
+            ));
+        }
+        if extras.if_match {
+            fields.push((quote! { if_match }, quote! { Option<#root::ConcurrencyToken> }, quote! { None }));
+        }
+        if extras.idempotent {
+            fields.push((quote! { idempotent }, quote! { bool }, quote! { false }));
+        }
+        if extras.prefer_representation {
+            fields.push((quote! { prefer_representation }, quote! { bool }, quote! { false }));
+        }
+        if extras.if_none_match() {
+            fields.push((quote! { if_none_match }, quote! { Option<String> }, quote! { None }));
+        }
+        fields.push((quote! { query_params }, quote! { Vec<(String, String)> }, quote! { Vec::new() }));
+        if extras.multipart {
+            fields.push((
+                quote! { multipart },
+                quote! { Option<azure_core::multipart::MultipartForm> },
+                quote! { None },
+            ));
+        }
+        if extras.raw_body {
+            fields.push((quote! { raw_body }, quote! { Option<(bytes::Bytes, String)> }, quote! { None }));
+        }
+        if let Some(paging) = &extras.paging {
+            if paging.select {
+                fields.push((quote! { select }, quote! { Option<String> }, quote! { None }));
+            }
+            if paging.item_type.is_some() {
+                fields.push((quote! { max_items }, quote! { Option<usize> }, quote! { None }));
+            }
+            if paging.on_missing_empty {
+                fields.push((quote! { on_missing_empty }, quote! { bool }, quote! { false }));
+            }
+            fields.push((quote! { resume_from }, quote! { Option<String> }, quote! { None }));
+        }
+        fields
+    }
+
+    fn initializers(&self) -> Vec<TokenStream> {
+        self.fields().into_iter().map(|(name, _, value)| quote! { #name: #value }).collect()
+    }
+
+    fn declarations(&self) -> Vec<TokenStream> {
+        self.fields()
+            .into_iter()
+            .map(|(name, type_name, _)| quote! { pub(crate) #name: #type_name })
+            .collect()
+    }
+}
+
 #[derive(Clone)]
 struct DocCommentCode {
     comment: Option<String>,
@@ -1348,26 +2162,22 @@ impl ToTokens for DocCommentCode {
 #[derive(Clone)]
 struct RequestBuilderStructCode {
     parameters: FunctionParams,
-    in_operation_group: bool,
+    extras: BuilderExtras,
 }
 
 impl RequestBuilderStructCode {
-    fn new(parameters: &FunctionParams, in_operation_group: bool) -> Self {
+    fn new(parameters: &FunctionParams, extras: &BuilderExtras) -> Self {
         Self {
             parameters: parameters.clone(),
-            in_operation_group,
+            extras: extras.clone(),
         }
     }
 }
 
 impl ToTokens for RequestBuilderStructCode {
     fn to_tokens(&self, tokens: &mut TokenStream) {
-        let mut params: Vec<TokenStream> = Vec::new();
-        if self.in_operation_group {
-            params.push(quote! { pub(crate) client: super::super::Client });
-        } else {
-            params.push(quote! { pub(crate) client: super::Client });
-        }
+        let root = &self.extras.root;
+        let mut params: Vec<TokenStream> = vec![quote! { pub(crate) client: #root::Client }];
         for param in self.parameters.required_params() {
             let FunctionParam {
                 variable_name, type_name, ..
@@ -1384,6 +2194,7 @@ impl ToTokens for RequestBuilderStructCode {
             }
             params.push(quote! { pub(crate) #variable_name: #type_name });
         }
+        params.extend(ExtraFieldsCode(&self.extras).declarations());
         tokens.extend(quote! {
             #[derive(Clone)]
             pub struct RequestBuilder {
@@ -1397,18 +2208,104 @@ impl ToTokens for RequestBuilderStructCode {
 #[derive(Clone)]
 struct RequestBuilderSettersCode {
     parameters: FunctionParams,
+    extras: BuilderExtras,
 }
 
 impl RequestBuilderSettersCode {
-    fn new(parameters: &FunctionParams) -> Self {
+    fn new(parameters: &FunctionParams, extras: &BuilderExtras) -> Self {
         Self {
             parameters: parameters.clone(),
+            extras: extras.clone(),
         }
     }
 }
 
 impl ToTokens for RequestBuilderSettersCode {
     fn to_tokens(&self, tokens: &mut TokenStream) {
+        let extras = &self.extras;
+        let root = &extras.root;
+        let claims_doc = format!(
+            "Ask the credential for a token that satisfies the JSON `claims`; {}.",
+            extras.see("claims", "claims")
+        );
+        tokens.extend(quote! {
+            #[doc = #claims_doc]
+            pub fn claims(mut self, claims: impl Into<String>) -> Self {
+                self.claims = Some(claims.into());
+                self
+            }
+            #[doc = "Send this request with `accept_language` as its `Accept-Language` header, such as `fr-FR`, overriding `ClientBuilder::accept_language`."]
+            pub fn accept_language(mut self, accept_language: impl Into<String>) -> Self {
+                self.accept_language = Some(accept_language.into());
+                self
+            }
+        });
+        if extras.idempotency_key {
+            tokens.extend(quote! {
+                #[doc = "Send `key` in the `Repeatability-Request-ID` header, so that a service that deduplicates requests can recognize a retry of this one."]
+                #[doc = ""]
+                #[doc = "A service that does not deduplicate requests ignores the header, so a retry may still create the resource twice."]
+                #[doc = ""]
+                #[doc = "The time of this call is sent in `Repeatability-First-Sent` with every attempt, including retries by the pipeline and repeated calls of `send`; set a new key for a new request."]
+                pub fn idempotency_key(mut self, key: impl Into<String>) -> Self {
+                    self.idempotency_key = Some((key.into(), time::OffsetDateTime::now_utc()));
+                    self
+                }
+            });
+        }
+        if extras.if_match {
+            tokens.extend(quote! {
+                #[doc = "Only apply the change if the resource still has the ETag of `token`, as read with `get`."]
+                #[doc = ""]
+                #[doc = "If the resource was changed in the meantime, the service answers `412 Precondition Failed` and the request fails with a `PreconditionFailed` error carrying the resource's current ETag, so an optimistic concurrency loop can read it again and retry."]
+                pub fn if_match(mut self, token: #root::ConcurrencyToken) -> Self {
+                    self.if_match = Some(token);
+                    self
+                }
+            });
+        }
+        if extras.idempotent {
+            tokens.extend(quote! {
+                #[doc = "Mark the request as safe to repeat, so the retry policy retries it after a connection error."]
+                #[doc = ""]
+                #[doc = "This request is not idempotent by default, and repeating it may repeat its side effect, so it is not retried after a connection error or timed out attempt unless this is set."]
+                pub fn idempotent(mut self, idempotent: bool) -> Self {
+                    self.idempotent = idempotent;
+                    self
+                }
+            });
+        }
+        if extras.prefer_representation {
+            tokens.extend(quote! {
+                #[doc = "Ask the service to answer with the full updated resource by sending `Prefer: return=representation`."]
+                #[doc = ""]
+                #[doc = "Saves a follow-up `get` when the service would otherwise answer with a minimal body. Off by default; the service may ignore the preference."]
+                pub fn prefer_representation(mut self, prefer_representation: bool) -> Self {
+                    self.prefer_representation = prefer_representation;
+                    self
+                }
+            });
+        }
+        if extras.get {
+            tokens.extend(quote! {
+                #[doc = "Only return the resource if its ETag does not match `if_none_match`. Otherwise the response has status `304 Not Modified` and no body."]
+                pub fn if_none_match(mut self, if_none_match: impl Into<String>) -> Self {
+                    self.if_none_match = Some(if_none_match.into());
+                    self
+                }
+            });
+        } else if let Some(resource) = &extras.create_if_none_match {
+            let doc = format!(
+                "Only create the {resource} if no resource matches `if_none_match`. Pass `\"*\"` to fail instead of overwriting an existing {resource}."
+            );
+            tokens.extend(quote! {
+                #[doc = #doc]
+                pub fn if_none_match(mut self, if_none_match: impl Into<String>) -> Self {
+                    self.if_none_match = Some(if_none_match.into());
+                    self
+                }
+            });
+        }
         for param in self.parameters.optional_params() {
             let FunctionParam {
                 variable_name, type_name, ..
@@ -1429,14 +2326,106 @@ impl ToTokens for RequestBuilderSettersCode {
                 Some(desc) if !desc.is_empty() => quote! { #[ doc = #desc ] },
                 _ => quote! {},
             };
+            let page_size_doc = if extras.paging.is_some() && param.name == "$top" {
+                quote! {
+                    #[doc = ""]
+                    #[doc = "This is the page size. The service may cap it, so a page can hold fewer items than requested."]
+                }
+            } else {
+                quote! {}
+            };
             tokens.extend(quote! {
                 #doc_comment
+                #page_size_doc
                 pub fn #variable_name(mut self, #variable_name: #type_name) -> Self {
                     self.#variable_name = #value;
                     self
                 }
             });
         }
+        let query_param_doc = format!(
+            "Append `key=value` to the query string; {}.",
+            extras.see("query parameters", "query-parameters")
+        );
+        tokens.extend(quote! {
+            #[doc = #query_param_doc]
+            pub fn query_param(mut self, key: impl Into<String>, value: impl Into<String>) -> Self {
+                self.query_params.push((key.into(), value.into()));
+                self
+            }
+        });
+        if extras.multipart {
+            let doc = format!(
+                "Send `form` as a `multipart/form-data` body instead of `{}` as JSON, such as for uploading content inline.",
+                self.parameters.body_param().map(ToString::to_string).unwrap_or_default()
+            );
+            tokens.extend(quote! {
+                #[doc = #doc]
+                pub fn multipart(mut self, form: azure_core::multipart::MultipartForm) -> Self {
+                    self.multipart = Some(form);
+                    self
+                }
+            });
+        }
+        if extras.raw_body {
+            tokens.extend(quote! {
+                #[doc = "Send `body` as the JSON request body instead of the typed parameters, for fields the `models` type does not have yet."]
+                #[doc = ""]
+                #[doc = "Shorthand for `raw_body` with `content-type: application/json`; whichever of the two is called last wins."]
+                pub fn raw_json_body(self, body: serde_json::Value) -> Self {
+                    self.raw_body(body.to_string(), "application/json")
+                }
+                #[doc = "Send `body` with the given `content-type` instead of the typed parameters."]
+                #[doc = ""]
+                #[doc = "The typed request body is not sent at all, so this is a stopgap for service changes the `models` types do not cover yet."]
+                pub fn raw_body(mut self, body: impl Into<bytes::Bytes>, content_type: impl Into<String>) -> Self {
+                    self.raw_body = Some((body.into(), content_type.into()));
+                    self
+                }
+            });
+        }
+        if let Some(paging) = &extras.paging {
+            if paging.select {
+                tokens.extend(quote! {
+                    #[doc = "Only return the given properties of each item, such as `[\"id\", \"name\"]`, to reduce the work done by the service and the size of each page."]
+                    #[doc = ""]
+                    #[doc = "Fields of the returned models that were not selected are `None`."]
+                    pub fn select(mut self, select: &[&str]) -> Self {
+                        self.select = Some(select.join(","));
+                        self
+                    }
+                });
+            }
+            if paging.item_type.is_some() {
+                tokens.extend(quote! {
+                    #[doc = "Limit the total number of items yielded by `into_item_stream`. No further pages are requested once the limit is reached."]
+                    pub fn max_items(mut self, max_items: usize) -> Self {
+                        self.max_items = Some(max_items);
+                        self
+                    }
+                });
+            }
+            if paging.on_missing_empty {
+                tokens.extend(quote! {
+                    #[doc = "Treat `404 Not Found` on the first page as an empty list instead of an error."]
+                    #[doc = ""]
+                    #[doc = "Useful when the parent resource may not exist yet, such as in a reconciliation loop racing its provisioning. The stream then yields a single empty page. Off by default, and a `404` on a later page is always an error."]
+                    pub fn on_missing_empty(mut self, on_missing_empty: bool) -> Self {
+                        self.on_missing_empty = on_missing_empty;
+                        self
+                    }
+                });
+            }
+            tokens.extend(quote! {
+                #[doc = "Start paging from `continuation`, a token saved from `into_checkpoint_stream` or `page`, instead of from the first page."]
+                #[doc = ""]
+                #[doc = "Lets a long listing be checkpointed and picked up where it stopped, such as after a restart."]
+                pub fn resume_from(mut self, continuation: impl Into<String>) -> Self {
+                    self.resume_from = Some(continuation.into());
+                    self
+                }
+            });
+        }
     }
 }
 
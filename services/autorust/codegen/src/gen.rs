@@ -1,8 +1,10 @@
 use crate::{
     autorust_toml, cargo_toml, io, lib_rs,
     readme_md::{self, ReadmeMd},
+    spec::WebVerb,
     CrateConfig, Error, Result, RunConfig, SpecReadme, WebOperation,
 };
+use autorust_openapi::ParameterType;
 use std::{collections::HashMap, fs};
 
 /// Get the package name, such as "azure_svc_blobstorage".
@@ -51,6 +53,9 @@ pub fn gen_crate(package_name: &str, spec: &SpecReadme, run_config: &RunConfig,
     let mut api_version_totals = HashMap::new();
     let mut api_versions = HashMap::new();
     let mut has_xml = false;
+    let mut declares_expand = false;
+    let mut has_create = false;
+    let mut declares_repeatability = false;
     for tag in tags {
         println!("  {}", tag.name());
         let output_folder = io::join(&src_folder, tag.rust_mod_name())?;
@@ -81,6 +86,28 @@ pub fn gen_crate(package_name: &str, spec: &SpecReadme, run_config: &RunConfig,
         if !has_xml {
             has_xml = operations.iter().any(WebOperation::has_xml);
         }
+        if !declares_expand {
+            declares_expand = operations.iter().any(|operation| {
+                operation
+                    .parameters()
+                    .iter()
+                    .any(|param| param.name() == "$expand" && param.type_() == &ParameterType::Query)
+            });
+        }
+        if !has_create {
+            has_create = operations.iter().any(|operation| {
+                let id = operation.id.as_deref().unwrap_or_default();
+                operation.verb == WebVerb::Put && (id.ends_with("_Create") || id.ends_with("_CreateOrUpdate"))
+            });
+        }
+        if !declares_repeatability {
+            declares_repeatability = operations.iter().any(|operation| {
+                operation
+                    .parameters()
+                    .iter()
+                    .any(|param| param.name().eq_ignore_ascii_case("Repeatability-Request-ID"))
+            });
+        }
     }
 
     let default_tag_name = if let Some(name) = package_config.default_tag() {
@@ -90,7 +117,15 @@ pub fn gen_crate(package_name: &str, spec: &SpecReadme, run_config: &RunConfig,
     };
     let default_tag = cargo_toml::get_default_tag(tags, default_tag_name);
 
-    cargo_toml::create(package_name, tags, default_tag, has_xml, &cargo_toml_path)?;
+    cargo_toml::create(
+        package_name,
+        tags,
+        default_tag,
+        has_xml,
+        run_config.is_mgmt(),
+        output_folder,
+        &cargo_toml_path,
+    )?;
     lib_rs::create(tags, &io::join(src_folder, "lib.rs")?, false)?;
     let readme = ReadmeMd {
         package_name,
@@ -100,6 +135,9 @@ pub fn gen_crate(package_name: &str, spec: &SpecReadme, run_config: &RunConfig,
         operation_totals,
         api_version_totals,
         api_versions,
+        declares_expand,
+        has_create,
+        declares_repeatability,
     };
     readme.create(&readme_path)?;
 
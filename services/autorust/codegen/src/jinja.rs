@@ -53,6 +53,11 @@ pub struct CargoToml<'a> {
     pub default_feature: &'a str,
     pub features: Vec<String>,
     pub azure_core_features: Vec<&'a str>,
+    /// Azure Resource Manager crates have an `identity` feature for `Client::from_env`
+    pub is_mgmt: bool,
+    /// Hand-written tests and benchmarks run against the hyper mock server of `tests/common`
+    pub has_tests: bool,
+    pub benches: Vec<String>,
 }
 
 impl<'a> CargoToml<'a> {
@@ -61,6 +61,11 @@ impl<'a> RunConfig<'a> {
             print_writing_file: false,
         }
     }
+
+    /// Whether the crates are Azure Resource Manager crates, such as `azure_mgmt_compute`
+    pub fn is_mgmt(&self) -> bool {
+        self.crate_name_prefix == "azure_mgmt_"
+    }
 }
 
 /// Settings for generating of a single crate
@@ -97,6 +102,7 @@ pub fn run<'a>(crate_config: &'a CrateConfig, package_config: &'a PackageConfig)
     let optional_properties: HashSet<PropertyName> = package_config.properties.optional.iter().map(to_property_name).collect();
     let fix_case_properties: HashSet<&'a str> = package_config.properties.fix_case.iter().map(AsRef::as_ref).collect();
     let invalid_types: HashSet<PropertyName> = package_config.properties.invalid_type.iter().map(to_property_name).collect();
+    let customize = package_config.customize(directory.file_name().unwrap_or_default());
 
     let cg = CodeGen::new(
         crate_config,
@@ -104,6 +110,7 @@ pub fn run<'a>(crate_config: &'a CrateConfig, package_config: &'a PackageConfig)
         optional_properties,
         fix_case_properties,
         invalid_types,
+        customize,
     )?;
 
     // create models from schemas
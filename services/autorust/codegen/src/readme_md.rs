@@ -16,6 +16,12 @@ pub struct ReadmeMd<'a> {
     pub operation_totals: HashMap<&'a str, usize>,
     pub api_version_totals: HashMap<&'a str, usize>,
     pub api_versions: HashMap<&'a str, String>,
+    /// Whether any operation declares an `$expand` query parameter
+    pub declares_expand: bool,
+    /// Whether any operation is a `PUT` named `create` or `create_or_update`, which has an `idempotency_key` setter
+    pub has_create: bool,
+    /// Whether any operation declares a `Repeatability-Request-ID` header parameter
+    pub declares_repeatability: bool,
 }
 
 impl<'a> ReadmeMd<'a> {
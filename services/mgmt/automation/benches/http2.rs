@@ -0,0 +1,59 @@
+//! Compares sending many `dsc_node::get` requests concurrently over HTTP/1.1 with sending them over
+//! HTTP/2, against a local server that answers both.
+
+use azure_core::auth::StaticTokenCredential;
+use azure_core::{HttpClientOptions, HttpVersion, TransportOptions};
+use azure_mgmt_automation::package_2022_08_08::Client;
+use criterion::{criterion_group, criterion_main, BenchmarkId, Criterion};
+use hyper::service::{make_service_fn, service_fn};
+use hyper::{Body, Response, Server};
+use std::convert::Infallible;
+use std::net::SocketAddr;
+use std::sync::Arc;
+use time::OffsetDateTime;
+
+const CONCURRENCY: [usize; 3] = [1, 16, 128];
+
+/// Starts a server that answers every request with an empty `200 OK` over either HTTP version.
+fn serve(runtime: &tokio::runtime::Runtime) -> SocketAddr {
+    let _guard = runtime.enter();
+    let make_service =
+        make_service_fn(|_| async { Ok::<_, Infallible>(service_fn(|_| async { Ok::<_, Infallible>(Response::new(Body::empty())) })) });
+    let server = Server::bind(&SocketAddr::from(([127, 0, 0, 1], 0))).serve(make_service);
+    let addr = server.local_addr();
+    runtime.spawn(server);
+    addr
+}
+
+fn client(addr: SocketAddr, http_version: HttpVersion) -> Client {
+    let credential = StaticTokenCredential::new("token", OffsetDateTime::now_utc() + time::Duration::days(1));
+    let transport = TransportOptions::with_http_options(HttpClientOptions::default().http_version(http_version)).unwrap();
+    Client::builder(Arc::new(credential))
+        .endpoint(format!("http://{addr}"))
+        .transport(transport)
+        .build()
+}
+
+fn dsc_node_get(c: &mut Criterion) {
+    let runtime = tokio::runtime::Runtime::new().unwrap();
+    let addr = serve(&runtime);
+    let mut group = c.benchmark_group("dsc_node::get concurrently");
+    for (name, http_version) in [("http1", HttpVersion::Http1Only), ("http2", HttpVersion::Http2)] {
+        let client = client(addr, http_version);
+        let dsc_node = client.dsc_node_client();
+        let builder = dsc_node.get("resource_group", "automation_account", "node_id", "subscription_id");
+        for concurrency in CONCURRENCY {
+            group.bench_with_input(BenchmarkId::new(name, concurrency), &concurrency, |b, &concurrency| {
+                b.iter(|| {
+                    runtime.block_on(futures::future::try_join_all(
+                        (0..concurrency).map(|_| async { builder.send_ref().await?.into_raw_response().into_body().collect().await }),
+                    ))
+                })
+            });
+        }
+    }
+    group.finish();
+}
+
+criterion_group!(benches, dsc_node_get);
+criterion_main!(benches);
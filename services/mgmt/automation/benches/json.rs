@@ -0,0 +1,48 @@
+//! Compares deserializing a large `DscNodeListResult` with `serde_json` and, with the `simd-json`
+//! feature enabled, with `simd-json`.
+
+use azure_core::json::{JsonFormat, SerdeJson};
+use azure_mgmt_automation::package_2022_08_08::models::DscNodeListResult;
+use criterion::{criterion_group, criterion_main, Criterion, Throughput};
+
+/// A page of `nodes` DSC nodes, shaped like a `dsc_node::list_by_automation_account` response.
+fn dsc_node_list(nodes: usize) -> Vec<u8> {
+    let value: Vec<serde_json::Value> = (0..nodes)
+        .map(|i| {
+            serde_json::json!({
+                "id": format!("/subscriptions/00000000-0000-0000-0000-000000000000/resourceGroups/rg/providers/Microsoft.Automation/automationAccounts/account/nodes/{i}"),
+                "name": format!("node-{i}"),
+                "type": "Microsoft.Automation/AutomationAccounts/Nodes",
+                "properties": {
+                    "lastSeen": "2022-11-01T10:20:30.1234567+00:00",
+                    "registrationTime": "2022-01-01T00:00:00+00:00",
+                    "ip": format!("10.0.{}.{}", i / 256, i % 256),
+                    "accountId": "00000000-0000-0000-0000-000000000000",
+                    "nodeConfiguration": { "name": "web.localhost" },
+                    "status": "Compliant",
+                    "nodeId": format!("{i:08x}-0000-0000-0000-000000000000"),
+                    "etag": format!("\"{i}\""),
+                    "extensionHandler": [{ "name": "Microsoft.Powershell.DSC", "version": "2.77.0.0" }]
+                }
+            })
+        })
+        .collect();
+    serde_json::to_vec(&serde_json::json!({ "value": value, "nextLink": null })).unwrap()
+}
+
+fn deserialize(c: &mut Criterion) {
+    let body = dsc_node_list(5_000);
+    let mut group = c.benchmark_group("DscNodeListResult");
+    group.throughput(Throughput::Bytes(body.len() as u64));
+    group.bench_function("serde_json", |b| {
+        b.iter(|| SerdeJson::from_json::<DscNodeListResult>(&body).unwrap())
+    });
+    #[cfg(feature = "simd-json")]
+    group.bench_function("simd_json", |b| {
+        b.iter(|| azure_core::json::SimdJson::from_json::<DscNodeListResult>(&body).unwrap())
+    });
+    group.finish();
+}
+
+criterion_group!(benches, deserialize);
+criterion_main!(benches);
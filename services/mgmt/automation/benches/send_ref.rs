@@ -0,0 +1,39 @@
+//! Compares sending a `dsc_node::get` builder that is kept across calls through `send`, which
+//! clones it into a `'static` future, with `send_ref`, which borrows it.
+
+use azure_core::auth::StaticTokenCredential;
+use azure_core::headers::Headers;
+use azure_core::{HttpClient, Request, Response, StatusCode};
+use azure_mgmt_automation::package_2022_08_08::Client;
+use criterion::{criterion_group, criterion_main, Criterion};
+use std::sync::Arc;
+use time::OffsetDateTime;
+
+/// Answers every request with an empty `200 OK`, so the benchmark measures the client only.
+#[derive(Debug)]
+struct OkTransport;
+
+#[async_trait::async_trait]
+impl HttpClient for OkTransport {
+    async fn execute_request(&self, _request: &Request) -> azure_core::Result<Response> {
+        Ok(Response::new(StatusCode::Ok, Headers::new(), Box::pin(futures::stream::empty())))
+    }
+}
+
+fn client() -> Client {
+    let credential = StaticTokenCredential::new("token", OffsetDateTime::now_utc() + time::Duration::days(1));
+    Client::builder(Arc::new(credential)).http_client(Arc::new(OkTransport)).build()
+}
+
+fn dsc_node_get(c: &mut Criterion) {
+    let client = client();
+    let dsc_node = client.dsc_node_client();
+    let mut group = c.benchmark_group("dsc_node::get");
+    let builder = dsc_node.get("resource_group", "automation_account", "node_id", "subscription_id");
+    group.bench_function("send", |b| b.iter(|| futures::executor::block_on(builder.send()).unwrap()));
+    group.bench_function("send_ref", |b| b.iter(|| futures::executor::block_on(builder.send_ref()).unwrap()));
+    group.finish();
+}
+
+criterion_group!(benches, dsc_node_get);
+criterion_main!(benches);
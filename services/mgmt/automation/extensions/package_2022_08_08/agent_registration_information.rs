@@ -0,0 +1,31 @@
+// Hand-written additions to the `agent_registration_information` operations, included at the end of the generated module.
+impl Client {
+    #[doc = "Regenerate the primary agent registration key. Shorthand for `regenerate_key` with `AgentRegistrationRegenerateKeyParameter::primary()`."]
+    pub fn regenerate_primary(
+        &self,
+        subscription_id: impl Into<String>,
+        resource_group_name: impl Into<String>,
+        automation_account_name: impl Into<String>,
+    ) -> regenerate_key::RequestBuilder {
+        self.regenerate_key(
+            subscription_id,
+            resource_group_name,
+            automation_account_name,
+            models::AgentRegistrationRegenerateKeyParameter::primary(),
+        )
+    }
+    #[doc = "Regenerate the secondary agent registration key. Shorthand for `regenerate_key` with `AgentRegistrationRegenerateKeyParameter::secondary()`."]
+    pub fn regenerate_secondary(
+        &self,
+        subscription_id: impl Into<String>,
+        resource_group_name: impl Into<String>,
+        automation_account_name: impl Into<String>,
+    ) -> regenerate_key::RequestBuilder {
+        self.regenerate_key(
+            subscription_id,
+            resource_group_name,
+            automation_account_name,
+            models::AgentRegistrationRegenerateKeyParameter::secondary(),
+        )
+    }
+}
@@ -0,0 +1,114 @@
+// Hand-written additions to the `automation_account` operations, included at the end of the generated module.
+impl Client {
+    #[doc = "List every account in the subscription together with its parsed resource ID, which names its resource group."]
+    #[doc = ""]
+    #[doc = "Arguments:"]
+    #[doc = "* `subscription_id`: Gets subscription credentials which uniquely identify Microsoft Azure subscription. The subscription ID forms part of the URI for every service call."]
+    pub fn list_all(&self, subscription_id: impl Into<String>) -> list_all::RequestBuilder {
+        list_all::RequestBuilder(self.list(subscription_id))
+    }
+    #[doc = "Poll `get` as `options` asks until the account has finished provisioning, resolving with the account as last read."]
+    #[doc = ""]
+    #[doc = "Meant to follow `create_or_update`. An account whose provisioning state is absent counts as ready. A state of `Failed` or `Canceled` fails with an `ErrorKind::Other` error naming it. Once `options` times out, fails with a `PollTimeout<models::AutomationAccount>` error carrying the account as last read."]
+    #[doc = ""]
+    #[doc = "Arguments:"]
+    #[doc = "* `resource_group_name`: Name of an Azure Resource group."]
+    #[doc = "* `automation_account_name`: The name of the automation account."]
+    #[doc = "* `subscription_id`: Gets subscription credentials which uniquely identify Microsoft Azure subscription. The subscription ID forms part of the URI for every service call."]
+    #[doc = "* `options`: How to pace the polls; `PollOptions::default()` waits ten seconds between them, with no deadline."]
+    pub fn wait_until_ready(
+        &self,
+        resource_group_name: impl Into<String>,
+        automation_account_name: impl Into<String>,
+        subscription_id: impl Into<String>,
+        options: impl Into<super::PollOptions>,
+    ) -> futures::future::BoxFuture<'static, azure_core::Result<models::AutomationAccount>> {
+        let get = self.get(resource_group_name, automation_account_name, subscription_id);
+        let mut clock = azure_core::lro::PollClock::new(options.into());
+        Box::pin(async move {
+            loop {
+                let rsp = get.send().await?;
+                let retry_after = rsp.as_raw_response().retry_after();
+                let account = rsp.into_body().await?;
+                let state = account
+                    .properties
+                    .as_ref()
+                    .and_then(|properties| properties.provisioning_state.clone());
+                match state {
+                    None | Some(models::ProvisioningState::Succeeded) => return Ok(account),
+                    Some(state) if state.is_terminal() => {
+                        let name = get.automation_account_name.clone();
+                        return Err(azure_core::error::Error::with_message(azure_core::error::ErrorKind::Other, || {
+                            format!("automation account {} ended as {}", name, state)
+                        }));
+                    }
+                    Some(_) => clock.tick("automation_account::wait_until_ready", retry_after, account).await?,
+                }
+            }
+        })
+    }
+}
+impl delete::RequestBuilder {
+    #[doc = "Send the request and return a `Poller` for the deletion, without waiting for it to finish."]
+    #[doc = ""]
+    #[doc = "Deletion is long-running. The service answers `200 OK` or `204 No Content` if the account is gone right away, and `202 Accepted` with a `Location` header while deletion is in progress. Call `wait` on the poller to poll `Location` until the account is gone, and `cancel` to stop polling."]
+    #[doc = ""]
+    #[doc = "The service has no operation to cancel a deletion, so `cancel` only stops polling: the account is deleted regardless."]
+    pub fn begin(self) -> futures::future::BoxFuture<'static, azure_core::Result<super::Poller>> {
+        Box::pin(async move {
+            let client = self.client.0.clone();
+            let url = self.url()?;
+            let rsp = self.send().await?.into_raw_response();
+            super::Poller::new(client, &rsp, url, "automation_account::delete", super::FinalStateVia::Location)
+        })
+    }
+    #[doc = "Send the request and wait until the automation account has been deleted."]
+    #[doc = ""]
+    #[doc = "Shorthand for `begin` followed by `Poller::wait`. Resolves to `Ok(())` once the service answers `200 OK` or `204 No Content`; any other status fails with an `ErrorKind::HttpResponse` error carrying the status. Use `send` to start the deletion without waiting for it, or `begin` to keep a handle that can stop waiting."]
+    pub fn send_and_wait(self) -> futures::future::BoxFuture<'static, azure_core::Result<()>> {
+        Box::pin(async move { self.begin().await?.wait().await })
+    }
+}
+pub mod list_all {
+    use super::models;
+    #[derive(Clone)]
+    pub struct RequestBuilder(pub(crate) super::list::RequestBuilder);
+    impl super::super::private::Sealed for RequestBuilder {}
+    impl super::super::Operation for RequestBuilder {
+        fn method() -> azure_core::Method {
+            <super::list::RequestBuilder as super::super::Operation>::method()
+        }
+        fn path_template() -> &'static str {
+            <super::list::RequestBuilder as super::super::Operation>::path_template()
+        }
+    }
+    impl RequestBuilder {
+        #[doc = "Ask the credential for a token that satisfies `claims`; see `list::RequestBuilder::claims`."]
+        pub fn claims(self, claims: impl Into<String>) -> Self {
+            Self(self.0.claims(claims))
+        }
+        #[doc = "Set the `Accept-Language` header of the requests; see `list::RequestBuilder::accept_language`."]
+        pub fn accept_language(self, accept_language: impl Into<String>) -> Self {
+            Self(self.0.accept_language(accept_language))
+        }
+        #[doc = "Limit the total number of accounts yielded by `into_item_stream`. No further pages are requested once the limit is reached."]
+        pub fn max_items(self, max_items: usize) -> Self {
+            Self(self.0.max_items(max_items))
+        }
+        #[doc = "Stream every account in the subscription paired with its resource ID."]
+        #[doc = ""]
+        #[doc = "The pages of the subscription-wide `list` are flattened, so no request is sent per resource group. An account without a valid `id` fails its item with an `ErrorKind::DataConversion` error, and the stream carries on with the next account."]
+        pub fn into_item_stream(
+            self,
+        ) -> futures::stream::BoxStream<'static, azure_core::Result<(models::AutomationAccountId, models::AutomationAccount)>> {
+            use futures::StreamExt;
+            self.0
+                .into_item_stream()
+                .map(|account| {
+                    let account = account?;
+                    Ok((account.account_id()?, account))
+                })
+                .boxed()
+        }
+    }
+}
@@ -0,0 +1,87 @@
+// Hand-written additions to the `dsc_compilation_job` operations, included at the end of the generated module.
+#[doc = "An OData `$filter` expression for `list_by_automation_account`."]
+#[doc = ""]
+#[doc = "Values are quoted and escaped, so the result can be passed straight to `filter`, e.g. `.filter(JobFilter::status(Status::Failed))`."]
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct JobFilter(String);
+impl JobFilter {
+    fn eq(property: &str, value: &str) -> Self {
+        Self(format!("{} eq '{}'", property, value.replace('\'', "''")))
+    }
+    #[doc = "Match jobs with the given status."]
+    pub fn status(status: models::dsc_compilation_job_properties::Status) -> Self {
+        Self::eq("properties/status", status.as_str())
+    }
+    #[doc = "Match jobs with the given provisioning state."]
+    pub fn provisioning_state(provisioning_state: models::JobProvisioningStateProperty) -> Self {
+        Self::eq("properties/provisioningState", provisioning_state.as_str())
+    }
+    #[doc = "Match jobs that satisfy both filters."]
+    pub fn and(self, other: JobFilter) -> Self {
+        Self(format!("{} and {}", self.0, other.0))
+    }
+    #[doc = "Match jobs that satisfy either filter."]
+    pub fn or(self, other: JobFilter) -> Self {
+        Self(format!("({}) or ({})", self.0, other.0))
+    }
+}
+impl std::fmt::Display for JobFilter {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.write_str(&self.0)
+    }
+}
+impl From<JobFilter> for String {
+    fn from(filter: JobFilter) -> Self {
+        filter.0
+    }
+}
+impl create::RequestBuilder {
+    #[doc = "Create the compilation job, then poll `get` as `options` asks until the job has finished, resolving with the completed job."]
+    #[doc = ""]
+    #[doc = "A job whose status is `Failed` or `Stopped` fails with an `ErrorKind::Other` error carrying the job's exception. Once `options` times out, fails with a `PollTimeout<models::DscCompilationJob>` error carrying the job as last read."]
+    #[doc = ""]
+    #[doc = "The service has no operation to stop a compilation job, so dropping the future only stops polling: the job runs to completion."]
+    pub fn poll_until_done(
+        self,
+        options: impl Into<super::PollOptions>,
+    ) -> futures::future::BoxFuture<'static, azure_core::Result<models::DscCompilationJob>> {
+        let mut clock = azure_core::lro::PollClock::new(options.into());
+        Box::pin(async move {
+            self.send().await?;
+            let get = get::RequestBuilder {
+                client: self.client,
+                resource_group_name: self.resource_group_name,
+                automation_account_name: self.automation_account_name,
+                compilation_job_name: self.compilation_job_name,
+                subscription_id: self.subscription_id,
+                if_none_match: None,
+                claims: self.claims,
+                accept_language: self.accept_language,
+                query_params: Vec::new(),
+            };
+            loop {
+                let rsp = get.send().await?;
+                let retry_after = rsp.as_raw_response().retry_after();
+                let job = rsp.into_body().await?;
+                let properties = job.properties.as_ref();
+                match properties.and_then(|properties| properties.status.as_ref()) {
+                    Some(models::dsc_compilation_job_properties::Status::Completed) => return Ok(job),
+                    Some(
+                        status @ (models::dsc_compilation_job_properties::Status::Failed
+                        | models::dsc_compilation_job_properties::Status::Stopped),
+                    ) => {
+                        let status = status.clone();
+                        let exception = properties
+                            .and_then(|properties| properties.exception.clone())
+                            .unwrap_or_else(|| "no exception reported".to_owned());
+                        let name = get.compilation_job_name.clone();
+                        return Err(azure_core::error::Error::with_message(azure_core::error::ErrorKind::Other, || {
+                            format!("compilation job {} ended as {:?}: {}", name, status, exception)
+                        }));
+                    }
+                    _ => clock.tick("dsc_compilation_job::create", retry_after, job).await?,
+                }
+            }
+        })
+    }
+}
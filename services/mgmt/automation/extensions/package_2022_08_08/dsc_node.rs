@@ -0,0 +1,8 @@
+// Hand-written additions to the `dsc_node` operations, included at the end of the generated module.
+impl list_by_automation_account::RequestBuilder {
+    #[doc = "Set the filter from a `DscNodeFilter`, which only builds expressions on fields the service can filter nodes by."]
+    pub fn filter_typed(mut self, filter: models::DscNodeFilter) -> Self {
+        self.filter = Some(filter.to_string());
+        self
+    }
+}
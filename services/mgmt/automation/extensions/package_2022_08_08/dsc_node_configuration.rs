@@ -0,0 +1,197 @@
+// Hand-written additions to the `dsc_node_configuration` operations, included at the end of the generated module.
+impl Client {
+    #[doc = "Retrieve the MOF document of the node configuration identified by node configuration name."]
+    #[doc = ""]
+    #[doc = "Unlike `get`, which returns the metadata, the content is returned as raw bytes, so it can be streamed to a file with `save_to` without going through `models`."]
+    #[doc = ""]
+    #[doc = "Arguments:"]
+    #[doc = "* `subscription_id`: Gets subscription credentials which uniquely identify Microsoft Azure subscription. The subscription ID forms part of the URI for every service call."]
+    #[doc = "* `resource_group_name`: Name of an Azure Resource group."]
+    #[doc = "* `automation_account_name`: The name of the automation account."]
+    #[doc = "* `node_configuration_name`: The Dsc node configuration name."]
+    pub fn get_content(
+        &self,
+        subscription_id: impl Into<String>,
+        resource_group_name: impl Into<String>,
+        automation_account_name: impl Into<String>,
+        node_configuration_name: impl Into<String>,
+    ) -> get_content::RequestBuilder {
+        get_content::RequestBuilder {
+            client: self.0.clone(),
+            subscription_id: subscription_id.into(),
+            resource_group_name: resource_group_name.into(),
+            automation_account_name: automation_account_name.into(),
+            node_configuration_name: node_configuration_name.into(),
+            claims: None,
+            accept_language: None,
+            query_params: Vec::new(),
+        }
+    }
+}
+pub mod get_content {
+    use super::models;
+    pub struct Response(azure_core::Response);
+    impl Response {
+        pub async fn into_body(self) -> azure_core::Result<bytes::Bytes> {
+            let bytes = self.into_byte_stream().collect().await?;
+            let body = bytes;
+            Ok(body)
+        }
+        #[doc = "Write the response body to the file at `path` as it arrives, without buffering the whole configuration, and return the number of bytes written."]
+        #[doc = ""]
+        #[doc = "The file is created, or truncated if it exists."]
+        pub async fn save_to(self, path: impl AsRef<std::path::Path>) -> azure_core::Result<u64> {
+            use azure_core::error::ResultExt;
+            use futures::StreamExt;
+            use std::io::Write;
+            let path = path.as_ref();
+            let mut file = std::fs::File::create(path)
+                .with_context(azure_core::error::ErrorKind::Io, || format!("failed to create {}", path.display()))?;
+            let mut body = self.into_byte_stream();
+            let mut written = 0u64;
+            while let Some(chunk) = body.next().await {
+                let chunk = chunk?;
+                file.write_all(&chunk)
+                    .with_context(azure_core::error::ErrorKind::Io, || format!("failed to write {}", path.display()))?;
+                written += chunk.len() as u64;
+            }
+            file.flush()
+                .with_context(azure_core::error::ErrorKind::Io, || format!("failed to write {}", path.display()))?;
+            Ok(written)
+        }
+        #[doc = "Stream the response body in chunks as they are received, instead of buffering all of it like `into_body`."]
+        pub fn into_byte_stream(self) -> azure_core::ResponseBody {
+            self.0.into_body()
+        }
+        pub fn status(&self) -> azure_core::StatusCode {
+            self.0.status()
+        }
+        pub fn into_raw_response(self) -> azure_core::Response {
+            self.0
+        }
+        #[doc = "Read the whole response and return its status, headers and body as received, for forwarding it verbatim without going through `models`."]
+        pub async fn into_raw_parts(self) -> azure_core::Result<(azure_core::StatusCode, azure_core::headers::Headers, bytes::Bytes)> {
+            let (status, headers, body) = self.0.deconstruct();
+            Ok((status, headers, body.collect().await?))
+        }
+        pub fn as_raw_response(&self) -> &azure_core::Response {
+            &self.0
+        }
+        #[doc = "The remaining request quota; see [responses](crate::package_2022_08_08#responses)."]
+        pub fn rate_limit_remaining(&self) -> Option<u32> {
+            azure_core::client::rate_limit_remaining(self.0.headers())
+        }
+        #[doc = "The `x-ms-client-request-id` the request was sent with; see [responses](crate::package_2022_08_08#responses)."]
+        pub fn client_request_id(&self) -> Option<&str> {
+            self.0.headers().get_optional_str(&azure_core::headers::CLIENT_REQUEST_ID)
+        }
+        #[doc = "How long the request took; see [responses](crate::package_2022_08_08#responses)."]
+        pub fn elapsed(&self) -> std::time::Duration {
+            self.0.elapsed().unwrap_or_default()
+        }
+    }
+    impl From<Response> for azure_core::Response {
+        fn from(rsp: Response) -> Self {
+            rsp.into_raw_response()
+        }
+    }
+    impl AsRef<azure_core::Response> for Response {
+        fn as_ref(&self) -> &azure_core::Response {
+            self.as_raw_response()
+        }
+    }
+    #[derive(Clone)]
+    pub struct RequestBuilder {
+        pub(crate) client: super::super::Client,
+        pub(crate) subscription_id: String,
+        pub(crate) resource_group_name: String,
+        pub(crate) automation_account_name: String,
+        pub(crate) node_configuration_name: String,
+        pub(crate) claims: Option<String>,
+        pub(crate) accept_language: Option<String>,
+        pub(crate) query_params: Vec<(String, String)>,
+    }
+    impl super::super::private::Sealed for RequestBuilder {}
+    impl super::super::Operation for RequestBuilder {
+        fn method() -> azure_core::Method {
+            azure_core::Method::Get
+        }
+        fn path_template() -> &'static str {
+            "/subscriptions/{subscriptionId}/resourceGroups/{resourceGroupName}/providers/Microsoft.Automation/automationAccounts/{automationAccountName}/nodeConfigurations/{nodeConfigurationName}/content"
+        }
+    }
+    impl super::super::BatchRead for RequestBuilder {
+        fn batch_url(&self) -> azure_core::Result<azure_core::Url> {
+            self.url()
+        }
+    }
+    impl RequestBuilder {
+        #[doc = "Ask the credential for a token that satisfies the JSON `claims`; see [claims](crate::package_2022_08_08#claims)."]
+        pub fn claims(mut self, claims: impl Into<String>) -> Self {
+            self.claims = Some(claims.into());
+            self
+        }
+        #[doc = "Send this request with `accept_language` as its `Accept-Language` header, such as `fr-FR`, overriding `ClientBuilder::accept_language`."]
+        pub fn accept_language(mut self, accept_language: impl Into<String>) -> Self {
+            self.accept_language = Some(accept_language.into());
+            self
+        }
+        #[doc = "Append `key=value` to the query string; see [query parameters](crate::package_2022_08_08#query-parameters)."]
+        pub fn query_param(mut self, key: impl Into<String>, value: impl Into<String>) -> Self {
+            self.query_params.push((key.into(), value.into()));
+            self
+        }
+        #[doc = "Build the URL the request is sent to, including the api-version and any query parameters set on the builder, without sending the request."]
+        pub fn url(&self) -> azure_core::Result<azure_core::Url> {
+            let mut url = azure_core::Url::parse(&format!(
+                "{}/subscriptions/{}/resourceGroups/{}/providers/Microsoft.Automation/automationAccounts/{}/nodeConfigurations/{}/content",
+                self.client.endpoint(),
+                &azure_core::client::path_segment("subscription_id", &self.subscription_id)?,
+                &azure_core::client::path_segment("resource_group_name", &self.resource_group_name)?,
+                &azure_core::client::path_segment("automation_account_name", &self.automation_account_name)?,
+                &azure_core::client::path_segment("node_configuration_name", &self.node_configuration_name)?
+            ))?;
+            url.query_pairs_mut()
+                .append_pair(azure_core::query_param::API_VERSION, "2022-08-08");
+            azure_core::client::append_query_params(&mut url, &self.query_params);
+            Ok(url)
+        }
+        #[doc = "Send the request and returns the response."]
+        #[doc = ""]
+        #[doc = "The builder is not consumed, so a polling loop can build it once and call `send` on every iteration; each call fetches a fresh token."]
+        pub async fn send(&self) -> azure_core::Result<Response> {
+            let url = self.url()?;
+            let mut req = azure_core::Request::new(url, azure_core::Method::Get);
+            let token_response = self.client.token(self.claims.as_deref()).await?;
+            req.insert_header(
+                azure_core::headers::AUTHORIZATION,
+                format!("Bearer {}", token_response.token.secret()),
+            );
+            if let Some(accept_language) = &self.accept_language {
+                req.insert_header(azure_core::headers::ACCEPT_LANGUAGE, accept_language.clone());
+            }
+            let req_body = azure_core::EMPTY_BODY;
+            req.set_body(req_body);
+            Ok(Response(self.client.send(&mut req, "dsc_node_configuration::get_content").await?))
+        }
+        #[doc = "Send the request and return the response body."]
+        pub fn into_future(self) -> futures::future::BoxFuture<'static, azure_core::Result<bytes::Bytes>> {
+            Box::pin(async move { self.send().await?.into_body().await })
+        }
+        #[doc = "Send the request and return the response body together with the response headers, such as the `ETag`."]
+        pub fn into_future_with_headers(
+            self,
+        ) -> futures::future::BoxFuture<'static, azure_core::Result<(bytes::Bytes, azure_core::headers::Headers)>> {
+            Box::pin(async move {
+                let rsp = self.send().await?;
+                let headers = rsp.as_raw_response().headers().clone();
+                Ok((rsp.into_body().await?, headers))
+            })
+        }
+        #[doc = "Send the request and return the response body, blocking the current thread."]
+        #[cfg(feature = "blocking")]
+        pub fn call(self) -> azure_core::Result<bytes::Bytes> {
+            azure_core::blocking::block_on(self.into_future())
+        }
+    }
+}
@@ -0,0 +1,658 @@
+// Hand-written additions to the generated models of the tag, included at the end of `models.rs`.
+#[doc = "Quote `value` as an OData string literal, doubling any single quotes in it."]
+fn odata_string(value: &str) -> String {
+    format!("'{}'", value.replace('\'', "''"))
+}
+#[doc = "A resource ID usable as a `HashMap` key or `HashSet` element, such as when diffing desired against actual inventory."]
+#[doc = ""]
+#[doc = "Azure Resource Manager treats resource IDs case-insensitively, so the ID is stored in ASCII lowercase and two keys are equal if their IDs differ only in case. Most models also implement `Eq` and `Hash` themselves, but those compare every field, including ones that change between reads such as `etag`."]
+#[derive(Clone, Debug, PartialEq, Eq, Hash, PartialOrd, Ord)]
+pub struct ResourceKey(String);
+impl ResourceKey {
+    pub fn new(id: &str) -> Self {
+        Self(id.to_ascii_lowercase())
+    }
+    #[doc = "The lowercased resource ID."]
+    pub fn as_str(&self) -> &str {
+        &self.0
+    }
+}
+impl std::fmt::Display for ResourceKey {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.write_str(&self.0)
+    }
+}
+impl Resource {
+    #[doc = "The key of the resource `id`, for deduplicating resources or keying maps by resource; `None` if the service returned no `id`."]
+    pub fn key(&self) -> Option<ResourceKey> {
+        self.id.as_deref().map(ResourceKey::new)
+    }
+}
+impl ProxyResource {
+    #[doc = "The key of the resource `id`, for deduplicating resources or keying maps by resource; `None` if the service returned no `id`."]
+    pub fn key(&self) -> Option<ResourceKey> {
+        self.resource.key()
+    }
+}
+impl TrackedResource {
+    #[doc = "The key of the resource `id`, for deduplicating resources or keying maps by resource; `None` if the service returned no `id`."]
+    pub fn key(&self) -> Option<ResourceKey> {
+        self.resource.key()
+    }
+}
+#[doc = "The ARM resource ID of an Automation account, `/subscriptions/{subscriptionId}/resourceGroups/{resourceGroupName}/providers/Microsoft.Automation/automationAccounts/{automationAccountName}`."]
+#[derive(Clone, Debug, PartialEq, Eq, Hash)]
+pub struct AutomationAccountId {
+    pub subscription_id: String,
+    pub resource_group_name: String,
+    pub automation_account_name: String,
+}
+impl FromStr for AutomationAccountId {
+    type Err = azure_core::error::Error;
+    #[doc = "Parse a resource ID as returned in the `id` of an `AutomationAccount`. Segment names are matched case-insensitively and components are percent-decoded."]
+    fn from_str(s: &str) -> azure_core::Result<Self> {
+        let invalid = || {
+            azure_core::error::Error::with_message(azure_core::error::ErrorKind::DataConversion, || {
+                format!("invalid automation account resource id {:?}", s)
+            })
+        };
+        let segments: Vec<&str> = s.strip_prefix('/').ok_or_else(invalid)?.trim_end_matches('/').split('/').collect();
+        match segments.as_slice() {
+            [subscriptions, subscription_id, resource_groups, resource_group_name, providers, namespace, automation_accounts, automation_account_name]
+                if subscriptions.eq_ignore_ascii_case("subscriptions")
+                    && resource_groups.eq_ignore_ascii_case("resourceGroups")
+                    && providers.eq_ignore_ascii_case("providers")
+                    && namespace.eq_ignore_ascii_case("Microsoft.Automation")
+                    && automation_accounts.eq_ignore_ascii_case("automationAccounts") =>
+            {
+                Ok(Self {
+                    subscription_id: azure_core::client::decode_path_segment("subscription_id", subscription_id)?,
+                    resource_group_name: azure_core::client::decode_path_segment("resource_group_name", resource_group_name)?,
+                    automation_account_name: azure_core::client::decode_path_segment("automation_account_name", automation_account_name)?,
+                })
+            }
+            _ => Err(invalid()),
+        }
+    }
+}
+impl AutomationAccountId {
+    pub fn new(
+        subscription_id: impl Into<String>,
+        resource_group_name: impl Into<String>,
+        automation_account_name: impl Into<String>,
+    ) -> Self {
+        Self {
+            subscription_id: subscription_id.into(),
+            resource_group_name: resource_group_name.into(),
+            automation_account_name: automation_account_name.into(),
+        }
+    }
+    #[doc = "Format the resource ID, percent-encoding each component the same way request URLs are built. Fails if a component is empty, `.` or `..`."]
+    pub fn resource_id(&self) -> azure_core::Result<String> {
+        Ok(format!(
+            "/subscriptions/{}/resourceGroups/{}/providers/Microsoft.Automation/automationAccounts/{}",
+            azure_core::client::path_segment("subscription_id", &self.subscription_id)?,
+            azure_core::client::path_segment("resource_group_name", &self.resource_group_name)?,
+            azure_core::client::path_segment("automation_account_name", &self.automation_account_name)?
+        ))
+    }
+}
+impl AutomationAccount {
+    #[doc = "The key of the resource `id`, for deduplicating resources or keying maps by resource; `None` if the service returned no `id`."]
+    pub fn key(&self) -> Option<ResourceKey> {
+        self.tracked_resource.key()
+    }
+    #[doc = "Parse the resource `id`, which names the subscription and resource group of the account. Fails if the service returned no `id` or it is not an Automation account resource ID."]
+    pub fn account_id(&self) -> azure_core::Result<AutomationAccountId> {
+        match &self.tracked_resource.resource.id {
+            Some(id) => id.parse(),
+            None => Err(azure_core::error::Error::message(
+                azure_core::error::ErrorKind::DataConversion,
+                "automation account has no id",
+            )),
+        }
+    }
+}
+impl Certificate {
+    #[doc = "The key of the resource `id`, for deduplicating resources or keying maps by resource; `None` if the service returned no `id`."]
+    pub fn key(&self) -> Option<ResourceKey> {
+        self.proxy_resource.key()
+    }
+}
+impl Connection {
+    #[doc = "The key of the resource `id`, for deduplicating resources or keying maps by resource; `None` if the service returned no `id`."]
+    pub fn key(&self) -> Option<ResourceKey> {
+        self.proxy_resource.key()
+    }
+}
+impl Credential {
+    #[doc = "The key of the resource `id`, for deduplicating resources or keying maps by resource; `None` if the service returned no `id`."]
+    pub fn key(&self) -> Option<ResourceKey> {
+        self.proxy_resource.key()
+    }
+}
+impl DscCompilationJob {
+    #[doc = "The key of the resource `id`, for deduplicating resources or keying maps by resource; `None` if the service returned no `id`."]
+    pub fn key(&self) -> Option<ResourceKey> {
+        self.proxy_resource.key()
+    }
+}
+impl DscConfiguration {
+    #[doc = "The key of the resource `id`, for deduplicating resources or keying maps by resource; `None` if the service returned no `id`."]
+    pub fn key(&self) -> Option<ResourceKey> {
+        self.tracked_resource.key()
+    }
+}
+impl DscNode {
+    #[doc = "The key of the resource `id`, for deduplicating resources or keying maps by resource; `None` if the service returned no `id`."]
+    pub fn key(&self) -> Option<ResourceKey> {
+        self.proxy_resource.key()
+    }
+}
+impl DscNodeConfiguration {
+    #[doc = "The key of the resource `id`, for deduplicating resources or keying maps by resource; `None` if the service returned no `id`."]
+    pub fn key(&self) -> Option<ResourceKey> {
+        self.proxy_resource.key()
+    }
+}
+impl HybridRunbookWorker {
+    #[doc = "The key of the resource `id`, for deduplicating resources or keying maps by resource; `None` if the service returned no `id`."]
+    pub fn key(&self) -> Option<ResourceKey> {
+        self.resource.key()
+    }
+}
+impl HybridRunbookWorkerGroup {
+    #[doc = "The key of the resource `id`, for deduplicating resources or keying maps by resource; `None` if the service returned no `id`."]
+    pub fn key(&self) -> Option<ResourceKey> {
+        self.resource.key()
+    }
+}
+impl Job {
+    #[doc = "The key of the resource `id`, for deduplicating resources or keying maps by resource; `None` if the service returned no `id`."]
+    pub fn key(&self) -> Option<ResourceKey> {
+        self.proxy_resource.key()
+    }
+}
+impl JobCollectionItem {
+    #[doc = "The key of the resource `id`, for deduplicating resources or keying maps by resource; `None` if the service returned no `id`."]
+    pub fn key(&self) -> Option<ResourceKey> {
+        self.proxy_resource.key()
+    }
+}
+impl Module {
+    #[doc = "The key of the resource `id`, for deduplicating resources or keying maps by resource; `None` if the service returned no `id`."]
+    pub fn key(&self) -> Option<ResourceKey> {
+        self.tracked_resource.key()
+    }
+}
+impl PrivateEndpointConnection {
+    #[doc = "The key of the resource `id`, for deduplicating resources or keying maps by resource; `None` if the service returned no `id`."]
+    pub fn key(&self) -> Option<ResourceKey> {
+        self.proxy_resource.key()
+    }
+}
+impl PrivateLinkResource {
+    #[doc = "The key of the resource `id`, for deduplicating resources or keying maps by resource; `None` if the service returned no `id`."]
+    pub fn key(&self) -> Option<ResourceKey> {
+        self.proxy_resource.key()
+    }
+}
+impl Runbook {
+    #[doc = "The key of the resource `id`, for deduplicating resources or keying maps by resource; `None` if the service returned no `id`."]
+    pub fn key(&self) -> Option<ResourceKey> {
+        self.tracked_resource.key()
+    }
+}
+impl Schedule {
+    #[doc = "The key of the resource `id`, for deduplicating resources or keying maps by resource; `None` if the service returned no `id`."]
+    pub fn key(&self) -> Option<ResourceKey> {
+        self.proxy_resource.key()
+    }
+}
+impl SourceControl {
+    #[doc = "The key of the resource `id`, for deduplicating resources or keying maps by resource; `None` if the service returned no `id`."]
+    pub fn key(&self) -> Option<ResourceKey> {
+        self.proxy_resource.key()
+    }
+}
+impl Variable {
+    #[doc = "The key of the resource `id`, for deduplicating resources or keying maps by resource; `None` if the service returned no `id`."]
+    pub fn key(&self) -> Option<ResourceKey> {
+        self.proxy_resource.key()
+    }
+}
+impl Watcher {
+    #[doc = "The key of the resource `id`, for deduplicating resources or keying maps by resource; `None` if the service returned no `id`."]
+    pub fn key(&self) -> Option<ResourceKey> {
+        self.resource.key()
+    }
+}
+impl Webhook {
+    #[doc = "The key of the resource `id`, for deduplicating resources or keying maps by resource; `None` if the service returned no `id`."]
+    pub fn key(&self) -> Option<ResourceKey> {
+        self.proxy_resource.key()
+    }
+}
+#[doc = "The type of counts to retrieve."]
+#[derive(Clone, Debug, PartialEq, Eq, Hash, Serialize, Deserialize)]
+#[serde(remote = "CountType")]
+pub enum CountType {
+    #[serde(rename = "status")]
+    Status,
+    #[serde(rename = "nodeconfiguration")]
+    NodeConfiguration,
+    #[serde(skip_deserializing)]
+    UnknownValue(String),
+}
+impl FromStr for CountType {
+    type Err = value::Error;
+    fn from_str(s: &str) -> std::result::Result<Self, Self::Err> {
+        Self::deserialize(s.into_deserializer())
+    }
+}
+impl<'de> Deserialize<'de> for CountType {
+    fn deserialize<D>(deserializer: D) -> std::result::Result<Self, D::Error>
+    where
+        D: Deserializer<'de>,
+    {
+        let s = String::deserialize(deserializer)?;
+        let deserialized = Self::from_str(&s).unwrap_or(Self::UnknownValue(s));
+        Ok(deserialized)
+    }
+}
+impl Serialize for CountType {
+    fn serialize<S>(&self, serializer: S) -> std::result::Result<S::Ok, S::Error>
+    where
+        S: Serializer,
+    {
+        match self {
+            Self::Status => serializer.serialize_unit_variant("CountType", 0u32, "status"),
+            Self::NodeConfiguration => serializer.serialize_unit_variant("CountType", 1u32, "nodeconfiguration"),
+            Self::UnknownValue(s) => serializer.serialize_str(s.as_str()),
+        }
+    }
+}
+impl CountType {
+    #[doc = "The path segment used for this count type."]
+    pub fn as_str(&self) -> &str {
+        match self {
+            Self::Status => "status",
+            Self::NodeConfiguration => "nodeconfiguration",
+            Self::UnknownValue(s) => s.as_str(),
+        }
+    }
+}
+impl std::fmt::Display for CountType {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.write_str(self.as_str())
+    }
+}
+impl From<&str> for CountType {
+    fn from(s: &str) -> Self {
+        Self::from_str(s).unwrap_or_else(|_| Self::UnknownValue(s.to_owned()))
+    }
+}
+impl From<String> for CountType {
+    fn from(s: String) -> Self {
+        Self::from_str(&s).unwrap_or(Self::UnknownValue(s))
+    }
+}
+#[doc = "The provisioning state of a resource."]
+#[derive(Clone, Debug, PartialEq, Eq, Hash, Serialize, Deserialize)]
+#[serde(remote = "ProvisioningState")]
+pub enum ProvisioningState {
+    Succeeded,
+    Failed,
+    Canceled,
+    Creating,
+    Updating,
+    Deleting,
+    Provisioning,
+    #[serde(skip_deserializing)]
+    UnknownValue(String),
+}
+impl FromStr for ProvisioningState {
+    type Err = value::Error;
+    fn from_str(s: &str) -> std::result::Result<Self, Self::Err> {
+        Self::deserialize(s.into_deserializer())
+    }
+}
+impl<'de> Deserialize<'de> for ProvisioningState {
+    fn deserialize<D>(deserializer: D) -> std::result::Result<Self, D::Error>
+    where
+        D: Deserializer<'de>,
+    {
+        let s = String::deserialize(deserializer)?;
+        let deserialized = Self::from_str(&s).unwrap_or(Self::UnknownValue(s));
+        Ok(deserialized)
+    }
+}
+impl Serialize for ProvisioningState {
+    fn serialize<S>(&self, serializer: S) -> std::result::Result<S::Ok, S::Error>
+    where
+        S: Serializer,
+    {
+        match self {
+            Self::Succeeded => serializer.serialize_unit_variant("ProvisioningState", 0u32, "Succeeded"),
+            Self::Failed => serializer.serialize_unit_variant("ProvisioningState", 1u32, "Failed"),
+            Self::Canceled => serializer.serialize_unit_variant("ProvisioningState", 2u32, "Canceled"),
+            Self::Creating => serializer.serialize_unit_variant("ProvisioningState", 3u32, "Creating"),
+            Self::Updating => serializer.serialize_unit_variant("ProvisioningState", 4u32, "Updating"),
+            Self::Deleting => serializer.serialize_unit_variant("ProvisioningState", 5u32, "Deleting"),
+            Self::Provisioning => serializer.serialize_unit_variant("ProvisioningState", 6u32, "Provisioning"),
+            Self::UnknownValue(s) => serializer.serialize_str(s.as_str()),
+        }
+    }
+}
+impl ProvisioningState {
+    #[doc = "Whether the operation has finished, successfully or not. Polling can stop once this is `true`."]
+    pub fn is_terminal(&self) -> bool {
+        matches!(self, Self::Succeeded | Self::Failed | Self::Canceled)
+    }
+    #[doc = "Whether the operation finished successfully."]
+    pub fn is_success(&self) -> bool {
+        matches!(self, Self::Succeeded)
+    }
+    #[doc = "The value as it is sent on the wire."]
+    pub fn as_str(&self) -> &str {
+        match self {
+            Self::Succeeded => "Succeeded",
+            Self::Failed => "Failed",
+            Self::Canceled => "Canceled",
+            Self::Creating => "Creating",
+            Self::Updating => "Updating",
+            Self::Deleting => "Deleting",
+            Self::Provisioning => "Provisioning",
+            Self::UnknownValue(s) => s.as_str(),
+        }
+    }
+}
+impl std::fmt::Display for ProvisioningState {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.write_str(self.as_str())
+    }
+}
+#[doc = "The compliance status of a DSC node, as reported in `DscNodeProperties::status`."]
+#[derive(Clone, Debug, PartialEq, Eq, Hash)]
+pub enum DscNodeStatus {
+    Compliant,
+    NotCompliant,
+    Failed,
+    Pending,
+    Received,
+    Unresponsive,
+    UnknownValue(String),
+}
+impl DscNodeStatus {
+    #[doc = "The value as it is sent on the wire."]
+    pub fn as_str(&self) -> &str {
+        match self {
+            Self::Compliant => "Compliant",
+            Self::NotCompliant => "NotCompliant",
+            Self::Failed => "Failed",
+            Self::Pending => "Pending",
+            Self::Received => "Received",
+            Self::Unresponsive => "Unresponsive",
+            Self::UnknownValue(s) => s.as_str(),
+        }
+    }
+}
+impl std::fmt::Display for DscNodeStatus {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.write_str(self.as_str())
+    }
+}
+#[doc = "An OData `$filter` for `dsc_node::list_by_automation_account`, built from the fields the service supports filtering on."]
+#[doc = ""]
+#[doc = "Pass it to `filter_typed`. Filters combine with `and` and `or`, which parenthesize both sides, so `DscNodeFilter::status(DscNodeStatus::Failed).or(DscNodeFilter::status(DscNodeStatus::Unresponsive)).and(DscNodeFilter::node_configuration_name(\"web.localhost\"))` means what it reads as."]
+#[derive(Clone, Debug, PartialEq, Eq, Hash)]
+pub struct DscNodeFilter(String);
+impl DscNodeFilter {
+    #[doc = "Nodes whose `properties/status` is `eq`."]
+    pub fn status(eq: DscNodeStatus) -> Self {
+        Self(format!("properties/status eq {}", odata_string(eq.as_str())))
+    }
+    #[doc = "Nodes assigned the node configuration named `eq`, such as `web.localhost`, by `properties/nodeConfiguration/name`."]
+    pub fn node_configuration_name(eq: &str) -> Self {
+        Self(format!("properties/nodeConfiguration/name eq {}", odata_string(eq)))
+    }
+    #[doc = "Nodes matching both `self` and `other`."]
+    pub fn and(self, other: Self) -> Self {
+        Self(format!("({}) and ({})", self.0, other.0))
+    }
+    #[doc = "Nodes matching `self`, `other`, or both."]
+    pub fn or(self, other: Self) -> Self {
+        Self(format!("({}) or ({})", self.0, other.0))
+    }
+    #[doc = "The `$filter` expression."]
+    pub fn as_str(&self) -> &str {
+        &self.0
+    }
+}
+impl std::fmt::Display for DscNodeFilter {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.write_str(&self.0)
+    }
+}
+impl Identity {
+    #[doc = "A system-assigned managed identity, created and deleted together with the resource."]
+    pub fn system_assigned() -> Self {
+        Self {
+            type_: Some(identity::Type::SystemAssigned),
+            ..Self::default()
+        }
+    }
+    #[doc = "The user-assigned managed identity `resource_id`, such as `/subscriptions/{subscriptionId}/resourceGroups/{resourceGroupName}/providers/Microsoft.ManagedIdentity/userAssignedIdentities/{identityName}`. Add more with `with_user_assigned`."]
+    pub fn user_assigned(resource_id: impl Into<String>) -> Self {
+        Self::new().with_user_assigned(resource_id)
+    }
+    #[doc = "No managed identity, for removing the identities of an existing resource."]
+    pub fn none() -> Self {
+        Self {
+            type_: Some(identity::Type::None),
+            ..Self::default()
+        }
+    }
+    #[doc = "Add the system-assigned managed identity, keeping any user-assigned ones."]
+    pub fn with_system_assigned(mut self) -> Self {
+        self.type_ = Some(match self.type_ {
+            Some(identity::Type::UserAssigned | identity::Type::SystemAssignedUserAssigned) => identity::Type::SystemAssignedUserAssigned,
+            _ => identity::Type::SystemAssigned,
+        });
+        self
+    }
+    #[doc = "Add the user-assigned managed identity `resource_id`, keeping the system-assigned one if there is one."]
+    #[doc = ""]
+    #[doc = "The type is set to match, so that an identity built this way always lists the user-assigned identities its type calls for, which the service requires."]
+    pub fn with_user_assigned(mut self, resource_id: impl Into<String>) -> Self {
+        self.type_ = Some(match self.type_ {
+            Some(identity::Type::SystemAssigned | identity::Type::SystemAssignedUserAssigned) => identity::Type::SystemAssignedUserAssigned,
+            _ => identity::Type::UserAssigned,
+        });
+        let mut user_assigned_identities = match self.user_assigned_identities.take() {
+            Some(serde_json::Value::Object(user_assigned_identities)) => user_assigned_identities,
+            _ => serde_json::Map::new(),
+        };
+        user_assigned_identities.insert(resource_id.into(), serde_json::json!({}));
+        self.user_assigned_identities = Some(serde_json::Value::Object(user_assigned_identities));
+        self
+    }
+    #[doc = "The resource ids of the user-assigned managed identities."]
+    pub fn user_assigned_ids(&self) -> Vec<&str> {
+        match &self.user_assigned_identities {
+            Some(serde_json::Value::Object(user_assigned_identities)) => user_assigned_identities.keys().map(String::as_str).collect(),
+            _ => Vec::new(),
+        }
+    }
+}
+impl AgentRegistrationRegenerateKeyParameter {
+    #[doc = "Regenerate the primary key."]
+    pub fn primary() -> Self {
+        Self::new(AgentRegistrationKeyName::Primary)
+    }
+    #[doc = "Regenerate the secondary key."]
+    pub fn secondary() -> Self {
+        Self::new(AgentRegistrationKeyName::Secondary)
+    }
+    #[doc = "Regenerate the key named `key_name`, `primary` or `secondary` in any case. Any other name fails here rather than with a `400 Bad Request` from the service."]
+    pub fn from_key_name(key_name: &str) -> azure_core::Result<Self> {
+        match AgentRegistrationKeyName::from_str(&key_name.to_ascii_lowercase()) {
+            Ok(key_name) => Ok(Self::new(key_name)),
+            Err(_) => Err(azure_core::error::Error::with_message(
+                azure_core::error::ErrorKind::DataConversion,
+                || {
+                    format!(
+                        "invalid agent registration key name {:?}: expected \"primary\" or \"secondary\"",
+                        key_name
+                    )
+                },
+            )),
+        }
+    }
+}
+#[doc = "The name of an agent registration key, `primary` or `secondary`."]
+pub type AgentRegistrationKeyName = agent_registration_regenerate_key_parameter::KeyName;
+impl AutomationAccountCreateOrUpdateParameters {
+    #[doc = "Set the create or update account properties."]
+    pub fn properties(mut self, properties: impl Into<AutomationAccountCreateOrUpdateProperties>) -> Self {
+        self.properties = Some(properties.into());
+        self
+    }
+    #[doc = "Set the account SKU, creating the account properties if they are not set yet."]
+    pub fn sku(mut self, sku: impl Into<Sku>) -> Self {
+        self.properties.get_or_insert_with(Default::default).sku = Some(sku.into());
+        self
+    }
+    #[doc = "Set the name of the resource."]
+    pub fn name(mut self, name: impl Into<String>) -> Self {
+        self.name = Some(name.into());
+        self
+    }
+    #[doc = "Set the location of the resource."]
+    pub fn location(mut self, location: impl Into<String>) -> Self {
+        self.location = Some(location.into());
+        self
+    }
+    #[doc = "Set the identity for the resource."]
+    pub fn identity(mut self, identity: impl Into<Identity>) -> Self {
+        self.identity = Some(identity.into());
+        self
+    }
+    #[doc = "Set the tags attached to the resource."]
+    pub fn tags(mut self, tags: impl Into<serde_json::Value>) -> Self {
+        self.tags = Some(tags.into());
+        self
+    }
+}
+impl AutomationAccountCreateOrUpdateProperties {
+    #[doc = "Set the account SKU."]
+    pub fn sku(mut self, sku: impl Into<Sku>) -> Self {
+        self.sku = Some(sku.into());
+        self
+    }
+    #[doc = "Set the encryption settings for the automation account."]
+    pub fn encryption(mut self, encryption: impl Into<EncryptionProperties>) -> Self {
+        self.encryption = Some(encryption.into());
+        self
+    }
+    #[doc = "Set whether traffic on the non-ARM endpoint (Webhook/Agent) is allowed from the public internet."]
+    pub fn public_network_access(mut self, public_network_access: bool) -> Self {
+        self.public_network_access = Some(public_network_access);
+        self
+    }
+    #[doc = "Set whether requests using non-AAD authentication are blocked."]
+    pub fn disable_local_auth(mut self, disable_local_auth: bool) -> Self {
+        self.disable_local_auth = Some(disable_local_auth);
+        self
+    }
+}
+impl DscNodeConfigurationCreateOrUpdateParameters {
+    #[doc = "Set the properties supplied to the create or update node configuration operation."]
+    pub fn properties(mut self, properties: impl Into<DscNodeConfigurationCreateOrUpdateParametersProperties>) -> Self {
+        self.properties = Some(properties.into());
+        self
+    }
+    #[doc = "Set the name of the node configuration."]
+    pub fn name(mut self, name: impl Into<String>) -> Self {
+        self.name = Some(name.into());
+        self
+    }
+    #[doc = "Set the tags attached to the resource."]
+    pub fn tags(mut self, tags: impl Into<serde_json::Value>) -> Self {
+        self.tags = Some(tags.into());
+        self
+    }
+}
+impl DscNodeConfigurationCreateOrUpdateParametersProperties {
+    #[doc = "Set whether a new build version of the node configuration is required."]
+    pub fn increment_node_configuration_build(mut self, increment_node_configuration_build: bool) -> Self {
+        self.increment_node_configuration_build = Some(increment_node_configuration_build);
+        self
+    }
+}
+#[doc = "Copies the fields of a fetched account that the update operation accepts, for read-modify-write flows; read-only fields such as `state` are dropped."]
+impl From<AutomationAccount> for AutomationAccountUpdateParameters {
+    fn from(account: AutomationAccount) -> Self {
+        Self {
+            properties: account.properties.map(Into::into),
+            name: account.tracked_resource.resource.name,
+            location: account.tracked_resource.location,
+            identity: account.identity,
+            tags: account.tracked_resource.tags,
+        }
+    }
+}
+impl From<AutomationAccountProperties> for AutomationAccountUpdateProperties {
+    fn from(properties: AutomationAccountProperties) -> Self {
+        Self {
+            sku: properties.sku,
+            encryption: properties.encryption,
+            public_network_access: properties.public_network_access,
+            disable_local_auth: properties.disable_local_auth,
+        }
+    }
+}
+#[doc = "Copies the fields of a fetched configuration that the update operation accepts, for read-modify-write flows. The update properties require a `source`, so `properties` is left unset if the fetched configuration has none."]
+impl From<DscConfiguration> for DscConfigurationUpdateParameters {
+    fn from(configuration: DscConfiguration) -> Self {
+        let properties = configuration.properties.and_then(|properties| {
+            Some(DscConfigurationCreateOrUpdateProperties {
+                log_verbose: properties.log_verbose,
+                log_progress: None,
+                source: properties.source?,
+                parameters: properties.parameters,
+                description: properties.description,
+            })
+        });
+        Self {
+            properties,
+            name: configuration.tracked_resource.resource.name,
+            tags: configuration.tracked_resource.tags,
+        }
+    }
+}
+#[doc = "Copies the fields of a fetched source control that the update operation accepts, for read-modify-write flows. The security token is never returned by the service, so it is left unset."]
+impl From<SourceControl> for SourceControlUpdateParameters {
+    fn from(source_control: SourceControl) -> Self {
+        Self {
+            properties: source_control.properties.map(Into::into),
+        }
+    }
+}
+impl From<SourceControlProperties> for SourceControlUpdateProperties {
+    fn from(properties: SourceControlProperties) -> Self {
+        Self {
+            branch: properties.branch,
+            folder_path: properties.folder_path,
+            auto_sync: properties.auto_sync,
+            publish_runbook: properties.publish_runbook,
+            security_token: None,
+            description: properties.description,
+        }
+    }
+}
+impl From<sku::Name> for Sku {
+    fn from(name: sku::Name) -> Self {
+        Self::new(name)
+    }
+}
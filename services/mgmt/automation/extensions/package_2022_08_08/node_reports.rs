@@ -0,0 +1,89 @@
+// Hand-written additions to the `node_reports` operations, included at the end of the generated module.
+impl Client {
+    #[doc = "Fetch the `n` most recent reports of a node, newest first."]
+    #[doc = ""]
+    #[doc = "The service lists reports in no particular order and cannot sort them, so every page of `list_by_node` is read and the reports are ordered by `start_time` on the client, keeping no more than `2 * n` in memory. Reports without a start time come last."]
+    pub async fn latest(
+        &self,
+        resource_group_name: &str,
+        automation_account_name: &str,
+        node_id: &str,
+        subscription_id: &str,
+        n: usize,
+    ) -> azure_core::Result<Vec<models::DscNodeReport>> {
+        use futures::TryStreamExt;
+        fn newest_first(reports: &mut Vec<models::DscNodeReport>, n: usize) {
+            reports.sort_by_key(|report| std::cmp::Reverse(report.start_time));
+            reports.truncate(n);
+        }
+        let mut reports = Vec::new();
+        if n == 0 {
+            return Ok(reports);
+        }
+        let mut items = self
+            .list_by_node(resource_group_name, automation_account_name, node_id, subscription_id)
+            .into_item_stream();
+        while let Some(report) = items.try_next().await? {
+            reports.push(report);
+            if reports.len() >= n.saturating_mul(2) {
+                newest_first(&mut reports, n);
+            }
+        }
+        newest_first(&mut reports, n);
+        Ok(reports)
+    }
+    #[doc = "Fetch the latest report of each node in `node_ids`, with at most `concurrency` requests in flight."]
+    #[doc = ""]
+    #[doc = "Returns each node id with its latest report, `None` if the node has not reported yet, in the order of `node_ids`. A failure for one node does not stop the others. Each node's reports are read in full, as `latest` does, since the service does not list them in order. See `map_concurrent` for fanning out other operations."]
+    pub async fn latest_for_nodes(
+        &self,
+        resource_group_name: &str,
+        automation_account_name: &str,
+        node_ids: &[&str],
+        subscription_id: &str,
+        concurrency: usize,
+    ) -> Vec<(String, azure_core::Result<Option<models::DscNodeReport>>)> {
+        super::map_concurrent(
+            node_ids.iter().map(|node_id| (*node_id).to_owned()),
+            concurrency,
+            |node_id| async move {
+                let latest = self
+                    .latest(resource_group_name, automation_account_name, &node_id, subscription_id, 1)
+                    .await?;
+                Ok(latest.into_iter().next())
+            },
+        )
+        .await
+    }
+}
+impl get_content::Response {
+    #[doc = "Deserialize the response body into a caller supplied type rather than a `serde_json::Value`."]
+    pub async fn into_body_as<T: serde::de::DeserializeOwned>(self) -> azure_core::Result<T> {
+        let status = self.status();
+        let bytes = self.into_byte_stream().collect().await?;
+        let body: T = azure_core::client::from_json_body("node_reports::get_content", status, &bytes)?;
+        Ok(body)
+    }
+    #[doc = "Write the response body to the file at `path` as it arrives, without buffering the whole report, and return the number of bytes written."]
+    #[doc = ""]
+    #[doc = "The file is created, or truncated if it exists."]
+    pub async fn save_to(self, path: impl AsRef<std::path::Path>) -> azure_core::Result<u64> {
+        use azure_core::error::ResultExt;
+        use futures::StreamExt;
+        use std::io::Write;
+        let path = path.as_ref();
+        let mut file = std::fs::File::create(path)
+            .with_context(azure_core::error::ErrorKind::Io, || format!("failed to create {}", path.display()))?;
+        let mut body = self.into_byte_stream();
+        let mut written = 0u64;
+        while let Some(chunk) = body.next().await {
+            let chunk = chunk?;
+            file.write_all(&chunk)
+                .with_context(azure_core::error::ErrorKind::Io, || format!("failed to write {}", path.display()))?;
+            written += chunk.len() as u64;
+        }
+        file.flush()
+            .with_context(azure_core::error::ErrorKind::Io, || format!("failed to write {}", path.display()))?;
+        Ok(written)
+    }
+}
@@ -0,0 +1,9 @@
+// Hand-written additions to the `operations` operations, included at the end of the generated module.
+impl Client {
+    #[doc = "Stream every operation the Automation resource provider offers, across all pages, such as for generating RBAC role definitions from their names."]
+    #[doc = ""]
+    #[doc = "Shorthand for `list().into_item_stream()`."]
+    pub fn all(&self) -> futures::stream::BoxStream<'static, azure_core::Result<models::Operation>> {
+        self.list().into_item_stream()
+    }
+}
@@ -0,0 +1,102 @@
+// Hand-written additions to the `source_control` operations, included at the end of the generated module.
+impl Client {
+    #[doc = "Get a source control, creating it from `parameters` if it does not exist."]
+    #[doc = ""]
+    #[doc = "Sends a `GET`, and on `404 Not Found` a `PUT` with `If-None-Match: *`, so that a source control created by someone else in between is not overwritten. If the service reports that race, with `409 Conflict` or `412 Precondition Failed`, the call fails with an `ErrorKind::HttpResponse` error of status `409 Conflict`; calling `get_or_create` again returns the source control that was created first. Any other error is returned as is."]
+    #[doc = ""]
+    #[doc = "Arguments:"]
+    #[doc = "* `resource_group_name`: Name of an Azure Resource group."]
+    #[doc = "* `automation_account_name`: The name of the automation account."]
+    #[doc = "* `source_control_name`: The source control name."]
+    #[doc = "* `parameters`: The parameters supplied to the create or update source control operation."]
+    #[doc = "* `subscription_id`: Gets subscription credentials which uniquely identify Microsoft Azure subscription. The subscription ID forms part of the URI for every service call."]
+    pub fn get_or_create(
+        &self,
+        resource_group_name: impl Into<String>,
+        automation_account_name: impl Into<String>,
+        source_control_name: impl Into<String>,
+        parameters: impl Into<models::SourceControlCreateOrUpdateParameters>,
+        subscription_id: impl Into<String>,
+    ) -> futures::future::BoxFuture<'static, azure_core::Result<models::SourceControl>> {
+        let create = self
+            .create_or_update(
+                resource_group_name,
+                automation_account_name,
+                source_control_name,
+                parameters,
+                subscription_id,
+            )
+            .if_none_match("*");
+        let get = self.get(
+            create.resource_group_name.clone(),
+            create.automation_account_name.clone(),
+            create.source_control_name.clone(),
+            create.subscription_id.clone(),
+        );
+        Box::pin(async move {
+            if let Some(source_control) = get.get_if_exists().await? {
+                return Ok(source_control);
+            }
+            let source_control_name = create.source_control_name.clone();
+            create.into_future().await.map_err(|err| match err.kind() {
+                azure_core::error::ErrorKind::HttpResponse {
+                    status: azure_core::StatusCode::Conflict | azure_core::StatusCode::PreconditionFailed,
+                    ..
+                } => azure_core::error::Error::full(
+                    azure_core::error::ErrorKind::HttpResponse {
+                        status: azure_core::StatusCode::Conflict,
+                        error_code: None,
+                    },
+                    err,
+                    format!("source control {:?} was created concurrently", source_control_name),
+                ),
+                _ => err,
+            })
+        })
+    }
+    #[doc = "Delete every source control in the automation account."]
+    #[doc = ""]
+    #[doc = "All pages of the listing are read first, then the source controls are deleted with at most `DELETE_ALL_CONCURRENCY` requests in flight. Each source control gets its own entry in the returned `Vec`, so partial failures are visible. An error while listing is returned before anything is deleted."]
+    #[doc = ""]
+    #[doc = "Arguments:"]
+    #[doc = "* `resource_group_name`: Name of an Azure Resource group."]
+    #[doc = "* `automation_account_name`: The name of the automation account."]
+    #[doc = "* `subscription_id`: Gets subscription credentials which uniquely identify Microsoft Azure subscription. The subscription ID forms part of the URI for every service call."]
+    pub async fn delete_all(
+        &self,
+        resource_group_name: impl Into<String>,
+        automation_account_name: impl Into<String>,
+        subscription_id: impl Into<String>,
+    ) -> azure_core::Result<Vec<(String, azure_core::Result<()>)>> {
+        use futures::StreamExt;
+        let resource_group_name = resource_group_name.into();
+        let automation_account_name = automation_account_name.into();
+        let subscription_id = subscription_id.into();
+        let mut names = Vec::new();
+        let mut pages = self
+            .list_by_automation_account(&resource_group_name, &automation_account_name, &subscription_id)
+            .into_stream();
+        while let Some(page) = pages.next().await {
+            names.extend(
+                page?
+                    .value
+                    .into_iter()
+                    .filter_map(|source_control| source_control.proxy_resource.resource.name),
+            );
+        }
+        let results = futures::stream::iter(names)
+            .map(|name| {
+                let request = self.delete(&resource_group_name, &automation_account_name, &name, &subscription_id);
+                async move {
+                    let result = request.send().await.map(|_| ());
+                    (name, result)
+                }
+            })
+            .buffer_unordered(DELETE_ALL_CONCURRENCY)
+            .collect()
+            .await;
+        Ok(results)
+    }
+}
+#[doc = "The maximum number of deletes `Client::delete_all` keeps in flight."]
+pub const DELETE_ALL_CONCURRENCY: usize = 4;
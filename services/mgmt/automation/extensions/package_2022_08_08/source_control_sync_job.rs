@@ -0,0 +1,48 @@
+// Hand-written additions to the `source_control_sync_job` operations, included at the end of the generated module.
+impl create::RequestBuilder {
+    #[doc = "Create the sync job, then poll `get` as `options` asks until the job has finished, resolving with the finished job."]
+    #[doc = ""]
+    #[doc = "A job whose provisioning state is `Failed` fails with an `ErrorKind::Other` error carrying the job's exception. Once `options` times out, fails with a `PollTimeout<models::SourceControlSyncJobById>` error carrying the job as last read. Passing a `Duration` polls at that interval without a deadline."]
+    #[doc = ""]
+    #[doc = "The service has no operation to stop a sync job, so dropping the future only stops polling: the sync runs to completion."]
+    pub fn poll_until_done(
+        self,
+        options: impl Into<super::PollOptions>,
+    ) -> futures::future::BoxFuture<'static, azure_core::Result<models::SourceControlSyncJobById>> {
+        let mut clock = azure_core::lro::PollClock::new(options.into());
+        Box::pin(async move {
+            self.send().await?;
+            let get = get::RequestBuilder {
+                client: self.client,
+                resource_group_name: self.resource_group_name,
+                automation_account_name: self.automation_account_name,
+                source_control_name: self.source_control_name,
+                source_control_sync_job_id: self.source_control_sync_job_id,
+                subscription_id: self.subscription_id,
+                if_none_match: None,
+                claims: self.claims,
+                accept_language: self.accept_language,
+                query_params: Vec::new(),
+            };
+            loop {
+                let rsp = get.send().await?;
+                let retry_after = rsp.as_raw_response().retry_after();
+                let job = rsp.into_body().await?;
+                let properties = job.properties.as_ref();
+                match properties.and_then(|properties| properties.provisioning_state.as_ref()) {
+                    Some(models::source_control_sync_job_by_id_properties::ProvisioningState::Completed) => return Ok(job),
+                    Some(models::source_control_sync_job_by_id_properties::ProvisioningState::Failed) => {
+                        let exception = properties
+                            .and_then(|properties| properties.exception.clone())
+                            .unwrap_or_else(|| "no exception reported".to_owned());
+                        let id = get.source_control_sync_job_id.clone();
+                        return Err(azure_core::error::Error::with_message(azure_core::error::ErrorKind::Other, || {
+                            format!("source control sync job {} failed: {}", id, exception)
+                        }));
+                    }
+                    _ => clock.tick("source_control_sync_job::create", retry_after, job).await?,
+                }
+            }
+        })
+    }
+}
@@ -0,0 +1,36 @@
+// Hand-written additions to the `source_control_sync_job_streams` operations, included at the end of the generated module.
+#[doc = "An OData `$filter` expression for `list_by_sync_job`."]
+#[doc = ""]
+#[doc = "Pass it to `filter_typed`, e.g. `.filter_typed(SyncJobStreamFilter::stream_type(StreamType::Output))`."]
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct SyncJobStreamFilter(String);
+impl SyncJobStreamFilter {
+    #[doc = "Match streams of the given type."]
+    pub fn stream_type(stream_type: models::source_control_sync_job_stream_properties::StreamType) -> Self {
+        Self(format!("properties/streamType eq '{}'", stream_type.as_str().replace('\'', "''")))
+    }
+    #[doc = "Match streams generated after `time`."]
+    pub fn time_generated_after(time: time::OffsetDateTime) -> Self {
+        Self(format!("properties/time gt {}", azure_core::date::to_rfc3339(&time)))
+    }
+    #[doc = "Match streams that satisfy both filters."]
+    pub fn and(self, other: SyncJobStreamFilter) -> Self {
+        Self(format!("{} and {}", self.0, other.0))
+    }
+}
+impl std::fmt::Display for SyncJobStreamFilter {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.write_str(&self.0)
+    }
+}
+impl From<SyncJobStreamFilter> for String {
+    fn from(filter: SyncJobStreamFilter) -> Self {
+        filter.0
+    }
+}
+impl list_by_sync_job::RequestBuilder {
+    #[doc = "The filter to apply on the operation, built from the typed `SyncJobStreamFilter`."]
+    pub fn filter_typed(self, filter: SyncJobStreamFilter) -> Self {
+        self.filter(filter)
+    }
+}
@@ -4,7 +4,7 @@ use serde::de::{value, Deserializer, IntoDeserializer};
 use serde::{Deserialize, Serialize, Serializer};
 use std::str::FromStr;
 #[doc = "Definition of the activity."]
-#[derive(Clone, Debug, PartialEq, Serialize, Deserialize, Default)]
+#[derive(Clone, Debug, PartialEq, Eq, Hash, Serialize, Deserialize, Default)]
 pub struct Activity {
     #[doc = "Gets or sets the id of the resource."]
     #[serde(default, skip_serializing_if = "Option::is_none")]
@@ -22,7 +22,7 @@ impl Activity {
     }
 }
 #[doc = "The response model for the list activity operation."]
-#[derive(Clone, Debug, PartialEq, Serialize, Deserialize, Default)]
+#[derive(Clone, Debug, PartialEq, Eq, Hash, Serialize, Deserialize, Default)]
 pub struct ActivityListResult {
     #[doc = "Gets or sets a list of activities."]
     #[serde(
@@ -45,9 +45,13 @@ impl ActivityListResult {
     pub fn new() -> Self {
         Self::default()
     }
+    #[doc = "The items in this page."]
+    pub fn items(&self) -> &[Activity] {
+        &self.value
+    }
 }
 #[doc = "Definition of the activity output type."]
-#[derive(Clone, Debug, PartialEq, Serialize, Deserialize, Default)]
+#[derive(Clone, Debug, PartialEq, Eq, Hash, Serialize, Deserialize, Default)]
 pub struct ActivityOutputType {
     #[doc = "Gets or sets the name of the activity output type."]
     #[serde(default, skip_serializing_if = "Option::is_none")]
@@ -62,7 +66,7 @@ impl ActivityOutputType {
     }
 }
 #[doc = "Definition of the activity parameter."]
-#[derive(Clone, Debug, PartialEq, Serialize, Deserialize, Default)]
+#[derive(Clone, Debug, PartialEq, Eq, Hash, Serialize, Deserialize, Default)]
 pub struct ActivityParameter {
     #[doc = "Gets or sets the name of the activity parameter."]
     #[serde(default, skip_serializing_if = "Option::is_none")]
@@ -106,7 +110,7 @@ impl ActivityParameter {
     }
 }
 #[doc = "Definition of the activity parameter set."]
-#[derive(Clone, Debug, PartialEq, Serialize, Deserialize, Default)]
+#[derive(Clone, Debug, PartialEq, Eq, Hash, Serialize, Deserialize, Default)]
 pub struct ActivityParameterSet {
     #[doc = "Gets or sets the name of the activity parameter set."]
     #[serde(default, skip_serializing_if = "Option::is_none")]
@@ -125,7 +129,7 @@ impl ActivityParameterSet {
     }
 }
 #[doc = "Definition of the activity parameter validation set."]
-#[derive(Clone, Debug, PartialEq, Serialize, Deserialize, Default)]
+#[derive(Clone, Debug, PartialEq, Eq, Hash, Serialize, Deserialize, Default)]
 pub struct ActivityParameterValidationSet {
     #[doc = "Gets or sets the name of the activity parameter validation set member."]
     #[serde(rename = "memberValue", default, skip_serializing_if = "Option::is_none")]
@@ -137,7 +141,7 @@ impl ActivityParameterValidationSet {
     }
 }
 #[doc = "Properties of the activity."]
-#[derive(Clone, Debug, PartialEq, Serialize, Deserialize, Default)]
+#[derive(Clone, Debug, PartialEq, Eq, Hash, Serialize, Deserialize, Default)]
 pub struct ActivityProperties {
     #[doc = "Gets or sets the user name of the activity."]
     #[serde(default, skip_serializing_if = "Option::is_none")]
@@ -159,10 +163,20 @@ pub struct ActivityProperties {
     )]
     pub output_types: Vec<ActivityOutputType>,
     #[doc = "Gets or sets the creation time."]
-    #[serde(rename = "creationTime", default, with = "azure_core::date::rfc3339::option")]
+    #[serde(
+        rename = "creationTime",
+        default,
+        with = "azure_core::date::iso8601::option",
+        skip_serializing_if = "Option::is_none"
+    )]
     pub creation_time: Option<time::OffsetDateTime>,
     #[doc = "Gets or sets the last modified time."]
-    #[serde(rename = "lastModifiedTime", default, with = "azure_core::date::rfc3339::option")]
+    #[serde(
+        rename = "lastModifiedTime",
+        default,
+        with = "azure_core::date::iso8601::option",
+        skip_serializing_if = "Option::is_none"
+    )]
     pub last_modified_time: Option<time::OffsetDateTime>,
     #[doc = "Gets or sets the description."]
     #[serde(default, skip_serializing_if = "Option::is_none")]
@@ -174,7 +188,7 @@ impl ActivityProperties {
     }
 }
 #[doc = "The properties of the create Advanced Schedule."]
-#[derive(Clone, Debug, PartialEq, Serialize, Deserialize, Default)]
+#[derive(Clone, Debug, PartialEq, Eq, Hash, Serialize, Deserialize, Default)]
 pub struct AdvancedSchedule {
     #[doc = "Days of the week that the job should execute on."]
     #[serde(
@@ -207,7 +221,7 @@ impl AdvancedSchedule {
     }
 }
 #[doc = "The properties of the create advanced schedule monthly occurrence."]
-#[derive(Clone, Debug, PartialEq, Serialize, Deserialize, Default)]
+#[derive(Clone, Debug, PartialEq, Eq, Hash, Serialize, Deserialize, Default)]
 pub struct AdvancedScheduleMonthlyOccurrence {
     #[doc = "Occurrence of the week within the month. Must be between 1 and 5"]
     #[serde(default, skip_serializing_if = "Option::is_none")]
@@ -224,7 +238,7 @@ impl AdvancedScheduleMonthlyOccurrence {
 pub mod advanced_schedule_monthly_occurrence {
     use super::*;
     #[doc = "Day of the occurrence. Must be one of monday, tuesday, wednesday, thursday, friday, saturday, sunday."]
-    #[derive(Clone, Debug, PartialEq, Serialize, Deserialize)]
+    #[derive(Clone, Debug, PartialEq, Eq, Hash, Serialize, Deserialize)]
     #[serde(remote = "Day")]
     pub enum Day {
         Monday,
@@ -270,9 +284,29 @@ pub mod advanced_schedule_monthly_occurrence {
             }
         }
     }
+    impl Day {
+        #[doc = "The value as it is sent on the wire."]
+        pub fn as_str(&self) -> &str {
+            match self {
+                Self::Monday => "Monday",
+                Self::Tuesday => "Tuesday",
+                Self::Wednesday => "Wednesday",
+                Self::Thursday => "Thursday",
+                Self::Friday => "Friday",
+                Self::Saturday => "Saturday",
+                Self::Sunday => "Sunday",
+                Self::UnknownValue(s) => s.as_str(),
+            }
+        }
+    }
+    impl std::fmt::Display for Day {
+        fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+            f.write_str(self.as_str())
+        }
+    }
 }
 #[doc = "Definition of the agent registration information type."]
-#[derive(Clone, Debug, PartialEq, Serialize, Deserialize, Default)]
+#[derive(Clone, Debug, PartialEq, Eq, Hash, Serialize, Deserialize, Default)]
 pub struct AgentRegistration {
     #[doc = "Gets or sets the dsc meta configuration."]
     #[serde(rename = "dscMetaConfiguration", default, skip_serializing_if = "Option::is_none")]
@@ -293,7 +327,7 @@ impl AgentRegistration {
     }
 }
 #[doc = "Definition of the agent registration keys."]
-#[derive(Clone, Debug, PartialEq, Serialize, Deserialize, Default)]
+#[derive(Clone, Debug, PartialEq, Eq, Hash, Serialize, Deserialize, Default)]
 pub struct AgentRegistrationKeys {
     #[doc = "Gets or sets the primary key."]
     #[serde(default, skip_serializing_if = "Option::is_none")]
@@ -308,7 +342,7 @@ impl AgentRegistrationKeys {
     }
 }
 #[doc = "The parameters supplied to the regenerate keys operation."]
-#[derive(Clone, Debug, PartialEq, Serialize, Deserialize)]
+#[derive(Clone, Debug, PartialEq, Eq, Hash, Serialize, Deserialize)]
 pub struct AgentRegistrationRegenerateKeyParameter {
     #[doc = "Gets or sets the agent registration key name - primary or secondary."]
     #[serde(rename = "keyName")]
@@ -322,7 +356,7 @@ impl AgentRegistrationRegenerateKeyParameter {
 pub mod agent_registration_regenerate_key_parameter {
     use super::*;
     #[doc = "Gets or sets the agent registration key name - primary or secondary."]
-    #[derive(Clone, Debug, PartialEq, Serialize, Deserialize)]
+    #[derive(Clone, Debug, PartialEq, Eq, Hash, Serialize, Deserialize)]
     #[serde(remote = "KeyName")]
     pub enum KeyName {
         #[serde(rename = "primary")]
@@ -360,6 +394,21 @@ pub mod agent_registration_regenerate_key_parameter {
             }
         }
     }
+    impl KeyName {
+        #[doc = "The value as it is sent on the wire."]
+        pub fn as_str(&self) -> &str {
+            match self {
+                Self::Primary => "primary",
+                Self::Secondary => "secondary",
+                Self::UnknownValue(s) => s.as_str(),
+            }
+        }
+    }
+    impl std::fmt::Display for KeyName {
+        fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+            f.write_str(self.as_str())
+        }
+    }
 }
 #[doc = "Definition of the automation account type."]
 #[derive(Clone, Debug, PartialEq, Serialize, Deserialize, Default)]
@@ -453,6 +502,10 @@ impl AutomationAccountListResult {
     pub fn new() -> Self {
         Self::default()
     }
+    #[doc = "The items in this page."]
+    pub fn items(&self) -> &[AutomationAccount] {
+        &self.value
+    }
 }
 #[doc = "Definition of the account property."]
 #[derive(Clone, Debug, PartialEq, Serialize, Deserialize, Default)]
@@ -467,10 +520,20 @@ pub struct AutomationAccountProperties {
     #[serde(default, skip_serializing_if = "Option::is_none")]
     pub state: Option<automation_account_properties::State>,
     #[doc = "Gets the creation time."]
-    #[serde(rename = "creationTime", default, with = "azure_core::date::rfc3339::option")]
+    #[serde(
+        rename = "creationTime",
+        default,
+        with = "azure_core::date::iso8601::option",
+        skip_serializing_if = "Option::is_none"
+    )]
     pub creation_time: Option<time::OffsetDateTime>,
     #[doc = "Gets the last modified time."]
-    #[serde(rename = "lastModifiedTime", default, with = "azure_core::date::rfc3339::option")]
+    #[serde(
+        rename = "lastModifiedTime",
+        default,
+        with = "azure_core::date::iso8601::option",
+        skip_serializing_if = "Option::is_none"
+    )]
     pub last_modified_time: Option<time::OffsetDateTime>,
     #[doc = "Gets or sets the description."]
     #[serde(default, skip_serializing_if = "Option::is_none")]
@@ -495,6 +558,12 @@ pub struct AutomationAccountProperties {
     #[doc = "URL of automation hybrid service which is used for hybrid worker on-boarding."]
     #[serde(rename = "automationHybridServiceUrl", default, skip_serializing_if = "Option::is_none")]
     pub automation_hybrid_service_url: Option<String>,
+    #[doc = "The provisioning state of the account. The service may leave it out once the account is ready."]
+    #[serde(rename = "provisioningState", default, skip_serializing_if = "Option::is_none")]
+    pub provisioning_state: Option<ProvisioningState>,
+    #[doc = "Fields of the response that this version of the crate has no field for, kept so that they are not lost when the model is sent back, such as in a read-modify-write update."]
+    #[serde(flatten, default, skip_serializing_if = "std::collections::HashMap::is_empty")]
+    pub extra: std::collections::HashMap<String, serde_json::Value>,
 }
 impl AutomationAccountProperties {
     pub fn new() -> Self {
@@ -504,7 +573,7 @@ impl AutomationAccountProperties {
 pub mod automation_account_properties {
     use super::*;
     #[doc = "Gets status of account."]
-    #[derive(Clone, Debug, PartialEq, Serialize, Deserialize)]
+    #[derive(Clone, Debug, PartialEq, Eq, Hash, Serialize, Deserialize)]
     #[serde(remote = "State")]
     pub enum State {
         Ok,
@@ -542,6 +611,22 @@ pub mod automation_account_properties {
             }
         }
     }
+    impl State {
+        #[doc = "The value as it is sent on the wire."]
+        pub fn as_str(&self) -> &str {
+            match self {
+                Self::Ok => "Ok",
+                Self::Unavailable => "Unavailable",
+                Self::Suspended => "Suspended",
+                Self::UnknownValue(s) => s.as_str(),
+            }
+        }
+    }
+    impl std::fmt::Display for State {
+        fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+            f.write_str(self.as_str())
+        }
+    }
 }
 #[doc = "The parameters supplied to the update automation account operation."]
 #[derive(Clone, Debug, PartialEq, Serialize, Deserialize, Default)]
@@ -615,7 +700,7 @@ impl AzureQueryProperties {
     }
 }
 #[doc = "Definition of the certificate."]
-#[derive(Clone, Debug, PartialEq, Serialize, Deserialize, Default)]
+#[derive(Clone, Debug, PartialEq, Eq, Hash, Serialize, Deserialize, Default)]
 pub struct Certificate {
     #[serde(flatten)]
     pub proxy_resource: ProxyResource,
@@ -629,7 +714,7 @@ impl Certificate {
     }
 }
 #[doc = "The parameters supplied to the create or update or replace certificate operation."]
-#[derive(Clone, Debug, PartialEq, Serialize, Deserialize)]
+#[derive(Clone, Debug, PartialEq, Eq, Hash, Serialize, Deserialize)]
 pub struct CertificateCreateOrUpdateParameters {
     #[doc = "Gets or sets the name of the certificate."]
     pub name: String,
@@ -642,7 +727,7 @@ impl CertificateCreateOrUpdateParameters {
     }
 }
 #[doc = "The properties of the create certificate operation."]
-#[derive(Clone, Debug, PartialEq, Serialize, Deserialize)]
+#[derive(Clone, Debug, PartialEq, Eq, Hash, Serialize, Deserialize)]
 pub struct CertificateCreateOrUpdateProperties {
     #[doc = "Gets or sets the base64 encoded value of the certificate."]
     #[serde(rename = "base64Value")]
@@ -668,7 +753,7 @@ impl CertificateCreateOrUpdateProperties {
     }
 }
 #[doc = "The response model for the list certificate operation."]
-#[derive(Clone, Debug, PartialEq, Serialize, Deserialize, Default)]
+#[derive(Clone, Debug, PartialEq, Eq, Hash, Serialize, Deserialize, Default)]
 pub struct CertificateListResult {
     #[doc = "Gets or sets a list of certificates."]
     #[serde(
@@ -691,24 +776,43 @@ impl CertificateListResult {
     pub fn new() -> Self {
         Self::default()
     }
+    #[doc = "The items in this page."]
+    pub fn items(&self) -> &[Certificate] {
+        &self.value
+    }
 }
 #[doc = "Properties of the certificate."]
-#[derive(Clone, Debug, PartialEq, Serialize, Deserialize, Default)]
+#[derive(Clone, Debug, PartialEq, Eq, Hash, Serialize, Deserialize, Default)]
 pub struct CertificateProperties {
     #[doc = "Gets the thumbprint of the certificate."]
     #[serde(default, skip_serializing_if = "Option::is_none")]
     pub thumbprint: Option<String>,
     #[doc = "Gets the expiry time of the certificate."]
-    #[serde(rename = "expiryTime", default, with = "azure_core::date::rfc3339::option")]
+    #[serde(
+        rename = "expiryTime",
+        default,
+        with = "azure_core::date::iso8601::option",
+        skip_serializing_if = "Option::is_none"
+    )]
     pub expiry_time: Option<time::OffsetDateTime>,
     #[doc = "Gets the is exportable flag of the certificate."]
     #[serde(rename = "isExportable", default, skip_serializing_if = "Option::is_none")]
     pub is_exportable: Option<bool>,
     #[doc = "Gets the creation time."]
-    #[serde(rename = "creationTime", default, with = "azure_core::date::rfc3339::option")]
+    #[serde(
+        rename = "creationTime",
+        default,
+        with = "azure_core::date::iso8601::option",
+        skip_serializing_if = "Option::is_none"
+    )]
     pub creation_time: Option<time::OffsetDateTime>,
     #[doc = "Gets the last modified time."]
-    #[serde(rename = "lastModifiedTime", default, with = "azure_core::date::rfc3339::option")]
+    #[serde(
+        rename = "lastModifiedTime",
+        default,
+        with = "azure_core::date::iso8601::option",
+        skip_serializing_if = "Option::is_none"
+    )]
     pub last_modified_time: Option<time::OffsetDateTime>,
     #[doc = "Gets or sets the description."]
     #[serde(default, skip_serializing_if = "Option::is_none")]
@@ -720,7 +824,7 @@ impl CertificateProperties {
     }
 }
 #[doc = "The parameters supplied to the update certificate operation."]
-#[derive(Clone, Debug, PartialEq, Serialize, Deserialize, Default)]
+#[derive(Clone, Debug, PartialEq, Eq, Hash, Serialize, Deserialize, Default)]
 pub struct CertificateUpdateParameters {
     #[doc = "Gets or sets the name of the certificate."]
     #[serde(default, skip_serializing_if = "Option::is_none")]
@@ -735,7 +839,7 @@ impl CertificateUpdateParameters {
     }
 }
 #[doc = "The properties of the update certificate operation"]
-#[derive(Clone, Debug, PartialEq, Serialize, Deserialize, Default)]
+#[derive(Clone, Debug, PartialEq, Eq, Hash, Serialize, Deserialize, Default)]
 pub struct CertificateUpdateProperties {
     #[doc = "Gets or sets the description of the certificate."]
     #[serde(default, skip_serializing_if = "Option::is_none")]
@@ -819,6 +923,10 @@ impl ConnectionListResult {
     pub fn new() -> Self {
         Self::default()
     }
+    #[doc = "The items in this page."]
+    pub fn items(&self) -> &[Connection] {
+        &self.value
+    }
 }
 #[doc = "Definition of the connection properties."]
 #[derive(Clone, Debug, PartialEq, Serialize, Deserialize, Default)]
@@ -830,10 +938,20 @@ pub struct ConnectionProperties {
     #[serde(rename = "fieldDefinitionValues", default, skip_serializing_if = "Option::is_none")]
     pub field_definition_values: Option<serde_json::Value>,
     #[doc = "Gets the creation time."]
-    #[serde(rename = "creationTime", default, with = "azure_core::date::rfc3339::option")]
+    #[serde(
+        rename = "creationTime",
+        default,
+        with = "azure_core::date::iso8601::option",
+        skip_serializing_if = "Option::is_none"
+    )]
     pub creation_time: Option<time::OffsetDateTime>,
     #[doc = "Gets the last modified time."]
-    #[serde(rename = "lastModifiedTime", default, with = "azure_core::date::rfc3339::option")]
+    #[serde(
+        rename = "lastModifiedTime",
+        default,
+        with = "azure_core::date::iso8601::option",
+        skip_serializing_if = "Option::is_none"
+    )]
     pub last_modified_time: Option<time::OffsetDateTime>,
     #[doc = "Gets or sets the description."]
     #[serde(default, skip_serializing_if = "Option::is_none")]
@@ -866,7 +984,7 @@ impl ConnectionType {
     }
 }
 #[doc = "The connection type property associated with the entity."]
-#[derive(Clone, Debug, PartialEq, Serialize, Deserialize, Default)]
+#[derive(Clone, Debug, PartialEq, Eq, Hash, Serialize, Deserialize, Default)]
 pub struct ConnectionTypeAssociationProperty {
     #[doc = "Gets or sets the name of the connection type."]
     #[serde(default, skip_serializing_if = "Option::is_none")]
@@ -932,6 +1050,10 @@ impl ConnectionTypeListResult {
     pub fn new() -> Self {
         Self::default()
     }
+    #[doc = "The items in this page."]
+    pub fn items(&self) -> &[ConnectionType] {
+        &self.value
+    }
 }
 #[doc = "Properties of the connection type."]
 #[derive(Clone, Debug, PartialEq, Serialize, Deserialize, Default)]
@@ -943,10 +1065,20 @@ pub struct ConnectionTypeProperties {
     #[serde(rename = "fieldDefinitions", default, skip_serializing_if = "Option::is_none")]
     pub field_definitions: Option<serde_json::Value>,
     #[doc = "Gets the creation time."]
-    #[serde(rename = "creationTime", default, with = "azure_core::date::rfc3339::option")]
+    #[serde(
+        rename = "creationTime",
+        default,
+        with = "azure_core::date::iso8601::option",
+        skip_serializing_if = "Option::is_none"
+    )]
     pub creation_time: Option<time::OffsetDateTime>,
     #[doc = "Gets or sets the last modified time."]
-    #[serde(rename = "lastModifiedTime", default, with = "azure_core::date::rfc3339::option")]
+    #[serde(
+        rename = "lastModifiedTime",
+        default,
+        with = "azure_core::date::iso8601::option",
+        skip_serializing_if = "Option::is_none"
+    )]
     pub last_modified_time: Option<time::OffsetDateTime>,
     #[doc = "Gets or sets the description."]
     #[serde(default, skip_serializing_if = "Option::is_none")]
@@ -988,7 +1120,7 @@ impl ConnectionUpdateProperties {
     }
 }
 #[doc = "Definition of the runbook property type."]
-#[derive(Clone, Debug, PartialEq, Serialize, Deserialize)]
+#[derive(Clone, Debug, PartialEq, Eq, Hash, Serialize, Deserialize)]
 pub struct ContentHash {
     #[doc = "Gets or sets the content hash algorithm used to hash the content."]
     pub algorithm: String,
@@ -1001,7 +1133,7 @@ impl ContentHash {
     }
 }
 #[doc = "Definition of the content link."]
-#[derive(Clone, Debug, PartialEq, Serialize, Deserialize, Default)]
+#[derive(Clone, Debug, PartialEq, Eq, Hash, Serialize, Deserialize, Default)]
 pub struct ContentLink {
     #[doc = "Gets or sets the uri of the runbook content."]
     #[serde(default, skip_serializing_if = "Option::is_none")]
@@ -1019,7 +1151,7 @@ impl ContentLink {
     }
 }
 #[doc = "Definition of the content source."]
-#[derive(Clone, Debug, PartialEq, Serialize, Deserialize, Default)]
+#[derive(Clone, Debug, PartialEq, Eq, Hash, Serialize, Deserialize, Default)]
 pub struct ContentSource {
     #[doc = "Definition of the runbook property type."]
     #[serde(default, skip_serializing_if = "Option::is_none")]
@@ -1042,7 +1174,7 @@ impl ContentSource {
 pub mod content_source {
     use super::*;
     #[doc = "Gets or sets the content source type."]
-    #[derive(Clone, Debug, PartialEq, Serialize, Deserialize)]
+    #[derive(Clone, Debug, PartialEq, Eq, Hash, Serialize, Deserialize)]
     #[serde(remote = "Type")]
     pub enum Type {
         #[serde(rename = "embeddedContent")]
@@ -1080,9 +1212,24 @@ pub mod content_source {
             }
         }
     }
+    impl Type {
+        #[doc = "The value as it is sent on the wire."]
+        pub fn as_str(&self) -> &str {
+            match self {
+                Self::EmbeddedContent => "embeddedContent",
+                Self::Uri => "uri",
+                Self::UnknownValue(s) => s.as_str(),
+            }
+        }
+    }
+    impl std::fmt::Display for Type {
+        fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+            f.write_str(self.as_str())
+        }
+    }
 }
 #[doc = "Definition of the credential."]
-#[derive(Clone, Debug, PartialEq, Serialize, Deserialize, Default)]
+#[derive(Clone, Debug, PartialEq, Eq, Hash, Serialize, Deserialize, Default)]
 pub struct Credential {
     #[serde(flatten)]
     pub proxy_resource: ProxyResource,
@@ -1096,7 +1243,7 @@ impl Credential {
     }
 }
 #[doc = "The parameters supplied to the create or update credential operation."]
-#[derive(Clone, Debug, PartialEq, Serialize, Deserialize)]
+#[derive(Clone, Debug, PartialEq, Eq, Hash, Serialize, Deserialize)]
 pub struct CredentialCreateOrUpdateParameters {
     #[doc = "Gets or sets the name of the credential."]
     pub name: String,
@@ -1109,7 +1256,7 @@ impl CredentialCreateOrUpdateParameters {
     }
 }
 #[doc = "The properties of the create credential operation."]
-#[derive(Clone, Debug, PartialEq, Serialize, Deserialize)]
+#[derive(Clone, Debug, PartialEq, Eq, Hash, Serialize, Deserialize)]
 pub struct CredentialCreateOrUpdateProperties {
     #[doc = "Gets or sets the user name of the credential."]
     #[serde(rename = "userName")]
@@ -1130,7 +1277,7 @@ impl CredentialCreateOrUpdateProperties {
     }
 }
 #[doc = "The response model for the list credential operation."]
-#[derive(Clone, Debug, PartialEq, Serialize, Deserialize, Default)]
+#[derive(Clone, Debug, PartialEq, Eq, Hash, Serialize, Deserialize, Default)]
 pub struct CredentialListResult {
     #[doc = "Gets or sets a list of credentials."]
     #[serde(
@@ -1153,18 +1300,32 @@ impl CredentialListResult {
     pub fn new() -> Self {
         Self::default()
     }
+    #[doc = "The items in this page."]
+    pub fn items(&self) -> &[Credential] {
+        &self.value
+    }
 }
 #[doc = "Definition of the credential properties"]
-#[derive(Clone, Debug, PartialEq, Serialize, Deserialize, Default)]
+#[derive(Clone, Debug, PartialEq, Eq, Hash, Serialize, Deserialize, Default)]
 pub struct CredentialProperties {
     #[doc = "Gets the user name of the credential."]
     #[serde(rename = "userName", default, skip_serializing_if = "Option::is_none")]
     pub user_name: Option<String>,
     #[doc = "Gets the creation time."]
-    #[serde(rename = "creationTime", default, with = "azure_core::date::rfc3339::option")]
+    #[serde(
+        rename = "creationTime",
+        default,
+        with = "azure_core::date::iso8601::option",
+        skip_serializing_if = "Option::is_none"
+    )]
     pub creation_time: Option<time::OffsetDateTime>,
     #[doc = "Gets the last modified time."]
-    #[serde(rename = "lastModifiedTime", default, with = "azure_core::date::rfc3339::option")]
+    #[serde(
+        rename = "lastModifiedTime",
+        default,
+        with = "azure_core::date::iso8601::option",
+        skip_serializing_if = "Option::is_none"
+    )]
     pub last_modified_time: Option<time::OffsetDateTime>,
     #[doc = "Gets or sets the description."]
     #[serde(default, skip_serializing_if = "Option::is_none")]
@@ -1176,7 +1337,7 @@ impl CredentialProperties {
     }
 }
 #[doc = "The parameters supplied to the Update credential operation."]
-#[derive(Clone, Debug, PartialEq, Serialize, Deserialize, Default)]
+#[derive(Clone, Debug, PartialEq, Eq, Hash, Serialize, Deserialize, Default)]
 pub struct CredentialUpdateParameters {
     #[doc = "Gets or sets the name of the credential."]
     #[serde(default, skip_serializing_if = "Option::is_none")]
@@ -1191,7 +1352,7 @@ impl CredentialUpdateParameters {
     }
 }
 #[doc = "The properties of the Update credential"]
-#[derive(Clone, Debug, PartialEq, Serialize, Deserialize, Default)]
+#[derive(Clone, Debug, PartialEq, Eq, Hash, Serialize, Deserialize, Default)]
 pub struct CredentialUpdateProperties {
     #[doc = "Gets or sets the user name of the credential."]
     #[serde(rename = "userName", default, skip_serializing_if = "Option::is_none")]
@@ -1209,7 +1370,7 @@ impl CredentialUpdateProperties {
     }
 }
 #[doc = "Definition of the deleted automation account type."]
-#[derive(Clone, Debug, PartialEq, Serialize, Deserialize, Default)]
+#[derive(Clone, Debug, PartialEq, Eq, Hash, Serialize, Deserialize, Default)]
 pub struct DeletedAutomationAccount {
     #[doc = "Definition of the deleted automation account properties."]
     #[serde(default, skip_serializing_if = "Option::is_none")]
@@ -1233,7 +1394,7 @@ impl DeletedAutomationAccount {
     }
 }
 #[doc = "The response model for the list deleted automation account."]
-#[derive(Clone, Debug, PartialEq, Serialize, Deserialize, Default)]
+#[derive(Clone, Debug, PartialEq, Eq, Hash, Serialize, Deserialize, Default)]
 pub struct DeletedAutomationAccountListResult {
     #[doc = "Gets or sets the list of deleted automation accounts."]
     #[serde(
@@ -1247,9 +1408,13 @@ impl DeletedAutomationAccountListResult {
     pub fn new() -> Self {
         Self::default()
     }
+    #[doc = "The items in this page."]
+    pub fn items(&self) -> &[DeletedAutomationAccount] {
+        &self.value
+    }
 }
 #[doc = "Definition of the deleted automation account properties."]
-#[derive(Clone, Debug, PartialEq, Serialize, Deserialize, Default)]
+#[derive(Clone, Debug, PartialEq, Eq, Hash, Serialize, Deserialize, Default)]
 pub struct DeletedAutomationAccountProperties {
     #[doc = "Gets or sets the Automation Account Resource Id."]
     #[serde(rename = "automationAccountResourceId", default, skip_serializing_if = "Option::is_none")]
@@ -1261,7 +1426,12 @@ pub struct DeletedAutomationAccountProperties {
     #[serde(default, skip_serializing_if = "Option::is_none")]
     pub location: Option<String>,
     #[doc = "Gets the deletion time."]
-    #[serde(rename = "deletionTime", default, with = "azure_core::date::rfc3339::option")]
+    #[serde(
+        rename = "deletionTime",
+        default,
+        with = "azure_core::date::iso8601::option",
+        skip_serializing_if = "Option::is_none"
+    )]
     pub deletion_time: Option<time::OffsetDateTime>,
 }
 impl DeletedAutomationAccountProperties {
@@ -1270,7 +1440,7 @@ impl DeletedAutomationAccountProperties {
     }
 }
 #[doc = "Dimension of the metric."]
-#[derive(Clone, Debug, PartialEq, Serialize, Deserialize, Default)]
+#[derive(Clone, Debug, PartialEq, Eq, Hash, Serialize, Deserialize, Default)]
 pub struct Dimension {
     #[doc = "The name of the dimension."]
     #[serde(default, skip_serializing_if = "Option::is_none")]
@@ -1368,6 +1538,10 @@ impl DscCompilationJobListResult {
     pub fn new() -> Self {
         Self::default()
     }
+    #[doc = "The items in this page."]
+    pub fn items(&self) -> &[DscCompilationJob] {
+        &self.value
+    }
 }
 #[doc = "Definition of Dsc Compilation job properties."]
 #[derive(Clone, Debug, PartialEq, Serialize, Deserialize, Default)]
@@ -1382,7 +1556,12 @@ pub struct DscCompilationJobProperties {
     #[serde(rename = "jobId", default, skip_serializing_if = "Option::is_none")]
     pub job_id: Option<String>,
     #[doc = "Gets the creation time of the job."]
-    #[serde(rename = "creationTime", default, with = "azure_core::date::rfc3339::option")]
+    #[serde(
+        rename = "creationTime",
+        default,
+        with = "azure_core::date::iso8601::option",
+        skip_serializing_if = "Option::is_none"
+    )]
     pub creation_time: Option<time::OffsetDateTime>,
     #[doc = "The provisioning state of the resource."]
     #[serde(rename = "provisioningState", default, skip_serializing_if = "Option::is_none")]
@@ -1397,23 +1576,46 @@ pub struct DscCompilationJobProperties {
     #[serde(rename = "statusDetails", default, skip_serializing_if = "Option::is_none")]
     pub status_details: Option<String>,
     #[doc = "Gets the start time of the job."]
-    #[serde(rename = "startTime", default, with = "azure_core::date::rfc3339::option")]
+    #[serde(
+        rename = "startTime",
+        default,
+        with = "azure_core::date::iso8601::option",
+        skip_serializing_if = "Option::is_none"
+    )]
     pub start_time: Option<time::OffsetDateTime>,
     #[doc = "Gets the end time of the job."]
-    #[serde(rename = "endTime", default, with = "azure_core::date::rfc3339::option")]
+    #[serde(
+        rename = "endTime",
+        default,
+        with = "azure_core::date::iso8601::option",
+        skip_serializing_if = "Option::is_none"
+    )]
     pub end_time: Option<time::OffsetDateTime>,
     #[doc = "Gets the exception of the job."]
     #[serde(default, skip_serializing_if = "Option::is_none")]
     pub exception: Option<String>,
     #[doc = "Gets the last modified time of the job."]
-    #[serde(rename = "lastModifiedTime", default, with = "azure_core::date::rfc3339::option")]
+    #[serde(
+        rename = "lastModifiedTime",
+        default,
+        with = "azure_core::date::iso8601::option",
+        skip_serializing_if = "Option::is_none"
+    )]
     pub last_modified_time: Option<time::OffsetDateTime>,
     #[doc = "Gets the last status modified time of the job."]
-    #[serde(rename = "lastStatusModifiedTime", default, with = "azure_core::date::rfc3339::option")]
+    #[serde(
+        rename = "lastStatusModifiedTime",
+        default,
+        with = "azure_core::date::iso8601::option",
+        skip_serializing_if = "Option::is_none"
+    )]
     pub last_status_modified_time: Option<time::OffsetDateTime>,
     #[doc = "Gets or sets the parameters of the job."]
     #[serde(default, skip_serializing_if = "Option::is_none")]
     pub parameters: Option<serde_json::Value>,
+    #[doc = "Fields of the response that this version of the crate has no field for, kept so that they are not lost when the model is sent back, such as in a read-modify-write update."]
+    #[serde(flatten, default, skip_serializing_if = "std::collections::HashMap::is_empty")]
+    pub extra: std::collections::HashMap<String, serde_json::Value>,
 }
 impl DscCompilationJobProperties {
     pub fn new() -> Self {
@@ -1423,7 +1625,7 @@ impl DscCompilationJobProperties {
 pub mod dsc_compilation_job_properties {
     use super::*;
     #[doc = "Gets or sets the status of the job."]
-    #[derive(Clone, Debug, PartialEq, Serialize, Deserialize)]
+    #[derive(Clone, Debug, PartialEq, Eq, Hash, Serialize, Deserialize)]
     #[serde(remote = "Status")]
     pub enum Status {
         New,
@@ -1481,6 +1683,32 @@ pub mod dsc_compilation_job_properties {
             }
         }
     }
+    impl Status {
+        #[doc = "The value as it is sent on the wire."]
+        pub fn as_str(&self) -> &str {
+            match self {
+                Self::New => "New",
+                Self::Activating => "Activating",
+                Self::Running => "Running",
+                Self::Completed => "Completed",
+                Self::Failed => "Failed",
+                Self::Stopped => "Stopped",
+                Self::Blocked => "Blocked",
+                Self::Suspended => "Suspended",
+                Self::Disconnected => "Disconnected",
+                Self::Suspending => "Suspending",
+                Self::Stopping => "Stopping",
+                Self::Resuming => "Resuming",
+                Self::Removing => "Removing",
+                Self::UnknownValue(s) => s.as_str(),
+            }
+        }
+    }
+    impl std::fmt::Display for Status {
+        fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+            f.write_str(self.as_str())
+        }
+    }
 }
 #[doc = "Definition of the configuration type."]
 #[derive(Clone, Debug, PartialEq, Serialize, Deserialize, Default)]
@@ -1500,7 +1728,7 @@ impl DscConfiguration {
     }
 }
 #[doc = "The Dsc configuration property associated with the entity."]
-#[derive(Clone, Debug, PartialEq, Serialize, Deserialize, Default)]
+#[derive(Clone, Debug, PartialEq, Eq, Hash, Serialize, Deserialize, Default)]
 pub struct DscConfigurationAssociationProperty {
     #[doc = "Gets or sets the name of the Dsc configuration."]
     #[serde(default, skip_serializing_if = "Option::is_none")]
@@ -1592,9 +1820,13 @@ impl DscConfigurationListResult {
     pub fn new() -> Self {
         Self::default()
     }
+    #[doc = "The items in this page."]
+    pub fn items(&self) -> &[DscConfiguration] {
+        &self.value
+    }
 }
 #[doc = "Definition of the configuration parameter type."]
-#[derive(Clone, Debug, PartialEq, Serialize, Deserialize, Default)]
+#[derive(Clone, Debug, PartialEq, Eq, Hash, Serialize, Deserialize, Default)]
 pub struct DscConfigurationParameter {
     #[doc = "Gets or sets the type of the parameter."]
     #[serde(rename = "type", default, skip_serializing_if = "Option::is_none")]
@@ -1636,10 +1868,20 @@ pub struct DscConfigurationProperties {
     #[serde(rename = "logVerbose", default, skip_serializing_if = "Option::is_none")]
     pub log_verbose: Option<bool>,
     #[doc = "Gets or sets the creation time."]
-    #[serde(rename = "creationTime", default, with = "azure_core::date::rfc3339::option")]
+    #[serde(
+        rename = "creationTime",
+        default,
+        with = "azure_core::date::iso8601::option",
+        skip_serializing_if = "Option::is_none"
+    )]
     pub creation_time: Option<time::OffsetDateTime>,
     #[doc = "Gets or sets the last modified time."]
-    #[serde(rename = "lastModifiedTime", default, with = "azure_core::date::rfc3339::option")]
+    #[serde(
+        rename = "lastModifiedTime",
+        default,
+        with = "azure_core::date::iso8601::option",
+        skip_serializing_if = "Option::is_none"
+    )]
     pub last_modified_time: Option<time::OffsetDateTime>,
     #[doc = "Gets the number of compiled node configurations."]
     #[serde(rename = "nodeConfigurationCount", default, skip_serializing_if = "Option::is_none")]
@@ -1647,6 +1889,9 @@ pub struct DscConfigurationProperties {
     #[doc = "Gets or sets the description."]
     #[serde(default, skip_serializing_if = "Option::is_none")]
     pub description: Option<String>,
+    #[doc = "Fields of the response that this version of the crate has no field for, kept so that they are not lost when the model is sent back, such as in a read-modify-write update."]
+    #[serde(flatten, default, skip_serializing_if = "std::collections::HashMap::is_empty")]
+    pub extra: std::collections::HashMap<String, serde_json::Value>,
 }
 impl DscConfigurationProperties {
     pub fn new() -> Self {
@@ -1656,12 +1901,12 @@ impl DscConfigurationProperties {
 pub mod dsc_configuration_properties {
     use super::*;
     #[doc = "Gets or sets the provisioning state of the configuration."]
-    #[derive(Clone, Debug, PartialEq, Serialize, Deserialize)]
+    #[derive(Clone, Debug, PartialEq, Eq, Hash, Serialize, Deserialize)]
     pub enum ProvisioningState {
         Succeeded,
     }
     #[doc = "Gets or sets the state of the configuration."]
-    #[derive(Clone, Debug, PartialEq, Serialize, Deserialize)]
+    #[derive(Clone, Debug, PartialEq, Eq, Hash, Serialize, Deserialize)]
     #[serde(remote = "State")]
     pub enum State {
         New,
@@ -1699,6 +1944,22 @@ pub mod dsc_configuration_properties {
             }
         }
     }
+    impl State {
+        #[doc = "The value as it is sent on the wire."]
+        pub fn as_str(&self) -> &str {
+            match self {
+                Self::New => "New",
+                Self::Edit => "Edit",
+                Self::Published => "Published",
+                Self::UnknownValue(s) => s.as_str(),
+            }
+        }
+    }
+    impl std::fmt::Display for State {
+        fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+            f.write_str(self.as_str())
+        }
+    }
 }
 #[doc = "The parameters supplied to the create or update configuration operation."]
 #[derive(Clone, Debug, PartialEq, Serialize, Deserialize, Default)]
@@ -1719,7 +1980,7 @@ impl DscConfigurationUpdateParameters {
     }
 }
 #[doc = "Definition of the DSC Meta Configuration."]
-#[derive(Clone, Debug, PartialEq, Serialize, Deserialize, Default)]
+#[derive(Clone, Debug, PartialEq, Eq, Hash, Serialize, Deserialize, Default)]
 pub struct DscMetaConfiguration {
     #[doc = "Gets or sets the ConfigurationModeFrequencyMins value of the meta configuration."]
     #[serde(rename = "configurationModeFrequencyMins", default, skip_serializing_if = "Option::is_none")]
@@ -1749,7 +2010,7 @@ impl DscMetaConfiguration {
     }
 }
 #[doc = "Definition of a DscNode"]
-#[derive(Clone, Debug, PartialEq, Serialize, Deserialize, Default)]
+#[derive(Clone, Debug, PartialEq, Eq, Hash, Serialize, Deserialize, Default)]
 pub struct DscNode {
     #[serde(flatten)]
     pub proxy_resource: ProxyResource,
@@ -1763,7 +2024,7 @@ impl DscNode {
     }
 }
 #[doc = "Definition of the dsc node configuration."]
-#[derive(Clone, Debug, PartialEq, Serialize, Deserialize, Default)]
+#[derive(Clone, Debug, PartialEq, Eq, Hash, Serialize, Deserialize, Default)]
 pub struct DscNodeConfiguration {
     #[serde(flatten)]
     pub proxy_resource: ProxyResource,
@@ -1777,7 +2038,7 @@ impl DscNodeConfiguration {
     }
 }
 #[doc = "The dsc node configuration property associated with the entity."]
-#[derive(Clone, Debug, PartialEq, Serialize, Deserialize, Default)]
+#[derive(Clone, Debug, PartialEq, Eq, Hash, Serialize, Deserialize, Default)]
 pub struct DscNodeConfigurationAssociationProperty {
     #[doc = "Gets or sets the name of the dsc node configuration."]
     #[serde(default, skip_serializing_if = "Option::is_none")]
@@ -1807,7 +2068,7 @@ impl DscNodeConfigurationCreateOrUpdateParameters {
     }
 }
 #[doc = "The parameter properties supplied to the create or update node configuration operation."]
-#[derive(Clone, Debug, PartialEq, Serialize, Deserialize)]
+#[derive(Clone, Debug, PartialEq, Eq, Hash, Serialize, Deserialize)]
 pub struct DscNodeConfigurationCreateOrUpdateParametersProperties {
     #[doc = "Definition of the content source."]
     pub source: ContentSource,
@@ -1827,7 +2088,7 @@ impl DscNodeConfigurationCreateOrUpdateParametersProperties {
     }
 }
 #[doc = "The response model for the list job operation."]
-#[derive(Clone, Debug, PartialEq, Serialize, Deserialize, Default)]
+#[derive(Clone, Debug, PartialEq, Eq, Hash, Serialize, Deserialize, Default)]
 pub struct DscNodeConfigurationListResult {
     #[doc = "Gets or sets a list of Dsc node configurations."]
     #[serde(
@@ -1853,15 +2114,29 @@ impl DscNodeConfigurationListResult {
     pub fn new() -> Self {
         Self::default()
     }
+    #[doc = "The items in this page."]
+    pub fn items(&self) -> &[DscNodeConfiguration] {
+        &self.value
+    }
 }
 #[doc = "Properties for the DscNodeConfiguration"]
-#[derive(Clone, Debug, PartialEq, Serialize, Deserialize, Default)]
+#[derive(Clone, Debug, PartialEq, Eq, Hash, Serialize, Deserialize, Default)]
 pub struct DscNodeConfigurationProperties {
     #[doc = "Gets or sets the last modified time."]
-    #[serde(rename = "lastModifiedTime", default, with = "azure_core::date::rfc3339::option")]
+    #[serde(
+        rename = "lastModifiedTime",
+        default,
+        with = "azure_core::date::iso8601::option",
+        skip_serializing_if = "Option::is_none"
+    )]
     pub last_modified_time: Option<time::OffsetDateTime>,
     #[doc = "Gets or sets creation time."]
-    #[serde(rename = "creationTime", default, with = "azure_core::date::rfc3339::option")]
+    #[serde(
+        rename = "creationTime",
+        default,
+        with = "azure_core::date::iso8601::option",
+        skip_serializing_if = "Option::is_none"
+    )]
     pub creation_time: Option<time::OffsetDateTime>,
     #[doc = "The Dsc configuration property associated with the entity."]
     #[serde(default, skip_serializing_if = "Option::is_none")]
@@ -1882,7 +2157,7 @@ impl DscNodeConfigurationProperties {
     }
 }
 #[doc = "The dsc extensionHandler property associated with the node"]
-#[derive(Clone, Debug, PartialEq, Serialize, Deserialize, Default)]
+#[derive(Clone, Debug, PartialEq, Eq, Hash, Serialize, Deserialize, Default)]
 pub struct DscNodeExtensionHandlerAssociationProperty {
     #[doc = "Gets or sets the name of the extension handler."]
     #[serde(default, skip_serializing_if = "Option::is_none")]
@@ -1897,7 +2172,7 @@ impl DscNodeExtensionHandlerAssociationProperty {
     }
 }
 #[doc = "The response model for the list dsc nodes operation."]
-#[derive(Clone, Debug, PartialEq, Serialize, Deserialize, Default)]
+#[derive(Clone, Debug, PartialEq, Eq, Hash, Serialize, Deserialize, Default)]
 pub struct DscNodeListResult {
     #[doc = "Gets or sets a list of dsc nodes."]
     #[serde(
@@ -1923,15 +2198,29 @@ impl DscNodeListResult {
     pub fn new() -> Self {
         Self::default()
     }
+    #[doc = "The items in this page."]
+    pub fn items(&self) -> &[DscNode] {
+        &self.value
+    }
 }
 #[doc = "The properties of a DscNode"]
-#[derive(Clone, Debug, PartialEq, Serialize, Deserialize, Default)]
+#[derive(Clone, Debug, PartialEq, Eq, Hash, Serialize, Deserialize, Default)]
 pub struct DscNodeProperties {
     #[doc = "Gets or sets the last seen time of the node."]
-    #[serde(rename = "lastSeen", default, with = "azure_core::date::rfc3339::option")]
+    #[serde(
+        rename = "lastSeen",
+        default,
+        with = "azure_core::date::iso8601::option",
+        skip_serializing_if = "Option::is_none"
+    )]
     pub last_seen: Option<time::OffsetDateTime>,
     #[doc = "Gets or sets the registration time of the node."]
-    #[serde(rename = "registrationTime", default, with = "azure_core::date::rfc3339::option")]
+    #[serde(
+        rename = "registrationTime",
+        default,
+        with = "azure_core::date::iso8601::option",
+        skip_serializing_if = "Option::is_none"
+    )]
     pub registration_time: Option<time::OffsetDateTime>,
     #[doc = "Gets or sets the ip of the node."]
     #[serde(default, skip_serializing_if = "Option::is_none")]
@@ -1972,13 +2261,28 @@ impl DscNodeProperties {
 #[derive(Clone, Debug, PartialEq, Serialize, Deserialize, Default)]
 pub struct DscNodeReport {
     #[doc = "Gets or sets the end time of the node report."]
-    #[serde(rename = "endTime", default, with = "azure_core::date::rfc3339::option")]
+    #[serde(
+        rename = "endTime",
+        default,
+        with = "azure_core::date::iso8601::option",
+        skip_serializing_if = "Option::is_none"
+    )]
     pub end_time: Option<time::OffsetDateTime>,
     #[doc = "Gets or sets the lastModifiedTime of the node report."]
-    #[serde(rename = "lastModifiedTime", default, with = "azure_core::date::rfc3339::option")]
+    #[serde(
+        rename = "lastModifiedTime",
+        default,
+        with = "azure_core::date::iso8601::option",
+        skip_serializing_if = "Option::is_none"
+    )]
     pub last_modified_time: Option<time::OffsetDateTime>,
     #[doc = "Gets or sets the start time of the node report."]
-    #[serde(rename = "startTime", default, with = "azure_core::date::rfc3339::option")]
+    #[serde(
+        rename = "startTime",
+        default,
+        with = "azure_core::date::iso8601::option",
+        skip_serializing_if = "Option::is_none"
+    )]
     pub start_time: Option<time::OffsetDateTime>,
     #[doc = "Gets or sets the type of the node report."]
     #[serde(rename = "type", default, skip_serializing_if = "Option::is_none")]
@@ -2046,6 +2350,9 @@ pub struct DscNodeReport {
     #[doc = "Gets or sets the unparsed errors for the node report."]
     #[serde(rename = "rawErrors", default, skip_serializing_if = "Option::is_none")]
     pub raw_errors: Option<String>,
+    #[doc = "Fields of the response that this version of the crate has no field for, kept so that they are not lost when the model is sent back, such as in a read-modify-write update."]
+    #[serde(flatten, default, skip_serializing_if = "std::collections::HashMap::is_empty")]
+    pub extra: std::collections::HashMap<String, serde_json::Value>,
 }
 impl DscNodeReport {
     pub fn new() -> Self {
@@ -2076,9 +2383,13 @@ impl DscNodeReportListResult {
     pub fn new() -> Self {
         Self::default()
     }
+    #[doc = "The items in this page."]
+    pub fn items(&self) -> &[DscNodeReport] {
+        &self.value
+    }
 }
 #[doc = "The parameters supplied to the update dsc node operation."]
-#[derive(Clone, Debug, PartialEq, Serialize, Deserialize, Default)]
+#[derive(Clone, Debug, PartialEq, Eq, Hash, Serialize, Deserialize, Default)]
 pub struct DscNodeUpdateParameters {
     #[doc = "Gets or sets the id of the dsc node."]
     #[serde(rename = "nodeId", default, skip_serializing_if = "Option::is_none")]
@@ -2093,7 +2404,7 @@ impl DscNodeUpdateParameters {
 }
 pub mod dsc_node_update_parameters {
     use super::*;
-    #[derive(Clone, Debug, PartialEq, Serialize, Deserialize, Default)]
+    #[derive(Clone, Debug, PartialEq, Eq, Hash, Serialize, Deserialize, Default)]
     pub struct Properties {
         #[doc = "The dsc node configuration property associated with the entity."]
         #[serde(rename = "nodeConfiguration", default, skip_serializing_if = "Option::is_none")]
@@ -2106,7 +2417,7 @@ pub mod dsc_node_update_parameters {
     }
 }
 #[doc = "Definition of the dsc node report error type."]
-#[derive(Clone, Debug, PartialEq, Serialize, Deserialize, Default)]
+#[derive(Clone, Debug, PartialEq, Eq, Hash, Serialize, Deserialize, Default)]
 pub struct DscReportError {
     #[doc = "Gets or sets the source of the error."]
     #[serde(rename = "errorSource", default, skip_serializing_if = "Option::is_none")]
@@ -2168,7 +2479,12 @@ pub struct DscReportResource {
     #[serde(rename = "durationInSeconds", default, skip_serializing_if = "Option::is_none")]
     pub duration_in_seconds: Option<f64>,
     #[doc = "Gets or sets the start date of the resource."]
-    #[serde(rename = "startDate", default, with = "azure_core::date::rfc3339::option")]
+    #[serde(
+        rename = "startDate",
+        default,
+        with = "azure_core::date::iso8601::option",
+        skip_serializing_if = "Option::is_none"
+    )]
     pub start_date: Option<time::OffsetDateTime>,
 }
 impl DscReportResource {
@@ -2177,7 +2493,7 @@ impl DscReportResource {
     }
 }
 #[doc = "Navigation for DSC Report Resource."]
-#[derive(Clone, Debug, PartialEq, Serialize, Deserialize, Default)]
+#[derive(Clone, Debug, PartialEq, Eq, Hash, Serialize, Deserialize, Default)]
 pub struct DscReportResourceNavigation {
     #[doc = "Gets or sets the ID of the resource to navigate to."]
     #[serde(rename = "resourceId", default, skip_serializing_if = "Option::is_none")]
@@ -2209,7 +2525,7 @@ impl EncryptionProperties {
 pub mod encryption_properties {
     use super::*;
     #[doc = "Encryption Key Source"]
-    #[derive(Clone, Debug, PartialEq, Serialize, Deserialize)]
+    #[derive(Clone, Debug, PartialEq, Eq, Hash, Serialize, Deserialize)]
     pub enum KeySource {
         #[serde(rename = "Microsoft.Automation")]
         MicrosoftAutomation,
@@ -2230,7 +2546,7 @@ pub mod encryption_properties {
     }
 }
 #[doc = "Error response of an operation failure"]
-#[derive(Clone, Debug, PartialEq, Serialize, Deserialize, Default)]
+#[derive(Clone, Debug, PartialEq, Eq, Hash, Serialize, Deserialize, Default)]
 pub struct ErrorResponse {
     #[doc = "Error code"]
     #[serde(default, skip_serializing_if = "Option::is_none")]
@@ -2251,7 +2567,7 @@ impl ErrorResponse {
     }
 }
 #[doc = "Definition of the connection fields."]
-#[derive(Clone, Debug, PartialEq, Serialize, Deserialize)]
+#[derive(Clone, Debug, PartialEq, Eq, Hash, Serialize, Deserialize)]
 pub struct FieldDefinition {
     #[doc = "Gets or sets the isEncrypted flag of the connection field definition."]
     #[serde(rename = "isEncrypted", default, skip_serializing_if = "Option::is_none")]
@@ -2273,7 +2589,7 @@ impl FieldDefinition {
     }
 }
 #[doc = "Graphical Runbook Content"]
-#[derive(Clone, Debug, PartialEq, Serialize, Deserialize, Default)]
+#[derive(Clone, Debug, PartialEq, Eq, Hash, Serialize, Deserialize, Default)]
 pub struct GraphicalRunbookContent {
     #[doc = "Raw Graphical Runbook content"]
     #[serde(rename = "rawContent", default, skip_serializing_if = "Option::is_none")]
@@ -2289,7 +2605,7 @@ impl GraphicalRunbookContent {
 }
 pub type GroupIdsProperty = Vec<String>;
 #[doc = "Definition of hybrid runbook worker."]
-#[derive(Clone, Debug, PartialEq, Serialize, Deserialize, Default)]
+#[derive(Clone, Debug, PartialEq, Eq, Hash, Serialize, Deserialize, Default)]
 pub struct HybridRunbookWorker {
     #[serde(flatten)]
     pub resource: Resource,
@@ -2306,7 +2622,7 @@ impl HybridRunbookWorker {
     }
 }
 #[doc = "The parameters supplied to the create or update hybrid runbook worker operation."]
-#[derive(Clone, Debug, PartialEq, Serialize, Deserialize, Default)]
+#[derive(Clone, Debug, PartialEq, Eq, Hash, Serialize, Deserialize, Default)]
 pub struct HybridRunbookWorkerCreateOrUpdateParameters {
     #[doc = "Azure Resource Manager Id for a virtual machine."]
     #[serde(rename = "vmResourceId", default, skip_serializing_if = "Option::is_none")]
@@ -2318,7 +2634,7 @@ impl HybridRunbookWorkerCreateOrUpdateParameters {
     }
 }
 #[doc = "The parameters supplied to the create hybrid runbook worker operation."]
-#[derive(Clone, Debug, PartialEq, Serialize, Deserialize)]
+#[derive(Clone, Debug, PartialEq, Eq, Hash, Serialize, Deserialize)]
 pub struct HybridRunbookWorkerCreateParameters {
     #[doc = "The parameters supplied to the create or update hybrid runbook worker operation."]
     pub properties: HybridRunbookWorkerCreateOrUpdateParameters,
@@ -2332,7 +2648,7 @@ impl HybridRunbookWorkerCreateParameters {
     }
 }
 #[doc = "Definition of hybrid runbook worker group."]
-#[derive(Clone, Debug, PartialEq, Serialize, Deserialize, Default)]
+#[derive(Clone, Debug, PartialEq, Eq, Hash, Serialize, Deserialize, Default)]
 pub struct HybridRunbookWorkerGroup {
     #[serde(flatten)]
     pub resource: Resource,
@@ -2349,7 +2665,7 @@ impl HybridRunbookWorkerGroup {
     }
 }
 #[doc = "The parameters supplied to the create hybrid runbook worker group operation."]
-#[derive(Clone, Debug, PartialEq, Serialize, Deserialize, Default)]
+#[derive(Clone, Debug, PartialEq, Eq, Hash, Serialize, Deserialize, Default)]
 pub struct HybridRunbookWorkerGroupCreateOrUpdateParameters {
     #[doc = "The hybrid runbook worker group properties."]
     #[serde(default, skip_serializing_if = "Option::is_none")]
@@ -2364,7 +2680,7 @@ impl HybridRunbookWorkerGroupCreateOrUpdateParameters {
     }
 }
 #[doc = "The hybrid runbook worker group properties."]
-#[derive(Clone, Debug, PartialEq, Serialize, Deserialize, Default)]
+#[derive(Clone, Debug, PartialEq, Eq, Hash, Serialize, Deserialize, Default)]
 pub struct HybridRunbookWorkerGroupCreateOrUpdateProperties {
     #[doc = "Definition of RunAs credential to use for hybrid worker."]
     #[serde(default, skip_serializing_if = "Option::is_none")]
@@ -2376,7 +2692,7 @@ impl HybridRunbookWorkerGroupCreateOrUpdateProperties {
     }
 }
 #[doc = "Definition of hybrid runbook worker group property."]
-#[derive(Clone, Debug, PartialEq, Serialize, Deserialize, Default)]
+#[derive(Clone, Debug, PartialEq, Eq, Hash, Serialize, Deserialize, Default)]
 pub struct HybridRunbookWorkerGroupProperties {
     #[doc = "Type of the HybridWorkerGroup."]
     #[serde(rename = "groupType", default, skip_serializing_if = "Option::is_none")]
@@ -2393,7 +2709,7 @@ impl HybridRunbookWorkerGroupProperties {
 pub mod hybrid_runbook_worker_group_properties {
     use super::*;
     #[doc = "Type of the HybridWorkerGroup."]
-    #[derive(Clone, Debug, PartialEq, Serialize, Deserialize)]
+    #[derive(Clone, Debug, PartialEq, Eq, Hash, Serialize, Deserialize)]
     #[serde(remote = "GroupType")]
     pub enum GroupType {
         User,
@@ -2429,9 +2745,24 @@ pub mod hybrid_runbook_worker_group_properties {
             }
         }
     }
+    impl GroupType {
+        #[doc = "The value as it is sent on the wire."]
+        pub fn as_str(&self) -> &str {
+            match self {
+                Self::User => "User",
+                Self::System => "System",
+                Self::UnknownValue(s) => s.as_str(),
+            }
+        }
+    }
+    impl std::fmt::Display for GroupType {
+        fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+            f.write_str(self.as_str())
+        }
+    }
 }
 #[doc = "The response model for the list hybrid runbook worker groups."]
-#[derive(Clone, Debug, PartialEq, Serialize, Deserialize, Default)]
+#[derive(Clone, Debug, PartialEq, Eq, Hash, Serialize, Deserialize, Default)]
 pub struct HybridRunbookWorkerGroupsListResult {
     #[doc = "Gets or sets a list of hybrid runbook worker groups."]
     #[serde(
@@ -2454,9 +2785,13 @@ impl HybridRunbookWorkerGroupsListResult {
     pub fn new() -> Self {
         Self::default()
     }
+    #[doc = "The items in this page."]
+    pub fn items(&self) -> &[HybridRunbookWorkerGroup] {
+        &self.value
+    }
 }
 #[doc = "Parameters supplied to move hybrid worker operation."]
-#[derive(Clone, Debug, PartialEq, Serialize, Deserialize, Default)]
+#[derive(Clone, Debug, PartialEq, Eq, Hash, Serialize, Deserialize, Default)]
 pub struct HybridRunbookWorkerMoveParameters {
     #[doc = "Gets or sets the target hybrid runbook worker group."]
     #[serde(rename = "hybridRunbookWorkerGroupName", default, skip_serializing_if = "Option::is_none")]
@@ -2468,16 +2803,26 @@ impl HybridRunbookWorkerMoveParameters {
     }
 }
 #[doc = "Definition of hybrid runbook worker property."]
-#[derive(Clone, Debug, PartialEq, Serialize, Deserialize, Default)]
+#[derive(Clone, Debug, PartialEq, Eq, Hash, Serialize, Deserialize, Default)]
 pub struct HybridRunbookWorkerProperties {
     #[doc = "Gets or sets the assigned machine IP address."]
     #[serde(default, skip_serializing_if = "Option::is_none")]
     pub ip: Option<String>,
     #[doc = "Gets or sets the registration time of the worker machine."]
-    #[serde(rename = "registeredDateTime", default, with = "azure_core::date::rfc3339::option")]
+    #[serde(
+        rename = "registeredDateTime",
+        default,
+        with = "azure_core::date::iso8601::option",
+        skip_serializing_if = "Option::is_none"
+    )]
     pub registered_date_time: Option<time::OffsetDateTime>,
     #[doc = "Last Heartbeat from the Worker"]
-    #[serde(rename = "lastSeenDateTime", default, with = "azure_core::date::rfc3339::option")]
+    #[serde(
+        rename = "lastSeenDateTime",
+        default,
+        with = "azure_core::date::iso8601::option",
+        skip_serializing_if = "Option::is_none"
+    )]
     pub last_seen_date_time: Option<time::OffsetDateTime>,
     #[doc = "Azure Resource Manager Id for a virtual machine."]
     #[serde(rename = "vmResourceId", default, skip_serializing_if = "Option::is_none")]
@@ -2497,7 +2842,7 @@ impl HybridRunbookWorkerProperties {
 pub mod hybrid_runbook_worker_properties {
     use super::*;
     #[doc = "Type of the HybridWorker."]
-    #[derive(Clone, Debug, PartialEq, Serialize, Deserialize)]
+    #[derive(Clone, Debug, PartialEq, Eq, Hash, Serialize, Deserialize)]
     #[serde(remote = "WorkerType")]
     pub enum WorkerType {
         HybridV1,
@@ -2533,9 +2878,24 @@ pub mod hybrid_runbook_worker_properties {
             }
         }
     }
+    impl WorkerType {
+        #[doc = "The value as it is sent on the wire."]
+        pub fn as_str(&self) -> &str {
+            match self {
+                Self::HybridV1 => "HybridV1",
+                Self::HybridV2 => "HybridV2",
+                Self::UnknownValue(s) => s.as_str(),
+            }
+        }
+    }
+    impl std::fmt::Display for WorkerType {
+        fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+            f.write_str(self.as_str())
+        }
+    }
 }
 #[doc = "The response model for the list hybrid runbook workers."]
-#[derive(Clone, Debug, PartialEq, Serialize, Deserialize, Default)]
+#[derive(Clone, Debug, PartialEq, Eq, Hash, Serialize, Deserialize, Default)]
 pub struct HybridRunbookWorkersListResult {
     #[doc = "Gets or sets a list of hybrid runbook workers."]
     #[serde(
@@ -2558,6 +2918,10 @@ impl HybridRunbookWorkersListResult {
     pub fn new() -> Self {
         Self::default()
     }
+    #[doc = "The items in this page."]
+    pub fn items(&self) -> &[HybridRunbookWorker] {
+        &self.value
+    }
 }
 #[doc = "Identity for the resource."]
 #[derive(Clone, Debug, PartialEq, Serialize, Deserialize, Default)]
@@ -2583,13 +2947,63 @@ impl Identity {
 pub mod identity {
     use super::*;
     #[doc = "The identity type."]
-    #[derive(Clone, Debug, PartialEq, Serialize, Deserialize)]
+    #[derive(Clone, Debug, PartialEq, Eq, Hash, Serialize, Deserialize)]
+    #[serde(remote = "Type")]
     pub enum Type {
         SystemAssigned,
         UserAssigned,
         #[serde(rename = "SystemAssigned, UserAssigned")]
         SystemAssignedUserAssigned,
         None,
+        #[serde(skip_deserializing)]
+        UnknownValue(String),
+    }
+    impl FromStr for Type {
+        type Err = value::Error;
+        fn from_str(s: &str) -> std::result::Result<Self, Self::Err> {
+            Self::deserialize(s.into_deserializer())
+        }
+    }
+    impl<'de> Deserialize<'de> for Type {
+        fn deserialize<D>(deserializer: D) -> std::result::Result<Self, D::Error>
+        where
+            D: Deserializer<'de>,
+        {
+            let s = String::deserialize(deserializer)?;
+            let deserialized = Self::from_str(&s).unwrap_or(Self::UnknownValue(s));
+            Ok(deserialized)
+        }
+    }
+    impl Serialize for Type {
+        fn serialize<S>(&self, serializer: S) -> std::result::Result<S::Ok, S::Error>
+        where
+            S: Serializer,
+        {
+            match self {
+                Self::SystemAssigned => serializer.serialize_unit_variant("Type", 0u32, "SystemAssigned"),
+                Self::UserAssigned => serializer.serialize_unit_variant("Type", 1u32, "UserAssigned"),
+                Self::SystemAssignedUserAssigned => serializer.serialize_unit_variant("Type", 2u32, "SystemAssigned, UserAssigned"),
+                Self::None => serializer.serialize_unit_variant("Type", 3u32, "None"),
+                Self::UnknownValue(s) => serializer.serialize_str(s.as_str()),
+            }
+        }
+    }
+    impl Type {
+        #[doc = "The value as it is sent on the wire."]
+        pub fn as_str(&self) -> &str {
+            match self {
+                Self::SystemAssigned => "SystemAssigned",
+                Self::UserAssigned => "UserAssigned",
+                Self::SystemAssignedUserAssigned => "SystemAssigned, UserAssigned",
+                Self::None => "None",
+                Self::UnknownValue(s) => s.as_str(),
+            }
+        }
+    }
+    impl std::fmt::Display for Type {
+        fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+            f.write_str(self.as_str())
+        }
     }
 }
 #[doc = "Definition of the job."]
@@ -2607,7 +3021,7 @@ impl Job {
     }
 }
 #[doc = "Job collection item properties."]
-#[derive(Clone, Debug, PartialEq, Serialize, Deserialize)]
+#[derive(Clone, Debug, PartialEq, Eq, Hash, Serialize, Deserialize)]
 pub struct JobCollectionItem {
     #[serde(flatten)]
     pub proxy_resource: ProxyResource,
@@ -2623,7 +3037,7 @@ impl JobCollectionItem {
     }
 }
 #[doc = "Job collection item properties."]
-#[derive(Clone, Debug, PartialEq, Serialize, Deserialize, Default)]
+#[derive(Clone, Debug, PartialEq, Eq, Hash, Serialize, Deserialize, Default)]
 pub struct JobCollectionItemProperties {
     #[doc = "The runbook property associated with the entity."]
     #[serde(default, skip_serializing_if = "Option::is_none")]
@@ -2632,23 +3046,43 @@ pub struct JobCollectionItemProperties {
     #[serde(rename = "jobId", default, skip_serializing_if = "Option::is_none")]
     pub job_id: Option<String>,
     #[doc = "The creation time of the job."]
-    #[serde(rename = "creationTime", default, with = "azure_core::date::rfc3339::option")]
+    #[serde(
+        rename = "creationTime",
+        default,
+        with = "azure_core::date::iso8601::option",
+        skip_serializing_if = "Option::is_none"
+    )]
     pub creation_time: Option<time::OffsetDateTime>,
     #[doc = "The status of the job."]
     #[serde(default, skip_serializing_if = "Option::is_none")]
     pub status: Option<job_collection_item_properties::Status>,
     #[doc = "The start time of the job."]
-    #[serde(rename = "startTime", default, with = "azure_core::date::rfc3339::option")]
+    #[serde(
+        rename = "startTime",
+        default,
+        with = "azure_core::date::iso8601::option",
+        skip_serializing_if = "Option::is_none"
+    )]
     pub start_time: Option<time::OffsetDateTime>,
     #[doc = "The end time of the job."]
-    #[serde(rename = "endTime", default, with = "azure_core::date::rfc3339::option")]
+    #[serde(
+        rename = "endTime",
+        default,
+        with = "azure_core::date::iso8601::option",
+        skip_serializing_if = "Option::is_none"
+    )]
     pub end_time: Option<time::OffsetDateTime>,
     #[doc = "The last modified time of the job."]
-    #[serde(rename = "lastModifiedTime", default, with = "azure_core::date::rfc3339::option")]
+    #[serde(
+        rename = "lastModifiedTime",
+        default,
+        with = "azure_core::date::iso8601::option",
+        skip_serializing_if = "Option::is_none"
+    )]
     pub last_modified_time: Option<time::OffsetDateTime>,
     #[doc = "The provisioning state of a resource."]
     #[serde(rename = "provisioningState", default, skip_serializing_if = "Option::is_none")]
-    pub provisioning_state: Option<String>,
+    pub provisioning_state: Option<ProvisioningState>,
     #[doc = "Specifies the runOn group name where the job was executed."]
     #[serde(rename = "runOn", default, skip_serializing_if = "Option::is_none")]
     pub run_on: Option<String>,
@@ -2661,7 +3095,7 @@ impl JobCollectionItemProperties {
 pub mod job_collection_item_properties {
     use super::*;
     #[doc = "The status of the job."]
-    #[derive(Clone, Debug, PartialEq, Serialize, Deserialize)]
+    #[derive(Clone, Debug, PartialEq, Eq, Hash, Serialize, Deserialize)]
     #[serde(remote = "Status")]
     pub enum Status {
         New,
@@ -2719,6 +3153,32 @@ pub mod job_collection_item_properties {
             }
         }
     }
+    impl Status {
+        #[doc = "The value as it is sent on the wire."]
+        pub fn as_str(&self) -> &str {
+            match self {
+                Self::New => "New",
+                Self::Activating => "Activating",
+                Self::Running => "Running",
+                Self::Completed => "Completed",
+                Self::Failed => "Failed",
+                Self::Stopped => "Stopped",
+                Self::Blocked => "Blocked",
+                Self::Suspended => "Suspended",
+                Self::Disconnected => "Disconnected",
+                Self::Suspending => "Suspending",
+                Self::Stopping => "Stopping",
+                Self::Resuming => "Resuming",
+                Self::Removing => "Removing",
+                Self::UnknownValue(s) => s.as_str(),
+            }
+        }
+    }
+    impl std::fmt::Display for Status {
+        fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+            f.write_str(self.as_str())
+        }
+    }
 }
 #[doc = "The parameters supplied to the create job operation."]
 #[derive(Clone, Debug, PartialEq, Serialize, Deserialize)]
@@ -2748,7 +3208,7 @@ impl JobCreateProperties {
     }
 }
 #[doc = "The response model for the list job operation."]
-#[derive(Clone, Debug, PartialEq, Serialize, Deserialize, Default)]
+#[derive(Clone, Debug, PartialEq, Eq, Hash, Serialize, Deserialize, Default)]
 pub struct JobListResultV2 {
     #[doc = "List of jobs."]
     #[serde(
@@ -2771,6 +3231,22 @@ impl JobListResultV2 {
     pub fn new() -> Self {
         Self::default()
     }
+    #[doc = "The items in this page."]
+    pub fn items(&self) -> &[JobCollectionItem] {
+        &self.value
+    }
+}
+#[doc = "Software update configuration machine run job navigation properties."]
+#[derive(Clone, Debug, PartialEq, Eq, Hash, Serialize, Deserialize, Default)]
+pub struct JobNavigation {
+    #[doc = "Id of the job associated with the software update configuration run"]
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub id: Option<String>,
+}
+impl JobNavigation {
+    pub fn new() -> Self {
+        Self::default()
+    }
 }
 #[doc = "Definition of job properties."]
 #[derive(Clone, Debug, PartialEq, Serialize, Deserialize, Default)]
@@ -2788,7 +3264,12 @@ pub struct JobProperties {
     #[serde(rename = "jobId", default, skip_serializing_if = "Option::is_none")]
     pub job_id: Option<String>,
     #[doc = "Gets or sets the creation time of the job."]
-    #[serde(rename = "creationTime", default, with = "azure_core::date::rfc3339::option")]
+    #[serde(
+        rename = "creationTime",
+        default,
+        with = "azure_core::date::iso8601::option",
+        skip_serializing_if = "Option::is_none"
+    )]
     pub creation_time: Option<time::OffsetDateTime>,
     #[doc = "Gets or sets the status of the job."]
     #[serde(default, skip_serializing_if = "Option::is_none")]
@@ -2797,19 +3278,39 @@ pub struct JobProperties {
     #[serde(rename = "statusDetails", default, skip_serializing_if = "Option::is_none")]
     pub status_details: Option<String>,
     #[doc = "Gets or sets the start time of the job."]
-    #[serde(rename = "startTime", default, with = "azure_core::date::rfc3339::option")]
+    #[serde(
+        rename = "startTime",
+        default,
+        with = "azure_core::date::iso8601::option",
+        skip_serializing_if = "Option::is_none"
+    )]
     pub start_time: Option<time::OffsetDateTime>,
     #[doc = "Gets or sets the end time of the job."]
-    #[serde(rename = "endTime", default, with = "azure_core::date::rfc3339::option")]
+    #[serde(
+        rename = "endTime",
+        default,
+        with = "azure_core::date::iso8601::option",
+        skip_serializing_if = "Option::is_none"
+    )]
     pub end_time: Option<time::OffsetDateTime>,
     #[doc = "Gets or sets the exception of the job."]
     #[serde(default, skip_serializing_if = "Option::is_none")]
     pub exception: Option<String>,
     #[doc = "Gets or sets the last modified time of the job."]
-    #[serde(rename = "lastModifiedTime", default, with = "azure_core::date::rfc3339::option")]
+    #[serde(
+        rename = "lastModifiedTime",
+        default,
+        with = "azure_core::date::iso8601::option",
+        skip_serializing_if = "Option::is_none"
+    )]
     pub last_modified_time: Option<time::OffsetDateTime>,
     #[doc = "Gets or sets the last status modified time of the job."]
-    #[serde(rename = "lastStatusModifiedTime", default, with = "azure_core::date::rfc3339::option")]
+    #[serde(
+        rename = "lastStatusModifiedTime",
+        default,
+        with = "azure_core::date::iso8601::option",
+        skip_serializing_if = "Option::is_none"
+    )]
     pub last_status_modified_time: Option<time::OffsetDateTime>,
     #[doc = "Gets or sets the parameters of the job."]
     #[serde(default, skip_serializing_if = "Option::is_none")]
@@ -2817,6 +3318,9 @@ pub struct JobProperties {
     #[doc = "The provisioning state of the resource."]
     #[serde(rename = "provisioningState", default, skip_serializing_if = "Option::is_none")]
     pub provisioning_state: Option<JobProvisioningStateProperty>,
+    #[doc = "Fields of the response that this version of the crate has no field for, kept so that they are not lost when the model is sent back, such as in a read-modify-write update."]
+    #[serde(flatten, default, skip_serializing_if = "std::collections::HashMap::is_empty")]
+    pub extra: std::collections::HashMap<String, serde_json::Value>,
 }
 impl JobProperties {
     pub fn new() -> Self {
@@ -2826,7 +3330,7 @@ impl JobProperties {
 pub mod job_properties {
     use super::*;
     #[doc = "Gets or sets the status of the job."]
-    #[derive(Clone, Debug, PartialEq, Serialize, Deserialize)]
+    #[derive(Clone, Debug, PartialEq, Eq, Hash, Serialize, Deserialize)]
     #[serde(remote = "Status")]
     pub enum Status {
         New,
@@ -2884,9 +3388,35 @@ pub mod job_properties {
             }
         }
     }
+    impl Status {
+        #[doc = "The value as it is sent on the wire."]
+        pub fn as_str(&self) -> &str {
+            match self {
+                Self::New => "New",
+                Self::Activating => "Activating",
+                Self::Running => "Running",
+                Self::Completed => "Completed",
+                Self::Failed => "Failed",
+                Self::Stopped => "Stopped",
+                Self::Blocked => "Blocked",
+                Self::Suspended => "Suspended",
+                Self::Disconnected => "Disconnected",
+                Self::Suspending => "Suspending",
+                Self::Stopping => "Stopping",
+                Self::Resuming => "Resuming",
+                Self::Removing => "Removing",
+                Self::UnknownValue(s) => s.as_str(),
+            }
+        }
+    }
+    impl std::fmt::Display for Status {
+        fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+            f.write_str(self.as_str())
+        }
+    }
 }
 #[doc = "The provisioning state of the resource."]
-#[derive(Clone, Debug, PartialEq, Serialize, Deserialize)]
+#[derive(Clone, Debug, PartialEq, Eq, Hash, Serialize, Deserialize)]
 #[serde(remote = "JobProvisioningStateProperty")]
 pub enum JobProvisioningStateProperty {
     Failed,
@@ -2926,6 +3456,23 @@ impl Serialize for JobProvisioningStateProperty {
         }
     }
 }
+impl JobProvisioningStateProperty {
+    #[doc = "The value as it is sent on the wire."]
+    pub fn as_str(&self) -> &str {
+        match self {
+            Self::Failed => "Failed",
+            Self::Succeeded => "Succeeded",
+            Self::Suspended => "Suspended",
+            Self::Processing => "Processing",
+            Self::UnknownValue(s) => s.as_str(),
+        }
+    }
+}
+impl std::fmt::Display for JobProvisioningStateProperty {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.write_str(self.as_str())
+    }
+}
 #[doc = "Definition of the job schedule."]
 #[derive(Clone, Debug, PartialEq, Serialize, Deserialize, Default)]
 pub struct JobSchedule {
@@ -3006,6 +3553,10 @@ impl JobScheduleListResult {
     pub fn new() -> Self {
         Self::default()
     }
+    #[doc = "The items in this page."]
+    pub fn items(&self) -> &[JobSchedule] {
+        &self.value
+    }
 }
 #[doc = "Definition of job schedule parameters."]
 #[derive(Clone, Debug, PartialEq, Serialize, Deserialize, Default)]
@@ -3070,6 +3621,10 @@ impl JobStreamListResult {
     pub fn new() -> Self {
         Self::default()
     }
+    #[doc = "The items in this page."]
+    pub fn items(&self) -> &[JobStream] {
+        &self.value
+    }
 }
 #[doc = "Definition of the job stream."]
 #[derive(Clone, Debug, PartialEq, Serialize, Deserialize, Default)]
@@ -3078,7 +3633,7 @@ pub struct JobStreamProperties {
     #[serde(rename = "jobStreamId", default, skip_serializing_if = "Option::is_none")]
     pub job_stream_id: Option<String>,
     #[doc = "Gets or sets the creation time of the job."]
-    #[serde(default, with = "azure_core::date::rfc3339::option")]
+    #[serde(default, with = "azure_core::date::iso8601::option", skip_serializing_if = "Option::is_none")]
     pub time: Option<time::OffsetDateTime>,
     #[doc = "Gets or sets the stream type."]
     #[serde(rename = "streamType", default, skip_serializing_if = "Option::is_none")]
@@ -3101,7 +3656,7 @@ impl JobStreamProperties {
 pub mod job_stream_properties {
     use super::*;
     #[doc = "Gets or sets the stream type."]
-    #[derive(Clone, Debug, PartialEq, Serialize, Deserialize)]
+    #[derive(Clone, Debug, PartialEq, Eq, Hash, Serialize, Deserialize)]
     #[serde(remote = "StreamType")]
     pub enum StreamType {
         Progress,
@@ -3147,9 +3702,29 @@ pub mod job_stream_properties {
             }
         }
     }
+    impl StreamType {
+        #[doc = "The value as it is sent on the wire."]
+        pub fn as_str(&self) -> &str {
+            match self {
+                Self::Progress => "Progress",
+                Self::Output => "Output",
+                Self::Warning => "Warning",
+                Self::Error => "Error",
+                Self::Debug => "Debug",
+                Self::Verbose => "Verbose",
+                Self::Any => "Any",
+                Self::UnknownValue(s) => s.as_str(),
+            }
+        }
+    }
+    impl std::fmt::Display for StreamType {
+        fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+            f.write_str(self.as_str())
+        }
+    }
 }
 #[doc = "Automation key which is used to register a DSC Node"]
-#[derive(Clone, Debug, PartialEq, Serialize, Deserialize, Default)]
+#[derive(Clone, Debug, PartialEq, Eq, Hash, Serialize, Deserialize, Default)]
 pub struct Key {
     #[doc = "Automation key name."]
     #[serde(rename = "KeyName", default, skip_serializing_if = "Option::is_none")]
@@ -3169,7 +3744,7 @@ impl Key {
 pub mod key {
     use super::*;
     #[doc = "Automation key name."]
-    #[derive(Clone, Debug, PartialEq, Serialize, Deserialize)]
+    #[derive(Clone, Debug, PartialEq, Eq, Hash, Serialize, Deserialize)]
     #[serde(remote = "KeyName")]
     pub enum KeyName {
         Primary,
@@ -3205,8 +3780,23 @@ pub mod key {
             }
         }
     }
+    impl KeyName {
+        #[doc = "The value as it is sent on the wire."]
+        pub fn as_str(&self) -> &str {
+            match self {
+                Self::Primary => "Primary",
+                Self::Secondary => "Secondary",
+                Self::UnknownValue(s) => s.as_str(),
+            }
+        }
+    }
+    impl std::fmt::Display for KeyName {
+        fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+            f.write_str(self.as_str())
+        }
+    }
     #[doc = "Automation key permissions."]
-    #[derive(Clone, Debug, PartialEq, Serialize, Deserialize)]
+    #[derive(Clone, Debug, PartialEq, Eq, Hash, Serialize, Deserialize)]
     #[serde(remote = "Permissions")]
     pub enum Permissions {
         Read,
@@ -3242,8 +3832,23 @@ pub mod key {
             }
         }
     }
+    impl Permissions {
+        #[doc = "The value as it is sent on the wire."]
+        pub fn as_str(&self) -> &str {
+            match self {
+                Self::Read => "Read",
+                Self::Full => "Full",
+                Self::UnknownValue(s) => s.as_str(),
+            }
+        }
+    }
+    impl std::fmt::Display for Permissions {
+        fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+            f.write_str(self.as_str())
+        }
+    }
 }
-#[derive(Clone, Debug, PartialEq, Serialize, Deserialize, Default)]
+#[derive(Clone, Debug, PartialEq, Eq, Hash, Serialize, Deserialize, Default)]
 pub struct KeyListResult {
     #[doc = "Lists the automation keys."]
     #[serde(
@@ -3257,9 +3862,13 @@ impl KeyListResult {
     pub fn new() -> Self {
         Self::default()
     }
+    #[doc = "The items in this page."]
+    pub fn items(&self) -> &[Key] {
+        &self.keys
+    }
 }
 #[doc = "Settings concerning key vault encryption for a configuration store."]
-#[derive(Clone, Debug, PartialEq, Serialize, Deserialize, Default)]
+#[derive(Clone, Debug, PartialEq, Eq, Hash, Serialize, Deserialize, Default)]
 pub struct KeyVaultProperties {
     #[doc = "The URI of the key vault key used to encrypt data."]
     #[serde(rename = "keyvaultUri", default, skip_serializing_if = "Option::is_none")]
@@ -3277,7 +3886,7 @@ impl KeyVaultProperties {
     }
 }
 #[doc = "Definition of the linked workspace."]
-#[derive(Clone, Debug, PartialEq, Serialize, Deserialize, Default)]
+#[derive(Clone, Debug, PartialEq, Eq, Hash, Serialize, Deserialize, Default)]
 pub struct LinkedWorkspace {
     #[doc = "Gets the id of the linked workspace."]
     #[serde(default, skip_serializing_if = "Option::is_none")]
@@ -3289,7 +3898,7 @@ impl LinkedWorkspace {
     }
 }
 #[doc = "Linux specific update configuration."]
-#[derive(Clone, Debug, PartialEq, Serialize, Deserialize, Default)]
+#[derive(Clone, Debug, PartialEq, Eq, Hash, Serialize, Deserialize, Default)]
 pub struct LinuxProperties {
     #[doc = "Update classifications included in the software update configuration."]
     #[serde(rename = "includedPackageClassifications", default, skip_serializing_if = "Option::is_none")]
@@ -3322,7 +3931,7 @@ impl LinuxProperties {
 pub mod linux_properties {
     use super::*;
     #[doc = "Update classifications included in the software update configuration."]
-    #[derive(Clone, Debug, PartialEq, Serialize, Deserialize)]
+    #[derive(Clone, Debug, PartialEq, Eq, Hash, Serialize, Deserialize)]
     #[serde(remote = "IncludedPackageClassifications")]
     pub enum IncludedPackageClassifications {
         Unclassified,
@@ -3362,9 +3971,26 @@ pub mod linux_properties {
             }
         }
     }
+    impl IncludedPackageClassifications {
+        #[doc = "The value as it is sent on the wire."]
+        pub fn as_str(&self) -> &str {
+            match self {
+                Self::Unclassified => "Unclassified",
+                Self::Critical => "Critical",
+                Self::Security => "Security",
+                Self::Other => "Other",
+                Self::UnknownValue(s) => s.as_str(),
+            }
+        }
+    }
+    impl std::fmt::Display for IncludedPackageClassifications {
+        fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+            f.write_str(self.as_str())
+        }
+    }
 }
 #[doc = "Description of logging specification."]
-#[derive(Clone, Debug, PartialEq, Serialize, Deserialize, Default)]
+#[derive(Clone, Debug, PartialEq, Eq, Hash, Serialize, Deserialize, Default)]
 pub struct LogSpecification {
     #[doc = "The name of the specification."]
     #[serde(default, skip_serializing_if = "Option::is_none")]
@@ -3382,7 +4008,7 @@ impl LogSpecification {
     }
 }
 #[doc = "Description of metrics specification."]
-#[derive(Clone, Debug, PartialEq, Serialize, Deserialize, Default)]
+#[derive(Clone, Debug, PartialEq, Eq, Hash, Serialize, Deserialize, Default)]
 pub struct MetricSpecification {
     #[doc = "The name of the metric."]
     #[serde(default, skip_serializing_if = "Option::is_none")]
@@ -3455,7 +4081,7 @@ impl ModuleCreateOrUpdateParameters {
     }
 }
 #[doc = "The parameters supplied to the create or update module properties."]
-#[derive(Clone, Debug, PartialEq, Serialize, Deserialize)]
+#[derive(Clone, Debug, PartialEq, Eq, Hash, Serialize, Deserialize)]
 pub struct ModuleCreateOrUpdateProperties {
     #[doc = "Definition of the content link."]
     #[serde(rename = "contentLink")]
@@ -3467,7 +4093,7 @@ impl ModuleCreateOrUpdateProperties {
     }
 }
 #[doc = "Definition of the module error info type."]
-#[derive(Clone, Debug, PartialEq, Serialize, Deserialize, Default)]
+#[derive(Clone, Debug, PartialEq, Eq, Hash, Serialize, Deserialize, Default)]
 pub struct ModuleErrorInfo {
     #[doc = "Gets or sets the error code."]
     #[serde(default, skip_serializing_if = "Option::is_none")]
@@ -3505,9 +4131,13 @@ impl ModuleListResult {
     pub fn new() -> Self {
         Self::default()
     }
+    #[doc = "The items in this page."]
+    pub fn items(&self) -> &[Module] {
+        &self.value
+    }
 }
 #[doc = "Definition of the module property type."]
-#[derive(Clone, Debug, PartialEq, Serialize, Deserialize, Default)]
+#[derive(Clone, Debug, PartialEq, Eq, Hash, Serialize, Deserialize, Default)]
 pub struct ModuleProperties {
     #[doc = "Gets or sets the isGlobal flag of the module."]
     #[serde(rename = "isGlobal", default, skip_serializing_if = "Option::is_none")]
@@ -3531,10 +4161,20 @@ pub struct ModuleProperties {
     #[serde(default, skip_serializing_if = "Option::is_none")]
     pub error: Option<ModuleErrorInfo>,
     #[doc = "Gets or sets the creation time."]
-    #[serde(rename = "creationTime", default, with = "azure_core::date::rfc3339::option")]
+    #[serde(
+        rename = "creationTime",
+        default,
+        with = "azure_core::date::iso8601::option",
+        skip_serializing_if = "Option::is_none"
+    )]
     pub creation_time: Option<time::OffsetDateTime>,
     #[doc = "Gets or sets the last modified time."]
-    #[serde(rename = "lastModifiedTime", default, with = "azure_core::date::rfc3339::option")]
+    #[serde(
+        rename = "lastModifiedTime",
+        default,
+        with = "azure_core::date::iso8601::option",
+        skip_serializing_if = "Option::is_none"
+    )]
     pub last_modified_time: Option<time::OffsetDateTime>,
     #[doc = "Gets or sets the description."]
     #[serde(default, skip_serializing_if = "Option::is_none")]
@@ -3551,7 +4191,7 @@ impl ModuleProperties {
 pub mod module_properties {
     use super::*;
     #[doc = "Gets or sets the provisioning state of the module."]
-    #[derive(Clone, Debug, PartialEq, Serialize, Deserialize)]
+    #[derive(Clone, Debug, PartialEq, Eq, Hash, Serialize, Deserialize)]
     #[serde(remote = "ProvisioningState")]
     pub enum ProvisioningState {
         Created,
@@ -3621,6 +4261,35 @@ pub mod module_properties {
             }
         }
     }
+    impl ProvisioningState {
+        #[doc = "The value as it is sent on the wire."]
+        pub fn as_str(&self) -> &str {
+            match self {
+                Self::Created => "Created",
+                Self::Creating => "Creating",
+                Self::StartingImportModuleRunbook => "StartingImportModuleRunbook",
+                Self::RunningImportModuleRunbook => "RunningImportModuleRunbook",
+                Self::ContentRetrieved => "ContentRetrieved",
+                Self::ContentDownloaded => "ContentDownloaded",
+                Self::ContentValidated => "ContentValidated",
+                Self::ConnectionTypeImported => "ConnectionTypeImported",
+                Self::ContentStored => "ContentStored",
+                Self::ModuleDataStored => "ModuleDataStored",
+                Self::ActivitiesStored => "ActivitiesStored",
+                Self::ModuleImportRunbookComplete => "ModuleImportRunbookComplete",
+                Self::Succeeded => "Succeeded",
+                Self::Failed => "Failed",
+                Self::Cancelled => "Cancelled",
+                Self::Updating => "Updating",
+                Self::UnknownValue(s) => s.as_str(),
+            }
+        }
+    }
+    impl std::fmt::Display for ProvisioningState {
+        fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+            f.write_str(self.as_str())
+        }
+    }
 }
 #[doc = "The parameters supplied to the update module operation."]
 #[derive(Clone, Debug, PartialEq, Serialize, Deserialize, Default)]
@@ -3644,7 +4313,7 @@ impl ModuleUpdateParameters {
     }
 }
 #[doc = "The parameters supplied to the update properties."]
-#[derive(Clone, Debug, PartialEq, Serialize, Deserialize, Default)]
+#[derive(Clone, Debug, PartialEq, Eq, Hash, Serialize, Deserialize, Default)]
 pub struct ModuleUpdateProperties {
     #[doc = "Definition of the content link."]
     #[serde(rename = "contentLink", default, skip_serializing_if = "Option::is_none")]
@@ -3656,7 +4325,7 @@ impl ModuleUpdateProperties {
     }
 }
 #[doc = "Number of nodes based on the Filter"]
-#[derive(Clone, Debug, PartialEq, Serialize, Deserialize, Default)]
+#[derive(Clone, Debug, PartialEq, Eq, Hash, Serialize, Deserialize, Default)]
 pub struct NodeCount {
     #[doc = "Gets the name of a count type"]
     #[serde(default, skip_serializing_if = "Option::is_none")]
@@ -3669,7 +4338,7 @@ impl NodeCount {
         Self::default()
     }
 }
-#[derive(Clone, Debug, PartialEq, Serialize, Deserialize, Default)]
+#[derive(Clone, Debug, PartialEq, Eq, Hash, Serialize, Deserialize, Default)]
 pub struct NodeCountProperties {
     #[doc = "Gets the count for the name"]
     #[serde(default, skip_serializing_if = "Option::is_none")]
@@ -3681,7 +4350,7 @@ impl NodeCountProperties {
     }
 }
 #[doc = "Gets the count of nodes by count type"]
-#[derive(Clone, Debug, PartialEq, Serialize, Deserialize, Default)]
+#[derive(Clone, Debug, PartialEq, Eq, Hash, Serialize, Deserialize, Default)]
 pub struct NodeCounts {
     #[doc = "Gets an array of counts"]
     #[serde(
@@ -3700,7 +4369,7 @@ impl NodeCounts {
     }
 }
 #[doc = "Non Azure query for the update configuration."]
-#[derive(Clone, Debug, PartialEq, Serialize, Deserialize, Default)]
+#[derive(Clone, Debug, PartialEq, Eq, Hash, Serialize, Deserialize, Default)]
 pub struct NonAzureQueryProperties {
     #[doc = "Log Analytics Saved Search name."]
     #[serde(rename = "functionAlias", default, skip_serializing_if = "Option::is_none")]
@@ -3714,8 +4383,14 @@ impl NonAzureQueryProperties {
         Self::default()
     }
 }
+#[doc = "Target operating system for the software update configuration."]
+#[derive(Clone, Debug, PartialEq, Eq, Hash, Serialize, Deserialize)]
+pub enum OperatingSystemType {
+    Windows,
+    Linux,
+}
 #[doc = "Automation REST API operation"]
-#[derive(Clone, Debug, PartialEq, Serialize, Deserialize, Default)]
+#[derive(Clone, Debug, PartialEq, Eq, Hash, Serialize, Deserialize, Default)]
 pub struct Operation {
     #[doc = "Operation name: {provider}/{resource}/{operation}"]
     #[serde(default, skip_serializing_if = "Option::is_none")]
@@ -3738,7 +4413,7 @@ impl Operation {
 pub mod operation {
     use super::*;
     #[doc = "Provider, Resource and Operation values"]
-    #[derive(Clone, Debug, PartialEq, Serialize, Deserialize, Default)]
+    #[derive(Clone, Debug, PartialEq, Eq, Hash, Serialize, Deserialize, Default)]
     pub struct Display {
         #[doc = "Service provider: Microsoft.Automation"]
         #[serde(default, skip_serializing_if = "Option::is_none")]
@@ -3760,7 +4435,7 @@ pub mod operation {
     }
 }
 #[doc = "The response model for the list of Automation operations"]
-#[derive(Clone, Debug, PartialEq, Serialize, Deserialize, Default)]
+#[derive(Clone, Debug, PartialEq, Eq, Hash, Serialize, Deserialize, Default)]
 pub struct OperationListResult {
     #[doc = "List of Automation operations supported by the Automation resource provider."]
     #[serde(
@@ -3769,20 +4444,27 @@ pub struct OperationListResult {
         skip_serializing_if = "Vec::is_empty"
     )]
     pub value: Vec<Operation>,
+    #[doc = "The link to the next page of operations."]
+    #[serde(rename = "nextLink", default, skip_serializing_if = "Option::is_none")]
+    pub next_link: Option<String>,
 }
 impl azure_core::Continuable for OperationListResult {
     type Continuation = String;
     fn continuation(&self) -> Option<Self::Continuation> {
-        None
+        self.next_link.clone()
     }
 }
 impl OperationListResult {
     pub fn new() -> Self {
         Self::default()
     }
+    #[doc = "The items in this page."]
+    pub fn items(&self) -> &[Operation] {
+        &self.value
+    }
 }
 #[doc = "Description of operation properties format."]
-#[derive(Clone, Debug, PartialEq, Serialize, Deserialize, Default)]
+#[derive(Clone, Debug, PartialEq, Eq, Hash, Serialize, Deserialize, Default)]
 pub struct OperationPropertiesFormat {
     #[doc = "Specification of the service."]
     #[serde(rename = "serviceSpecification", default, skip_serializing_if = "Option::is_none")]
@@ -3796,7 +4478,7 @@ impl OperationPropertiesFormat {
 pub mod operation_properties_format {
     use super::*;
     #[doc = "Specification of the service."]
-    #[derive(Clone, Debug, PartialEq, Serialize, Deserialize, Default)]
+    #[derive(Clone, Debug, PartialEq, Eq, Hash, Serialize, Deserialize, Default)]
     pub struct ServiceSpecification {
         #[doc = "Operation service specification."]
         #[serde(
@@ -3822,7 +4504,7 @@ pub mod operation_properties_format {
     }
 }
 #[doc = "A private endpoint connection"]
-#[derive(Clone, Debug, PartialEq, Serialize, Deserialize, Default)]
+#[derive(Clone, Debug, PartialEq, Eq, Hash, Serialize, Deserialize, Default)]
 pub struct PrivateEndpointConnection {
     #[serde(flatten)]
     pub proxy_resource: ProxyResource,
@@ -3836,7 +4518,7 @@ impl PrivateEndpointConnection {
     }
 }
 #[doc = "A list of private endpoint connections"]
-#[derive(Clone, Debug, PartialEq, Serialize, Deserialize, Default)]
+#[derive(Clone, Debug, PartialEq, Eq, Hash, Serialize, Deserialize, Default)]
 pub struct PrivateEndpointConnectionListResult {
     #[doc = "Array of private endpoint connections"]
     #[serde(
@@ -3856,9 +4538,13 @@ impl PrivateEndpointConnectionListResult {
     pub fn new() -> Self {
         Self::default()
     }
+    #[doc = "The items in this page."]
+    pub fn items(&self) -> &[PrivateEndpointConnection] {
+        &self.value
+    }
 }
 #[doc = "Properties of a private endpoint connection."]
-#[derive(Clone, Debug, PartialEq, Serialize, Deserialize, Default)]
+#[derive(Clone, Debug, PartialEq, Eq, Hash, Serialize, Deserialize, Default)]
 pub struct PrivateEndpointConnectionProperties {
     #[doc = "Private endpoint which the connection belongs to."]
     #[serde(rename = "privateEndpoint", default, skip_serializing_if = "Option::is_none")]
@@ -3876,7 +4562,7 @@ impl PrivateEndpointConnectionProperties {
     }
 }
 #[doc = "Private endpoint which the connection belongs to."]
-#[derive(Clone, Debug, PartialEq, Serialize, Deserialize, Default)]
+#[derive(Clone, Debug, PartialEq, Eq, Hash, Serialize, Deserialize, Default)]
 pub struct PrivateEndpointProperty {
     #[doc = "Resource id of the private endpoint."]
     #[serde(default, skip_serializing_if = "Option::is_none")]
@@ -3888,7 +4574,7 @@ impl PrivateEndpointProperty {
     }
 }
 #[doc = "A private link resource"]
-#[derive(Clone, Debug, PartialEq, Serialize, Deserialize, Default)]
+#[derive(Clone, Debug, PartialEq, Eq, Hash, Serialize, Deserialize, Default)]
 pub struct PrivateLinkResource {
     #[serde(flatten)]
     pub proxy_resource: ProxyResource,
@@ -3902,7 +4588,7 @@ impl PrivateLinkResource {
     }
 }
 #[doc = "A list of private link resources"]
-#[derive(Clone, Debug, PartialEq, Serialize, Deserialize, Default)]
+#[derive(Clone, Debug, PartialEq, Eq, Hash, Serialize, Deserialize, Default)]
 pub struct PrivateLinkResourceListResult {
     #[doc = "Array of private link resources"]
     #[serde(
@@ -3922,9 +4608,13 @@ impl PrivateLinkResourceListResult {
     pub fn new() -> Self {
         Self::default()
     }
+    #[doc = "The items in this page."]
+    pub fn items(&self) -> &[PrivateLinkResource] {
+        &self.value
+    }
 }
 #[doc = "Properties of a private link resource."]
-#[derive(Clone, Debug, PartialEq, Serialize, Deserialize, Default)]
+#[derive(Clone, Debug, PartialEq, Eq, Hash, Serialize, Deserialize, Default)]
 pub struct PrivateLinkResourceProperties {
     #[doc = "The private link resource group id."]
     #[serde(rename = "groupId", default, skip_serializing_if = "Option::is_none")]
@@ -3944,7 +4634,7 @@ impl PrivateLinkResourceProperties {
     }
 }
 #[doc = "Connection State of the Private Endpoint Connection."]
-#[derive(Clone, Debug, PartialEq, Serialize, Deserialize, Default)]
+#[derive(Clone, Debug, PartialEq, Eq, Hash, Serialize, Deserialize, Default)]
 pub struct PrivateLinkServiceConnectionStateProperty {
     #[doc = "The private link service connection status."]
     #[serde(default, skip_serializing_if = "Option::is_none")]
@@ -3962,7 +4652,7 @@ impl PrivateLinkServiceConnectionStateProperty {
     }
 }
 #[doc = "ARM proxy resource."]
-#[derive(Clone, Debug, PartialEq, Serialize, Deserialize, Default)]
+#[derive(Clone, Debug, PartialEq, Eq, Hash, Serialize, Deserialize, Default)]
 pub struct ProxyResource {
     #[serde(flatten)]
     pub resource: Resource,
@@ -3987,7 +4677,7 @@ impl PythonPackageCreateParameters {
     }
 }
 #[doc = "The parameters supplied to the create or update module properties."]
-#[derive(Clone, Debug, PartialEq, Serialize, Deserialize)]
+#[derive(Clone, Debug, PartialEq, Eq, Hash, Serialize, Deserialize)]
 pub struct PythonPackageCreateProperties {
     #[doc = "Definition of the content link."]
     #[serde(rename = "contentLink")]
@@ -4011,7 +4701,7 @@ impl PythonPackageUpdateParameters {
     }
 }
 #[doc = "Raw Graphical Runbook content"]
-#[derive(Clone, Debug, PartialEq, Serialize, Deserialize, Default)]
+#[derive(Clone, Debug, PartialEq, Eq, Hash, Serialize, Deserialize, Default)]
 pub struct RawGraphicalRunbookContent {
     #[doc = "Schema version of the serializer."]
     #[serde(rename = "schemaVersion", default, skip_serializing_if = "Option::is_none")]
@@ -4031,7 +4721,7 @@ impl RawGraphicalRunbookContent {
 pub mod raw_graphical_runbook_content {
     use super::*;
     #[doc = "Runbook Type"]
-    #[derive(Clone, Debug, PartialEq, Serialize, Deserialize)]
+    #[derive(Clone, Debug, PartialEq, Eq, Hash, Serialize, Deserialize)]
     #[serde(remote = "RunbookType")]
     pub enum RunbookType {
         GraphPowerShell,
@@ -4067,9 +4757,24 @@ pub mod raw_graphical_runbook_content {
             }
         }
     }
+    impl RunbookType {
+        #[doc = "The value as it is sent on the wire."]
+        pub fn as_str(&self) -> &str {
+            match self {
+                Self::GraphPowerShell => "GraphPowerShell",
+                Self::GraphPowerShellWorkflow => "GraphPowerShellWorkflow",
+                Self::UnknownValue(s) => s.as_str(),
+            }
+        }
+    }
+    impl std::fmt::Display for RunbookType {
+        fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+            f.write_str(self.as_str())
+        }
+    }
 }
 #[doc = "The core properties of ARM resources"]
-#[derive(Clone, Debug, PartialEq, Serialize, Deserialize, Default)]
+#[derive(Clone, Debug, PartialEq, Eq, Hash, Serialize, Deserialize, Default)]
 pub struct Resource {
     #[doc = "Fully qualified resource Id for the resource"]
     #[serde(default, skip_serializing_if = "Option::is_none")]
@@ -4087,7 +4792,7 @@ impl Resource {
     }
 }
 #[doc = "Definition of RunAs credential to use for hybrid worker."]
-#[derive(Clone, Debug, PartialEq, Serialize, Deserialize, Default)]
+#[derive(Clone, Debug, PartialEq, Eq, Hash, Serialize, Deserialize, Default)]
 pub struct RunAsCredentialAssociationProperty {
     #[doc = "Gets or sets the name of the credential."]
     #[serde(default, skip_serializing_if = "Option::is_none")]
@@ -4116,7 +4821,7 @@ impl Runbook {
     }
 }
 #[doc = "The runbook property associated with the entity."]
-#[derive(Clone, Debug, PartialEq, Serialize, Deserialize, Default)]
+#[derive(Clone, Debug, PartialEq, Eq, Hash, Serialize, Deserialize, Default)]
 pub struct RunbookAssociationProperty {
     #[doc = "Gets or sets the name of the runbook."]
     #[serde(default, skip_serializing_if = "Option::is_none")]
@@ -4128,7 +4833,7 @@ impl RunbookAssociationProperty {
     }
 }
 #[doc = "The parameters supplied to the create or update runbook operation."]
-#[derive(Clone, Debug, PartialEq, Serialize, Deserialize)]
+#[derive(Clone, Debug, PartialEq, Eq, Hash, Serialize, Deserialize)]
 pub struct RunbookCreateOrUpdateDraftParameters {
     #[doc = "Content of the Runbook."]
     #[serde(rename = "runbookContent")]
@@ -4174,7 +4879,7 @@ impl RunbookCreateOrUpdateDraftProperties {
 pub mod runbook_create_or_update_draft_properties {
     use super::*;
     #[doc = "Gets or sets the type of the runbook."]
-    #[derive(Clone, Debug, PartialEq, Serialize, Deserialize)]
+    #[derive(Clone, Debug, PartialEq, Eq, Hash, Serialize, Deserialize)]
     #[serde(remote = "RunbookType")]
     pub enum RunbookType {
         Script,
@@ -4222,6 +4927,27 @@ pub mod runbook_create_or_update_draft_properties {
             }
         }
     }
+    impl RunbookType {
+        #[doc = "The value as it is sent on the wire."]
+        pub fn as_str(&self) -> &str {
+            match self {
+                Self::Script => "Script",
+                Self::Graph => "Graph",
+                Self::PowerShellWorkflow => "PowerShellWorkflow",
+                Self::PowerShell => "PowerShell",
+                Self::GraphPowerShellWorkflow => "GraphPowerShellWorkflow",
+                Self::GraphPowerShell => "GraphPowerShell",
+                Self::Python2 => "Python2",
+                Self::Python3 => "Python3",
+                Self::UnknownValue(s) => s.as_str(),
+            }
+        }
+    }
+    impl std::fmt::Display for RunbookType {
+        fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+            f.write_str(self.as_str())
+        }
+    }
 }
 #[doc = "The parameters supplied to the create or update runbook operation."]
 #[derive(Clone, Debug, PartialEq, Serialize, Deserialize)]
@@ -4288,7 +5014,7 @@ impl RunbookCreateOrUpdateProperties {
 pub mod runbook_create_or_update_properties {
     use super::*;
     #[doc = "Gets or sets the type of the runbook."]
-    #[derive(Clone, Debug, PartialEq, Serialize, Deserialize)]
+    #[derive(Clone, Debug, PartialEq, Eq, Hash, Serialize, Deserialize)]
     #[serde(remote = "RunbookType")]
     pub enum RunbookType {
         Script,
@@ -4336,6 +5062,27 @@ pub mod runbook_create_or_update_properties {
             }
         }
     }
+    impl RunbookType {
+        #[doc = "The value as it is sent on the wire."]
+        pub fn as_str(&self) -> &str {
+            match self {
+                Self::Script => "Script",
+                Self::Graph => "Graph",
+                Self::PowerShellWorkflow => "PowerShellWorkflow",
+                Self::PowerShell => "PowerShell",
+                Self::GraphPowerShellWorkflow => "GraphPowerShellWorkflow",
+                Self::GraphPowerShell => "GraphPowerShell",
+                Self::Python2 => "Python2",
+                Self::Python3 => "Python3",
+                Self::UnknownValue(s) => s.as_str(),
+            }
+        }
+    }
+    impl std::fmt::Display for RunbookType {
+        fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+            f.write_str(self.as_str())
+        }
+    }
 }
 #[derive(Clone, Debug, PartialEq, Serialize, Deserialize, Default)]
 pub struct RunbookDraft {
@@ -4346,10 +5093,20 @@ pub struct RunbookDraft {
     #[serde(rename = "draftContentLink", default, skip_serializing_if = "Option::is_none")]
     pub draft_content_link: Option<ContentLink>,
     #[doc = "Gets or sets the creation time of the runbook draft."]
-    #[serde(rename = "creationTime", default, with = "azure_core::date::rfc3339::option")]
+    #[serde(
+        rename = "creationTime",
+        default,
+        with = "azure_core::date::iso8601::option",
+        skip_serializing_if = "Option::is_none"
+    )]
     pub creation_time: Option<time::OffsetDateTime>,
     #[doc = "Gets or sets the last modified time of the runbook draft."]
-    #[serde(rename = "lastModifiedTime", default, with = "azure_core::date::rfc3339::option")]
+    #[serde(
+        rename = "lastModifiedTime",
+        default,
+        with = "azure_core::date::iso8601::option",
+        skip_serializing_if = "Option::is_none"
+    )]
     pub last_modified_time: Option<time::OffsetDateTime>,
     #[doc = "Gets or sets the runbook draft parameters."]
     #[serde(default, skip_serializing_if = "Option::is_none")]
@@ -4369,7 +5126,7 @@ impl RunbookDraft {
     }
 }
 #[doc = "The response model for the undo edit runbook operation."]
-#[derive(Clone, Debug, PartialEq, Serialize, Deserialize, Default)]
+#[derive(Clone, Debug, PartialEq, Eq, Hash, Serialize, Deserialize, Default)]
 pub struct RunbookDraftUndoEditResult {
     #[serde(rename = "statusCode", default, skip_serializing_if = "Option::is_none")]
     pub status_code: Option<runbook_draft_undo_edit_result::StatusCode>,
@@ -4383,7 +5140,7 @@ impl RunbookDraftUndoEditResult {
 }
 pub mod runbook_draft_undo_edit_result {
     use super::*;
-    #[derive(Clone, Debug, PartialEq, Serialize, Deserialize)]
+    #[derive(Clone, Debug, PartialEq, Eq, Hash, Serialize, Deserialize)]
     #[serde(remote = "StatusCode")]
     pub enum StatusCode {
         Continue,
@@ -4512,6 +5269,66 @@ pub mod runbook_draft_undo_edit_result {
             }
         }
     }
+    impl StatusCode {
+        #[doc = "The value as it is sent on the wire."]
+        pub fn as_str(&self) -> &str {
+            match self {
+                Self::Continue => "Continue",
+                Self::SwitchingProtocols => "SwitchingProtocols",
+                Self::Ok => "OK",
+                Self::Created => "Created",
+                Self::Accepted => "Accepted",
+                Self::NonAuthoritativeInformation => "NonAuthoritativeInformation",
+                Self::NoContent => "NoContent",
+                Self::ResetContent => "ResetContent",
+                Self::PartialContent => "PartialContent",
+                Self::MultipleChoices => "MultipleChoices",
+                Self::Ambiguous => "Ambiguous",
+                Self::MovedPermanently => "MovedPermanently",
+                Self::Moved => "Moved",
+                Self::Found => "Found",
+                Self::Redirect => "Redirect",
+                Self::SeeOther => "SeeOther",
+                Self::RedirectMethod => "RedirectMethod",
+                Self::NotModified => "NotModified",
+                Self::UseProxy => "UseProxy",
+                Self::Unused => "Unused",
+                Self::TemporaryRedirect => "TemporaryRedirect",
+                Self::RedirectKeepVerb => "RedirectKeepVerb",
+                Self::BadRequest => "BadRequest",
+                Self::Unauthorized => "Unauthorized",
+                Self::PaymentRequired => "PaymentRequired",
+                Self::Forbidden => "Forbidden",
+                Self::NotFound => "NotFound",
+                Self::MethodNotAllowed => "MethodNotAllowed",
+                Self::NotAcceptable => "NotAcceptable",
+                Self::ProxyAuthenticationRequired => "ProxyAuthenticationRequired",
+                Self::RequestTimeout => "RequestTimeout",
+                Self::Conflict => "Conflict",
+                Self::Gone => "Gone",
+                Self::LengthRequired => "LengthRequired",
+                Self::PreconditionFailed => "PreconditionFailed",
+                Self::RequestEntityTooLarge => "RequestEntityTooLarge",
+                Self::RequestUriTooLong => "RequestUriTooLong",
+                Self::UnsupportedMediaType => "UnsupportedMediaType",
+                Self::RequestedRangeNotSatisfiable => "RequestedRangeNotSatisfiable",
+                Self::ExpectationFailed => "ExpectationFailed",
+                Self::UpgradeRequired => "UpgradeRequired",
+                Self::InternalServerError => "InternalServerError",
+                Self::NotImplemented => "NotImplemented",
+                Self::BadGateway => "BadGateway",
+                Self::ServiceUnavailable => "ServiceUnavailable",
+                Self::GatewayTimeout => "GatewayTimeout",
+                Self::HttpVersionNotSupported => "HttpVersionNotSupported",
+                Self::UnknownValue(s) => s.as_str(),
+            }
+        }
+    }
+    impl std::fmt::Display for StatusCode {
+        fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+            f.write_str(self.as_str())
+        }
+    }
 }
 #[doc = "The response model for the list runbook operation."]
 #[derive(Clone, Debug, PartialEq, Serialize, Deserialize, Default)]
@@ -4537,9 +5354,13 @@ impl RunbookListResult {
     pub fn new() -> Self {
         Self::default()
     }
+    #[doc = "The items in this page."]
+    pub fn items(&self) -> &[Runbook] {
+        &self.value
+    }
 }
 #[doc = "Definition of the runbook parameter type."]
-#[derive(Clone, Debug, PartialEq, Serialize, Deserialize, Default)]
+#[derive(Clone, Debug, PartialEq, Eq, Hash, Serialize, Deserialize, Default)]
 pub struct RunbookParameter {
     #[doc = "Gets or sets the type of the parameter."]
     #[serde(rename = "type", default, skip_serializing_if = "Option::is_none")]
@@ -4603,14 +5424,27 @@ pub struct RunbookProperties {
     #[serde(rename = "lastModifiedBy", default, skip_serializing_if = "Option::is_none")]
     pub last_modified_by: Option<String>,
     #[doc = "Gets or sets the creation time."]
-    #[serde(rename = "creationTime", default, with = "azure_core::date::rfc3339::option")]
+    #[serde(
+        rename = "creationTime",
+        default,
+        with = "azure_core::date::iso8601::option",
+        skip_serializing_if = "Option::is_none"
+    )]
     pub creation_time: Option<time::OffsetDateTime>,
     #[doc = "Gets or sets the last modified time."]
-    #[serde(rename = "lastModifiedTime", default, with = "azure_core::date::rfc3339::option")]
+    #[serde(
+        rename = "lastModifiedTime",
+        default,
+        with = "azure_core::date::iso8601::option",
+        skip_serializing_if = "Option::is_none"
+    )]
     pub last_modified_time: Option<time::OffsetDateTime>,
     #[doc = "Gets or sets the description."]
     #[serde(default, skip_serializing_if = "Option::is_none")]
     pub description: Option<String>,
+    #[doc = "Fields of the response that this version of the crate has no field for, kept so that they are not lost when the model is sent back, such as in a read-modify-write update."]
+    #[serde(flatten, default, skip_serializing_if = "std::collections::HashMap::is_empty")]
+    pub extra: std::collections::HashMap<String, serde_json::Value>,
 }
 impl RunbookProperties {
     pub fn new() -> Self {
@@ -4620,7 +5454,7 @@ impl RunbookProperties {
 pub mod runbook_properties {
     use super::*;
     #[doc = "Gets or sets the type of the runbook."]
-    #[derive(Clone, Debug, PartialEq, Serialize, Deserialize)]
+    #[derive(Clone, Debug, PartialEq, Eq, Hash, Serialize, Deserialize)]
     #[serde(remote = "RunbookType")]
     pub enum RunbookType {
         Script,
@@ -4668,8 +5502,29 @@ pub mod runbook_properties {
             }
         }
     }
+    impl RunbookType {
+        #[doc = "The value as it is sent on the wire."]
+        pub fn as_str(&self) -> &str {
+            match self {
+                Self::Script => "Script",
+                Self::Graph => "Graph",
+                Self::PowerShellWorkflow => "PowerShellWorkflow",
+                Self::PowerShell => "PowerShell",
+                Self::GraphPowerShellWorkflow => "GraphPowerShellWorkflow",
+                Self::GraphPowerShell => "GraphPowerShell",
+                Self::Python2 => "Python2",
+                Self::Python3 => "Python3",
+                Self::UnknownValue(s) => s.as_str(),
+            }
+        }
+    }
+    impl std::fmt::Display for RunbookType {
+        fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+            f.write_str(self.as_str())
+        }
+    }
     #[doc = "Gets or sets the state of the runbook."]
-    #[derive(Clone, Debug, PartialEq, Serialize, Deserialize)]
+    #[derive(Clone, Debug, PartialEq, Eq, Hash, Serialize, Deserialize)]
     #[serde(remote = "State")]
     pub enum State {
         New,
@@ -4707,8 +5562,24 @@ pub mod runbook_properties {
             }
         }
     }
+    impl State {
+        #[doc = "The value as it is sent on the wire."]
+        pub fn as_str(&self) -> &str {
+            match self {
+                Self::New => "New",
+                Self::Edit => "Edit",
+                Self::Published => "Published",
+                Self::UnknownValue(s) => s.as_str(),
+            }
+        }
+    }
+    impl std::fmt::Display for State {
+        fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+            f.write_str(self.as_str())
+        }
+    }
     #[doc = "Gets or sets the provisioning state of the runbook."]
-    #[derive(Clone, Debug, PartialEq, Serialize, Deserialize)]
+    #[derive(Clone, Debug, PartialEq, Eq, Hash, Serialize, Deserialize)]
     pub enum ProvisioningState {
         Succeeded,
     }
@@ -4735,7 +5606,7 @@ impl RunbookUpdateParameters {
     }
 }
 #[doc = "The parameters supplied to the update runbook properties."]
-#[derive(Clone, Debug, PartialEq, Serialize, Deserialize, Default)]
+#[derive(Clone, Debug, PartialEq, Eq, Hash, Serialize, Deserialize, Default)]
 pub struct RunbookUpdateProperties {
     #[doc = "Gets or sets the description of the runbook."]
     #[serde(default, skip_serializing_if = "Option::is_none")]
@@ -4755,57 +5626,6 @@ impl RunbookUpdateProperties {
         Self::default()
     }
 }
-#[doc = "Definition of schedule parameters."]
-#[derive(Clone, Debug, PartialEq, Serialize, Deserialize, Default)]
-pub struct SucScheduleProperties {
-    #[doc = "Gets or sets the start time of the schedule."]
-    #[serde(rename = "startTime", default, with = "azure_core::date::rfc3339::option")]
-    pub start_time: Option<time::OffsetDateTime>,
-    #[doc = "Gets the start time's offset in minutes."]
-    #[serde(rename = "startTimeOffsetMinutes", default, skip_serializing_if = "Option::is_none")]
-    pub start_time_offset_minutes: Option<f64>,
-    #[doc = "Gets or sets the end time of the schedule."]
-    #[serde(rename = "expiryTime", default, with = "azure_core::date::rfc3339::option")]
-    pub expiry_time: Option<time::OffsetDateTime>,
-    #[doc = "Gets or sets the expiry time's offset in minutes."]
-    #[serde(rename = "expiryTimeOffsetMinutes", default, skip_serializing_if = "Option::is_none")]
-    pub expiry_time_offset_minutes: Option<f64>,
-    #[doc = "Gets or sets a value indicating whether this schedule is enabled."]
-    #[serde(rename = "isEnabled", default, skip_serializing_if = "Option::is_none")]
-    pub is_enabled: Option<bool>,
-    #[doc = "Gets or sets the next run time of the schedule."]
-    #[serde(rename = "nextRun", default, with = "azure_core::date::rfc3339::option")]
-    pub next_run: Option<time::OffsetDateTime>,
-    #[doc = "Gets or sets the next run time's offset in minutes."]
-    #[serde(rename = "nextRunOffsetMinutes", default, skip_serializing_if = "Option::is_none")]
-    pub next_run_offset_minutes: Option<f64>,
-    #[doc = "Gets or sets the interval of the schedule."]
-    #[serde(default, skip_serializing_if = "Option::is_none")]
-    pub interval: Option<i64>,
-    #[doc = "Gets or sets the frequency of the schedule."]
-    #[serde(default, skip_serializing_if = "Option::is_none")]
-    pub frequency: Option<ScheduleFrequency>,
-    #[doc = "Gets or sets the time zone of the schedule."]
-    #[serde(rename = "timeZone", default, skip_serializing_if = "Option::is_none")]
-    pub time_zone: Option<String>,
-    #[doc = "The properties of the create Advanced Schedule."]
-    #[serde(rename = "advancedSchedule", default, skip_serializing_if = "Option::is_none")]
-    pub advanced_schedule: Option<AdvancedSchedule>,
-    #[doc = "Gets or sets the creation time."]
-    #[serde(rename = "creationTime", default, with = "azure_core::date::rfc3339::option")]
-    pub creation_time: Option<time::OffsetDateTime>,
-    #[doc = "Gets or sets the last modified time."]
-    #[serde(rename = "lastModifiedTime", default, with = "azure_core::date::rfc3339::option")]
-    pub last_modified_time: Option<time::OffsetDateTime>,
-    #[doc = "Gets or sets the description."]
-    #[serde(default, skip_serializing_if = "Option::is_none")]
-    pub description: Option<String>,
-}
-impl SucScheduleProperties {
-    pub fn new() -> Self {
-        Self::default()
-    }
-}
 #[doc = "Definition of the schedule."]
 #[derive(Clone, Debug, PartialEq, Serialize, Deserialize, Default)]
 pub struct Schedule {
@@ -4821,7 +5641,7 @@ impl Schedule {
     }
 }
 #[doc = "The schedule property associated with the entity."]
-#[derive(Clone, Debug, PartialEq, Serialize, Deserialize, Default)]
+#[derive(Clone, Debug, PartialEq, Eq, Hash, Serialize, Deserialize, Default)]
 pub struct ScheduleAssociationProperty {
     #[doc = "Gets or sets the name of the Schedule."]
     #[serde(default, skip_serializing_if = "Option::is_none")]
@@ -4852,10 +5672,15 @@ pub struct ScheduleCreateOrUpdateProperties {
     #[serde(default, skip_serializing_if = "Option::is_none")]
     pub description: Option<String>,
     #[doc = "Gets or sets the start time of the schedule."]
-    #[serde(rename = "startTime", with = "azure_core::date::rfc3339")]
+    #[serde(rename = "startTime", with = "azure_core::date::iso8601")]
     pub start_time: time::OffsetDateTime,
     #[doc = "Gets or sets the end time of the schedule."]
-    #[serde(rename = "expiryTime", default, with = "azure_core::date::rfc3339::option")]
+    #[serde(
+        rename = "expiryTime",
+        default,
+        with = "azure_core::date::iso8601::option",
+        skip_serializing_if = "Option::is_none"
+    )]
     pub expiry_time: Option<time::OffsetDateTime>,
     #[doc = "Gets or sets the interval of the schedule."]
     #[serde(default, skip_serializing_if = "Option::is_none")]
@@ -4882,13 +5707,77 @@ impl ScheduleCreateOrUpdateProperties {
         }
     }
 }
-#[doc = "The response model for the list schedule operation."]
-#[derive(Clone, Debug, PartialEq, Serialize, Deserialize, Default)]
-pub struct ScheduleListResult {
-    #[doc = "Gets or sets a list of schedules."]
-    #[serde(
-        default,
-        deserialize_with = "azure_core::util::deserialize_null_as_default",
+#[doc = "Gets or sets the frequency of the schedule."]
+#[derive(Clone, Debug, PartialEq, Eq, Hash, Serialize, Deserialize)]
+#[serde(remote = "ScheduleFrequency")]
+pub enum ScheduleFrequency {
+    OneTime,
+    Day,
+    Hour,
+    Week,
+    Month,
+    Minute,
+    #[serde(skip_deserializing)]
+    UnknownValue(String),
+}
+impl FromStr for ScheduleFrequency {
+    type Err = value::Error;
+    fn from_str(s: &str) -> std::result::Result<Self, Self::Err> {
+        Self::deserialize(s.into_deserializer())
+    }
+}
+impl<'de> Deserialize<'de> for ScheduleFrequency {
+    fn deserialize<D>(deserializer: D) -> std::result::Result<Self, D::Error>
+    where
+        D: Deserializer<'de>,
+    {
+        let s = String::deserialize(deserializer)?;
+        let deserialized = Self::from_str(&s).unwrap_or(Self::UnknownValue(s));
+        Ok(deserialized)
+    }
+}
+impl Serialize for ScheduleFrequency {
+    fn serialize<S>(&self, serializer: S) -> std::result::Result<S::Ok, S::Error>
+    where
+        S: Serializer,
+    {
+        match self {
+            Self::OneTime => serializer.serialize_unit_variant("ScheduleFrequency", 0u32, "OneTime"),
+            Self::Day => serializer.serialize_unit_variant("ScheduleFrequency", 1u32, "Day"),
+            Self::Hour => serializer.serialize_unit_variant("ScheduleFrequency", 2u32, "Hour"),
+            Self::Week => serializer.serialize_unit_variant("ScheduleFrequency", 3u32, "Week"),
+            Self::Month => serializer.serialize_unit_variant("ScheduleFrequency", 4u32, "Month"),
+            Self::Minute => serializer.serialize_unit_variant("ScheduleFrequency", 5u32, "Minute"),
+            Self::UnknownValue(s) => serializer.serialize_str(s.as_str()),
+        }
+    }
+}
+impl ScheduleFrequency {
+    #[doc = "The value as it is sent on the wire."]
+    pub fn as_str(&self) -> &str {
+        match self {
+            Self::OneTime => "OneTime",
+            Self::Day => "Day",
+            Self::Hour => "Hour",
+            Self::Week => "Week",
+            Self::Month => "Month",
+            Self::Minute => "Minute",
+            Self::UnknownValue(s) => s.as_str(),
+        }
+    }
+}
+impl std::fmt::Display for ScheduleFrequency {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.write_str(self.as_str())
+    }
+}
+#[doc = "The response model for the list schedule operation."]
+#[derive(Clone, Debug, PartialEq, Serialize, Deserialize, Default)]
+pub struct ScheduleListResult {
+    #[doc = "Gets or sets a list of schedules."]
+    #[serde(
+        default,
+        deserialize_with = "azure_core::util::deserialize_null_as_default",
         skip_serializing_if = "Vec::is_empty"
     )]
     pub value: Vec<Schedule>,
@@ -4906,18 +5795,32 @@ impl ScheduleListResult {
     pub fn new() -> Self {
         Self::default()
     }
+    #[doc = "The items in this page."]
+    pub fn items(&self) -> &[Schedule] {
+        &self.value
+    }
 }
 #[doc = "Definition of schedule parameters."]
 #[derive(Clone, Debug, PartialEq, Serialize, Deserialize, Default)]
 pub struct ScheduleProperties {
     #[doc = "Gets or sets the start time of the schedule."]
-    #[serde(rename = "startTime", default, with = "azure_core::date::rfc3339::option")]
+    #[serde(
+        rename = "startTime",
+        default,
+        with = "azure_core::date::iso8601::option",
+        skip_serializing_if = "Option::is_none"
+    )]
     pub start_time: Option<time::OffsetDateTime>,
     #[doc = "Gets the start time's offset in minutes."]
     #[serde(rename = "startTimeOffsetMinutes", default, skip_serializing_if = "Option::is_none")]
     pub start_time_offset_minutes: Option<f64>,
     #[doc = "Gets or sets the end time of the schedule."]
-    #[serde(rename = "expiryTime", default, with = "azure_core::date::rfc3339::option")]
+    #[serde(
+        rename = "expiryTime",
+        default,
+        with = "azure_core::date::iso8601::option",
+        skip_serializing_if = "Option::is_none"
+    )]
     pub expiry_time: Option<time::OffsetDateTime>,
     #[doc = "Gets or sets the expiry time's offset in minutes."]
     #[serde(rename = "expiryTimeOffsetMinutes", default, skip_serializing_if = "Option::is_none")]
@@ -4926,7 +5829,12 @@ pub struct ScheduleProperties {
     #[serde(rename = "isEnabled", default, skip_serializing_if = "Option::is_none")]
     pub is_enabled: Option<bool>,
     #[doc = "Gets or sets the next run time of the schedule."]
-    #[serde(rename = "nextRun", default, with = "azure_core::date::rfc3339::option")]
+    #[serde(
+        rename = "nextRun",
+        default,
+        with = "azure_core::date::iso8601::option",
+        skip_serializing_if = "Option::is_none"
+    )]
     pub next_run: Option<time::OffsetDateTime>,
     #[doc = "Gets or sets the next run time's offset in minutes."]
     #[serde(rename = "nextRunOffsetMinutes", default, skip_serializing_if = "Option::is_none")]
@@ -4944,14 +5852,27 @@ pub struct ScheduleProperties {
     #[serde(rename = "advancedSchedule", default, skip_serializing_if = "Option::is_none")]
     pub advanced_schedule: Option<AdvancedSchedule>,
     #[doc = "Gets or sets the creation time."]
-    #[serde(rename = "creationTime", default, with = "azure_core::date::rfc3339::option")]
+    #[serde(
+        rename = "creationTime",
+        default,
+        with = "azure_core::date::iso8601::option",
+        skip_serializing_if = "Option::is_none"
+    )]
     pub creation_time: Option<time::OffsetDateTime>,
     #[doc = "Gets or sets the last modified time."]
-    #[serde(rename = "lastModifiedTime", default, with = "azure_core::date::rfc3339::option")]
+    #[serde(
+        rename = "lastModifiedTime",
+        default,
+        with = "azure_core::date::iso8601::option",
+        skip_serializing_if = "Option::is_none"
+    )]
     pub last_modified_time: Option<time::OffsetDateTime>,
     #[doc = "Gets or sets the description."]
     #[serde(default, skip_serializing_if = "Option::is_none")]
     pub description: Option<String>,
+    #[doc = "Fields of the response that this version of the crate has no field for, kept so that they are not lost when the model is sent back, such as in a read-modify-write update."]
+    #[serde(flatten, default, skip_serializing_if = "std::collections::HashMap::is_empty")]
+    pub extra: std::collections::HashMap<String, serde_json::Value>,
 }
 impl ScheduleProperties {
     pub fn new() -> Self {
@@ -4959,7 +5880,7 @@ impl ScheduleProperties {
     }
 }
 #[doc = "The parameters supplied to the update schedule operation."]
-#[derive(Clone, Debug, PartialEq, Serialize, Deserialize, Default)]
+#[derive(Clone, Debug, PartialEq, Eq, Hash, Serialize, Deserialize, Default)]
 pub struct ScheduleUpdateParameters {
     #[doc = "Gets or sets the name of the Schedule."]
     #[serde(default, skip_serializing_if = "Option::is_none")]
@@ -4974,7 +5895,7 @@ impl ScheduleUpdateParameters {
     }
 }
 #[doc = "The parameters supplied to the update schedule operation."]
-#[derive(Clone, Debug, PartialEq, Serialize, Deserialize, Default)]
+#[derive(Clone, Debug, PartialEq, Eq, Hash, Serialize, Deserialize, Default)]
 pub struct ScheduleUpdateProperties {
     #[doc = "Gets or sets the description of the schedule."]
     #[serde(default, skip_serializing_if = "Option::is_none")]
@@ -4989,7 +5910,7 @@ impl ScheduleUpdateProperties {
     }
 }
 #[doc = "The account SKU."]
-#[derive(Clone, Debug, PartialEq, Serialize, Deserialize)]
+#[derive(Clone, Debug, PartialEq, Eq, Hash, Serialize, Deserialize)]
 pub struct Sku {
     #[doc = "Gets or sets the SKU name of the account."]
     pub name: sku::Name,
@@ -5012,7 +5933,7 @@ impl Sku {
 pub mod sku {
     use super::*;
     #[doc = "Gets or sets the SKU name of the account."]
-    #[derive(Clone, Debug, PartialEq, Serialize, Deserialize)]
+    #[derive(Clone, Debug, PartialEq, Eq, Hash, Serialize, Deserialize)]
     #[serde(remote = "Name")]
     pub enum Name {
         Free,
@@ -5048,133 +5969,541 @@ pub mod sku {
             }
         }
     }
+    impl Name {
+        #[doc = "The value as it is sent on the wire."]
+        pub fn as_str(&self) -> &str {
+            match self {
+                Self::Free => "Free",
+                Self::Basic => "Basic",
+                Self::UnknownValue(s) => s.as_str(),
+            }
+        }
+    }
+    impl std::fmt::Display for Name {
+        fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+            f.write_str(self.as_str())
+        }
+    }
 }
-#[doc = "Definition of the source control."]
-#[derive(Clone, Debug, PartialEq, Serialize, Deserialize, Default)]
-pub struct SourceControl {
-    #[serde(flatten)]
-    pub proxy_resource: ProxyResource,
-    #[doc = "Definition of the source control properties"]
+#[doc = "Software update configuration properties."]
+#[derive(Clone, Debug, PartialEq, Serialize, Deserialize)]
+pub struct SoftwareUpdateConfiguration {
+    #[doc = "Resource name."]
     #[serde(default, skip_serializing_if = "Option::is_none")]
-    pub properties: Option<SourceControlProperties>,
+    pub name: Option<String>,
+    #[doc = "Resource Id."]
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub id: Option<String>,
+    #[doc = "Resource type"]
+    #[serde(rename = "type", default, skip_serializing_if = "Option::is_none")]
+    pub type_: Option<String>,
+    #[doc = "Software update configuration properties."]
+    pub properties: SoftwareUpdateConfigurationProperties,
 }
-impl SourceControl {
-    pub fn new() -> Self {
-        Self::default()
+impl SoftwareUpdateConfiguration {
+    pub fn new(properties: SoftwareUpdateConfigurationProperties) -> Self {
+        Self {
+            name: None,
+            id: None,
+            type_: None,
+            properties,
+        }
     }
 }
-#[doc = "The parameters supplied to the create or update source control operation."]
+#[doc = "Software update configuration collection item properties."]
 #[derive(Clone, Debug, PartialEq, Serialize, Deserialize)]
-pub struct SourceControlCreateOrUpdateParameters {
-    #[doc = "The properties of the create source control operation."]
-    pub properties: SourceControlCreateOrUpdateProperties,
+pub struct SoftwareUpdateConfigurationCollectionItem {
+    #[doc = "Name of the software update configuration."]
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub name: Option<String>,
+    #[doc = "Resource Id of the software update configuration"]
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub id: Option<String>,
+    #[doc = "Software update configuration collection item properties."]
+    pub properties: SoftwareUpdateConfigurationCollectionItemProperties,
 }
-impl SourceControlCreateOrUpdateParameters {
-    pub fn new(properties: SourceControlCreateOrUpdateProperties) -> Self {
-        Self { properties }
+impl SoftwareUpdateConfigurationCollectionItem {
+    pub fn new(properties: SoftwareUpdateConfigurationCollectionItemProperties) -> Self {
+        Self {
+            name: None,
+            id: None,
+            properties,
+        }
     }
 }
-#[doc = "The properties of the create source control operation."]
+#[doc = "Software update configuration collection item properties."]
 #[derive(Clone, Debug, PartialEq, Serialize, Deserialize, Default)]
-pub struct SourceControlCreateOrUpdateProperties {
-    #[doc = "The repo url of the source control."]
-    #[serde(rename = "repoUrl", default, skip_serializing_if = "Option::is_none")]
-    pub repo_url: Option<String>,
-    #[doc = "The repo branch of the source control. Include branch as empty string for VsoTfvc."]
+pub struct SoftwareUpdateConfigurationCollectionItemProperties {
+    #[doc = "Update specific properties of the software update configuration."]
+    #[serde(rename = "updateConfiguration", default, skip_serializing_if = "Option::is_none")]
+    pub update_configuration: Option<UpdateConfiguration>,
+    #[doc = "Task properties of the software update configuration."]
     #[serde(default, skip_serializing_if = "Option::is_none")]
-    pub branch: Option<String>,
-    #[doc = "The folder path of the source control. Path must be relative."]
-    #[serde(rename = "folderPath", default, skip_serializing_if = "Option::is_none")]
-    pub folder_path: Option<String>,
-    #[doc = "The auto async of the source control. Default is false."]
-    #[serde(rename = "autoSync", default, skip_serializing_if = "Option::is_none")]
-    pub auto_sync: Option<bool>,
-    #[doc = "The auto publish of the source control. Default is true."]
-    #[serde(rename = "publishRunbook", default, skip_serializing_if = "Option::is_none")]
-    pub publish_runbook: Option<bool>,
-    #[doc = "The source type. Must be one of VsoGit, VsoTfvc, GitHub, case sensitive."]
-    #[serde(rename = "sourceType", default, skip_serializing_if = "Option::is_none")]
-    pub source_type: Option<source_control_create_or_update_properties::SourceType>,
-    #[serde(rename = "securityToken", default, skip_serializing_if = "Option::is_none")]
-    pub security_token: Option<SourceControlSecurityTokenProperties>,
-    #[doc = "The user description of the source control."]
+    pub tasks: Option<SoftwareUpdateConfigurationTasks>,
+    #[doc = "Gets or sets the frequency of the schedule."]
     #[serde(default, skip_serializing_if = "Option::is_none")]
-    pub description: Option<String>,
+    pub frequency: Option<ScheduleFrequency>,
+    #[doc = "the start time of the update."]
+    #[serde(
+        rename = "startTime",
+        default,
+        with = "azure_core::date::iso8601::option",
+        skip_serializing_if = "Option::is_none"
+    )]
+    pub start_time: Option<time::OffsetDateTime>,
+    #[doc = "Creation time of the software update configuration, which only appears in the response."]
+    #[serde(
+        rename = "creationTime",
+        default,
+        with = "azure_core::date::iso8601::option",
+        skip_serializing_if = "Option::is_none"
+    )]
+    pub creation_time: Option<time::OffsetDateTime>,
+    #[doc = "Last time software update configuration was modified, which only appears in the response."]
+    #[serde(
+        rename = "lastModifiedTime",
+        default,
+        with = "azure_core::date::iso8601::option",
+        skip_serializing_if = "Option::is_none"
+    )]
+    pub last_modified_time: Option<time::OffsetDateTime>,
+    #[doc = "Provisioning state for the software update configuration, which only appears in the response."]
+    #[serde(rename = "provisioningState", default, skip_serializing_if = "Option::is_none")]
+    pub provisioning_state: Option<ProvisioningState>,
+    #[doc = "ext run time of the update."]
+    #[serde(
+        rename = "nextRun",
+        default,
+        with = "azure_core::date::iso8601::option",
+        skip_serializing_if = "Option::is_none"
+    )]
+    pub next_run: Option<time::OffsetDateTime>,
 }
-impl SourceControlCreateOrUpdateProperties {
+impl SoftwareUpdateConfigurationCollectionItemProperties {
     pub fn new() -> Self {
         Self::default()
     }
 }
-pub mod source_control_create_or_update_properties {
-    use super::*;
-    #[doc = "The source type. Must be one of VsoGit, VsoTfvc, GitHub, case sensitive."]
-    #[derive(Clone, Debug, PartialEq, Serialize, Deserialize)]
-    #[serde(remote = "SourceType")]
-    pub enum SourceType {
-        VsoGit,
-        VsoTfvc,
-        GitHub,
-        #[serde(skip_deserializing)]
-        UnknownValue(String),
-    }
-    impl FromStr for SourceType {
-        type Err = value::Error;
-        fn from_str(s: &str) -> std::result::Result<Self, Self::Err> {
-            Self::deserialize(s.into_deserializer())
-        }
-    }
-    impl<'de> Deserialize<'de> for SourceType {
-        fn deserialize<D>(deserializer: D) -> std::result::Result<Self, D::Error>
-        where
-            D: Deserializer<'de>,
-        {
-            let s = String::deserialize(deserializer)?;
-            let deserialized = Self::from_str(&s).unwrap_or(Self::UnknownValue(s));
-            Ok(deserialized)
-        }
-    }
-    impl Serialize for SourceType {
-        fn serialize<S>(&self, serializer: S) -> std::result::Result<S::Ok, S::Error>
-        where
-            S: Serializer,
-        {
-            match self {
-                Self::VsoGit => serializer.serialize_unit_variant("SourceType", 0u32, "VsoGit"),
-                Self::VsoTfvc => serializer.serialize_unit_variant("SourceType", 1u32, "VsoTfvc"),
-                Self::GitHub => serializer.serialize_unit_variant("SourceType", 2u32, "GitHub"),
-                Self::UnknownValue(s) => serializer.serialize_str(s.as_str()),
-            }
-        }
-    }
-}
-#[doc = "The response model for the list source controls operation."]
+#[doc = "result of listing all software update configuration"]
 #[derive(Clone, Debug, PartialEq, Serialize, Deserialize, Default)]
-pub struct SourceControlListResult {
-    #[doc = "The list of source controls."]
+pub struct SoftwareUpdateConfigurationListResult {
+    #[doc = "outer object returned when listing all software update configurations"]
     #[serde(
         default,
         deserialize_with = "azure_core::util::deserialize_null_as_default",
         skip_serializing_if = "Vec::is_empty"
     )]
-    pub value: Vec<SourceControl>,
-    #[doc = "The next link."]
-    #[serde(rename = "nextLink", default, skip_serializing_if = "Option::is_none")]
-    pub next_link: Option<String>,
-}
-impl azure_core::Continuable for SourceControlListResult {
-    type Continuation = String;
-    fn continuation(&self) -> Option<Self::Continuation> {
-        self.next_link.clone()
-    }
+    pub value: Vec<SoftwareUpdateConfigurationCollectionItem>,
 }
-impl SourceControlListResult {
+impl SoftwareUpdateConfigurationListResult {
     pub fn new() -> Self {
         Self::default()
     }
+    #[doc = "The items in this page."]
+    pub fn items(&self) -> &[SoftwareUpdateConfigurationCollectionItem] {
+        &self.value
+    }
 }
-#[doc = "Definition of the source control properties"]
-#[derive(Clone, Debug, PartialEq, Serialize, Deserialize, Default)]
+#[doc = "Software update configuration machine run model."]
+#[derive(Clone, Debug, PartialEq, Eq, Hash, Serialize, Deserialize, Default)]
+pub struct SoftwareUpdateConfigurationMachineRun {
+    #[doc = "Name of the software update configuration machine run"]
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub name: Option<String>,
+    #[doc = "Resource Id of the software update configuration machine run"]
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub id: Option<String>,
+    #[doc = "Software update configuration machine run properties."]
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub properties: Option<UpdateConfigurationMachineRunProperties>,
+}
+impl SoftwareUpdateConfigurationMachineRun {
+    pub fn new() -> Self {
+        Self::default()
+    }
+}
+#[doc = "result of listing all software update configuration machine runs"]
+#[derive(Clone, Debug, PartialEq, Eq, Hash, Serialize, Deserialize, Default)]
+pub struct SoftwareUpdateConfigurationMachineRunListResult {
+    #[doc = "outer object returned when listing all software update configuration machine runs"]
+    #[serde(
+        default,
+        deserialize_with = "azure_core::util::deserialize_null_as_default",
+        skip_serializing_if = "Vec::is_empty"
+    )]
+    pub value: Vec<SoftwareUpdateConfigurationMachineRun>,
+    #[doc = "link to next page of results."]
+    #[serde(rename = "nextLink", default, skip_serializing_if = "Option::is_none")]
+    pub next_link: Option<String>,
+}
+impl SoftwareUpdateConfigurationMachineRunListResult {
+    pub fn new() -> Self {
+        Self::default()
+    }
+    #[doc = "The items in this page."]
+    pub fn items(&self) -> &[SoftwareUpdateConfigurationMachineRun] {
+        &self.value
+    }
+}
+#[doc = "Software update configuration properties."]
+#[derive(Clone, Debug, PartialEq, Serialize, Deserialize)]
+pub struct SoftwareUpdateConfigurationProperties {
+    #[doc = "Update specific properties of the software update configuration."]
+    #[serde(rename = "updateConfiguration")]
+    pub update_configuration: UpdateConfiguration,
+    #[doc = "Definition of schedule parameters."]
+    #[serde(rename = "scheduleInfo")]
+    pub schedule_info: SucScheduleProperties,
+    #[doc = "Provisioning state for the software update configuration, which only appears in the response."]
+    #[serde(rename = "provisioningState", default, skip_serializing_if = "Option::is_none")]
+    pub provisioning_state: Option<ProvisioningState>,
+    #[doc = "Error response of an operation failure"]
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub error: Option<ErrorResponse>,
+    #[doc = "Creation time of the resource, which only appears in the response."]
+    #[serde(
+        rename = "creationTime",
+        default,
+        with = "azure_core::date::iso8601::option",
+        skip_serializing_if = "Option::is_none"
+    )]
+    pub creation_time: Option<time::OffsetDateTime>,
+    #[doc = "CreatedBy property, which only appears in the response."]
+    #[serde(rename = "createdBy", default, skip_serializing_if = "Option::is_none")]
+    pub created_by: Option<String>,
+    #[doc = "Last time resource was modified, which only appears in the response."]
+    #[serde(
+        rename = "lastModifiedTime",
+        default,
+        with = "azure_core::date::iso8601::option",
+        skip_serializing_if = "Option::is_none"
+    )]
+    pub last_modified_time: Option<time::OffsetDateTime>,
+    #[doc = "LastModifiedBy property, which only appears in the response."]
+    #[serde(rename = "lastModifiedBy", default, skip_serializing_if = "Option::is_none")]
+    pub last_modified_by: Option<String>,
+    #[doc = "Task properties of the software update configuration."]
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub tasks: Option<SoftwareUpdateConfigurationTasks>,
+}
+impl SoftwareUpdateConfigurationProperties {
+    pub fn new(update_configuration: UpdateConfiguration, schedule_info: SucScheduleProperties) -> Self {
+        Self {
+            update_configuration,
+            schedule_info,
+            provisioning_state: None,
+            error: None,
+            creation_time: None,
+            created_by: None,
+            last_modified_time: None,
+            last_modified_by: None,
+            tasks: None,
+        }
+    }
+}
+#[doc = "Software update configuration Run properties."]
+#[derive(Clone, Debug, PartialEq, Eq, Hash, Serialize, Deserialize, Default)]
+pub struct SoftwareUpdateConfigurationRun {
+    #[doc = "Name of the software update configuration run."]
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub name: Option<String>,
+    #[doc = "Resource Id of the software update configuration run"]
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub id: Option<String>,
+    #[doc = "Software update configuration properties."]
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub properties: Option<SoftwareUpdateConfigurationRunProperties>,
+}
+impl SoftwareUpdateConfigurationRun {
+    pub fn new() -> Self {
+        Self::default()
+    }
+}
+#[doc = "result of listing all software update configuration runs"]
+#[derive(Clone, Debug, PartialEq, Eq, Hash, Serialize, Deserialize, Default)]
+pub struct SoftwareUpdateConfigurationRunListResult {
+    #[doc = "outer object returned when listing all software update configuration runs"]
+    #[serde(
+        default,
+        deserialize_with = "azure_core::util::deserialize_null_as_default",
+        skip_serializing_if = "Vec::is_empty"
+    )]
+    pub value: Vec<SoftwareUpdateConfigurationRun>,
+    #[doc = "link to next page of results."]
+    #[serde(rename = "nextLink", default, skip_serializing_if = "Option::is_none")]
+    pub next_link: Option<String>,
+}
+impl SoftwareUpdateConfigurationRunListResult {
+    pub fn new() -> Self {
+        Self::default()
+    }
+    #[doc = "The items in this page."]
+    pub fn items(&self) -> &[SoftwareUpdateConfigurationRun] {
+        &self.value
+    }
+}
+#[doc = "Software update configuration properties."]
+#[derive(Clone, Debug, PartialEq, Eq, Hash, Serialize, Deserialize, Default)]
+pub struct SoftwareUpdateConfigurationRunProperties {
+    #[doc = "Software update configuration Run Navigation model."]
+    #[serde(rename = "softwareUpdateConfiguration", default, skip_serializing_if = "Option::is_none")]
+    pub software_update_configuration: Option<UpdateConfigurationNavigation>,
+    #[doc = "Status of the software update configuration run."]
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub status: Option<String>,
+    #[doc = "Configured duration for the software update configuration run."]
+    #[serde(rename = "configuredDuration", default, skip_serializing_if = "Option::is_none")]
+    pub configured_duration: Option<String>,
+    #[doc = "Operating system target of the software update configuration triggered this run"]
+    #[serde(rename = "osType", default, skip_serializing_if = "Option::is_none")]
+    pub os_type: Option<String>,
+    #[doc = "Start time of the software update configuration run."]
+    #[serde(
+        rename = "startTime",
+        default,
+        with = "azure_core::date::iso8601::option",
+        skip_serializing_if = "Option::is_none"
+    )]
+    pub start_time: Option<time::OffsetDateTime>,
+    #[doc = "End time of the software update configuration run."]
+    #[serde(
+        rename = "endTime",
+        default,
+        with = "azure_core::date::iso8601::option",
+        skip_serializing_if = "Option::is_none"
+    )]
+    pub end_time: Option<time::OffsetDateTime>,
+    #[doc = "Number of computers in the software update configuration run."]
+    #[serde(rename = "computerCount", default, skip_serializing_if = "Option::is_none")]
+    pub computer_count: Option<i64>,
+    #[doc = "Number of computers with failed status."]
+    #[serde(rename = "failedCount", default, skip_serializing_if = "Option::is_none")]
+    pub failed_count: Option<i64>,
+    #[doc = "Creation time of the resource, which only appears in the response."]
+    #[serde(
+        rename = "creationTime",
+        default,
+        with = "azure_core::date::iso8601::option",
+        skip_serializing_if = "Option::is_none"
+    )]
+    pub creation_time: Option<time::OffsetDateTime>,
+    #[doc = "CreatedBy property, which only appears in the response."]
+    #[serde(rename = "createdBy", default, skip_serializing_if = "Option::is_none")]
+    pub created_by: Option<String>,
+    #[doc = "Last time resource was modified, which only appears in the response."]
+    #[serde(
+        rename = "lastModifiedTime",
+        default,
+        with = "azure_core::date::iso8601::option",
+        skip_serializing_if = "Option::is_none"
+    )]
+    pub last_modified_time: Option<time::OffsetDateTime>,
+    #[doc = "LastModifiedBy property, which only appears in the response."]
+    #[serde(rename = "lastModifiedBy", default, skip_serializing_if = "Option::is_none")]
+    pub last_modified_by: Option<String>,
+    #[doc = "Software update configuration run tasks model."]
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub tasks: Option<SoftwareUpdateConfigurationRunTasks>,
+}
+impl SoftwareUpdateConfigurationRunProperties {
+    pub fn new() -> Self {
+        Self::default()
+    }
+}
+#[doc = "Task properties of the software update configuration."]
+#[derive(Clone, Debug, PartialEq, Eq, Hash, Serialize, Deserialize, Default)]
+pub struct SoftwareUpdateConfigurationRunTaskProperties {
+    #[doc = "The status of the task."]
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub status: Option<String>,
+    #[doc = "The name of the source of the task."]
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub source: Option<String>,
+    #[doc = "The job id of the task."]
+    #[serde(rename = "jobId", default, skip_serializing_if = "Option::is_none")]
+    pub job_id: Option<String>,
+}
+impl SoftwareUpdateConfigurationRunTaskProperties {
+    pub fn new() -> Self {
+        Self::default()
+    }
+}
+#[doc = "Software update configuration run tasks model."]
+#[derive(Clone, Debug, PartialEq, Eq, Hash, Serialize, Deserialize, Default)]
+pub struct SoftwareUpdateConfigurationRunTasks {
+    #[doc = "Task properties of the software update configuration."]
+    #[serde(rename = "preTask", default, skip_serializing_if = "Option::is_none")]
+    pub pre_task: Option<SoftwareUpdateConfigurationRunTaskProperties>,
+    #[doc = "Task properties of the software update configuration."]
+    #[serde(rename = "postTask", default, skip_serializing_if = "Option::is_none")]
+    pub post_task: Option<SoftwareUpdateConfigurationRunTaskProperties>,
+}
+impl SoftwareUpdateConfigurationRunTasks {
+    pub fn new() -> Self {
+        Self::default()
+    }
+}
+#[doc = "Task properties of the software update configuration."]
+#[derive(Clone, Debug, PartialEq, Serialize, Deserialize, Default)]
+pub struct SoftwareUpdateConfigurationTasks {
+    #[doc = "Task properties of the software update configuration."]
+    #[serde(rename = "preTask", default, skip_serializing_if = "Option::is_none")]
+    pub pre_task: Option<TaskProperties>,
+    #[doc = "Task properties of the software update configuration."]
+    #[serde(rename = "postTask", default, skip_serializing_if = "Option::is_none")]
+    pub post_task: Option<TaskProperties>,
+}
+impl SoftwareUpdateConfigurationTasks {
+    pub fn new() -> Self {
+        Self::default()
+    }
+}
+#[doc = "Definition of the source control."]
+#[derive(Clone, Debug, PartialEq, Eq, Hash, Serialize, Deserialize, Default)]
+pub struct SourceControl {
+    #[serde(flatten)]
+    pub proxy_resource: ProxyResource,
+    #[doc = "Definition of the source control properties"]
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub properties: Option<SourceControlProperties>,
+}
+impl SourceControl {
+    pub fn new() -> Self {
+        Self::default()
+    }
+}
+#[doc = "The parameters supplied to the create or update source control operation."]
+#[derive(Clone, Debug, PartialEq, Eq, Hash, Serialize, Deserialize)]
+pub struct SourceControlCreateOrUpdateParameters {
+    #[doc = "The properties of the create source control operation."]
+    pub properties: SourceControlCreateOrUpdateProperties,
+}
+impl SourceControlCreateOrUpdateParameters {
+    pub fn new(properties: SourceControlCreateOrUpdateProperties) -> Self {
+        Self { properties }
+    }
+}
+#[doc = "The properties of the create source control operation."]
+#[derive(Clone, Debug, PartialEq, Eq, Hash, Serialize, Deserialize, Default)]
+pub struct SourceControlCreateOrUpdateProperties {
+    #[doc = "The repo url of the source control."]
+    #[serde(rename = "repoUrl", default, skip_serializing_if = "Option::is_none")]
+    pub repo_url: Option<String>,
+    #[doc = "The repo branch of the source control. Include branch as empty string for VsoTfvc."]
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub branch: Option<String>,
+    #[doc = "The folder path of the source control. Path must be relative."]
+    #[serde(rename = "folderPath", default, skip_serializing_if = "Option::is_none")]
+    pub folder_path: Option<String>,
+    #[doc = "The auto async of the source control. Default is false."]
+    #[serde(rename = "autoSync", default, skip_serializing_if = "Option::is_none")]
+    pub auto_sync: Option<bool>,
+    #[doc = "The auto publish of the source control. Default is true."]
+    #[serde(rename = "publishRunbook", default, skip_serializing_if = "Option::is_none")]
+    pub publish_runbook: Option<bool>,
+    #[doc = "The source type. Must be one of VsoGit, VsoTfvc, GitHub, case sensitive."]
+    #[serde(rename = "sourceType", default, skip_serializing_if = "Option::is_none")]
+    pub source_type: Option<source_control_create_or_update_properties::SourceType>,
+    #[serde(rename = "securityToken", default, skip_serializing_if = "Option::is_none")]
+    pub security_token: Option<SourceControlSecurityTokenProperties>,
+    #[doc = "The user description of the source control."]
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub description: Option<String>,
+}
+impl SourceControlCreateOrUpdateProperties {
+    pub fn new() -> Self {
+        Self::default()
+    }
+}
+pub mod source_control_create_or_update_properties {
+    use super::*;
+    #[doc = "The source type. Must be one of VsoGit, VsoTfvc, GitHub, case sensitive."]
+    #[derive(Clone, Debug, PartialEq, Eq, Hash, Serialize, Deserialize)]
+    #[serde(remote = "SourceType")]
+    pub enum SourceType {
+        VsoGit,
+        VsoTfvc,
+        GitHub,
+        #[serde(skip_deserializing)]
+        UnknownValue(String),
+    }
+    impl FromStr for SourceType {
+        type Err = value::Error;
+        fn from_str(s: &str) -> std::result::Result<Self, Self::Err> {
+            Self::deserialize(s.into_deserializer())
+        }
+    }
+    impl<'de> Deserialize<'de> for SourceType {
+        fn deserialize<D>(deserializer: D) -> std::result::Result<Self, D::Error>
+        where
+            D: Deserializer<'de>,
+        {
+            let s = String::deserialize(deserializer)?;
+            let deserialized = Self::from_str(&s).unwrap_or(Self::UnknownValue(s));
+            Ok(deserialized)
+        }
+    }
+    impl Serialize for SourceType {
+        fn serialize<S>(&self, serializer: S) -> std::result::Result<S::Ok, S::Error>
+        where
+            S: Serializer,
+        {
+            match self {
+                Self::VsoGit => serializer.serialize_unit_variant("SourceType", 0u32, "VsoGit"),
+                Self::VsoTfvc => serializer.serialize_unit_variant("SourceType", 1u32, "VsoTfvc"),
+                Self::GitHub => serializer.serialize_unit_variant("SourceType", 2u32, "GitHub"),
+                Self::UnknownValue(s) => serializer.serialize_str(s.as_str()),
+            }
+        }
+    }
+    impl SourceType {
+        #[doc = "The value as it is sent on the wire."]
+        pub fn as_str(&self) -> &str {
+            match self {
+                Self::VsoGit => "VsoGit",
+                Self::VsoTfvc => "VsoTfvc",
+                Self::GitHub => "GitHub",
+                Self::UnknownValue(s) => s.as_str(),
+            }
+        }
+    }
+    impl std::fmt::Display for SourceType {
+        fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+            f.write_str(self.as_str())
+        }
+    }
+}
+#[doc = "The response model for the list source controls operation."]
+#[derive(Clone, Debug, PartialEq, Eq, Hash, Serialize, Deserialize, Default)]
+pub struct SourceControlListResult {
+    #[doc = "The list of source controls."]
+    #[serde(
+        default,
+        deserialize_with = "azure_core::util::deserialize_null_as_default",
+        skip_serializing_if = "Vec::is_empty"
+    )]
+    pub value: Vec<SourceControl>,
+    #[doc = "The next link."]
+    #[serde(rename = "nextLink", default, skip_serializing_if = "Option::is_none")]
+    pub next_link: Option<String>,
+}
+impl azure_core::Continuable for SourceControlListResult {
+    type Continuation = String;
+    fn continuation(&self) -> Option<Self::Continuation> {
+        self.next_link.clone()
+    }
+}
+impl SourceControlListResult {
+    pub fn new() -> Self {
+        Self::default()
+    }
+    #[doc = "The items in this page."]
+    pub fn items(&self) -> &[SourceControl] {
+        &self.value
+    }
+}
+#[doc = "Definition of the source control properties"]
+#[derive(Clone, Debug, PartialEq, Eq, Hash, Serialize, Deserialize, Default)]
 pub struct SourceControlProperties {
     #[doc = "The repo url of the source control."]
     #[serde(rename = "repoUrl", default, skip_serializing_if = "Option::is_none")]
@@ -5198,10 +6527,20 @@ pub struct SourceControlProperties {
     #[serde(default, skip_serializing_if = "Option::is_none")]
     pub description: Option<String>,
     #[doc = "The creation time."]
-    #[serde(rename = "creationTime", default, with = "azure_core::date::rfc3339::option")]
+    #[serde(
+        rename = "creationTime",
+        default,
+        with = "azure_core::date::iso8601::option",
+        skip_serializing_if = "Option::is_none"
+    )]
     pub creation_time: Option<time::OffsetDateTime>,
     #[doc = "The last modified time."]
-    #[serde(rename = "lastModifiedTime", default, with = "azure_core::date::rfc3339::option")]
+    #[serde(
+        rename = "lastModifiedTime",
+        default,
+        with = "azure_core::date::iso8601::option",
+        skip_serializing_if = "Option::is_none"
+    )]
     pub last_modified_time: Option<time::OffsetDateTime>,
 }
 impl SourceControlProperties {
@@ -5212,7 +6551,7 @@ impl SourceControlProperties {
 pub mod source_control_properties {
     use super::*;
     #[doc = "The source type. Must be one of VsoGit, VsoTfvc, GitHub."]
-    #[derive(Clone, Debug, PartialEq, Serialize, Deserialize)]
+    #[derive(Clone, Debug, PartialEq, Eq, Hash, Serialize, Deserialize)]
     #[serde(remote = "SourceType")]
     pub enum SourceType {
         VsoGit,
@@ -5250,8 +6589,24 @@ pub mod source_control_properties {
             }
         }
     }
+    impl SourceType {
+        #[doc = "The value as it is sent on the wire."]
+        pub fn as_str(&self) -> &str {
+            match self {
+                Self::VsoGit => "VsoGit",
+                Self::VsoTfvc => "VsoTfvc",
+                Self::GitHub => "GitHub",
+                Self::UnknownValue(s) => s.as_str(),
+            }
+        }
+    }
+    impl std::fmt::Display for SourceType {
+        fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+            f.write_str(self.as_str())
+        }
+    }
 }
-#[derive(Clone, Debug, PartialEq, Serialize, Deserialize, Default)]
+#[derive(Clone, Debug, PartialEq, Eq, Hash, Serialize, Deserialize, Default)]
 pub struct SourceControlSecurityTokenProperties {
     #[doc = "The access token."]
     #[serde(rename = "accessToken", default, skip_serializing_if = "Option::is_none")]
@@ -5271,7 +6626,7 @@ impl SourceControlSecurityTokenProperties {
 pub mod source_control_security_token_properties {
     use super::*;
     #[doc = "The token type. Must be either PersonalAccessToken or Oauth."]
-    #[derive(Clone, Debug, PartialEq, Serialize, Deserialize)]
+    #[derive(Clone, Debug, PartialEq, Eq, Hash, Serialize, Deserialize)]
     #[serde(remote = "TokenType")]
     pub enum TokenType {
         PersonalAccessToken,
@@ -5307,9 +6662,24 @@ pub mod source_control_security_token_properties {
             }
         }
     }
+    impl TokenType {
+        #[doc = "The value as it is sent on the wire."]
+        pub fn as_str(&self) -> &str {
+            match self {
+                Self::PersonalAccessToken => "PersonalAccessToken",
+                Self::Oauth => "Oauth",
+                Self::UnknownValue(s) => s.as_str(),
+            }
+        }
+    }
+    impl std::fmt::Display for TokenType {
+        fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+            f.write_str(self.as_str())
+        }
+    }
 }
 #[doc = "Definition of the source control sync job."]
-#[derive(Clone, Debug, PartialEq, Serialize, Deserialize, Default)]
+#[derive(Clone, Debug, PartialEq, Eq, Hash, Serialize, Deserialize, Default)]
 pub struct SourceControlSyncJob {
     #[doc = "Resource name."]
     #[serde(default, skip_serializing_if = "Option::is_none")]
@@ -5330,7 +6700,7 @@ impl SourceControlSyncJob {
     }
 }
 #[doc = "Definition of the source control sync job."]
-#[derive(Clone, Debug, PartialEq, Serialize, Deserialize, Default)]
+#[derive(Clone, Debug, PartialEq, Eq, Hash, Serialize, Deserialize, Default)]
 pub struct SourceControlSyncJobById {
     #[doc = "The id of the job."]
     #[serde(default, skip_serializing_if = "Option::is_none")]
@@ -5345,22 +6715,37 @@ impl SourceControlSyncJobById {
     }
 }
 #[doc = "Definition of source control sync job properties."]
-#[derive(Clone, Debug, PartialEq, Serialize, Deserialize, Default)]
+#[derive(Clone, Debug, PartialEq, Eq, Hash, Serialize, Deserialize, Default)]
 pub struct SourceControlSyncJobByIdProperties {
     #[doc = "The source control sync job id."]
     #[serde(rename = "sourceControlSyncJobId", default, skip_serializing_if = "Option::is_none")]
     pub source_control_sync_job_id: Option<String>,
     #[doc = "The creation time of the job."]
-    #[serde(rename = "creationTime", default, with = "azure_core::date::rfc3339::option")]
+    #[serde(
+        rename = "creationTime",
+        default,
+        with = "azure_core::date::iso8601::option",
+        skip_serializing_if = "Option::is_none"
+    )]
     pub creation_time: Option<time::OffsetDateTime>,
     #[doc = "The provisioning state of the job."]
     #[serde(rename = "provisioningState", default, skip_serializing_if = "Option::is_none")]
     pub provisioning_state: Option<source_control_sync_job_by_id_properties::ProvisioningState>,
     #[doc = "The start time of the job."]
-    #[serde(rename = "startTime", default, with = "azure_core::date::rfc3339::option")]
+    #[serde(
+        rename = "startTime",
+        default,
+        with = "azure_core::date::iso8601::option",
+        skip_serializing_if = "Option::is_none"
+    )]
     pub start_time: Option<time::OffsetDateTime>,
     #[doc = "The end time of the job."]
-    #[serde(rename = "endTime", default, with = "azure_core::date::rfc3339::option")]
+    #[serde(
+        rename = "endTime",
+        default,
+        with = "azure_core::date::iso8601::option",
+        skip_serializing_if = "Option::is_none"
+    )]
     pub end_time: Option<time::OffsetDateTime>,
     #[doc = "The sync type."]
     #[serde(rename = "syncType", default, skip_serializing_if = "Option::is_none")]
@@ -5374,164 +6759,10 @@ impl SourceControlSyncJobByIdProperties {
         Self::default()
     }
 }
-pub mod source_control_sync_job_by_id_properties {
-    use super::*;
-    #[doc = "The provisioning state of the job."]
-    #[derive(Clone, Debug, PartialEq, Serialize, Deserialize)]
-    #[serde(remote = "ProvisioningState")]
-    pub enum ProvisioningState {
-        Completed,
-        Failed,
-        Running,
-        #[serde(skip_deserializing)]
-        UnknownValue(String),
-    }
-    impl FromStr for ProvisioningState {
-        type Err = value::Error;
-        fn from_str(s: &str) -> std::result::Result<Self, Self::Err> {
-            Self::deserialize(s.into_deserializer())
-        }
-    }
-    impl<'de> Deserialize<'de> for ProvisioningState {
-        fn deserialize<D>(deserializer: D) -> std::result::Result<Self, D::Error>
-        where
-            D: Deserializer<'de>,
-        {
-            let s = String::deserialize(deserializer)?;
-            let deserialized = Self::from_str(&s).unwrap_or(Self::UnknownValue(s));
-            Ok(deserialized)
-        }
-    }
-    impl Serialize for ProvisioningState {
-        fn serialize<S>(&self, serializer: S) -> std::result::Result<S::Ok, S::Error>
-        where
-            S: Serializer,
-        {
-            match self {
-                Self::Completed => serializer.serialize_unit_variant("ProvisioningState", 0u32, "Completed"),
-                Self::Failed => serializer.serialize_unit_variant("ProvisioningState", 1u32, "Failed"),
-                Self::Running => serializer.serialize_unit_variant("ProvisioningState", 2u32, "Running"),
-                Self::UnknownValue(s) => serializer.serialize_str(s.as_str()),
-            }
-        }
-    }
-    #[doc = "The sync type."]
-    #[derive(Clone, Debug, PartialEq, Serialize, Deserialize)]
-    #[serde(remote = "SyncType")]
-    pub enum SyncType {
-        PartialSync,
-        FullSync,
-        #[serde(skip_deserializing)]
-        UnknownValue(String),
-    }
-    impl FromStr for SyncType {
-        type Err = value::Error;
-        fn from_str(s: &str) -> std::result::Result<Self, Self::Err> {
-            Self::deserialize(s.into_deserializer())
-        }
-    }
-    impl<'de> Deserialize<'de> for SyncType {
-        fn deserialize<D>(deserializer: D) -> std::result::Result<Self, D::Error>
-        where
-            D: Deserializer<'de>,
-        {
-            let s = String::deserialize(deserializer)?;
-            let deserialized = Self::from_str(&s).unwrap_or(Self::UnknownValue(s));
-            Ok(deserialized)
-        }
-    }
-    impl Serialize for SyncType {
-        fn serialize<S>(&self, serializer: S) -> std::result::Result<S::Ok, S::Error>
-        where
-            S: Serializer,
-        {
-            match self {
-                Self::PartialSync => serializer.serialize_unit_variant("SyncType", 0u32, "PartialSync"),
-                Self::FullSync => serializer.serialize_unit_variant("SyncType", 1u32, "FullSync"),
-                Self::UnknownValue(s) => serializer.serialize_str(s.as_str()),
-            }
-        }
-    }
-}
-#[doc = "The parameters supplied to the create source control sync job operation."]
-#[derive(Clone, Debug, PartialEq, Serialize, Deserialize)]
-pub struct SourceControlSyncJobCreateParameters {
-    #[doc = "Definition of create source control sync job properties."]
-    pub properties: SourceControlSyncJobCreateProperties,
-}
-impl SourceControlSyncJobCreateParameters {
-    pub fn new(properties: SourceControlSyncJobCreateProperties) -> Self {
-        Self { properties }
-    }
-}
-#[doc = "Definition of create source control sync job properties."]
-#[derive(Clone, Debug, PartialEq, Serialize, Deserialize)]
-pub struct SourceControlSyncJobCreateProperties {
-    #[doc = "The commit id of the source control sync job. If not syncing to a commitId, enter an empty string."]
-    #[serde(rename = "commitId")]
-    pub commit_id: String,
-}
-impl SourceControlSyncJobCreateProperties {
-    pub fn new(commit_id: String) -> Self {
-        Self { commit_id }
-    }
-}
-#[doc = "The response model for the list source control sync jobs operation."]
-#[derive(Clone, Debug, PartialEq, Serialize, Deserialize, Default)]
-pub struct SourceControlSyncJobListResult {
-    #[doc = "The list of source control sync jobs."]
-    #[serde(
-        default,
-        deserialize_with = "azure_core::util::deserialize_null_as_default",
-        skip_serializing_if = "Vec::is_empty"
-    )]
-    pub value: Vec<SourceControlSyncJob>,
-    #[doc = "The next link."]
-    #[serde(rename = "nextLink", default, skip_serializing_if = "Option::is_none")]
-    pub next_link: Option<String>,
-}
-impl azure_core::Continuable for SourceControlSyncJobListResult {
-    type Continuation = String;
-    fn continuation(&self) -> Option<Self::Continuation> {
-        self.next_link.clone()
-    }
-}
-impl SourceControlSyncJobListResult {
-    pub fn new() -> Self {
-        Self::default()
-    }
-}
-#[doc = "Definition of source control sync job properties."]
-#[derive(Clone, Debug, PartialEq, Serialize, Deserialize, Default)]
-pub struct SourceControlSyncJobProperties {
-    #[doc = "The source control sync job id."]
-    #[serde(rename = "sourceControlSyncJobId", default, skip_serializing_if = "Option::is_none")]
-    pub source_control_sync_job_id: Option<String>,
-    #[doc = "The creation time of the job."]
-    #[serde(rename = "creationTime", default, with = "azure_core::date::rfc3339::option")]
-    pub creation_time: Option<time::OffsetDateTime>,
-    #[doc = "The provisioning state of the job."]
-    #[serde(rename = "provisioningState", default, skip_serializing_if = "Option::is_none")]
-    pub provisioning_state: Option<source_control_sync_job_properties::ProvisioningState>,
-    #[doc = "The start time of the job."]
-    #[serde(rename = "startTime", default, with = "azure_core::date::rfc3339::option")]
-    pub start_time: Option<time::OffsetDateTime>,
-    #[doc = "The end time of the job."]
-    #[serde(rename = "endTime", default, with = "azure_core::date::rfc3339::option")]
-    pub end_time: Option<time::OffsetDateTime>,
-    #[doc = "The sync type."]
-    #[serde(rename = "syncType", default, skip_serializing_if = "Option::is_none")]
-    pub sync_type: Option<source_control_sync_job_properties::SyncType>,
-}
-impl SourceControlSyncJobProperties {
-    pub fn new() -> Self {
-        Self::default()
-    }
-}
-pub mod source_control_sync_job_properties {
+pub mod source_control_sync_job_by_id_properties {
     use super::*;
     #[doc = "The provisioning state of the job."]
-    #[derive(Clone, Debug, PartialEq, Serialize, Deserialize)]
+    #[derive(Clone, Debug, PartialEq, Eq, Hash, Serialize, Deserialize)]
     #[serde(remote = "ProvisioningState")]
     pub enum ProvisioningState {
         Completed,
@@ -5569,8 +6800,24 @@ pub mod source_control_sync_job_properties {
             }
         }
     }
+    impl ProvisioningState {
+        #[doc = "The value as it is sent on the wire."]
+        pub fn as_str(&self) -> &str {
+            match self {
+                Self::Completed => "Completed",
+                Self::Failed => "Failed",
+                Self::Running => "Running",
+                Self::UnknownValue(s) => s.as_str(),
+            }
+        }
+    }
+    impl std::fmt::Display for ProvisioningState {
+        fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+            f.write_str(self.as_str())
+        }
+    }
     #[doc = "The sync type."]
-    #[derive(Clone, Debug, PartialEq, Serialize, Deserialize)]
+    #[derive(Clone, Debug, PartialEq, Eq, Hash, Serialize, Deserialize)]
     #[serde(remote = "SyncType")]
     pub enum SyncType {
         PartialSync,
@@ -5606,1409 +6853,1708 @@ pub mod source_control_sync_job_properties {
             }
         }
     }
-}
-#[doc = "Definition of the source control sync job stream."]
-#[derive(Clone, Debug, PartialEq, Serialize, Deserialize, Default)]
-pub struct SourceControlSyncJobStream {
-    #[doc = "Resource id."]
-    #[serde(default, skip_serializing_if = "Option::is_none")]
-    pub id: Option<String>,
-    #[doc = "Definition of source control sync job stream properties."]
-    #[serde(default, skip_serializing_if = "Option::is_none")]
-    pub properties: Option<SourceControlSyncJobStreamProperties>,
-}
-impl SourceControlSyncJobStream {
-    pub fn new() -> Self {
-        Self::default()
-    }
-}
-#[doc = "Definition of the source control sync job stream by id."]
-#[derive(Clone, Debug, PartialEq, Serialize, Deserialize, Default)]
-pub struct SourceControlSyncJobStreamById {
-    #[doc = "Resource id."]
-    #[serde(default, skip_serializing_if = "Option::is_none")]
-    pub id: Option<String>,
-    #[doc = "Definition of source control sync job stream by id properties."]
-    #[serde(default, skip_serializing_if = "Option::is_none")]
-    pub properties: Option<SourceControlSyncJobStreamByIdProperties>,
-}
-impl SourceControlSyncJobStreamById {
-    pub fn new() -> Self {
-        Self::default()
-    }
-}
-#[doc = "Definition of source control sync job stream by id properties."]
-#[derive(Clone, Debug, PartialEq, Serialize, Deserialize, Default)]
-pub struct SourceControlSyncJobStreamByIdProperties {
-    #[doc = "The sync job stream id."]
-    #[serde(rename = "sourceControlSyncJobStreamId", default, skip_serializing_if = "Option::is_none")]
-    pub source_control_sync_job_stream_id: Option<String>,
-    #[doc = "The summary of the sync job stream."]
-    #[serde(default, skip_serializing_if = "Option::is_none")]
-    pub summary: Option<String>,
-    #[doc = "The time of the sync job stream."]
-    #[serde(default, with = "azure_core::date::rfc3339::option")]
-    pub time: Option<time::OffsetDateTime>,
-    #[doc = "The type of the sync job stream."]
-    #[serde(rename = "streamType", default, skip_serializing_if = "Option::is_none")]
-    pub stream_type: Option<source_control_sync_job_stream_by_id_properties::StreamType>,
-    #[doc = "The text of the sync job stream."]
-    #[serde(rename = "streamText", default, skip_serializing_if = "Option::is_none")]
-    pub stream_text: Option<String>,
-    #[doc = "The values of the job stream."]
-    #[serde(default, skip_serializing_if = "Option::is_none")]
-    pub value: Option<serde_json::Value>,
-}
-impl SourceControlSyncJobStreamByIdProperties {
-    pub fn new() -> Self {
-        Self::default()
-    }
-}
-pub mod source_control_sync_job_stream_by_id_properties {
-    use super::*;
-    #[doc = "The type of the sync job stream."]
-    #[derive(Clone, Debug, PartialEq, Serialize, Deserialize)]
-    #[serde(remote = "StreamType")]
-    pub enum StreamType {
-        Error,
-        Output,
-        #[serde(skip_deserializing)]
-        UnknownValue(String),
-    }
-    impl FromStr for StreamType {
-        type Err = value::Error;
-        fn from_str(s: &str) -> std::result::Result<Self, Self::Err> {
-            Self::deserialize(s.into_deserializer())
-        }
-    }
-    impl<'de> Deserialize<'de> for StreamType {
-        fn deserialize<D>(deserializer: D) -> std::result::Result<Self, D::Error>
-        where
-            D: Deserializer<'de>,
-        {
-            let s = String::deserialize(deserializer)?;
-            let deserialized = Self::from_str(&s).unwrap_or(Self::UnknownValue(s));
-            Ok(deserialized)
-        }
-    }
-    impl Serialize for StreamType {
-        fn serialize<S>(&self, serializer: S) -> std::result::Result<S::Ok, S::Error>
-        where
-            S: Serializer,
-        {
+    impl SyncType {
+        #[doc = "The value as it is sent on the wire."]
+        pub fn as_str(&self) -> &str {
             match self {
-                Self::Error => serializer.serialize_unit_variant("StreamType", 0u32, "Error"),
-                Self::Output => serializer.serialize_unit_variant("StreamType", 1u32, "Output"),
-                Self::UnknownValue(s) => serializer.serialize_str(s.as_str()),
+                Self::PartialSync => "PartialSync",
+                Self::FullSync => "FullSync",
+                Self::UnknownValue(s) => s.as_str(),
             }
         }
     }
-}
-#[doc = "Definition of source control sync job stream properties."]
-#[derive(Clone, Debug, PartialEq, Serialize, Deserialize, Default)]
-pub struct SourceControlSyncJobStreamProperties {
-    #[doc = "The sync job stream id."]
-    #[serde(rename = "sourceControlSyncJobStreamId", default, skip_serializing_if = "Option::is_none")]
-    pub source_control_sync_job_stream_id: Option<String>,
-    #[doc = "The summary of the sync job stream."]
-    #[serde(default, skip_serializing_if = "Option::is_none")]
-    pub summary: Option<String>,
-    #[doc = "The time of the sync job stream."]
-    #[serde(default, with = "azure_core::date::rfc3339::option")]
-    pub time: Option<time::OffsetDateTime>,
-    #[doc = "The type of the sync job stream."]
-    #[serde(rename = "streamType", default, skip_serializing_if = "Option::is_none")]
-    pub stream_type: Option<source_control_sync_job_stream_properties::StreamType>,
-}
-impl SourceControlSyncJobStreamProperties {
-    pub fn new() -> Self {
-        Self::default()
-    }
-}
-pub mod source_control_sync_job_stream_properties {
-    use super::*;
-    #[doc = "The type of the sync job stream."]
-    #[derive(Clone, Debug, PartialEq, Serialize, Deserialize)]
-    #[serde(remote = "StreamType")]
-    pub enum StreamType {
-        Error,
-        Output,
-        #[serde(skip_deserializing)]
-        UnknownValue(String),
-    }
-    impl FromStr for StreamType {
-        type Err = value::Error;
-        fn from_str(s: &str) -> std::result::Result<Self, Self::Err> {
-            Self::deserialize(s.into_deserializer())
-        }
-    }
-    impl<'de> Deserialize<'de> for StreamType {
-        fn deserialize<D>(deserializer: D) -> std::result::Result<Self, D::Error>
-        where
-            D: Deserializer<'de>,
-        {
-            let s = String::deserialize(deserializer)?;
-            let deserialized = Self::from_str(&s).unwrap_or(Self::UnknownValue(s));
-            Ok(deserialized)
-        }
-    }
-    impl Serialize for StreamType {
-        fn serialize<S>(&self, serializer: S) -> std::result::Result<S::Ok, S::Error>
-        where
-            S: Serializer,
-        {
-            match self {
-                Self::Error => serializer.serialize_unit_variant("StreamType", 0u32, "Error"),
-                Self::Output => serializer.serialize_unit_variant("StreamType", 1u32, "Output"),
-                Self::UnknownValue(s) => serializer.serialize_str(s.as_str()),
-            }
+    impl std::fmt::Display for SyncType {
+        fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+            f.write_str(self.as_str())
         }
     }
 }
-#[doc = "The response model for the list source control sync job streams operation."]
-#[derive(Clone, Debug, PartialEq, Serialize, Deserialize, Default)]
-pub struct SourceControlSyncJobStreamsListBySyncJob {
-    #[doc = "The list of source control sync job streams."]
-    #[serde(
-        default,
-        deserialize_with = "azure_core::util::deserialize_null_as_default",
-        skip_serializing_if = "Vec::is_empty"
-    )]
-    pub value: Vec<SourceControlSyncJobStream>,
-    #[doc = "The next link."]
-    #[serde(rename = "nextLink", default, skip_serializing_if = "Option::is_none")]
-    pub next_link: Option<String>,
-}
-impl azure_core::Continuable for SourceControlSyncJobStreamsListBySyncJob {
-    type Continuation = String;
-    fn continuation(&self) -> Option<Self::Continuation> {
-        self.next_link.clone()
-    }
-}
-impl SourceControlSyncJobStreamsListBySyncJob {
-    pub fn new() -> Self {
-        Self::default()
-    }
-}
-#[doc = "The parameters supplied to the update source control operation."]
-#[derive(Clone, Debug, PartialEq, Serialize, Deserialize, Default)]
-pub struct SourceControlUpdateParameters {
-    #[doc = "The properties of the update source control"]
-    #[serde(default, skip_serializing_if = "Option::is_none")]
-    pub properties: Option<SourceControlUpdateProperties>,
-}
-impl SourceControlUpdateParameters {
-    pub fn new() -> Self {
-        Self::default()
-    }
-}
-#[doc = "The properties of the update source control"]
-#[derive(Clone, Debug, PartialEq, Serialize, Deserialize, Default)]
-pub struct SourceControlUpdateProperties {
-    #[doc = "The repo branch of the source control."]
-    #[serde(default, skip_serializing_if = "Option::is_none")]
-    pub branch: Option<String>,
-    #[doc = "The folder path of the source control. Path must be relative."]
-    #[serde(rename = "folderPath", default, skip_serializing_if = "Option::is_none")]
-    pub folder_path: Option<String>,
-    #[doc = "The auto sync of the source control. Default is false."]
-    #[serde(rename = "autoSync", default, skip_serializing_if = "Option::is_none")]
-    pub auto_sync: Option<bool>,
-    #[doc = "The auto publish of the source control. Default is true."]
-    #[serde(rename = "publishRunbook", default, skip_serializing_if = "Option::is_none")]
-    pub publish_runbook: Option<bool>,
-    #[serde(rename = "securityToken", default, skip_serializing_if = "Option::is_none")]
-    pub security_token: Option<SourceControlSecurityTokenProperties>,
-    #[doc = "The user description of the source control."]
-    #[serde(default, skip_serializing_if = "Option::is_none")]
-    pub description: Option<String>,
+#[doc = "The parameters supplied to the create source control sync job operation."]
+#[derive(Clone, Debug, PartialEq, Eq, Hash, Serialize, Deserialize)]
+pub struct SourceControlSyncJobCreateParameters {
+    #[doc = "Definition of create source control sync job properties."]
+    pub properties: SourceControlSyncJobCreateProperties,
 }
-impl SourceControlUpdateProperties {
-    pub fn new() -> Self {
-        Self::default()
+impl SourceControlSyncJobCreateParameters {
+    pub fn new(properties: SourceControlSyncJobCreateProperties) -> Self {
+        Self { properties }
     }
 }
-#[doc = "Definition of the statistic."]
-#[derive(Clone, Debug, PartialEq, Serialize, Deserialize, Default)]
-pub struct Statistics {
-    #[doc = "Gets the property value of the statistic."]
-    #[serde(rename = "counterProperty", default, skip_serializing_if = "Option::is_none")]
-    pub counter_property: Option<String>,
-    #[doc = "Gets the value of the statistic."]
-    #[serde(rename = "counterValue", default, skip_serializing_if = "Option::is_none")]
-    pub counter_value: Option<i64>,
-    #[doc = "Gets the startTime of the statistic."]
-    #[serde(rename = "startTime", default, with = "azure_core::date::rfc3339::option")]
-    pub start_time: Option<time::OffsetDateTime>,
-    #[doc = "Gets the endTime of the statistic."]
-    #[serde(rename = "endTime", default, with = "azure_core::date::rfc3339::option")]
-    pub end_time: Option<time::OffsetDateTime>,
-    #[doc = "Gets the id."]
-    #[serde(default, skip_serializing_if = "Option::is_none")]
-    pub id: Option<String>,
+#[doc = "Definition of create source control sync job properties."]
+#[derive(Clone, Debug, PartialEq, Eq, Hash, Serialize, Deserialize)]
+pub struct SourceControlSyncJobCreateProperties {
+    #[doc = "The commit id of the source control sync job. If not syncing to a commitId, enter an empty string."]
+    #[serde(rename = "commitId")]
+    pub commit_id: String,
 }
-impl Statistics {
-    pub fn new() -> Self {
-        Self::default()
+impl SourceControlSyncJobCreateProperties {
+    pub fn new(commit_id: String) -> Self {
+        Self { commit_id }
     }
 }
-#[doc = "The response model for the list statistics operation."]
-#[derive(Clone, Debug, PartialEq, Serialize, Deserialize, Default)]
-pub struct StatisticsListResult {
-    #[doc = "Gets or sets a list of statistics."]
+#[doc = "The response model for the list source control sync jobs operation."]
+#[derive(Clone, Debug, PartialEq, Eq, Hash, Serialize, Deserialize, Default)]
+pub struct SourceControlSyncJobListResult {
+    #[doc = "The list of source control sync jobs."]
     #[serde(
         default,
         deserialize_with = "azure_core::util::deserialize_null_as_default",
         skip_serializing_if = "Vec::is_empty"
     )]
-    pub value: Vec<Statistics>,
+    pub value: Vec<SourceControlSyncJob>,
+    #[doc = "The next link."]
+    #[serde(rename = "nextLink", default, skip_serializing_if = "Option::is_none")]
+    pub next_link: Option<String>,
 }
-impl azure_core::Continuable for StatisticsListResult {
+impl azure_core::Continuable for SourceControlSyncJobListResult {
     type Continuation = String;
     fn continuation(&self) -> Option<Self::Continuation> {
-        None
-    }
-}
-impl StatisticsListResult {
-    pub fn new() -> Self {
-        Self::default()
+        self.next_link.clone()
     }
 }
-#[doc = "Tag filter information for the VM."]
-#[derive(Clone, Debug, PartialEq, Serialize, Deserialize, Default)]
-pub struct TagSettingsProperties {
-    #[doc = "Dictionary of tags with its list of values."]
-    #[serde(default, skip_serializing_if = "Option::is_none")]
-    pub tags: Option<serde_json::Value>,
-    #[doc = "Filter VMs by Any or All specified tags."]
-    #[serde(rename = "filterOperator", default, skip_serializing_if = "Option::is_none")]
-    pub filter_operator: Option<tag_settings_properties::FilterOperator>,
-}
-impl TagSettingsProperties {
+impl SourceControlSyncJobListResult {
     pub fn new() -> Self {
         Self::default()
     }
-}
-pub mod tag_settings_properties {
-    use super::*;
-    #[doc = "Filter VMs by Any or All specified tags."]
-    #[derive(Clone, Debug, PartialEq, Serialize, Deserialize)]
-    pub enum FilterOperator {
-        All,
-        Any,
+    #[doc = "The items in this page."]
+    pub fn items(&self) -> &[SourceControlSyncJob] {
+        &self.value
     }
 }
-#[doc = "Group specific to the update configuration."]
-#[derive(Clone, Debug, PartialEq, Serialize, Deserialize, Default)]
-pub struct TargetProperties {
-    #[doc = "List of Azure queries in the software update configuration."]
+#[doc = "Definition of source control sync job properties."]
+#[derive(Clone, Debug, PartialEq, Eq, Hash, Serialize, Deserialize, Default)]
+pub struct SourceControlSyncJobProperties {
+    #[doc = "The source control sync job id."]
+    #[serde(rename = "sourceControlSyncJobId", default, skip_serializing_if = "Option::is_none")]
+    pub source_control_sync_job_id: Option<String>,
+    #[doc = "The creation time of the job."]
     #[serde(
-        rename = "azureQueries",
+        rename = "creationTime",
         default,
-        deserialize_with = "azure_core::util::deserialize_null_as_default",
-        skip_serializing_if = "Vec::is_empty"
+        with = "azure_core::date::iso8601::option",
+        skip_serializing_if = "Option::is_none"
     )]
-    pub azure_queries: Vec<AzureQueryProperties>,
-    #[doc = "List of non Azure queries in the software update configuration."]
+    pub creation_time: Option<time::OffsetDateTime>,
+    #[doc = "The provisioning state of the job."]
+    #[serde(rename = "provisioningState", default, skip_serializing_if = "Option::is_none")]
+    pub provisioning_state: Option<source_control_sync_job_properties::ProvisioningState>,
+    #[doc = "The start time of the job."]
     #[serde(
-        rename = "nonAzureQueries",
+        rename = "startTime",
         default,
-        deserialize_with = "azure_core::util::deserialize_null_as_default",
-        skip_serializing_if = "Vec::is_empty"
+        with = "azure_core::date::iso8601::option",
+        skip_serializing_if = "Option::is_none"
     )]
-    pub non_azure_queries: Vec<NonAzureQueryProperties>,
-}
-impl TargetProperties {
-    pub fn new() -> Self {
-        Self::default()
-    }
-}
-#[doc = "Definition of the test job."]
-#[derive(Clone, Debug, PartialEq, Serialize, Deserialize, Default)]
-pub struct TestJob {
-    #[doc = "Gets or sets the creation time of the test job."]
-    #[serde(rename = "creationTime", default, with = "azure_core::date::rfc3339::option")]
-    pub creation_time: Option<time::OffsetDateTime>,
-    #[doc = "Gets or sets the status of the test job."]
-    #[serde(default, skip_serializing_if = "Option::is_none")]
-    pub status: Option<String>,
-    #[doc = "Gets or sets the status details of the test job."]
-    #[serde(rename = "statusDetails", default, skip_serializing_if = "Option::is_none")]
-    pub status_details: Option<String>,
-    #[doc = "Gets or sets the runOn which specifies the group name where the job is to be executed."]
-    #[serde(rename = "runOn", default, skip_serializing_if = "Option::is_none")]
-    pub run_on: Option<String>,
-    #[doc = "Gets or sets the start time of the test job."]
-    #[serde(rename = "startTime", default, with = "azure_core::date::rfc3339::option")]
     pub start_time: Option<time::OffsetDateTime>,
-    #[doc = "Gets or sets the end time of the test job."]
-    #[serde(rename = "endTime", default, with = "azure_core::date::rfc3339::option")]
+    #[doc = "The end time of the job."]
+    #[serde(
+        rename = "endTime",
+        default,
+        with = "azure_core::date::iso8601::option",
+        skip_serializing_if = "Option::is_none"
+    )]
     pub end_time: Option<time::OffsetDateTime>,
-    #[doc = "Gets or sets the exception of the test job."]
-    #[serde(default, skip_serializing_if = "Option::is_none")]
-    pub exception: Option<String>,
-    #[doc = "Gets or sets the last modified time of the test job."]
-    #[serde(rename = "lastModifiedTime", default, with = "azure_core::date::rfc3339::option")]
-    pub last_modified_time: Option<time::OffsetDateTime>,
-    #[doc = "Gets or sets the last status modified time of the test job."]
-    #[serde(rename = "lastStatusModifiedTime", default, with = "azure_core::date::rfc3339::option")]
-    pub last_status_modified_time: Option<time::OffsetDateTime>,
-    #[doc = "Gets or sets the parameters of the test job."]
-    #[serde(default, skip_serializing_if = "Option::is_none")]
-    pub parameters: Option<serde_json::Value>,
-    #[doc = "The activity-level tracing options of the runbook."]
-    #[serde(rename = "logActivityTrace", default, skip_serializing_if = "Option::is_none")]
-    pub log_activity_trace: Option<i32>,
+    #[doc = "The sync type."]
+    #[serde(rename = "syncType", default, skip_serializing_if = "Option::is_none")]
+    pub sync_type: Option<source_control_sync_job_properties::SyncType>,
 }
-impl TestJob {
+impl SourceControlSyncJobProperties {
     pub fn new() -> Self {
         Self::default()
     }
 }
-#[doc = "The parameters supplied to the create test job operation."]
-#[derive(Clone, Debug, PartialEq, Serialize, Deserialize, Default)]
-pub struct TestJobCreateParameters {
-    #[doc = "Gets or sets the parameters of the test job."]
-    #[serde(default, skip_serializing_if = "Option::is_none")]
-    pub parameters: Option<serde_json::Value>,
-    #[doc = "Gets or sets the runOn which specifies the group name where the job is to be executed."]
-    #[serde(rename = "runOn", default, skip_serializing_if = "Option::is_none")]
-    pub run_on: Option<String>,
-}
-impl TestJobCreateParameters {
-    pub fn new() -> Self {
-        Self::default()
+pub mod source_control_sync_job_properties {
+    use super::*;
+    #[doc = "The provisioning state of the job."]
+    #[derive(Clone, Debug, PartialEq, Eq, Hash, Serialize, Deserialize)]
+    #[serde(remote = "ProvisioningState")]
+    pub enum ProvisioningState {
+        Completed,
+        Failed,
+        Running,
+        #[serde(skip_deserializing)]
+        UnknownValue(String),
+    }
+    impl FromStr for ProvisioningState {
+        type Err = value::Error;
+        fn from_str(s: &str) -> std::result::Result<Self, Self::Err> {
+            Self::deserialize(s.into_deserializer())
+        }
+    }
+    impl<'de> Deserialize<'de> for ProvisioningState {
+        fn deserialize<D>(deserializer: D) -> std::result::Result<Self, D::Error>
+        where
+            D: Deserializer<'de>,
+        {
+            let s = String::deserialize(deserializer)?;
+            let deserialized = Self::from_str(&s).unwrap_or(Self::UnknownValue(s));
+            Ok(deserialized)
+        }
+    }
+    impl Serialize for ProvisioningState {
+        fn serialize<S>(&self, serializer: S) -> std::result::Result<S::Ok, S::Error>
+        where
+            S: Serializer,
+        {
+            match self {
+                Self::Completed => serializer.serialize_unit_variant("ProvisioningState", 0u32, "Completed"),
+                Self::Failed => serializer.serialize_unit_variant("ProvisioningState", 1u32, "Failed"),
+                Self::Running => serializer.serialize_unit_variant("ProvisioningState", 2u32, "Running"),
+                Self::UnknownValue(s) => serializer.serialize_str(s.as_str()),
+            }
+        }
+    }
+    impl ProvisioningState {
+        #[doc = "The value as it is sent on the wire."]
+        pub fn as_str(&self) -> &str {
+            match self {
+                Self::Completed => "Completed",
+                Self::Failed => "Failed",
+                Self::Running => "Running",
+                Self::UnknownValue(s) => s.as_str(),
+            }
+        }
+    }
+    impl std::fmt::Display for ProvisioningState {
+        fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+            f.write_str(self.as_str())
+        }
+    }
+    #[doc = "The sync type."]
+    #[derive(Clone, Debug, PartialEq, Eq, Hash, Serialize, Deserialize)]
+    #[serde(remote = "SyncType")]
+    pub enum SyncType {
+        PartialSync,
+        FullSync,
+        #[serde(skip_deserializing)]
+        UnknownValue(String),
+    }
+    impl FromStr for SyncType {
+        type Err = value::Error;
+        fn from_str(s: &str) -> std::result::Result<Self, Self::Err> {
+            Self::deserialize(s.into_deserializer())
+        }
+    }
+    impl<'de> Deserialize<'de> for SyncType {
+        fn deserialize<D>(deserializer: D) -> std::result::Result<Self, D::Error>
+        where
+            D: Deserializer<'de>,
+        {
+            let s = String::deserialize(deserializer)?;
+            let deserialized = Self::from_str(&s).unwrap_or(Self::UnknownValue(s));
+            Ok(deserialized)
+        }
+    }
+    impl Serialize for SyncType {
+        fn serialize<S>(&self, serializer: S) -> std::result::Result<S::Ok, S::Error>
+        where
+            S: Serializer,
+        {
+            match self {
+                Self::PartialSync => serializer.serialize_unit_variant("SyncType", 0u32, "PartialSync"),
+                Self::FullSync => serializer.serialize_unit_variant("SyncType", 1u32, "FullSync"),
+                Self::UnknownValue(s) => serializer.serialize_str(s.as_str()),
+            }
+        }
+    }
+    impl SyncType {
+        #[doc = "The value as it is sent on the wire."]
+        pub fn as_str(&self) -> &str {
+            match self {
+                Self::PartialSync => "PartialSync",
+                Self::FullSync => "FullSync",
+                Self::UnknownValue(s) => s.as_str(),
+            }
+        }
+    }
+    impl std::fmt::Display for SyncType {
+        fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+            f.write_str(self.as_str())
+        }
     }
 }
-#[doc = "The resource model definition for a ARM tracked top level resource"]
-#[derive(Clone, Debug, PartialEq, Serialize, Deserialize, Default)]
-pub struct TrackedResource {
-    #[serde(flatten)]
-    pub resource: Resource,
-    #[doc = "Resource tags."]
+#[doc = "Definition of the source control sync job stream."]
+#[derive(Clone, Debug, PartialEq, Eq, Hash, Serialize, Deserialize, Default)]
+pub struct SourceControlSyncJobStream {
+    #[doc = "Resource id."]
     #[serde(default, skip_serializing_if = "Option::is_none")]
-    pub tags: Option<serde_json::Value>,
-    #[doc = "The Azure Region where the resource lives"]
+    pub id: Option<String>,
+    #[doc = "Definition of source control sync job stream properties."]
     #[serde(default, skip_serializing_if = "Option::is_none")]
-    pub location: Option<String>,
+    pub properties: Option<SourceControlSyncJobStreamProperties>,
 }
-impl TrackedResource {
+impl SourceControlSyncJobStream {
     pub fn new() -> Self {
         Self::default()
     }
 }
-#[doc = "Information about a field of a type."]
+#[doc = "Definition of the source control sync job stream by id."]
 #[derive(Clone, Debug, PartialEq, Serialize, Deserialize, Default)]
-pub struct TypeField {
-    #[doc = "Gets or sets the name of the field."]
+pub struct SourceControlSyncJobStreamById {
+    #[doc = "Resource id."]
     #[serde(default, skip_serializing_if = "Option::is_none")]
-    pub name: Option<String>,
-    #[doc = "Gets or sets the type of the field."]
-    #[serde(rename = "type", default, skip_serializing_if = "Option::is_none")]
-    pub type_: Option<String>,
-}
-impl TypeField {
-    pub fn new() -> Self {
-        Self::default()
-    }
-}
-#[doc = "The response model for the list fields operation."]
-#[derive(Clone, Debug, PartialEq, Serialize, Deserialize, Default)]
-pub struct TypeFieldListResult {
-    #[doc = "Gets or sets a list of fields."]
-    #[serde(
-        default,
-        deserialize_with = "azure_core::util::deserialize_null_as_default",
-        skip_serializing_if = "Vec::is_empty"
-    )]
-    pub value: Vec<TypeField>,
-}
-impl azure_core::Continuable for TypeFieldListResult {
-    type Continuation = String;
-    fn continuation(&self) -> Option<Self::Continuation> {
-        None
-    }
+    pub id: Option<String>,
+    #[doc = "Definition of source control sync job stream by id properties."]
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub properties: Option<SourceControlSyncJobStreamByIdProperties>,
 }
-impl TypeFieldListResult {
+impl SourceControlSyncJobStreamById {
     pub fn new() -> Self {
         Self::default()
     }
 }
-#[doc = "Definition of Usage."]
+#[doc = "Definition of source control sync job stream by id properties."]
 #[derive(Clone, Debug, PartialEq, Serialize, Deserialize, Default)]
-pub struct Usage {
-    #[doc = "Gets or sets the id of the resource."]
-    #[serde(default, skip_serializing_if = "Option::is_none")]
-    pub id: Option<String>,
-    #[doc = "Definition of usage counter name."]
-    #[serde(default, skip_serializing_if = "Option::is_none")]
-    pub name: Option<UsageCounterName>,
-    #[doc = "Gets or sets the usage unit name."]
+pub struct SourceControlSyncJobStreamByIdProperties {
+    #[doc = "The sync job stream id."]
+    #[serde(rename = "sourceControlSyncJobStreamId", default, skip_serializing_if = "Option::is_none")]
+    pub source_control_sync_job_stream_id: Option<String>,
+    #[doc = "The summary of the sync job stream."]
     #[serde(default, skip_serializing_if = "Option::is_none")]
-    pub unit: Option<String>,
-    #[doc = "Gets or sets the current usage value."]
-    #[serde(rename = "currentValue", default, skip_serializing_if = "Option::is_none")]
-    pub current_value: Option<f64>,
-    #[doc = "Gets or sets max limit. -1 for unlimited"]
+    pub summary: Option<String>,
+    #[doc = "The time of the sync job stream."]
+    #[serde(default, with = "azure_core::date::iso8601::option", skip_serializing_if = "Option::is_none")]
+    pub time: Option<time::OffsetDateTime>,
+    #[doc = "The type of the sync job stream."]
+    #[serde(rename = "streamType", default, skip_serializing_if = "Option::is_none")]
+    pub stream_type: Option<source_control_sync_job_stream_by_id_properties::StreamType>,
+    #[doc = "The text of the sync job stream."]
+    #[serde(rename = "streamText", default, skip_serializing_if = "Option::is_none")]
+    pub stream_text: Option<String>,
+    #[doc = "The values of the job stream."]
     #[serde(default, skip_serializing_if = "Option::is_none")]
-    pub limit: Option<i64>,
-    #[doc = "Gets or sets the throttle status."]
-    #[serde(rename = "throttleStatus", default, skip_serializing_if = "Option::is_none")]
-    pub throttle_status: Option<String>,
+    pub value: Option<serde_json::Value>,
 }
-impl Usage {
+impl SourceControlSyncJobStreamByIdProperties {
     pub fn new() -> Self {
         Self::default()
     }
 }
-#[doc = "Definition of usage counter name."]
-#[derive(Clone, Debug, PartialEq, Serialize, Deserialize, Default)]
-pub struct UsageCounterName {
-    #[doc = "Gets or sets the usage counter name."]
+pub mod source_control_sync_job_stream_by_id_properties {
+    use super::*;
+    #[doc = "The type of the sync job stream."]
+    #[derive(Clone, Debug, PartialEq, Eq, Hash, Serialize, Deserialize)]
+    #[serde(remote = "StreamType")]
+    pub enum StreamType {
+        Error,
+        Output,
+        #[serde(skip_deserializing)]
+        UnknownValue(String),
+    }
+    impl FromStr for StreamType {
+        type Err = value::Error;
+        fn from_str(s: &str) -> std::result::Result<Self, Self::Err> {
+            Self::deserialize(s.into_deserializer())
+        }
+    }
+    impl<'de> Deserialize<'de> for StreamType {
+        fn deserialize<D>(deserializer: D) -> std::result::Result<Self, D::Error>
+        where
+            D: Deserializer<'de>,
+        {
+            let s = String::deserialize(deserializer)?;
+            let deserialized = Self::from_str(&s).unwrap_or(Self::UnknownValue(s));
+            Ok(deserialized)
+        }
+    }
+    impl Serialize for StreamType {
+        fn serialize<S>(&self, serializer: S) -> std::result::Result<S::Ok, S::Error>
+        where
+            S: Serializer,
+        {
+            match self {
+                Self::Error => serializer.serialize_unit_variant("StreamType", 0u32, "Error"),
+                Self::Output => serializer.serialize_unit_variant("StreamType", 1u32, "Output"),
+                Self::UnknownValue(s) => serializer.serialize_str(s.as_str()),
+            }
+        }
+    }
+    impl StreamType {
+        #[doc = "The value as it is sent on the wire."]
+        pub fn as_str(&self) -> &str {
+            match self {
+                Self::Error => "Error",
+                Self::Output => "Output",
+                Self::UnknownValue(s) => s.as_str(),
+            }
+        }
+    }
+    impl std::fmt::Display for StreamType {
+        fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+            f.write_str(self.as_str())
+        }
+    }
+}
+#[doc = "Definition of source control sync job stream properties."]
+#[derive(Clone, Debug, PartialEq, Eq, Hash, Serialize, Deserialize, Default)]
+pub struct SourceControlSyncJobStreamProperties {
+    #[doc = "The sync job stream id."]
+    #[serde(rename = "sourceControlSyncJobStreamId", default, skip_serializing_if = "Option::is_none")]
+    pub source_control_sync_job_stream_id: Option<String>,
+    #[doc = "The summary of the sync job stream."]
     #[serde(default, skip_serializing_if = "Option::is_none")]
-    pub value: Option<String>,
-    #[doc = "Gets or sets the localized usage counter name."]
-    #[serde(rename = "localizedValue", default, skip_serializing_if = "Option::is_none")]
-    pub localized_value: Option<String>,
+    pub summary: Option<String>,
+    #[doc = "The time of the sync job stream."]
+    #[serde(default, with = "azure_core::date::iso8601::option", skip_serializing_if = "Option::is_none")]
+    pub time: Option<time::OffsetDateTime>,
+    #[doc = "The type of the sync job stream."]
+    #[serde(rename = "streamType", default, skip_serializing_if = "Option::is_none")]
+    pub stream_type: Option<source_control_sync_job_stream_properties::StreamType>,
 }
-impl UsageCounterName {
+impl SourceControlSyncJobStreamProperties {
     pub fn new() -> Self {
         Self::default()
     }
 }
-#[doc = "The response model for the get usage operation."]
-#[derive(Clone, Debug, PartialEq, Serialize, Deserialize, Default)]
-pub struct UsageListResult {
-    #[doc = "Gets or sets usage."]
+pub mod source_control_sync_job_stream_properties {
+    use super::*;
+    #[doc = "The type of the sync job stream."]
+    #[derive(Clone, Debug, PartialEq, Eq, Hash, Serialize, Deserialize)]
+    #[serde(remote = "StreamType")]
+    pub enum StreamType {
+        Error,
+        Output,
+        #[serde(skip_deserializing)]
+        UnknownValue(String),
+    }
+    impl FromStr for StreamType {
+        type Err = value::Error;
+        fn from_str(s: &str) -> std::result::Result<Self, Self::Err> {
+            Self::deserialize(s.into_deserializer())
+        }
+    }
+    impl<'de> Deserialize<'de> for StreamType {
+        fn deserialize<D>(deserializer: D) -> std::result::Result<Self, D::Error>
+        where
+            D: Deserializer<'de>,
+        {
+            let s = String::deserialize(deserializer)?;
+            let deserialized = Self::from_str(&s).unwrap_or(Self::UnknownValue(s));
+            Ok(deserialized)
+        }
+    }
+    impl Serialize for StreamType {
+        fn serialize<S>(&self, serializer: S) -> std::result::Result<S::Ok, S::Error>
+        where
+            S: Serializer,
+        {
+            match self {
+                Self::Error => serializer.serialize_unit_variant("StreamType", 0u32, "Error"),
+                Self::Output => serializer.serialize_unit_variant("StreamType", 1u32, "Output"),
+                Self::UnknownValue(s) => serializer.serialize_str(s.as_str()),
+            }
+        }
+    }
+    impl StreamType {
+        #[doc = "The value as it is sent on the wire."]
+        pub fn as_str(&self) -> &str {
+            match self {
+                Self::Error => "Error",
+                Self::Output => "Output",
+                Self::UnknownValue(s) => s.as_str(),
+            }
+        }
+    }
+    impl std::fmt::Display for StreamType {
+        fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+            f.write_str(self.as_str())
+        }
+    }
+}
+#[doc = "The response model for the list source control sync job streams operation."]
+#[derive(Clone, Debug, PartialEq, Eq, Hash, Serialize, Deserialize, Default)]
+pub struct SourceControlSyncJobStreamsListBySyncJob {
+    #[doc = "The list of source control sync job streams."]
     #[serde(
         default,
         deserialize_with = "azure_core::util::deserialize_null_as_default",
         skip_serializing_if = "Vec::is_empty"
     )]
-    pub value: Vec<Usage>,
+    pub value: Vec<SourceControlSyncJobStream>,
+    #[doc = "The next link."]
+    #[serde(rename = "nextLink", default, skip_serializing_if = "Option::is_none")]
+    pub next_link: Option<String>,
 }
-impl azure_core::Continuable for UsageListResult {
+impl azure_core::Continuable for SourceControlSyncJobStreamsListBySyncJob {
     type Continuation = String;
     fn continuation(&self) -> Option<Self::Continuation> {
-        None
+        self.next_link.clone()
     }
 }
-impl UsageListResult {
+impl SourceControlSyncJobStreamsListBySyncJob {
     pub fn new() -> Self {
         Self::default()
     }
 }
-#[derive(Clone, Debug, PartialEq, Serialize, Deserialize, Default)]
-pub struct UserAssignedIdentitiesProperties {
-    #[doc = "The principal id of user assigned identity."]
-    #[serde(rename = "principalId", default, skip_serializing_if = "Option::is_none")]
-    pub principal_id: Option<String>,
-    #[doc = "The client id of user assigned identity."]
-    #[serde(rename = "clientId", default, skip_serializing_if = "Option::is_none")]
-    pub client_id: Option<String>,
+#[doc = "The parameters supplied to the update source control operation."]
+#[derive(Clone, Debug, PartialEq, Eq, Hash, Serialize, Deserialize, Default)]
+pub struct SourceControlUpdateParameters {
+    #[doc = "The properties of the update source control"]
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub properties: Option<SourceControlUpdateProperties>,
 }
-impl UserAssignedIdentitiesProperties {
+impl SourceControlUpdateParameters {
     pub fn new() -> Self {
         Self::default()
     }
 }
-#[doc = "Definition of the variable."]
-#[derive(Clone, Debug, PartialEq, Serialize, Deserialize, Default)]
-pub struct Variable {
-    #[serde(flatten)]
-    pub proxy_resource: ProxyResource,
-    #[doc = "Definition of the variable properties"]
+#[doc = "The properties of the update source control"]
+#[derive(Clone, Debug, PartialEq, Eq, Hash, Serialize, Deserialize, Default)]
+pub struct SourceControlUpdateProperties {
+    #[doc = "The repo branch of the source control."]
     #[serde(default, skip_serializing_if = "Option::is_none")]
-    pub properties: Option<VariableProperties>,
+    pub branch: Option<String>,
+    #[doc = "The folder path of the source control. Path must be relative."]
+    #[serde(rename = "folderPath", default, skip_serializing_if = "Option::is_none")]
+    pub folder_path: Option<String>,
+    #[doc = "The auto sync of the source control. Default is false."]
+    #[serde(rename = "autoSync", default, skip_serializing_if = "Option::is_none")]
+    pub auto_sync: Option<bool>,
+    #[doc = "The auto publish of the source control. Default is true."]
+    #[serde(rename = "publishRunbook", default, skip_serializing_if = "Option::is_none")]
+    pub publish_runbook: Option<bool>,
+    #[serde(rename = "securityToken", default, skip_serializing_if = "Option::is_none")]
+    pub security_token: Option<SourceControlSecurityTokenProperties>,
+    #[doc = "The user description of the source control."]
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub description: Option<String>,
 }
-impl Variable {
+impl SourceControlUpdateProperties {
     pub fn new() -> Self {
         Self::default()
     }
 }
-#[doc = "The parameters supplied to the create or update variable operation."]
-#[derive(Clone, Debug, PartialEq, Serialize, Deserialize)]
-pub struct VariableCreateOrUpdateParameters {
-    #[doc = "Gets or sets the name of the variable."]
-    pub name: String,
-    #[doc = "The properties of the create variable operation."]
-    pub properties: VariableCreateOrUpdateProperties,
-}
-impl VariableCreateOrUpdateParameters {
-    pub fn new(name: String, properties: VariableCreateOrUpdateProperties) -> Self {
-        Self { name, properties }
-    }
-}
-#[doc = "The properties of the create variable operation."]
-#[derive(Clone, Debug, PartialEq, Serialize, Deserialize, Default)]
-pub struct VariableCreateOrUpdateProperties {
-    #[doc = "Gets or sets the value of the variable."]
-    #[serde(default, skip_serializing_if = "Option::is_none")]
-    pub value: Option<String>,
-    #[doc = "Gets or sets the description of the variable."]
+#[doc = "Definition of the statistic."]
+#[derive(Clone, Debug, PartialEq, Eq, Hash, Serialize, Deserialize, Default)]
+pub struct Statistics {
+    #[doc = "Gets the property value of the statistic."]
+    #[serde(rename = "counterProperty", default, skip_serializing_if = "Option::is_none")]
+    pub counter_property: Option<String>,
+    #[doc = "Gets the value of the statistic."]
+    #[serde(rename = "counterValue", default, skip_serializing_if = "Option::is_none")]
+    pub counter_value: Option<i64>,
+    #[doc = "Gets the startTime of the statistic."]
+    #[serde(
+        rename = "startTime",
+        default,
+        with = "azure_core::date::iso8601::option",
+        skip_serializing_if = "Option::is_none"
+    )]
+    pub start_time: Option<time::OffsetDateTime>,
+    #[doc = "Gets the endTime of the statistic."]
+    #[serde(
+        rename = "endTime",
+        default,
+        with = "azure_core::date::iso8601::option",
+        skip_serializing_if = "Option::is_none"
+    )]
+    pub end_time: Option<time::OffsetDateTime>,
+    #[doc = "Gets the id."]
     #[serde(default, skip_serializing_if = "Option::is_none")]
-    pub description: Option<String>,
-    #[doc = "Gets or sets the encrypted flag of the variable."]
-    #[serde(rename = "isEncrypted", default, skip_serializing_if = "Option::is_none")]
-    pub is_encrypted: Option<bool>,
+    pub id: Option<String>,
 }
-impl VariableCreateOrUpdateProperties {
+impl Statistics {
     pub fn new() -> Self {
         Self::default()
     }
 }
-#[doc = "The response model for the list variables operation."]
-#[derive(Clone, Debug, PartialEq, Serialize, Deserialize, Default)]
-pub struct VariableListResult {
-    #[doc = "Gets or sets a list of variables."]
+#[doc = "The response model for the list statistics operation."]
+#[derive(Clone, Debug, PartialEq, Eq, Hash, Serialize, Deserialize, Default)]
+pub struct StatisticsListResult {
+    #[doc = "Gets or sets a list of statistics."]
     #[serde(
         default,
         deserialize_with = "azure_core::util::deserialize_null_as_default",
         skip_serializing_if = "Vec::is_empty"
     )]
-    pub value: Vec<Variable>,
-    #[doc = "Gets or sets the next link."]
-    #[serde(rename = "nextLink", default, skip_serializing_if = "Option::is_none")]
-    pub next_link: Option<String>,
+    pub value: Vec<Statistics>,
 }
-impl azure_core::Continuable for VariableListResult {
+impl azure_core::Continuable for StatisticsListResult {
     type Continuation = String;
     fn continuation(&self) -> Option<Self::Continuation> {
-        self.next_link.clone()
+        None
     }
 }
-impl VariableListResult {
+impl StatisticsListResult {
     pub fn new() -> Self {
         Self::default()
     }
+    #[doc = "The items in this page."]
+    pub fn items(&self) -> &[Statistics] {
+        &self.value
+    }
 }
-#[doc = "Definition of the variable properties"]
+#[doc = "Definition of schedule parameters."]
 #[derive(Clone, Debug, PartialEq, Serialize, Deserialize, Default)]
-pub struct VariableProperties {
-    #[doc = "Gets or sets the value of the variable."]
+pub struct SucScheduleProperties {
+    #[doc = "Gets or sets the start time of the schedule."]
+    #[serde(
+        rename = "startTime",
+        default,
+        with = "azure_core::date::iso8601::option",
+        skip_serializing_if = "Option::is_none"
+    )]
+    pub start_time: Option<time::OffsetDateTime>,
+    #[doc = "Gets the start time's offset in minutes."]
+    #[serde(rename = "startTimeOffsetMinutes", default, skip_serializing_if = "Option::is_none")]
+    pub start_time_offset_minutes: Option<f64>,
+    #[doc = "Gets or sets the end time of the schedule."]
+    #[serde(
+        rename = "expiryTime",
+        default,
+        with = "azure_core::date::iso8601::option",
+        skip_serializing_if = "Option::is_none"
+    )]
+    pub expiry_time: Option<time::OffsetDateTime>,
+    #[doc = "Gets or sets the expiry time's offset in minutes."]
+    #[serde(rename = "expiryTimeOffsetMinutes", default, skip_serializing_if = "Option::is_none")]
+    pub expiry_time_offset_minutes: Option<f64>,
+    #[doc = "Gets or sets a value indicating whether this schedule is enabled."]
+    #[serde(rename = "isEnabled", default, skip_serializing_if = "Option::is_none")]
+    pub is_enabled: Option<bool>,
+    #[doc = "Gets or sets the next run time of the schedule."]
+    #[serde(
+        rename = "nextRun",
+        default,
+        with = "azure_core::date::iso8601::option",
+        skip_serializing_if = "Option::is_none"
+    )]
+    pub next_run: Option<time::OffsetDateTime>,
+    #[doc = "Gets or sets the next run time's offset in minutes."]
+    #[serde(rename = "nextRunOffsetMinutes", default, skip_serializing_if = "Option::is_none")]
+    pub next_run_offset_minutes: Option<f64>,
+    #[doc = "Gets or sets the interval of the schedule."]
     #[serde(default, skip_serializing_if = "Option::is_none")]
-    pub value: Option<String>,
-    #[doc = "Gets or sets the encrypted flag of the variable."]
-    #[serde(rename = "isEncrypted", default, skip_serializing_if = "Option::is_none")]
-    pub is_encrypted: Option<bool>,
+    pub interval: Option<i64>,
+    #[doc = "Gets or sets the frequency of the schedule."]
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub frequency: Option<ScheduleFrequency>,
+    #[doc = "Gets or sets the time zone of the schedule."]
+    #[serde(rename = "timeZone", default, skip_serializing_if = "Option::is_none")]
+    pub time_zone: Option<String>,
+    #[doc = "The properties of the create Advanced Schedule."]
+    #[serde(rename = "advancedSchedule", default, skip_serializing_if = "Option::is_none")]
+    pub advanced_schedule: Option<AdvancedSchedule>,
     #[doc = "Gets or sets the creation time."]
-    #[serde(rename = "creationTime", default, with = "azure_core::date::rfc3339::option")]
+    #[serde(
+        rename = "creationTime",
+        default,
+        with = "azure_core::date::iso8601::option",
+        skip_serializing_if = "Option::is_none"
+    )]
     pub creation_time: Option<time::OffsetDateTime>,
     #[doc = "Gets or sets the last modified time."]
-    #[serde(rename = "lastModifiedTime", default, with = "azure_core::date::rfc3339::option")]
+    #[serde(
+        rename = "lastModifiedTime",
+        default,
+        with = "azure_core::date::iso8601::option",
+        skip_serializing_if = "Option::is_none"
+    )]
     pub last_modified_time: Option<time::OffsetDateTime>,
     #[doc = "Gets or sets the description."]
     #[serde(default, skip_serializing_if = "Option::is_none")]
     pub description: Option<String>,
+    #[doc = "Fields of the response that this version of the crate has no field for, kept so that they are not lost when the model is sent back, such as in a read-modify-write update."]
+    #[serde(flatten, default, skip_serializing_if = "std::collections::HashMap::is_empty")]
+    pub extra: std::collections::HashMap<String, serde_json::Value>,
 }
-impl VariableProperties {
+impl SucScheduleProperties {
     pub fn new() -> Self {
         Self::default()
     }
 }
-#[doc = "The parameters supplied to the update variable operation."]
-#[derive(Clone, Debug, PartialEq, Serialize, Deserialize, Default)]
-pub struct VariableUpdateParameters {
-    #[doc = "Gets or sets the name of the variable."]
-    #[serde(default, skip_serializing_if = "Option::is_none")]
-    pub name: Option<String>,
-    #[doc = "The properties of the update variable"]
-    #[serde(default, skip_serializing_if = "Option::is_none")]
-    pub properties: Option<VariableUpdateProperties>,
+#[doc = "Metadata pertaining to creation and last modification of the resource."]
+#[derive(Clone, Debug, PartialEq, Eq, Hash, Serialize, Deserialize, Default)]
+pub struct SystemData {
+    #[doc = "The identity that created the resource."]
+    #[serde(rename = "createdBy", default, skip_serializing_if = "Option::is_none")]
+    pub created_by: Option<String>,
+    #[doc = "The type of identity that created the resource."]
+    #[serde(rename = "createdByType", default, skip_serializing_if = "Option::is_none")]
+    pub created_by_type: Option<system_data::CreatedByType>,
+    #[doc = "The timestamp of resource creation (UTC)."]
+    #[serde(
+        rename = "createdAt",
+        default,
+        with = "azure_core::date::iso8601::option",
+        skip_serializing_if = "Option::is_none"
+    )]
+    pub created_at: Option<time::OffsetDateTime>,
+    #[doc = "The identity that last modified the resource."]
+    #[serde(rename = "lastModifiedBy", default, skip_serializing_if = "Option::is_none")]
+    pub last_modified_by: Option<String>,
+    #[doc = "The type of identity that last modified the resource."]
+    #[serde(rename = "lastModifiedByType", default, skip_serializing_if = "Option::is_none")]
+    pub last_modified_by_type: Option<system_data::LastModifiedByType>,
+    #[doc = "The timestamp of resource last modification (UTC)"]
+    #[serde(
+        rename = "lastModifiedAt",
+        default,
+        with = "azure_core::date::iso8601::option",
+        skip_serializing_if = "Option::is_none"
+    )]
+    pub last_modified_at: Option<time::OffsetDateTime>,
 }
-impl VariableUpdateParameters {
+impl SystemData {
     pub fn new() -> Self {
         Self::default()
     }
 }
-#[doc = "The properties of the update variable"]
-#[derive(Clone, Debug, PartialEq, Serialize, Deserialize, Default)]
-pub struct VariableUpdateProperties {
-    #[doc = "Gets or sets the value of the variable."]
-    #[serde(default, skip_serializing_if = "Option::is_none")]
-    pub value: Option<String>,
-    #[doc = "Gets or sets the description of the variable."]
-    #[serde(default, skip_serializing_if = "Option::is_none")]
-    pub description: Option<String>,
-}
-impl VariableUpdateProperties {
-    pub fn new() -> Self {
-        Self::default()
+pub mod system_data {
+    use super::*;
+    #[doc = "The type of identity that created the resource."]
+    #[derive(Clone, Debug, PartialEq, Eq, Hash, Serialize, Deserialize)]
+    #[serde(remote = "CreatedByType")]
+    pub enum CreatedByType {
+        User,
+        Application,
+        ManagedIdentity,
+        Key,
+        #[serde(skip_deserializing)]
+        UnknownValue(String),
+    }
+    impl FromStr for CreatedByType {
+        type Err = value::Error;
+        fn from_str(s: &str) -> std::result::Result<Self, Self::Err> {
+            Self::deserialize(s.into_deserializer())
+        }
+    }
+    impl<'de> Deserialize<'de> for CreatedByType {
+        fn deserialize<D>(deserializer: D) -> std::result::Result<Self, D::Error>
+        where
+            D: Deserializer<'de>,
+        {
+            let s = String::deserialize(deserializer)?;
+            let deserialized = Self::from_str(&s).unwrap_or(Self::UnknownValue(s));
+            Ok(deserialized)
+        }
+    }
+    impl Serialize for CreatedByType {
+        fn serialize<S>(&self, serializer: S) -> std::result::Result<S::Ok, S::Error>
+        where
+            S: Serializer,
+        {
+            match self {
+                Self::User => serializer.serialize_unit_variant("CreatedByType", 0u32, "User"),
+                Self::Application => serializer.serialize_unit_variant("CreatedByType", 1u32, "Application"),
+                Self::ManagedIdentity => serializer.serialize_unit_variant("CreatedByType", 2u32, "ManagedIdentity"),
+                Self::Key => serializer.serialize_unit_variant("CreatedByType", 3u32, "Key"),
+                Self::UnknownValue(s) => serializer.serialize_str(s.as_str()),
+            }
+        }
+    }
+    impl CreatedByType {
+        #[doc = "The value as it is sent on the wire."]
+        pub fn as_str(&self) -> &str {
+            match self {
+                Self::User => "User",
+                Self::Application => "Application",
+                Self::ManagedIdentity => "ManagedIdentity",
+                Self::Key => "Key",
+                Self::UnknownValue(s) => s.as_str(),
+            }
+        }
+    }
+    impl std::fmt::Display for CreatedByType {
+        fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+            f.write_str(self.as_str())
+        }
+    }
+    #[doc = "The type of identity that last modified the resource."]
+    #[derive(Clone, Debug, PartialEq, Eq, Hash, Serialize, Deserialize)]
+    #[serde(remote = "LastModifiedByType")]
+    pub enum LastModifiedByType {
+        User,
+        Application,
+        ManagedIdentity,
+        Key,
+        #[serde(skip_deserializing)]
+        UnknownValue(String),
+    }
+    impl FromStr for LastModifiedByType {
+        type Err = value::Error;
+        fn from_str(s: &str) -> std::result::Result<Self, Self::Err> {
+            Self::deserialize(s.into_deserializer())
+        }
+    }
+    impl<'de> Deserialize<'de> for LastModifiedByType {
+        fn deserialize<D>(deserializer: D) -> std::result::Result<Self, D::Error>
+        where
+            D: Deserializer<'de>,
+        {
+            let s = String::deserialize(deserializer)?;
+            let deserialized = Self::from_str(&s).unwrap_or(Self::UnknownValue(s));
+            Ok(deserialized)
+        }
+    }
+    impl Serialize for LastModifiedByType {
+        fn serialize<S>(&self, serializer: S) -> std::result::Result<S::Ok, S::Error>
+        where
+            S: Serializer,
+        {
+            match self {
+                Self::User => serializer.serialize_unit_variant("LastModifiedByType", 0u32, "User"),
+                Self::Application => serializer.serialize_unit_variant("LastModifiedByType", 1u32, "Application"),
+                Self::ManagedIdentity => serializer.serialize_unit_variant("LastModifiedByType", 2u32, "ManagedIdentity"),
+                Self::Key => serializer.serialize_unit_variant("LastModifiedByType", 3u32, "Key"),
+                Self::UnknownValue(s) => serializer.serialize_str(s.as_str()),
+            }
+        }
+    }
+    impl LastModifiedByType {
+        #[doc = "The value as it is sent on the wire."]
+        pub fn as_str(&self) -> &str {
+            match self {
+                Self::User => "User",
+                Self::Application => "Application",
+                Self::ManagedIdentity => "ManagedIdentity",
+                Self::Key => "Key",
+                Self::UnknownValue(s) => s.as_str(),
+            }
+        }
+    }
+    impl std::fmt::Display for LastModifiedByType {
+        fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+            f.write_str(self.as_str())
+        }
     }
 }
-#[doc = "Definition of the watcher type."]
+#[doc = "Tag filter information for the VM."]
 #[derive(Clone, Debug, PartialEq, Serialize, Deserialize, Default)]
-pub struct Watcher {
-    #[serde(flatten)]
-    pub resource: Resource,
-    #[doc = "Definition of the watcher properties"]
-    #[serde(default, skip_serializing_if = "Option::is_none")]
-    pub properties: Option<WatcherProperties>,
-    #[doc = "Gets or sets the etag of the resource."]
-    #[serde(default, skip_serializing_if = "Option::is_none")]
-    pub etag: Option<String>,
-    #[doc = "Resource tags."]
+pub struct TagSettingsProperties {
+    #[doc = "Dictionary of tags with its list of values."]
     #[serde(default, skip_serializing_if = "Option::is_none")]
     pub tags: Option<serde_json::Value>,
-    #[doc = "The geo-location where the resource lives"]
-    #[serde(default, skip_serializing_if = "Option::is_none")]
-    pub location: Option<String>,
+    #[doc = "Filter VMs by Any or All specified tags."]
+    #[serde(rename = "filterOperator", default, skip_serializing_if = "Option::is_none")]
+    pub filter_operator: Option<tag_settings_properties::FilterOperator>,
 }
-impl Watcher {
+impl TagSettingsProperties {
     pub fn new() -> Self {
         Self::default()
     }
 }
-#[doc = "The response model for the list watcher operation."]
+pub mod tag_settings_properties {
+    use super::*;
+    #[doc = "Filter VMs by Any or All specified tags."]
+    #[derive(Clone, Debug, PartialEq, Eq, Hash, Serialize, Deserialize)]
+    pub enum FilterOperator {
+        All,
+        Any,
+    }
+}
+#[doc = "Group specific to the update configuration."]
 #[derive(Clone, Debug, PartialEq, Serialize, Deserialize, Default)]
-pub struct WatcherListResult {
-    #[doc = "Gets or sets a list of watchers."]
+pub struct TargetProperties {
+    #[doc = "List of Azure queries in the software update configuration."]
+    #[serde(
+        rename = "azureQueries",
+        default,
+        deserialize_with = "azure_core::util::deserialize_null_as_default",
+        skip_serializing_if = "Vec::is_empty"
+    )]
+    pub azure_queries: Vec<AzureQueryProperties>,
+    #[doc = "List of non Azure queries in the software update configuration."]
     #[serde(
+        rename = "nonAzureQueries",
         default,
         deserialize_with = "azure_core::util::deserialize_null_as_default",
         skip_serializing_if = "Vec::is_empty"
     )]
-    pub value: Vec<Watcher>,
-    #[doc = "Gets or sets the next link."]
-    #[serde(rename = "nextLink", default, skip_serializing_if = "Option::is_none")]
-    pub next_link: Option<String>,
+    pub non_azure_queries: Vec<NonAzureQueryProperties>,
 }
-impl azure_core::Continuable for WatcherListResult {
-    type Continuation = String;
-    fn continuation(&self) -> Option<Self::Continuation> {
-        self.next_link.clone()
+impl TargetProperties {
+    pub fn new() -> Self {
+        Self::default()
     }
 }
-impl WatcherListResult {
+#[doc = "Task properties of the software update configuration."]
+#[derive(Clone, Debug, PartialEq, Serialize, Deserialize, Default)]
+pub struct TaskProperties {
+    #[doc = "Gets or sets the parameters of the task."]
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub parameters: Option<serde_json::Value>,
+    #[doc = "Gets or sets the name of the runbook."]
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub source: Option<String>,
+}
+impl TaskProperties {
     pub fn new() -> Self {
         Self::default()
     }
 }
-#[doc = "Definition of the watcher properties"]
+#[doc = "Definition of the test job."]
 #[derive(Clone, Debug, PartialEq, Serialize, Deserialize, Default)]
-pub struct WatcherProperties {
-    #[doc = "Gets or sets the frequency at which the watcher is invoked."]
-    #[serde(rename = "executionFrequencyInSeconds", default, skip_serializing_if = "Option::is_none")]
-    pub execution_frequency_in_seconds: Option<i64>,
-    #[doc = "Gets or sets the name of the script the watcher is attached to, i.e. the name of an existing runbook."]
-    #[serde(rename = "scriptName", default, skip_serializing_if = "Option::is_none")]
-    pub script_name: Option<String>,
-    #[doc = "Gets or sets the parameters of the script."]
-    #[serde(rename = "scriptParameters", default, skip_serializing_if = "Option::is_none")]
-    pub script_parameters: Option<serde_json::Value>,
-    #[doc = "Gets or sets the name of the hybrid worker group the watcher will run on."]
-    #[serde(rename = "scriptRunOn", default, skip_serializing_if = "Option::is_none")]
-    pub script_run_on: Option<String>,
-    #[doc = "Gets the current status of the watcher."]
+pub struct TestJob {
+    #[doc = "Gets or sets the creation time of the test job."]
+    #[serde(
+        rename = "creationTime",
+        default,
+        with = "azure_core::date::iso8601::option",
+        skip_serializing_if = "Option::is_none"
+    )]
+    pub creation_time: Option<time::OffsetDateTime>,
+    #[doc = "Gets or sets the status of the test job."]
     #[serde(default, skip_serializing_if = "Option::is_none")]
     pub status: Option<String>,
-    #[doc = "Gets or sets the creation time."]
-    #[serde(rename = "creationTime", default, with = "azure_core::date::rfc3339::option")]
-    pub creation_time: Option<time::OffsetDateTime>,
-    #[doc = "Gets or sets the last modified time."]
-    #[serde(rename = "lastModifiedTime", default, with = "azure_core::date::rfc3339::option")]
+    #[doc = "Gets or sets the status details of the test job."]
+    #[serde(rename = "statusDetails", default, skip_serializing_if = "Option::is_none")]
+    pub status_details: Option<String>,
+    #[doc = "Gets or sets the runOn which specifies the group name where the job is to be executed."]
+    #[serde(rename = "runOn", default, skip_serializing_if = "Option::is_none")]
+    pub run_on: Option<String>,
+    #[doc = "Gets or sets the start time of the test job."]
+    #[serde(
+        rename = "startTime",
+        default,
+        with = "azure_core::date::iso8601::option",
+        skip_serializing_if = "Option::is_none"
+    )]
+    pub start_time: Option<time::OffsetDateTime>,
+    #[doc = "Gets or sets the end time of the test job."]
+    #[serde(
+        rename = "endTime",
+        default,
+        with = "azure_core::date::iso8601::option",
+        skip_serializing_if = "Option::is_none"
+    )]
+    pub end_time: Option<time::OffsetDateTime>,
+    #[doc = "Gets or sets the exception of the test job."]
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub exception: Option<String>,
+    #[doc = "Gets or sets the last modified time of the test job."]
+    #[serde(
+        rename = "lastModifiedTime",
+        default,
+        with = "azure_core::date::iso8601::option",
+        skip_serializing_if = "Option::is_none"
+    )]
     pub last_modified_time: Option<time::OffsetDateTime>,
-    #[doc = "Details of the user who last modified the watcher."]
-    #[serde(rename = "lastModifiedBy", default, skip_serializing_if = "Option::is_none")]
-    pub last_modified_by: Option<String>,
-    #[doc = "Gets or sets the description."]
+    #[doc = "Gets or sets the last status modified time of the test job."]
+    #[serde(
+        rename = "lastStatusModifiedTime",
+        default,
+        with = "azure_core::date::iso8601::option",
+        skip_serializing_if = "Option::is_none"
+    )]
+    pub last_status_modified_time: Option<time::OffsetDateTime>,
+    #[doc = "Gets or sets the parameters of the test job."]
     #[serde(default, skip_serializing_if = "Option::is_none")]
-    pub description: Option<String>,
+    pub parameters: Option<serde_json::Value>,
+    #[doc = "The activity-level tracing options of the runbook."]
+    #[serde(rename = "logActivityTrace", default, skip_serializing_if = "Option::is_none")]
+    pub log_activity_trace: Option<i32>,
+    #[doc = "Fields of the response that this version of the crate has no field for, kept so that they are not lost when the model is sent back, such as in a read-modify-write update."]
+    #[serde(flatten, default, skip_serializing_if = "std::collections::HashMap::is_empty")]
+    pub extra: std::collections::HashMap<String, serde_json::Value>,
 }
-impl WatcherProperties {
+impl TestJob {
     pub fn new() -> Self {
         Self::default()
     }
 }
+#[doc = "The parameters supplied to the create test job operation."]
 #[derive(Clone, Debug, PartialEq, Serialize, Deserialize, Default)]
-pub struct WatcherUpdateParameters {
-    #[doc = "The properties of the update watcher operation."]
-    #[serde(default, skip_serializing_if = "Option::is_none")]
-    pub properties: Option<WatcherUpdateProperties>,
-    #[doc = "Gets or sets the name of the resource."]
+pub struct TestJobCreateParameters {
+    #[doc = "Gets or sets the parameters of the test job."]
     #[serde(default, skip_serializing_if = "Option::is_none")]
-    pub name: Option<String>,
+    pub parameters: Option<serde_json::Value>,
+    #[doc = "Gets or sets the runOn which specifies the group name where the job is to be executed."]
+    #[serde(rename = "runOn", default, skip_serializing_if = "Option::is_none")]
+    pub run_on: Option<String>,
 }
-impl WatcherUpdateParameters {
+impl TestJobCreateParameters {
     pub fn new() -> Self {
         Self::default()
     }
 }
-#[doc = "The properties of the update watcher operation."]
+#[doc = "The resource model definition for a ARM tracked top level resource"]
 #[derive(Clone, Debug, PartialEq, Serialize, Deserialize, Default)]
-pub struct WatcherUpdateProperties {
-    #[doc = "Gets or sets the frequency at which the watcher is invoked."]
-    #[serde(rename = "executionFrequencyInSeconds", default, skip_serializing_if = "Option::is_none")]
-    pub execution_frequency_in_seconds: Option<i64>,
+pub struct TrackedResource {
+    #[serde(flatten)]
+    pub resource: Resource,
+    #[doc = "Resource tags."]
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub tags: Option<serde_json::Value>,
+    #[doc = "The Azure Region where the resource lives"]
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub location: Option<String>,
 }
-impl WatcherUpdateProperties {
+impl TrackedResource {
     pub fn new() -> Self {
         Self::default()
     }
 }
-#[doc = "Definition of the webhook type."]
-#[derive(Clone, Debug, PartialEq, Serialize, Deserialize, Default)]
-pub struct Webhook {
-    #[serde(flatten)]
-    pub proxy_resource: ProxyResource,
-    #[doc = "Definition of the webhook properties"]
+#[doc = "Information about a field of a type."]
+#[derive(Clone, Debug, PartialEq, Eq, Hash, Serialize, Deserialize, Default)]
+pub struct TypeField {
+    #[doc = "Gets or sets the name of the field."]
     #[serde(default, skip_serializing_if = "Option::is_none")]
-    pub properties: Option<WebhookProperties>,
+    pub name: Option<String>,
+    #[doc = "Gets or sets the type of the field."]
+    #[serde(rename = "type", default, skip_serializing_if = "Option::is_none")]
+    pub type_: Option<String>,
 }
-impl Webhook {
+impl TypeField {
     pub fn new() -> Self {
         Self::default()
     }
 }
-#[doc = "The parameters supplied to the create or update webhook operation."]
-#[derive(Clone, Debug, PartialEq, Serialize, Deserialize)]
-pub struct WebhookCreateOrUpdateParameters {
-    #[doc = "Gets or sets the name of the webhook."]
-    pub name: String,
-    #[doc = "The properties of the create webhook operation."]
-    pub properties: WebhookCreateOrUpdateProperties,
+#[doc = "The response model for the list fields operation."]
+#[derive(Clone, Debug, PartialEq, Eq, Hash, Serialize, Deserialize, Default)]
+pub struct TypeFieldListResult {
+    #[doc = "Gets or sets a list of fields."]
+    #[serde(
+        default,
+        deserialize_with = "azure_core::util::deserialize_null_as_default",
+        skip_serializing_if = "Vec::is_empty"
+    )]
+    pub value: Vec<TypeField>,
 }
-impl WebhookCreateOrUpdateParameters {
-    pub fn new(name: String, properties: WebhookCreateOrUpdateProperties) -> Self {
-        Self { name, properties }
+impl azure_core::Continuable for TypeFieldListResult {
+    type Continuation = String;
+    fn continuation(&self) -> Option<Self::Continuation> {
+        None
     }
 }
-#[doc = "The properties of the create webhook operation."]
-#[derive(Clone, Debug, PartialEq, Serialize, Deserialize, Default)]
-pub struct WebhookCreateOrUpdateProperties {
-    #[doc = "Gets or sets the value of the enabled flag of webhook."]
-    #[serde(rename = "isEnabled", default, skip_serializing_if = "Option::is_none")]
-    pub is_enabled: Option<bool>,
-    #[doc = "Gets or sets the uri."]
+impl TypeFieldListResult {
+    pub fn new() -> Self {
+        Self::default()
+    }
+    #[doc = "The items in this page."]
+    pub fn items(&self) -> &[TypeField] {
+        &self.value
+    }
+}
+#[doc = "Update specific properties of the software update configuration."]
+#[derive(Clone, Debug, PartialEq, Serialize, Deserialize)]
+pub struct UpdateConfiguration {
+    #[doc = "Target operating system for the software update configuration."]
+    #[serde(rename = "operatingSystem")]
+    pub operating_system: OperatingSystemType,
+    #[doc = "Windows specific update configuration."]
     #[serde(default, skip_serializing_if = "Option::is_none")]
-    pub uri: Option<String>,
-    #[doc = "Gets or sets the expiry time."]
-    #[serde(rename = "expiryTime", default, with = "azure_core::date::rfc3339::option")]
-    pub expiry_time: Option<time::OffsetDateTime>,
-    #[doc = "Gets or sets the parameters of the job."]
+    pub windows: Option<WindowsProperties>,
+    #[doc = "Linux specific update configuration."]
     #[serde(default, skip_serializing_if = "Option::is_none")]
-    pub parameters: Option<serde_json::Value>,
-    #[doc = "The runbook property associated with the entity."]
+    pub linux: Option<LinuxProperties>,
+    #[doc = "Maximum time allowed for the software update configuration run. Duration needs to be specified using the format PT[n]H[n]M[n]S as per ISO8601"]
     #[serde(default, skip_serializing_if = "Option::is_none")]
-    pub runbook: Option<RunbookAssociationProperty>,
-    #[doc = "Gets or sets the name of the hybrid worker group the webhook job will run on."]
-    #[serde(rename = "runOn", default, skip_serializing_if = "Option::is_none")]
-    pub run_on: Option<String>,
+    pub duration: Option<String>,
+    #[doc = "List of azure resource Ids for azure virtual machines targeted by the software update configuration."]
+    #[serde(
+        rename = "azureVirtualMachines",
+        default,
+        deserialize_with = "azure_core::util::deserialize_null_as_default",
+        skip_serializing_if = "Vec::is_empty"
+    )]
+    pub azure_virtual_machines: Vec<String>,
+    #[doc = "List of names of non-azure machines targeted by the software update configuration."]
+    #[serde(
+        rename = "nonAzureComputerNames",
+        default,
+        deserialize_with = "azure_core::util::deserialize_null_as_default",
+        skip_serializing_if = "Vec::is_empty"
+    )]
+    pub non_azure_computer_names: Vec<String>,
+    #[doc = "Group specific to the update configuration."]
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub targets: Option<TargetProperties>,
 }
-impl WebhookCreateOrUpdateProperties {
-    pub fn new() -> Self {
-        Self::default()
+impl UpdateConfiguration {
+    pub fn new(operating_system: OperatingSystemType) -> Self {
+        Self {
+            operating_system,
+            windows: None,
+            linux: None,
+            duration: None,
+            azure_virtual_machines: Vec::new(),
+            non_azure_computer_names: Vec::new(),
+            targets: None,
+        }
     }
 }
-#[doc = "The response model for the list webhook operation."]
-#[derive(Clone, Debug, PartialEq, Serialize, Deserialize, Default)]
-pub struct WebhookListResult {
-    #[doc = "Gets or sets a list of webhooks."]
+#[doc = "Software update configuration machine run properties."]
+#[derive(Clone, Debug, PartialEq, Eq, Hash, Serialize, Deserialize, Default)]
+pub struct UpdateConfigurationMachineRunProperties {
+    #[doc = "name of the updated computer"]
+    #[serde(rename = "targetComputer", default, skip_serializing_if = "Option::is_none")]
+    pub target_computer: Option<String>,
+    #[doc = "type of the updated computer."]
+    #[serde(rename = "targetComputerType", default, skip_serializing_if = "Option::is_none")]
+    pub target_computer_type: Option<String>,
+    #[doc = "Software update configuration Run Navigation model."]
+    #[serde(rename = "softwareUpdateConfiguration", default, skip_serializing_if = "Option::is_none")]
+    pub software_update_configuration: Option<UpdateConfigurationNavigation>,
+    #[doc = "Status of the software update configuration machine run."]
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub status: Option<String>,
+    #[doc = "Operating system target of the software update configuration triggered this run"]
+    #[serde(rename = "osType", default, skip_serializing_if = "Option::is_none")]
+    pub os_type: Option<String>,
+    #[doc = "correlation id of the software update configuration machine run"]
+    #[serde(rename = "correlationId", default, skip_serializing_if = "Option::is_none")]
+    pub correlation_id: Option<String>,
+    #[doc = "source computer id of the software update configuration machine run"]
+    #[serde(rename = "sourceComputerId", default, skip_serializing_if = "Option::is_none")]
+    pub source_computer_id: Option<String>,
+    #[doc = "Start time of the software update configuration machine run."]
     #[serde(
+        rename = "startTime",
         default,
-        deserialize_with = "azure_core::util::deserialize_null_as_default",
-        skip_serializing_if = "Vec::is_empty"
+        with = "azure_core::date::iso8601::option",
+        skip_serializing_if = "Option::is_none"
     )]
-    pub value: Vec<Webhook>,
-    #[doc = "Gets or sets the next link."]
-    #[serde(rename = "nextLink", default, skip_serializing_if = "Option::is_none")]
-    pub next_link: Option<String>,
-}
-impl azure_core::Continuable for WebhookListResult {
-    type Continuation = String;
-    fn continuation(&self) -> Option<Self::Continuation> {
-        self.next_link.clone()
-    }
-}
-impl WebhookListResult {
-    pub fn new() -> Self {
-        Self::default()
-    }
-}
-#[doc = "Definition of the webhook properties"]
-#[derive(Clone, Debug, PartialEq, Serialize, Deserialize, Default)]
-pub struct WebhookProperties {
-    #[doc = "Gets or sets the value of the enabled flag of the webhook."]
-    #[serde(rename = "isEnabled", default, skip_serializing_if = "Option::is_none")]
-    pub is_enabled: Option<bool>,
-    #[doc = "Gets or sets the webhook uri."]
-    #[serde(default, skip_serializing_if = "Option::is_none")]
-    pub uri: Option<String>,
-    #[doc = "Gets or sets the expiry time."]
-    #[serde(rename = "expiryTime", default, with = "azure_core::date::rfc3339::option")]
-    pub expiry_time: Option<time::OffsetDateTime>,
-    #[doc = "Gets or sets the last invoked time."]
-    #[serde(rename = "lastInvokedTime", default, with = "azure_core::date::rfc3339::option")]
-    pub last_invoked_time: Option<time::OffsetDateTime>,
-    #[doc = "Gets or sets the parameters of the job that is created when the webhook calls the runbook it is associated with."]
-    #[serde(default, skip_serializing_if = "Option::is_none")]
-    pub parameters: Option<serde_json::Value>,
-    #[doc = "The runbook property associated with the entity."]
+    pub start_time: Option<time::OffsetDateTime>,
+    #[doc = "End time of the software update configuration machine run."]
+    #[serde(
+        rename = "endTime",
+        default,
+        with = "azure_core::date::iso8601::option",
+        skip_serializing_if = "Option::is_none"
+    )]
+    pub end_time: Option<time::OffsetDateTime>,
+    #[doc = "configured duration for the software update configuration run."]
+    #[serde(rename = "configuredDuration", default, skip_serializing_if = "Option::is_none")]
+    pub configured_duration: Option<String>,
+    #[doc = "Software update configuration machine run job navigation properties."]
     #[serde(default, skip_serializing_if = "Option::is_none")]
-    pub runbook: Option<RunbookAssociationProperty>,
-    #[doc = "Gets or sets the name of the hybrid worker group the webhook job will run on."]
-    #[serde(rename = "runOn", default, skip_serializing_if = "Option::is_none")]
-    pub run_on: Option<String>,
-    #[doc = "Gets or sets the creation time."]
-    #[serde(rename = "creationTime", default, with = "azure_core::date::rfc3339::option")]
+    pub job: Option<JobNavigation>,
+    #[doc = "Creation time of the resource, which only appears in the response."]
+    #[serde(
+        rename = "creationTime",
+        default,
+        with = "azure_core::date::iso8601::option",
+        skip_serializing_if = "Option::is_none"
+    )]
     pub creation_time: Option<time::OffsetDateTime>,
-    #[doc = "Gets or sets the last modified time."]
-    #[serde(rename = "lastModifiedTime", default, with = "azure_core::date::rfc3339::option")]
+    #[doc = "createdBy property, which only appears in the response."]
+    #[serde(rename = "createdBy", default, skip_serializing_if = "Option::is_none")]
+    pub created_by: Option<String>,
+    #[doc = "Last time resource was modified, which only appears in the response."]
+    #[serde(
+        rename = "lastModifiedTime",
+        default,
+        with = "azure_core::date::iso8601::option",
+        skip_serializing_if = "Option::is_none"
+    )]
     pub last_modified_time: Option<time::OffsetDateTime>,
-    #[doc = "Details of the user who last modified the Webhook"]
+    #[doc = "lastModifiedBy property, which only appears in the response."]
     #[serde(rename = "lastModifiedBy", default, skip_serializing_if = "Option::is_none")]
     pub last_modified_by: Option<String>,
-    #[doc = "Gets or sets the description."]
+    #[doc = "Error response of an operation failure"]
     #[serde(default, skip_serializing_if = "Option::is_none")]
-    pub description: Option<String>,
+    pub error: Option<ErrorResponse>,
 }
-impl WebhookProperties {
+impl UpdateConfigurationMachineRunProperties {
     pub fn new() -> Self {
         Self::default()
     }
 }
-#[doc = "The parameters supplied to the update webhook operation."]
-#[derive(Clone, Debug, PartialEq, Serialize, Deserialize, Default)]
-pub struct WebhookUpdateParameters {
-    #[doc = "Gets or sets the name of the webhook."]
+#[doc = "Software update configuration Run Navigation model."]
+#[derive(Clone, Debug, PartialEq, Eq, Hash, Serialize, Deserialize, Default)]
+pub struct UpdateConfigurationNavigation {
+    #[doc = "Name of the software update configuration triggered the software update configuration run"]
     #[serde(default, skip_serializing_if = "Option::is_none")]
     pub name: Option<String>,
-    #[doc = "The properties of the update webhook."]
-    #[serde(default, skip_serializing_if = "Option::is_none")]
-    pub properties: Option<WebhookUpdateProperties>,
 }
-impl WebhookUpdateParameters {
+impl UpdateConfigurationNavigation {
     pub fn new() -> Self {
         Self::default()
     }
 }
-#[doc = "The properties of the update webhook."]
+#[doc = "Definition of Usage."]
 #[derive(Clone, Debug, PartialEq, Serialize, Deserialize, Default)]
-pub struct WebhookUpdateProperties {
-    #[doc = "Gets or sets the value of the enabled flag of webhook."]
-    #[serde(rename = "isEnabled", default, skip_serializing_if = "Option::is_none")]
-    pub is_enabled: Option<bool>,
-    #[doc = "Gets or sets the name of the hybrid worker group the webhook job will run on."]
-    #[serde(rename = "runOn", default, skip_serializing_if = "Option::is_none")]
-    pub run_on: Option<String>,
-    #[doc = "Gets or sets the parameters of the job."]
+pub struct Usage {
+    #[doc = "Gets or sets the id of the resource."]
     #[serde(default, skip_serializing_if = "Option::is_none")]
-    pub parameters: Option<serde_json::Value>,
-    #[doc = "Gets or sets the description of the webhook."]
+    pub id: Option<String>,
+    #[doc = "Definition of usage counter name."]
     #[serde(default, skip_serializing_if = "Option::is_none")]
-    pub description: Option<String>,
+    pub name: Option<UsageCounterName>,
+    #[doc = "Gets or sets the usage unit name."]
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub unit: Option<String>,
+    #[doc = "Gets or sets the current usage value."]
+    #[serde(rename = "currentValue", default, skip_serializing_if = "Option::is_none")]
+    pub current_value: Option<f64>,
+    #[doc = "Gets or sets max limit. -1 for unlimited"]
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub limit: Option<i64>,
+    #[doc = "Gets or sets the throttle status."]
+    #[serde(rename = "throttleStatus", default, skip_serializing_if = "Option::is_none")]
+    pub throttle_status: Option<String>,
 }
-impl WebhookUpdateProperties {
+impl Usage {
     pub fn new() -> Self {
         Self::default()
     }
 }
-#[doc = "Windows specific update configuration."]
+#[doc = "Definition of usage counter name."]
+#[derive(Clone, Debug, PartialEq, Eq, Hash, Serialize, Deserialize, Default)]
+pub struct UsageCounterName {
+    #[doc = "Gets or sets the usage counter name."]
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub value: Option<String>,
+    #[doc = "Gets or sets the localized usage counter name."]
+    #[serde(rename = "localizedValue", default, skip_serializing_if = "Option::is_none")]
+    pub localized_value: Option<String>,
+}
+impl UsageCounterName {
+    pub fn new() -> Self {
+        Self::default()
+    }
+}
+#[doc = "The response model for the get usage operation."]
 #[derive(Clone, Debug, PartialEq, Serialize, Deserialize, Default)]
-pub struct WindowsProperties {
-    #[doc = "Update classification included in the software update configuration. A comma separated string with required values"]
-    #[serde(rename = "includedUpdateClassifications", default, skip_serializing_if = "Option::is_none")]
-    pub included_update_classifications: Option<windows_properties::IncludedUpdateClassifications>,
-    #[doc = "KB numbers excluded from the software update configuration."]
-    #[serde(
-        rename = "excludedKbNumbers",
-        default,
-        deserialize_with = "azure_core::util::deserialize_null_as_default",
-        skip_serializing_if = "Vec::is_empty"
-    )]
-    pub excluded_kb_numbers: Vec<String>,
-    #[doc = "KB numbers included from the software update configuration."]
+pub struct UsageListResult {
+    #[doc = "Gets or sets usage."]
     #[serde(
-        rename = "includedKbNumbers",
         default,
         deserialize_with = "azure_core::util::deserialize_null_as_default",
         skip_serializing_if = "Vec::is_empty"
     )]
-    pub included_kb_numbers: Vec<String>,
-    #[doc = "Reboot setting for the software update configuration."]
-    #[serde(rename = "rebootSetting", default, skip_serializing_if = "Option::is_none")]
-    pub reboot_setting: Option<String>,
+    pub value: Vec<Usage>,
 }
-impl WindowsProperties {
+impl azure_core::Continuable for UsageListResult {
+    type Continuation = String;
+    fn continuation(&self) -> Option<Self::Continuation> {
+        None
+    }
+}
+impl UsageListResult {
     pub fn new() -> Self {
         Self::default()
     }
-}
-pub mod windows_properties {
-    use super::*;
-    #[doc = "Update classification included in the software update configuration. A comma separated string with required values"]
-    #[derive(Clone, Debug, PartialEq, Serialize, Deserialize)]
-    #[serde(remote = "IncludedUpdateClassifications")]
-    pub enum IncludedUpdateClassifications {
-        Unclassified,
-        Critical,
-        Security,
-        UpdateRollup,
-        FeaturePack,
-        ServicePack,
-        Definition,
-        Tools,
-        Updates,
-        #[serde(skip_deserializing)]
-        UnknownValue(String),
+    #[doc = "The items in this page."]
+    pub fn items(&self) -> &[Usage] {
+        &self.value
     }
-    impl FromStr for IncludedUpdateClassifications {
-        type Err = value::Error;
-        fn from_str(s: &str) -> std::result::Result<Self, Self::Err> {
-            Self::deserialize(s.into_deserializer())
-        }
+}
+#[derive(Clone, Debug, PartialEq, Eq, Hash, Serialize, Deserialize, Default)]
+pub struct UserAssignedIdentitiesProperties {
+    #[doc = "The principal id of user assigned identity."]
+    #[serde(rename = "principalId", default, skip_serializing_if = "Option::is_none")]
+    pub principal_id: Option<String>,
+    #[doc = "The client id of user assigned identity."]
+    #[serde(rename = "clientId", default, skip_serializing_if = "Option::is_none")]
+    pub client_id: Option<String>,
+}
+impl UserAssignedIdentitiesProperties {
+    pub fn new() -> Self {
+        Self::default()
     }
-    impl<'de> Deserialize<'de> for IncludedUpdateClassifications {
-        fn deserialize<D>(deserializer: D) -> std::result::Result<Self, D::Error>
-        where
-            D: Deserializer<'de>,
-        {
-            let s = String::deserialize(deserializer)?;
-            let deserialized = Self::from_str(&s).unwrap_or(Self::UnknownValue(s));
-            Ok(deserialized)
-        }
+}
+#[doc = "Definition of the variable."]
+#[derive(Clone, Debug, PartialEq, Eq, Hash, Serialize, Deserialize, Default)]
+pub struct Variable {
+    #[serde(flatten)]
+    pub proxy_resource: ProxyResource,
+    #[doc = "Definition of the variable properties"]
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub properties: Option<VariableProperties>,
+}
+impl Variable {
+    pub fn new() -> Self {
+        Self::default()
     }
-    impl Serialize for IncludedUpdateClassifications {
-        fn serialize<S>(&self, serializer: S) -> std::result::Result<S::Ok, S::Error>
-        where
-            S: Serializer,
-        {
-            match self {
-                Self::Unclassified => serializer.serialize_unit_variant("IncludedUpdateClassifications", 0u32, "Unclassified"),
-                Self::Critical => serializer.serialize_unit_variant("IncludedUpdateClassifications", 1u32, "Critical"),
-                Self::Security => serializer.serialize_unit_variant("IncludedUpdateClassifications", 2u32, "Security"),
-                Self::UpdateRollup => serializer.serialize_unit_variant("IncludedUpdateClassifications", 3u32, "UpdateRollup"),
-                Self::FeaturePack => serializer.serialize_unit_variant("IncludedUpdateClassifications", 4u32, "FeaturePack"),
-                Self::ServicePack => serializer.serialize_unit_variant("IncludedUpdateClassifications", 5u32, "ServicePack"),
-                Self::Definition => serializer.serialize_unit_variant("IncludedUpdateClassifications", 6u32, "Definition"),
-                Self::Tools => serializer.serialize_unit_variant("IncludedUpdateClassifications", 7u32, "Tools"),
-                Self::Updates => serializer.serialize_unit_variant("IncludedUpdateClassifications", 8u32, "Updates"),
-                Self::UnknownValue(s) => serializer.serialize_str(s.as_str()),
-            }
-        }
+}
+#[doc = "The parameters supplied to the create or update variable operation."]
+#[derive(Clone, Debug, PartialEq, Eq, Hash, Serialize, Deserialize)]
+pub struct VariableCreateOrUpdateParameters {
+    #[doc = "Gets or sets the name of the variable."]
+    pub name: String,
+    #[doc = "The properties of the create variable operation."]
+    pub properties: VariableCreateOrUpdateProperties,
+}
+impl VariableCreateOrUpdateParameters {
+    pub fn new(name: String, properties: VariableCreateOrUpdateProperties) -> Self {
+        Self { name, properties }
     }
 }
-#[doc = "Software update configuration machine run job navigation properties."]
-#[derive(Clone, Debug, PartialEq, Serialize, Deserialize, Default)]
-pub struct JobNavigation {
-    #[doc = "Id of the job associated with the software update configuration run"]
+#[doc = "The properties of the create variable operation."]
+#[derive(Clone, Debug, PartialEq, Eq, Hash, Serialize, Deserialize, Default)]
+pub struct VariableCreateOrUpdateProperties {
+    #[doc = "Gets or sets the value of the variable."]
     #[serde(default, skip_serializing_if = "Option::is_none")]
-    pub id: Option<String>,
+    pub value: Option<String>,
+    #[doc = "Gets or sets the description of the variable."]
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub description: Option<String>,
+    #[doc = "Gets or sets the encrypted flag of the variable."]
+    #[serde(rename = "isEncrypted", default, skip_serializing_if = "Option::is_none")]
+    pub is_encrypted: Option<bool>,
 }
-impl JobNavigation {
+impl VariableCreateOrUpdateProperties {
     pub fn new() -> Self {
         Self::default()
     }
 }
-#[doc = "Target operating system for the software update configuration."]
-#[derive(Clone, Debug, PartialEq, Serialize, Deserialize)]
-pub enum OperatingSystemType {
-    Windows,
-    Linux,
-}
-#[doc = "Gets or sets the frequency of the schedule."]
-#[derive(Clone, Debug, PartialEq, Serialize, Deserialize)]
-#[serde(remote = "ScheduleFrequency")]
-pub enum ScheduleFrequency {
-    OneTime,
-    Day,
-    Hour,
-    Week,
-    Month,
-    Minute,
-    #[serde(skip_deserializing)]
-    UnknownValue(String),
+#[doc = "The response model for the list variables operation."]
+#[derive(Clone, Debug, PartialEq, Eq, Hash, Serialize, Deserialize, Default)]
+pub struct VariableListResult {
+    #[doc = "Gets or sets a list of variables."]
+    #[serde(
+        default,
+        deserialize_with = "azure_core::util::deserialize_null_as_default",
+        skip_serializing_if = "Vec::is_empty"
+    )]
+    pub value: Vec<Variable>,
+    #[doc = "Gets or sets the next link."]
+    #[serde(rename = "nextLink", default, skip_serializing_if = "Option::is_none")]
+    pub next_link: Option<String>,
 }
-impl FromStr for ScheduleFrequency {
-    type Err = value::Error;
-    fn from_str(s: &str) -> std::result::Result<Self, Self::Err> {
-        Self::deserialize(s.into_deserializer())
+impl azure_core::Continuable for VariableListResult {
+    type Continuation = String;
+    fn continuation(&self) -> Option<Self::Continuation> {
+        self.next_link.clone()
     }
 }
-impl<'de> Deserialize<'de> for ScheduleFrequency {
-    fn deserialize<D>(deserializer: D) -> std::result::Result<Self, D::Error>
-    where
-        D: Deserializer<'de>,
-    {
-        let s = String::deserialize(deserializer)?;
-        let deserialized = Self::from_str(&s).unwrap_or(Self::UnknownValue(s));
-        Ok(deserialized)
+impl VariableListResult {
+    pub fn new() -> Self {
+        Self::default()
+    }
+    #[doc = "The items in this page."]
+    pub fn items(&self) -> &[Variable] {
+        &self.value
     }
 }
-impl Serialize for ScheduleFrequency {
-    fn serialize<S>(&self, serializer: S) -> std::result::Result<S::Ok, S::Error>
-    where
-        S: Serializer,
-    {
-        match self {
-            Self::OneTime => serializer.serialize_unit_variant("ScheduleFrequency", 0u32, "OneTime"),
-            Self::Day => serializer.serialize_unit_variant("ScheduleFrequency", 1u32, "Day"),
-            Self::Hour => serializer.serialize_unit_variant("ScheduleFrequency", 2u32, "Hour"),
-            Self::Week => serializer.serialize_unit_variant("ScheduleFrequency", 3u32, "Week"),
-            Self::Month => serializer.serialize_unit_variant("ScheduleFrequency", 4u32, "Month"),
-            Self::Minute => serializer.serialize_unit_variant("ScheduleFrequency", 5u32, "Minute"),
-            Self::UnknownValue(s) => serializer.serialize_str(s.as_str()),
-        }
+#[doc = "Definition of the variable properties"]
+#[derive(Clone, Debug, PartialEq, Eq, Hash, Serialize, Deserialize, Default)]
+pub struct VariableProperties {
+    #[doc = "Gets or sets the value of the variable."]
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub value: Option<String>,
+    #[doc = "Gets or sets the encrypted flag of the variable."]
+    #[serde(rename = "isEncrypted", default, skip_serializing_if = "Option::is_none")]
+    pub is_encrypted: Option<bool>,
+    #[doc = "Gets or sets the creation time."]
+    #[serde(
+        rename = "creationTime",
+        default,
+        with = "azure_core::date::iso8601::option",
+        skip_serializing_if = "Option::is_none"
+    )]
+    pub creation_time: Option<time::OffsetDateTime>,
+    #[doc = "Gets or sets the last modified time."]
+    #[serde(
+        rename = "lastModifiedTime",
+        default,
+        with = "azure_core::date::iso8601::option",
+        skip_serializing_if = "Option::is_none"
+    )]
+    pub last_modified_time: Option<time::OffsetDateTime>,
+    #[doc = "Gets or sets the description."]
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub description: Option<String>,
+}
+impl VariableProperties {
+    pub fn new() -> Self {
+        Self::default()
     }
 }
-#[doc = "Software update configuration properties."]
-#[derive(Clone, Debug, PartialEq, Serialize, Deserialize)]
-pub struct SoftwareUpdateConfiguration {
-    #[doc = "Resource name."]
+#[doc = "The parameters supplied to the update variable operation."]
+#[derive(Clone, Debug, PartialEq, Eq, Hash, Serialize, Deserialize, Default)]
+pub struct VariableUpdateParameters {
+    #[doc = "Gets or sets the name of the variable."]
     #[serde(default, skip_serializing_if = "Option::is_none")]
     pub name: Option<String>,
-    #[doc = "Resource Id."]
+    #[doc = "The properties of the update variable"]
     #[serde(default, skip_serializing_if = "Option::is_none")]
-    pub id: Option<String>,
-    #[doc = "Resource type"]
-    #[serde(rename = "type", default, skip_serializing_if = "Option::is_none")]
-    pub type_: Option<String>,
-    #[doc = "Software update configuration properties."]
-    pub properties: SoftwareUpdateConfigurationProperties,
+    pub properties: Option<VariableUpdateProperties>,
 }
-impl SoftwareUpdateConfiguration {
-    pub fn new(properties: SoftwareUpdateConfigurationProperties) -> Self {
-        Self {
-            name: None,
-            id: None,
-            type_: None,
-            properties,
-        }
+impl VariableUpdateParameters {
+    pub fn new() -> Self {
+        Self::default()
     }
 }
-#[doc = "Software update configuration collection item properties."]
-#[derive(Clone, Debug, PartialEq, Serialize, Deserialize)]
-pub struct SoftwareUpdateConfigurationCollectionItem {
-    #[doc = "Name of the software update configuration."]
+#[doc = "The properties of the update variable"]
+#[derive(Clone, Debug, PartialEq, Eq, Hash, Serialize, Deserialize, Default)]
+pub struct VariableUpdateProperties {
+    #[doc = "Gets or sets the value of the variable."]
     #[serde(default, skip_serializing_if = "Option::is_none")]
-    pub name: Option<String>,
-    #[doc = "Resource Id of the software update configuration"]
+    pub value: Option<String>,
+    #[doc = "Gets or sets the description of the variable."]
     #[serde(default, skip_serializing_if = "Option::is_none")]
-    pub id: Option<String>,
-    #[doc = "Software update configuration collection item properties."]
-    pub properties: SoftwareUpdateConfigurationCollectionItemProperties,
+    pub description: Option<String>,
 }
-impl SoftwareUpdateConfigurationCollectionItem {
-    pub fn new(properties: SoftwareUpdateConfigurationCollectionItemProperties) -> Self {
-        Self {
-            name: None,
-            id: None,
-            properties,
-        }
+impl VariableUpdateProperties {
+    pub fn new() -> Self {
+        Self::default()
     }
 }
-#[doc = "Software update configuration collection item properties."]
+#[doc = "Definition of the watcher type."]
 #[derive(Clone, Debug, PartialEq, Serialize, Deserialize, Default)]
-pub struct SoftwareUpdateConfigurationCollectionItemProperties {
-    #[doc = "Update specific properties of the software update configuration."]
-    #[serde(rename = "updateConfiguration", default, skip_serializing_if = "Option::is_none")]
-    pub update_configuration: Option<UpdateConfiguration>,
-    #[doc = "Task properties of the software update configuration."]
+pub struct Watcher {
+    #[serde(flatten)]
+    pub resource: Resource,
+    #[doc = "Definition of the watcher properties"]
     #[serde(default, skip_serializing_if = "Option::is_none")]
-    pub tasks: Option<SoftwareUpdateConfigurationTasks>,
-    #[doc = "Gets or sets the frequency of the schedule."]
+    pub properties: Option<WatcherProperties>,
+    #[doc = "Gets or sets the etag of the resource."]
     #[serde(default, skip_serializing_if = "Option::is_none")]
-    pub frequency: Option<ScheduleFrequency>,
-    #[doc = "the start time of the update."]
-    #[serde(rename = "startTime", default, with = "azure_core::date::rfc3339::option")]
-    pub start_time: Option<time::OffsetDateTime>,
-    #[doc = "Creation time of the software update configuration, which only appears in the response."]
-    #[serde(rename = "creationTime", default, with = "azure_core::date::rfc3339::option")]
-    pub creation_time: Option<time::OffsetDateTime>,
-    #[doc = "Last time software update configuration was modified, which only appears in the response."]
-    #[serde(rename = "lastModifiedTime", default, with = "azure_core::date::rfc3339::option")]
-    pub last_modified_time: Option<time::OffsetDateTime>,
-    #[doc = "Provisioning state for the software update configuration, which only appears in the response."]
-    #[serde(rename = "provisioningState", default, skip_serializing_if = "Option::is_none")]
-    pub provisioning_state: Option<String>,
-    #[doc = "ext run time of the update."]
-    #[serde(rename = "nextRun", default, with = "azure_core::date::rfc3339::option")]
-    pub next_run: Option<time::OffsetDateTime>,
+    pub etag: Option<String>,
+    #[doc = "Resource tags."]
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub tags: Option<serde_json::Value>,
+    #[doc = "The geo-location where the resource lives"]
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub location: Option<String>,
 }
-impl SoftwareUpdateConfigurationCollectionItemProperties {
+impl Watcher {
     pub fn new() -> Self {
         Self::default()
     }
 }
-#[doc = "result of listing all software update configuration"]
+#[doc = "The response model for the list watcher operation."]
 #[derive(Clone, Debug, PartialEq, Serialize, Deserialize, Default)]
-pub struct SoftwareUpdateConfigurationListResult {
-    #[doc = "outer object returned when listing all software update configurations"]
+pub struct WatcherListResult {
+    #[doc = "Gets or sets a list of watchers."]
     #[serde(
         default,
         deserialize_with = "azure_core::util::deserialize_null_as_default",
         skip_serializing_if = "Vec::is_empty"
     )]
-    pub value: Vec<SoftwareUpdateConfigurationCollectionItem>,
+    pub value: Vec<Watcher>,
+    #[doc = "Gets or sets the next link."]
+    #[serde(rename = "nextLink", default, skip_serializing_if = "Option::is_none")]
+    pub next_link: Option<String>,
+}
+impl azure_core::Continuable for WatcherListResult {
+    type Continuation = String;
+    fn continuation(&self) -> Option<Self::Continuation> {
+        self.next_link.clone()
+    }
+}
+impl WatcherListResult {
+    pub fn new() -> Self {
+        Self::default()
+    }
+    #[doc = "The items in this page."]
+    pub fn items(&self) -> &[Watcher] {
+        &self.value
+    }
+}
+#[doc = "Definition of the watcher properties"]
+#[derive(Clone, Debug, PartialEq, Serialize, Deserialize, Default)]
+pub struct WatcherProperties {
+    #[doc = "Gets or sets the frequency at which the watcher is invoked."]
+    #[serde(rename = "executionFrequencyInSeconds", default, skip_serializing_if = "Option::is_none")]
+    pub execution_frequency_in_seconds: Option<i64>,
+    #[doc = "Gets or sets the name of the script the watcher is attached to, i.e. the name of an existing runbook."]
+    #[serde(rename = "scriptName", default, skip_serializing_if = "Option::is_none")]
+    pub script_name: Option<String>,
+    #[doc = "Gets or sets the parameters of the script."]
+    #[serde(rename = "scriptParameters", default, skip_serializing_if = "Option::is_none")]
+    pub script_parameters: Option<serde_json::Value>,
+    #[doc = "Gets or sets the name of the hybrid worker group the watcher will run on."]
+    #[serde(rename = "scriptRunOn", default, skip_serializing_if = "Option::is_none")]
+    pub script_run_on: Option<String>,
+    #[doc = "Gets the current status of the watcher."]
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub status: Option<String>,
+    #[doc = "Gets or sets the creation time."]
+    #[serde(
+        rename = "creationTime",
+        default,
+        with = "azure_core::date::iso8601::option",
+        skip_serializing_if = "Option::is_none"
+    )]
+    pub creation_time: Option<time::OffsetDateTime>,
+    #[doc = "Gets or sets the last modified time."]
+    #[serde(
+        rename = "lastModifiedTime",
+        default,
+        with = "azure_core::date::iso8601::option",
+        skip_serializing_if = "Option::is_none"
+    )]
+    pub last_modified_time: Option<time::OffsetDateTime>,
+    #[doc = "Details of the user who last modified the watcher."]
+    #[serde(rename = "lastModifiedBy", default, skip_serializing_if = "Option::is_none")]
+    pub last_modified_by: Option<String>,
+    #[doc = "Gets or sets the description."]
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub description: Option<String>,
+    #[doc = "Fields of the response that this version of the crate has no field for, kept so that they are not lost when the model is sent back, such as in a read-modify-write update."]
+    #[serde(flatten, default, skip_serializing_if = "std::collections::HashMap::is_empty")]
+    pub extra: std::collections::HashMap<String, serde_json::Value>,
+}
+impl WatcherProperties {
+    pub fn new() -> Self {
+        Self::default()
+    }
+}
+#[derive(Clone, Debug, PartialEq, Eq, Hash, Serialize, Deserialize, Default)]
+pub struct WatcherUpdateParameters {
+    #[doc = "The properties of the update watcher operation."]
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub properties: Option<WatcherUpdateProperties>,
+    #[doc = "Gets or sets the name of the resource."]
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub name: Option<String>,
 }
-impl SoftwareUpdateConfigurationListResult {
+impl WatcherUpdateParameters {
     pub fn new() -> Self {
         Self::default()
     }
 }
-#[doc = "Software update configuration machine run model."]
-#[derive(Clone, Debug, PartialEq, Serialize, Deserialize, Default)]
-pub struct SoftwareUpdateConfigurationMachineRun {
-    #[doc = "Name of the software update configuration machine run"]
-    #[serde(default, skip_serializing_if = "Option::is_none")]
-    pub name: Option<String>,
-    #[doc = "Resource Id of the software update configuration machine run"]
-    #[serde(default, skip_serializing_if = "Option::is_none")]
-    pub id: Option<String>,
-    #[doc = "Software update configuration machine run properties."]
-    #[serde(default, skip_serializing_if = "Option::is_none")]
-    pub properties: Option<UpdateConfigurationMachineRunProperties>,
+#[doc = "The properties of the update watcher operation."]
+#[derive(Clone, Debug, PartialEq, Eq, Hash, Serialize, Deserialize, Default)]
+pub struct WatcherUpdateProperties {
+    #[doc = "Gets or sets the frequency at which the watcher is invoked."]
+    #[serde(rename = "executionFrequencyInSeconds", default, skip_serializing_if = "Option::is_none")]
+    pub execution_frequency_in_seconds: Option<i64>,
 }
-impl SoftwareUpdateConfigurationMachineRun {
+impl WatcherUpdateProperties {
     pub fn new() -> Self {
         Self::default()
     }
 }
-#[doc = "result of listing all software update configuration machine runs"]
+#[doc = "Definition of the webhook type."]
 #[derive(Clone, Debug, PartialEq, Serialize, Deserialize, Default)]
-pub struct SoftwareUpdateConfigurationMachineRunListResult {
-    #[doc = "outer object returned when listing all software update configuration machine runs"]
-    #[serde(
-        default,
-        deserialize_with = "azure_core::util::deserialize_null_as_default",
-        skip_serializing_if = "Vec::is_empty"
-    )]
-    pub value: Vec<SoftwareUpdateConfigurationMachineRun>,
-    #[doc = "link to next page of results."]
-    #[serde(rename = "nextLink", default, skip_serializing_if = "Option::is_none")]
-    pub next_link: Option<String>,
+pub struct Webhook {
+    #[serde(flatten)]
+    pub proxy_resource: ProxyResource,
+    #[doc = "Definition of the webhook properties"]
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub properties: Option<WebhookProperties>,
 }
-impl SoftwareUpdateConfigurationMachineRunListResult {
+impl Webhook {
     pub fn new() -> Self {
         Self::default()
     }
 }
-#[doc = "Software update configuration properties."]
+#[doc = "The parameters supplied to the create or update webhook operation."]
 #[derive(Clone, Debug, PartialEq, Serialize, Deserialize)]
-pub struct SoftwareUpdateConfigurationProperties {
-    #[doc = "Update specific properties of the software update configuration."]
-    #[serde(rename = "updateConfiguration")]
-    pub update_configuration: UpdateConfiguration,
-    #[doc = "Definition of schedule parameters."]
-    #[serde(rename = "scheduleInfo")]
-    pub schedule_info: SucScheduleProperties,
-    #[doc = "Provisioning state for the software update configuration, which only appears in the response."]
-    #[serde(rename = "provisioningState", default, skip_serializing_if = "Option::is_none")]
-    pub provisioning_state: Option<String>,
-    #[doc = "Error response of an operation failure"]
-    #[serde(default, skip_serializing_if = "Option::is_none")]
-    pub error: Option<ErrorResponse>,
-    #[doc = "Creation time of the resource, which only appears in the response."]
-    #[serde(rename = "creationTime", default, with = "azure_core::date::rfc3339::option")]
-    pub creation_time: Option<time::OffsetDateTime>,
-    #[doc = "CreatedBy property, which only appears in the response."]
-    #[serde(rename = "createdBy", default, skip_serializing_if = "Option::is_none")]
-    pub created_by: Option<String>,
-    #[doc = "Last time resource was modified, which only appears in the response."]
-    #[serde(rename = "lastModifiedTime", default, with = "azure_core::date::rfc3339::option")]
-    pub last_modified_time: Option<time::OffsetDateTime>,
-    #[doc = "LastModifiedBy property, which only appears in the response."]
-    #[serde(rename = "lastModifiedBy", default, skip_serializing_if = "Option::is_none")]
-    pub last_modified_by: Option<String>,
-    #[doc = "Task properties of the software update configuration."]
-    #[serde(default, skip_serializing_if = "Option::is_none")]
-    pub tasks: Option<SoftwareUpdateConfigurationTasks>,
+pub struct WebhookCreateOrUpdateParameters {
+    #[doc = "Gets or sets the name of the webhook."]
+    pub name: String,
+    #[doc = "The properties of the create webhook operation."]
+    pub properties: WebhookCreateOrUpdateProperties,
 }
-impl SoftwareUpdateConfigurationProperties {
-    pub fn new(update_configuration: UpdateConfiguration, schedule_info: SucScheduleProperties) -> Self {
-        Self {
-            update_configuration,
-            schedule_info,
-            provisioning_state: None,
-            error: None,
-            creation_time: None,
-            created_by: None,
-            last_modified_time: None,
-            last_modified_by: None,
-            tasks: None,
-        }
+impl WebhookCreateOrUpdateParameters {
+    pub fn new(name: String, properties: WebhookCreateOrUpdateProperties) -> Self {
+        Self { name, properties }
     }
 }
-#[doc = "Software update configuration Run properties."]
+#[doc = "The properties of the create webhook operation."]
 #[derive(Clone, Debug, PartialEq, Serialize, Deserialize, Default)]
-pub struct SoftwareUpdateConfigurationRun {
-    #[doc = "Name of the software update configuration run."]
+pub struct WebhookCreateOrUpdateProperties {
+    #[doc = "Gets or sets the value of the enabled flag of webhook."]
+    #[serde(rename = "isEnabled", default, skip_serializing_if = "Option::is_none")]
+    pub is_enabled: Option<bool>,
+    #[doc = "Gets or sets the uri."]
     #[serde(default, skip_serializing_if = "Option::is_none")]
-    pub name: Option<String>,
-    #[doc = "Resource Id of the software update configuration run"]
+    pub uri: Option<String>,
+    #[doc = "Gets or sets the expiry time."]
+    #[serde(
+        rename = "expiryTime",
+        default,
+        with = "azure_core::date::iso8601::option",
+        skip_serializing_if = "Option::is_none"
+    )]
+    pub expiry_time: Option<time::OffsetDateTime>,
+    #[doc = "Gets or sets the parameters of the job."]
     #[serde(default, skip_serializing_if = "Option::is_none")]
-    pub id: Option<String>,
-    #[doc = "Software update configuration properties."]
+    pub parameters: Option<serde_json::Value>,
+    #[doc = "The runbook property associated with the entity."]
     #[serde(default, skip_serializing_if = "Option::is_none")]
-    pub properties: Option<SoftwareUpdateConfigurationRunProperties>,
+    pub runbook: Option<RunbookAssociationProperty>,
+    #[doc = "Gets or sets the name of the hybrid worker group the webhook job will run on."]
+    #[serde(rename = "runOn", default, skip_serializing_if = "Option::is_none")]
+    pub run_on: Option<String>,
 }
-impl SoftwareUpdateConfigurationRun {
+impl WebhookCreateOrUpdateProperties {
     pub fn new() -> Self {
         Self::default()
     }
 }
-#[doc = "result of listing all software update configuration runs"]
+#[doc = "The response model for the list webhook operation."]
 #[derive(Clone, Debug, PartialEq, Serialize, Deserialize, Default)]
-pub struct SoftwareUpdateConfigurationRunListResult {
-    #[doc = "outer object returned when listing all software update configuration runs"]
+pub struct WebhookListResult {
+    #[doc = "Gets or sets a list of webhooks."]
     #[serde(
         default,
         deserialize_with = "azure_core::util::deserialize_null_as_default",
         skip_serializing_if = "Vec::is_empty"
     )]
-    pub value: Vec<SoftwareUpdateConfigurationRun>,
-    #[doc = "link to next page of results."]
+    pub value: Vec<Webhook>,
+    #[doc = "Gets or sets the next link."]
     #[serde(rename = "nextLink", default, skip_serializing_if = "Option::is_none")]
     pub next_link: Option<String>,
 }
-impl SoftwareUpdateConfigurationRunListResult {
+impl azure_core::Continuable for WebhookListResult {
+    type Continuation = String;
+    fn continuation(&self) -> Option<Self::Continuation> {
+        self.next_link.clone()
+    }
+}
+impl WebhookListResult {
     pub fn new() -> Self {
         Self::default()
     }
+    #[doc = "The items in this page."]
+    pub fn items(&self) -> &[Webhook] {
+        &self.value
+    }
 }
-#[doc = "Software update configuration properties."]
+#[doc = "Definition of the webhook properties"]
 #[derive(Clone, Debug, PartialEq, Serialize, Deserialize, Default)]
-pub struct SoftwareUpdateConfigurationRunProperties {
-    #[doc = "Software update configuration Run Navigation model."]
-    #[serde(rename = "softwareUpdateConfiguration", default, skip_serializing_if = "Option::is_none")]
-    pub software_update_configuration: Option<UpdateConfigurationNavigation>,
-    #[doc = "Status of the software update configuration run."]
+pub struct WebhookProperties {
+    #[doc = "Gets or sets the value of the enabled flag of the webhook."]
+    #[serde(rename = "isEnabled", default, skip_serializing_if = "Option::is_none")]
+    pub is_enabled: Option<bool>,
+    #[doc = "Gets or sets the webhook uri."]
     #[serde(default, skip_serializing_if = "Option::is_none")]
-    pub status: Option<String>,
-    #[doc = "Configured duration for the software update configuration run."]
-    #[serde(rename = "configuredDuration", default, skip_serializing_if = "Option::is_none")]
-    pub configured_duration: Option<String>,
-    #[doc = "Operating system target of the software update configuration triggered this run"]
-    #[serde(rename = "osType", default, skip_serializing_if = "Option::is_none")]
-    pub os_type: Option<String>,
-    #[doc = "Start time of the software update configuration run."]
-    #[serde(rename = "startTime", default, with = "azure_core::date::rfc3339::option")]
-    pub start_time: Option<time::OffsetDateTime>,
-    #[doc = "End time of the software update configuration run."]
-    #[serde(rename = "endTime", default, with = "azure_core::date::rfc3339::option")]
-    pub end_time: Option<time::OffsetDateTime>,
-    #[doc = "Number of computers in the software update configuration run."]
-    #[serde(rename = "computerCount", default, skip_serializing_if = "Option::is_none")]
-    pub computer_count: Option<i64>,
-    #[doc = "Number of computers with failed status."]
-    #[serde(rename = "failedCount", default, skip_serializing_if = "Option::is_none")]
-    pub failed_count: Option<i64>,
-    #[doc = "Creation time of the resource, which only appears in the response."]
-    #[serde(rename = "creationTime", default, with = "azure_core::date::rfc3339::option")]
+    pub uri: Option<String>,
+    #[doc = "Gets or sets the expiry time."]
+    #[serde(
+        rename = "expiryTime",
+        default,
+        with = "azure_core::date::iso8601::option",
+        skip_serializing_if = "Option::is_none"
+    )]
+    pub expiry_time: Option<time::OffsetDateTime>,
+    #[doc = "Gets or sets the last invoked time."]
+    #[serde(
+        rename = "lastInvokedTime",
+        default,
+        with = "azure_core::date::iso8601::option",
+        skip_serializing_if = "Option::is_none"
+    )]
+    pub last_invoked_time: Option<time::OffsetDateTime>,
+    #[doc = "Gets or sets the parameters of the job that is created when the webhook calls the runbook it is associated with."]
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub parameters: Option<serde_json::Value>,
+    #[doc = "The runbook property associated with the entity."]
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub runbook: Option<RunbookAssociationProperty>,
+    #[doc = "Gets or sets the name of the hybrid worker group the webhook job will run on."]
+    #[serde(rename = "runOn", default, skip_serializing_if = "Option::is_none")]
+    pub run_on: Option<String>,
+    #[doc = "Gets or sets the creation time."]
+    #[serde(
+        rename = "creationTime",
+        default,
+        with = "azure_core::date::iso8601::option",
+        skip_serializing_if = "Option::is_none"
+    )]
     pub creation_time: Option<time::OffsetDateTime>,
-    #[doc = "CreatedBy property, which only appears in the response."]
-    #[serde(rename = "createdBy", default, skip_serializing_if = "Option::is_none")]
-    pub created_by: Option<String>,
-    #[doc = "Last time resource was modified, which only appears in the response."]
-    #[serde(rename = "lastModifiedTime", default, with = "azure_core::date::rfc3339::option")]
+    #[doc = "Gets or sets the last modified time."]
+    #[serde(
+        rename = "lastModifiedTime",
+        default,
+        with = "azure_core::date::iso8601::option",
+        skip_serializing_if = "Option::is_none"
+    )]
     pub last_modified_time: Option<time::OffsetDateTime>,
-    #[doc = "LastModifiedBy property, which only appears in the response."]
+    #[doc = "Details of the user who last modified the Webhook"]
     #[serde(rename = "lastModifiedBy", default, skip_serializing_if = "Option::is_none")]
     pub last_modified_by: Option<String>,
-    #[doc = "Software update configuration run tasks model."]
+    #[doc = "Gets or sets the description."]
     #[serde(default, skip_serializing_if = "Option::is_none")]
-    pub tasks: Option<SoftwareUpdateConfigurationRunTasks>,
+    pub description: Option<String>,
+    #[doc = "Fields of the response that this version of the crate has no field for, kept so that they are not lost when the model is sent back, such as in a read-modify-write update."]
+    #[serde(flatten, default, skip_serializing_if = "std::collections::HashMap::is_empty")]
+    pub extra: std::collections::HashMap<String, serde_json::Value>,
 }
-impl SoftwareUpdateConfigurationRunProperties {
+impl WebhookProperties {
     pub fn new() -> Self {
         Self::default()
     }
 }
-#[doc = "Task properties of the software update configuration."]
+#[doc = "The parameters supplied to the update webhook operation."]
 #[derive(Clone, Debug, PartialEq, Serialize, Deserialize, Default)]
-pub struct SoftwareUpdateConfigurationRunTaskProperties {
-    #[doc = "The status of the task."]
+pub struct WebhookUpdateParameters {
+    #[doc = "Gets or sets the name of the webhook."]
     #[serde(default, skip_serializing_if = "Option::is_none")]
-    pub status: Option<String>,
-    #[doc = "The name of the source of the task."]
+    pub name: Option<String>,
+    #[doc = "The properties of the update webhook."]
     #[serde(default, skip_serializing_if = "Option::is_none")]
-    pub source: Option<String>,
-    #[doc = "The job id of the task."]
-    #[serde(rename = "jobId", default, skip_serializing_if = "Option::is_none")]
-    pub job_id: Option<String>,
-}
-impl SoftwareUpdateConfigurationRunTaskProperties {
-    pub fn new() -> Self {
-        Self::default()
-    }
-}
-#[doc = "Software update configuration run tasks model."]
-#[derive(Clone, Debug, PartialEq, Serialize, Deserialize, Default)]
-pub struct SoftwareUpdateConfigurationRunTasks {
-    #[doc = "Task properties of the software update configuration."]
-    #[serde(rename = "preTask", default, skip_serializing_if = "Option::is_none")]
-    pub pre_task: Option<SoftwareUpdateConfigurationRunTaskProperties>,
-    #[doc = "Task properties of the software update configuration."]
-    #[serde(rename = "postTask", default, skip_serializing_if = "Option::is_none")]
-    pub post_task: Option<SoftwareUpdateConfigurationRunTaskProperties>,
+    pub properties: Option<WebhookUpdateProperties>,
 }
-impl SoftwareUpdateConfigurationRunTasks {
+impl WebhookUpdateParameters {
     pub fn new() -> Self {
         Self::default()
     }
 }
-#[doc = "Task properties of the software update configuration."]
+#[doc = "The properties of the update webhook."]
 #[derive(Clone, Debug, PartialEq, Serialize, Deserialize, Default)]
-pub struct SoftwareUpdateConfigurationTasks {
-    #[doc = "Task properties of the software update configuration."]
-    #[serde(rename = "preTask", default, skip_serializing_if = "Option::is_none")]
-    pub pre_task: Option<TaskProperties>,
-    #[doc = "Task properties of the software update configuration."]
-    #[serde(rename = "postTask", default, skip_serializing_if = "Option::is_none")]
-    pub post_task: Option<TaskProperties>,
+pub struct WebhookUpdateProperties {
+    #[doc = "Gets or sets the value of the enabled flag of webhook."]
+    #[serde(rename = "isEnabled", default, skip_serializing_if = "Option::is_none")]
+    pub is_enabled: Option<bool>,
+    #[doc = "Gets or sets the name of the hybrid worker group the webhook job will run on."]
+    #[serde(rename = "runOn", default, skip_serializing_if = "Option::is_none")]
+    pub run_on: Option<String>,
+    #[doc = "Gets or sets the parameters of the job."]
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub parameters: Option<serde_json::Value>,
+    #[doc = "Gets or sets the description of the webhook."]
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub description: Option<String>,
 }
-impl SoftwareUpdateConfigurationTasks {
+impl WebhookUpdateProperties {
     pub fn new() -> Self {
         Self::default()
     }
 }
-#[doc = "Metadata pertaining to creation and last modification of the resource."]
-#[derive(Clone, Debug, PartialEq, Serialize, Deserialize, Default)]
-pub struct SystemData {
-    #[doc = "The identity that created the resource."]
-    #[serde(rename = "createdBy", default, skip_serializing_if = "Option::is_none")]
-    pub created_by: Option<String>,
-    #[doc = "The type of identity that created the resource."]
-    #[serde(rename = "createdByType", default, skip_serializing_if = "Option::is_none")]
-    pub created_by_type: Option<system_data::CreatedByType>,
-    #[doc = "The timestamp of resource creation (UTC)."]
-    #[serde(rename = "createdAt", default, with = "azure_core::date::rfc3339::option")]
-    pub created_at: Option<time::OffsetDateTime>,
-    #[doc = "The identity that last modified the resource."]
-    #[serde(rename = "lastModifiedBy", default, skip_serializing_if = "Option::is_none")]
-    pub last_modified_by: Option<String>,
-    #[doc = "The type of identity that last modified the resource."]
-    #[serde(rename = "lastModifiedByType", default, skip_serializing_if = "Option::is_none")]
-    pub last_modified_by_type: Option<system_data::LastModifiedByType>,
-    #[doc = "The timestamp of resource last modification (UTC)"]
-    #[serde(rename = "lastModifiedAt", default, with = "azure_core::date::rfc3339::option")]
-    pub last_modified_at: Option<time::OffsetDateTime>,
+#[doc = "Windows specific update configuration."]
+#[derive(Clone, Debug, PartialEq, Eq, Hash, Serialize, Deserialize, Default)]
+pub struct WindowsProperties {
+    #[doc = "Update classification included in the software update configuration. A comma separated string with required values"]
+    #[serde(rename = "includedUpdateClassifications", default, skip_serializing_if = "Option::is_none")]
+    pub included_update_classifications: Option<windows_properties::IncludedUpdateClassifications>,
+    #[doc = "KB numbers excluded from the software update configuration."]
+    #[serde(
+        rename = "excludedKbNumbers",
+        default,
+        deserialize_with = "azure_core::util::deserialize_null_as_default",
+        skip_serializing_if = "Vec::is_empty"
+    )]
+    pub excluded_kb_numbers: Vec<String>,
+    #[doc = "KB numbers included from the software update configuration."]
+    #[serde(
+        rename = "includedKbNumbers",
+        default,
+        deserialize_with = "azure_core::util::deserialize_null_as_default",
+        skip_serializing_if = "Vec::is_empty"
+    )]
+    pub included_kb_numbers: Vec<String>,
+    #[doc = "Reboot setting for the software update configuration."]
+    #[serde(rename = "rebootSetting", default, skip_serializing_if = "Option::is_none")]
+    pub reboot_setting: Option<String>,
 }
-impl SystemData {
+impl WindowsProperties {
     pub fn new() -> Self {
         Self::default()
     }
 }
-pub mod system_data {
+pub mod windows_properties {
     use super::*;
-    #[doc = "The type of identity that created the resource."]
-    #[derive(Clone, Debug, PartialEq, Serialize, Deserialize)]
-    #[serde(remote = "CreatedByType")]
-    pub enum CreatedByType {
-        User,
-        Application,
-        ManagedIdentity,
-        Key,
+    #[doc = "Update classification included in the software update configuration. A comma separated string with required values"]
+    #[derive(Clone, Debug, PartialEq, Eq, Hash, Serialize, Deserialize)]
+    #[serde(remote = "IncludedUpdateClassifications")]
+    pub enum IncludedUpdateClassifications {
+        Unclassified,
+        Critical,
+        Security,
+        UpdateRollup,
+        FeaturePack,
+        ServicePack,
+        Definition,
+        Tools,
+        Updates,
         #[serde(skip_deserializing)]
         UnknownValue(String),
     }
-    impl FromStr for CreatedByType {
+    impl FromStr for IncludedUpdateClassifications {
         type Err = value::Error;
         fn from_str(s: &str) -> std::result::Result<Self, Self::Err> {
             Self::deserialize(s.into_deserializer())
         }
     }
-    impl<'de> Deserialize<'de> for CreatedByType {
+    impl<'de> Deserialize<'de> for IncludedUpdateClassifications {
         fn deserialize<D>(deserializer: D) -> std::result::Result<Self, D::Error>
         where
             D: Deserializer<'de>,
@@ -7018,191 +8564,46 @@ pub mod system_data {
             Ok(deserialized)
         }
     }
-    impl Serialize for CreatedByType {
+    impl Serialize for IncludedUpdateClassifications {
         fn serialize<S>(&self, serializer: S) -> std::result::Result<S::Ok, S::Error>
         where
             S: Serializer,
         {
             match self {
-                Self::User => serializer.serialize_unit_variant("CreatedByType", 0u32, "User"),
-                Self::Application => serializer.serialize_unit_variant("CreatedByType", 1u32, "Application"),
-                Self::ManagedIdentity => serializer.serialize_unit_variant("CreatedByType", 2u32, "ManagedIdentity"),
-                Self::Key => serializer.serialize_unit_variant("CreatedByType", 3u32, "Key"),
+                Self::Unclassified => serializer.serialize_unit_variant("IncludedUpdateClassifications", 0u32, "Unclassified"),
+                Self::Critical => serializer.serialize_unit_variant("IncludedUpdateClassifications", 1u32, "Critical"),
+                Self::Security => serializer.serialize_unit_variant("IncludedUpdateClassifications", 2u32, "Security"),
+                Self::UpdateRollup => serializer.serialize_unit_variant("IncludedUpdateClassifications", 3u32, "UpdateRollup"),
+                Self::FeaturePack => serializer.serialize_unit_variant("IncludedUpdateClassifications", 4u32, "FeaturePack"),
+                Self::ServicePack => serializer.serialize_unit_variant("IncludedUpdateClassifications", 5u32, "ServicePack"),
+                Self::Definition => serializer.serialize_unit_variant("IncludedUpdateClassifications", 6u32, "Definition"),
+                Self::Tools => serializer.serialize_unit_variant("IncludedUpdateClassifications", 7u32, "Tools"),
+                Self::Updates => serializer.serialize_unit_variant("IncludedUpdateClassifications", 8u32, "Updates"),
                 Self::UnknownValue(s) => serializer.serialize_str(s.as_str()),
             }
         }
     }
-    #[doc = "The type of identity that last modified the resource."]
-    #[derive(Clone, Debug, PartialEq, Serialize, Deserialize)]
-    #[serde(remote = "LastModifiedByType")]
-    pub enum LastModifiedByType {
-        User,
-        Application,
-        ManagedIdentity,
-        Key,
-        #[serde(skip_deserializing)]
-        UnknownValue(String),
-    }
-    impl FromStr for LastModifiedByType {
-        type Err = value::Error;
-        fn from_str(s: &str) -> std::result::Result<Self, Self::Err> {
-            Self::deserialize(s.into_deserializer())
-        }
-    }
-    impl<'de> Deserialize<'de> for LastModifiedByType {
-        fn deserialize<D>(deserializer: D) -> std::result::Result<Self, D::Error>
-        where
-            D: Deserializer<'de>,
-        {
-            let s = String::deserialize(deserializer)?;
-            let deserialized = Self::from_str(&s).unwrap_or(Self::UnknownValue(s));
-            Ok(deserialized)
-        }
-    }
-    impl Serialize for LastModifiedByType {
-        fn serialize<S>(&self, serializer: S) -> std::result::Result<S::Ok, S::Error>
-        where
-            S: Serializer,
-        {
+    impl IncludedUpdateClassifications {
+        #[doc = "The value as it is sent on the wire."]
+        pub fn as_str(&self) -> &str {
             match self {
-                Self::User => serializer.serialize_unit_variant("LastModifiedByType", 0u32, "User"),
-                Self::Application => serializer.serialize_unit_variant("LastModifiedByType", 1u32, "Application"),
-                Self::ManagedIdentity => serializer.serialize_unit_variant("LastModifiedByType", 2u32, "ManagedIdentity"),
-                Self::Key => serializer.serialize_unit_variant("LastModifiedByType", 3u32, "Key"),
-                Self::UnknownValue(s) => serializer.serialize_str(s.as_str()),
+                Self::Unclassified => "Unclassified",
+                Self::Critical => "Critical",
+                Self::Security => "Security",
+                Self::UpdateRollup => "UpdateRollup",
+                Self::FeaturePack => "FeaturePack",
+                Self::ServicePack => "ServicePack",
+                Self::Definition => "Definition",
+                Self::Tools => "Tools",
+                Self::Updates => "Updates",
+                Self::UnknownValue(s) => s.as_str(),
             }
         }
     }
-}
-#[doc = "Task properties of the software update configuration."]
-#[derive(Clone, Debug, PartialEq, Serialize, Deserialize, Default)]
-pub struct TaskProperties {
-    #[doc = "Gets or sets the parameters of the task."]
-    #[serde(default, skip_serializing_if = "Option::is_none")]
-    pub parameters: Option<serde_json::Value>,
-    #[doc = "Gets or sets the name of the runbook."]
-    #[serde(default, skip_serializing_if = "Option::is_none")]
-    pub source: Option<String>,
-}
-impl TaskProperties {
-    pub fn new() -> Self {
-        Self::default()
-    }
-}
-#[doc = "Update specific properties of the software update configuration."]
-#[derive(Clone, Debug, PartialEq, Serialize, Deserialize)]
-pub struct UpdateConfiguration {
-    #[doc = "Target operating system for the software update configuration."]
-    #[serde(rename = "operatingSystem")]
-    pub operating_system: OperatingSystemType,
-    #[doc = "Windows specific update configuration."]
-    #[serde(default, skip_serializing_if = "Option::is_none")]
-    pub windows: Option<WindowsProperties>,
-    #[doc = "Linux specific update configuration."]
-    #[serde(default, skip_serializing_if = "Option::is_none")]
-    pub linux: Option<LinuxProperties>,
-    #[doc = "Maximum time allowed for the software update configuration run. Duration needs to be specified using the format PT[n]H[n]M[n]S as per ISO8601"]
-    #[serde(default, skip_serializing_if = "Option::is_none")]
-    pub duration: Option<String>,
-    #[doc = "List of azure resource Ids for azure virtual machines targeted by the software update configuration."]
-    #[serde(
-        rename = "azureVirtualMachines",
-        default,
-        deserialize_with = "azure_core::util::deserialize_null_as_default",
-        skip_serializing_if = "Vec::is_empty"
-    )]
-    pub azure_virtual_machines: Vec<String>,
-    #[doc = "List of names of non-azure machines targeted by the software update configuration."]
-    #[serde(
-        rename = "nonAzureComputerNames",
-        default,
-        deserialize_with = "azure_core::util::deserialize_null_as_default",
-        skip_serializing_if = "Vec::is_empty"
-    )]
-    pub non_azure_computer_names: Vec<String>,
-    #[doc = "Group specific to the update configuration."]
-    #[serde(default, skip_serializing_if = "Option::is_none")]
-    pub targets: Option<TargetProperties>,
-}
-impl UpdateConfiguration {
-    pub fn new(operating_system: OperatingSystemType) -> Self {
-        Self {
-            operating_system,
-            windows: None,
-            linux: None,
-            duration: None,
-            azure_virtual_machines: Vec::new(),
-            non_azure_computer_names: Vec::new(),
-            targets: None,
+    impl std::fmt::Display for IncludedUpdateClassifications {
+        fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+            f.write_str(self.as_str())
         }
     }
 }
-#[doc = "Software update configuration machine run properties."]
-#[derive(Clone, Debug, PartialEq, Serialize, Deserialize, Default)]
-pub struct UpdateConfigurationMachineRunProperties {
-    #[doc = "name of the updated computer"]
-    #[serde(rename = "targetComputer", default, skip_serializing_if = "Option::is_none")]
-    pub target_computer: Option<String>,
-    #[doc = "type of the updated computer."]
-    #[serde(rename = "targetComputerType", default, skip_serializing_if = "Option::is_none")]
-    pub target_computer_type: Option<String>,
-    #[doc = "Software update configuration Run Navigation model."]
-    #[serde(rename = "softwareUpdateConfiguration", default, skip_serializing_if = "Option::is_none")]
-    pub software_update_configuration: Option<UpdateConfigurationNavigation>,
-    #[doc = "Status of the software update configuration machine run."]
-    #[serde(default, skip_serializing_if = "Option::is_none")]
-    pub status: Option<String>,
-    #[doc = "Operating system target of the software update configuration triggered this run"]
-    #[serde(rename = "osType", default, skip_serializing_if = "Option::is_none")]
-    pub os_type: Option<String>,
-    #[doc = "correlation id of the software update configuration machine run"]
-    #[serde(rename = "correlationId", default, skip_serializing_if = "Option::is_none")]
-    pub correlation_id: Option<String>,
-    #[doc = "source computer id of the software update configuration machine run"]
-    #[serde(rename = "sourceComputerId", default, skip_serializing_if = "Option::is_none")]
-    pub source_computer_id: Option<String>,
-    #[doc = "Start time of the software update configuration machine run."]
-    #[serde(rename = "startTime", default, with = "azure_core::date::rfc3339::option")]
-    pub start_time: Option<time::OffsetDateTime>,
-    #[doc = "End time of the software update configuration machine run."]
-    #[serde(rename = "endTime", default, with = "azure_core::date::rfc3339::option")]
-    pub end_time: Option<time::OffsetDateTime>,
-    #[doc = "configured duration for the software update configuration run."]
-    #[serde(rename = "configuredDuration", default, skip_serializing_if = "Option::is_none")]
-    pub configured_duration: Option<String>,
-    #[doc = "Software update configuration machine run job navigation properties."]
-    #[serde(default, skip_serializing_if = "Option::is_none")]
-    pub job: Option<JobNavigation>,
-    #[doc = "Creation time of the resource, which only appears in the response."]
-    #[serde(rename = "creationTime", default, with = "azure_core::date::rfc3339::option")]
-    pub creation_time: Option<time::OffsetDateTime>,
-    #[doc = "createdBy property, which only appears in the response."]
-    #[serde(rename = "createdBy", default, skip_serializing_if = "Option::is_none")]
-    pub created_by: Option<String>,
-    #[doc = "Last time resource was modified, which only appears in the response."]
-    #[serde(rename = "lastModifiedTime", default, with = "azure_core::date::rfc3339::option")]
-    pub last_modified_time: Option<time::OffsetDateTime>,
-    #[doc = "lastModifiedBy property, which only appears in the response."]
-    #[serde(rename = "lastModifiedBy", default, skip_serializing_if = "Option::is_none")]
-    pub last_modified_by: Option<String>,
-    #[doc = "Error response of an operation failure"]
-    #[serde(default, skip_serializing_if = "Option::is_none")]
-    pub error: Option<ErrorResponse>,
-}
-impl UpdateConfigurationMachineRunProperties {
-    pub fn new() -> Self {
-        Self::default()
-    }
-}
-#[doc = "Software update configuration Run Navigation model."]
-#[derive(Clone, Debug, PartialEq, Serialize, Deserialize, Default)]
-pub struct UpdateConfigurationNavigation {
-    #[doc = "Name of the software update configuration triggered the software update configuration run"]
-    #[serde(default, skip_serializing_if = "Option::is_none")]
-    pub name: Option<String>,
-}
-impl UpdateConfigurationNavigation {
-    pub fn new() -> Self {
-        Self::default()
-    }
-}
+include!("../../extensions/package_2022_08_08/models.rs");
@@ -0,0 +1,59 @@
+//! `Accept-Language` is sent on every request, so the service can localize its error messages.
+
+mod common;
+
+use azure_core::headers::{Headers, ACCEPT_LANGUAGE};
+use azure_core::StatusCode;
+use azure_mgmt_automation::package_2022_08_08::Client;
+use common::MockTransport;
+use serde_json::json;
+use std::sync::Arc;
+
+/// Answers every request with `404 Not Found`, its message in the language asked for.
+fn localizing_transport() -> MockTransport {
+    MockTransport::new(|request| {
+        let message = match request.headers().get_optional_str(&ACCEPT_LANGUAGE) {
+            Some("fr-FR") => "Le compte est introuvable.",
+            Some("de-DE") => "Das Konto wurde nicht gefunden.",
+            _ => "The account was not found.",
+        };
+        let body = json!({ "error": { "code": "ResourceNotFound", "message": message } }).to_string();
+        common::response(StatusCode::NotFound, Headers::new(), body)
+    })
+}
+
+fn client(accept_language: Option<&str>) -> Client {
+    let mut builder = common::client_builder(Arc::new(localizing_transport()));
+    if let Some(accept_language) = accept_language {
+        builder = builder.accept_language(accept_language);
+    }
+    builder.build()
+}
+
+async fn error_message(client: &Client, accept_language: Option<&str>) -> String {
+    let mut get = client
+        .automation_account_client()
+        .get("resource_group", "automation_account", "subscription_id");
+    if let Some(accept_language) = accept_language {
+        get = get.accept_language(accept_language);
+    }
+    let err = get.send().await.map(drop).unwrap_err();
+    let http_error = err.as_http_error().unwrap();
+    assert_eq!(http_error.error_code(), Some("ResourceNotFound"));
+    http_error.error_message().unwrap().to_owned()
+}
+
+#[tokio::test]
+async fn client_language_is_sent() {
+    assert_eq!(error_message(&client(None), None).await, "The account was not found.");
+    assert_eq!(error_message(&client(Some("fr-FR")), None).await, "Le compte est introuvable.");
+}
+
+#[tokio::test]
+async fn request_language_overrides_the_client() {
+    assert_eq!(
+        error_message(&client(Some("fr-FR")), Some("de-DE")).await,
+        "Das Konto wurde nicht gefunden."
+    );
+    assert_eq!(error_message(&client(None), Some("de-DE")).await, "Das Konto wurde nicht gefunden.");
+}
@@ -0,0 +1,55 @@
+//! The SKU and managed identity of an automation account are built from typed values and
+//! deserialized tolerantly.
+
+use azure_mgmt_automation::package_2022_08_08::models::{identity, sku, AutomationAccountCreateOrUpdateParameters, Identity};
+use serde_json::json;
+
+const IDENTITY_A: &str = "/subscriptions/s/resourceGroups/rg/providers/Microsoft.ManagedIdentity/userAssignedIdentities/a";
+const IDENTITY_B: &str = "/subscriptions/s/resourceGroups/rg/providers/Microsoft.ManagedIdentity/userAssignedIdentities/b";
+
+#[test]
+fn account_with_sku_and_identities() {
+    let parameters = AutomationAccountCreateOrUpdateParameters::new()
+        .location("westus")
+        .sku(sku::Name::Basic)
+        .identity(Identity::system_assigned().with_user_assigned(IDENTITY_A));
+    assert_eq!(
+        serde_json::to_value(&parameters).unwrap(),
+        json!({
+            "location": "westus",
+            "properties": { "sku": { "name": "Basic" } },
+            "identity": {
+                "type": "SystemAssigned, UserAssigned",
+                "userAssignedIdentities": { IDENTITY_A: {} },
+            },
+        })
+    );
+}
+
+#[test]
+fn identity_type_follows_the_identities() {
+    let identity = Identity::user_assigned(IDENTITY_A).with_user_assigned(IDENTITY_B);
+    assert_eq!(identity.type_, Some(identity::Type::UserAssigned));
+    assert_eq!(identity.user_assigned_ids(), [IDENTITY_A, IDENTITY_B]);
+    assert_eq!(
+        identity.with_system_assigned().type_,
+        Some(identity::Type::SystemAssignedUserAssigned)
+    );
+    assert_eq!(Identity::none().with_system_assigned().type_, Some(identity::Type::SystemAssigned));
+    assert_eq!(serde_json::to_value(Identity::none()).unwrap(), json!({ "type": "None" }));
+}
+
+#[test]
+fn unknown_identity_types_are_kept() {
+    let identity: Identity = serde_json::from_value(json!({ "type": "SystemAssigned, FederatedCredential" })).unwrap();
+    assert_eq!(
+        identity.type_,
+        Some(identity::Type::UnknownValue("SystemAssigned, FederatedCredential".to_owned()))
+    );
+    assert_eq!(
+        serde_json::to_value(&identity).unwrap(),
+        json!({ "type": "SystemAssigned, FederatedCredential" })
+    );
+    let sku: sku::Name = serde_json::from_value(json!("Premium")).unwrap();
+    assert_eq!(sku.as_str(), "Premium");
+}
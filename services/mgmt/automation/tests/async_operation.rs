@@ -0,0 +1,85 @@
+//! `Poller` follows an `Azure-AsyncOperation` status monitor, with a final `GET` only when asked for.
+
+mod common;
+
+use azure_core::error::ErrorKind;
+use azure_core::headers::{Headers, AZURE_ASYNCOPERATION, LOCATION};
+use azure_core::{Method, StatusCode};
+use azure_mgmt_automation::package_2022_08_08::{FinalGet, PollOptions, Poller};
+use common::MockTransport;
+use std::sync::atomic::{AtomicUsize, Ordering};
+use std::sync::Arc;
+use std::time::Duration;
+
+const MONITOR: &str = "https://management.azure.com/operations/1";
+const RESULT: &str = "https://management.azure.com/operationResults/1";
+
+/// Accepts a deletion with both a status monitor and a `Location`. The monitor reports
+/// `InProgress` once, then `outcome`; `Location` answers with the result.
+fn async_operation_transport(outcome: &'static str) -> Arc<MockTransport> {
+    let monitor_polls = AtomicUsize::new(0);
+    Arc::new(MockTransport::new(move |request| {
+        let mut headers = Headers::new();
+        let body = match (request.method(), request.url().as_str()) {
+            (Method::Delete, _) => {
+                headers.insert(AZURE_ASYNCOPERATION, MONITOR);
+                headers.insert(LOCATION, RESULT);
+                return common::response(StatusCode::Accepted, headers, "");
+            }
+            (_, MONITOR) if monitor_polls.fetch_add(1, Ordering::SeqCst) == 0 => r#"{"status":"InProgress"}"#.to_owned(),
+            (_, MONITOR) => format!(
+                r#"{{"status":"{}","error":{{"code":"Conflict","message":"account is locked"}}}}"#,
+                outcome
+            ),
+            _ => r#"{"result":"from location"}"#.to_owned(),
+        };
+        common::response(StatusCode::Ok, headers, body)
+    }))
+}
+
+/// How many requests `transport` was sent to `url`.
+fn requests_to(transport: &MockTransport, url: &str) -> usize {
+    transport.requests().iter().filter(|request| request.url().as_str() == url).count()
+}
+
+async fn begin(transport: Arc<MockTransport>) -> Poller {
+    common::client(transport)
+        .automation_account_client()
+        .delete("resource_group", "automation_account", "subscription_id")
+        .begin()
+        .await
+        .unwrap()
+}
+
+fn options() -> PollOptions {
+    PollOptions::new().interval(Duration::ZERO)
+}
+
+#[tokio::test]
+async fn result_is_fetched_from_location_by_default() {
+    let transport = async_operation_transport("Succeeded");
+    let poller = begin(transport.clone()).await;
+    poller.wait_with(options()).await.unwrap();
+    assert_eq!(requests_to(&transport, MONITOR), 2);
+    assert_eq!(requests_to(&transport, RESULT), 1);
+    assert_eq!(poller.final_body().unwrap(), r#"{"result":"from location"}"#);
+}
+
+#[tokio::test]
+async fn final_get_can_be_skipped() {
+    let transport = async_operation_transport("Succeeded");
+    let poller = begin(transport.clone()).await.final_get(FinalGet::Never);
+    poller.wait_with(options()).await.unwrap();
+    assert_eq!(requests_to(&transport, RESULT), 0);
+    let body: serde_json::Value = serde_json::from_slice(&poller.final_body().unwrap()).unwrap();
+    assert_eq!(body["status"], "Succeeded");
+}
+
+#[tokio::test]
+async fn failed_operation_carries_the_service_error() {
+    let transport = async_operation_transport("Failed");
+    let err = begin(transport.clone()).await.wait_with(options()).await.unwrap_err();
+    assert!(matches!(err.kind(), ErrorKind::Other));
+    assert!(err.to_string().contains("Conflict"), "{}", err);
+    assert_eq!(requests_to(&transport, RESULT), 0);
+}
@@ -0,0 +1,63 @@
+//! `ClientBuilder::audience` sets the scope tokens are requested for, unless `scopes` is set.
+
+mod common;
+
+use azure_core::auth::{TokenCredential, TokenResponse};
+use azure_mgmt_automation::package_2022_08_08::{Client, ClientBuilder};
+use common::MockTransport;
+use std::sync::{Arc, Mutex};
+use time::OffsetDateTime;
+
+/// Keeps the scopes it was asked for a token for.
+#[derive(Debug, Default)]
+struct ScopeCredential {
+    scopes: Mutex<Vec<String>>,
+}
+
+#[async_trait::async_trait]
+impl TokenCredential for ScopeCredential {
+    async fn get_token(&self, resource: &str) -> azure_core::Result<TokenResponse> {
+        self.scopes.lock().unwrap().push(resource.to_owned());
+        Ok(TokenResponse::new(
+            "token".to_owned().into(),
+            OffsetDateTime::now_utc() + time::Duration::hours(1),
+        ))
+    }
+}
+
+async fn requested_scope(configure: impl FnOnce(ClientBuilder) -> ClientBuilder) -> String {
+    let credential = Arc::new(ScopeCredential::default());
+    let client = configure(Client::builder(credential.clone()).http_client(Arc::new(MockTransport::ok()))).build();
+    client.check_credential().await.unwrap();
+    let scopes = credential.scopes.lock().unwrap();
+    scopes.last().unwrap().clone()
+}
+
+#[tokio::test]
+async fn audience_sets_the_default_scope() {
+    assert_eq!(requested_scope(|builder| builder).await, "https://management.azure.com/");
+    assert_eq!(
+        requested_scope(|builder| builder.audience("https://management.core.windows.net/")).await,
+        "https://management.core.windows.net/.default"
+    );
+    assert_eq!(
+        requested_scope(|builder| builder.scope_suffix("").audience("https://management.core.windows.net")).await,
+        "https://management.core.windows.net/.default"
+    );
+}
+
+#[tokio::test]
+async fn scopes_take_precedence_over_audience() {
+    assert_eq!(
+        requested_scope(|builder| builder.audience("https://management.core.windows.net/").scopes(&["custom"])).await,
+        "custom"
+    );
+}
+
+#[test]
+fn try_build_checks_the_audience() {
+    let credential = Arc::new(ScopeCredential::default());
+    let builder = Client::builder(credential).audience("https://management.core.windows.net/");
+    assert!(builder.clone().try_build().is_err());
+    assert!(builder.validate_scopes(false).try_build().is_ok());
+}
@@ -0,0 +1,147 @@
+//! `Client::batch` sends reads as ARM `$batch` requests and returns the responses in order, split into
+//! successes and failures on request.
+
+mod common;
+
+use azure_core::StatusCode;
+use azure_mgmt_automation::package_2022_08_08::{batch, models};
+use common::MockTransport;
+use serde_json::json;
+use std::sync::Arc;
+
+/// Answers every `$batch` request in reverse order, with `404 Not Found` for the node named `missing`.
+fn batch_transport() -> Arc<MockTransport> {
+    Arc::new(MockTransport::new(|request| {
+        assert_eq!(request.url().path(), "/batch");
+        let body: serde_json::Value = serde_json::from_slice(common::body(request)).unwrap();
+        let responses: Vec<_> = body["requests"]
+            .as_array()
+            .unwrap()
+            .iter()
+            .rev()
+            .map(|request| {
+                assert_eq!(request["httpMethod"], "GET");
+                let url = request["url"].as_str().unwrap();
+                let node = url.split('?').next().unwrap().rsplit('/').next().unwrap();
+                if node == "missing" {
+                    json!({
+                        "name": request["name"],
+                        "httpStatusCode": 404,
+                        "content": { "error": { "code": "NotFound", "message": "node not found" } },
+                    })
+                } else {
+                    json!({
+                        "name": request["name"],
+                        "httpStatusCode": 200,
+                        "headers": { "ETag": "\"1\"" },
+                        "content": { "name": node },
+                    })
+                }
+            })
+            .collect();
+        common::json(json!({ "responses": responses }))
+    }))
+}
+
+/// The number of requests in each `$batch` request `transport` was sent.
+fn batch_sizes(transport: &MockTransport) -> Vec<usize> {
+    transport
+        .requests()
+        .iter()
+        .map(|request| {
+            let body: serde_json::Value = serde_json::from_slice(common::body(request)).unwrap();
+            body["requests"].as_array().unwrap().len()
+        })
+        .collect()
+}
+
+#[tokio::test]
+async fn responses_come_in_request_order() {
+    let transport = batch_transport();
+    let client = common::client(transport.clone());
+    let dsc_node = client.dsc_node_client();
+    let mut batch = client.batch();
+    for node_id in ["node-1", "missing", "node-2"] {
+        batch
+            .push(&dsc_node.get("resource_group", "automation_account", node_id, "subscription_id"))
+            .unwrap();
+    }
+    let mut responses = batch.send().await.unwrap().into_iter();
+
+    let first = responses.next().unwrap();
+    assert_eq!(first.status(), StatusCode::Ok);
+    assert_eq!(first.header("etag"), Some("\"1\""));
+    let node: models::DscNode = first.into_body().unwrap();
+    assert_eq!(node.proxy_resource.resource.name.as_deref(), Some("node-1"));
+
+    let missing = responses.next().unwrap();
+    assert_eq!(missing.status(), StatusCode::NotFound);
+    let err = missing.into_value().unwrap_err();
+    assert!(matches!(
+        err.kind(),
+        azure_core::error::ErrorKind::HttpResponse { status: StatusCode::NotFound, error_code: Some(code) } if code == "NotFound"
+    ));
+
+    assert_eq!(responses.next().unwrap().into_value().unwrap()["name"], "node-2");
+    assert!(responses.next().is_none());
+    assert_eq!(batch_sizes(&transport), [3]);
+}
+
+#[tokio::test]
+async fn large_batches_are_split() {
+    let transport = batch_transport();
+    let client = common::client(transport.clone());
+    let dsc_node = client.dsc_node_client();
+    let mut batch = client.batch();
+    for index in 0..batch::MAX_REQUESTS + 1 {
+        batch
+            .push(&dsc_node.get("resource_group", "automation_account", format!("node-{}", index), "subscription_id"))
+            .unwrap();
+    }
+    let responses = batch.send().await.unwrap();
+    assert_eq!(responses.len(), batch::MAX_REQUESTS + 1);
+    assert_eq!(
+        responses.responses()[batch::MAX_REQUESTS].clone().into_value().unwrap()["name"],
+        "node-500"
+    );
+    assert_eq!(batch_sizes(&transport), [batch::MAX_REQUESTS, 1]);
+}
+
+#[tokio::test]
+async fn partial_success_is_split() {
+    let client = common::client(batch_transport());
+    let dsc_node = client.dsc_node_client();
+    let mut batch = client.batch();
+    for node_id in ["node-1", "missing", "node-2"] {
+        batch
+            .push(&dsc_node.get("resource_group", "automation_account", node_id, "subscription_id"))
+            .unwrap();
+    }
+    let response = batch.send().await.unwrap();
+    assert!(response.is_partial_success());
+    assert!(!response.all_succeeded());
+
+    let (succeeded, failed) = response.clone().partition();
+    assert_eq!(succeeded.iter().map(|(index, _)| *index).collect::<Vec<_>>(), [0, 2]);
+    assert_eq!(failed.len(), 1);
+    assert_eq!(failed[0].1.status(), StatusCode::NotFound);
+
+    let (nodes, failures) = response.split::<models::DscNode>();
+    let names: Vec<_> = nodes
+        .into_iter()
+        .map(|(index, node)| (index, node.proxy_resource.resource.name.unwrap()))
+        .collect();
+    assert_eq!(names, [(0, "node-1".to_owned()), (2, "node-2".to_owned())]);
+    assert_eq!(failures.len(), 1);
+    assert_eq!(failures[0].0, 1);
+}
+
+#[test]
+fn empty_arguments_are_rejected_when_pushed() {
+    let client = common::client(batch_transport());
+    let mut batch = client.batch();
+    assert!(batch
+        .push(&client.dsc_node_client().get("resource_group", "", "node-1", "subscription_id"))
+        .is_err());
+    assert!(batch.is_empty());
+}
@@ -0,0 +1,57 @@
+//! `Poller::cancel` fails a pending wait and stops polling; the deletion itself is not cancelled on the service.
+
+mod common;
+
+use azure_core::error::ErrorKind;
+use azure_core::headers::{Headers, LOCATION};
+use azure_core::StatusCode;
+use azure_mgmt_automation::package_2022_08_08::{PollOptions, Poller};
+use common::MockTransport;
+use std::sync::Arc;
+use std::time::Duration;
+
+/// Accepts a deletion and keeps answering polls with `202 Accepted`.
+fn pending_deletion_transport() -> Arc<MockTransport> {
+    Arc::new(MockTransport::new(|_| {
+        let mut headers = Headers::new();
+        headers.insert(LOCATION, "https://management.azure.com/operationResults/1");
+        common::response(StatusCode::Accepted, headers, "")
+    }))
+}
+
+async fn begin(transport: Arc<MockTransport>) -> Poller {
+    common::client(transport)
+        .automation_account_client()
+        .delete("resource_group", "automation_account", "subscription_id")
+        .begin()
+        .await
+        .unwrap()
+}
+
+#[tokio::test]
+async fn cancel_fails_the_pending_wait_and_stops_polling() {
+    let transport = pending_deletion_transport();
+    let poller = begin(transport.clone()).await;
+    let wait = tokio::spawn(poller.wait_with(PollOptions::new().interval(Duration::from_millis(10))));
+    tokio::time::sleep(Duration::from_millis(100)).await;
+    assert!(transport.request_count() > 1, "polling had not started");
+
+    assert_eq!(poller.cancel().await, StatusCode::Accepted);
+    let err = wait.await.unwrap().unwrap_err();
+    assert!(matches!(err.kind(), ErrorKind::Other), "{:?}", err.kind());
+    assert!(err.to_string().contains("automation_account::delete was cancelled"), "{}", err);
+
+    let sent = transport.request_count();
+    tokio::time::sleep(Duration::from_millis(100)).await;
+    assert_eq!(transport.request_count(), sent);
+}
+
+#[tokio::test]
+async fn wait_after_cancel_sends_nothing() {
+    let transport = pending_deletion_transport();
+    let poller = begin(transport.clone()).await;
+    poller.cancel().await;
+    let err = poller.wait_with(PollOptions::new().interval(Duration::ZERO)).await.unwrap_err();
+    assert!(matches!(err.kind(), ErrorKind::Other), "{:?}", err.kind());
+    assert_eq!(transport.request_count(), 1);
+}
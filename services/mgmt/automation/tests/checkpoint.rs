@@ -0,0 +1,72 @@
+//! `into_checkpoint_stream` pairs each page with the token that resumes after it, and
+//! `resume_from` carries on from such a token.
+
+mod common;
+
+use azure_mgmt_automation::package_2022_08_08::dsc_node::list_by_automation_account::RequestBuilder;
+use azure_mgmt_automation::package_2022_08_08::models::DscNode;
+use common::MockTransport;
+use futures::TryStreamExt;
+use serde_json::json;
+use std::sync::Arc;
+
+const NODES: &str = "https://management.azure.com/subscriptions/subscription_id/resourceGroups/resource_group/providers/Microsoft.Automation/automationAccounts/automation_account/nodes";
+
+/// Serves three pages of two nodes each.
+fn three_page_transport() -> Arc<MockTransport> {
+    Arc::new(MockTransport::new(|request| {
+        let page: usize = common::query(request, "$skiptoken").map_or(0, |page| page.parse().unwrap());
+        let value: Vec<_> = (0..2).map(|n| json!({ "name": format!("node-{}", page * 2 + n) })).collect();
+        let next_link = (page < 2).then(|| format!("{NODES}?api-version=2020-01-13-preview&$skiptoken={}", page + 1));
+        common::json(json!({ "value": value, "nextLink": next_link }))
+    }))
+}
+
+fn list(transport: Arc<MockTransport>) -> RequestBuilder {
+    common::client(transport)
+        .dsc_node_client()
+        .list_by_automation_account("resource_group", "automation_account", "subscription_id")
+}
+
+/// The names of the nodes of each page, with the page's continuation token.
+fn names(pages: Vec<(Vec<DscNode>, Option<String>)>) -> Vec<(Vec<String>, Option<String>)> {
+    pages
+        .into_iter()
+        .map(|(nodes, continuation)| {
+            let names = nodes.into_iter().map(|node| node.proxy_resource.resource.name.unwrap()).collect();
+            (names, continuation)
+        })
+        .collect()
+}
+
+#[tokio::test]
+async fn each_page_comes_with_the_token_after_it() {
+    let pages = list(three_page_transport()).into_checkpoint_stream().try_collect().await.unwrap();
+    let token = |page| Some(format!("{NODES}?api-version=2020-01-13-preview&$skiptoken={page}"));
+    assert_eq!(
+        names(pages),
+        [
+            (vec!["node-0".to_owned(), "node-1".to_owned()], token(1)),
+            (vec!["node-2".to_owned(), "node-3".to_owned()], token(2)),
+            (vec!["node-4".to_owned(), "node-5".to_owned()], None),
+        ]
+    );
+}
+
+#[tokio::test]
+async fn resume_from_starts_after_the_checkpoint() {
+    let first: Vec<_> = list(three_page_transport()).into_checkpoint_stream().try_collect().await.unwrap();
+    let checkpoint = first[0].1.clone().unwrap();
+
+    let transport = three_page_transport();
+    let pages = list(transport.clone())
+        .resume_from(checkpoint)
+        .into_checkpoint_stream()
+        .try_collect()
+        .await
+        .unwrap();
+    let names: Vec<_> = names(pages).into_iter().flat_map(|(names, _)| names).collect();
+    assert_eq!(names, ["node-2", "node-3", "node-4", "node-5"]);
+    assert_eq!(transport.request_count(), 2);
+    assert_eq!(common::query(&transport.requests()[0], "$skiptoken").as_deref(), Some("1"));
+}
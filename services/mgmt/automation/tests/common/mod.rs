@@ -0,0 +1,118 @@
+//! A mock transport and client setup shared by the integration tests.
+#![allow(dead_code)]
+
+use azure_core::auth::StaticTokenCredential;
+use azure_core::headers::Headers;
+use azure_core::{Body, HttpClient, Request, Response, StatusCode};
+use azure_mgmt_automation::package_2022_08_08::{Client, ClientBuilder};
+use std::sync::{Arc, Mutex};
+use std::time::Duration;
+use time::OffsetDateTime;
+
+type Respond = dyn Fn(&Request) -> Response + Send + Sync;
+
+/// Answers every request with what `respond` returns for it, after `delay`, and keeps every
+/// request it was sent.
+pub struct MockTransport {
+    respond: Box<Respond>,
+    delay: Duration,
+    requests: Mutex<Vec<Request>>,
+}
+
+impl MockTransport {
+    pub fn new(respond: impl Fn(&Request) -> Response + Send + Sync + 'static) -> Self {
+        Self {
+            respond: Box::new(respond),
+            delay: Duration::ZERO,
+            requests: Mutex::new(Vec::new()),
+        }
+    }
+
+    /// Answers every request with an empty `200 OK`.
+    pub fn ok() -> Self {
+        Self::new(|_| response(StatusCode::Ok, Headers::new(), ""))
+    }
+
+    /// Fails the test if a request reaches it.
+    pub fn unreachable() -> Self {
+        Self::new(|request| panic!("unexpected request to {}", request.url()))
+    }
+
+    /// Block the calling thread for `delay` before answering, as a slow service would.
+    pub fn delay(mut self, delay: Duration) -> Self {
+        self.delay = delay;
+        self
+    }
+
+    /// The requests sent so far, in order.
+    pub fn requests(&self) -> Vec<Request> {
+        self.requests.lock().unwrap().clone()
+    }
+
+    /// The headers of the requests sent so far, in order.
+    pub fn headers(&self) -> Vec<Headers> {
+        self.requests().iter().map(|request| request.headers().clone()).collect()
+    }
+
+    pub fn request_count(&self) -> usize {
+        self.requests.lock().unwrap().len()
+    }
+}
+
+impl std::fmt::Debug for MockTransport {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.debug_struct("MockTransport").field("delay", &self.delay).finish_non_exhaustive()
+    }
+}
+
+#[async_trait::async_trait]
+impl HttpClient for MockTransport {
+    async fn execute_request(&self, request: &Request) -> azure_core::Result<Response> {
+        if !self.delay.is_zero() {
+            std::thread::sleep(self.delay);
+        }
+        self.requests.lock().unwrap().push(request.clone());
+        Ok((self.respond)(request))
+    }
+}
+
+/// A response with `body` as its only chunk, or with no body at all if it is empty.
+pub fn response(status: StatusCode, headers: Headers, body: impl Into<bytes::Bytes>) -> Response {
+    let body = body.into();
+    if body.is_empty() {
+        return Response::new(status, headers, Box::pin(futures::stream::empty()));
+    }
+    Response::new(status, headers, Box::pin(futures::stream::once(async move { Ok(body) })))
+}
+
+/// A `200 OK` with `body` as JSON.
+pub fn json(body: serde_json::Value) -> Response {
+    response(StatusCode::Ok, Headers::new(), body.to_string())
+}
+
+/// The bytes of a request body.
+pub fn body(request: &Request) -> &[u8] {
+    match request.body() {
+        Body::Bytes(bytes) => bytes,
+        Body::SeekableStream(_) => panic!("expected a body of bytes"),
+    }
+}
+
+/// The value of the query parameter `name` of `request`, if any.
+pub fn query(request: &Request, name: &str) -> Option<String> {
+    request
+        .url()
+        .query_pairs()
+        .find(|(key, _)| key == name)
+        .map(|(_, value)| value.into_owned())
+}
+
+/// A builder for a client that sends its requests to `transport`, authenticated with a static token.
+pub fn client_builder(transport: Arc<dyn HttpClient>) -> ClientBuilder {
+    let credential = StaticTokenCredential::new("token", OffsetDateTime::now_utc() + time::Duration::days(1));
+    Client::builder(Arc::new(credential)).http_client(transport)
+}
+
+pub fn client(transport: Arc<dyn HttpClient>) -> Client {
+    client_builder(transport).build()
+}
@@ -0,0 +1,22 @@
+//! ETags read with `get` are compared weakly, since proxies may mark them as weak in transit.
+
+use azure_core::headers::{Headers, ETAG};
+use azure_mgmt_automation::package_2022_08_08::ConcurrencyToken;
+
+#[test]
+fn token_is_read_from_the_etag_header() {
+    let mut headers = Headers::new();
+    assert_eq!(ConcurrencyToken::from_headers(&headers), None);
+    headers.insert(ETAG, "\"00000000-0000-0000-0000-000000000001\"");
+    let token = ConcurrencyToken::from_headers(&headers).unwrap();
+    assert_eq!(token.etag(), "\"00000000-0000-0000-0000-000000000001\"");
+}
+
+#[test]
+fn weak_etags_match_their_strong_form() {
+    let token = ConcurrencyToken::new("\"1\"");
+    assert!(token.matches("\"1\""));
+    assert!(token.matches("W/\"1\""));
+    assert!(ConcurrencyToken::new("W/\"1\"").matches("\"1\""));
+    assert!(!token.matches("\"2\""));
+}
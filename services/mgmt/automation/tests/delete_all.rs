@@ -0,0 +1,86 @@
+//! `source_control::Client::delete_all` deletes every source control of every page, at most
+//! `DELETE_ALL_CONCURRENCY` at a time, and reports each failure by name.
+
+mod common;
+
+use azure_core::{HttpClient, Method, Request, Response, StatusCode};
+use azure_mgmt_automation::package_2022_08_08::source_control::DELETE_ALL_CONCURRENCY;
+use serde_json::json;
+use std::sync::atomic::{AtomicUsize, Ordering};
+use std::sync::Arc;
+use std::time::Duration;
+
+const SOURCE_CONTROLS: &str = "https://management.azure.com/subscriptions/subscription_id/resourceGroups/resource_group/providers/Microsoft.Automation/automationAccounts/automation_account/sourceControls";
+
+/// Lists ten source controls over two pages and answers each delete after a short wait, keeping
+/// track of how many deletes were in flight at once. Deleting `sc-3` fails with a conflict.
+#[derive(Debug, Default)]
+struct DeletingTransport {
+    in_flight: AtomicUsize,
+    max_in_flight: AtomicUsize,
+    deletes: AtomicUsize,
+}
+
+#[async_trait::async_trait]
+impl HttpClient for DeletingTransport {
+    async fn execute_request(&self, request: &Request) -> azure_core::Result<Response> {
+        if *request.method() == Method::Get {
+            let (names, next_link) = match common::query(request, "$skiptoken") {
+                None => (0..5, Some(format!("{SOURCE_CONTROLS}?api-version=2022-08-08&$skiptoken=2"))),
+                Some(_) => (5..10, None),
+            };
+            let value: Vec<_> = names.map(|n| json!({ "name": format!("sc-{n}") })).collect();
+            return Ok(common::json(json!({ "value": value, "nextLink": next_link })));
+        }
+        let in_flight = self.in_flight.fetch_add(1, Ordering::SeqCst) + 1;
+        self.max_in_flight.fetch_max(in_flight, Ordering::SeqCst);
+        tokio::time::sleep(Duration::from_millis(20)).await;
+        self.in_flight.fetch_sub(1, Ordering::SeqCst);
+        self.deletes.fetch_add(1, Ordering::SeqCst);
+        let status = if request.url().path().ends_with("/sc-3") {
+            StatusCode::Conflict
+        } else {
+            StatusCode::Ok
+        };
+        Ok(common::response(status, Default::default(), ""))
+    }
+}
+
+#[tokio::test]
+async fn every_source_control_is_deleted_with_bounded_concurrency() {
+    let transport = Arc::new(DeletingTransport::default());
+    let mut results = common::client(transport.clone())
+        .source_control_client()
+        .delete_all("resource_group", "automation_account", "subscription_id")
+        .await
+        .unwrap();
+    results.sort_by(|a, b| a.0.cmp(&b.0));
+
+    let names: Vec<_> = results.iter().map(|(name, _)| name.as_str()).collect();
+    assert_eq!(names, (0..10).map(|n| format!("sc-{n}")).collect::<Vec<_>>());
+    let failed: Vec<_> = results
+        .iter()
+        .filter(|(_, result)| result.is_err())
+        .map(|(name, _)| name.as_str())
+        .collect();
+    assert_eq!(failed, ["sc-3"]);
+
+    assert_eq!(transport.deletes.load(Ordering::SeqCst), 10);
+    let max_in_flight = transport.max_in_flight.load(Ordering::SeqCst);
+    assert!(max_in_flight > 1, "deletes were sent one at a time");
+    assert!(max_in_flight <= DELETE_ALL_CONCURRENCY, "{max_in_flight} deletes in flight");
+}
+
+#[tokio::test]
+async fn a_failed_listing_deletes_nothing() {
+    let transport = Arc::new(common::MockTransport::new(|request| {
+        assert_eq!(*request.method(), Method::Get, "unexpected delete");
+        common::response(StatusCode::NotFound, Default::default(), "")
+    }));
+    let result = common::client(transport.clone())
+        .source_control_client()
+        .delete_all("resource_group", "automation_account", "subscription_id")
+        .await;
+    assert!(result.is_err());
+    assert_eq!(transport.request_count(), 1);
+}
@@ -0,0 +1,74 @@
+//! `ClientBuilder::dry_run` answers `PUT`, `PATCH` and `DELETE` itself, echoing the request body,
+//! and still sends everything else.
+
+mod common;
+
+use azure_core::Method;
+use azure_mgmt_automation::package_2022_08_08::models::{Variable, VariableCreateOrUpdateParameters, VariableCreateOrUpdateProperties};
+use azure_mgmt_automation::package_2022_08_08::Client;
+use common::MockTransport;
+use serde_json::json;
+use std::sync::Arc;
+
+fn dry_run_client(transport: Arc<MockTransport>) -> Client {
+    common::client_builder(transport).dry_run(true).build()
+}
+
+async fn create_variable(client: &Client) -> azure_core::Result<Variable> {
+    let mut properties = VariableCreateOrUpdateProperties::new();
+    properties.value = Some("\"value\"".to_owned());
+    client
+        .variable_client()
+        .create_or_update(
+            "resource_group",
+            "automation_account",
+            "variable",
+            VariableCreateOrUpdateParameters::new("variable".to_owned(), properties),
+            "subscription_id",
+        )
+        .into_future()
+        .await
+}
+
+#[tokio::test]
+async fn mutating_requests_are_not_sent() {
+    let transport = Arc::new(MockTransport::unreachable());
+    let client = dry_run_client(transport.clone());
+
+    let variable = create_variable(&client).await.unwrap();
+    assert_eq!(variable.properties.unwrap().value.as_deref(), Some("\"value\""));
+    client
+        .variable_client()
+        .delete("resource_group", "automation_account", "variable", "subscription_id")
+        .send()
+        .await
+        .unwrap();
+    assert_eq!(transport.request_count(), 0);
+}
+
+#[tokio::test]
+async fn reads_and_actions_are_still_sent() {
+    let transport = Arc::new(MockTransport::new(|_| common::json(json!({ "keys": [] }))));
+    let client = dry_run_client(transport.clone());
+    client
+        .keys_client()
+        .list_by_automation_account("resource_group", "automation_account", "subscription_id")
+        .send()
+        .await
+        .unwrap();
+    client
+        .variable_client()
+        .get("resource_group", "automation_account", "variable", "subscription_id")
+        .send()
+        .await
+        .unwrap();
+    let methods: Vec<_> = transport.requests().iter().map(|request| *request.method()).collect();
+    assert_eq!(methods, [Method::Post, Method::Get]);
+}
+
+#[tokio::test]
+async fn mutating_requests_are_sent_by_default() {
+    let transport = Arc::new(MockTransport::new(|_| common::json(json!({}))));
+    create_variable(&common::client(transport.clone())).await.unwrap();
+    assert_eq!(*transport.requests()[0].method(), Method::Put);
+}
@@ -0,0 +1,38 @@
+//! Responses report how long their request took.
+
+mod common;
+
+use azure_mgmt_automation::package_2022_08_08::Client;
+use common::MockTransport;
+use std::sync::Arc;
+use std::time::Duration;
+
+const DELAY: Duration = Duration::from_millis(50);
+
+fn client(dry_run: bool) -> Client {
+    common::client_builder(Arc::new(MockTransport::ok().delay(DELAY)))
+        .dry_run(dry_run)
+        .build()
+}
+
+#[tokio::test]
+async fn elapsed_covers_the_round_trip() {
+    let rsp = client(false)
+        .dsc_node_client()
+        .get("resource_group", "automation_account", "node_id", "subscription_id")
+        .send()
+        .await
+        .unwrap();
+    assert!(rsp.elapsed() >= DELAY);
+}
+
+#[tokio::test]
+async fn dry_run_responses_take_no_time() {
+    let rsp = client(true)
+        .dsc_node_client()
+        .delete("resource_group", "automation_account", "node_id", "subscription_id")
+        .send()
+        .await
+        .unwrap();
+    assert_eq!(rsp.elapsed(), Duration::ZERO);
+}
@@ -0,0 +1,20 @@
+//! An empty path argument fails the request before anything is sent.
+
+mod common;
+
+use azure_core::error::ErrorKind;
+use common::MockTransport;
+use std::sync::Arc;
+
+#[tokio::test]
+async fn empty_path_argument_is_rejected_before_sending() {
+    let err = common::client(Arc::new(MockTransport::unreachable()))
+        .dsc_node_client()
+        .get("resource_group", "", "node_id", "subscription_id")
+        .send()
+        .await
+        .err()
+        .unwrap();
+    assert_eq!(err.kind(), &ErrorKind::Other);
+    assert_eq!(err.to_string(), "required argument `automation_account_name` is empty");
+}
@@ -0,0 +1,35 @@
+//! `get_if_exists` returns `None` for a `404 Not Found` and still fails on any other error.
+
+mod common;
+
+use azure_core::StatusCode;
+use common::MockTransport;
+use serde_json::json;
+use std::sync::Arc;
+
+async fn get_node(transport: MockTransport) -> azure_core::Result<Option<String>> {
+    let node = common::client(Arc::new(transport))
+        .dsc_node_client()
+        .get("resource_group", "automation_account", "node", "subscription_id")
+        .get_if_exists()
+        .await?;
+    Ok(node.map(|node| node.proxy_resource.resource.name.unwrap()))
+}
+
+#[tokio::test]
+async fn an_existing_resource_is_returned() {
+    let node = get_node(MockTransport::new(|_| common::json(json!({ "name": "node" })))).await;
+    assert_eq!(node.unwrap().as_deref(), Some("node"));
+}
+
+#[tokio::test]
+async fn not_found_is_none() {
+    let not_found = MockTransport::new(|_| common::response(StatusCode::NotFound, Default::default(), ""));
+    assert_eq!(get_node(not_found).await.unwrap(), None);
+}
+
+#[tokio::test]
+async fn other_errors_are_returned() {
+    let forbidden = MockTransport::new(|_| common::response(StatusCode::Forbidden, Default::default(), ""));
+    assert!(get_node(forbidden).await.is_err());
+}
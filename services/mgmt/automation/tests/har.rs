@@ -0,0 +1,55 @@
+//! `ClientBuilder::record_har` writes the client's traffic as an HTTP Archive.
+#![cfg(feature = "har")]
+
+mod common;
+
+use azure_core::auth::StaticTokenCredential;
+use azure_core::har::HarRecorder;
+use azure_mgmt_automation::package_2022_08_08::Client;
+use common::MockTransport;
+use serde_json::json;
+use std::io::Write;
+use std::sync::{Arc, Mutex};
+use time::OffsetDateTime;
+
+#[derive(Clone, Default)]
+struct SharedSink(Arc<Mutex<Vec<u8>>>);
+
+impl Write for SharedSink {
+    fn write(&mut self, buf: &[u8]) -> std::io::Result<usize> {
+        self.0.lock().unwrap().write(buf)
+    }
+
+    fn flush(&mut self) -> std::io::Result<()> {
+        Ok(())
+    }
+}
+
+#[tokio::test]
+async fn traffic_is_archived_without_the_token() {
+    let sink = SharedSink::default();
+    let recorder = HarRecorder::new(sink.clone());
+    let credential = StaticTokenCredential::new("secret-token", OffsetDateTime::now_utc() + time::Duration::days(1));
+    let client = Client::builder(Arc::new(credential))
+        .http_client(Arc::new(MockTransport::new(|_| common::json(json!({})))))
+        .record_har(recorder.clone())
+        .build();
+    client
+        .dsc_node_client()
+        .get("resource_group", "automation_account", "node_id", "subscription_id")
+        .send()
+        .await
+        .unwrap();
+    recorder.finish().unwrap();
+
+    let archive = sink.0.lock().unwrap().clone();
+    assert!(!String::from_utf8_lossy(&archive).contains("secret-token"));
+    let archive: serde_json::Value = serde_json::from_slice(&archive).unwrap();
+    let entries = archive["log"]["entries"].as_array().unwrap();
+    assert_eq!(entries.len(), 1);
+    assert_eq!(entries[0]["request"]["method"], "GET");
+    assert!(entries[0]["request"]["url"]
+        .as_str()
+        .unwrap()
+        .ends_with("/nodes/node_id?api-version=2020-01-13-preview"));
+}
@@ -0,0 +1,53 @@
+//! Paging follows a continuation in the `Link` or `x-ms-continuation` response header when the body has no next link. A
+//! token from `x-ms-continuation` is sent back in the same header, with the query of the first request.
+
+mod common;
+
+use azure_core::headers::{Headers, CONTINUATION, LINK};
+use azure_core::StatusCode;
+use common::MockTransport;
+use futures::TryStreamExt;
+use serde_json::json;
+use std::sync::Arc;
+
+/// Serves three pages of nodes: the first links to the second in a `Link` header, the second to
+/// the third with an `x-ms-continuation` token, and the third ends the list.
+fn header_paging_transport() -> MockTransport {
+    MockTransport::new(|request| {
+        let mut headers = Headers::new();
+        let node = if request.headers().get_optional_str(&CONTINUATION) == Some("page-3") {
+            "c"
+        } else if common::query(request, "page").as_deref() == Some("2") {
+            headers.insert(CONTINUATION, "page-3");
+            "b"
+        } else {
+            headers.insert(
+                LINK,
+                r#"<https://management.azure.com/subscriptions/subscription_id/resourceGroups/resource_group/providers/Microsoft.Automation/automationAccounts/automation_account/nodes?api-version=2020-01-13-preview&page=2>; rel="next""#,
+            );
+            "a"
+        };
+        common::response(StatusCode::Ok, headers, json!({ "value": [{ "name": node }] }).to_string())
+    })
+}
+
+#[tokio::test]
+async fn continuation_headers_are_followed() {
+    let transport = Arc::new(header_paging_transport());
+    let nodes: Vec<_> = common::client(transport.clone())
+        .dsc_node_client()
+        .list_by_automation_account("resource_group", "automation_account", "subscription_id")
+        .into_item_stream()
+        .map_ok(|node| node.proxy_resource.resource.name.unwrap())
+        .try_collect()
+        .await
+        .unwrap();
+    assert_eq!(nodes, ["a", "b", "c"]);
+
+    let requests = transport.requests();
+    let third = &requests[2];
+    assert_eq!(third.url().query(), requests[0].url().query());
+    assert_eq!(common::query(third, "$skiptoken"), None);
+    assert_eq!(third.headers().get_optional_str(&CONTINUATION), Some("page-3"));
+    assert_eq!(requests[1].headers().get_optional_str(&CONTINUATION), None);
+}
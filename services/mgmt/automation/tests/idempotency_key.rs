@@ -0,0 +1,46 @@
+//! `idempotency_key` sends the same repeatability headers with every attempt of a create.
+
+mod common;
+
+use azure_core::headers::{REPEATABILITY_FIRST_SENT, REPEATABILITY_REQUEST_ID};
+use azure_mgmt_automation::package_2022_08_08::{models, Client};
+use common::MockTransport;
+use std::sync::Arc;
+
+fn create_job(client: &Client) -> azure_mgmt_automation::package_2022_08_08::job::create::RequestBuilder {
+    client.job_client().create(
+        "subscription_id",
+        "resource_group",
+        "automation_account",
+        "job_name",
+        models::JobCreateParameters::new(models::JobCreateProperties::default()),
+    )
+}
+
+#[tokio::test]
+async fn key_is_repeated_on_every_send() {
+    let transport = Arc::new(MockTransport::ok());
+    let client = common::client(transport.clone());
+    let builder = create_job(&client).idempotency_key("3f2c8a1e");
+    builder.send().await.unwrap();
+    builder.send().await.unwrap();
+
+    let headers = transport.headers();
+    assert_eq!(headers.len(), 2);
+    for headers in headers.iter() {
+        assert_eq!(headers.get_optional_str(&REPEATABILITY_REQUEST_ID), Some("3f2c8a1e"));
+    }
+    let first_sent = headers[0].get_optional_str(&REPEATABILITY_FIRST_SENT).unwrap();
+    assert!(first_sent.ends_with(" GMT"));
+    assert_eq!(headers[1].get_optional_str(&REPEATABILITY_FIRST_SENT), Some(first_sent));
+}
+
+#[tokio::test]
+async fn no_headers_without_a_key() {
+    let transport = Arc::new(MockTransport::ok());
+    create_job(&common::client(transport.clone())).send().await.unwrap();
+
+    let headers = transport.headers();
+    assert!(headers[0].get_optional_str(&REPEATABILITY_REQUEST_ID).is_none());
+    assert!(headers[0].get_optional_str(&REPEATABILITY_FIRST_SENT).is_none());
+}
@@ -0,0 +1,86 @@
+//! `automation_account::delete` followed by `send_and_wait` polls `Location` until the account is gone, even when a
+//! `202 Accepted` poll does not repeat the header.
+
+mod common;
+
+use azure_core::error::ErrorKind;
+use azure_core::headers::{Headers, LOCATION, RETRY_AFTER};
+use azure_core::{Method, StatusCode};
+use common::MockTransport;
+use std::sync::atomic::{AtomicUsize, Ordering};
+use std::sync::Arc;
+
+const RESULT: &str = "https://management.azure.com/operationResults/1";
+
+/// Accepts the deletion with a `Location`, then answers the polls with the statuses in `polls`, in turn. A poll
+/// answered `202 Accepted` repeats the `Location` only if its flag is set, and asks for no wait.
+fn location_transport(polls: &'static [(StatusCode, bool)]) -> Arc<MockTransport> {
+    let count = AtomicUsize::new(0);
+    Arc::new(MockTransport::new(move |request| {
+        let (status, repeat_location) = if *request.method() == Method::Delete {
+            (StatusCode::Accepted, true)
+        } else {
+            assert_eq!(request.url().as_str(), RESULT);
+            polls[count.fetch_add(1, Ordering::SeqCst)]
+        };
+        let mut headers = Headers::new();
+        if status == StatusCode::Accepted {
+            headers.insert(RETRY_AFTER, "0");
+            if repeat_location {
+                headers.insert(LOCATION, RESULT);
+            }
+        }
+        common::response(status, headers, "")
+    }))
+}
+
+async fn send_and_wait(transport: Arc<MockTransport>) -> azure_core::Result<()> {
+    common::client(transport)
+        .automation_account_client()
+        .delete("resource_group", "automation_account", "subscription_id")
+        .send_and_wait()
+        .await
+}
+
+#[tokio::test]
+async fn location_is_polled_until_ok() {
+    let transport = location_transport(&[(StatusCode::Accepted, true), (StatusCode::Ok, false)]);
+    send_and_wait(transport.clone()).await.unwrap();
+    assert_eq!(transport.request_count(), 3);
+}
+
+#[tokio::test]
+async fn location_is_polled_until_no_content() {
+    let transport = location_transport(&[(StatusCode::Accepted, true), (StatusCode::NoContent, false)]);
+    send_and_wait(transport.clone()).await.unwrap();
+    assert_eq!(transport.request_count(), 3);
+}
+
+#[tokio::test]
+async fn location_need_not_be_repeated() {
+    let transport = location_transport(&[
+        (StatusCode::Accepted, false),
+        (StatusCode::Accepted, false),
+        (StatusCode::NoContent, false),
+    ]);
+    send_and_wait(transport.clone()).await.unwrap();
+    assert_eq!(transport.request_count(), 4);
+}
+
+#[tokio::test]
+async fn failed_poll_ends_the_wait() {
+    let transport = location_transport(&[(StatusCode::Accepted, false), (StatusCode::Conflict, false)]);
+    let err = send_and_wait(transport.clone()).await.unwrap_err();
+    assert!(
+        matches!(
+            err.kind(),
+            ErrorKind::HttpResponse {
+                status: StatusCode::Conflict,
+                ..
+            }
+        ),
+        "{:?}",
+        err.kind()
+    );
+    assert_eq!(transport.request_count(), 3);
+}
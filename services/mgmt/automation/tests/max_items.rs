@@ -0,0 +1,48 @@
+//! `max_items` caps the items of `into_item_stream` and stops requesting pages once the cap is reached.
+
+mod common;
+
+use common::MockTransport;
+use futures::TryStreamExt;
+use serde_json::json;
+use std::sync::Arc;
+
+const NODES: &str = "https://management.azure.com/subscriptions/subscription_id/resourceGroups/resource_group/providers/Microsoft.Automation/automationAccounts/automation_account/nodes";
+
+/// Serves an endless list of nodes, three per page.
+fn endless_transport() -> Arc<MockTransport> {
+    Arc::new(MockTransport::new(|request| {
+        let page: usize = common::query(request, "$skiptoken").map_or(0, |page| page.parse().unwrap());
+        let value: Vec<_> = (0..3).map(|n| json!({ "name": format!("node-{}", page * 3 + n) })).collect();
+        let next_link = format!("{NODES}?api-version=2020-01-13-preview&$skiptoken={}", page + 1);
+        common::json(json!({ "value": value, "nextLink": next_link }))
+    }))
+}
+
+/// The names of the nodes listed with `max_items`, and how many requests that took.
+async fn list(max_items: usize) -> (Vec<String>, usize) {
+    let transport = endless_transport();
+    let names = common::client(transport.clone())
+        .dsc_node_client()
+        .list_by_automation_account("resource_group", "automation_account", "subscription_id")
+        .max_items(max_items)
+        .into_item_stream()
+        .map_ok(|node| node.proxy_resource.resource.name.unwrap())
+        .try_collect()
+        .await
+        .unwrap();
+    (names, transport.request_count())
+}
+
+#[tokio::test]
+async fn max_items_spans_pages() {
+    let (names, requests) = list(4).await;
+    assert_eq!(names, ["node-0", "node-1", "node-2", "node-3"]);
+    assert_eq!(requests, 2);
+}
+
+#[tokio::test]
+async fn no_page_is_requested_past_the_cap() {
+    assert_eq!(list(3).await.1, 1);
+    assert_eq!(list(0).await, (Vec::new(), 0));
+}
@@ -0,0 +1,72 @@
+//! The query parameters set on a list builder are sent with every page, even when the service's
+//! next link leaves them out.
+
+mod common;
+
+use azure_core::Request;
+use common::MockTransport;
+use futures::TryStreamExt;
+use serde_json::json;
+use std::sync::Arc;
+
+const NODES: &str = "https://management.azure.com/subscriptions/subscription_id/resourceGroups/resource_group/providers/Microsoft.Automation/automationAccounts/automation_account/nodes";
+
+/// Serves three pages of nodes. The next links repeat `$filter` but not `$select` or `mode`, and
+/// the second one sets its own `mode`.
+fn paging_transport() -> MockTransport {
+    MockTransport::new(|request| {
+        let (node, next_link) = match common::query(request, "$skiptoken").as_deref() {
+            None => (
+                "a",
+                Some(format!("{NODES}?api-version=2020-01-13-preview&$filter=status&$skiptoken=2")),
+            ),
+            Some("2") => (
+                "b",
+                Some(format!(
+                    "{NODES}?api-version=2020-01-13-preview&$filter=status&$skiptoken=3&mode=server"
+                )),
+            ),
+            Some(_) => ("c", None),
+        };
+        common::json(json!({ "value": [{ "name": node }], "nextLink": next_link }))
+    })
+}
+
+fn query_names(request: &Request) -> Vec<String> {
+    request.url().query_pairs().map(|(key, _)| key.into_owned()).collect()
+}
+
+#[tokio::test]
+async fn every_page_carries_the_builder_query() {
+    let transport = Arc::new(paging_transport());
+    let nodes: Vec<_> = common::client(transport.clone())
+        .dsc_node_client()
+        .list_by_automation_account("resource_group", "automation_account", "subscription_id")
+        .filter("status")
+        .select(&["name"])
+        .query_param("mode", "client")
+        .into_item_stream()
+        .map_ok(|node| node.proxy_resource.resource.name.unwrap())
+        .try_collect()
+        .await
+        .unwrap();
+    assert_eq!(nodes, ["a", "b", "c"]);
+
+    let requests = transport.requests();
+    assert_eq!(requests.len(), 3);
+    for request in &requests {
+        assert_eq!(common::query(request, "$select").as_deref(), Some("name"));
+        assert_eq!(common::query(request, "mode").as_deref(), Some("client"));
+        assert_eq!(common::query(request, "api-version").as_deref(), Some("2020-01-13-preview"));
+        for name in ["api-version", "$filter", "$select", "mode"] {
+            assert_eq!(
+                query_names(request).iter().filter(|key| *key == name).count(),
+                1,
+                "{name} in {}",
+                request.url()
+            );
+        }
+    }
+    assert_eq!(common::query(&requests[1], "$skiptoken").as_deref(), Some("2"));
+    assert_eq!(common::query(&requests[2], "$skiptoken").as_deref(), Some("3"));
+}
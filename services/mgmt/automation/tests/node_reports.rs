@@ -0,0 +1,50 @@
+//! `node_reports::Client::latest` orders reports across pages on the client.
+
+mod common;
+
+use common::MockTransport;
+use serde_json::json;
+use std::sync::Arc;
+
+fn report(id: &str, start_time: Option<&str>) -> serde_json::Value {
+    match start_time {
+        Some(start_time) => json!({ "reportId": id, "startTime": start_time }),
+        None => json!({ "reportId": id }),
+    }
+}
+
+/// Lists reports `a` to `e` out of order, over two pages.
+fn reports_transport() -> MockTransport {
+    MockTransport::new(|request| {
+        if common::query(request, "page").as_deref() == Some("2") {
+            common::json(json!({ "value": [report("d", None), report("e", Some("2023-01-05T00:00:00Z"))] }))
+        } else {
+            common::json(json!({
+                "value": [
+                    report("b", Some("2023-01-02T00:00:00Z")),
+                    report("c", Some("2023-01-03T00:00:00Z")),
+                    report("a", Some("2023-01-01T00:00:00Z")),
+                ],
+                "nextLink": "https://management.azure.com/reports?page=2",
+            }))
+        }
+    })
+}
+
+async fn latest(n: usize) -> Vec<String> {
+    common::client(Arc::new(reports_transport()))
+        .node_reports_client()
+        .latest("resource_group", "automation_account", "node_id", "subscription_id", n)
+        .await
+        .unwrap()
+        .into_iter()
+        .map(|report| report.report_id.unwrap())
+        .collect()
+}
+
+#[tokio::test]
+async fn latest_reports_come_newest_first() {
+    assert_eq!(latest(2).await, ["e", "c"]);
+    assert_eq!(latest(10).await, ["e", "c", "b", "a", "d"]);
+    assert!(latest(0).await.is_empty());
+}
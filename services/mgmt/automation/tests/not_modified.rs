@@ -0,0 +1,60 @@
+//! A `304 Not Modified` answer to a conditional `get` keeps the headers the service sent.
+
+mod common;
+
+use azure_core::headers::{Headers, ETAG, IF_NONE_MATCH, REQUEST_ID};
+use azure_core::StatusCode;
+use azure_mgmt_automation::package_2022_08_08::Client;
+use common::MockTransport;
+use std::sync::Arc;
+use std::time::Duration;
+
+const ETAG_VALUE: &str = "\"00000000-0000-0000-0000-000000000001\"";
+const DELAY: Duration = Duration::from_millis(50);
+
+/// A client for a service that answers a request whose `If-None-Match` matches `ETAG_VALUE` with
+/// `304 Not Modified` after `DELAY`, and any other with `404 Not Found`.
+fn client() -> Client {
+    let transport = MockTransport::new(|request| {
+        let status = match request.headers().get_optional_str(&IF_NONE_MATCH) {
+            Some(ETAG_VALUE) => StatusCode::NotModified,
+            _ => StatusCode::NotFound,
+        };
+        let mut headers = Headers::new();
+        headers.insert(ETAG, ETAG_VALUE);
+        headers.insert(REQUEST_ID, "request-id");
+        common::response(status, headers, "")
+    });
+    common::client_builder(Arc::new(transport.delay(DELAY)))
+        .client_request_id_generator(Arc::new(|| "client-request-id".to_owned()))
+        .build()
+}
+
+#[tokio::test]
+async fn not_modified_keeps_the_response_headers() {
+    let rsp = client()
+        .dsc_node_client()
+        .get("resource_group", "automation_account", "node_id", "subscription_id")
+        .if_none_match(ETAG_VALUE)
+        .send()
+        .await
+        .unwrap();
+    assert!(rsp.is_not_modified());
+    assert_eq!(rsp.concurrency_token().unwrap().etag(), ETAG_VALUE);
+    assert_eq!(rsp.client_request_id(), Some("client-request-id"));
+    assert_eq!(rsp.as_raw_response().headers().get_optional_str(&REQUEST_ID), Some("request-id"));
+    assert!(rsp.elapsed() >= DELAY);
+}
+
+#[tokio::test]
+async fn other_failures_of_a_conditional_get_are_errors() {
+    let err = client()
+        .dsc_node_client()
+        .get("resource_group", "automation_account", "node_id", "subscription_id")
+        .if_none_match("\"other\"")
+        .send()
+        .await
+        .map(drop)
+        .unwrap_err();
+    assert_eq!(err.as_http_error().unwrap().status(), StatusCode::NotFound);
+}
@@ -0,0 +1,59 @@
+//! `on_missing_empty` turns a `404 Not Found` on the first page into an empty list, and only there.
+
+mod common;
+
+use azure_core::StatusCode;
+use common::MockTransport;
+use futures::TryStreamExt;
+use serde_json::json;
+use std::sync::Arc;
+
+const NODES: &str = "https://management.azure.com/subscriptions/subscription_id/resourceGroups/resource_group/providers/Microsoft.Automation/automationAccounts/automation_account/nodes";
+
+fn not_found() -> MockTransport {
+    MockTransport::new(|_| common::response(StatusCode::NotFound, Default::default(), ""))
+}
+
+async fn list(transport: Arc<MockTransport>, on_missing_empty: bool) -> azure_core::Result<Vec<String>> {
+    common::client(transport)
+        .dsc_node_client()
+        .list_by_automation_account("resource_group", "automation_account", "subscription_id")
+        .on_missing_empty(on_missing_empty)
+        .into_item_stream()
+        .map_ok(|node| node.proxy_resource.resource.name.unwrap())
+        .try_collect()
+        .await
+}
+
+#[tokio::test]
+async fn a_missing_first_page_is_empty() {
+    let transport = Arc::new(not_found());
+    assert_eq!(list(transport.clone(), true).await.unwrap(), Vec::<String>::new());
+    assert_eq!(transport.request_count(), 1);
+}
+
+#[tokio::test]
+async fn a_missing_first_page_is_an_error_by_default() {
+    assert!(list(Arc::new(not_found()), false).await.is_err());
+}
+
+#[tokio::test]
+async fn a_missing_later_page_is_still_an_error() {
+    let transport = Arc::new(MockTransport::new(|request| match common::query(request, "$skiptoken") {
+        None => common::json(json!({
+            "value": [{ "name": "node-0" }],
+            "nextLink": format!("{NODES}?api-version=2020-01-13-preview&$skiptoken=1"),
+        })),
+        Some(_) => common::response(StatusCode::NotFound, Default::default(), ""),
+    }));
+    assert!(list(transport.clone(), true).await.is_err());
+    assert_eq!(transport.request_count(), 2);
+}
+
+#[tokio::test]
+async fn other_errors_are_not_empty() {
+    let transport = Arc::new(MockTransport::new(|_| {
+        common::response(StatusCode::Forbidden, Default::default(), "")
+    }));
+    assert!(list(transport, true).await.is_err());
+}
@@ -0,0 +1,37 @@
+//! Every request builder describes its operation through the `Operation` trait.
+
+use azure_core::Method;
+use azure_mgmt_automation::package_2022_08_08::{automation_account, dsc_node, keys, Operation};
+
+fn describe<O: Operation>() -> (Method, &'static str, bool) {
+    (O::method(), O::path_template(), O::is_mutation())
+}
+
+#[test]
+fn builders_describe_their_operation() {
+    assert_eq!(
+        describe::<dsc_node::get::RequestBuilder>(),
+        (
+            Method::Get,
+            "/subscriptions/{subscriptionId}/resourceGroups/{resourceGroupName}/providers/Microsoft.Automation/automationAccounts/{automationAccountName}/nodes/{nodeId}",
+            false
+        )
+    );
+    assert_eq!(describe::<dsc_node::update::RequestBuilder>().0, Method::Patch);
+    assert!(dsc_node::update::RequestBuilder::is_mutation());
+    assert!(dsc_node::delete::RequestBuilder::is_mutation());
+}
+
+#[test]
+fn read_only_posts_are_not_mutations() {
+    assert_eq!(keys::list_by_automation_account::RequestBuilder::method(), Method::Post);
+    assert!(!keys::list_by_automation_account::RequestBuilder::is_mutation());
+}
+
+#[test]
+fn list_all_describes_the_list_it_wraps() {
+    assert_eq!(
+        describe::<automation_account::list_all::RequestBuilder>(),
+        describe::<automation_account::list::RequestBuilder>()
+    );
+}
@@ -0,0 +1,55 @@
+//! `operations::Client::all` pages through every operation of the resource provider.
+
+mod common;
+
+use azure_mgmt_automation::package_2022_08_08::Client;
+use common::MockTransport;
+use futures::TryStreamExt;
+use serde_json::json;
+use std::sync::Arc;
+
+/// A client for a service that serves the operations in two pages, the first linking to the second
+/// with `nextLink`.
+fn client() -> Client {
+    common::client(Arc::new(MockTransport::new(|request| {
+        if common::query(request, "page").as_deref() == Some("2") {
+            common::json(json!({ "value": [{ "name": "Microsoft.Automation/automationAccounts/write" }] }))
+        } else {
+            common::json(json!({
+                "value": [{ "name": "Microsoft.Automation/automationAccounts/read" }],
+                "nextLink": "https://management.azure.com/providers/Microsoft.Automation/operations?api-version=2022-08-08&page=2",
+            }))
+        }
+    })))
+}
+
+#[tokio::test]
+async fn every_page_of_operations_is_listed() {
+    let names: Vec<_> = client()
+        .operations_client()
+        .all()
+        .map_ok(|operation| operation.name.unwrap())
+        .try_collect()
+        .await
+        .unwrap();
+    assert_eq!(
+        names,
+        [
+            "Microsoft.Automation/automationAccounts/read",
+            "Microsoft.Automation/automationAccounts/write"
+        ]
+    );
+}
+
+#[tokio::test]
+async fn max_items_stops_after_the_first_page() {
+    let operations: Vec<_> = client()
+        .operations_client()
+        .list()
+        .max_items(1)
+        .into_item_stream()
+        .try_collect()
+        .await
+        .unwrap();
+    assert_eq!(operations.len(), 1);
+}
@@ -0,0 +1,82 @@
+//! Polling helpers share `PollOptions`, and give up with a `PollTimeout` carrying the last state.
+
+mod common;
+
+use azure_core::headers::{Headers, LOCATION};
+use azure_core::{Method, StatusCode};
+use azure_mgmt_automation::package_2022_08_08::models::{
+    dsc_compilation_job_properties::Status, DscCompilationJob, DscCompilationJobCreateParameters, DscCompilationJobCreateProperties,
+    DscConfigurationAssociationProperty,
+};
+use azure_mgmt_automation::package_2022_08_08::{PollOptions, PollTimeout};
+use common::MockTransport;
+use std::sync::atomic::{AtomicUsize, Ordering};
+use std::sync::Arc;
+use std::time::Duration;
+
+/// Answers `GET` with a compilation job that is `Running` for the first `running_polls` polls and
+/// `Completed` after, and every other request with the job as created.
+fn compilation_transport(running_polls: usize) -> MockTransport {
+    let polls = AtomicUsize::new(0);
+    MockTransport::new(move |request| {
+        let status = match request.method() {
+            Method::Get if polls.fetch_add(1, Ordering::SeqCst) >= running_polls => "Completed",
+            Method::Get => "Running",
+            _ => "New",
+        };
+        let body = format!(r#"{{"properties":{{"status":"{}"}}}}"#, status);
+        common::response(StatusCode::Ok, Headers::new(), body)
+    })
+}
+
+/// Accepts a deletion and keeps answering polls with `202 Accepted`.
+fn pending_deletion_transport() -> MockTransport {
+    MockTransport::new(|_| {
+        let mut headers = Headers::new();
+        headers.insert(LOCATION, "https://management.azure.com/operationResults/1");
+        common::response(StatusCode::Accepted, headers, "")
+    })
+}
+
+async fn compile(running_polls: usize, options: PollOptions) -> azure_core::Result<DscCompilationJob> {
+    let parameters =
+        DscCompilationJobCreateParameters::new(DscCompilationJobCreateProperties::new(DscConfigurationAssociationProperty::new()));
+    common::client(Arc::new(compilation_transport(running_polls)))
+        .dsc_compilation_job_client()
+        .create("resource_group", "automation_account", "job", parameters, "subscription_id")
+        .poll_until_done(options)
+        .await
+}
+
+#[tokio::test]
+async fn compilation_job_is_polled_until_completed() {
+    let job = compile(3, PollOptions::new().interval(Duration::ZERO)).await.unwrap();
+    assert_eq!(job.properties.unwrap().status, Some(Status::Completed));
+}
+
+#[tokio::test]
+async fn compilation_job_times_out_with_the_last_job() {
+    let options = PollOptions::new()
+        .interval(Duration::from_millis(5))
+        .timeout(Duration::from_millis(50));
+    let err = compile(usize::MAX, options).await.unwrap_err();
+    let timeout = err.downcast_ref::<PollTimeout<DscCompilationJob>>().unwrap();
+    assert_eq!(timeout.timeout(), Duration::from_millis(50));
+    assert_eq!(timeout.last().properties.as_ref().unwrap().status, Some(Status::Running));
+}
+
+#[tokio::test]
+async fn poller_times_out_with_the_last_status() {
+    let poller = common::client(Arc::new(pending_deletion_transport()))
+        .automation_account_client()
+        .delete("resource_group", "automation_account", "subscription_id")
+        .begin()
+        .await
+        .unwrap();
+    let err = poller
+        .wait_with(PollOptions::new().timeout(Duration::from_millis(20)).backoff(2.0))
+        .await
+        .unwrap_err();
+    let timeout = err.downcast_ref::<PollTimeout<StatusCode>>().unwrap();
+    assert_eq!(*timeout.last(), StatusCode::Accepted);
+}
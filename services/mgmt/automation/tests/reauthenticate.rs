@@ -0,0 +1,242 @@
+//! Claims reach the credential, whether preset on a request builder or asked for by a `Bearer`
+//! challenge, and a challenged request is resent once with a new token.
+
+mod common;
+
+use azure_core::auth::{TokenCredential, TokenResponse};
+use azure_core::headers::{Headers, AUTHORIZATION, CONTENT_TYPE, WWW_AUTHENTICATE};
+use azure_core::{Request, Response, StatusCode};
+use azure_identity::{AutoRefreshingTokenCredential, ClientSecretCredential, TokenCredentialOptions};
+use azure_mgmt_automation::package_2022_08_08::Client;
+use common::MockTransport;
+use std::sync::{Arc, Mutex};
+use time::OffsetDateTime;
+
+const LOGIN_HOST: &str = "login.microsoftonline.com";
+
+/// `{"access_token":{"nbf":{"essential":true}}}`, base64 encoded.
+const CLAIMS: &str = "eyJhY2Nlc3NfdG9rZW4iOnsibmJmIjp7ImVzc2VudGlhbCI6dHJ1ZX19fQ==";
+
+/// Hands out `token-1`, `token-2`, ... and keeps the claims it was asked for.
+#[derive(Debug, Default)]
+struct CountingCredential {
+    issued: Mutex<u32>,
+    cleared: Mutex<u32>,
+    claims: Mutex<Vec<String>>,
+}
+
+#[async_trait::async_trait]
+impl TokenCredential for CountingCredential {
+    async fn get_token(&self, _resource: &str) -> azure_core::Result<TokenResponse> {
+        let mut issued = self.issued.lock().unwrap();
+        *issued += 1;
+        Ok(TokenResponse::new(
+            format!("token-{}", issued).into(),
+            OffsetDateTime::now_utc() + time::Duration::hours(1),
+        ))
+    }
+
+    async fn get_token_with_claims(&self, resource: &str, claims: &str) -> azure_core::Result<TokenResponse> {
+        self.claims.lock().unwrap().push(claims.to_owned());
+        self.get_token(resource).await
+    }
+
+    async fn clear_cache(&self) -> azure_core::Result<()> {
+        *self.cleared.lock().unwrap() += 1;
+        Ok(())
+    }
+}
+
+/// A `401 Unauthorized` with a claims challenge.
+fn challenge() -> Response {
+    let mut headers = Headers::new();
+    headers.insert(
+        WWW_AUTHENTICATE,
+        format!(r#"Bearer realm="", error="insufficient_claims", claims="{}""#, CLAIMS),
+    );
+    common::response(StatusCode::Unauthorized, headers, "")
+}
+
+fn authorization(request: &Request) -> String {
+    request.headers().get_optional_string(&AUTHORIZATION).unwrap_or_default()
+}
+
+/// Answers every request with a claims challenge, except for requests with the token `accepted`.
+fn challenging_transport(accepted: &'static str) -> Arc<MockTransport> {
+    Arc::new(MockTransport::new(move |request| {
+        if authorization(request) == format!("Bearer {}", accepted) {
+            common::response(StatusCode::Ok, Headers::new(), "")
+        } else {
+            challenge()
+        }
+    }))
+}
+
+/// The `Authorization` header of every request `transport` was sent, other than token requests.
+fn authorizations(transport: &MockTransport) -> Vec<String> {
+    transport
+        .requests()
+        .iter()
+        .filter(|request| request.url().host_str() != Some(LOGIN_HOST))
+        .map(authorization)
+        .collect()
+}
+
+async fn get_dsc_node(
+    accepted: &'static str,
+    reauthenticate_on_challenge: bool,
+) -> (Arc<CountingCredential>, Arc<MockTransport>, azure_core::Result<()>) {
+    let credential = Arc::new(CountingCredential::default());
+    let transport = challenging_transport(accepted);
+    let result = Client::builder(credential.clone())
+        .http_client(transport.clone())
+        .reauthenticate_on_challenge(reauthenticate_on_challenge)
+        .build()
+        .dsc_node_client()
+        .get("resource_group", "automation_account", "node_id", "subscription_id")
+        .send()
+        .await
+        .map(|_| ());
+    (credential, transport, result)
+}
+
+#[tokio::test]
+async fn challenged_request_is_resent_with_a_token_for_the_claims() {
+    let (credential, transport, result) = get_dsc_node("token-2", true).await;
+    result.unwrap();
+    assert_eq!(authorizations(&transport), ["Bearer token-1", "Bearer token-2"]);
+    assert_eq!(*credential.cleared.lock().unwrap(), 1);
+    assert_eq!(
+        *credential.claims.lock().unwrap(),
+        [r#"{"access_token":{"nbf":{"essential":true}}}"#]
+    );
+}
+
+#[tokio::test]
+async fn challenged_request_is_resent_only_once() {
+    let (_, transport, result) = get_dsc_node("never", true).await;
+    let err = result.unwrap_err();
+    assert_eq!(err.as_http_error().unwrap().status(), StatusCode::Unauthorized);
+    assert_eq!(authorizations(&transport).len(), 2);
+}
+
+#[tokio::test]
+async fn challenge_is_returned_when_disabled() {
+    let (credential, transport, result) = get_dsc_node("token-2", false).await;
+    assert!(result.is_err());
+    assert_eq!(authorizations(&transport).len(), 1);
+    assert_eq!(*credential.cleared.lock().unwrap(), 0);
+}
+
+#[tokio::test]
+async fn preset_claims_are_passed_to_the_credential() {
+    let credential = Arc::new(CountingCredential::default());
+    let transport = challenging_transport("token-1");
+    Client::builder(credential.clone())
+        .http_client(transport.clone())
+        .build()
+        .dsc_node_client()
+        .get("resource_group", "automation_account", "node_id", "subscription_id")
+        .claims(r#"{"access_token":{"acrs":{"essential":true,"value":"c1"}}}"#)
+        .send()
+        .await
+        .unwrap();
+    assert_eq!(
+        *credential.claims.lock().unwrap(),
+        [r#"{"access_token":{"acrs":{"essential":true,"value":"c1"}}}"#]
+    );
+    assert_eq!(authorizations(&transport), ["Bearer token-1"]);
+}
+
+/// Plays both Azure Active Directory, issuing `claims-token` for a token request with claims and
+/// `plain-token` otherwise, and Azure Resource Manager, challenging every request without
+/// `claims-token`.
+fn directory_transport() -> Arc<MockTransport> {
+    Arc::new(MockTransport::new(|request| {
+        if request.url().host_str() == Some(LOGIN_HOST) {
+            let token = if parse_form(common::body(request)).iter().any(|(key, _)| key == "claims") {
+                "claims-token"
+            } else {
+                "plain-token"
+            };
+            let mut headers = Headers::new();
+            headers.insert(CONTENT_TYPE, "application/json");
+            let body = format!(r#"{{"token_type":"Bearer","expires_in":3600,"access_token":"{token}"}}"#);
+            common::response(StatusCode::Ok, headers, body)
+        } else if authorization(request) == "Bearer claims-token" {
+            common::response(StatusCode::Ok, Headers::new(), "")
+        } else {
+            challenge()
+        }
+    }))
+}
+
+/// The form of every token request `transport` was sent.
+fn token_requests(transport: &MockTransport) -> Vec<Vec<(String, String)>> {
+    transport
+        .requests()
+        .iter()
+        .filter(|request| request.url().host_str() == Some(LOGIN_HOST))
+        .map(|request| parse_form(common::body(request)))
+        .collect()
+}
+
+/// Decode a `application/x-www-form-urlencoded` body.
+fn parse_form(body: &[u8]) -> Vec<(String, String)> {
+    let mut url = azure_core::Url::parse("https://form.invalid/").unwrap();
+    url.set_query(Some(std::str::from_utf8(body).unwrap()));
+    url.query_pairs().into_owned().collect()
+}
+
+#[tokio::test]
+async fn identity_credential_requests_the_challenged_claims() {
+    let transport = directory_transport();
+    let credential = ClientSecretCredential::new(
+        transport.clone(),
+        "tenant_id".to_owned(),
+        "client_id".to_owned(),
+        "client_secret".to_owned(),
+        TokenCredentialOptions::default(),
+    );
+    let credential = Arc::new(AutoRefreshingTokenCredential::new(Arc::new(credential)));
+    Client::builder(credential)
+        .http_client(transport.clone())
+        .reauthenticate_on_challenge(true)
+        .build()
+        .dsc_node_client()
+        .get("resource_group", "automation_account", "node_id", "subscription_id")
+        .send()
+        .await
+        .unwrap();
+    assert_eq!(authorizations(&transport), ["Bearer plain-token", "Bearer claims-token"]);
+    let token_requests = token_requests(&transport);
+    assert_eq!(token_requests.len(), 2);
+    let claims: Vec<_> = token_requests[1].iter().filter(|(key, _)| key == "claims").collect();
+    assert_eq!(
+        claims,
+        [&("claims".to_owned(), r#"{"access_token":{"nbf":{"essential":true}}}"#.to_owned())]
+    );
+}
+
+#[tokio::test]
+async fn challenge_is_returned_by_default() {
+    let transport = directory_transport();
+    let credential = ClientSecretCredential::new(
+        transport.clone(),
+        "tenant_id".to_owned(),
+        "client_id".to_owned(),
+        "client_secret".to_owned(),
+        TokenCredentialOptions::default(),
+    );
+    let err = Client::builder(Arc::new(credential))
+        .http_client(transport.clone())
+        .build()
+        .dsc_node_client()
+        .get("resource_group", "automation_account", "node_id", "subscription_id")
+        .send()
+        .await
+        .map(drop)
+        .unwrap_err();
+    assert_eq!(err.as_http_error().unwrap().status(), StatusCode::Unauthorized);
+    assert_eq!(token_requests(&transport).len(), 1);
+}
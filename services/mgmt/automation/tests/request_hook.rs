@@ -0,0 +1,29 @@
+//! The `on_request` hook sees every request after authentication and before it is sent.
+
+mod common;
+
+use azure_core::headers::{HeaderName, AUTHORIZATION};
+use azure_core::Request;
+use common::MockTransport;
+use std::sync::Arc;
+
+const SIGNATURE: HeaderName = HeaderName::from_static("x-signature");
+
+#[tokio::test]
+async fn hook_mutates_headers_after_authentication() {
+    let transport = Arc::new(MockTransport::ok());
+    let client = common::client_builder(transport.clone())
+        .on_request(Arc::new(|request: &mut Request| {
+            let authorization = request.headers().get_optional_string(&AUTHORIZATION).unwrap_or_default();
+            request.insert_header(SIGNATURE, format!("signed:{}", authorization));
+        }))
+        .build();
+    client
+        .dsc_node_client()
+        .get("resource_group", "automation_account", "node_id", "subscription_id")
+        .send()
+        .await
+        .unwrap();
+    let headers = transport.headers();
+    assert_eq!(headers[0].get_optional_str(&SIGNATURE), Some("signed:Bearer token"));
+}
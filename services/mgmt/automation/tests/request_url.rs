@@ -0,0 +1,47 @@
+//! `url` builds the URL a request builder sends to, query included, without sending anything.
+
+mod common;
+
+use common::MockTransport;
+use futures::StreamExt;
+use serde_json::json;
+use std::sync::Arc;
+
+#[tokio::test]
+async fn url_is_the_url_sent() {
+    let transport = Arc::new(MockTransport::new(|_| common::json(json!({ "value": [] }))));
+    let builder = common::client(transport.clone())
+        .dsc_node_client()
+        .list_by_automation_account("resource_group", "automation_account", "subscription_id")
+        .filter("name eq 'node'")
+        .top(5)
+        .select(&["name"]);
+    let url = builder.url().unwrap();
+    assert_eq!(transport.request_count(), 0);
+    assert_eq!(url.path(), "/subscriptions/subscription_id/resourceGroups/resource_group/providers/Microsoft.Automation/automationAccounts/automation_account/nodes");
+    assert_eq!(
+        url.query_pairs()
+            .map(|(key, value)| (key.into_owned(), value.into_owned()))
+            .collect::<Vec<_>>(),
+        [
+            ("api-version", "2020-01-13-preview"),
+            ("$filter", "name eq 'node'"),
+            ("$top", "5"),
+            ("$select", "name"),
+        ]
+        .map(|(key, value)| (key.to_owned(), value.to_owned()))
+    );
+
+    builder.into_stream().next().await.unwrap().unwrap();
+    assert_eq!(transport.requests()[0].url(), &url);
+}
+
+#[test]
+fn url_fails_on_an_invalid_argument() {
+    let err = common::client(Arc::new(MockTransport::unreachable()))
+        .dsc_node_client()
+        .get("resource_group", "automation_account", "", "subscription_id")
+        .url()
+        .unwrap_err();
+    assert!(err.to_string().contains("node_id"), "{}", err);
+}
@@ -0,0 +1,56 @@
+//! Requests slower than `slow_request_threshold` are logged as warnings.
+
+mod common;
+
+use common::MockTransport;
+use std::sync::{Arc, Mutex};
+use std::time::Duration;
+
+/// Keeps the messages of all warnings logged in this test binary.
+struct CapturingLogger {
+    warnings: Mutex<Vec<String>>,
+}
+
+impl log::Log for CapturingLogger {
+    fn enabled(&self, metadata: &log::Metadata) -> bool {
+        metadata.level() <= log::Level::Warn
+    }
+
+    fn log(&self, record: &log::Record) {
+        if self.enabled(record.metadata()) {
+            self.warnings.lock().unwrap().push(record.args().to_string());
+        }
+    }
+
+    fn flush(&self) {}
+}
+
+static LOGGER: CapturingLogger = CapturingLogger {
+    warnings: Mutex::new(Vec::new()),
+};
+
+async fn get_dsc_node(delay: Duration, threshold: Duration) {
+    common::client_builder(Arc::new(MockTransport::ok().delay(delay)))
+        .slow_request_threshold(threshold)
+        .build()
+        .dsc_node_client()
+        .get("resource_group", "automation_account", "node_id", "subscription_id")
+        .send()
+        .await
+        .unwrap();
+}
+
+#[tokio::test]
+async fn only_requests_above_the_threshold_are_logged() {
+    log::set_logger(&LOGGER).unwrap();
+    log::set_max_level(log::LevelFilter::Warn);
+
+    get_dsc_node(Duration::ZERO, Duration::from_secs(3600)).await;
+    assert!(LOGGER.warnings.lock().unwrap().is_empty());
+
+    get_dsc_node(Duration::from_millis(20), Duration::from_millis(1)).await;
+    let warnings = LOGGER.warnings.lock().unwrap();
+    assert_eq!(warnings.len(), 1);
+    assert!(warnings[0].starts_with("slow request: operation=dsc_node::get status=200 elapsed_ms="));
+    assert!(warnings[0].contains("threshold_ms=1 "));
+}
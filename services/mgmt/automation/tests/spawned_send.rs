@@ -0,0 +1,53 @@
+//! The future returned by `send` owns a clone of the builder, so it can be spawned or stored while
+//! the builder is kept for another send; `send_ref` borrows the builder instead of cloning it.
+
+mod common;
+
+use common::MockTransport;
+use std::sync::Arc;
+
+#[tokio::test]
+async fn send_can_be_spawned_and_the_builder_reused() {
+    let transport = Arc::new(MockTransport::ok());
+    let builder =
+        common::client(transport.clone())
+            .dsc_node_client()
+            .get("resource_group", "automation_account", "node_id", "subscription_id");
+    let pending: Vec<_> = (0..3).map(|_| tokio::spawn(builder.send())).collect();
+    drop(builder);
+    for task in pending {
+        task.await.unwrap().unwrap();
+    }
+    assert_eq!(transport.request_count(), 3);
+}
+
+#[tokio::test]
+async fn batch_send_can_be_spawned() {
+    let transport = Arc::new(MockTransport::new(|_| {
+        common::json(serde_json::json!({ "responses": [{ "name": "0", "httpStatusCode": 200, "content": {} }] }))
+    }));
+    let client = common::client(transport.clone());
+    let mut batch = client.batch();
+    batch
+        .push(
+            &client
+                .dsc_node_client()
+                .get("resource_group", "automation_account", "node_id", "subscription_id"),
+        )
+        .unwrap();
+    let rsp = tokio::spawn(batch.send()).await.unwrap().unwrap();
+    assert_eq!(rsp.len(), 1);
+    assert_eq!(transport.request_count(), 1);
+}
+
+#[tokio::test]
+async fn send_ref_sends_a_borrowed_builder() {
+    let transport = Arc::new(MockTransport::ok());
+    let builder =
+        common::client(transport.clone())
+            .dsc_node_client()
+            .get("resource_group", "automation_account", "node_id", "subscription_id");
+    builder.send_ref().await.unwrap();
+    builder.send_ref().await.unwrap();
+    assert_eq!(transport.request_count(), 2);
+}
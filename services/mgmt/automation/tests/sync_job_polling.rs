@@ -0,0 +1,80 @@
+//! `source_control_sync_job::create::RequestBuilder::poll_until_done` creates the job, then polls it
+//! until it has completed or failed, or `PollOptions` times out.
+
+mod common;
+
+use azure_core::error::ErrorKind;
+use azure_core::Method;
+use azure_mgmt_automation::package_2022_08_08::models::{
+    source_control_sync_job_by_id_properties::ProvisioningState, SourceControlSyncJobById, SourceControlSyncJobCreateParameters,
+    SourceControlSyncJobCreateProperties,
+};
+use azure_mgmt_automation::package_2022_08_08::{PollOptions, PollTimeout};
+use common::MockTransport;
+use serde_json::json;
+use std::sync::atomic::{AtomicUsize, Ordering};
+use std::sync::Arc;
+use std::time::Duration;
+
+/// Answers the creation with a `Running` job, then answers `GET` with a `Running` job for the
+/// first `running_polls` polls and with `finished` after.
+fn sync_job_transport(running_polls: usize, finished: serde_json::Value) -> Arc<MockTransport> {
+    let polls = AtomicUsize::new(0);
+    Arc::new(MockTransport::new(move |request| {
+        let properties = match request.method() {
+            Method::Get if polls.fetch_add(1, Ordering::SeqCst) >= running_polls => finished.clone(),
+            _ => json!({ "provisioningState": "Running" }),
+        };
+        common::json(json!({ "properties": properties }))
+    }))
+}
+
+async fn sync(transport: Arc<MockTransport>, options: PollOptions) -> azure_core::Result<SourceControlSyncJobById> {
+    let parameters = SourceControlSyncJobCreateParameters::new(SourceControlSyncJobCreateProperties::new("commit".to_owned()));
+    common::client(transport)
+        .source_control_sync_job_client()
+        .create(
+            "resource_group",
+            "automation_account",
+            "source_control",
+            "job",
+            parameters,
+            "subscription_id",
+        )
+        .poll_until_done(options)
+        .await
+}
+
+#[tokio::test]
+async fn sync_job_is_polled_until_completed() {
+    let transport = sync_job_transport(2, json!({ "provisioningState": "Completed" }));
+    let job = sync(transport.clone(), PollOptions::new().interval(Duration::ZERO)).await.unwrap();
+    assert_eq!(job.properties.unwrap().provisioning_state, Some(ProvisioningState::Completed));
+
+    let methods: Vec<_> = transport.requests().iter().map(|request| *request.method()).collect();
+    assert_eq!(methods, [Method::Put, Method::Get, Method::Get, Method::Get]);
+}
+
+#[tokio::test]
+async fn a_failed_sync_job_fails_with_its_exception() {
+    let transport = sync_job_transport(0, json!({ "provisioningState": "Failed", "exception": "bad commit" }));
+    let err = sync(transport, Duration::ZERO.into()).await.unwrap_err();
+    assert!(matches!(err.kind(), ErrorKind::Other), "{:?}", err.kind());
+    assert!(
+        err.to_string().contains("source control sync job job failed: bad commit"),
+        "{}",
+        err
+    );
+}
+
+#[tokio::test]
+async fn sync_job_times_out_with_the_last_job() {
+    let transport = sync_job_transport(usize::MAX, json!({}));
+    let options = PollOptions::new()
+        .interval(Duration::from_millis(5))
+        .timeout(Duration::from_millis(50));
+    let err = sync(transport, options).await.unwrap_err();
+    let timeout = err.downcast_ref::<PollTimeout<SourceControlSyncJobById>>().unwrap();
+    let properties = timeout.last().properties.as_ref().unwrap();
+    assert_eq!(properties.provisioning_state, Some(ProvisioningState::Running));
+}
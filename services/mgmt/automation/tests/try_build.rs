@@ -0,0 +1,53 @@
+//! `ClientBuilder::try_build` fails when a scope is for another host than the endpoint.
+
+mod common;
+
+use azure_core::error::ErrorKind;
+use azure_core::resource_manager_endpoint::AZURE_CHINA_CLOUD;
+use azure_mgmt_automation::package_2022_08_08::ClientBuilder;
+use common::MockTransport;
+use std::sync::Arc;
+
+fn builder() -> ClientBuilder {
+    common::client_builder(Arc::new(MockTransport::unreachable()))
+}
+
+#[test]
+fn matching_scopes_build() {
+    assert!(builder().try_build().is_ok());
+    assert!(builder().endpoint(AZURE_CHINA_CLOUD).try_build().is_ok());
+    let china = builder()
+        .endpoint(AZURE_CHINA_CLOUD)
+        .scopes(&["https://MANAGEMENT.chinacloudapi.cn/.default"]);
+    assert!(china.try_build().is_ok());
+}
+
+#[test]
+fn a_scope_for_another_cloud_fails() {
+    let err = builder()
+        .endpoint(AZURE_CHINA_CLOUD)
+        .scopes(&["https://management.azure.com/.default"])
+        .try_build()
+        .err()
+        .expect("a public cloud scope was accepted for the China cloud");
+    assert!(matches!(err.kind(), ErrorKind::Credential), "{:?}", err.kind());
+    let message = err.to_string();
+    assert!(message.contains("https://management.azure.com/"), "{}", message);
+    assert!(message.contains(AZURE_CHINA_CLOUD), "{}", message);
+}
+
+#[test]
+fn validation_can_be_turned_off() {
+    let builder = builder()
+        .endpoint(AZURE_CHINA_CLOUD)
+        .scopes(&["https://management.azure.com/.default"]);
+    assert!(builder.validate_scopes(false).try_build().is_ok());
+}
+
+#[test]
+fn scopes_that_are_not_urls_are_not_checked() {
+    let builder = builder()
+        .endpoint(AZURE_CHINA_CLOUD)
+        .scopes(&["00000000-0000-0000-0000-000000000000"]);
+    assert!(builder.try_build().is_ok());
+}
@@ -0,0 +1,27 @@
+//! Fields the service added after the models were generated are kept rather than dropped.
+
+use azure_mgmt_automation::package_2022_08_08::models::RunbookProperties;
+use serde_json::json;
+
+#[test]
+fn unknown_fields_survive_a_round_trip() {
+    let body = json!({
+        "logVerbose": true,
+        "jobCount": 3,
+        "runtimeEnvironment": "PowerShell-7.2",
+        "future": { "nested": [1, 2] }
+    });
+    let properties: RunbookProperties = serde_json::from_value(body.clone()).unwrap();
+    assert_eq!(properties.log_verbose, Some(true));
+    assert_eq!(properties.job_count, Some(3));
+    assert_eq!(properties.extra.len(), 2);
+    assert_eq!(properties.extra["runtimeEnvironment"], json!("PowerShell-7.2"));
+    assert_eq!(serde_json::to_value(&properties).unwrap(), body);
+}
+
+#[test]
+fn known_fields_are_not_duplicated_into_extra() {
+    let properties: RunbookProperties = serde_json::from_value(json!({ "logVerbose": false })).unwrap();
+    assert!(properties.extra.is_empty());
+    assert_eq!(serde_json::to_value(RunbookProperties::default()).unwrap(), json!({}));
+}
@@ -0,0 +1,32 @@
+//! Unset fields of request bodies must be omitted, since Azure Resource Manager treats an explicit
+//! `null` as a request to clear the field.
+
+use azure_mgmt_automation::package_2022_08_08::models::{
+    AutomationAccountUpdateParameters, AutomationAccountUpdateProperties, ScheduleCreateOrUpdateProperties, ScheduleFrequency,
+};
+use serde_json::json;
+
+#[test]
+fn sparse_automation_account_update_is_minimal() {
+    let parameters = AutomationAccountUpdateParameters {
+        properties: Some(AutomationAccountUpdateProperties {
+            public_network_access: Some(false),
+            ..Default::default()
+        }),
+        ..Default::default()
+    };
+    assert_eq!(
+        serde_json::to_value(&parameters).unwrap(),
+        json!({ "properties": { "publicNetworkAccess": false } })
+    );
+    assert_eq!(serde_json::to_value(AutomationAccountUpdateParameters::new()).unwrap(), json!({}));
+}
+
+#[test]
+fn unset_date_times_are_omitted() {
+    let properties = ScheduleCreateOrUpdateProperties::new(time::OffsetDateTime::UNIX_EPOCH, ScheduleFrequency::Day);
+    let value = serde_json::to_value(&properties).unwrap();
+    let mut fields: Vec<&str> = value.as_object().unwrap().keys().map(String::as_str).collect();
+    fields.sort_unstable();
+    assert_eq!(fields, ["frequency", "startTime"]);
+}
@@ -0,0 +1,72 @@
+//! `ClientBuilder::validate_ids` rejects malformed ids before a request is sent.
+
+mod common;
+
+use azure_core::error::ErrorKind;
+use azure_mgmt_automation::package_2022_08_08::Client;
+use common::MockTransport;
+use std::sync::Arc;
+
+const SUBSCRIPTION_ID: &str = "4c3f1f22-93a4-4b5e-9a4a-0f5c6a1d2e3b";
+
+fn client(transport: Arc<MockTransport>, validate_ids: bool) -> Client {
+    common::client_builder(transport).validate_ids(validate_ids).build()
+}
+
+async fn get_node(client: &Client, resource_group: &str, automation_account: &str, subscription_id: &str) -> azure_core::Result<()> {
+    client
+        .dsc_node_client()
+        .get(resource_group, automation_account, "node_id", subscription_id)
+        .send()
+        .await
+        .map(drop)
+}
+
+#[tokio::test]
+async fn valid_ids_are_sent() {
+    let transport = Arc::new(MockTransport::ok());
+    let client = client(transport.clone(), true);
+    get_node(&client, "rg_prod-1.(west)", "contoso-automation", SUBSCRIPTION_ID)
+        .await
+        .unwrap();
+    get_node(&client, "Ressourcengruppe-Zürich", "a12345", SUBSCRIPTION_ID)
+        .await
+        .unwrap();
+    assert_eq!(transport.request_count(), 2);
+}
+
+#[tokio::test]
+async fn invalid_ids_are_rejected_before_sending() {
+    let transport = Arc::new(MockTransport::ok());
+    let client = client(transport.clone(), true);
+    for (resource_group, automation_account, subscription_id, argument) in [
+        ("rg", "contoso-automation", "my-subscription", "subscription_id"),
+        (
+            "rg",
+            "contoso-automation",
+            "{4c3f1f22-93a4-4b5e-9a4a-0f5c6a1d2e3b}",
+            "subscription_id",
+        ),
+        ("rg.", "contoso-automation", SUBSCRIPTION_ID, "resource_group_name"),
+        ("rg/../other", "contoso-automation", SUBSCRIPTION_ID, "resource_group_name"),
+        ("rg", "auto", SUBSCRIPTION_ID, "automation_account_name"),
+        ("rg", "1contoso", SUBSCRIPTION_ID, "automation_account_name"),
+        ("rg", "contoso_automation", SUBSCRIPTION_ID, "automation_account_name"),
+    ] {
+        let err = get_node(&client, resource_group, automation_account, subscription_id)
+            .await
+            .unwrap_err();
+        assert!(matches!(err.kind(), ErrorKind::Other), "{}", err);
+        assert!(err.to_string().contains(&format!("`{}`", argument)), "{}", err);
+    }
+    assert_eq!(transport.request_count(), 0);
+}
+
+#[tokio::test]
+async fn ids_are_not_checked_by_default() {
+    let transport = Arc::new(MockTransport::ok());
+    get_node(&client(transport.clone(), false), "rg", "auto", "my-subscription")
+        .await
+        .unwrap();
+    assert_eq!(transport.request_count(), 1);
+}
@@ -0,0 +1,73 @@
+//! `automation_account::Client::wait_until_ready` polls an account until it has finished provisioning.
+
+mod common;
+
+use azure_core::error::ErrorKind;
+use azure_mgmt_automation::package_2022_08_08::models::{AutomationAccount, ProvisioningState};
+use azure_mgmt_automation::package_2022_08_08::{PollOptions, PollTimeout};
+use common::MockTransport;
+use serde_json::json;
+use std::sync::atomic::{AtomicUsize, Ordering};
+use std::sync::Arc;
+use std::time::Duration;
+
+/// Answers each poll with the next of `states`, repeating the last one; `None` leaves the
+/// provisioning state out of the account.
+fn provisioning_transport(states: &[Option<&'static str>]) -> MockTransport {
+    let states = states.to_vec();
+    let polls = AtomicUsize::new(0);
+    MockTransport::new(move |_| {
+        let poll = polls.fetch_add(1, Ordering::SeqCst).min(states.len() - 1);
+        match states[poll] {
+            Some(state) => common::json(json!({ "name": "account", "properties": { "provisioningState": state } })),
+            None => common::json(json!({ "name": "account", "properties": {} })),
+        }
+    })
+}
+
+async fn wait(states: &[Option<&'static str>], options: PollOptions) -> (azure_core::Result<AutomationAccount>, usize) {
+    let transport = Arc::new(provisioning_transport(states));
+    let result = common::client(transport.clone())
+        .automation_account_client()
+        .wait_until_ready("resource_group", "account", "subscription_id", options)
+        .await;
+    (result, transport.request_count())
+}
+
+fn provisioning_state(account: &AutomationAccount) -> Option<&ProvisioningState> {
+    account.properties.as_ref().unwrap().provisioning_state.as_ref()
+}
+
+#[tokio::test]
+async fn account_is_polled_until_succeeded() {
+    let (account, polls) = wait(&[Some("Creating"), Some("Provisioning"), Some("Succeeded")], Duration::ZERO.into()).await;
+    assert_eq!(provisioning_state(&account.unwrap()), Some(&ProvisioningState::Succeeded));
+    assert_eq!(polls, 3);
+}
+
+#[tokio::test]
+async fn account_without_a_provisioning_state_is_ready() {
+    let (account, polls) = wait(&[None], Duration::ZERO.into()).await;
+    assert_eq!(provisioning_state(&account.unwrap()), None);
+    assert_eq!(polls, 1);
+}
+
+#[tokio::test]
+async fn failed_provisioning_is_an_error() {
+    let (result, polls) = wait(&[Some("Creating"), Some("Failed")], Duration::ZERO.into()).await;
+    let err = result.unwrap_err();
+    assert!(matches!(err.kind(), ErrorKind::Other));
+    assert!(err.to_string().contains("Failed"), "{}", err);
+    assert_eq!(polls, 2);
+}
+
+#[tokio::test]
+async fn waiting_times_out_with_the_last_account() {
+    let options = PollOptions::new()
+        .interval(Duration::from_millis(5))
+        .timeout(Duration::from_millis(50));
+    let (result, _) = wait(&[Some("Creating")], options).await;
+    let err = result.unwrap_err();
+    let timeout = err.downcast_ref::<PollTimeout<AutomationAccount>>().unwrap();
+    assert_eq!(provisioning_state(timeout.last()), Some(&ProvisioningState::Creating));
+}
@@ -2,78 +2,117 @@
 #![allow(unused_variables)]
 #![allow(unused_imports)]
 #![allow(clippy::redundant_clone)]
+#![doc = "Client for the API at api-version 2021-04-01-preview, with a request builder for every operation."]
+#![doc = ""]
+#![doc = "# Claims"]
+#![doc = ""]
+#![doc = "`claims` on a request builder asks the credential for a token that satisfies a JSON claims request, not base64 encoded, such as the claims a conditional access policy requires for step-up authentication. Credentials that cannot request claims fail with an `ErrorKind::Credential` error; see `azure_core::auth::TokenCredential::get_token_with_claims`. A claims challenge from the service is answered without this; see `ClientBuilder::reauthenticate_on_challenge`."]
+#![doc = ""]
+#![doc = "# Query parameters"]
+#![doc = ""]
+#![doc = "`query_param` on a request builder appends `key=value` to the query string, for query parameters the service added after this crate was generated. A parameter the builder already sets, such as `$filter`, is replaced. `api-version` is fixed by the crate and cannot be overridden."]
+#![doc = ""]
+#![doc = "# Responses"]
+#![doc = ""]
+#![doc = "The `Response` of every operation has these helpers besides `into_body`:"]
+#![doc = ""]
+#![doc = "* `into_value` returns the body as untyped JSON, including any fields the `models` type does not have yet."]
+#![doc = "* `client_request_id` is the `x-ms-client-request-id` the request was sent with, for correlating client and service logs."]
+#![doc = "* `rate_limit_remaining` is the remaining request quota reported by the `x-ms-ratelimit-remaining-*` response headers, or `None` if the service sent none."]
+#![doc = "* `elapsed` is how long the request took, from handing it to the pipeline until the response headers arrived, including retries; reading the body is not counted. It is zero for a response that did not come from the service, such as one `ClientBuilder::dry_run` answered without sending."]
+#![doc = "* `concurrency_token`, on the responses of operations that read a resource, is its ETag, for passing to `if_match` of a later `update` or `create_or_update` so that it only applies if the resource has not changed since."]
 pub mod models;
+pub use azure_core::batch::{self, BatchRead};
+pub use azure_core::client::{map_concurrent, ClientRequestIdGenerator, RequestHook};
+pub use azure_core::concurrency::{ConcurrencyToken, PreconditionFailed};
+pub use azure_core::lro::{FinalGet, FinalStateVia, PollOptions, PollTimeout, Poller};
 #[derive(Clone)]
-pub struct Client {
-    endpoint: String,
-    credential: std::sync::Arc<dyn azure_core::auth::TokenCredential>,
-    scopes: Vec<String>,
-    pipeline: azure_core::Pipeline,
+pub struct Client(pub(crate) azure_core::client::ServiceClient);
+#[doc = "Builds a `Client`; see `azure_core::client::ClientBuilder` for the settings."]
+pub type ClientBuilder = azure_core::client::ClientBuilder<Client>;
+pub const DEFAULT_ENDPOINT: &str = azure_core::resource_manager_endpoint::AZURE_PUBLIC_CLOUD;
+impl azure_core::client::GeneratedClient for Client {
+    const DEFAULT_ENDPOINT: &'static str = DEFAULT_ENDPOINT;
+    const CRATE_NAME: Option<&'static str> = option_env!("CARGO_PKG_NAME");
+    const CRATE_VERSION: Option<&'static str> = option_env!("CARGO_PKG_VERSION");
 }
-#[derive(Clone)]
-pub struct ClientBuilder {
-    credential: std::sync::Arc<dyn azure_core::auth::TokenCredential>,
-    endpoint: Option<String>,
-    scopes: Option<Vec<String>>,
-    options: azure_core::ClientOptions,
+impl From<azure_core::client::ServiceClient> for Client {
+    fn from(client: azure_core::client::ServiceClient) -> Self {
+        Self(client)
+    }
 }
-pub const DEFAULT_ENDPOINT: &str = azure_core::resource_manager_endpoint::AZURE_PUBLIC_CLOUD;
-impl ClientBuilder {
-    #[doc = "Create a new instance of `ClientBuilder`."]
-    #[must_use]
-    pub fn new(credential: std::sync::Arc<dyn azure_core::auth::TokenCredential>) -> Self {
-        Self {
-            credential,
-            endpoint: None,
-            scopes: None,
-            options: azure_core::ClientOptions::default(),
-        }
+impl Client {
+    pub(crate) fn endpoint(&self) -> &str {
+        self.0.endpoint()
     }
-    #[doc = "Set the endpoint."]
-    #[must_use]
-    pub fn endpoint(mut self, endpoint: impl Into<String>) -> Self {
-        self.endpoint = Some(endpoint.into());
-        self
+    pub(crate) async fn token(&self, claims: Option<&str>) -> azure_core::Result<azure_core::auth::TokenResponse> {
+        self.0.token(claims).await
     }
-    #[doc = "Set the scopes."]
-    #[must_use]
-    pub fn scopes(mut self, scopes: &[&str]) -> Self {
-        self.scopes = Some(scopes.iter().map(|scope| (*scope).to_owned()).collect());
-        self
+    pub(crate) async fn send(
+        &self,
+        request: &mut azure_core::Request,
+        operation: &'static str,
+    ) -> azure_core::Result<azure_core::Response> {
+        self.0.send(request, operation).await
     }
-    #[doc = "Set the retry options."]
-    #[must_use]
-    pub fn retry(mut self, retry: impl Into<azure_core::RetryOptions>) -> Self {
-        self.options = self.options.retry(retry);
-        self
+    pub(crate) async fn send_with_context(
+        &self,
+        request: &mut azure_core::Request,
+        operation: &'static str,
+        context: azure_core::Context,
+    ) -> azure_core::Result<azure_core::Response> {
+        self.0.send_with_context(request, operation, context).await
     }
-    #[doc = "Set the transport options."]
-    #[must_use]
-    pub fn transport(mut self, transport: impl Into<azure_core::TransportOptions>) -> Self {
-        self.options = self.options.transport(transport);
-        self
+    #[doc = "Check that the credential can get a token for the configured scopes."]
+    #[doc = ""]
+    #[doc = "Requests a token once and discards it. Useful as a preflight before starting work, so an authentication problem is reported up front instead of from the first operation."]
+    pub async fn check_credential(&self) -> azure_core::Result<()> {
+        self.0.check_credential().await
     }
-    #[doc = "Convert the builder into a `Client` instance."]
+    #[doc = "Create a client that sends requests to `endpoint`, sharing the credential, pipeline and settings of this one."]
+    #[doc = ""]
+    #[doc = "For failing over to another region without rebuilding authentication state: the credential, and so its token cache, is shared rather than recreated. Scopes that start with the current endpoint, such as the default scope derived from it, are rewritten to start with `endpoint`; other scopes are kept as they are."]
     #[must_use]
-    pub fn build(self) -> Client {
-        let endpoint = self.endpoint.unwrap_or_else(|| DEFAULT_ENDPOINT.to_owned());
-        let scopes = self.scopes.unwrap_or_else(|| vec![format!("{}/", endpoint)]);
-        Client::new(endpoint, self.credential, scopes, self.options)
-    }
-}
-impl Client {
-    pub(crate) fn endpoint(&self) -> &str {
-        self.endpoint.as_str()
+    pub fn with_endpoint(&self, endpoint: impl Into<String>) -> Client {
+        Client(self.0.with_endpoint(endpoint))
     }
-    pub(crate) fn token_credential(&self) -> &dyn azure_core::auth::TokenCredential {
-        self.credential.as_ref()
+    #[doc = "Check that every scope is for the host of the endpoint."]
+    #[doc = ""]
+    #[doc = "Scopes that are not URLs, such as bare application ids, are not checked. Fails with an `ErrorKind::Credential` error naming the scope and the endpoint otherwise."]
+    pub fn validate_scopes(&self) -> azure_core::Result<()> {
+        self.0.validate_scopes()
     }
-    pub(crate) fn scopes(&self) -> Vec<&str> {
-        self.scopes.iter().map(String::as_str).collect()
+    #[doc = "Create a builder that sends many reads as Azure Resource Manager `$batch` requests, instead of one round trip each."]
+    #[doc = ""]
+    #[doc = "Add the `GET` request builders of other operations with `batch::RequestBuilder::push`, such as a `get` for each of many resources. The batch is sent with the client's token and settings; see `batch::RequestBuilder::send`."]
+    #[must_use]
+    pub fn batch(&self) -> batch::RequestBuilder {
+        batch::RequestBuilder::new(self.0.clone())
     }
-    pub(crate) async fn send(&self, request: &mut azure_core::Request) -> azure_core::Result<azure_core::Response> {
-        let mut context = azure_core::Context::default();
-        self.pipeline.send(&mut context, request).await
+    #[doc = "Create a `Client` from the environment, returning it together with the subscription id to pass to its operations."]
+    #[doc = ""]
+    #[doc = "For quick tools and CLIs. The environment variables read are:"]
+    #[doc = ""]
+    #[doc = "* `AZURE_SUBSCRIPTION_ID`: the subscription id. Required."]
+    #[doc = "* `AZURE_RESOURCE_MANAGER_ENDPOINT`: the endpoint, such as `https://management.chinacloudapi.cn`. Defaults to `DEFAULT_ENDPOINT`, the public cloud."]
+    #[doc = "* `AZURE_RESOURCE_MANAGER_AUDIENCE`: the audience to request tokens for, such as `https://management.core.windows.net/`, when it differs from the endpoint; see `ClientBuilder::audience`. Defaults to the endpoint."]
+    #[doc = ""]
+    #[doc = "Tokens come from an `azure_identity::DefaultAzureCredential`, cached until they are about to expire. It tries, in order: a service principal from `AZURE_TENANT_ID` and `AZURE_CLIENT_ID` together with `AZURE_CLIENT_SECRET`, `AZURE_CLIENT_CERTIFICATE_PATH` or `AZURE_USERNAME` and `AZURE_PASSWORD`; a managed identity; and the Azure CLI. The first that yields a token wins, so service principal variables left in the environment take precedence over a CLI login. Use `Client::builder` for anything more specific."]
+    #[cfg(feature = "identity")]
+    pub fn from_env() -> azure_core::Result<(Client, String)> {
+        use azure_core::error::ResultExt;
+        let subscription_id =
+            std::env::var("AZURE_SUBSCRIPTION_ID").context(azure_core::error::ErrorKind::Credential, "AZURE_SUBSCRIPTION_ID is not set")?;
+        let credential =
+            azure_identity::AutoRefreshingTokenCredential::new(std::sync::Arc::new(azure_identity::DefaultAzureCredential::default()));
+        let mut builder = ClientBuilder::new(std::sync::Arc::new(credential));
+        if let Ok(endpoint) = std::env::var("AZURE_RESOURCE_MANAGER_ENDPOINT") {
+            builder = builder.endpoint(endpoint);
+        }
+        if let Ok(audience) = std::env::var("AZURE_RESOURCE_MANAGER_AUDIENCE") {
+            builder = builder.audience(audience);
+        }
+        Ok((builder.build(), subscription_id))
     }
     #[doc = "Create a new `ClientBuilder`."]
     #[must_use]
@@ -88,7 +127,6 @@ impl Client {
         scopes: Vec<String>,
         options: azure_core::ClientOptions,
     ) -> Self {
-        let endpoint = endpoint.into();
         let pipeline = azure_core::Pipeline::new(
             option_env!("CARGO_PKG_NAME"),
             option_env!("CARGO_PKG_VERSION"),
@@ -96,12 +134,7 @@ impl Client {
             Vec::new(),
             Vec::new(),
         );
-        Self {
-            endpoint,
-            credential,
-            scopes,
-            pipeline,
-        }
+        Self(azure_core::client::ServiceClient::new(endpoint, credential, scopes, pipeline))
     }
     pub fn managed_hsms_client(&self) -> managed_hsms::Client {
         managed_hsms::Client(self.clone())
@@ -125,6 +158,22 @@ impl Client {
         vaults::Client(self.clone())
     }
 }
+mod private {
+    pub trait Sealed {}
+}
+#[doc = "Static facts about an operation, implemented by every `RequestBuilder` so that generic code, such as audit or retry wrappers, can tell operations apart without matching on URLs."]
+#[doc = ""]
+#[doc = "The trait is sealed and cannot be implemented outside this crate, so that methods can be added to it later. Pipeline policies, which see requests rather than builders, get the operation's name, such as `dsc_node::get`, from the `azure_core::OperationName` in their context."]
+pub trait Operation: private::Sealed {
+    #[doc = "The HTTP method the operation is sent with."]
+    fn method() -> azure_core::Method;
+    #[doc = "The path of the operation as declared by the specification, with a placeholder such as `{resourceGroupName}` for each path parameter."]
+    fn path_template() -> &'static str;
+    #[doc = "Whether the operation changes anything on the service. True for every method but `GET`, except for `POST` operations that only read, such as those that list keys."]
+    fn is_mutation() -> bool {
+        !matches!(Self::method(), azure_core::Method::Get | azure_core::Method::Head)
+    }
+}
 pub mod vaults {
     use super::models;
     pub struct Client(pub(crate) super::Client);
@@ -146,6 +195,10 @@ pub mod vaults {
                 resource_group_name: resource_group_name.into(),
                 vault_name: vault_name.into(),
                 subscription_id: subscription_id.into(),
+                claims: None,
+                accept_language: None,
+                if_none_match: None,
+                query_params: Vec::new(),
             }
         }
         #[doc = "Create or update a key vault in the specified subscription."]
@@ -168,6 +221,12 @@ pub mod vaults {
                 vault_name: vault_name.into(),
                 parameters: parameters.into(),
                 subscription_id: subscription_id.into(),
+                claims: None,
+                accept_language: None,
+                idempotency_key: None,
+                if_match: None,
+                query_params: Vec::new(),
+                raw_body: None,
             }
         }
         #[doc = "Update a key vault in the specified subscription."]
@@ -190,6 +249,13 @@ pub mod vaults {
                 vault_name: vault_name.into(),
                 parameters: parameters.into(),
                 subscription_id: subscription_id.into(),
+                claims: None,
+                accept_language: None,
+                if_match: None,
+                idempotent: false,
+                prefer_representation: false,
+                query_params: Vec::new(),
+                raw_body: None,
             }
         }
         #[doc = "Deletes the specified Azure key vault."]
@@ -209,6 +275,9 @@ pub mod vaults {
                 resource_group_name: resource_group_name.into(),
                 vault_name: vault_name.into(),
                 subscription_id: subscription_id.into(),
+                claims: None,
+                accept_language: None,
+                query_params: Vec::new(),
             }
         }
         #[doc = "Update access policies in a key vault in the specified subscription."]
@@ -234,6 +303,10 @@ pub mod vaults {
                 operation_kind: operation_kind.into(),
                 parameters: parameters.into(),
                 subscription_id: subscription_id.into(),
+                claims: None,
+                accept_language: None,
+                query_params: Vec::new(),
+                raw_body: None,
             }
         }
         #[doc = "The List operation gets information about the vaults associated with the subscription and within the specified resource group."]
@@ -251,6 +324,13 @@ pub mod vaults {
                 resource_group_name: resource_group_name.into(),
                 subscription_id: subscription_id.into(),
                 top: None,
+                claims: None,
+                accept_language: None,
+                query_params: Vec::new(),
+                select: None,
+                max_items: None,
+                on_missing_empty: false,
+                resume_from: None,
             }
         }
         #[doc = "The List operation gets information about the vaults associated with the subscription."]
@@ -262,6 +342,13 @@ pub mod vaults {
                 client: self.0.clone(),
                 subscription_id: subscription_id.into(),
                 top: None,
+                claims: None,
+                accept_language: None,
+                query_params: Vec::new(),
+                select: None,
+                max_items: None,
+                on_missing_empty: false,
+                resume_from: None,
             }
         }
         #[doc = "Gets information about the deleted vaults in a subscription."]
@@ -272,6 +359,13 @@ pub mod vaults {
             list_deleted::RequestBuilder {
                 client: self.0.clone(),
                 subscription_id: subscription_id.into(),
+                claims: None,
+                accept_language: None,
+                query_params: Vec::new(),
+                select: None,
+                max_items: None,
+                on_missing_empty: false,
+                resume_from: None,
             }
         }
         #[doc = "Gets the deleted Azure key vault."]
@@ -291,6 +385,9 @@ pub mod vaults {
                 vault_name: vault_name.into(),
                 location: location.into(),
                 subscription_id: subscription_id.into(),
+                claims: None,
+                accept_language: None,
+                query_params: Vec::new(),
             }
         }
         #[doc = "Permanently deletes the specified vault. aka Purges the deleted Azure key vault."]
@@ -310,6 +407,10 @@ pub mod vaults {
                 vault_name: vault_name.into(),
                 location: location.into(),
                 subscription_id: subscription_id.into(),
+                claims: None,
+                accept_language: None,
+                idempotent: false,
+                query_params: Vec::new(),
             }
         }
         #[doc = "The List operation gets information about the vaults associated with the subscription."]
@@ -323,6 +424,13 @@ pub mod vaults {
                 filter: filter.into(),
                 subscription_id: subscription_id.into(),
                 top: None,
+                claims: None,
+                accept_language: None,
+                query_params: Vec::new(),
+                select: None,
+                max_items: None,
+                on_missing_empty: false,
+                resume_from: None,
             }
         }
         #[doc = "Checks that the vault name is valid and is not already in use."]
@@ -339,6 +447,11 @@ pub mod vaults {
                 client: self.0.clone(),
                 vault_name: vault_name.into(),
                 subscription_id: subscription_id.into(),
+                claims: None,
+                accept_language: None,
+                idempotent: false,
+                query_params: Vec::new(),
+                raw_body: None,
             }
         }
     }
@@ -347,16 +460,72 @@ pub mod vaults {
         pub struct Response(azure_core::Response);
         impl Response {
             pub async fn into_body(self) -> azure_core::Result<models::Vault> {
-                let bytes = self.0.into_body().collect().await?;
-                let body: models::Vault = serde_json::from_slice(&bytes)?;
+                if self.is_not_modified() {
+                    return Err(azure_core::error::ErrorKind::HttpResponse {
+                        status: azure_core::StatusCode::NotModified,
+                        error_code: None,
+                    }
+                    .into_error());
+                }
+                let status = self.0.status();
+                let bytes = self.into_byte_stream().collect().await?;
+                let body: models::Vault = azure_core::client::from_json_body("vaults::get", status, &bytes)?;
+                Ok(body)
+            }
+            #[doc = "Return the response body as untyped JSON; see [responses](crate::package_preview_2021_04#responses)."]
+            pub async fn into_value(self) -> azure_core::Result<serde_json::Value> {
+                if self.is_not_modified() {
+                    return Err(azure_core::error::ErrorKind::HttpResponse {
+                        status: azure_core::StatusCode::NotModified,
+                        error_code: None,
+                    }
+                    .into_error());
+                }
+                let status = self.0.status();
+                let bytes = self.into_byte_stream().collect().await?;
+                let body: serde_json::Value = azure_core::client::from_json_body("vaults::get", status, &bytes)?;
                 Ok(body)
             }
+            #[doc = "Whether the service answered a request sent with `if_none_match` with `304 Not Modified`. Such a response has no body, but carries the headers the service sent, so `concurrency_token`, `client_request_id` and `elapsed` still report them."]
+            #[doc = ""]
+            #[doc = "Derived from the status code alone, so polling loops can count unchanged responses against transferred bodies to track their cache hit rate."]
+            pub fn is_not_modified(&self) -> bool {
+                self.0.status() == azure_core::StatusCode::NotModified
+            }
+            #[doc = "Stream the response body in chunks as they are received, instead of buffering all of it like `into_body`."]
+            pub fn into_byte_stream(self) -> azure_core::ResponseBody {
+                self.0.into_body()
+            }
+            pub fn status(&self) -> azure_core::StatusCode {
+                self.0.status()
+            }
             pub fn into_raw_response(self) -> azure_core::Response {
                 self.0
             }
+            #[doc = "Read the whole response and return its status, headers and body as received, for forwarding it verbatim without going through `models`."]
+            pub async fn into_raw_parts(self) -> azure_core::Result<(azure_core::StatusCode, azure_core::headers::Headers, bytes::Bytes)> {
+                let (status, headers, body) = self.0.deconstruct();
+                Ok((status, headers, body.collect().await?))
+            }
             pub fn as_raw_response(&self) -> &azure_core::Response {
                 &self.0
             }
+            #[doc = "The remaining request quota; see [responses](crate::package_preview_2021_04#responses)."]
+            pub fn rate_limit_remaining(&self) -> Option<u32> {
+                azure_core::client::rate_limit_remaining(self.0.headers())
+            }
+            #[doc = "The `x-ms-client-request-id` the request was sent with; see [responses](crate::package_preview_2021_04#responses)."]
+            pub fn client_request_id(&self) -> Option<&str> {
+                self.0.headers().get_optional_str(&azure_core::headers::CLIENT_REQUEST_ID)
+            }
+            #[doc = "How long the request took; see [responses](crate::package_preview_2021_04#responses)."]
+            pub fn elapsed(&self) -> std::time::Duration {
+                self.0.elapsed().unwrap_or_default()
+            }
+            #[doc = "The resource's ETag; see [responses](crate::package_preview_2021_04#responses)."]
+            pub fn concurrency_token(&self) -> Option<super::super::ConcurrencyToken> {
+                super::super::ConcurrencyToken::from_headers(self.0.headers())
+            }
         }
         impl From<Response> for azure_core::Response {
             fn from(rsp: Response) -> Self {
@@ -374,40 +543,116 @@ pub mod vaults {
             pub(crate) resource_group_name: String,
             pub(crate) vault_name: String,
             pub(crate) subscription_id: String,
+            pub(crate) claims: Option<String>,
+            pub(crate) accept_language: Option<String>,
+            pub(crate) if_none_match: Option<String>,
+            pub(crate) query_params: Vec<(String, String)>,
+        }
+        impl super::super::private::Sealed for RequestBuilder {}
+        impl super::super::Operation for RequestBuilder {
+            fn method() -> azure_core::Method {
+                azure_core::Method::Get
+            }
+            fn path_template() -> &'static str {
+                "/subscriptions/{subscriptionId}/resourceGroups/{resourceGroupName}/providers/Microsoft.KeyVault/vaults/{vaultName}"
+            }
+        }
+        impl super::super::BatchRead for RequestBuilder {
+            fn batch_url(&self) -> azure_core::Result<azure_core::Url> {
+                self.url()
+            }
         }
         impl RequestBuilder {
+            #[doc = "Ask the credential for a token that satisfies the JSON `claims`; see [claims](crate::package_preview_2021_04#claims)."]
+            pub fn claims(mut self, claims: impl Into<String>) -> Self {
+                self.claims = Some(claims.into());
+                self
+            }
+            #[doc = "Send this request with `accept_language` as its `Accept-Language` header, such as `fr-FR`, overriding `ClientBuilder::accept_language`."]
+            pub fn accept_language(mut self, accept_language: impl Into<String>) -> Self {
+                self.accept_language = Some(accept_language.into());
+                self
+            }
+            #[doc = "Only return the resource if its ETag does not match `if_none_match`. Otherwise the response has status `304 Not Modified` and no body."]
+            pub fn if_none_match(mut self, if_none_match: impl Into<String>) -> Self {
+                self.if_none_match = Some(if_none_match.into());
+                self
+            }
+            #[doc = "Append `key=value` to the query string; see [query parameters](crate::package_preview_2021_04#query-parameters)."]
+            pub fn query_param(mut self, key: impl Into<String>, value: impl Into<String>) -> Self {
+                self.query_params.push((key.into(), value.into()));
+                self
+            }
+            #[doc = "Build the URL the request is sent to, including the api-version and any query parameters set on the builder, without sending the request."]
+            pub fn url(&self) -> azure_core::Result<azure_core::Url> {
+                let mut url = azure_core::Url::parse(&format!(
+                    "{}/subscriptions/{}/resourceGroups/{}/providers/Microsoft.KeyVault/vaults/{}",
+                    self.client.endpoint(),
+                    &azure_core::client::path_segment("subscription_id", &self.subscription_id)?,
+                    &azure_core::client::path_segment("resource_group_name", &self.resource_group_name)?,
+                    &azure_core::client::path_segment("vault_name", &self.vault_name)?
+                ))?;
+                url.query_pairs_mut()
+                    .append_pair(azure_core::query_param::API_VERSION, "2021-04-01-preview");
+                azure_core::client::append_query_params(&mut url, &self.query_params);
+                Ok(url)
+            }
             #[doc = "Send the request and returns the response."]
-            pub fn send(self) -> futures::future::BoxFuture<'static, azure_core::Result<Response>> {
-                Box::pin({
-                    let this = self.clone();
-                    async move {
-                        let url = azure_core::Url::parse(&format!(
-                            "{}/subscriptions/{}/resourceGroups/{}/providers/Microsoft.KeyVault/vaults/{}",
-                            this.client.endpoint(),
-                            &this.subscription_id,
-                            &this.resource_group_name,
-                            &this.vault_name
-                        ))?;
-                        let mut req = azure_core::Request::new(url, azure_core::Method::Get);
-                        let credential = this.client.token_credential();
-                        let token_response = credential.get_token(&this.client.scopes().join(" ")).await?;
-                        req.insert_header(
-                            azure_core::headers::AUTHORIZATION,
-                            format!("Bearer {}", token_response.token.secret()),
-                        );
-                        req.url_mut()
-                            .query_pairs_mut()
-                            .append_pair(azure_core::query_param::API_VERSION, "2021-04-01-preview");
-                        let req_body = azure_core::EMPTY_BODY;
-                        req.set_body(req_body);
-                        Ok(Response(this.client.send(&mut req).await?))
-                    }
-                })
+            #[doc = ""]
+            #[doc = "The builder is not consumed, so a polling loop can build it once and call `send` on every iteration; each call fetches a fresh token. The returned future is `'static` because it owns a clone of the builder, so it can be spawned or stored."]
+            pub fn send(&self) -> futures::future::BoxFuture<'static, azure_core::Result<Response>> {
+                let this = self.clone();
+                Box::pin(async move { this.send_ref().await })
+            }
+            #[doc = "Send the request without consuming the builder."]
+            #[doc = ""]
+            #[doc = "Unlike `send`, which clones the builder so that its future is `'static`, the request is built from borrowed fields, so a builder kept across calls, such as in a polling loop, is sent again without being cloned. The future borrows the builder in turn."]
+            pub async fn send_ref(&self) -> azure_core::Result<Response> {
+                let url = self.url()?;
+                let mut req = azure_core::Request::new(url, azure_core::Method::Get);
+                let token_response = self.client.token(self.claims.as_deref()).await?;
+                req.insert_header(
+                    azure_core::headers::AUTHORIZATION,
+                    format!("Bearer {}", token_response.token.secret()),
+                );
+                if let Some(accept_language) = &self.accept_language {
+                    req.insert_header(azure_core::headers::ACCEPT_LANGUAGE, accept_language.clone());
+                }
+                if let Some(if_none_match) = &self.if_none_match {
+                    req.insert_header(azure_core::headers::IF_NONE_MATCH, if_none_match);
+                }
+                let req_body = azure_core::EMPTY_BODY;
+                req.set_body(req_body);
+                Ok(Response(self.client.send(&mut req, "vaults::get").await?))
             }
             #[doc = "Send the request and return the response body."]
             pub fn into_future(self) -> futures::future::BoxFuture<'static, azure_core::Result<models::Vault>> {
                 Box::pin(async move { self.send().await?.into_body().await })
             }
+            #[doc = "Send the request and return the response body together with the response headers, such as the `ETag`."]
+            pub fn into_future_with_headers(
+                self,
+            ) -> futures::future::BoxFuture<'static, azure_core::Result<(models::Vault, azure_core::headers::Headers)>> {
+                Box::pin(async move {
+                    let rsp = self.send().await?;
+                    let headers = rsp.as_raw_response().headers().clone();
+                    Ok((rsp.into_body().await?, headers))
+                })
+            }
+            #[doc = "Send the request and return the response body, or `None` if the resource does not exist."]
+            pub fn get_if_exists(self) -> futures::future::BoxFuture<'static, azure_core::Result<Option<models::Vault>>> {
+                Box::pin(async move {
+                    match azure_core::client::not_found_as_none(self.send().await)? {
+                        Some(rsp) => Ok(Some(rsp.into_body().await?)),
+                        None => Ok(None),
+                    }
+                })
+            }
+            #[doc = "Send the request and return the response body, blocking the current thread."]
+            #[cfg(feature = "blocking")]
+            pub fn call(self) -> azure_core::Result<models::Vault> {
+                azure_core::blocking::block_on(self.into_future())
+            }
         }
     }
     pub mod create_or_update {
@@ -415,16 +660,48 @@ pub mod vaults {
         pub struct Response(azure_core::Response);
         impl Response {
             pub async fn into_body(self) -> azure_core::Result<models::Vault> {
-                let bytes = self.0.into_body().collect().await?;
-                let body: models::Vault = serde_json::from_slice(&bytes)?;
+                let status = self.0.status();
+                let bytes = self.into_byte_stream().collect().await?;
+                let body: models::Vault = azure_core::client::from_json_body("vaults::create_or_update", status, &bytes)?;
+                Ok(body)
+            }
+            #[doc = "Return the response body as untyped JSON; see [responses](crate::package_preview_2021_04#responses)."]
+            pub async fn into_value(self) -> azure_core::Result<serde_json::Value> {
+                let status = self.0.status();
+                let bytes = self.into_byte_stream().collect().await?;
+                let body: serde_json::Value = azure_core::client::from_json_body("vaults::create_or_update", status, &bytes)?;
                 Ok(body)
             }
+            #[doc = "Stream the response body in chunks as they are received, instead of buffering all of it like `into_body`."]
+            pub fn into_byte_stream(self) -> azure_core::ResponseBody {
+                self.0.into_body()
+            }
+            pub fn status(&self) -> azure_core::StatusCode {
+                self.0.status()
+            }
             pub fn into_raw_response(self) -> azure_core::Response {
                 self.0
             }
+            #[doc = "Read the whole response and return its status, headers and body as received, for forwarding it verbatim without going through `models`."]
+            pub async fn into_raw_parts(self) -> azure_core::Result<(azure_core::StatusCode, azure_core::headers::Headers, bytes::Bytes)> {
+                let (status, headers, body) = self.0.deconstruct();
+                Ok((status, headers, body.collect().await?))
+            }
             pub fn as_raw_response(&self) -> &azure_core::Response {
                 &self.0
             }
+            #[doc = "The remaining request quota; see [responses](crate::package_preview_2021_04#responses)."]
+            pub fn rate_limit_remaining(&self) -> Option<u32> {
+                azure_core::client::rate_limit_remaining(self.0.headers())
+            }
+            #[doc = "The `x-ms-client-request-id` the request was sent with; see [responses](crate::package_preview_2021_04#responses)."]
+            pub fn client_request_id(&self) -> Option<&str> {
+                self.0.headers().get_optional_str(&azure_core::headers::CLIENT_REQUEST_ID)
+            }
+            #[doc = "How long the request took; see [responses](crate::package_preview_2021_04#responses)."]
+            pub fn elapsed(&self) -> std::time::Duration {
+                self.0.elapsed().unwrap_or_default()
+            }
         }
         impl From<Response> for azure_core::Response {
             fn from(rsp: Response) -> Self {
@@ -443,42 +720,148 @@ pub mod vaults {
             pub(crate) vault_name: String,
             pub(crate) parameters: models::VaultCreateOrUpdateParameters,
             pub(crate) subscription_id: String,
+            pub(crate) claims: Option<String>,
+            pub(crate) accept_language: Option<String>,
+            pub(crate) idempotency_key: Option<(String, time::OffsetDateTime)>,
+            pub(crate) if_match: Option<super::super::ConcurrencyToken>,
+            pub(crate) query_params: Vec<(String, String)>,
+            pub(crate) raw_body: Option<(bytes::Bytes, String)>,
+        }
+        impl super::super::private::Sealed for RequestBuilder {}
+        impl super::super::Operation for RequestBuilder {
+            fn method() -> azure_core::Method {
+                azure_core::Method::Put
+            }
+            fn path_template() -> &'static str {
+                "/subscriptions/{subscriptionId}/resourceGroups/{resourceGroupName}/providers/Microsoft.KeyVault/vaults/{vaultName}"
+            }
         }
         impl RequestBuilder {
+            #[doc = "Ask the credential for a token that satisfies the JSON `claims`; see [claims](crate::package_preview_2021_04#claims)."]
+            pub fn claims(mut self, claims: impl Into<String>) -> Self {
+                self.claims = Some(claims.into());
+                self
+            }
+            #[doc = "Send this request with `accept_language` as its `Accept-Language` header, such as `fr-FR`, overriding `ClientBuilder::accept_language`."]
+            pub fn accept_language(mut self, accept_language: impl Into<String>) -> Self {
+                self.accept_language = Some(accept_language.into());
+                self
+            }
+            #[doc = "Send `key` in the `Repeatability-Request-ID` header, so that a service that deduplicates requests can recognize a retry of this one."]
+            #[doc = ""]
+            #[doc = "A service that does not deduplicate requests ignores the header, so a retry may still create the resource twice."]
+            #[doc = ""]
+            #[doc = "The time of this call is sent in `Repeatability-First-Sent` with every attempt, including retries by the pipeline and repeated calls of `send`; set a new key for a new request."]
+            pub fn idempotency_key(mut self, key: impl Into<String>) -> Self {
+                self.idempotency_key = Some((key.into(), time::OffsetDateTime::now_utc()));
+                self
+            }
+            #[doc = "Only apply the change if the resource still has the ETag of `token`, as read with `get`."]
+            #[doc = ""]
+            #[doc = "If the resource was changed in the meantime, the service answers `412 Precondition Failed` and the request fails with a `PreconditionFailed` error carrying the resource's current ETag, so an optimistic concurrency loop can read it again and retry."]
+            pub fn if_match(mut self, token: super::super::ConcurrencyToken) -> Self {
+                self.if_match = Some(token);
+                self
+            }
+            #[doc = "Append `key=value` to the query string; see [query parameters](crate::package_preview_2021_04#query-parameters)."]
+            pub fn query_param(mut self, key: impl Into<String>, value: impl Into<String>) -> Self {
+                self.query_params.push((key.into(), value.into()));
+                self
+            }
+            #[doc = "Send `body` as the JSON request body instead of the typed parameters, for fields the `models` type does not have yet."]
+            #[doc = ""]
+            #[doc = "Shorthand for `raw_body` with `content-type: application/json`; whichever of the two is called last wins."]
+            pub fn raw_json_body(self, body: serde_json::Value) -> Self {
+                self.raw_body(body.to_string(), "application/json")
+            }
+            #[doc = "Send `body` with the given `content-type` instead of the typed parameters."]
+            #[doc = ""]
+            #[doc = "The typed request body is not sent at all, so this is a stopgap for service changes the `models` types do not cover yet."]
+            pub fn raw_body(mut self, body: impl Into<bytes::Bytes>, content_type: impl Into<String>) -> Self {
+                self.raw_body = Some((body.into(), content_type.into()));
+                self
+            }
+            #[doc = "Build the URL the request is sent to, including the api-version and any query parameters set on the builder, without sending the request."]
+            pub fn url(&self) -> azure_core::Result<azure_core::Url> {
+                let mut url = azure_core::Url::parse(&format!(
+                    "{}/subscriptions/{}/resourceGroups/{}/providers/Microsoft.KeyVault/vaults/{}",
+                    self.client.endpoint(),
+                    &azure_core::client::path_segment("subscription_id", &self.subscription_id)?,
+                    &azure_core::client::path_segment("resource_group_name", &self.resource_group_name)?,
+                    &azure_core::client::path_segment("vault_name", &self.vault_name)?
+                ))?;
+                url.query_pairs_mut()
+                    .append_pair(azure_core::query_param::API_VERSION, "2021-04-01-preview");
+                azure_core::client::append_query_params(&mut url, &self.query_params);
+                Ok(url)
+            }
             #[doc = "only the first response will be fetched as long running operations are not supported yet"]
             #[doc = "Send the request and returns the response."]
-            pub fn send(self) -> futures::future::BoxFuture<'static, azure_core::Result<Response>> {
-                Box::pin({
-                    let this = self.clone();
-                    async move {
-                        let url = azure_core::Url::parse(&format!(
-                            "{}/subscriptions/{}/resourceGroups/{}/providers/Microsoft.KeyVault/vaults/{}",
-                            this.client.endpoint(),
-                            &this.subscription_id,
-                            &this.resource_group_name,
-                            &this.vault_name
-                        ))?;
-                        let mut req = azure_core::Request::new(url, azure_core::Method::Put);
-                        let credential = this.client.token_credential();
-                        let token_response = credential.get_token(&this.client.scopes().join(" ")).await?;
-                        req.insert_header(
-                            azure_core::headers::AUTHORIZATION,
-                            format!("Bearer {}", token_response.token.secret()),
-                        );
-                        req.url_mut()
-                            .query_pairs_mut()
-                            .append_pair(azure_core::query_param::API_VERSION, "2021-04-01-preview");
-                        req.insert_header("content-type", "application/json");
-                        let req_body = azure_core::to_json(&this.parameters)?;
-                        req.set_body(req_body);
-                        Ok(Response(this.client.send(&mut req).await?))
+            #[doc = ""]
+            #[doc = "The builder is not consumed, so a polling loop can build it once and call `send` on every iteration; each call fetches a fresh token. The returned future is `'static` because it owns a clone of the builder, so it can be spawned or stored."]
+            pub fn send(&self) -> futures::future::BoxFuture<'static, azure_core::Result<Response>> {
+                let this = self.clone();
+                Box::pin(async move { this.send_ref().await })
+            }
+            #[doc = "Send the request without consuming the builder."]
+            #[doc = ""]
+            #[doc = "Unlike `send`, which clones the builder so that its future is `'static`, the request is built from borrowed fields, so a builder kept across calls, such as in a polling loop, is sent again without being cloned. The future borrows the builder in turn."]
+            pub async fn send_ref(&self) -> azure_core::Result<Response> {
+                let url = self.url()?;
+                let mut req = azure_core::Request::new(url, azure_core::Method::Put);
+                let token_response = self.client.token(self.claims.as_deref()).await?;
+                req.insert_header(
+                    azure_core::headers::AUTHORIZATION,
+                    format!("Bearer {}", token_response.token.secret()),
+                );
+                if let Some(accept_language) = &self.accept_language {
+                    req.insert_header(azure_core::headers::ACCEPT_LANGUAGE, accept_language.clone());
+                }
+                if let Some((key, first_sent)) = &self.idempotency_key {
+                    req.insert_header(azure_core::headers::REPEATABILITY_REQUEST_ID, key.clone());
+                    req.insert_header(
+                        azure_core::headers::REPEATABILITY_FIRST_SENT,
+                        azure_core::date::to_rfc1123(first_sent),
+                    );
+                }
+                if let Some(if_match) = &self.if_match {
+                    req.insert_header(azure_core::headers::IF_MATCH, if_match.etag().to_owned());
+                }
+                req.insert_header("content-type", "application/json");
+                let req_body = match &self.raw_body {
+                    Some((body, content_type)) => {
+                        req.insert_header("content-type", content_type.clone());
+                        body.clone()
                     }
-                })
+                    None => azure_core::to_json(&self.parameters)?,
+                };
+                req.set_body(req_body);
+                Ok(Response(
+                    self.client
+                        .send(&mut req, "vaults::create_or_update")
+                        .await
+                        .map_err(azure_core::concurrency::precondition_failed)?,
+                ))
             }
             #[doc = "Send the request and return the response body."]
             pub fn into_future(self) -> futures::future::BoxFuture<'static, azure_core::Result<models::Vault>> {
                 Box::pin(async move { self.send().await?.into_body().await })
             }
+            #[doc = "Send the request and return the response body together with the response headers, such as the `ETag`."]
+            pub fn into_future_with_headers(
+                self,
+            ) -> futures::future::BoxFuture<'static, azure_core::Result<(models::Vault, azure_core::headers::Headers)>> {
+                Box::pin(async move {
+                    let rsp = self.send().await?;
+                    let headers = rsp.as_raw_response().headers().clone();
+                    Ok((rsp.into_body().await?, headers))
+                })
+            }
+            #[doc = "Send the request and return the response body, blocking the current thread."]
+            #[cfg(feature = "blocking")]
+            pub fn call(self) -> azure_core::Result<models::Vault> {
+                azure_core::blocking::block_on(self.into_future())
+            }
         }
     }
     pub mod update {
@@ -486,16 +869,48 @@ pub mod vaults {
         pub struct Response(azure_core::Response);
         impl Response {
             pub async fn into_body(self) -> azure_core::Result<models::Vault> {
-                let bytes = self.0.into_body().collect().await?;
-                let body: models::Vault = serde_json::from_slice(&bytes)?;
+                let status = self.0.status();
+                let bytes = self.into_byte_stream().collect().await?;
+                let body: models::Vault = azure_core::client::from_json_body("vaults::update", status, &bytes)?;
                 Ok(body)
             }
+            #[doc = "Return the response body as untyped JSON; see [responses](crate::package_preview_2021_04#responses)."]
+            pub async fn into_value(self) -> azure_core::Result<serde_json::Value> {
+                let status = self.0.status();
+                let bytes = self.into_byte_stream().collect().await?;
+                let body: serde_json::Value = azure_core::client::from_json_body("vaults::update", status, &bytes)?;
+                Ok(body)
+            }
+            #[doc = "Stream the response body in chunks as they are received, instead of buffering all of it like `into_body`."]
+            pub fn into_byte_stream(self) -> azure_core::ResponseBody {
+                self.0.into_body()
+            }
+            pub fn status(&self) -> azure_core::StatusCode {
+                self.0.status()
+            }
             pub fn into_raw_response(self) -> azure_core::Response {
                 self.0
             }
+            #[doc = "Read the whole response and return its status, headers and body as received, for forwarding it verbatim without going through `models`."]
+            pub async fn into_raw_parts(self) -> azure_core::Result<(azure_core::StatusCode, azure_core::headers::Headers, bytes::Bytes)> {
+                let (status, headers, body) = self.0.deconstruct();
+                Ok((status, headers, body.collect().await?))
+            }
             pub fn as_raw_response(&self) -> &azure_core::Response {
                 &self.0
             }
+            #[doc = "The remaining request quota; see [responses](crate::package_preview_2021_04#responses)."]
+            pub fn rate_limit_remaining(&self) -> Option<u32> {
+                azure_core::client::rate_limit_remaining(self.0.headers())
+            }
+            #[doc = "The `x-ms-client-request-id` the request was sent with; see [responses](crate::package_preview_2021_04#responses)."]
+            pub fn client_request_id(&self) -> Option<&str> {
+                self.0.headers().get_optional_str(&azure_core::headers::CLIENT_REQUEST_ID)
+            }
+            #[doc = "How long the request took; see [responses](crate::package_preview_2021_04#responses)."]
+            pub fn elapsed(&self) -> std::time::Duration {
+                self.0.elapsed().unwrap_or_default()
+            }
         }
         impl From<Response> for azure_core::Response {
             fn from(rsp: Response) -> Self {
@@ -514,81 +929,278 @@ pub mod vaults {
             pub(crate) vault_name: String,
             pub(crate) parameters: models::VaultPatchParameters,
             pub(crate) subscription_id: String,
+            pub(crate) claims: Option<String>,
+            pub(crate) accept_language: Option<String>,
+            pub(crate) if_match: Option<super::super::ConcurrencyToken>,
+            pub(crate) idempotent: bool,
+            pub(crate) prefer_representation: bool,
+            pub(crate) query_params: Vec<(String, String)>,
+            pub(crate) raw_body: Option<(bytes::Bytes, String)>,
+        }
+        impl super::super::private::Sealed for RequestBuilder {}
+        impl super::super::Operation for RequestBuilder {
+            fn method() -> azure_core::Method {
+                azure_core::Method::Patch
+            }
+            fn path_template() -> &'static str {
+                "/subscriptions/{subscriptionId}/resourceGroups/{resourceGroupName}/providers/Microsoft.KeyVault/vaults/{vaultName}"
+            }
         }
         impl RequestBuilder {
+            #[doc = "Ask the credential for a token that satisfies the JSON `claims`; see [claims](crate::package_preview_2021_04#claims)."]
+            pub fn claims(mut self, claims: impl Into<String>) -> Self {
+                self.claims = Some(claims.into());
+                self
+            }
+            #[doc = "Send this request with `accept_language` as its `Accept-Language` header, such as `fr-FR`, overriding `ClientBuilder::accept_language`."]
+            pub fn accept_language(mut self, accept_language: impl Into<String>) -> Self {
+                self.accept_language = Some(accept_language.into());
+                self
+            }
+            #[doc = "Only apply the change if the resource still has the ETag of `token`, as read with `get`."]
+            #[doc = ""]
+            #[doc = "If the resource was changed in the meantime, the service answers `412 Precondition Failed` and the request fails with a `PreconditionFailed` error carrying the resource's current ETag, so an optimistic concurrency loop can read it again and retry."]
+            pub fn if_match(mut self, token: super::super::ConcurrencyToken) -> Self {
+                self.if_match = Some(token);
+                self
+            }
+            #[doc = "Mark the request as safe to repeat, so the retry policy retries it after a connection error."]
+            #[doc = ""]
+            #[doc = "This request is not idempotent by default, and repeating it may repeat its side effect, so it is not retried after a connection error or timed out attempt unless this is set."]
+            pub fn idempotent(mut self, idempotent: bool) -> Self {
+                self.idempotent = idempotent;
+                self
+            }
+            #[doc = "Ask the service to answer with the full updated resource by sending `Prefer: return=representation`."]
+            #[doc = ""]
+            #[doc = "Saves a follow-up `get` when the service would otherwise answer with a minimal body. Off by default; the service may ignore the preference."]
+            pub fn prefer_representation(mut self, prefer_representation: bool) -> Self {
+                self.prefer_representation = prefer_representation;
+                self
+            }
+            #[doc = "Append `key=value` to the query string; see [query parameters](crate::package_preview_2021_04#query-parameters)."]
+            pub fn query_param(mut self, key: impl Into<String>, value: impl Into<String>) -> Self {
+                self.query_params.push((key.into(), value.into()));
+                self
+            }
+            #[doc = "Send `body` as the JSON request body instead of the typed parameters, for fields the `models` type does not have yet."]
+            #[doc = ""]
+            #[doc = "Shorthand for `raw_body` with `content-type: application/json`; whichever of the two is called last wins."]
+            pub fn raw_json_body(self, body: serde_json::Value) -> Self {
+                self.raw_body(body.to_string(), "application/json")
+            }
+            #[doc = "Send `body` with the given `content-type` instead of the typed parameters."]
+            #[doc = ""]
+            #[doc = "The typed request body is not sent at all, so this is a stopgap for service changes the `models` types do not cover yet."]
+            pub fn raw_body(mut self, body: impl Into<bytes::Bytes>, content_type: impl Into<String>) -> Self {
+                self.raw_body = Some((body.into(), content_type.into()));
+                self
+            }
+            #[doc = "Build the URL the request is sent to, including the api-version and any query parameters set on the builder, without sending the request."]
+            pub fn url(&self) -> azure_core::Result<azure_core::Url> {
+                let mut url = azure_core::Url::parse(&format!(
+                    "{}/subscriptions/{}/resourceGroups/{}/providers/Microsoft.KeyVault/vaults/{}",
+                    self.client.endpoint(),
+                    &azure_core::client::path_segment("subscription_id", &self.subscription_id)?,
+                    &azure_core::client::path_segment("resource_group_name", &self.resource_group_name)?,
+                    &azure_core::client::path_segment("vault_name", &self.vault_name)?
+                ))?;
+                url.query_pairs_mut()
+                    .append_pair(azure_core::query_param::API_VERSION, "2021-04-01-preview");
+                azure_core::client::append_query_params(&mut url, &self.query_params);
+                Ok(url)
+            }
             #[doc = "Send the request and returns the response."]
-            pub fn send(self) -> futures::future::BoxFuture<'static, azure_core::Result<Response>> {
-                Box::pin({
-                    let this = self.clone();
-                    async move {
-                        let url = azure_core::Url::parse(&format!(
-                            "{}/subscriptions/{}/resourceGroups/{}/providers/Microsoft.KeyVault/vaults/{}",
-                            this.client.endpoint(),
-                            &this.subscription_id,
-                            &this.resource_group_name,
-                            &this.vault_name
-                        ))?;
-                        let mut req = azure_core::Request::new(url, azure_core::Method::Patch);
-                        let credential = this.client.token_credential();
-                        let token_response = credential.get_token(&this.client.scopes().join(" ")).await?;
-                        req.insert_header(
-                            azure_core::headers::AUTHORIZATION,
-                            format!("Bearer {}", token_response.token.secret()),
-                        );
-                        req.url_mut()
-                            .query_pairs_mut()
-                            .append_pair(azure_core::query_param::API_VERSION, "2021-04-01-preview");
-                        req.insert_header("content-type", "application/json");
-                        let req_body = azure_core::to_json(&this.parameters)?;
-                        req.set_body(req_body);
-                        Ok(Response(this.client.send(&mut req).await?))
+            #[doc = ""]
+            #[doc = "The builder is not consumed, so a polling loop can build it once and call `send` on every iteration; each call fetches a fresh token. The returned future is `'static` because it owns a clone of the builder, so it can be spawned or stored."]
+            pub fn send(&self) -> futures::future::BoxFuture<'static, azure_core::Result<Response>> {
+                let this = self.clone();
+                Box::pin(async move { this.send_ref().await })
+            }
+            #[doc = "Send the request without consuming the builder."]
+            #[doc = ""]
+            #[doc = "Unlike `send`, which clones the builder so that its future is `'static`, the request is built from borrowed fields, so a builder kept across calls, such as in a polling loop, is sent again without being cloned. The future borrows the builder in turn."]
+            pub async fn send_ref(&self) -> azure_core::Result<Response> {
+                let url = self.url()?;
+                let mut req = azure_core::Request::new(url, azure_core::Method::Patch);
+                let token_response = self.client.token(self.claims.as_deref()).await?;
+                req.insert_header(
+                    azure_core::headers::AUTHORIZATION,
+                    format!("Bearer {}", token_response.token.secret()),
+                );
+                if let Some(accept_language) = &self.accept_language {
+                    req.insert_header(azure_core::headers::ACCEPT_LANGUAGE, accept_language.clone());
+                }
+                if let Some(if_match) = &self.if_match {
+                    req.insert_header(azure_core::headers::IF_MATCH, if_match.etag().to_owned());
+                }
+                req.insert_header("content-type", "application/json");
+                if self.prefer_representation {
+                    req.insert_header(azure_core::headers::PREFER, "return=representation");
+                }
+                let req_body = match &self.raw_body {
+                    Some((body, content_type)) => {
+                        req.insert_header("content-type", content_type.clone());
+                        body.clone()
                     }
-                })
+                    None => azure_core::to_json(&self.parameters)?,
+                };
+                req.set_body(req_body);
+                let mut context = azure_core::Context::new();
+                if self.idempotent {
+                    context.insert(azure_core::Idempotent);
+                }
+                Ok(Response(
+                    self.client
+                        .send_with_context(&mut req, "vaults::update", context)
+                        .await
+                        .map_err(azure_core::concurrency::precondition_failed)?,
+                ))
             }
             #[doc = "Send the request and return the response body."]
             pub fn into_future(self) -> futures::future::BoxFuture<'static, azure_core::Result<models::Vault>> {
                 Box::pin(async move { self.send().await?.into_body().await })
             }
+            #[doc = "Send the request and return the response body together with the response headers, such as the `ETag`."]
+            pub fn into_future_with_headers(
+                self,
+            ) -> futures::future::BoxFuture<'static, azure_core::Result<(models::Vault, azure_core::headers::Headers)>> {
+                Box::pin(async move {
+                    let rsp = self.send().await?;
+                    let headers = rsp.as_raw_response().headers().clone();
+                    Ok((rsp.into_body().await?, headers))
+                })
+            }
+            #[doc = "Send the request and return the response body, blocking the current thread."]
+            #[cfg(feature = "blocking")]
+            pub fn call(self) -> azure_core::Result<models::Vault> {
+                azure_core::blocking::block_on(self.into_future())
+            }
         }
     }
     pub mod delete {
         use super::models;
         pub struct Response(azure_core::Response);
+        impl Response {
+            #[doc = "Stream the response body in chunks as they are received, instead of buffering all of it like `into_body`."]
+            pub fn into_byte_stream(self) -> azure_core::ResponseBody {
+                self.0.into_body()
+            }
+            pub fn status(&self) -> azure_core::StatusCode {
+                self.0.status()
+            }
+            pub fn into_raw_response(self) -> azure_core::Response {
+                self.0
+            }
+            #[doc = "Read the whole response and return its status, headers and body as received, for forwarding it verbatim without going through `models`."]
+            pub async fn into_raw_parts(self) -> azure_core::Result<(azure_core::StatusCode, azure_core::headers::Headers, bytes::Bytes)> {
+                let (status, headers, body) = self.0.deconstruct();
+                Ok((status, headers, body.collect().await?))
+            }
+            pub fn as_raw_response(&self) -> &azure_core::Response {
+                &self.0
+            }
+            #[doc = "The remaining request quota; see [responses](crate::package_preview_2021_04#responses)."]
+            pub fn rate_limit_remaining(&self) -> Option<u32> {
+                azure_core::client::rate_limit_remaining(self.0.headers())
+            }
+            #[doc = "The `x-ms-client-request-id` the request was sent with; see [responses](crate::package_preview_2021_04#responses)."]
+            pub fn client_request_id(&self) -> Option<&str> {
+                self.0.headers().get_optional_str(&azure_core::headers::CLIENT_REQUEST_ID)
+            }
+            #[doc = "How long the request took; see [responses](crate::package_preview_2021_04#responses)."]
+            pub fn elapsed(&self) -> std::time::Duration {
+                self.0.elapsed().unwrap_or_default()
+            }
+        }
+        impl From<Response> for azure_core::Response {
+            fn from(rsp: Response) -> Self {
+                rsp.into_raw_response()
+            }
+        }
+        impl AsRef<azure_core::Response> for Response {
+            fn as_ref(&self) -> &azure_core::Response {
+                self.as_raw_response()
+            }
+        }
         #[derive(Clone)]
         pub struct RequestBuilder {
             pub(crate) client: super::super::Client,
             pub(crate) resource_group_name: String,
             pub(crate) vault_name: String,
             pub(crate) subscription_id: String,
+            pub(crate) claims: Option<String>,
+            pub(crate) accept_language: Option<String>,
+            pub(crate) query_params: Vec<(String, String)>,
+        }
+        impl super::super::private::Sealed for RequestBuilder {}
+        impl super::super::Operation for RequestBuilder {
+            fn method() -> azure_core::Method {
+                azure_core::Method::Delete
+            }
+            fn path_template() -> &'static str {
+                "/subscriptions/{subscriptionId}/resourceGroups/{resourceGroupName}/providers/Microsoft.KeyVault/vaults/{vaultName}"
+            }
         }
         impl RequestBuilder {
+            #[doc = "Ask the credential for a token that satisfies the JSON `claims`; see [claims](crate::package_preview_2021_04#claims)."]
+            pub fn claims(mut self, claims: impl Into<String>) -> Self {
+                self.claims = Some(claims.into());
+                self
+            }
+            #[doc = "Send this request with `accept_language` as its `Accept-Language` header, such as `fr-FR`, overriding `ClientBuilder::accept_language`."]
+            pub fn accept_language(mut self, accept_language: impl Into<String>) -> Self {
+                self.accept_language = Some(accept_language.into());
+                self
+            }
+            #[doc = "Append `key=value` to the query string; see [query parameters](crate::package_preview_2021_04#query-parameters)."]
+            pub fn query_param(mut self, key: impl Into<String>, value: impl Into<String>) -> Self {
+                self.query_params.push((key.into(), value.into()));
+                self
+            }
+            #[doc = "Build the URL the request is sent to, including the api-version and any query parameters set on the builder, without sending the request."]
+            pub fn url(&self) -> azure_core::Result<azure_core::Url> {
+                let mut url = azure_core::Url::parse(&format!(
+                    "{}/subscriptions/{}/resourceGroups/{}/providers/Microsoft.KeyVault/vaults/{}",
+                    self.client.endpoint(),
+                    &azure_core::client::path_segment("subscription_id", &self.subscription_id)?,
+                    &azure_core::client::path_segment("resource_group_name", &self.resource_group_name)?,
+                    &azure_core::client::path_segment("vault_name", &self.vault_name)?
+                ))?;
+                url.query_pairs_mut()
+                    .append_pair(azure_core::query_param::API_VERSION, "2021-04-01-preview");
+                azure_core::client::append_query_params(&mut url, &self.query_params);
+                Ok(url)
+            }
             #[doc = "Send the request and returns the response."]
-            pub fn send(self) -> futures::future::BoxFuture<'static, azure_core::Result<Response>> {
-                Box::pin({
-                    let this = self.clone();
-                    async move {
-                        let url = azure_core::Url::parse(&format!(
-                            "{}/subscriptions/{}/resourceGroups/{}/providers/Microsoft.KeyVault/vaults/{}",
-                            this.client.endpoint(),
-                            &this.subscription_id,
-                            &this.resource_group_name,
-                            &this.vault_name
-                        ))?;
-                        let mut req = azure_core::Request::new(url, azure_core::Method::Delete);
-                        let credential = this.client.token_credential();
-                        let token_response = credential.get_token(&this.client.scopes().join(" ")).await?;
-                        req.insert_header(
-                            azure_core::headers::AUTHORIZATION,
-                            format!("Bearer {}", token_response.token.secret()),
-                        );
-                        req.url_mut()
-                            .query_pairs_mut()
-                            .append_pair(azure_core::query_param::API_VERSION, "2021-04-01-preview");
-                        let req_body = azure_core::EMPTY_BODY;
-                        req.set_body(req_body);
-                        Ok(Response(this.client.send(&mut req).await?))
-                    }
-                })
+            #[doc = ""]
+            #[doc = "The builder is not consumed, so a polling loop can build it once and call `send` on every iteration; each call fetches a fresh token. The returned future is `'static` because it owns a clone of the builder, so it can be spawned or stored."]
+            pub fn send(&self) -> futures::future::BoxFuture<'static, azure_core::Result<Response>> {
+                let this = self.clone();
+                Box::pin(async move { this.send_ref().await })
+            }
+            #[doc = "Send the request without consuming the builder."]
+            #[doc = ""]
+            #[doc = "Unlike `send`, which clones the builder so that its future is `'static`, the request is built from borrowed fields, so a builder kept across calls, such as in a polling loop, is sent again without being cloned. The future borrows the builder in turn."]
+            pub async fn send_ref(&self) -> azure_core::Result<Response> {
+                let url = self.url()?;
+                let mut req = azure_core::Request::new(url, azure_core::Method::Delete);
+                let token_response = self.client.token(self.claims.as_deref()).await?;
+                req.insert_header(
+                    azure_core::headers::AUTHORIZATION,
+                    format!("Bearer {}", token_response.token.secret()),
+                );
+                if let Some(accept_language) = &self.accept_language {
+                    req.insert_header(azure_core::headers::ACCEPT_LANGUAGE, accept_language.clone());
+                }
+                let req_body = azure_core::EMPTY_BODY;
+                req.set_body(req_body);
+                Ok(Response(self.client.send(&mut req, "vaults::delete").await?))
+            }
+            #[doc = "Send the request and return the response, blocking the current thread."]
+            #[cfg(feature = "blocking")]
+            pub fn call(self) -> azure_core::Result<Response> {
+                azure_core::blocking::block_on(self.send())
             }
         }
     }
@@ -597,16 +1209,49 @@ pub mod vaults {
         pub struct Response(azure_core::Response);
         impl Response {
             pub async fn into_body(self) -> azure_core::Result<models::VaultAccessPolicyParameters> {
-                let bytes = self.0.into_body().collect().await?;
-                let body: models::VaultAccessPolicyParameters = serde_json::from_slice(&bytes)?;
+                let status = self.0.status();
+                let bytes = self.into_byte_stream().collect().await?;
+                let body: models::VaultAccessPolicyParameters =
+                    azure_core::client::from_json_body("vaults::update_access_policy", status, &bytes)?;
+                Ok(body)
+            }
+            #[doc = "Return the response body as untyped JSON; see [responses](crate::package_preview_2021_04#responses)."]
+            pub async fn into_value(self) -> azure_core::Result<serde_json::Value> {
+                let status = self.0.status();
+                let bytes = self.into_byte_stream().collect().await?;
+                let body: serde_json::Value = azure_core::client::from_json_body("vaults::update_access_policy", status, &bytes)?;
                 Ok(body)
             }
+            #[doc = "Stream the response body in chunks as they are received, instead of buffering all of it like `into_body`."]
+            pub fn into_byte_stream(self) -> azure_core::ResponseBody {
+                self.0.into_body()
+            }
+            pub fn status(&self) -> azure_core::StatusCode {
+                self.0.status()
+            }
             pub fn into_raw_response(self) -> azure_core::Response {
                 self.0
             }
+            #[doc = "Read the whole response and return its status, headers and body as received, for forwarding it verbatim without going through `models`."]
+            pub async fn into_raw_parts(self) -> azure_core::Result<(azure_core::StatusCode, azure_core::headers::Headers, bytes::Bytes)> {
+                let (status, headers, body) = self.0.deconstruct();
+                Ok((status, headers, body.collect().await?))
+            }
             pub fn as_raw_response(&self) -> &azure_core::Response {
                 &self.0
             }
+            #[doc = "The remaining request quota; see [responses](crate::package_preview_2021_04#responses)."]
+            pub fn rate_limit_remaining(&self) -> Option<u32> {
+                azure_core::client::rate_limit_remaining(self.0.headers())
+            }
+            #[doc = "The `x-ms-client-request-id` the request was sent with; see [responses](crate::package_preview_2021_04#responses)."]
+            pub fn client_request_id(&self) -> Option<&str> {
+                self.0.headers().get_optional_str(&azure_core::headers::CLIENT_REQUEST_ID)
+            }
+            #[doc = "How long the request took; see [responses](crate::package_preview_2021_04#responses)."]
+            pub fn elapsed(&self) -> std::time::Duration {
+                self.0.elapsed().unwrap_or_default()
+            }
         }
         impl From<Response> for azure_core::Response {
             fn from(rsp: Response) -> Self {
@@ -626,42 +1271,116 @@ pub mod vaults {
             pub(crate) operation_kind: String,
             pub(crate) parameters: models::VaultAccessPolicyParameters,
             pub(crate) subscription_id: String,
+            pub(crate) claims: Option<String>,
+            pub(crate) accept_language: Option<String>,
+            pub(crate) query_params: Vec<(String, String)>,
+            pub(crate) raw_body: Option<(bytes::Bytes, String)>,
+        }
+        impl super::super::private::Sealed for RequestBuilder {}
+        impl super::super::Operation for RequestBuilder {
+            fn method() -> azure_core::Method {
+                azure_core::Method::Put
+            }
+            fn path_template() -> &'static str {
+                "/subscriptions/{subscriptionId}/resourceGroups/{resourceGroupName}/providers/Microsoft.KeyVault/vaults/{vaultName}/accessPolicies/{operationKind}"
+            }
         }
         impl RequestBuilder {
+            #[doc = "Ask the credential for a token that satisfies the JSON `claims`; see [claims](crate::package_preview_2021_04#claims)."]
+            pub fn claims(mut self, claims: impl Into<String>) -> Self {
+                self.claims = Some(claims.into());
+                self
+            }
+            #[doc = "Send this request with `accept_language` as its `Accept-Language` header, such as `fr-FR`, overriding `ClientBuilder::accept_language`."]
+            pub fn accept_language(mut self, accept_language: impl Into<String>) -> Self {
+                self.accept_language = Some(accept_language.into());
+                self
+            }
+            #[doc = "Append `key=value` to the query string; see [query parameters](crate::package_preview_2021_04#query-parameters)."]
+            pub fn query_param(mut self, key: impl Into<String>, value: impl Into<String>) -> Self {
+                self.query_params.push((key.into(), value.into()));
+                self
+            }
+            #[doc = "Send `body` as the JSON request body instead of the typed parameters, for fields the `models` type does not have yet."]
+            #[doc = ""]
+            #[doc = "Shorthand for `raw_body` with `content-type: application/json`; whichever of the two is called last wins."]
+            pub fn raw_json_body(self, body: serde_json::Value) -> Self {
+                self.raw_body(body.to_string(), "application/json")
+            }
+            #[doc = "Send `body` with the given `content-type` instead of the typed parameters."]
+            #[doc = ""]
+            #[doc = "The typed request body is not sent at all, so this is a stopgap for service changes the `models` types do not cover yet."]
+            pub fn raw_body(mut self, body: impl Into<bytes::Bytes>, content_type: impl Into<String>) -> Self {
+                self.raw_body = Some((body.into(), content_type.into()));
+                self
+            }
+            #[doc = "Build the URL the request is sent to, including the api-version and any query parameters set on the builder, without sending the request."]
+            pub fn url(&self) -> azure_core::Result<azure_core::Url> {
+                let mut url = azure_core::Url::parse(&format!(
+                    "{}/subscriptions/{}/resourceGroups/{}/providers/Microsoft.KeyVault/vaults/{}/accessPolicies/{}",
+                    self.client.endpoint(),
+                    &azure_core::client::path_segment("subscription_id", &self.subscription_id)?,
+                    &azure_core::client::path_segment("resource_group_name", &self.resource_group_name)?,
+                    &azure_core::client::path_segment("vault_name", &self.vault_name)?,
+                    &azure_core::client::path_segment("operation_kind", &self.operation_kind)?
+                ))?;
+                url.query_pairs_mut()
+                    .append_pair(azure_core::query_param::API_VERSION, "2021-04-01-preview");
+                azure_core::client::append_query_params(&mut url, &self.query_params);
+                Ok(url)
+            }
             #[doc = "Send the request and returns the response."]
-            pub fn send(self) -> futures::future::BoxFuture<'static, azure_core::Result<Response>> {
-                Box::pin({
-                    let this = self.clone();
-                    async move {
-                        let url = azure_core::Url::parse(&format!(
-                            "{}/subscriptions/{}/resourceGroups/{}/providers/Microsoft.KeyVault/vaults/{}/accessPolicies/{}",
-                            this.client.endpoint(),
-                            &this.subscription_id,
-                            &this.resource_group_name,
-                            &this.vault_name,
-                            &this.operation_kind
-                        ))?;
-                        let mut req = azure_core::Request::new(url, azure_core::Method::Put);
-                        let credential = this.client.token_credential();
-                        let token_response = credential.get_token(&this.client.scopes().join(" ")).await?;
-                        req.insert_header(
-                            azure_core::headers::AUTHORIZATION,
-                            format!("Bearer {}", token_response.token.secret()),
-                        );
-                        req.url_mut()
-                            .query_pairs_mut()
-                            .append_pair(azure_core::query_param::API_VERSION, "2021-04-01-preview");
-                        req.insert_header("content-type", "application/json");
-                        let req_body = azure_core::to_json(&this.parameters)?;
-                        req.set_body(req_body);
-                        Ok(Response(this.client.send(&mut req).await?))
+            #[doc = ""]
+            #[doc = "The builder is not consumed, so a polling loop can build it once and call `send` on every iteration; each call fetches a fresh token. The returned future is `'static` because it owns a clone of the builder, so it can be spawned or stored."]
+            pub fn send(&self) -> futures::future::BoxFuture<'static, azure_core::Result<Response>> {
+                let this = self.clone();
+                Box::pin(async move { this.send_ref().await })
+            }
+            #[doc = "Send the request without consuming the builder."]
+            #[doc = ""]
+            #[doc = "Unlike `send`, which clones the builder so that its future is `'static`, the request is built from borrowed fields, so a builder kept across calls, such as in a polling loop, is sent again without being cloned. The future borrows the builder in turn."]
+            pub async fn send_ref(&self) -> azure_core::Result<Response> {
+                let url = self.url()?;
+                let mut req = azure_core::Request::new(url, azure_core::Method::Put);
+                let token_response = self.client.token(self.claims.as_deref()).await?;
+                req.insert_header(
+                    azure_core::headers::AUTHORIZATION,
+                    format!("Bearer {}", token_response.token.secret()),
+                );
+                if let Some(accept_language) = &self.accept_language {
+                    req.insert_header(azure_core::headers::ACCEPT_LANGUAGE, accept_language.clone());
+                }
+                req.insert_header("content-type", "application/json");
+                let req_body = match &self.raw_body {
+                    Some((body, content_type)) => {
+                        req.insert_header("content-type", content_type.clone());
+                        body.clone()
                     }
-                })
+                    None => azure_core::to_json(&self.parameters)?,
+                };
+                req.set_body(req_body);
+                Ok(Response(self.client.send(&mut req, "vaults::update_access_policy").await?))
             }
             #[doc = "Send the request and return the response body."]
             pub fn into_future(self) -> futures::future::BoxFuture<'static, azure_core::Result<models::VaultAccessPolicyParameters>> {
                 Box::pin(async move { self.send().await?.into_body().await })
             }
+            #[doc = "Send the request and return the response body together with the response headers, such as the `ETag`."]
+            pub fn into_future_with_headers(
+                self,
+            ) -> futures::future::BoxFuture<'static, azure_core::Result<(models::VaultAccessPolicyParameters, azure_core::headers::Headers)>>
+            {
+                Box::pin(async move {
+                    let rsp = self.send().await?;
+                    let headers = rsp.as_raw_response().headers().clone();
+                    Ok((rsp.into_body().await?, headers))
+                })
+            }
+            #[doc = "Send the request and return the response body, blocking the current thread."]
+            #[cfg(feature = "blocking")]
+            pub fn call(self) -> azure_core::Result<models::VaultAccessPolicyParameters> {
+                azure_core::blocking::block_on(self.into_future())
+            }
         }
     }
     pub mod list_by_resource_group {
@@ -669,16 +1388,52 @@ pub mod vaults {
         pub struct Response(azure_core::Response);
         impl Response {
             pub async fn into_body(self) -> azure_core::Result<models::VaultListResult> {
-                let bytes = self.0.into_body().collect().await?;
-                let body: models::VaultListResult = serde_json::from_slice(&bytes)?;
+                let status = self.0.status();
+                let bytes = self.into_byte_stream().collect().await?;
+                let body: models::VaultListResult = azure_core::client::from_json_body("vaults::list_by_resource_group", status, &bytes)?;
                 Ok(body)
             }
+            #[doc = "Stream the items of the page as they are received, instead of buffering the whole body like `into_body`. Everything but the `value` array, including the link to the next page, is skipped."]
+            pub fn into_body_streaming(self) -> futures::stream::BoxStream<'static, azure_core::Result<models::Vault>> {
+                self.into_byte_stream().json_array_items("value")
+            }
+            #[doc = "Return the response body as untyped JSON; see [responses](crate::package_preview_2021_04#responses)."]
+            pub async fn into_value(self) -> azure_core::Result<serde_json::Value> {
+                let status = self.0.status();
+                let bytes = self.into_byte_stream().collect().await?;
+                let body: serde_json::Value = azure_core::client::from_json_body("vaults::list_by_resource_group", status, &bytes)?;
+                Ok(body)
+            }
+            #[doc = "Stream the response body in chunks as they are received, instead of buffering all of it like `into_body`."]
+            pub fn into_byte_stream(self) -> azure_core::ResponseBody {
+                self.0.into_body()
+            }
+            pub fn status(&self) -> azure_core::StatusCode {
+                self.0.status()
+            }
             pub fn into_raw_response(self) -> azure_core::Response {
                 self.0
             }
+            #[doc = "Read the whole response and return its status, headers and body as received, for forwarding it verbatim without going through `models`."]
+            pub async fn into_raw_parts(self) -> azure_core::Result<(azure_core::StatusCode, azure_core::headers::Headers, bytes::Bytes)> {
+                let (status, headers, body) = self.0.deconstruct();
+                Ok((status, headers, body.collect().await?))
+            }
             pub fn as_raw_response(&self) -> &azure_core::Response {
                 &self.0
             }
+            #[doc = "The remaining request quota; see [responses](crate::package_preview_2021_04#responses)."]
+            pub fn rate_limit_remaining(&self) -> Option<u32> {
+                azure_core::client::rate_limit_remaining(self.0.headers())
+            }
+            #[doc = "The `x-ms-client-request-id` the request was sent with; see [responses](crate::package_preview_2021_04#responses)."]
+            pub fn client_request_id(&self) -> Option<&str> {
+                self.0.headers().get_optional_str(&azure_core::headers::CLIENT_REQUEST_ID)
+            }
+            #[doc = "How long the request took; see [responses](crate::package_preview_2021_04#responses)."]
+            pub fn elapsed(&self) -> std::time::Duration {
+                self.0.elapsed().unwrap_or_default()
+            }
         }
         impl From<Response> for azure_core::Response {
             fn from(rsp: Response) -> Self {
@@ -696,75 +1451,197 @@ pub mod vaults {
             pub(crate) resource_group_name: String,
             pub(crate) subscription_id: String,
             pub(crate) top: Option<i32>,
+            pub(crate) claims: Option<String>,
+            pub(crate) accept_language: Option<String>,
+            pub(crate) query_params: Vec<(String, String)>,
+            pub(crate) select: Option<String>,
+            pub(crate) max_items: Option<usize>,
+            pub(crate) on_missing_empty: bool,
+            pub(crate) resume_from: Option<String>,
+        }
+        impl super::super::private::Sealed for RequestBuilder {}
+        impl super::super::Operation for RequestBuilder {
+            fn method() -> azure_core::Method {
+                azure_core::Method::Get
+            }
+            fn path_template() -> &'static str {
+                "/subscriptions/{subscriptionId}/resourceGroups/{resourceGroupName}/providers/Microsoft.KeyVault/vaults"
+            }
+        }
+        impl super::super::BatchRead for RequestBuilder {
+            fn batch_url(&self) -> azure_core::Result<azure_core::Url> {
+                self.url()
+            }
         }
         impl RequestBuilder {
+            #[doc = "Ask the credential for a token that satisfies the JSON `claims`; see [claims](crate::package_preview_2021_04#claims)."]
+            pub fn claims(mut self, claims: impl Into<String>) -> Self {
+                self.claims = Some(claims.into());
+                self
+            }
+            #[doc = "Send this request with `accept_language` as its `Accept-Language` header, such as `fr-FR`, overriding `ClientBuilder::accept_language`."]
+            pub fn accept_language(mut self, accept_language: impl Into<String>) -> Self {
+                self.accept_language = Some(accept_language.into());
+                self
+            }
             #[doc = "Maximum number of results to return."]
+            #[doc = ""]
+            #[doc = "This is the page size. The service may cap it, so a page can hold fewer items than requested."]
             pub fn top(mut self, top: i32) -> Self {
                 self.top = Some(top);
                 self
             }
+            #[doc = "Append `key=value` to the query string; see [query parameters](crate::package_preview_2021_04#query-parameters)."]
+            pub fn query_param(mut self, key: impl Into<String>, value: impl Into<String>) -> Self {
+                self.query_params.push((key.into(), value.into()));
+                self
+            }
+            #[doc = "Only return the given properties of each item, such as `[\"id\", \"name\"]`, to reduce the work done by the service and the size of each page."]
+            #[doc = ""]
+            #[doc = "Fields of the returned models that were not selected are `None`."]
+            pub fn select(mut self, select: &[&str]) -> Self {
+                self.select = Some(select.join(","));
+                self
+            }
+            #[doc = "Limit the total number of items yielded by `into_item_stream`. No further pages are requested once the limit is reached."]
+            pub fn max_items(mut self, max_items: usize) -> Self {
+                self.max_items = Some(max_items);
+                self
+            }
+            #[doc = "Treat `404 Not Found` on the first page as an empty list instead of an error."]
+            #[doc = ""]
+            #[doc = "Useful when the parent resource may not exist yet, such as in a reconciliation loop racing its provisioning. The stream then yields a single empty page. Off by default, and a `404` on a later page is always an error."]
+            pub fn on_missing_empty(mut self, on_missing_empty: bool) -> Self {
+                self.on_missing_empty = on_missing_empty;
+                self
+            }
+            #[doc = "Start paging from `continuation`, a token saved from `into_checkpoint_stream` or `page`, instead of from the first page."]
+            #[doc = ""]
+            #[doc = "Lets a long listing be checkpointed and picked up where it stopped, such as after a restart."]
+            pub fn resume_from(mut self, continuation: impl Into<String>) -> Self {
+                self.resume_from = Some(continuation.into());
+                self
+            }
+            #[doc = "Build the URL the request is sent to, including the api-version and any query parameters set on the builder, without sending the request."]
+            pub fn url(&self) -> azure_core::Result<azure_core::Url> {
+                let mut url = azure_core::Url::parse(&format!(
+                    "{}/subscriptions/{}/resourceGroups/{}/providers/Microsoft.KeyVault/vaults",
+                    self.client.endpoint(),
+                    &azure_core::client::path_segment("subscription_id", &self.subscription_id)?,
+                    &azure_core::client::path_segment("resource_group_name", &self.resource_group_name)?
+                ))?;
+                url.query_pairs_mut()
+                    .append_pair(azure_core::query_param::API_VERSION, "2021-04-01-preview");
+                if let Some(top) = &self.top {
+                    url.query_pairs_mut().append_pair("$top", &top.to_string());
+                }
+                if let Some(select) = &self.select {
+                    url.query_pairs_mut().append_pair("$select", select);
+                }
+                azure_core::client::append_query_params(&mut url, &self.query_params);
+                Ok(url)
+            }
+            #[doc = "Stream the items of every page, stopping early if `max_items` is set."]
+            pub fn into_item_stream(self) -> futures::stream::BoxStream<'static, azure_core::Result<models::Vault>> {
+                let max_items = self.max_items;
+                azure_core::client::item_stream(self.into_stream(), max_items, |page| page.value)
+            }
+            #[doc = "Stream the items of each page together with the continuation token that resumes after it, or `None` after the last page."]
+            #[doc = ""]
+            #[doc = "Save the token once a page has been processed and pass it to `resume_from` to carry on from there later. `max_items` is not applied."]
+            pub fn into_checkpoint_stream(
+                self,
+            ) -> futures::stream::BoxStream<'static, azure_core::Result<(Vec<models::Vault>, Option<String>)>> {
+                azure_core::client::checkpoint_stream(self.into_stream(), |page| page.value)
+            }
             pub fn into_stream(self) -> azure_core::Pageable<models::VaultListResult, azure_core::error::Error> {
                 let make_request = move |continuation: Option<String>| {
-                    let this = self.clone();
-                    async move {
-                        let mut url = azure_core::Url::parse(&format!(
-                            "{}/subscriptions/{}/resourceGroups/{}/providers/Microsoft.KeyVault/vaults",
-                            this.client.endpoint(),
-                            &this.subscription_id,
-                            &this.resource_group_name
-                        ))?;
-                        let rsp = match continuation {
-                            Some(value) => {
-                                url.set_path("");
-                                url = url.join(&value)?;
-                                let mut req = azure_core::Request::new(url, azure_core::Method::Get);
-                                let credential = this.client.token_credential();
-                                let token_response = credential.get_token(&this.client.scopes().join(" ")).await?;
-                                req.insert_header(
-                                    azure_core::headers::AUTHORIZATION,
-                                    format!("Bearer {}", token_response.token.secret()),
-                                );
-                                let has_api_version_already =
-                                    req.url_mut().query_pairs().any(|(k, _)| k == azure_core::query_param::API_VERSION);
-                                if !has_api_version_already {
-                                    req.url_mut()
-                                        .query_pairs_mut()
-                                        .append_pair(azure_core::query_param::API_VERSION, "2021-04-01-preview");
-                                }
-                                let req_body = azure_core::EMPTY_BODY;
-                                req.set_body(req_body);
-                                this.client.send(&mut req).await?
+                    let continuation = continuation.or_else(|| self.resume_from.clone());
+                    self.clone().fetch_page(continuation)
+                };
+                azure_core::Pageable::new(make_request)
+            }
+            #[doc = "Fetch a single page and return its body together with the continuation token for the next page, if any."]
+            #[doc = ""]
+            #[doc = "Pass `None` to fetch the first page, or a token returned by a previous call to fetch the page that follows it."]
+            pub fn page(
+                &self,
+                continuation: Option<String>,
+            ) -> futures::future::BoxFuture<'static, azure_core::Result<(models::VaultListResult, Option<String>)>> {
+                let this = self.clone();
+                Box::pin(async move {
+                    let body = this.fetch_page(continuation).await?;
+                    let continuation = azure_core::Continuable::continuation(&body);
+                    Ok((body, continuation))
+                })
+            }
+            fn fetch_page(
+                self,
+                continuation: Option<String>,
+            ) -> futures::future::BoxFuture<'static, azure_core::Result<models::VaultListResult>> {
+                Box::pin(async move {
+                    let mut url = self.url()?;
+                    let rsp = match continuation {
+                        Some(value) => {
+                            let first_page_url = url;
+                            url = azure_core::next_page_url(&first_page_url, &value)?;
+                            azure_core::client::carry_query_params(&mut url, &first_page_url, &self.query_params);
+                            let mut req = azure_core::Request::new(url, azure_core::Method::Get);
+                            let token_response = self.client.token(self.claims.as_deref()).await?;
+                            req.insert_header(
+                                azure_core::headers::AUTHORIZATION,
+                                format!("Bearer {}", token_response.token.secret()),
+                            );
+                            if let Some(accept_language) = &self.accept_language {
+                                req.insert_header(azure_core::headers::ACCEPT_LANGUAGE, accept_language.clone());
+                            }
+                            if let Some(token) = azure_core::continuation_header(&value) {
+                                req.insert_header(azure_core::headers::CONTINUATION, token.to_owned());
                             }
-                            None => {
-                                let mut req = azure_core::Request::new(url, azure_core::Method::Get);
-                                let credential = this.client.token_credential();
-                                let token_response = credential.get_token(&this.client.scopes().join(" ")).await?;
-                                req.insert_header(
-                                    azure_core::headers::AUTHORIZATION,
-                                    format!("Bearer {}", token_response.token.secret()),
-                                );
-                                req.url_mut()
-                                    .query_pairs_mut()
-                                    .append_pair(azure_core::query_param::API_VERSION, "2021-04-01-preview");
-                                if let Some(top) = &this.top {
-                                    req.url_mut().query_pairs_mut().append_pair("$top", &top.to_string());
+                            let req_body = azure_core::EMPTY_BODY;
+                            req.set_body(req_body);
+                            self.client.send(&mut req, "vaults::list_by_resource_group").await?
+                        }
+                        None => {
+                            let mut req = azure_core::Request::new(url, azure_core::Method::Get);
+                            let token_response = self.client.token(self.claims.as_deref()).await?;
+                            req.insert_header(
+                                azure_core::headers::AUTHORIZATION,
+                                format!("Bearer {}", token_response.token.secret()),
+                            );
+                            if let Some(accept_language) = &self.accept_language {
+                                req.insert_header(azure_core::headers::ACCEPT_LANGUAGE, accept_language.clone());
+                            }
+                            let req_body = azure_core::EMPTY_BODY;
+                            req.set_body(req_body);
+                            let rsp = self.client.send(&mut req, "vaults::list_by_resource_group").await;
+                            if self.on_missing_empty {
+                                match azure_core::client::not_found_as_none(rsp)? {
+                                    Some(rsp) => rsp,
+                                    None => return Ok(Default::default()),
                                 }
-                                let req_body = azure_core::EMPTY_BODY;
-                                req.set_body(req_body);
-                                this.client.send(&mut req).await?
+                            } else {
+                                rsp?
                             }
-                        };
-                        let rsp = match rsp.status() {
-                            azure_core::StatusCode::Ok => Ok(Response(rsp)),
-                            status_code => Err(azure_core::error::Error::from(azure_core::error::ErrorKind::HttpResponse {
-                                status: status_code,
-                                error_code: None,
-                            })),
-                        };
-                        rsp?.into_body().await
+                        }
+                    };
+                    let rsp = match rsp.status() {
+                        azure_core::StatusCode::Ok => Ok(Response(rsp)),
+                        _ => Err(azure_core::client::unexpected_status(&rsp)),
+                    };
+                    let rsp = rsp?;
+                    let continuation = azure_core::continuation_from_headers(rsp.as_raw_response().headers());
+                    let mut body = rsp.into_body().await?;
+                    if body.next_link.is_none() {
+                        body.next_link = continuation;
                     }
-                };
-                azure_core::Pageable::new(make_request)
+                    Ok(body)
+                })
+            }
+            #[doc = "Fetch every page and return all items, blocking the current thread. Stops early if `max_items` is set."]
+            #[cfg(feature = "blocking")]
+            pub fn call(self) -> azure_core::Result<Vec<models::Vault>> {
+                azure_core::blocking::block_on(futures::TryStreamExt::try_collect(self.into_item_stream()))
             }
         }
     }
@@ -773,16 +1650,52 @@ pub mod vaults {
         pub struct Response(azure_core::Response);
         impl Response {
             pub async fn into_body(self) -> azure_core::Result<models::VaultListResult> {
-                let bytes = self.0.into_body().collect().await?;
-                let body: models::VaultListResult = serde_json::from_slice(&bytes)?;
+                let status = self.0.status();
+                let bytes = self.into_byte_stream().collect().await?;
+                let body: models::VaultListResult = azure_core::client::from_json_body("vaults::list_by_subscription", status, &bytes)?;
+                Ok(body)
+            }
+            #[doc = "Stream the items of the page as they are received, instead of buffering the whole body like `into_body`. Everything but the `value` array, including the link to the next page, is skipped."]
+            pub fn into_body_streaming(self) -> futures::stream::BoxStream<'static, azure_core::Result<models::Vault>> {
+                self.into_byte_stream().json_array_items("value")
+            }
+            #[doc = "Return the response body as untyped JSON; see [responses](crate::package_preview_2021_04#responses)."]
+            pub async fn into_value(self) -> azure_core::Result<serde_json::Value> {
+                let status = self.0.status();
+                let bytes = self.into_byte_stream().collect().await?;
+                let body: serde_json::Value = azure_core::client::from_json_body("vaults::list_by_subscription", status, &bytes)?;
                 Ok(body)
             }
+            #[doc = "Stream the response body in chunks as they are received, instead of buffering all of it like `into_body`."]
+            pub fn into_byte_stream(self) -> azure_core::ResponseBody {
+                self.0.into_body()
+            }
+            pub fn status(&self) -> azure_core::StatusCode {
+                self.0.status()
+            }
             pub fn into_raw_response(self) -> azure_core::Response {
                 self.0
             }
+            #[doc = "Read the whole response and return its status, headers and body as received, for forwarding it verbatim without going through `models`."]
+            pub async fn into_raw_parts(self) -> azure_core::Result<(azure_core::StatusCode, azure_core::headers::Headers, bytes::Bytes)> {
+                let (status, headers, body) = self.0.deconstruct();
+                Ok((status, headers, body.collect().await?))
+            }
             pub fn as_raw_response(&self) -> &azure_core::Response {
                 &self.0
             }
+            #[doc = "The remaining request quota; see [responses](crate::package_preview_2021_04#responses)."]
+            pub fn rate_limit_remaining(&self) -> Option<u32> {
+                azure_core::client::rate_limit_remaining(self.0.headers())
+            }
+            #[doc = "The `x-ms-client-request-id` the request was sent with; see [responses](crate::package_preview_2021_04#responses)."]
+            pub fn client_request_id(&self) -> Option<&str> {
+                self.0.headers().get_optional_str(&azure_core::headers::CLIENT_REQUEST_ID)
+            }
+            #[doc = "How long the request took; see [responses](crate::package_preview_2021_04#responses)."]
+            pub fn elapsed(&self) -> std::time::Duration {
+                self.0.elapsed().unwrap_or_default()
+            }
         }
         impl From<Response> for azure_core::Response {
             fn from(rsp: Response) -> Self {
@@ -799,74 +1712,196 @@ pub mod vaults {
             pub(crate) client: super::super::Client,
             pub(crate) subscription_id: String,
             pub(crate) top: Option<i32>,
+            pub(crate) claims: Option<String>,
+            pub(crate) accept_language: Option<String>,
+            pub(crate) query_params: Vec<(String, String)>,
+            pub(crate) select: Option<String>,
+            pub(crate) max_items: Option<usize>,
+            pub(crate) on_missing_empty: bool,
+            pub(crate) resume_from: Option<String>,
+        }
+        impl super::super::private::Sealed for RequestBuilder {}
+        impl super::super::Operation for RequestBuilder {
+            fn method() -> azure_core::Method {
+                azure_core::Method::Get
+            }
+            fn path_template() -> &'static str {
+                "/subscriptions/{subscriptionId}/providers/Microsoft.KeyVault/vaults"
+            }
+        }
+        impl super::super::BatchRead for RequestBuilder {
+            fn batch_url(&self) -> azure_core::Result<azure_core::Url> {
+                self.url()
+            }
         }
         impl RequestBuilder {
+            #[doc = "Ask the credential for a token that satisfies the JSON `claims`; see [claims](crate::package_preview_2021_04#claims)."]
+            pub fn claims(mut self, claims: impl Into<String>) -> Self {
+                self.claims = Some(claims.into());
+                self
+            }
+            #[doc = "Send this request with `accept_language` as its `Accept-Language` header, such as `fr-FR`, overriding `ClientBuilder::accept_language`."]
+            pub fn accept_language(mut self, accept_language: impl Into<String>) -> Self {
+                self.accept_language = Some(accept_language.into());
+                self
+            }
             #[doc = "Maximum number of results to return."]
+            #[doc = ""]
+            #[doc = "This is the page size. The service may cap it, so a page can hold fewer items than requested."]
             pub fn top(mut self, top: i32) -> Self {
                 self.top = Some(top);
                 self
             }
+            #[doc = "Append `key=value` to the query string; see [query parameters](crate::package_preview_2021_04#query-parameters)."]
+            pub fn query_param(mut self, key: impl Into<String>, value: impl Into<String>) -> Self {
+                self.query_params.push((key.into(), value.into()));
+                self
+            }
+            #[doc = "Only return the given properties of each item, such as `[\"id\", \"name\"]`, to reduce the work done by the service and the size of each page."]
+            #[doc = ""]
+            #[doc = "Fields of the returned models that were not selected are `None`."]
+            pub fn select(mut self, select: &[&str]) -> Self {
+                self.select = Some(select.join(","));
+                self
+            }
+            #[doc = "Limit the total number of items yielded by `into_item_stream`. No further pages are requested once the limit is reached."]
+            pub fn max_items(mut self, max_items: usize) -> Self {
+                self.max_items = Some(max_items);
+                self
+            }
+            #[doc = "Treat `404 Not Found` on the first page as an empty list instead of an error."]
+            #[doc = ""]
+            #[doc = "Useful when the parent resource may not exist yet, such as in a reconciliation loop racing its provisioning. The stream then yields a single empty page. Off by default, and a `404` on a later page is always an error."]
+            pub fn on_missing_empty(mut self, on_missing_empty: bool) -> Self {
+                self.on_missing_empty = on_missing_empty;
+                self
+            }
+            #[doc = "Start paging from `continuation`, a token saved from `into_checkpoint_stream` or `page`, instead of from the first page."]
+            #[doc = ""]
+            #[doc = "Lets a long listing be checkpointed and picked up where it stopped, such as after a restart."]
+            pub fn resume_from(mut self, continuation: impl Into<String>) -> Self {
+                self.resume_from = Some(continuation.into());
+                self
+            }
+            #[doc = "Build the URL the request is sent to, including the api-version and any query parameters set on the builder, without sending the request."]
+            pub fn url(&self) -> azure_core::Result<azure_core::Url> {
+                let mut url = azure_core::Url::parse(&format!(
+                    "{}/subscriptions/{}/providers/Microsoft.KeyVault/vaults",
+                    self.client.endpoint(),
+                    &azure_core::client::path_segment("subscription_id", &self.subscription_id)?
+                ))?;
+                url.query_pairs_mut()
+                    .append_pair(azure_core::query_param::API_VERSION, "2021-04-01-preview");
+                if let Some(top) = &self.top {
+                    url.query_pairs_mut().append_pair("$top", &top.to_string());
+                }
+                if let Some(select) = &self.select {
+                    url.query_pairs_mut().append_pair("$select", select);
+                }
+                azure_core::client::append_query_params(&mut url, &self.query_params);
+                Ok(url)
+            }
+            #[doc = "Stream the items of every page, stopping early if `max_items` is set."]
+            pub fn into_item_stream(self) -> futures::stream::BoxStream<'static, azure_core::Result<models::Vault>> {
+                let max_items = self.max_items;
+                azure_core::client::item_stream(self.into_stream(), max_items, |page| page.value)
+            }
+            #[doc = "Stream the items of each page together with the continuation token that resumes after it, or `None` after the last page."]
+            #[doc = ""]
+            #[doc = "Save the token once a page has been processed and pass it to `resume_from` to carry on from there later. `max_items` is not applied."]
+            pub fn into_checkpoint_stream(
+                self,
+            ) -> futures::stream::BoxStream<'static, azure_core::Result<(Vec<models::Vault>, Option<String>)>> {
+                azure_core::client::checkpoint_stream(self.into_stream(), |page| page.value)
+            }
             pub fn into_stream(self) -> azure_core::Pageable<models::VaultListResult, azure_core::error::Error> {
                 let make_request = move |continuation: Option<String>| {
-                    let this = self.clone();
-                    async move {
-                        let mut url = azure_core::Url::parse(&format!(
-                            "{}/subscriptions/{}/providers/Microsoft.KeyVault/vaults",
-                            this.client.endpoint(),
-                            &this.subscription_id
-                        ))?;
-                        let rsp = match continuation {
-                            Some(value) => {
-                                url.set_path("");
-                                url = url.join(&value)?;
-                                let mut req = azure_core::Request::new(url, azure_core::Method::Get);
-                                let credential = this.client.token_credential();
-                                let token_response = credential.get_token(&this.client.scopes().join(" ")).await?;
-                                req.insert_header(
-                                    azure_core::headers::AUTHORIZATION,
-                                    format!("Bearer {}", token_response.token.secret()),
-                                );
-                                let has_api_version_already =
-                                    req.url_mut().query_pairs().any(|(k, _)| k == azure_core::query_param::API_VERSION);
-                                if !has_api_version_already {
-                                    req.url_mut()
-                                        .query_pairs_mut()
-                                        .append_pair(azure_core::query_param::API_VERSION, "2021-04-01-preview");
-                                }
-                                let req_body = azure_core::EMPTY_BODY;
-                                req.set_body(req_body);
-                                this.client.send(&mut req).await?
+                    let continuation = continuation.or_else(|| self.resume_from.clone());
+                    self.clone().fetch_page(continuation)
+                };
+                azure_core::Pageable::new(make_request)
+            }
+            #[doc = "Fetch a single page and return its body together with the continuation token for the next page, if any."]
+            #[doc = ""]
+            #[doc = "Pass `None` to fetch the first page, or a token returned by a previous call to fetch the page that follows it."]
+            pub fn page(
+                &self,
+                continuation: Option<String>,
+            ) -> futures::future::BoxFuture<'static, azure_core::Result<(models::VaultListResult, Option<String>)>> {
+                let this = self.clone();
+                Box::pin(async move {
+                    let body = this.fetch_page(continuation).await?;
+                    let continuation = azure_core::Continuable::continuation(&body);
+                    Ok((body, continuation))
+                })
+            }
+            fn fetch_page(
+                self,
+                continuation: Option<String>,
+            ) -> futures::future::BoxFuture<'static, azure_core::Result<models::VaultListResult>> {
+                Box::pin(async move {
+                    let mut url = self.url()?;
+                    let rsp = match continuation {
+                        Some(value) => {
+                            let first_page_url = url;
+                            url = azure_core::next_page_url(&first_page_url, &value)?;
+                            azure_core::client::carry_query_params(&mut url, &first_page_url, &self.query_params);
+                            let mut req = azure_core::Request::new(url, azure_core::Method::Get);
+                            let token_response = self.client.token(self.claims.as_deref()).await?;
+                            req.insert_header(
+                                azure_core::headers::AUTHORIZATION,
+                                format!("Bearer {}", token_response.token.secret()),
+                            );
+                            if let Some(accept_language) = &self.accept_language {
+                                req.insert_header(azure_core::headers::ACCEPT_LANGUAGE, accept_language.clone());
                             }
-                            None => {
-                                let mut req = azure_core::Request::new(url, azure_core::Method::Get);
-                                let credential = this.client.token_credential();
-                                let token_response = credential.get_token(&this.client.scopes().join(" ")).await?;
-                                req.insert_header(
-                                    azure_core::headers::AUTHORIZATION,
-                                    format!("Bearer {}", token_response.token.secret()),
-                                );
-                                req.url_mut()
-                                    .query_pairs_mut()
-                                    .append_pair(azure_core::query_param::API_VERSION, "2021-04-01-preview");
-                                if let Some(top) = &this.top {
-                                    req.url_mut().query_pairs_mut().append_pair("$top", &top.to_string());
+                            if let Some(token) = azure_core::continuation_header(&value) {
+                                req.insert_header(azure_core::headers::CONTINUATION, token.to_owned());
+                            }
+                            let req_body = azure_core::EMPTY_BODY;
+                            req.set_body(req_body);
+                            self.client.send(&mut req, "vaults::list_by_subscription").await?
+                        }
+                        None => {
+                            let mut req = azure_core::Request::new(url, azure_core::Method::Get);
+                            let token_response = self.client.token(self.claims.as_deref()).await?;
+                            req.insert_header(
+                                azure_core::headers::AUTHORIZATION,
+                                format!("Bearer {}", token_response.token.secret()),
+                            );
+                            if let Some(accept_language) = &self.accept_language {
+                                req.insert_header(azure_core::headers::ACCEPT_LANGUAGE, accept_language.clone());
+                            }
+                            let req_body = azure_core::EMPTY_BODY;
+                            req.set_body(req_body);
+                            let rsp = self.client.send(&mut req, "vaults::list_by_subscription").await;
+                            if self.on_missing_empty {
+                                match azure_core::client::not_found_as_none(rsp)? {
+                                    Some(rsp) => rsp,
+                                    None => return Ok(Default::default()),
                                 }
-                                let req_body = azure_core::EMPTY_BODY;
-                                req.set_body(req_body);
-                                this.client.send(&mut req).await?
+                            } else {
+                                rsp?
                             }
-                        };
-                        let rsp = match rsp.status() {
-                            azure_core::StatusCode::Ok => Ok(Response(rsp)),
-                            status_code => Err(azure_core::error::Error::from(azure_core::error::ErrorKind::HttpResponse {
-                                status: status_code,
-                                error_code: None,
-                            })),
-                        };
-                        rsp?.into_body().await
+                        }
+                    };
+                    let rsp = match rsp.status() {
+                        azure_core::StatusCode::Ok => Ok(Response(rsp)),
+                        _ => Err(azure_core::client::unexpected_status(&rsp)),
+                    };
+                    let rsp = rsp?;
+                    let continuation = azure_core::continuation_from_headers(rsp.as_raw_response().headers());
+                    let mut body = rsp.into_body().await?;
+                    if body.next_link.is_none() {
+                        body.next_link = continuation;
                     }
-                };
-                azure_core::Pageable::new(make_request)
+                    Ok(body)
+                })
+            }
+            #[doc = "Fetch every page and return all items, blocking the current thread. Stops early if `max_items` is set."]
+            #[cfg(feature = "blocking")]
+            pub fn call(self) -> azure_core::Result<Vec<models::Vault>> {
+                azure_core::blocking::block_on(futures::TryStreamExt::try_collect(self.into_item_stream()))
             }
         }
     }
@@ -875,16 +1910,52 @@ pub mod vaults {
         pub struct Response(azure_core::Response);
         impl Response {
             pub async fn into_body(self) -> azure_core::Result<models::DeletedVaultListResult> {
-                let bytes = self.0.into_body().collect().await?;
-                let body: models::DeletedVaultListResult = serde_json::from_slice(&bytes)?;
+                let status = self.0.status();
+                let bytes = self.into_byte_stream().collect().await?;
+                let body: models::DeletedVaultListResult = azure_core::client::from_json_body("vaults::list_deleted", status, &bytes)?;
+                Ok(body)
+            }
+            #[doc = "Stream the items of the page as they are received, instead of buffering the whole body like `into_body`. Everything but the `value` array, including the link to the next page, is skipped."]
+            pub fn into_body_streaming(self) -> futures::stream::BoxStream<'static, azure_core::Result<models::DeletedVault>> {
+                self.into_byte_stream().json_array_items("value")
+            }
+            #[doc = "Return the response body as untyped JSON; see [responses](crate::package_preview_2021_04#responses)."]
+            pub async fn into_value(self) -> azure_core::Result<serde_json::Value> {
+                let status = self.0.status();
+                let bytes = self.into_byte_stream().collect().await?;
+                let body: serde_json::Value = azure_core::client::from_json_body("vaults::list_deleted", status, &bytes)?;
                 Ok(body)
             }
+            #[doc = "Stream the response body in chunks as they are received, instead of buffering all of it like `into_body`."]
+            pub fn into_byte_stream(self) -> azure_core::ResponseBody {
+                self.0.into_body()
+            }
+            pub fn status(&self) -> azure_core::StatusCode {
+                self.0.status()
+            }
             pub fn into_raw_response(self) -> azure_core::Response {
                 self.0
             }
+            #[doc = "Read the whole response and return its status, headers and body as received, for forwarding it verbatim without going through `models`."]
+            pub async fn into_raw_parts(self) -> azure_core::Result<(azure_core::StatusCode, azure_core::headers::Headers, bytes::Bytes)> {
+                let (status, headers, body) = self.0.deconstruct();
+                Ok((status, headers, body.collect().await?))
+            }
             pub fn as_raw_response(&self) -> &azure_core::Response {
                 &self.0
             }
+            #[doc = "The remaining request quota; see [responses](crate::package_preview_2021_04#responses)."]
+            pub fn rate_limit_remaining(&self) -> Option<u32> {
+                azure_core::client::rate_limit_remaining(self.0.headers())
+            }
+            #[doc = "The `x-ms-client-request-id` the request was sent with; see [responses](crate::package_preview_2021_04#responses)."]
+            pub fn client_request_id(&self) -> Option<&str> {
+                self.0.headers().get_optional_str(&azure_core::headers::CLIENT_REQUEST_ID)
+            }
+            #[doc = "How long the request took; see [responses](crate::package_preview_2021_04#responses)."]
+            pub fn elapsed(&self) -> std::time::Duration {
+                self.0.elapsed().unwrap_or_default()
+            }
         }
         impl From<Response> for azure_core::Response {
             fn from(rsp: Response) -> Self {
@@ -900,66 +1971,186 @@ pub mod vaults {
         pub struct RequestBuilder {
             pub(crate) client: super::super::Client,
             pub(crate) subscription_id: String,
+            pub(crate) claims: Option<String>,
+            pub(crate) accept_language: Option<String>,
+            pub(crate) query_params: Vec<(String, String)>,
+            pub(crate) select: Option<String>,
+            pub(crate) max_items: Option<usize>,
+            pub(crate) on_missing_empty: bool,
+            pub(crate) resume_from: Option<String>,
+        }
+        impl super::super::private::Sealed for RequestBuilder {}
+        impl super::super::Operation for RequestBuilder {
+            fn method() -> azure_core::Method {
+                azure_core::Method::Get
+            }
+            fn path_template() -> &'static str {
+                "/subscriptions/{subscriptionId}/providers/Microsoft.KeyVault/deletedVaults"
+            }
+        }
+        impl super::super::BatchRead for RequestBuilder {
+            fn batch_url(&self) -> azure_core::Result<azure_core::Url> {
+                self.url()
+            }
         }
         impl RequestBuilder {
+            #[doc = "Ask the credential for a token that satisfies the JSON `claims`; see [claims](crate::package_preview_2021_04#claims)."]
+            pub fn claims(mut self, claims: impl Into<String>) -> Self {
+                self.claims = Some(claims.into());
+                self
+            }
+            #[doc = "Send this request with `accept_language` as its `Accept-Language` header, such as `fr-FR`, overriding `ClientBuilder::accept_language`."]
+            pub fn accept_language(mut self, accept_language: impl Into<String>) -> Self {
+                self.accept_language = Some(accept_language.into());
+                self
+            }
+            #[doc = "Append `key=value` to the query string; see [query parameters](crate::package_preview_2021_04#query-parameters)."]
+            pub fn query_param(mut self, key: impl Into<String>, value: impl Into<String>) -> Self {
+                self.query_params.push((key.into(), value.into()));
+                self
+            }
+            #[doc = "Only return the given properties of each item, such as `[\"id\", \"name\"]`, to reduce the work done by the service and the size of each page."]
+            #[doc = ""]
+            #[doc = "Fields of the returned models that were not selected are `None`."]
+            pub fn select(mut self, select: &[&str]) -> Self {
+                self.select = Some(select.join(","));
+                self
+            }
+            #[doc = "Limit the total number of items yielded by `into_item_stream`. No further pages are requested once the limit is reached."]
+            pub fn max_items(mut self, max_items: usize) -> Self {
+                self.max_items = Some(max_items);
+                self
+            }
+            #[doc = "Treat `404 Not Found` on the first page as an empty list instead of an error."]
+            #[doc = ""]
+            #[doc = "Useful when the parent resource may not exist yet, such as in a reconciliation loop racing its provisioning. The stream then yields a single empty page. Off by default, and a `404` on a later page is always an error."]
+            pub fn on_missing_empty(mut self, on_missing_empty: bool) -> Self {
+                self.on_missing_empty = on_missing_empty;
+                self
+            }
+            #[doc = "Start paging from `continuation`, a token saved from `into_checkpoint_stream` or `page`, instead of from the first page."]
+            #[doc = ""]
+            #[doc = "Lets a long listing be checkpointed and picked up where it stopped, such as after a restart."]
+            pub fn resume_from(mut self, continuation: impl Into<String>) -> Self {
+                self.resume_from = Some(continuation.into());
+                self
+            }
+            #[doc = "Build the URL the request is sent to, including the api-version and any query parameters set on the builder, without sending the request."]
+            pub fn url(&self) -> azure_core::Result<azure_core::Url> {
+                let mut url = azure_core::Url::parse(&format!(
+                    "{}/subscriptions/{}/providers/Microsoft.KeyVault/deletedVaults",
+                    self.client.endpoint(),
+                    &azure_core::client::path_segment("subscription_id", &self.subscription_id)?
+                ))?;
+                url.query_pairs_mut()
+                    .append_pair(azure_core::query_param::API_VERSION, "2021-04-01-preview");
+                if let Some(select) = &self.select {
+                    url.query_pairs_mut().append_pair("$select", select);
+                }
+                azure_core::client::append_query_params(&mut url, &self.query_params);
+                Ok(url)
+            }
+            #[doc = "Stream the items of every page, stopping early if `max_items` is set."]
+            pub fn into_item_stream(self) -> futures::stream::BoxStream<'static, azure_core::Result<models::DeletedVault>> {
+                let max_items = self.max_items;
+                azure_core::client::item_stream(self.into_stream(), max_items, |page| page.value)
+            }
+            #[doc = "Stream the items of each page together with the continuation token that resumes after it, or `None` after the last page."]
+            #[doc = ""]
+            #[doc = "Save the token once a page has been processed and pass it to `resume_from` to carry on from there later. `max_items` is not applied."]
+            pub fn into_checkpoint_stream(
+                self,
+            ) -> futures::stream::BoxStream<'static, azure_core::Result<(Vec<models::DeletedVault>, Option<String>)>> {
+                azure_core::client::checkpoint_stream(self.into_stream(), |page| page.value)
+            }
             pub fn into_stream(self) -> azure_core::Pageable<models::DeletedVaultListResult, azure_core::error::Error> {
                 let make_request = move |continuation: Option<String>| {
-                    let this = self.clone();
-                    async move {
-                        let mut url = azure_core::Url::parse(&format!(
-                            "{}/subscriptions/{}/providers/Microsoft.KeyVault/deletedVaults",
-                            this.client.endpoint(),
-                            &this.subscription_id
-                        ))?;
-                        let rsp = match continuation {
-                            Some(value) => {
-                                url.set_path("");
-                                url = url.join(&value)?;
-                                let mut req = azure_core::Request::new(url, azure_core::Method::Get);
-                                let credential = this.client.token_credential();
-                                let token_response = credential.get_token(&this.client.scopes().join(" ")).await?;
-                                req.insert_header(
-                                    azure_core::headers::AUTHORIZATION,
-                                    format!("Bearer {}", token_response.token.secret()),
-                                );
-                                let has_api_version_already =
-                                    req.url_mut().query_pairs().any(|(k, _)| k == azure_core::query_param::API_VERSION);
-                                if !has_api_version_already {
-                                    req.url_mut()
-                                        .query_pairs_mut()
-                                        .append_pair(azure_core::query_param::API_VERSION, "2021-04-01-preview");
-                                }
-                                let req_body = azure_core::EMPTY_BODY;
-                                req.set_body(req_body);
-                                this.client.send(&mut req).await?
+                    let continuation = continuation.or_else(|| self.resume_from.clone());
+                    self.clone().fetch_page(continuation)
+                };
+                azure_core::Pageable::new(make_request)
+            }
+            #[doc = "Fetch a single page and return its body together with the continuation token for the next page, if any."]
+            #[doc = ""]
+            #[doc = "Pass `None` to fetch the first page, or a token returned by a previous call to fetch the page that follows it."]
+            pub fn page(
+                &self,
+                continuation: Option<String>,
+            ) -> futures::future::BoxFuture<'static, azure_core::Result<(models::DeletedVaultListResult, Option<String>)>> {
+                let this = self.clone();
+                Box::pin(async move {
+                    let body = this.fetch_page(continuation).await?;
+                    let continuation = azure_core::Continuable::continuation(&body);
+                    Ok((body, continuation))
+                })
+            }
+            fn fetch_page(
+                self,
+                continuation: Option<String>,
+            ) -> futures::future::BoxFuture<'static, azure_core::Result<models::DeletedVaultListResult>> {
+                Box::pin(async move {
+                    let mut url = self.url()?;
+                    let rsp = match continuation {
+                        Some(value) => {
+                            let first_page_url = url;
+                            url = azure_core::next_page_url(&first_page_url, &value)?;
+                            azure_core::client::carry_query_params(&mut url, &first_page_url, &self.query_params);
+                            let mut req = azure_core::Request::new(url, azure_core::Method::Get);
+                            let token_response = self.client.token(self.claims.as_deref()).await?;
+                            req.insert_header(
+                                azure_core::headers::AUTHORIZATION,
+                                format!("Bearer {}", token_response.token.secret()),
+                            );
+                            if let Some(accept_language) = &self.accept_language {
+                                req.insert_header(azure_core::headers::ACCEPT_LANGUAGE, accept_language.clone());
+                            }
+                            if let Some(token) = azure_core::continuation_header(&value) {
+                                req.insert_header(azure_core::headers::CONTINUATION, token.to_owned());
                             }
-                            None => {
-                                let mut req = azure_core::Request::new(url, azure_core::Method::Get);
-                                let credential = this.client.token_credential();
-                                let token_response = credential.get_token(&this.client.scopes().join(" ")).await?;
-                                req.insert_header(
-                                    azure_core::headers::AUTHORIZATION,
-                                    format!("Bearer {}", token_response.token.secret()),
-                                );
-                                req.url_mut()
-                                    .query_pairs_mut()
-                                    .append_pair(azure_core::query_param::API_VERSION, "2021-04-01-preview");
-                                let req_body = azure_core::EMPTY_BODY;
-                                req.set_body(req_body);
-                                this.client.send(&mut req).await?
+                            let req_body = azure_core::EMPTY_BODY;
+                            req.set_body(req_body);
+                            self.client.send(&mut req, "vaults::list_deleted").await?
+                        }
+                        None => {
+                            let mut req = azure_core::Request::new(url, azure_core::Method::Get);
+                            let token_response = self.client.token(self.claims.as_deref()).await?;
+                            req.insert_header(
+                                azure_core::headers::AUTHORIZATION,
+                                format!("Bearer {}", token_response.token.secret()),
+                            );
+                            if let Some(accept_language) = &self.accept_language {
+                                req.insert_header(azure_core::headers::ACCEPT_LANGUAGE, accept_language.clone());
+                            }
+                            let req_body = azure_core::EMPTY_BODY;
+                            req.set_body(req_body);
+                            let rsp = self.client.send(&mut req, "vaults::list_deleted").await;
+                            if self.on_missing_empty {
+                                match azure_core::client::not_found_as_none(rsp)? {
+                                    Some(rsp) => rsp,
+                                    None => return Ok(Default::default()),
+                                }
+                            } else {
+                                rsp?
                             }
-                        };
-                        let rsp = match rsp.status() {
-                            azure_core::StatusCode::Ok => Ok(Response(rsp)),
-                            status_code => Err(azure_core::error::Error::from(azure_core::error::ErrorKind::HttpResponse {
-                                status: status_code,
-                                error_code: None,
-                            })),
-                        };
-                        rsp?.into_body().await
+                        }
+                    };
+                    let rsp = match rsp.status() {
+                        azure_core::StatusCode::Ok => Ok(Response(rsp)),
+                        _ => Err(azure_core::client::unexpected_status(&rsp)),
+                    };
+                    let rsp = rsp?;
+                    let continuation = azure_core::continuation_from_headers(rsp.as_raw_response().headers());
+                    let mut body = rsp.into_body().await?;
+                    if body.next_link.is_none() {
+                        body.next_link = continuation;
                     }
-                };
-                azure_core::Pageable::new(make_request)
+                    Ok(body)
+                })
+            }
+            #[doc = "Fetch every page and return all items, blocking the current thread. Stops early if `max_items` is set."]
+            #[cfg(feature = "blocking")]
+            pub fn call(self) -> azure_core::Result<Vec<models::DeletedVault>> {
+                azure_core::blocking::block_on(futures::TryStreamExt::try_collect(self.into_item_stream()))
             }
         }
     }
@@ -968,16 +2159,48 @@ pub mod vaults {
         pub struct Response(azure_core::Response);
         impl Response {
             pub async fn into_body(self) -> azure_core::Result<models::DeletedVault> {
-                let bytes = self.0.into_body().collect().await?;
-                let body: models::DeletedVault = serde_json::from_slice(&bytes)?;
+                let status = self.0.status();
+                let bytes = self.into_byte_stream().collect().await?;
+                let body: models::DeletedVault = azure_core::client::from_json_body("vaults::get_deleted", status, &bytes)?;
+                Ok(body)
+            }
+            #[doc = "Return the response body as untyped JSON; see [responses](crate::package_preview_2021_04#responses)."]
+            pub async fn into_value(self) -> azure_core::Result<serde_json::Value> {
+                let status = self.0.status();
+                let bytes = self.into_byte_stream().collect().await?;
+                let body: serde_json::Value = azure_core::client::from_json_body("vaults::get_deleted", status, &bytes)?;
                 Ok(body)
             }
+            #[doc = "Stream the response body in chunks as they are received, instead of buffering all of it like `into_body`."]
+            pub fn into_byte_stream(self) -> azure_core::ResponseBody {
+                self.0.into_body()
+            }
+            pub fn status(&self) -> azure_core::StatusCode {
+                self.0.status()
+            }
             pub fn into_raw_response(self) -> azure_core::Response {
                 self.0
             }
+            #[doc = "Read the whole response and return its status, headers and body as received, for forwarding it verbatim without going through `models`."]
+            pub async fn into_raw_parts(self) -> azure_core::Result<(azure_core::StatusCode, azure_core::headers::Headers, bytes::Bytes)> {
+                let (status, headers, body) = self.0.deconstruct();
+                Ok((status, headers, body.collect().await?))
+            }
             pub fn as_raw_response(&self) -> &azure_core::Response {
                 &self.0
             }
+            #[doc = "The remaining request quota; see [responses](crate::package_preview_2021_04#responses)."]
+            pub fn rate_limit_remaining(&self) -> Option<u32> {
+                azure_core::client::rate_limit_remaining(self.0.headers())
+            }
+            #[doc = "The `x-ms-client-request-id` the request was sent with; see [responses](crate::package_preview_2021_04#responses)."]
+            pub fn client_request_id(&self) -> Option<&str> {
+                self.0.headers().get_optional_str(&azure_core::headers::CLIENT_REQUEST_ID)
+            }
+            #[doc = "How long the request took; see [responses](crate::package_preview_2021_04#responses)."]
+            pub fn elapsed(&self) -> std::time::Duration {
+                self.0.elapsed().unwrap_or_default()
+            }
         }
         impl From<Response> for azure_core::Response {
             fn from(rsp: Response) -> Self {
@@ -995,82 +2218,239 @@ pub mod vaults {
             pub(crate) vault_name: String,
             pub(crate) location: String,
             pub(crate) subscription_id: String,
+            pub(crate) claims: Option<String>,
+            pub(crate) accept_language: Option<String>,
+            pub(crate) query_params: Vec<(String, String)>,
+        }
+        impl super::super::private::Sealed for RequestBuilder {}
+        impl super::super::Operation for RequestBuilder {
+            fn method() -> azure_core::Method {
+                azure_core::Method::Get
+            }
+            fn path_template() -> &'static str {
+                "/subscriptions/{subscriptionId}/providers/Microsoft.KeyVault/locations/{location}/deletedVaults/{vaultName}"
+            }
+        }
+        impl super::super::BatchRead for RequestBuilder {
+            fn batch_url(&self) -> azure_core::Result<azure_core::Url> {
+                self.url()
+            }
         }
         impl RequestBuilder {
+            #[doc = "Ask the credential for a token that satisfies the JSON `claims`; see [claims](crate::package_preview_2021_04#claims)."]
+            pub fn claims(mut self, claims: impl Into<String>) -> Self {
+                self.claims = Some(claims.into());
+                self
+            }
+            #[doc = "Send this request with `accept_language` as its `Accept-Language` header, such as `fr-FR`, overriding `ClientBuilder::accept_language`."]
+            pub fn accept_language(mut self, accept_language: impl Into<String>) -> Self {
+                self.accept_language = Some(accept_language.into());
+                self
+            }
+            #[doc = "Append `key=value` to the query string; see [query parameters](crate::package_preview_2021_04#query-parameters)."]
+            pub fn query_param(mut self, key: impl Into<String>, value: impl Into<String>) -> Self {
+                self.query_params.push((key.into(), value.into()));
+                self
+            }
+            #[doc = "Build the URL the request is sent to, including the api-version and any query parameters set on the builder, without sending the request."]
+            pub fn url(&self) -> azure_core::Result<azure_core::Url> {
+                let mut url = azure_core::Url::parse(&format!(
+                    "{}/subscriptions/{}/providers/Microsoft.KeyVault/locations/{}/deletedVaults/{}",
+                    self.client.endpoint(),
+                    &azure_core::client::path_segment("subscription_id", &self.subscription_id)?,
+                    &azure_core::client::path_segment("location", &self.location)?,
+                    &azure_core::client::path_segment("vault_name", &self.vault_name)?
+                ))?;
+                url.query_pairs_mut()
+                    .append_pair(azure_core::query_param::API_VERSION, "2021-04-01-preview");
+                azure_core::client::append_query_params(&mut url, &self.query_params);
+                Ok(url)
+            }
             #[doc = "Send the request and returns the response."]
-            pub fn send(self) -> futures::future::BoxFuture<'static, azure_core::Result<Response>> {
-                Box::pin({
-                    let this = self.clone();
-                    async move {
-                        let url = azure_core::Url::parse(&format!(
-                            "{}/subscriptions/{}/providers/Microsoft.KeyVault/locations/{}/deletedVaults/{}",
-                            this.client.endpoint(),
-                            &this.subscription_id,
-                            &this.location,
-                            &this.vault_name
-                        ))?;
-                        let mut req = azure_core::Request::new(url, azure_core::Method::Get);
-                        let credential = this.client.token_credential();
-                        let token_response = credential.get_token(&this.client.scopes().join(" ")).await?;
-                        req.insert_header(
-                            azure_core::headers::AUTHORIZATION,
-                            format!("Bearer {}", token_response.token.secret()),
-                        );
-                        req.url_mut()
-                            .query_pairs_mut()
-                            .append_pair(azure_core::query_param::API_VERSION, "2021-04-01-preview");
-                        let req_body = azure_core::EMPTY_BODY;
-                        req.set_body(req_body);
-                        Ok(Response(this.client.send(&mut req).await?))
-                    }
-                })
+            #[doc = ""]
+            #[doc = "The builder is not consumed, so a polling loop can build it once and call `send` on every iteration; each call fetches a fresh token. The returned future is `'static` because it owns a clone of the builder, so it can be spawned or stored."]
+            pub fn send(&self) -> futures::future::BoxFuture<'static, azure_core::Result<Response>> {
+                let this = self.clone();
+                Box::pin(async move { this.send_ref().await })
+            }
+            #[doc = "Send the request without consuming the builder."]
+            #[doc = ""]
+            #[doc = "Unlike `send`, which clones the builder so that its future is `'static`, the request is built from borrowed fields, so a builder kept across calls, such as in a polling loop, is sent again without being cloned. The future borrows the builder in turn."]
+            pub async fn send_ref(&self) -> azure_core::Result<Response> {
+                let url = self.url()?;
+                let mut req = azure_core::Request::new(url, azure_core::Method::Get);
+                let token_response = self.client.token(self.claims.as_deref()).await?;
+                req.insert_header(
+                    azure_core::headers::AUTHORIZATION,
+                    format!("Bearer {}", token_response.token.secret()),
+                );
+                if let Some(accept_language) = &self.accept_language {
+                    req.insert_header(azure_core::headers::ACCEPT_LANGUAGE, accept_language.clone());
+                }
+                let req_body = azure_core::EMPTY_BODY;
+                req.set_body(req_body);
+                Ok(Response(self.client.send(&mut req, "vaults::get_deleted").await?))
             }
             #[doc = "Send the request and return the response body."]
             pub fn into_future(self) -> futures::future::BoxFuture<'static, azure_core::Result<models::DeletedVault>> {
                 Box::pin(async move { self.send().await?.into_body().await })
             }
+            #[doc = "Send the request and return the response body together with the response headers, such as the `ETag`."]
+            pub fn into_future_with_headers(
+                self,
+            ) -> futures::future::BoxFuture<'static, azure_core::Result<(models::DeletedVault, azure_core::headers::Headers)>> {
+                Box::pin(async move {
+                    let rsp = self.send().await?;
+                    let headers = rsp.as_raw_response().headers().clone();
+                    Ok((rsp.into_body().await?, headers))
+                })
+            }
+            #[doc = "Send the request and return the response body, blocking the current thread."]
+            #[cfg(feature = "blocking")]
+            pub fn call(self) -> azure_core::Result<models::DeletedVault> {
+                azure_core::blocking::block_on(self.into_future())
+            }
         }
     }
     pub mod purge_deleted {
         use super::models;
         pub struct Response(azure_core::Response);
+        impl Response {
+            #[doc = "Stream the response body in chunks as they are received, instead of buffering all of it like `into_body`."]
+            pub fn into_byte_stream(self) -> azure_core::ResponseBody {
+                self.0.into_body()
+            }
+            pub fn status(&self) -> azure_core::StatusCode {
+                self.0.status()
+            }
+            pub fn into_raw_response(self) -> azure_core::Response {
+                self.0
+            }
+            #[doc = "Read the whole response and return its status, headers and body as received, for forwarding it verbatim without going through `models`."]
+            pub async fn into_raw_parts(self) -> azure_core::Result<(azure_core::StatusCode, azure_core::headers::Headers, bytes::Bytes)> {
+                let (status, headers, body) = self.0.deconstruct();
+                Ok((status, headers, body.collect().await?))
+            }
+            pub fn as_raw_response(&self) -> &azure_core::Response {
+                &self.0
+            }
+            #[doc = "The remaining request quota; see [responses](crate::package_preview_2021_04#responses)."]
+            pub fn rate_limit_remaining(&self) -> Option<u32> {
+                azure_core::client::rate_limit_remaining(self.0.headers())
+            }
+            #[doc = "The `x-ms-client-request-id` the request was sent with; see [responses](crate::package_preview_2021_04#responses)."]
+            pub fn client_request_id(&self) -> Option<&str> {
+                self.0.headers().get_optional_str(&azure_core::headers::CLIENT_REQUEST_ID)
+            }
+            #[doc = "How long the request took; see [responses](crate::package_preview_2021_04#responses)."]
+            pub fn elapsed(&self) -> std::time::Duration {
+                self.0.elapsed().unwrap_or_default()
+            }
+        }
+        impl From<Response> for azure_core::Response {
+            fn from(rsp: Response) -> Self {
+                rsp.into_raw_response()
+            }
+        }
+        impl AsRef<azure_core::Response> for Response {
+            fn as_ref(&self) -> &azure_core::Response {
+                self.as_raw_response()
+            }
+        }
         #[derive(Clone)]
         pub struct RequestBuilder {
             pub(crate) client: super::super::Client,
             pub(crate) vault_name: String,
             pub(crate) location: String,
             pub(crate) subscription_id: String,
+            pub(crate) claims: Option<String>,
+            pub(crate) accept_language: Option<String>,
+            pub(crate) idempotent: bool,
+            pub(crate) query_params: Vec<(String, String)>,
+        }
+        impl super::super::private::Sealed for RequestBuilder {}
+        impl super::super::Operation for RequestBuilder {
+            fn method() -> azure_core::Method {
+                azure_core::Method::Post
+            }
+            fn path_template() -> &'static str {
+                "/subscriptions/{subscriptionId}/providers/Microsoft.KeyVault/locations/{location}/deletedVaults/{vaultName}/purge"
+            }
         }
         impl RequestBuilder {
+            #[doc = "Ask the credential for a token that satisfies the JSON `claims`; see [claims](crate::package_preview_2021_04#claims)."]
+            pub fn claims(mut self, claims: impl Into<String>) -> Self {
+                self.claims = Some(claims.into());
+                self
+            }
+            #[doc = "Send this request with `accept_language` as its `Accept-Language` header, such as `fr-FR`, overriding `ClientBuilder::accept_language`."]
+            pub fn accept_language(mut self, accept_language: impl Into<String>) -> Self {
+                self.accept_language = Some(accept_language.into());
+                self
+            }
+            #[doc = "Mark the request as safe to repeat, so the retry policy retries it after a connection error."]
+            #[doc = ""]
+            #[doc = "This request is not idempotent by default, and repeating it may repeat its side effect, so it is not retried after a connection error or timed out attempt unless this is set."]
+            pub fn idempotent(mut self, idempotent: bool) -> Self {
+                self.idempotent = idempotent;
+                self
+            }
+            #[doc = "Append `key=value` to the query string; see [query parameters](crate::package_preview_2021_04#query-parameters)."]
+            pub fn query_param(mut self, key: impl Into<String>, value: impl Into<String>) -> Self {
+                self.query_params.push((key.into(), value.into()));
+                self
+            }
+            #[doc = "Build the URL the request is sent to, including the api-version and any query parameters set on the builder, without sending the request."]
+            pub fn url(&self) -> azure_core::Result<azure_core::Url> {
+                let mut url = azure_core::Url::parse(&format!(
+                    "{}/subscriptions/{}/providers/Microsoft.KeyVault/locations/{}/deletedVaults/{}/purge",
+                    self.client.endpoint(),
+                    &azure_core::client::path_segment("subscription_id", &self.subscription_id)?,
+                    &azure_core::client::path_segment("location", &self.location)?,
+                    &azure_core::client::path_segment("vault_name", &self.vault_name)?
+                ))?;
+                url.query_pairs_mut()
+                    .append_pair(azure_core::query_param::API_VERSION, "2021-04-01-preview");
+                azure_core::client::append_query_params(&mut url, &self.query_params);
+                Ok(url)
+            }
             #[doc = "only the first response will be fetched as long running operations are not supported yet"]
             #[doc = "Send the request and returns the response."]
-            pub fn send(self) -> futures::future::BoxFuture<'static, azure_core::Result<Response>> {
-                Box::pin({
-                    let this = self.clone();
-                    async move {
-                        let url = azure_core::Url::parse(&format!(
-                            "{}/subscriptions/{}/providers/Microsoft.KeyVault/locations/{}/deletedVaults/{}/purge",
-                            this.client.endpoint(),
-                            &this.subscription_id,
-                            &this.location,
-                            &this.vault_name
-                        ))?;
-                        let mut req = azure_core::Request::new(url, azure_core::Method::Post);
-                        let credential = this.client.token_credential();
-                        let token_response = credential.get_token(&this.client.scopes().join(" ")).await?;
-                        req.insert_header(
-                            azure_core::headers::AUTHORIZATION,
-                            format!("Bearer {}", token_response.token.secret()),
-                        );
-                        req.url_mut()
-                            .query_pairs_mut()
-                            .append_pair(azure_core::query_param::API_VERSION, "2021-04-01-preview");
-                        let req_body = azure_core::EMPTY_BODY;
-                        req.insert_header(azure_core::headers::CONTENT_LENGTH, "0");
-                        req.set_body(req_body);
-                        Ok(Response(this.client.send(&mut req).await?))
-                    }
-                })
+            #[doc = ""]
+            #[doc = "The builder is not consumed, so a polling loop can build it once and call `send` on every iteration; each call fetches a fresh token. The returned future is `'static` because it owns a clone of the builder, so it can be spawned or stored."]
+            pub fn send(&self) -> futures::future::BoxFuture<'static, azure_core::Result<Response>> {
+                let this = self.clone();
+                Box::pin(async move { this.send_ref().await })
+            }
+            #[doc = "Send the request without consuming the builder."]
+            #[doc = ""]
+            #[doc = "Unlike `send`, which clones the builder so that its future is `'static`, the request is built from borrowed fields, so a builder kept across calls, such as in a polling loop, is sent again without being cloned. The future borrows the builder in turn."]
+            pub async fn send_ref(&self) -> azure_core::Result<Response> {
+                let url = self.url()?;
+                let mut req = azure_core::Request::new(url, azure_core::Method::Post);
+                let token_response = self.client.token(self.claims.as_deref()).await?;
+                req.insert_header(
+                    azure_core::headers::AUTHORIZATION,
+                    format!("Bearer {}", token_response.token.secret()),
+                );
+                if let Some(accept_language) = &self.accept_language {
+                    req.insert_header(azure_core::headers::ACCEPT_LANGUAGE, accept_language.clone());
+                }
+                let req_body = azure_core::EMPTY_BODY;
+                req.insert_header(azure_core::headers::CONTENT_LENGTH, "0");
+                req.set_body(req_body);
+                let mut context = azure_core::Context::new();
+                if self.idempotent {
+                    context.insert(azure_core::Idempotent);
+                }
+                Ok(Response(
+                    self.client.send_with_context(&mut req, "vaults::purge_deleted", context).await?,
+                ))
+            }
+            #[doc = "Send the request and return the response, blocking the current thread."]
+            #[cfg(feature = "blocking")]
+            pub fn call(self) -> azure_core::Result<Response> {
+                azure_core::blocking::block_on(self.send())
             }
         }
     }
@@ -1079,16 +2459,52 @@ pub mod vaults {
         pub struct Response(azure_core::Response);
         impl Response {
             pub async fn into_body(self) -> azure_core::Result<models::ResourceListResult> {
-                let bytes = self.0.into_body().collect().await?;
-                let body: models::ResourceListResult = serde_json::from_slice(&bytes)?;
+                let status = self.0.status();
+                let bytes = self.into_byte_stream().collect().await?;
+                let body: models::ResourceListResult = azure_core::client::from_json_body("vaults::list", status, &bytes)?;
+                Ok(body)
+            }
+            #[doc = "Stream the items of the page as they are received, instead of buffering the whole body like `into_body`. Everything but the `value` array, including the link to the next page, is skipped."]
+            pub fn into_body_streaming(self) -> futures::stream::BoxStream<'static, azure_core::Result<models::Resource>> {
+                self.into_byte_stream().json_array_items("value")
+            }
+            #[doc = "Return the response body as untyped JSON; see [responses](crate::package_preview_2021_04#responses)."]
+            pub async fn into_value(self) -> azure_core::Result<serde_json::Value> {
+                let status = self.0.status();
+                let bytes = self.into_byte_stream().collect().await?;
+                let body: serde_json::Value = azure_core::client::from_json_body("vaults::list", status, &bytes)?;
                 Ok(body)
             }
+            #[doc = "Stream the response body in chunks as they are received, instead of buffering all of it like `into_body`."]
+            pub fn into_byte_stream(self) -> azure_core::ResponseBody {
+                self.0.into_body()
+            }
+            pub fn status(&self) -> azure_core::StatusCode {
+                self.0.status()
+            }
             pub fn into_raw_response(self) -> azure_core::Response {
                 self.0
             }
+            #[doc = "Read the whole response and return its status, headers and body as received, for forwarding it verbatim without going through `models`."]
+            pub async fn into_raw_parts(self) -> azure_core::Result<(azure_core::StatusCode, azure_core::headers::Headers, bytes::Bytes)> {
+                let (status, headers, body) = self.0.deconstruct();
+                Ok((status, headers, body.collect().await?))
+            }
             pub fn as_raw_response(&self) -> &azure_core::Response {
                 &self.0
             }
+            #[doc = "The remaining request quota; see [responses](crate::package_preview_2021_04#responses)."]
+            pub fn rate_limit_remaining(&self) -> Option<u32> {
+                azure_core::client::rate_limit_remaining(self.0.headers())
+            }
+            #[doc = "The `x-ms-client-request-id` the request was sent with; see [responses](crate::package_preview_2021_04#responses)."]
+            pub fn client_request_id(&self) -> Option<&str> {
+                self.0.headers().get_optional_str(&azure_core::headers::CLIENT_REQUEST_ID)
+            }
+            #[doc = "How long the request took; see [responses](crate::package_preview_2021_04#responses)."]
+            pub fn elapsed(&self) -> std::time::Duration {
+                self.0.elapsed().unwrap_or_default()
+            }
         }
         impl From<Response> for azure_core::Response {
             fn from(rsp: Response) -> Self {
@@ -1106,76 +2522,198 @@ pub mod vaults {
             pub(crate) filter: String,
             pub(crate) subscription_id: String,
             pub(crate) top: Option<i32>,
+            pub(crate) claims: Option<String>,
+            pub(crate) accept_language: Option<String>,
+            pub(crate) query_params: Vec<(String, String)>,
+            pub(crate) select: Option<String>,
+            pub(crate) max_items: Option<usize>,
+            pub(crate) on_missing_empty: bool,
+            pub(crate) resume_from: Option<String>,
+        }
+        impl super::super::private::Sealed for RequestBuilder {}
+        impl super::super::Operation for RequestBuilder {
+            fn method() -> azure_core::Method {
+                azure_core::Method::Get
+            }
+            fn path_template() -> &'static str {
+                "/subscriptions/{subscriptionId}/resources"
+            }
+        }
+        impl super::super::BatchRead for RequestBuilder {
+            fn batch_url(&self) -> azure_core::Result<azure_core::Url> {
+                self.url()
+            }
         }
         impl RequestBuilder {
+            #[doc = "Ask the credential for a token that satisfies the JSON `claims`; see [claims](crate::package_preview_2021_04#claims)."]
+            pub fn claims(mut self, claims: impl Into<String>) -> Self {
+                self.claims = Some(claims.into());
+                self
+            }
+            #[doc = "Send this request with `accept_language` as its `Accept-Language` header, such as `fr-FR`, overriding `ClientBuilder::accept_language`."]
+            pub fn accept_language(mut self, accept_language: impl Into<String>) -> Self {
+                self.accept_language = Some(accept_language.into());
+                self
+            }
             #[doc = "Maximum number of results to return."]
+            #[doc = ""]
+            #[doc = "This is the page size. The service may cap it, so a page can hold fewer items than requested."]
             pub fn top(mut self, top: i32) -> Self {
                 self.top = Some(top);
                 self
             }
+            #[doc = "Append `key=value` to the query string; see [query parameters](crate::package_preview_2021_04#query-parameters)."]
+            pub fn query_param(mut self, key: impl Into<String>, value: impl Into<String>) -> Self {
+                self.query_params.push((key.into(), value.into()));
+                self
+            }
+            #[doc = "Only return the given properties of each item, such as `[\"id\", \"name\"]`, to reduce the work done by the service and the size of each page."]
+            #[doc = ""]
+            #[doc = "Fields of the returned models that were not selected are `None`."]
+            pub fn select(mut self, select: &[&str]) -> Self {
+                self.select = Some(select.join(","));
+                self
+            }
+            #[doc = "Limit the total number of items yielded by `into_item_stream`. No further pages are requested once the limit is reached."]
+            pub fn max_items(mut self, max_items: usize) -> Self {
+                self.max_items = Some(max_items);
+                self
+            }
+            #[doc = "Treat `404 Not Found` on the first page as an empty list instead of an error."]
+            #[doc = ""]
+            #[doc = "Useful when the parent resource may not exist yet, such as in a reconciliation loop racing its provisioning. The stream then yields a single empty page. Off by default, and a `404` on a later page is always an error."]
+            pub fn on_missing_empty(mut self, on_missing_empty: bool) -> Self {
+                self.on_missing_empty = on_missing_empty;
+                self
+            }
+            #[doc = "Start paging from `continuation`, a token saved from `into_checkpoint_stream` or `page`, instead of from the first page."]
+            #[doc = ""]
+            #[doc = "Lets a long listing be checkpointed and picked up where it stopped, such as after a restart."]
+            pub fn resume_from(mut self, continuation: impl Into<String>) -> Self {
+                self.resume_from = Some(continuation.into());
+                self
+            }
+            #[doc = "Build the URL the request is sent to, including the api-version and any query parameters set on the builder, without sending the request."]
+            pub fn url(&self) -> azure_core::Result<azure_core::Url> {
+                let mut url = azure_core::Url::parse(&format!(
+                    "{}/subscriptions/{}/resources",
+                    self.client.endpoint(),
+                    &azure_core::client::path_segment("subscription_id", &self.subscription_id)?
+                ))?;
+                url.query_pairs_mut()
+                    .append_pair(azure_core::query_param::API_VERSION, "2021-04-01-preview");
+                let filter = &self.filter;
+                url.query_pairs_mut().append_pair("$filter", filter);
+                if let Some(top) = &self.top {
+                    url.query_pairs_mut().append_pair("$top", &top.to_string());
+                }
+                if let Some(select) = &self.select {
+                    url.query_pairs_mut().append_pair("$select", select);
+                }
+                azure_core::client::append_query_params(&mut url, &self.query_params);
+                Ok(url)
+            }
+            #[doc = "Stream the items of every page, stopping early if `max_items` is set."]
+            pub fn into_item_stream(self) -> futures::stream::BoxStream<'static, azure_core::Result<models::Resource>> {
+                let max_items = self.max_items;
+                azure_core::client::item_stream(self.into_stream(), max_items, |page| page.value)
+            }
+            #[doc = "Stream the items of each page together with the continuation token that resumes after it, or `None` after the last page."]
+            #[doc = ""]
+            #[doc = "Save the token once a page has been processed and pass it to `resume_from` to carry on from there later. `max_items` is not applied."]
+            pub fn into_checkpoint_stream(
+                self,
+            ) -> futures::stream::BoxStream<'static, azure_core::Result<(Vec<models::Resource>, Option<String>)>> {
+                azure_core::client::checkpoint_stream(self.into_stream(), |page| page.value)
+            }
             pub fn into_stream(self) -> azure_core::Pageable<models::ResourceListResult, azure_core::error::Error> {
                 let make_request = move |continuation: Option<String>| {
-                    let this = self.clone();
-                    async move {
-                        let mut url = azure_core::Url::parse(&format!(
-                            "{}/subscriptions/{}/resources",
-                            this.client.endpoint(),
-                            &this.subscription_id
-                        ))?;
-                        let rsp = match continuation {
-                            Some(value) => {
-                                url.set_path("");
-                                url = url.join(&value)?;
-                                let mut req = azure_core::Request::new(url, azure_core::Method::Get);
-                                let credential = this.client.token_credential();
-                                let token_response = credential.get_token(&this.client.scopes().join(" ")).await?;
-                                req.insert_header(
-                                    azure_core::headers::AUTHORIZATION,
-                                    format!("Bearer {}", token_response.token.secret()),
-                                );
-                                let has_api_version_already =
-                                    req.url_mut().query_pairs().any(|(k, _)| k == azure_core::query_param::API_VERSION);
-                                if !has_api_version_already {
-                                    req.url_mut()
-                                        .query_pairs_mut()
-                                        .append_pair(azure_core::query_param::API_VERSION, "2021-04-01-preview");
-                                }
-                                let req_body = azure_core::EMPTY_BODY;
-                                req.set_body(req_body);
-                                this.client.send(&mut req).await?
+                    let continuation = continuation.or_else(|| self.resume_from.clone());
+                    self.clone().fetch_page(continuation)
+                };
+                azure_core::Pageable::new(make_request)
+            }
+            #[doc = "Fetch a single page and return its body together with the continuation token for the next page, if any."]
+            #[doc = ""]
+            #[doc = "Pass `None` to fetch the first page, or a token returned by a previous call to fetch the page that follows it."]
+            pub fn page(
+                &self,
+                continuation: Option<String>,
+            ) -> futures::future::BoxFuture<'static, azure_core::Result<(models::ResourceListResult, Option<String>)>> {
+                let this = self.clone();
+                Box::pin(async move {
+                    let body = this.fetch_page(continuation).await?;
+                    let continuation = azure_core::Continuable::continuation(&body);
+                    Ok((body, continuation))
+                })
+            }
+            fn fetch_page(
+                self,
+                continuation: Option<String>,
+            ) -> futures::future::BoxFuture<'static, azure_core::Result<models::ResourceListResult>> {
+                Box::pin(async move {
+                    let mut url = self.url()?;
+                    let rsp = match continuation {
+                        Some(value) => {
+                            let first_page_url = url;
+                            url = azure_core::next_page_url(&first_page_url, &value)?;
+                            azure_core::client::carry_query_params(&mut url, &first_page_url, &self.query_params);
+                            let mut req = azure_core::Request::new(url, azure_core::Method::Get);
+                            let token_response = self.client.token(self.claims.as_deref()).await?;
+                            req.insert_header(
+                                azure_core::headers::AUTHORIZATION,
+                                format!("Bearer {}", token_response.token.secret()),
+                            );
+                            if let Some(accept_language) = &self.accept_language {
+                                req.insert_header(azure_core::headers::ACCEPT_LANGUAGE, accept_language.clone());
+                            }
+                            if let Some(token) = azure_core::continuation_header(&value) {
+                                req.insert_header(azure_core::headers::CONTINUATION, token.to_owned());
+                            }
+                            let req_body = azure_core::EMPTY_BODY;
+                            req.set_body(req_body);
+                            self.client.send(&mut req, "vaults::list").await?
+                        }
+                        None => {
+                            let mut req = azure_core::Request::new(url, azure_core::Method::Get);
+                            let token_response = self.client.token(self.claims.as_deref()).await?;
+                            req.insert_header(
+                                azure_core::headers::AUTHORIZATION,
+                                format!("Bearer {}", token_response.token.secret()),
+                            );
+                            if let Some(accept_language) = &self.accept_language {
+                                req.insert_header(azure_core::headers::ACCEPT_LANGUAGE, accept_language.clone());
                             }
-                            None => {
-                                let mut req = azure_core::Request::new(url, azure_core::Method::Get);
-                                let credential = this.client.token_credential();
-                                let token_response = credential.get_token(&this.client.scopes().join(" ")).await?;
-                                req.insert_header(
-                                    azure_core::headers::AUTHORIZATION,
-                                    format!("Bearer {}", token_response.token.secret()),
-                                );
-                                req.url_mut()
-                                    .query_pairs_mut()
-                                    .append_pair(azure_core::query_param::API_VERSION, "2021-04-01-preview");
-                                let filter = &this.filter;
-                                req.url_mut().query_pairs_mut().append_pair("$filter", filter);
-                                if let Some(top) = &this.top {
-                                    req.url_mut().query_pairs_mut().append_pair("$top", &top.to_string());
+                            let req_body = azure_core::EMPTY_BODY;
+                            req.set_body(req_body);
+                            let rsp = self.client.send(&mut req, "vaults::list").await;
+                            if self.on_missing_empty {
+                                match azure_core::client::not_found_as_none(rsp)? {
+                                    Some(rsp) => rsp,
+                                    None => return Ok(Default::default()),
                                 }
-                                let req_body = azure_core::EMPTY_BODY;
-                                req.set_body(req_body);
-                                this.client.send(&mut req).await?
+                            } else {
+                                rsp?
                             }
-                        };
-                        let rsp = match rsp.status() {
-                            azure_core::StatusCode::Ok => Ok(Response(rsp)),
-                            status_code => Err(azure_core::error::Error::from(azure_core::error::ErrorKind::HttpResponse {
-                                status: status_code,
-                                error_code: None,
-                            })),
-                        };
-                        rsp?.into_body().await
+                        }
+                    };
+                    let rsp = match rsp.status() {
+                        azure_core::StatusCode::Ok => Ok(Response(rsp)),
+                        _ => Err(azure_core::client::unexpected_status(&rsp)),
+                    };
+                    let rsp = rsp?;
+                    let continuation = azure_core::continuation_from_headers(rsp.as_raw_response().headers());
+                    let mut body = rsp.into_body().await?;
+                    if body.next_link.is_none() {
+                        body.next_link = continuation;
                     }
-                };
-                azure_core::Pageable::new(make_request)
+                    Ok(body)
+                })
+            }
+            #[doc = "Fetch every page and return all items, blocking the current thread. Stops early if `max_items` is set."]
+            #[cfg(feature = "blocking")]
+            pub fn call(self) -> azure_core::Result<Vec<models::Resource>> {
+                azure_core::blocking::block_on(futures::TryStreamExt::try_collect(self.into_item_stream()))
             }
         }
     }
@@ -1184,16 +2722,49 @@ pub mod vaults {
         pub struct Response(azure_core::Response);
         impl Response {
             pub async fn into_body(self) -> azure_core::Result<models::CheckNameAvailabilityResult> {
-                let bytes = self.0.into_body().collect().await?;
-                let body: models::CheckNameAvailabilityResult = serde_json::from_slice(&bytes)?;
+                let status = self.0.status();
+                let bytes = self.into_byte_stream().collect().await?;
+                let body: models::CheckNameAvailabilityResult =
+                    azure_core::client::from_json_body("vaults::check_name_availability", status, &bytes)?;
                 Ok(body)
             }
+            #[doc = "Return the response body as untyped JSON; see [responses](crate::package_preview_2021_04#responses)."]
+            pub async fn into_value(self) -> azure_core::Result<serde_json::Value> {
+                let status = self.0.status();
+                let bytes = self.into_byte_stream().collect().await?;
+                let body: serde_json::Value = azure_core::client::from_json_body("vaults::check_name_availability", status, &bytes)?;
+                Ok(body)
+            }
+            #[doc = "Stream the response body in chunks as they are received, instead of buffering all of it like `into_body`."]
+            pub fn into_byte_stream(self) -> azure_core::ResponseBody {
+                self.0.into_body()
+            }
+            pub fn status(&self) -> azure_core::StatusCode {
+                self.0.status()
+            }
             pub fn into_raw_response(self) -> azure_core::Response {
                 self.0
             }
+            #[doc = "Read the whole response and return its status, headers and body as received, for forwarding it verbatim without going through `models`."]
+            pub async fn into_raw_parts(self) -> azure_core::Result<(azure_core::StatusCode, azure_core::headers::Headers, bytes::Bytes)> {
+                let (status, headers, body) = self.0.deconstruct();
+                Ok((status, headers, body.collect().await?))
+            }
             pub fn as_raw_response(&self) -> &azure_core::Response {
                 &self.0
             }
+            #[doc = "The remaining request quota; see [responses](crate::package_preview_2021_04#responses)."]
+            pub fn rate_limit_remaining(&self) -> Option<u32> {
+                azure_core::client::rate_limit_remaining(self.0.headers())
+            }
+            #[doc = "The `x-ms-client-request-id` the request was sent with; see [responses](crate::package_preview_2021_04#responses)."]
+            pub fn client_request_id(&self) -> Option<&str> {
+                self.0.headers().get_optional_str(&azure_core::headers::CLIENT_REQUEST_ID)
+            }
+            #[doc = "How long the request took; see [responses](crate::package_preview_2021_04#responses)."]
+            pub fn elapsed(&self) -> std::time::Duration {
+                self.0.elapsed().unwrap_or_default()
+            }
         }
         impl From<Response> for azure_core::Response {
             fn from(rsp: Response) -> Self {
@@ -1210,39 +2781,129 @@ pub mod vaults {
             pub(crate) client: super::super::Client,
             pub(crate) vault_name: models::VaultCheckNameAvailabilityParameters,
             pub(crate) subscription_id: String,
+            pub(crate) claims: Option<String>,
+            pub(crate) accept_language: Option<String>,
+            pub(crate) idempotent: bool,
+            pub(crate) query_params: Vec<(String, String)>,
+            pub(crate) raw_body: Option<(bytes::Bytes, String)>,
+        }
+        impl super::super::private::Sealed for RequestBuilder {}
+        impl super::super::Operation for RequestBuilder {
+            fn method() -> azure_core::Method {
+                azure_core::Method::Post
+            }
+            fn path_template() -> &'static str {
+                "/subscriptions/{subscriptionId}/providers/Microsoft.KeyVault/checkNameAvailability"
+            }
         }
         impl RequestBuilder {
+            #[doc = "Ask the credential for a token that satisfies the JSON `claims`; see [claims](crate::package_preview_2021_04#claims)."]
+            pub fn claims(mut self, claims: impl Into<String>) -> Self {
+                self.claims = Some(claims.into());
+                self
+            }
+            #[doc = "Send this request with `accept_language` as its `Accept-Language` header, such as `fr-FR`, overriding `ClientBuilder::accept_language`."]
+            pub fn accept_language(mut self, accept_language: impl Into<String>) -> Self {
+                self.accept_language = Some(accept_language.into());
+                self
+            }
+            #[doc = "Mark the request as safe to repeat, so the retry policy retries it after a connection error."]
+            #[doc = ""]
+            #[doc = "This request is not idempotent by default, and repeating it may repeat its side effect, so it is not retried after a connection error or timed out attempt unless this is set."]
+            pub fn idempotent(mut self, idempotent: bool) -> Self {
+                self.idempotent = idempotent;
+                self
+            }
+            #[doc = "Append `key=value` to the query string; see [query parameters](crate::package_preview_2021_04#query-parameters)."]
+            pub fn query_param(mut self, key: impl Into<String>, value: impl Into<String>) -> Self {
+                self.query_params.push((key.into(), value.into()));
+                self
+            }
+            #[doc = "Send `body` as the JSON request body instead of the typed parameters, for fields the `models` type does not have yet."]
+            #[doc = ""]
+            #[doc = "Shorthand for `raw_body` with `content-type: application/json`; whichever of the two is called last wins."]
+            pub fn raw_json_body(self, body: serde_json::Value) -> Self {
+                self.raw_body(body.to_string(), "application/json")
+            }
+            #[doc = "Send `body` with the given `content-type` instead of the typed parameters."]
+            #[doc = ""]
+            #[doc = "The typed request body is not sent at all, so this is a stopgap for service changes the `models` types do not cover yet."]
+            pub fn raw_body(mut self, body: impl Into<bytes::Bytes>, content_type: impl Into<String>) -> Self {
+                self.raw_body = Some((body.into(), content_type.into()));
+                self
+            }
+            #[doc = "Build the URL the request is sent to, including the api-version and any query parameters set on the builder, without sending the request."]
+            pub fn url(&self) -> azure_core::Result<azure_core::Url> {
+                let mut url = azure_core::Url::parse(&format!(
+                    "{}/subscriptions/{}/providers/Microsoft.KeyVault/checkNameAvailability",
+                    self.client.endpoint(),
+                    &azure_core::client::path_segment("subscription_id", &self.subscription_id)?
+                ))?;
+                url.query_pairs_mut()
+                    .append_pair(azure_core::query_param::API_VERSION, "2021-04-01-preview");
+                azure_core::client::append_query_params(&mut url, &self.query_params);
+                Ok(url)
+            }
             #[doc = "Send the request and returns the response."]
-            pub fn send(self) -> futures::future::BoxFuture<'static, azure_core::Result<Response>> {
-                Box::pin({
-                    let this = self.clone();
-                    async move {
-                        let url = azure_core::Url::parse(&format!(
-                            "{}/subscriptions/{}/providers/Microsoft.KeyVault/checkNameAvailability",
-                            this.client.endpoint(),
-                            &this.subscription_id
-                        ))?;
-                        let mut req = azure_core::Request::new(url, azure_core::Method::Post);
-                        let credential = this.client.token_credential();
-                        let token_response = credential.get_token(&this.client.scopes().join(" ")).await?;
-                        req.insert_header(
-                            azure_core::headers::AUTHORIZATION,
-                            format!("Bearer {}", token_response.token.secret()),
-                        );
-                        req.url_mut()
-                            .query_pairs_mut()
-                            .append_pair(azure_core::query_param::API_VERSION, "2021-04-01-preview");
-                        req.insert_header("content-type", "application/json");
-                        let req_body = azure_core::to_json(&this.vault_name)?;
-                        req.set_body(req_body);
-                        Ok(Response(this.client.send(&mut req).await?))
+            #[doc = ""]
+            #[doc = "The builder is not consumed, so a polling loop can build it once and call `send` on every iteration; each call fetches a fresh token. The returned future is `'static` because it owns a clone of the builder, so it can be spawned or stored."]
+            pub fn send(&self) -> futures::future::BoxFuture<'static, azure_core::Result<Response>> {
+                let this = self.clone();
+                Box::pin(async move { this.send_ref().await })
+            }
+            #[doc = "Send the request without consuming the builder."]
+            #[doc = ""]
+            #[doc = "Unlike `send`, which clones the builder so that its future is `'static`, the request is built from borrowed fields, so a builder kept across calls, such as in a polling loop, is sent again without being cloned. The future borrows the builder in turn."]
+            pub async fn send_ref(&self) -> azure_core::Result<Response> {
+                let url = self.url()?;
+                let mut req = azure_core::Request::new(url, azure_core::Method::Post);
+                let token_response = self.client.token(self.claims.as_deref()).await?;
+                req.insert_header(
+                    azure_core::headers::AUTHORIZATION,
+                    format!("Bearer {}", token_response.token.secret()),
+                );
+                if let Some(accept_language) = &self.accept_language {
+                    req.insert_header(azure_core::headers::ACCEPT_LANGUAGE, accept_language.clone());
+                }
+                req.insert_header("content-type", "application/json");
+                let req_body = match &self.raw_body {
+                    Some((body, content_type)) => {
+                        req.insert_header("content-type", content_type.clone());
+                        body.clone()
                     }
-                })
+                    None => azure_core::to_json(&self.vault_name)?,
+                };
+                req.set_body(req_body);
+                let mut context = azure_core::Context::new();
+                if self.idempotent {
+                    context.insert(azure_core::Idempotent);
+                }
+                Ok(Response(
+                    self.client
+                        .send_with_context(&mut req, "vaults::check_name_availability", context)
+                        .await?,
+                ))
             }
             #[doc = "Send the request and return the response body."]
             pub fn into_future(self) -> futures::future::BoxFuture<'static, azure_core::Result<models::CheckNameAvailabilityResult>> {
                 Box::pin(async move { self.send().await?.into_body().await })
             }
+            #[doc = "Send the request and return the response body together with the response headers, such as the `ETag`."]
+            pub fn into_future_with_headers(
+                self,
+            ) -> futures::future::BoxFuture<'static, azure_core::Result<(models::CheckNameAvailabilityResult, azure_core::headers::Headers)>>
+            {
+                Box::pin(async move {
+                    let rsp = self.send().await?;
+                    let headers = rsp.as_raw_response().headers().clone();
+                    Ok((rsp.into_body().await?, headers))
+                })
+            }
+            #[doc = "Send the request and return the response body, blocking the current thread."]
+            #[cfg(feature = "blocking")]
+            pub fn call(self) -> azure_core::Result<models::CheckNameAvailabilityResult> {
+                azure_core::blocking::block_on(self.into_future())
+            }
         }
     }
 }
@@ -1270,6 +2931,10 @@ pub mod private_endpoint_connections {
                 resource_group_name: resource_group_name.into(),
                 vault_name: vault_name.into(),
                 private_endpoint_connection_name: private_endpoint_connection_name.into(),
+                claims: None,
+                accept_language: None,
+                if_none_match: None,
+                query_params: Vec::new(),
             }
         }
         #[doc = "Updates the specified private endpoint connection associated with the key vault."]
@@ -1295,6 +2960,10 @@ pub mod private_endpoint_connections {
                 vault_name: vault_name.into(),
                 private_endpoint_connection_name: private_endpoint_connection_name.into(),
                 properties: properties.into(),
+                claims: None,
+                accept_language: None,
+                query_params: Vec::new(),
+                raw_body: None,
             }
         }
         #[doc = "Deletes the specified private endpoint connection associated with the key vault."]
@@ -1317,6 +2986,9 @@ pub mod private_endpoint_connections {
                 resource_group_name: resource_group_name.into(),
                 vault_name: vault_name.into(),
                 private_endpoint_connection_name: private_endpoint_connection_name.into(),
+                claims: None,
+                accept_language: None,
+                query_params: Vec::new(),
             }
         }
         #[doc = "The List operation gets information about the private endpoint connections associated with the vault."]
@@ -1336,6 +3008,13 @@ pub mod private_endpoint_connections {
                 subscription_id: subscription_id.into(),
                 resource_group_name: resource_group_name.into(),
                 vault_name: vault_name.into(),
+                claims: None,
+                accept_language: None,
+                query_params: Vec::new(),
+                select: None,
+                max_items: None,
+                on_missing_empty: false,
+                resume_from: None,
             }
         }
     }
@@ -1344,16 +3023,69 @@ pub mod private_endpoint_connections {
         pub struct Response(azure_core::Response);
         impl Response {
             pub async fn into_body(self) -> azure_core::Result<models::PrivateEndpointConnection> {
-                let bytes = self.0.into_body().collect().await?;
-                let body: models::PrivateEndpointConnection = serde_json::from_slice(&bytes)?;
+                if self.is_not_modified() {
+                    return Err(azure_core::error::ErrorKind::HttpResponse {
+                        status: azure_core::StatusCode::NotModified,
+                        error_code: None,
+                    }
+                    .into_error());
+                }
+                let status = self.0.status();
+                let bytes = self.into_byte_stream().collect().await?;
+                let body: models::PrivateEndpointConnection =
+                    azure_core::client::from_json_body("private_endpoint_connections::get", status, &bytes)?;
+                Ok(body)
+            }
+            #[doc = "Return the response body as untyped JSON; see [responses](crate::package_preview_2021_04#responses)."]
+            pub async fn into_value(self) -> azure_core::Result<serde_json::Value> {
+                if self.is_not_modified() {
+                    return Err(azure_core::error::ErrorKind::HttpResponse {
+                        status: azure_core::StatusCode::NotModified,
+                        error_code: None,
+                    }
+                    .into_error());
+                }
+                let status = self.0.status();
+                let bytes = self.into_byte_stream().collect().await?;
+                let body: serde_json::Value = azure_core::client::from_json_body("private_endpoint_connections::get", status, &bytes)?;
                 Ok(body)
             }
+            #[doc = "Whether the service answered a request sent with `if_none_match` with `304 Not Modified`. Such a response has no body, but carries the headers the service sent, so `concurrency_token`, `client_request_id` and `elapsed` still report them."]
+            #[doc = ""]
+            #[doc = "Derived from the status code alone, so polling loops can count unchanged responses against transferred bodies to track their cache hit rate."]
+            pub fn is_not_modified(&self) -> bool {
+                self.0.status() == azure_core::StatusCode::NotModified
+            }
+            #[doc = "Stream the response body in chunks as they are received, instead of buffering all of it like `into_body`."]
+            pub fn into_byte_stream(self) -> azure_core::ResponseBody {
+                self.0.into_body()
+            }
+            pub fn status(&self) -> azure_core::StatusCode {
+                self.0.status()
+            }
             pub fn into_raw_response(self) -> azure_core::Response {
                 self.0
             }
+            #[doc = "Read the whole response and return its status, headers and body as received, for forwarding it verbatim without going through `models`."]
+            pub async fn into_raw_parts(self) -> azure_core::Result<(azure_core::StatusCode, azure_core::headers::Headers, bytes::Bytes)> {
+                let (status, headers, body) = self.0.deconstruct();
+                Ok((status, headers, body.collect().await?))
+            }
             pub fn as_raw_response(&self) -> &azure_core::Response {
                 &self.0
             }
+            #[doc = "The remaining request quota; see [responses](crate::package_preview_2021_04#responses)."]
+            pub fn rate_limit_remaining(&self) -> Option<u32> {
+                azure_core::client::rate_limit_remaining(self.0.headers())
+            }
+            #[doc = "The `x-ms-client-request-id` the request was sent with; see [responses](crate::package_preview_2021_04#responses)."]
+            pub fn client_request_id(&self) -> Option<&str> {
+                self.0.headers().get_optional_str(&azure_core::headers::CLIENT_REQUEST_ID)
+            }
+            #[doc = "How long the request took; see [responses](crate::package_preview_2021_04#responses)."]
+            pub fn elapsed(&self) -> std::time::Duration {
+                self.0.elapsed().unwrap_or_default()
+            }
         }
         impl From<Response> for azure_core::Response {
             fn from(rsp: Response) -> Self {
@@ -1372,41 +3104,120 @@ pub mod private_endpoint_connections {
             pub(crate) resource_group_name: String,
             pub(crate) vault_name: String,
             pub(crate) private_endpoint_connection_name: String,
+            pub(crate) claims: Option<String>,
+            pub(crate) accept_language: Option<String>,
+            pub(crate) if_none_match: Option<String>,
+            pub(crate) query_params: Vec<(String, String)>,
+        }
+        impl super::super::private::Sealed for RequestBuilder {}
+        impl super::super::Operation for RequestBuilder {
+            fn method() -> azure_core::Method {
+                azure_core::Method::Get
+            }
+            fn path_template() -> &'static str {
+                "/subscriptions/{subscriptionId}/resourceGroups/{resourceGroupName}/providers/Microsoft.KeyVault/vaults/{vaultName}/privateEndpointConnections/{privateEndpointConnectionName}"
+            }
+        }
+        impl super::super::BatchRead for RequestBuilder {
+            fn batch_url(&self) -> azure_core::Result<azure_core::Url> {
+                self.url()
+            }
         }
         impl RequestBuilder {
+            #[doc = "Ask the credential for a token that satisfies the JSON `claims`; see [claims](crate::package_preview_2021_04#claims)."]
+            pub fn claims(mut self, claims: impl Into<String>) -> Self {
+                self.claims = Some(claims.into());
+                self
+            }
+            #[doc = "Send this request with `accept_language` as its `Accept-Language` header, such as `fr-FR`, overriding `ClientBuilder::accept_language`."]
+            pub fn accept_language(mut self, accept_language: impl Into<String>) -> Self {
+                self.accept_language = Some(accept_language.into());
+                self
+            }
+            #[doc = "Only return the resource if its ETag does not match `if_none_match`. Otherwise the response has status `304 Not Modified` and no body."]
+            pub fn if_none_match(mut self, if_none_match: impl Into<String>) -> Self {
+                self.if_none_match = Some(if_none_match.into());
+                self
+            }
+            #[doc = "Append `key=value` to the query string; see [query parameters](crate::package_preview_2021_04#query-parameters)."]
+            pub fn query_param(mut self, key: impl Into<String>, value: impl Into<String>) -> Self {
+                self.query_params.push((key.into(), value.into()));
+                self
+            }
+            #[doc = "Build the URL the request is sent to, including the api-version and any query parameters set on the builder, without sending the request."]
+            pub fn url(&self) -> azure_core::Result<azure_core::Url> {
+                let mut url = azure_core::Url::parse(&format!(
+                    "{}/subscriptions/{}/resourceGroups/{}/providers/Microsoft.KeyVault/vaults/{}/privateEndpointConnections/{}",
+                    self.client.endpoint(),
+                    &azure_core::client::path_segment("subscription_id", &self.subscription_id)?,
+                    &azure_core::client::path_segment("resource_group_name", &self.resource_group_name)?,
+                    &azure_core::client::path_segment("vault_name", &self.vault_name)?,
+                    &azure_core::client::path_segment("private_endpoint_connection_name", &self.private_endpoint_connection_name)?
+                ))?;
+                url.query_pairs_mut()
+                    .append_pair(azure_core::query_param::API_VERSION, "2021-04-01-preview");
+                azure_core::client::append_query_params(&mut url, &self.query_params);
+                Ok(url)
+            }
             #[doc = "Send the request and returns the response."]
-            pub fn send(self) -> futures::future::BoxFuture<'static, azure_core::Result<Response>> {
-                Box::pin({
-                    let this = self.clone();
-                    async move {
-                        let url = azure_core::Url::parse(&format!(
-                            "{}/subscriptions/{}/resourceGroups/{}/providers/Microsoft.KeyVault/vaults/{}/privateEndpointConnections/{}",
-                            this.client.endpoint(),
-                            &this.subscription_id,
-                            &this.resource_group_name,
-                            &this.vault_name,
-                            &this.private_endpoint_connection_name
-                        ))?;
-                        let mut req = azure_core::Request::new(url, azure_core::Method::Get);
-                        let credential = this.client.token_credential();
-                        let token_response = credential.get_token(&this.client.scopes().join(" ")).await?;
-                        req.insert_header(
-                            azure_core::headers::AUTHORIZATION,
-                            format!("Bearer {}", token_response.token.secret()),
-                        );
-                        req.url_mut()
-                            .query_pairs_mut()
-                            .append_pair(azure_core::query_param::API_VERSION, "2021-04-01-preview");
-                        let req_body = azure_core::EMPTY_BODY;
-                        req.set_body(req_body);
-                        Ok(Response(this.client.send(&mut req).await?))
-                    }
-                })
+            #[doc = ""]
+            #[doc = "The builder is not consumed, so a polling loop can build it once and call `send` on every iteration; each call fetches a fresh token. The returned future is `'static` because it owns a clone of the builder, so it can be spawned or stored."]
+            pub fn send(&self) -> futures::future::BoxFuture<'static, azure_core::Result<Response>> {
+                let this = self.clone();
+                Box::pin(async move { this.send_ref().await })
+            }
+            #[doc = "Send the request without consuming the builder."]
+            #[doc = ""]
+            #[doc = "Unlike `send`, which clones the builder so that its future is `'static`, the request is built from borrowed fields, so a builder kept across calls, such as in a polling loop, is sent again without being cloned. The future borrows the builder in turn."]
+            pub async fn send_ref(&self) -> azure_core::Result<Response> {
+                let url = self.url()?;
+                let mut req = azure_core::Request::new(url, azure_core::Method::Get);
+                let token_response = self.client.token(self.claims.as_deref()).await?;
+                req.insert_header(
+                    azure_core::headers::AUTHORIZATION,
+                    format!("Bearer {}", token_response.token.secret()),
+                );
+                if let Some(accept_language) = &self.accept_language {
+                    req.insert_header(azure_core::headers::ACCEPT_LANGUAGE, accept_language.clone());
+                }
+                if let Some(if_none_match) = &self.if_none_match {
+                    req.insert_header(azure_core::headers::IF_NONE_MATCH, if_none_match);
+                }
+                let req_body = azure_core::EMPTY_BODY;
+                req.set_body(req_body);
+                Ok(Response(self.client.send(&mut req, "private_endpoint_connections::get").await?))
             }
             #[doc = "Send the request and return the response body."]
             pub fn into_future(self) -> futures::future::BoxFuture<'static, azure_core::Result<models::PrivateEndpointConnection>> {
                 Box::pin(async move { self.send().await?.into_body().await })
             }
+            #[doc = "Send the request and return the response body together with the response headers, such as the `ETag`."]
+            pub fn into_future_with_headers(
+                self,
+            ) -> futures::future::BoxFuture<'static, azure_core::Result<(models::PrivateEndpointConnection, azure_core::headers::Headers)>>
+            {
+                Box::pin(async move {
+                    let rsp = self.send().await?;
+                    let headers = rsp.as_raw_response().headers().clone();
+                    Ok((rsp.into_body().await?, headers))
+                })
+            }
+            #[doc = "Send the request and return the response body, or `None` if the resource does not exist."]
+            pub fn get_if_exists(
+                self,
+            ) -> futures::future::BoxFuture<'static, azure_core::Result<Option<models::PrivateEndpointConnection>>> {
+                Box::pin(async move {
+                    match azure_core::client::not_found_as_none(self.send().await)? {
+                        Some(rsp) => Ok(Some(rsp.into_body().await?)),
+                        None => Ok(None),
+                    }
+                })
+            }
+            #[doc = "Send the request and return the response body, blocking the current thread."]
+            #[cfg(feature = "blocking")]
+            pub fn call(self) -> azure_core::Result<models::PrivateEndpointConnection> {
+                azure_core::blocking::block_on(self.into_future())
+            }
         }
     }
     pub mod put {
@@ -1414,16 +3225,49 @@ pub mod private_endpoint_connections {
         pub struct Response(azure_core::Response);
         impl Response {
             pub async fn into_body(self) -> azure_core::Result<models::PrivateEndpointConnection> {
-                let bytes = self.0.into_body().collect().await?;
-                let body: models::PrivateEndpointConnection = serde_json::from_slice(&bytes)?;
+                let status = self.0.status();
+                let bytes = self.into_byte_stream().collect().await?;
+                let body: models::PrivateEndpointConnection =
+                    azure_core::client::from_json_body("private_endpoint_connections::put", status, &bytes)?;
                 Ok(body)
             }
+            #[doc = "Return the response body as untyped JSON; see [responses](crate::package_preview_2021_04#responses)."]
+            pub async fn into_value(self) -> azure_core::Result<serde_json::Value> {
+                let status = self.0.status();
+                let bytes = self.into_byte_stream().collect().await?;
+                let body: serde_json::Value = azure_core::client::from_json_body("private_endpoint_connections::put", status, &bytes)?;
+                Ok(body)
+            }
+            #[doc = "Stream the response body in chunks as they are received, instead of buffering all of it like `into_body`."]
+            pub fn into_byte_stream(self) -> azure_core::ResponseBody {
+                self.0.into_body()
+            }
+            pub fn status(&self) -> azure_core::StatusCode {
+                self.0.status()
+            }
             pub fn into_raw_response(self) -> azure_core::Response {
                 self.0
             }
+            #[doc = "Read the whole response and return its status, headers and body as received, for forwarding it verbatim without going through `models`."]
+            pub async fn into_raw_parts(self) -> azure_core::Result<(azure_core::StatusCode, azure_core::headers::Headers, bytes::Bytes)> {
+                let (status, headers, body) = self.0.deconstruct();
+                Ok((status, headers, body.collect().await?))
+            }
             pub fn as_raw_response(&self) -> &azure_core::Response {
                 &self.0
             }
+            #[doc = "The remaining request quota; see [responses](crate::package_preview_2021_04#responses)."]
+            pub fn rate_limit_remaining(&self) -> Option<u32> {
+                azure_core::client::rate_limit_remaining(self.0.headers())
+            }
+            #[doc = "The `x-ms-client-request-id` the request was sent with; see [responses](crate::package_preview_2021_04#responses)."]
+            pub fn client_request_id(&self) -> Option<&str> {
+                self.0.headers().get_optional_str(&azure_core::headers::CLIENT_REQUEST_ID)
+            }
+            #[doc = "How long the request took; see [responses](crate::package_preview_2021_04#responses)."]
+            pub fn elapsed(&self) -> std::time::Duration {
+                self.0.elapsed().unwrap_or_default()
+            }
             pub fn headers(&self) -> Headers {
                 Headers(self.0.headers())
             }
@@ -1458,42 +3302,116 @@ pub mod private_endpoint_connections {
             pub(crate) vault_name: String,
             pub(crate) private_endpoint_connection_name: String,
             pub(crate) properties: models::PrivateEndpointConnection,
+            pub(crate) claims: Option<String>,
+            pub(crate) accept_language: Option<String>,
+            pub(crate) query_params: Vec<(String, String)>,
+            pub(crate) raw_body: Option<(bytes::Bytes, String)>,
+        }
+        impl super::super::private::Sealed for RequestBuilder {}
+        impl super::super::Operation for RequestBuilder {
+            fn method() -> azure_core::Method {
+                azure_core::Method::Put
+            }
+            fn path_template() -> &'static str {
+                "/subscriptions/{subscriptionId}/resourceGroups/{resourceGroupName}/providers/Microsoft.KeyVault/vaults/{vaultName}/privateEndpointConnections/{privateEndpointConnectionName}"
+            }
         }
         impl RequestBuilder {
+            #[doc = "Ask the credential for a token that satisfies the JSON `claims`; see [claims](crate::package_preview_2021_04#claims)."]
+            pub fn claims(mut self, claims: impl Into<String>) -> Self {
+                self.claims = Some(claims.into());
+                self
+            }
+            #[doc = "Send this request with `accept_language` as its `Accept-Language` header, such as `fr-FR`, overriding `ClientBuilder::accept_language`."]
+            pub fn accept_language(mut self, accept_language: impl Into<String>) -> Self {
+                self.accept_language = Some(accept_language.into());
+                self
+            }
+            #[doc = "Append `key=value` to the query string; see [query parameters](crate::package_preview_2021_04#query-parameters)."]
+            pub fn query_param(mut self, key: impl Into<String>, value: impl Into<String>) -> Self {
+                self.query_params.push((key.into(), value.into()));
+                self
+            }
+            #[doc = "Send `body` as the JSON request body instead of the typed parameters, for fields the `models` type does not have yet."]
+            #[doc = ""]
+            #[doc = "Shorthand for `raw_body` with `content-type: application/json`; whichever of the two is called last wins."]
+            pub fn raw_json_body(self, body: serde_json::Value) -> Self {
+                self.raw_body(body.to_string(), "application/json")
+            }
+            #[doc = "Send `body` with the given `content-type` instead of the typed parameters."]
+            #[doc = ""]
+            #[doc = "The typed request body is not sent at all, so this is a stopgap for service changes the `models` types do not cover yet."]
+            pub fn raw_body(mut self, body: impl Into<bytes::Bytes>, content_type: impl Into<String>) -> Self {
+                self.raw_body = Some((body.into(), content_type.into()));
+                self
+            }
+            #[doc = "Build the URL the request is sent to, including the api-version and any query parameters set on the builder, without sending the request."]
+            pub fn url(&self) -> azure_core::Result<azure_core::Url> {
+                let mut url = azure_core::Url::parse(&format!(
+                    "{}/subscriptions/{}/resourceGroups/{}/providers/Microsoft.KeyVault/vaults/{}/privateEndpointConnections/{}",
+                    self.client.endpoint(),
+                    &azure_core::client::path_segment("subscription_id", &self.subscription_id)?,
+                    &azure_core::client::path_segment("resource_group_name", &self.resource_group_name)?,
+                    &azure_core::client::path_segment("vault_name", &self.vault_name)?,
+                    &azure_core::client::path_segment("private_endpoint_connection_name", &self.private_endpoint_connection_name)?
+                ))?;
+                url.query_pairs_mut()
+                    .append_pair(azure_core::query_param::API_VERSION, "2021-04-01-preview");
+                azure_core::client::append_query_params(&mut url, &self.query_params);
+                Ok(url)
+            }
             #[doc = "Send the request and returns the response."]
-            pub fn send(self) -> futures::future::BoxFuture<'static, azure_core::Result<Response>> {
-                Box::pin({
-                    let this = self.clone();
-                    async move {
-                        let url = azure_core::Url::parse(&format!(
-                            "{}/subscriptions/{}/resourceGroups/{}/providers/Microsoft.KeyVault/vaults/{}/privateEndpointConnections/{}",
-                            this.client.endpoint(),
-                            &this.subscription_id,
-                            &this.resource_group_name,
-                            &this.vault_name,
-                            &this.private_endpoint_connection_name
-                        ))?;
-                        let mut req = azure_core::Request::new(url, azure_core::Method::Put);
-                        let credential = this.client.token_credential();
-                        let token_response = credential.get_token(&this.client.scopes().join(" ")).await?;
-                        req.insert_header(
-                            azure_core::headers::AUTHORIZATION,
-                            format!("Bearer {}", token_response.token.secret()),
-                        );
-                        req.url_mut()
-                            .query_pairs_mut()
-                            .append_pair(azure_core::query_param::API_VERSION, "2021-04-01-preview");
-                        req.insert_header("content-type", "application/json");
-                        let req_body = azure_core::to_json(&this.properties)?;
-                        req.set_body(req_body);
-                        Ok(Response(this.client.send(&mut req).await?))
+            #[doc = ""]
+            #[doc = "The builder is not consumed, so a polling loop can build it once and call `send` on every iteration; each call fetches a fresh token. The returned future is `'static` because it owns a clone of the builder, so it can be spawned or stored."]
+            pub fn send(&self) -> futures::future::BoxFuture<'static, azure_core::Result<Response>> {
+                let this = self.clone();
+                Box::pin(async move { this.send_ref().await })
+            }
+            #[doc = "Send the request without consuming the builder."]
+            #[doc = ""]
+            #[doc = "Unlike `send`, which clones the builder so that its future is `'static`, the request is built from borrowed fields, so a builder kept across calls, such as in a polling loop, is sent again without being cloned. The future borrows the builder in turn."]
+            pub async fn send_ref(&self) -> azure_core::Result<Response> {
+                let url = self.url()?;
+                let mut req = azure_core::Request::new(url, azure_core::Method::Put);
+                let token_response = self.client.token(self.claims.as_deref()).await?;
+                req.insert_header(
+                    azure_core::headers::AUTHORIZATION,
+                    format!("Bearer {}", token_response.token.secret()),
+                );
+                if let Some(accept_language) = &self.accept_language {
+                    req.insert_header(azure_core::headers::ACCEPT_LANGUAGE, accept_language.clone());
+                }
+                req.insert_header("content-type", "application/json");
+                let req_body = match &self.raw_body {
+                    Some((body, content_type)) => {
+                        req.insert_header("content-type", content_type.clone());
+                        body.clone()
                     }
-                })
+                    None => azure_core::to_json(&self.properties)?,
+                };
+                req.set_body(req_body);
+                Ok(Response(self.client.send(&mut req, "private_endpoint_connections::put").await?))
             }
             #[doc = "Send the request and return the response body."]
             pub fn into_future(self) -> futures::future::BoxFuture<'static, azure_core::Result<models::PrivateEndpointConnection>> {
                 Box::pin(async move { self.send().await?.into_body().await })
             }
+            #[doc = "Send the request and return the response body together with the response headers, such as the `ETag`."]
+            pub fn into_future_with_headers(
+                self,
+            ) -> futures::future::BoxFuture<'static, azure_core::Result<(models::PrivateEndpointConnection, azure_core::headers::Headers)>>
+            {
+                Box::pin(async move {
+                    let rsp = self.send().await?;
+                    let headers = rsp.as_raw_response().headers().clone();
+                    Ok((rsp.into_body().await?, headers))
+                })
+            }
+            #[doc = "Send the request and return the response body, blocking the current thread."]
+            #[cfg(feature = "blocking")]
+            pub fn call(self) -> azure_core::Result<models::PrivateEndpointConnection> {
+                azure_core::blocking::block_on(self.into_future())
+            }
         }
     }
     pub mod delete {
@@ -1501,16 +3419,49 @@ pub mod private_endpoint_connections {
         pub struct Response(azure_core::Response);
         impl Response {
             pub async fn into_body(self) -> azure_core::Result<models::PrivateEndpointConnection> {
-                let bytes = self.0.into_body().collect().await?;
-                let body: models::PrivateEndpointConnection = serde_json::from_slice(&bytes)?;
+                let status = self.0.status();
+                let bytes = self.into_byte_stream().collect().await?;
+                let body: models::PrivateEndpointConnection =
+                    azure_core::client::from_json_body("private_endpoint_connections::delete", status, &bytes)?;
                 Ok(body)
             }
+            #[doc = "Return the response body as untyped JSON; see [responses](crate::package_preview_2021_04#responses)."]
+            pub async fn into_value(self) -> azure_core::Result<serde_json::Value> {
+                let status = self.0.status();
+                let bytes = self.into_byte_stream().collect().await?;
+                let body: serde_json::Value = azure_core::client::from_json_body("private_endpoint_connections::delete", status, &bytes)?;
+                Ok(body)
+            }
+            #[doc = "Stream the response body in chunks as they are received, instead of buffering all of it like `into_body`."]
+            pub fn into_byte_stream(self) -> azure_core::ResponseBody {
+                self.0.into_body()
+            }
+            pub fn status(&self) -> azure_core::StatusCode {
+                self.0.status()
+            }
             pub fn into_raw_response(self) -> azure_core::Response {
                 self.0
             }
+            #[doc = "Read the whole response and return its status, headers and body as received, for forwarding it verbatim without going through `models`."]
+            pub async fn into_raw_parts(self) -> azure_core::Result<(azure_core::StatusCode, azure_core::headers::Headers, bytes::Bytes)> {
+                let (status, headers, body) = self.0.deconstruct();
+                Ok((status, headers, body.collect().await?))
+            }
             pub fn as_raw_response(&self) -> &azure_core::Response {
                 &self.0
             }
+            #[doc = "The remaining request quota; see [responses](crate::package_preview_2021_04#responses)."]
+            pub fn rate_limit_remaining(&self) -> Option<u32> {
+                azure_core::client::rate_limit_remaining(self.0.headers())
+            }
+            #[doc = "The `x-ms-client-request-id` the request was sent with; see [responses](crate::package_preview_2021_04#responses)."]
+            pub fn client_request_id(&self) -> Option<&str> {
+                self.0.headers().get_optional_str(&azure_core::headers::CLIENT_REQUEST_ID)
+            }
+            #[doc = "How long the request took; see [responses](crate::package_preview_2021_04#responses)."]
+            pub fn elapsed(&self) -> std::time::Duration {
+                self.0.elapsed().unwrap_or_default()
+            }
             pub fn headers(&self) -> Headers {
                 Headers(self.0.headers())
             }
@@ -1543,42 +3494,96 @@ pub mod private_endpoint_connections {
             pub(crate) resource_group_name: String,
             pub(crate) vault_name: String,
             pub(crate) private_endpoint_connection_name: String,
+            pub(crate) claims: Option<String>,
+            pub(crate) accept_language: Option<String>,
+            pub(crate) query_params: Vec<(String, String)>,
+        }
+        impl super::super::private::Sealed for RequestBuilder {}
+        impl super::super::Operation for RequestBuilder {
+            fn method() -> azure_core::Method {
+                azure_core::Method::Delete
+            }
+            fn path_template() -> &'static str {
+                "/subscriptions/{subscriptionId}/resourceGroups/{resourceGroupName}/providers/Microsoft.KeyVault/vaults/{vaultName}/privateEndpointConnections/{privateEndpointConnectionName}"
+            }
         }
         impl RequestBuilder {
+            #[doc = "Ask the credential for a token that satisfies the JSON `claims`; see [claims](crate::package_preview_2021_04#claims)."]
+            pub fn claims(mut self, claims: impl Into<String>) -> Self {
+                self.claims = Some(claims.into());
+                self
+            }
+            #[doc = "Send this request with `accept_language` as its `Accept-Language` header, such as `fr-FR`, overriding `ClientBuilder::accept_language`."]
+            pub fn accept_language(mut self, accept_language: impl Into<String>) -> Self {
+                self.accept_language = Some(accept_language.into());
+                self
+            }
+            #[doc = "Append `key=value` to the query string; see [query parameters](crate::package_preview_2021_04#query-parameters)."]
+            pub fn query_param(mut self, key: impl Into<String>, value: impl Into<String>) -> Self {
+                self.query_params.push((key.into(), value.into()));
+                self
+            }
+            #[doc = "Build the URL the request is sent to, including the api-version and any query parameters set on the builder, without sending the request."]
+            pub fn url(&self) -> azure_core::Result<azure_core::Url> {
+                let mut url = azure_core::Url::parse(&format!(
+                    "{}/subscriptions/{}/resourceGroups/{}/providers/Microsoft.KeyVault/vaults/{}/privateEndpointConnections/{}",
+                    self.client.endpoint(),
+                    &azure_core::client::path_segment("subscription_id", &self.subscription_id)?,
+                    &azure_core::client::path_segment("resource_group_name", &self.resource_group_name)?,
+                    &azure_core::client::path_segment("vault_name", &self.vault_name)?,
+                    &azure_core::client::path_segment("private_endpoint_connection_name", &self.private_endpoint_connection_name)?
+                ))?;
+                url.query_pairs_mut()
+                    .append_pair(azure_core::query_param::API_VERSION, "2021-04-01-preview");
+                azure_core::client::append_query_params(&mut url, &self.query_params);
+                Ok(url)
+            }
             #[doc = "only the first response will be fetched as long running operations are not supported yet"]
             #[doc = "Send the request and returns the response."]
-            pub fn send(self) -> futures::future::BoxFuture<'static, azure_core::Result<Response>> {
-                Box::pin({
-                    let this = self.clone();
-                    async move {
-                        let url = azure_core::Url::parse(&format!(
-                            "{}/subscriptions/{}/resourceGroups/{}/providers/Microsoft.KeyVault/vaults/{}/privateEndpointConnections/{}",
-                            this.client.endpoint(),
-                            &this.subscription_id,
-                            &this.resource_group_name,
-                            &this.vault_name,
-                            &this.private_endpoint_connection_name
-                        ))?;
-                        let mut req = azure_core::Request::new(url, azure_core::Method::Delete);
-                        let credential = this.client.token_credential();
-                        let token_response = credential.get_token(&this.client.scopes().join(" ")).await?;
-                        req.insert_header(
-                            azure_core::headers::AUTHORIZATION,
-                            format!("Bearer {}", token_response.token.secret()),
-                        );
-                        req.url_mut()
-                            .query_pairs_mut()
-                            .append_pair(azure_core::query_param::API_VERSION, "2021-04-01-preview");
-                        let req_body = azure_core::EMPTY_BODY;
-                        req.set_body(req_body);
-                        Ok(Response(this.client.send(&mut req).await?))
-                    }
-                })
+            #[doc = ""]
+            #[doc = "The builder is not consumed, so a polling loop can build it once and call `send` on every iteration; each call fetches a fresh token. The returned future is `'static` because it owns a clone of the builder, so it can be spawned or stored."]
+            pub fn send(&self) -> futures::future::BoxFuture<'static, azure_core::Result<Response>> {
+                let this = self.clone();
+                Box::pin(async move { this.send_ref().await })
+            }
+            #[doc = "Send the request without consuming the builder."]
+            #[doc = ""]
+            #[doc = "Unlike `send`, which clones the builder so that its future is `'static`, the request is built from borrowed fields, so a builder kept across calls, such as in a polling loop, is sent again without being cloned. The future borrows the builder in turn."]
+            pub async fn send_ref(&self) -> azure_core::Result<Response> {
+                let url = self.url()?;
+                let mut req = azure_core::Request::new(url, azure_core::Method::Delete);
+                let token_response = self.client.token(self.claims.as_deref()).await?;
+                req.insert_header(
+                    azure_core::headers::AUTHORIZATION,
+                    format!("Bearer {}", token_response.token.secret()),
+                );
+                if let Some(accept_language) = &self.accept_language {
+                    req.insert_header(azure_core::headers::ACCEPT_LANGUAGE, accept_language.clone());
+                }
+                let req_body = azure_core::EMPTY_BODY;
+                req.set_body(req_body);
+                Ok(Response(self.client.send(&mut req, "private_endpoint_connections::delete").await?))
             }
             #[doc = "Send the request and return the response body."]
             pub fn into_future(self) -> futures::future::BoxFuture<'static, azure_core::Result<models::PrivateEndpointConnection>> {
                 Box::pin(async move { self.send().await?.into_body().await })
             }
+            #[doc = "Send the request and return the response body together with the response headers, such as the `ETag`."]
+            pub fn into_future_with_headers(
+                self,
+            ) -> futures::future::BoxFuture<'static, azure_core::Result<(models::PrivateEndpointConnection, azure_core::headers::Headers)>>
+            {
+                Box::pin(async move {
+                    let rsp = self.send().await?;
+                    let headers = rsp.as_raw_response().headers().clone();
+                    Ok((rsp.into_body().await?, headers))
+                })
+            }
+            #[doc = "Send the request and return the response body, blocking the current thread."]
+            #[cfg(feature = "blocking")]
+            pub fn call(self) -> azure_core::Result<models::PrivateEndpointConnection> {
+                azure_core::blocking::block_on(self.into_future())
+            }
         }
     }
     pub mod list_by_resource {
@@ -1586,16 +3591,54 @@ pub mod private_endpoint_connections {
         pub struct Response(azure_core::Response);
         impl Response {
             pub async fn into_body(self) -> azure_core::Result<models::PrivateEndpointConnectionListResult> {
-                let bytes = self.0.into_body().collect().await?;
-                let body: models::PrivateEndpointConnectionListResult = serde_json::from_slice(&bytes)?;
+                let status = self.0.status();
+                let bytes = self.into_byte_stream().collect().await?;
+                let body: models::PrivateEndpointConnectionListResult =
+                    azure_core::client::from_json_body("private_endpoint_connections::list_by_resource", status, &bytes)?;
+                Ok(body)
+            }
+            #[doc = "Stream the items of the page as they are received, instead of buffering the whole body like `into_body`. Everything but the `value` array, including the link to the next page, is skipped."]
+            pub fn into_body_streaming(self) -> futures::stream::BoxStream<'static, azure_core::Result<models::PrivateEndpointConnection>> {
+                self.into_byte_stream().json_array_items("value")
+            }
+            #[doc = "Return the response body as untyped JSON; see [responses](crate::package_preview_2021_04#responses)."]
+            pub async fn into_value(self) -> azure_core::Result<serde_json::Value> {
+                let status = self.0.status();
+                let bytes = self.into_byte_stream().collect().await?;
+                let body: serde_json::Value =
+                    azure_core::client::from_json_body("private_endpoint_connections::list_by_resource", status, &bytes)?;
                 Ok(body)
             }
+            #[doc = "Stream the response body in chunks as they are received, instead of buffering all of it like `into_body`."]
+            pub fn into_byte_stream(self) -> azure_core::ResponseBody {
+                self.0.into_body()
+            }
+            pub fn status(&self) -> azure_core::StatusCode {
+                self.0.status()
+            }
             pub fn into_raw_response(self) -> azure_core::Response {
                 self.0
             }
+            #[doc = "Read the whole response and return its status, headers and body as received, for forwarding it verbatim without going through `models`."]
+            pub async fn into_raw_parts(self) -> azure_core::Result<(azure_core::StatusCode, azure_core::headers::Headers, bytes::Bytes)> {
+                let (status, headers, body) = self.0.deconstruct();
+                Ok((status, headers, body.collect().await?))
+            }
             pub fn as_raw_response(&self) -> &azure_core::Response {
                 &self.0
             }
+            #[doc = "The remaining request quota; see [responses](crate::package_preview_2021_04#responses)."]
+            pub fn rate_limit_remaining(&self) -> Option<u32> {
+                azure_core::client::rate_limit_remaining(self.0.headers())
+            }
+            #[doc = "The `x-ms-client-request-id` the request was sent with; see [responses](crate::package_preview_2021_04#responses)."]
+            pub fn client_request_id(&self) -> Option<&str> {
+                self.0.headers().get_optional_str(&azure_core::headers::CLIENT_REQUEST_ID)
+            }
+            #[doc = "How long the request took; see [responses](crate::package_preview_2021_04#responses)."]
+            pub fn elapsed(&self) -> std::time::Duration {
+                self.0.elapsed().unwrap_or_default()
+            }
         }
         impl From<Response> for azure_core::Response {
             fn from(rsp: Response) -> Self {
@@ -1613,68 +3656,190 @@ pub mod private_endpoint_connections {
             pub(crate) subscription_id: String,
             pub(crate) resource_group_name: String,
             pub(crate) vault_name: String,
+            pub(crate) claims: Option<String>,
+            pub(crate) accept_language: Option<String>,
+            pub(crate) query_params: Vec<(String, String)>,
+            pub(crate) select: Option<String>,
+            pub(crate) max_items: Option<usize>,
+            pub(crate) on_missing_empty: bool,
+            pub(crate) resume_from: Option<String>,
+        }
+        impl super::super::private::Sealed for RequestBuilder {}
+        impl super::super::Operation for RequestBuilder {
+            fn method() -> azure_core::Method {
+                azure_core::Method::Get
+            }
+            fn path_template() -> &'static str {
+                "/subscriptions/{subscriptionId}/resourceGroups/{resourceGroupName}/providers/Microsoft.KeyVault/vaults/{vaultName}/privateEndpointConnections"
+            }
+        }
+        impl super::super::BatchRead for RequestBuilder {
+            fn batch_url(&self) -> azure_core::Result<azure_core::Url> {
+                self.url()
+            }
         }
         impl RequestBuilder {
+            #[doc = "Ask the credential for a token that satisfies the JSON `claims`; see [claims](crate::package_preview_2021_04#claims)."]
+            pub fn claims(mut self, claims: impl Into<String>) -> Self {
+                self.claims = Some(claims.into());
+                self
+            }
+            #[doc = "Send this request with `accept_language` as its `Accept-Language` header, such as `fr-FR`, overriding `ClientBuilder::accept_language`."]
+            pub fn accept_language(mut self, accept_language: impl Into<String>) -> Self {
+                self.accept_language = Some(accept_language.into());
+                self
+            }
+            #[doc = "Append `key=value` to the query string; see [query parameters](crate::package_preview_2021_04#query-parameters)."]
+            pub fn query_param(mut self, key: impl Into<String>, value: impl Into<String>) -> Self {
+                self.query_params.push((key.into(), value.into()));
+                self
+            }
+            #[doc = "Only return the given properties of each item, such as `[\"id\", \"name\"]`, to reduce the work done by the service and the size of each page."]
+            #[doc = ""]
+            #[doc = "Fields of the returned models that were not selected are `None`."]
+            pub fn select(mut self, select: &[&str]) -> Self {
+                self.select = Some(select.join(","));
+                self
+            }
+            #[doc = "Limit the total number of items yielded by `into_item_stream`. No further pages are requested once the limit is reached."]
+            pub fn max_items(mut self, max_items: usize) -> Self {
+                self.max_items = Some(max_items);
+                self
+            }
+            #[doc = "Treat `404 Not Found` on the first page as an empty list instead of an error."]
+            #[doc = ""]
+            #[doc = "Useful when the parent resource may not exist yet, such as in a reconciliation loop racing its provisioning. The stream then yields a single empty page. Off by default, and a `404` on a later page is always an error."]
+            pub fn on_missing_empty(mut self, on_missing_empty: bool) -> Self {
+                self.on_missing_empty = on_missing_empty;
+                self
+            }
+            #[doc = "Start paging from `continuation`, a token saved from `into_checkpoint_stream` or `page`, instead of from the first page."]
+            #[doc = ""]
+            #[doc = "Lets a long listing be checkpointed and picked up where it stopped, such as after a restart."]
+            pub fn resume_from(mut self, continuation: impl Into<String>) -> Self {
+                self.resume_from = Some(continuation.into());
+                self
+            }
+            #[doc = "Build the URL the request is sent to, including the api-version and any query parameters set on the builder, without sending the request."]
+            pub fn url(&self) -> azure_core::Result<azure_core::Url> {
+                let mut url = azure_core::Url::parse(&format!(
+                    "{}/subscriptions/{}/resourceGroups/{}/providers/Microsoft.KeyVault/vaults/{}/privateEndpointConnections",
+                    self.client.endpoint(),
+                    &azure_core::client::path_segment("subscription_id", &self.subscription_id)?,
+                    &azure_core::client::path_segment("resource_group_name", &self.resource_group_name)?,
+                    &azure_core::client::path_segment("vault_name", &self.vault_name)?
+                ))?;
+                url.query_pairs_mut()
+                    .append_pair(azure_core::query_param::API_VERSION, "2021-04-01-preview");
+                if let Some(select) = &self.select {
+                    url.query_pairs_mut().append_pair("$select", select);
+                }
+                azure_core::client::append_query_params(&mut url, &self.query_params);
+                Ok(url)
+            }
+            #[doc = "Stream the items of every page, stopping early if `max_items` is set."]
+            pub fn into_item_stream(self) -> futures::stream::BoxStream<'static, azure_core::Result<models::PrivateEndpointConnection>> {
+                let max_items = self.max_items;
+                azure_core::client::item_stream(self.into_stream(), max_items, |page| page.value)
+            }
+            #[doc = "Stream the items of each page together with the continuation token that resumes after it, or `None` after the last page."]
+            #[doc = ""]
+            #[doc = "Save the token once a page has been processed and pass it to `resume_from` to carry on from there later. `max_items` is not applied."]
+            pub fn into_checkpoint_stream(
+                self,
+            ) -> futures::stream::BoxStream<'static, azure_core::Result<(Vec<models::PrivateEndpointConnection>, Option<String>)>>
+            {
+                azure_core::client::checkpoint_stream(self.into_stream(), |page| page.value)
+            }
             pub fn into_stream(self) -> azure_core::Pageable<models::PrivateEndpointConnectionListResult, azure_core::error::Error> {
                 let make_request = move |continuation: Option<String>| {
-                    let this = self.clone();
-                    async move {
-                        let mut url = azure_core::Url::parse(&format!(
-                            "{}/subscriptions/{}/resourceGroups/{}/providers/Microsoft.KeyVault/vaults/{}/privateEndpointConnections",
-                            this.client.endpoint(),
-                            &this.subscription_id,
-                            &this.resource_group_name,
-                            &this.vault_name
-                        ))?;
-                        let rsp = match continuation {
-                            Some(value) => {
-                                url.set_path("");
-                                url = url.join(&value)?;
-                                let mut req = azure_core::Request::new(url, azure_core::Method::Get);
-                                let credential = this.client.token_credential();
-                                let token_response = credential.get_token(&this.client.scopes().join(" ")).await?;
-                                req.insert_header(
-                                    azure_core::headers::AUTHORIZATION,
-                                    format!("Bearer {}", token_response.token.secret()),
-                                );
-                                let has_api_version_already =
-                                    req.url_mut().query_pairs().any(|(k, _)| k == azure_core::query_param::API_VERSION);
-                                if !has_api_version_already {
-                                    req.url_mut()
-                                        .query_pairs_mut()
-                                        .append_pair(azure_core::query_param::API_VERSION, "2021-04-01-preview");
-                                }
-                                let req_body = azure_core::EMPTY_BODY;
-                                req.set_body(req_body);
-                                this.client.send(&mut req).await?
+                    let continuation = continuation.or_else(|| self.resume_from.clone());
+                    self.clone().fetch_page(continuation)
+                };
+                azure_core::Pageable::new(make_request)
+            }
+            #[doc = "Fetch a single page and return its body together with the continuation token for the next page, if any."]
+            #[doc = ""]
+            #[doc = "Pass `None` to fetch the first page, or a token returned by a previous call to fetch the page that follows it."]
+            pub fn page(
+                &self,
+                continuation: Option<String>,
+            ) -> futures::future::BoxFuture<'static, azure_core::Result<(models::PrivateEndpointConnectionListResult, Option<String>)>>
+            {
+                let this = self.clone();
+                Box::pin(async move {
+                    let body = this.fetch_page(continuation).await?;
+                    let continuation = azure_core::Continuable::continuation(&body);
+                    Ok((body, continuation))
+                })
+            }
+            fn fetch_page(
+                self,
+                continuation: Option<String>,
+            ) -> futures::future::BoxFuture<'static, azure_core::Result<models::PrivateEndpointConnectionListResult>> {
+                Box::pin(async move {
+                    let mut url = self.url()?;
+                    let rsp = match continuation {
+                        Some(value) => {
+                            let first_page_url = url;
+                            url = azure_core::next_page_url(&first_page_url, &value)?;
+                            azure_core::client::carry_query_params(&mut url, &first_page_url, &self.query_params);
+                            let mut req = azure_core::Request::new(url, azure_core::Method::Get);
+                            let token_response = self.client.token(self.claims.as_deref()).await?;
+                            req.insert_header(
+                                azure_core::headers::AUTHORIZATION,
+                                format!("Bearer {}", token_response.token.secret()),
+                            );
+                            if let Some(accept_language) = &self.accept_language {
+                                req.insert_header(azure_core::headers::ACCEPT_LANGUAGE, accept_language.clone());
+                            }
+                            if let Some(token) = azure_core::continuation_header(&value) {
+                                req.insert_header(azure_core::headers::CONTINUATION, token.to_owned());
                             }
-                            None => {
-                                let mut req = azure_core::Request::new(url, azure_core::Method::Get);
-                                let credential = this.client.token_credential();
-                                let token_response = credential.get_token(&this.client.scopes().join(" ")).await?;
-                                req.insert_header(
-                                    azure_core::headers::AUTHORIZATION,
-                                    format!("Bearer {}", token_response.token.secret()),
-                                );
-                                req.url_mut()
-                                    .query_pairs_mut()
-                                    .append_pair(azure_core::query_param::API_VERSION, "2021-04-01-preview");
-                                let req_body = azure_core::EMPTY_BODY;
-                                req.set_body(req_body);
-                                this.client.send(&mut req).await?
+                            let req_body = azure_core::EMPTY_BODY;
+                            req.set_body(req_body);
+                            self.client.send(&mut req, "private_endpoint_connections::list_by_resource").await?
+                        }
+                        None => {
+                            let mut req = azure_core::Request::new(url, azure_core::Method::Get);
+                            let token_response = self.client.token(self.claims.as_deref()).await?;
+                            req.insert_header(
+                                azure_core::headers::AUTHORIZATION,
+                                format!("Bearer {}", token_response.token.secret()),
+                            );
+                            if let Some(accept_language) = &self.accept_language {
+                                req.insert_header(azure_core::headers::ACCEPT_LANGUAGE, accept_language.clone());
                             }
-                        };
-                        let rsp = match rsp.status() {
-                            azure_core::StatusCode::Ok => Ok(Response(rsp)),
-                            status_code => Err(azure_core::error::Error::from(azure_core::error::ErrorKind::HttpResponse {
-                                status: status_code,
-                                error_code: None,
-                            })),
-                        };
-                        rsp?.into_body().await
+                            let req_body = azure_core::EMPTY_BODY;
+                            req.set_body(req_body);
+                            let rsp = self.client.send(&mut req, "private_endpoint_connections::list_by_resource").await;
+                            if self.on_missing_empty {
+                                match azure_core::client::not_found_as_none(rsp)? {
+                                    Some(rsp) => rsp,
+                                    None => return Ok(Default::default()),
+                                }
+                            } else {
+                                rsp?
+                            }
+                        }
+                    };
+                    let rsp = match rsp.status() {
+                        azure_core::StatusCode::Ok => Ok(Response(rsp)),
+                        _ => Err(azure_core::client::unexpected_status(&rsp)),
+                    };
+                    let rsp = rsp?;
+                    let continuation = azure_core::continuation_from_headers(rsp.as_raw_response().headers());
+                    let mut body = rsp.into_body().await?;
+                    if body.next_link.is_none() {
+                        body.next_link = continuation;
                     }
-                };
-                azure_core::Pageable::new(make_request)
+                    Ok(body)
+                })
+            }
+            #[doc = "Fetch every page and return all items, blocking the current thread. Stops early if `max_items` is set."]
+            #[cfg(feature = "blocking")]
+            pub fn call(self) -> azure_core::Result<Vec<models::PrivateEndpointConnection>> {
+                azure_core::blocking::block_on(futures::TryStreamExt::try_collect(self.into_item_stream()))
             }
         }
     }
@@ -1700,6 +3865,9 @@ pub mod private_link_resources {
                 subscription_id: subscription_id.into(),
                 resource_group_name: resource_group_name.into(),
                 vault_name: vault_name.into(),
+                claims: None,
+                accept_language: None,
+                query_params: Vec::new(),
             }
         }
     }
@@ -1708,16 +3876,53 @@ pub mod private_link_resources {
         pub struct Response(azure_core::Response);
         impl Response {
             pub async fn into_body(self) -> azure_core::Result<models::PrivateLinkResourceListResult> {
-                let bytes = self.0.into_body().collect().await?;
-                let body: models::PrivateLinkResourceListResult = serde_json::from_slice(&bytes)?;
+                let status = self.0.status();
+                let bytes = self.into_byte_stream().collect().await?;
+                let body: models::PrivateLinkResourceListResult =
+                    azure_core::client::from_json_body("private_link_resources::list_by_vault", status, &bytes)?;
                 Ok(body)
             }
+            #[doc = "Stream the items of the page as they are received, instead of buffering the whole body like `into_body`. Everything but the `value` array, including the link to the next page, is skipped."]
+            pub fn into_body_streaming(self) -> futures::stream::BoxStream<'static, azure_core::Result<models::PrivateLinkResource>> {
+                self.into_byte_stream().json_array_items("value")
+            }
+            #[doc = "Return the response body as untyped JSON; see [responses](crate::package_preview_2021_04#responses)."]
+            pub async fn into_value(self) -> azure_core::Result<serde_json::Value> {
+                let status = self.0.status();
+                let bytes = self.into_byte_stream().collect().await?;
+                let body: serde_json::Value = azure_core::client::from_json_body("private_link_resources::list_by_vault", status, &bytes)?;
+                Ok(body)
+            }
+            #[doc = "Stream the response body in chunks as they are received, instead of buffering all of it like `into_body`."]
+            pub fn into_byte_stream(self) -> azure_core::ResponseBody {
+                self.0.into_body()
+            }
+            pub fn status(&self) -> azure_core::StatusCode {
+                self.0.status()
+            }
             pub fn into_raw_response(self) -> azure_core::Response {
                 self.0
             }
+            #[doc = "Read the whole response and return its status, headers and body as received, for forwarding it verbatim without going through `models`."]
+            pub async fn into_raw_parts(self) -> azure_core::Result<(azure_core::StatusCode, azure_core::headers::Headers, bytes::Bytes)> {
+                let (status, headers, body) = self.0.deconstruct();
+                Ok((status, headers, body.collect().await?))
+            }
             pub fn as_raw_response(&self) -> &azure_core::Response {
                 &self.0
             }
+            #[doc = "The remaining request quota; see [responses](crate::package_preview_2021_04#responses)."]
+            pub fn rate_limit_remaining(&self) -> Option<u32> {
+                azure_core::client::rate_limit_remaining(self.0.headers())
+            }
+            #[doc = "The `x-ms-client-request-id` the request was sent with; see [responses](crate::package_preview_2021_04#responses)."]
+            pub fn client_request_id(&self) -> Option<&str> {
+                self.0.headers().get_optional_str(&azure_core::headers::CLIENT_REQUEST_ID)
+            }
+            #[doc = "How long the request took; see [responses](crate::package_preview_2021_04#responses)."]
+            pub fn elapsed(&self) -> std::time::Duration {
+                self.0.elapsed().unwrap_or_default()
+            }
         }
         impl From<Response> for azure_core::Response {
             fn from(rsp: Response) -> Self {
@@ -1735,40 +3940,101 @@ pub mod private_link_resources {
             pub(crate) subscription_id: String,
             pub(crate) resource_group_name: String,
             pub(crate) vault_name: String,
+            pub(crate) claims: Option<String>,
+            pub(crate) accept_language: Option<String>,
+            pub(crate) query_params: Vec<(String, String)>,
+        }
+        impl super::super::private::Sealed for RequestBuilder {}
+        impl super::super::Operation for RequestBuilder {
+            fn method() -> azure_core::Method {
+                azure_core::Method::Get
+            }
+            fn path_template() -> &'static str {
+                "/subscriptions/{subscriptionId}/resourceGroups/{resourceGroupName}/providers/Microsoft.KeyVault/vaults/{vaultName}/privateLinkResources"
+            }
+        }
+        impl super::super::BatchRead for RequestBuilder {
+            fn batch_url(&self) -> azure_core::Result<azure_core::Url> {
+                self.url()
+            }
         }
         impl RequestBuilder {
+            #[doc = "Ask the credential for a token that satisfies the JSON `claims`; see [claims](crate::package_preview_2021_04#claims)."]
+            pub fn claims(mut self, claims: impl Into<String>) -> Self {
+                self.claims = Some(claims.into());
+                self
+            }
+            #[doc = "Send this request with `accept_language` as its `Accept-Language` header, such as `fr-FR`, overriding `ClientBuilder::accept_language`."]
+            pub fn accept_language(mut self, accept_language: impl Into<String>) -> Self {
+                self.accept_language = Some(accept_language.into());
+                self
+            }
+            #[doc = "Append `key=value` to the query string; see [query parameters](crate::package_preview_2021_04#query-parameters)."]
+            pub fn query_param(mut self, key: impl Into<String>, value: impl Into<String>) -> Self {
+                self.query_params.push((key.into(), value.into()));
+                self
+            }
+            #[doc = "Build the URL the request is sent to, including the api-version and any query parameters set on the builder, without sending the request."]
+            pub fn url(&self) -> azure_core::Result<azure_core::Url> {
+                let mut url = azure_core::Url::parse(&format!(
+                    "{}/subscriptions/{}/resourceGroups/{}/providers/Microsoft.KeyVault/vaults/{}/privateLinkResources",
+                    self.client.endpoint(),
+                    &azure_core::client::path_segment("subscription_id", &self.subscription_id)?,
+                    &azure_core::client::path_segment("resource_group_name", &self.resource_group_name)?,
+                    &azure_core::client::path_segment("vault_name", &self.vault_name)?
+                ))?;
+                url.query_pairs_mut()
+                    .append_pair(azure_core::query_param::API_VERSION, "2021-04-01-preview");
+                azure_core::client::append_query_params(&mut url, &self.query_params);
+                Ok(url)
+            }
             #[doc = "Send the request and returns the response."]
-            pub fn send(self) -> futures::future::BoxFuture<'static, azure_core::Result<Response>> {
-                Box::pin({
-                    let this = self.clone();
-                    async move {
-                        let url = azure_core::Url::parse(&format!(
-                            "{}/subscriptions/{}/resourceGroups/{}/providers/Microsoft.KeyVault/vaults/{}/privateLinkResources",
-                            this.client.endpoint(),
-                            &this.subscription_id,
-                            &this.resource_group_name,
-                            &this.vault_name
-                        ))?;
-                        let mut req = azure_core::Request::new(url, azure_core::Method::Get);
-                        let credential = this.client.token_credential();
-                        let token_response = credential.get_token(&this.client.scopes().join(" ")).await?;
-                        req.insert_header(
-                            azure_core::headers::AUTHORIZATION,
-                            format!("Bearer {}", token_response.token.secret()),
-                        );
-                        req.url_mut()
-                            .query_pairs_mut()
-                            .append_pair(azure_core::query_param::API_VERSION, "2021-04-01-preview");
-                        let req_body = azure_core::EMPTY_BODY;
-                        req.set_body(req_body);
-                        Ok(Response(this.client.send(&mut req).await?))
-                    }
-                })
+            #[doc = ""]
+            #[doc = "The builder is not consumed, so a polling loop can build it once and call `send` on every iteration; each call fetches a fresh token. The returned future is `'static` because it owns a clone of the builder, so it can be spawned or stored."]
+            pub fn send(&self) -> futures::future::BoxFuture<'static, azure_core::Result<Response>> {
+                let this = self.clone();
+                Box::pin(async move { this.send_ref().await })
+            }
+            #[doc = "Send the request without consuming the builder."]
+            #[doc = ""]
+            #[doc = "Unlike `send`, which clones the builder so that its future is `'static`, the request is built from borrowed fields, so a builder kept across calls, such as in a polling loop, is sent again without being cloned. The future borrows the builder in turn."]
+            pub async fn send_ref(&self) -> azure_core::Result<Response> {
+                let url = self.url()?;
+                let mut req = azure_core::Request::new(url, azure_core::Method::Get);
+                let token_response = self.client.token(self.claims.as_deref()).await?;
+                req.insert_header(
+                    azure_core::headers::AUTHORIZATION,
+                    format!("Bearer {}", token_response.token.secret()),
+                );
+                if let Some(accept_language) = &self.accept_language {
+                    req.insert_header(azure_core::headers::ACCEPT_LANGUAGE, accept_language.clone());
+                }
+                let req_body = azure_core::EMPTY_BODY;
+                req.set_body(req_body);
+                Ok(Response(self.client.send(&mut req, "private_link_resources::list_by_vault").await?))
             }
             #[doc = "Send the request and return the response body."]
             pub fn into_future(self) -> futures::future::BoxFuture<'static, azure_core::Result<models::PrivateLinkResourceListResult>> {
                 Box::pin(async move { self.send().await?.into_body().await })
             }
+            #[doc = "Send the request and return the response body together with the response headers, such as the `ETag`."]
+            pub fn into_future_with_headers(
+                self,
+            ) -> futures::future::BoxFuture<
+                'static,
+                azure_core::Result<(models::PrivateLinkResourceListResult, azure_core::headers::Headers)>,
+            > {
+                Box::pin(async move {
+                    let rsp = self.send().await?;
+                    let headers = rsp.as_raw_response().headers().clone();
+                    Ok((rsp.into_body().await?, headers))
+                })
+            }
+            #[doc = "Send the request and return the response body, blocking the current thread."]
+            #[cfg(feature = "blocking")]
+            pub fn call(self) -> azure_core::Result<models::PrivateLinkResourceListResult> {
+                azure_core::blocking::block_on(self.into_future())
+            }
         }
     }
 }
@@ -1793,6 +4059,10 @@ pub mod managed_hsms {
                 resource_group_name: resource_group_name.into(),
                 name: name.into(),
                 subscription_id: subscription_id.into(),
+                claims: None,
+                accept_language: None,
+                if_none_match: None,
+                query_params: Vec::new(),
             }
         }
         #[doc = "Create or update a managed HSM Pool in the specified subscription."]
@@ -1815,6 +4085,12 @@ pub mod managed_hsms {
                 name: name.into(),
                 parameters: parameters.into(),
                 subscription_id: subscription_id.into(),
+                claims: None,
+                accept_language: None,
+                idempotency_key: None,
+                if_match: None,
+                query_params: Vec::new(),
+                raw_body: None,
             }
         }
         #[doc = "Update a managed HSM Pool in the specified subscription."]
@@ -1837,6 +4113,13 @@ pub mod managed_hsms {
                 name: name.into(),
                 parameters: parameters.into(),
                 subscription_id: subscription_id.into(),
+                claims: None,
+                accept_language: None,
+                if_match: None,
+                idempotent: false,
+                prefer_representation: false,
+                query_params: Vec::new(),
+                raw_body: None,
             }
         }
         #[doc = "Deletes the specified managed HSM Pool."]
@@ -1856,6 +4139,9 @@ pub mod managed_hsms {
                 resource_group_name: resource_group_name.into(),
                 name: name.into(),
                 subscription_id: subscription_id.into(),
+                claims: None,
+                accept_language: None,
+                query_params: Vec::new(),
             }
         }
         #[doc = "The List operation gets information about the managed HSM Pools associated with the subscription and within the specified resource group."]
@@ -1873,6 +4159,13 @@ pub mod managed_hsms {
                 resource_group_name: resource_group_name.into(),
                 subscription_id: subscription_id.into(),
                 top: None,
+                claims: None,
+                accept_language: None,
+                query_params: Vec::new(),
+                select: None,
+                max_items: None,
+                on_missing_empty: false,
+                resume_from: None,
             }
         }
         #[doc = "The List operation gets information about the managed HSM Pools associated with the subscription."]
@@ -1884,6 +4177,13 @@ pub mod managed_hsms {
                 client: self.0.clone(),
                 subscription_id: subscription_id.into(),
                 top: None,
+                claims: None,
+                accept_language: None,
+                query_params: Vec::new(),
+                select: None,
+                max_items: None,
+                on_missing_empty: false,
+                resume_from: None,
             }
         }
         #[doc = "The List operation gets information about the deleted managed HSMs associated with the subscription."]
@@ -1894,6 +4194,13 @@ pub mod managed_hsms {
             list_deleted::RequestBuilder {
                 client: self.0.clone(),
                 subscription_id: subscription_id.into(),
+                claims: None,
+                accept_language: None,
+                query_params: Vec::new(),
+                select: None,
+                max_items: None,
+                on_missing_empty: false,
+                resume_from: None,
             }
         }
         #[doc = "Gets the specified deleted managed HSM."]
@@ -1913,6 +4220,9 @@ pub mod managed_hsms {
                 name: name.into(),
                 location: location.into(),
                 subscription_id: subscription_id.into(),
+                claims: None,
+                accept_language: None,
+                query_params: Vec::new(),
             }
         }
         #[doc = "Permanently deletes the specified managed HSM."]
@@ -1932,6 +4242,10 @@ pub mod managed_hsms {
                 name: name.into(),
                 location: location.into(),
                 subscription_id: subscription_id.into(),
+                claims: None,
+                accept_language: None,
+                idempotent: false,
+                query_params: Vec::new(),
             }
         }
     }
@@ -1940,16 +4254,72 @@ pub mod managed_hsms {
         pub struct Response(azure_core::Response);
         impl Response {
             pub async fn into_body(self) -> azure_core::Result<models::ManagedHsm> {
-                let bytes = self.0.into_body().collect().await?;
-                let body: models::ManagedHsm = serde_json::from_slice(&bytes)?;
+                if self.is_not_modified() {
+                    return Err(azure_core::error::ErrorKind::HttpResponse {
+                        status: azure_core::StatusCode::NotModified,
+                        error_code: None,
+                    }
+                    .into_error());
+                }
+                let status = self.0.status();
+                let bytes = self.into_byte_stream().collect().await?;
+                let body: models::ManagedHsm = azure_core::client::from_json_body("managed_hsms::get", status, &bytes)?;
+                Ok(body)
+            }
+            #[doc = "Return the response body as untyped JSON; see [responses](crate::package_preview_2021_04#responses)."]
+            pub async fn into_value(self) -> azure_core::Result<serde_json::Value> {
+                if self.is_not_modified() {
+                    return Err(azure_core::error::ErrorKind::HttpResponse {
+                        status: azure_core::StatusCode::NotModified,
+                        error_code: None,
+                    }
+                    .into_error());
+                }
+                let status = self.0.status();
+                let bytes = self.into_byte_stream().collect().await?;
+                let body: serde_json::Value = azure_core::client::from_json_body("managed_hsms::get", status, &bytes)?;
                 Ok(body)
             }
+            #[doc = "Whether the service answered a request sent with `if_none_match` with `304 Not Modified`. Such a response has no body, but carries the headers the service sent, so `concurrency_token`, `client_request_id` and `elapsed` still report them."]
+            #[doc = ""]
+            #[doc = "Derived from the status code alone, so polling loops can count unchanged responses against transferred bodies to track their cache hit rate."]
+            pub fn is_not_modified(&self) -> bool {
+                self.0.status() == azure_core::StatusCode::NotModified
+            }
+            #[doc = "Stream the response body in chunks as they are received, instead of buffering all of it like `into_body`."]
+            pub fn into_byte_stream(self) -> azure_core::ResponseBody {
+                self.0.into_body()
+            }
+            pub fn status(&self) -> azure_core::StatusCode {
+                self.0.status()
+            }
             pub fn into_raw_response(self) -> azure_core::Response {
                 self.0
             }
+            #[doc = "Read the whole response and return its status, headers and body as received, for forwarding it verbatim without going through `models`."]
+            pub async fn into_raw_parts(self) -> azure_core::Result<(azure_core::StatusCode, azure_core::headers::Headers, bytes::Bytes)> {
+                let (status, headers, body) = self.0.deconstruct();
+                Ok((status, headers, body.collect().await?))
+            }
             pub fn as_raw_response(&self) -> &azure_core::Response {
                 &self.0
             }
+            #[doc = "The remaining request quota; see [responses](crate::package_preview_2021_04#responses)."]
+            pub fn rate_limit_remaining(&self) -> Option<u32> {
+                azure_core::client::rate_limit_remaining(self.0.headers())
+            }
+            #[doc = "The `x-ms-client-request-id` the request was sent with; see [responses](crate::package_preview_2021_04#responses)."]
+            pub fn client_request_id(&self) -> Option<&str> {
+                self.0.headers().get_optional_str(&azure_core::headers::CLIENT_REQUEST_ID)
+            }
+            #[doc = "How long the request took; see [responses](crate::package_preview_2021_04#responses)."]
+            pub fn elapsed(&self) -> std::time::Duration {
+                self.0.elapsed().unwrap_or_default()
+            }
+            #[doc = "The resource's ETag; see [responses](crate::package_preview_2021_04#responses)."]
+            pub fn concurrency_token(&self) -> Option<super::super::ConcurrencyToken> {
+                super::super::ConcurrencyToken::from_headers(self.0.headers())
+            }
         }
         impl From<Response> for azure_core::Response {
             fn from(rsp: Response) -> Self {
@@ -1967,40 +4337,116 @@ pub mod managed_hsms {
             pub(crate) resource_group_name: String,
             pub(crate) name: String,
             pub(crate) subscription_id: String,
+            pub(crate) claims: Option<String>,
+            pub(crate) accept_language: Option<String>,
+            pub(crate) if_none_match: Option<String>,
+            pub(crate) query_params: Vec<(String, String)>,
+        }
+        impl super::super::private::Sealed for RequestBuilder {}
+        impl super::super::Operation for RequestBuilder {
+            fn method() -> azure_core::Method {
+                azure_core::Method::Get
+            }
+            fn path_template() -> &'static str {
+                "/subscriptions/{subscriptionId}/resourceGroups/{resourceGroupName}/providers/Microsoft.KeyVault/managedHSMs/{name}"
+            }
+        }
+        impl super::super::BatchRead for RequestBuilder {
+            fn batch_url(&self) -> azure_core::Result<azure_core::Url> {
+                self.url()
+            }
         }
         impl RequestBuilder {
+            #[doc = "Ask the credential for a token that satisfies the JSON `claims`; see [claims](crate::package_preview_2021_04#claims)."]
+            pub fn claims(mut self, claims: impl Into<String>) -> Self {
+                self.claims = Some(claims.into());
+                self
+            }
+            #[doc = "Send this request with `accept_language` as its `Accept-Language` header, such as `fr-FR`, overriding `ClientBuilder::accept_language`."]
+            pub fn accept_language(mut self, accept_language: impl Into<String>) -> Self {
+                self.accept_language = Some(accept_language.into());
+                self
+            }
+            #[doc = "Only return the resource if its ETag does not match `if_none_match`. Otherwise the response has status `304 Not Modified` and no body."]
+            pub fn if_none_match(mut self, if_none_match: impl Into<String>) -> Self {
+                self.if_none_match = Some(if_none_match.into());
+                self
+            }
+            #[doc = "Append `key=value` to the query string; see [query parameters](crate::package_preview_2021_04#query-parameters)."]
+            pub fn query_param(mut self, key: impl Into<String>, value: impl Into<String>) -> Self {
+                self.query_params.push((key.into(), value.into()));
+                self
+            }
+            #[doc = "Build the URL the request is sent to, including the api-version and any query parameters set on the builder, without sending the request."]
+            pub fn url(&self) -> azure_core::Result<azure_core::Url> {
+                let mut url = azure_core::Url::parse(&format!(
+                    "{}/subscriptions/{}/resourceGroups/{}/providers/Microsoft.KeyVault/managedHSMs/{}",
+                    self.client.endpoint(),
+                    &azure_core::client::path_segment("subscription_id", &self.subscription_id)?,
+                    &azure_core::client::path_segment("resource_group_name", &self.resource_group_name)?,
+                    &azure_core::client::path_segment("name", &self.name)?
+                ))?;
+                url.query_pairs_mut()
+                    .append_pair(azure_core::query_param::API_VERSION, "2021-04-01-preview");
+                azure_core::client::append_query_params(&mut url, &self.query_params);
+                Ok(url)
+            }
             #[doc = "Send the request and returns the response."]
-            pub fn send(self) -> futures::future::BoxFuture<'static, azure_core::Result<Response>> {
-                Box::pin({
-                    let this = self.clone();
-                    async move {
-                        let url = azure_core::Url::parse(&format!(
-                            "{}/subscriptions/{}/resourceGroups/{}/providers/Microsoft.KeyVault/managedHSMs/{}",
-                            this.client.endpoint(),
-                            &this.subscription_id,
-                            &this.resource_group_name,
-                            &this.name
-                        ))?;
-                        let mut req = azure_core::Request::new(url, azure_core::Method::Get);
-                        let credential = this.client.token_credential();
-                        let token_response = credential.get_token(&this.client.scopes().join(" ")).await?;
-                        req.insert_header(
-                            azure_core::headers::AUTHORIZATION,
-                            format!("Bearer {}", token_response.token.secret()),
-                        );
-                        req.url_mut()
-                            .query_pairs_mut()
-                            .append_pair(azure_core::query_param::API_VERSION, "2021-04-01-preview");
-                        let req_body = azure_core::EMPTY_BODY;
-                        req.set_body(req_body);
-                        Ok(Response(this.client.send(&mut req).await?))
-                    }
-                })
+            #[doc = ""]
+            #[doc = "The builder is not consumed, so a polling loop can build it once and call `send` on every iteration; each call fetches a fresh token. The returned future is `'static` because it owns a clone of the builder, so it can be spawned or stored."]
+            pub fn send(&self) -> futures::future::BoxFuture<'static, azure_core::Result<Response>> {
+                let this = self.clone();
+                Box::pin(async move { this.send_ref().await })
+            }
+            #[doc = "Send the request without consuming the builder."]
+            #[doc = ""]
+            #[doc = "Unlike `send`, which clones the builder so that its future is `'static`, the request is built from borrowed fields, so a builder kept across calls, such as in a polling loop, is sent again without being cloned. The future borrows the builder in turn."]
+            pub async fn send_ref(&self) -> azure_core::Result<Response> {
+                let url = self.url()?;
+                let mut req = azure_core::Request::new(url, azure_core::Method::Get);
+                let token_response = self.client.token(self.claims.as_deref()).await?;
+                req.insert_header(
+                    azure_core::headers::AUTHORIZATION,
+                    format!("Bearer {}", token_response.token.secret()),
+                );
+                if let Some(accept_language) = &self.accept_language {
+                    req.insert_header(azure_core::headers::ACCEPT_LANGUAGE, accept_language.clone());
+                }
+                if let Some(if_none_match) = &self.if_none_match {
+                    req.insert_header(azure_core::headers::IF_NONE_MATCH, if_none_match);
+                }
+                let req_body = azure_core::EMPTY_BODY;
+                req.set_body(req_body);
+                Ok(Response(self.client.send(&mut req, "managed_hsms::get").await?))
             }
             #[doc = "Send the request and return the response body."]
             pub fn into_future(self) -> futures::future::BoxFuture<'static, azure_core::Result<models::ManagedHsm>> {
                 Box::pin(async move { self.send().await?.into_body().await })
             }
+            #[doc = "Send the request and return the response body together with the response headers, such as the `ETag`."]
+            pub fn into_future_with_headers(
+                self,
+            ) -> futures::future::BoxFuture<'static, azure_core::Result<(models::ManagedHsm, azure_core::headers::Headers)>> {
+                Box::pin(async move {
+                    let rsp = self.send().await?;
+                    let headers = rsp.as_raw_response().headers().clone();
+                    Ok((rsp.into_body().await?, headers))
+                })
+            }
+            #[doc = "Send the request and return the response body, or `None` if the resource does not exist."]
+            pub fn get_if_exists(self) -> futures::future::BoxFuture<'static, azure_core::Result<Option<models::ManagedHsm>>> {
+                Box::pin(async move {
+                    match azure_core::client::not_found_as_none(self.send().await)? {
+                        Some(rsp) => Ok(Some(rsp.into_body().await?)),
+                        None => Ok(None),
+                    }
+                })
+            }
+            #[doc = "Send the request and return the response body, blocking the current thread."]
+            #[cfg(feature = "blocking")]
+            pub fn call(self) -> azure_core::Result<models::ManagedHsm> {
+                azure_core::blocking::block_on(self.into_future())
+            }
         }
     }
     pub mod create_or_update {
@@ -2008,16 +4454,48 @@ pub mod managed_hsms {
         pub struct Response(azure_core::Response);
         impl Response {
             pub async fn into_body(self) -> azure_core::Result<models::ManagedHsm> {
-                let bytes = self.0.into_body().collect().await?;
-                let body: models::ManagedHsm = serde_json::from_slice(&bytes)?;
+                let status = self.0.status();
+                let bytes = self.into_byte_stream().collect().await?;
+                let body: models::ManagedHsm = azure_core::client::from_json_body("managed_hsms::create_or_update", status, &bytes)?;
+                Ok(body)
+            }
+            #[doc = "Return the response body as untyped JSON; see [responses](crate::package_preview_2021_04#responses)."]
+            pub async fn into_value(self) -> azure_core::Result<serde_json::Value> {
+                let status = self.0.status();
+                let bytes = self.into_byte_stream().collect().await?;
+                let body: serde_json::Value = azure_core::client::from_json_body("managed_hsms::create_or_update", status, &bytes)?;
                 Ok(body)
             }
+            #[doc = "Stream the response body in chunks as they are received, instead of buffering all of it like `into_body`."]
+            pub fn into_byte_stream(self) -> azure_core::ResponseBody {
+                self.0.into_body()
+            }
+            pub fn status(&self) -> azure_core::StatusCode {
+                self.0.status()
+            }
             pub fn into_raw_response(self) -> azure_core::Response {
                 self.0
             }
+            #[doc = "Read the whole response and return its status, headers and body as received, for forwarding it verbatim without going through `models`."]
+            pub async fn into_raw_parts(self) -> azure_core::Result<(azure_core::StatusCode, azure_core::headers::Headers, bytes::Bytes)> {
+                let (status, headers, body) = self.0.deconstruct();
+                Ok((status, headers, body.collect().await?))
+            }
             pub fn as_raw_response(&self) -> &azure_core::Response {
                 &self.0
             }
+            #[doc = "The remaining request quota; see [responses](crate::package_preview_2021_04#responses)."]
+            pub fn rate_limit_remaining(&self) -> Option<u32> {
+                azure_core::client::rate_limit_remaining(self.0.headers())
+            }
+            #[doc = "The `x-ms-client-request-id` the request was sent with; see [responses](crate::package_preview_2021_04#responses)."]
+            pub fn client_request_id(&self) -> Option<&str> {
+                self.0.headers().get_optional_str(&azure_core::headers::CLIENT_REQUEST_ID)
+            }
+            #[doc = "How long the request took; see [responses](crate::package_preview_2021_04#responses)."]
+            pub fn elapsed(&self) -> std::time::Duration {
+                self.0.elapsed().unwrap_or_default()
+            }
         }
         impl From<Response> for azure_core::Response {
             fn from(rsp: Response) -> Self {
@@ -2036,42 +4514,148 @@ pub mod managed_hsms {
             pub(crate) name: String,
             pub(crate) parameters: models::ManagedHsm,
             pub(crate) subscription_id: String,
+            pub(crate) claims: Option<String>,
+            pub(crate) accept_language: Option<String>,
+            pub(crate) idempotency_key: Option<(String, time::OffsetDateTime)>,
+            pub(crate) if_match: Option<super::super::ConcurrencyToken>,
+            pub(crate) query_params: Vec<(String, String)>,
+            pub(crate) raw_body: Option<(bytes::Bytes, String)>,
+        }
+        impl super::super::private::Sealed for RequestBuilder {}
+        impl super::super::Operation for RequestBuilder {
+            fn method() -> azure_core::Method {
+                azure_core::Method::Put
+            }
+            fn path_template() -> &'static str {
+                "/subscriptions/{subscriptionId}/resourceGroups/{resourceGroupName}/providers/Microsoft.KeyVault/managedHSMs/{name}"
+            }
         }
         impl RequestBuilder {
+            #[doc = "Ask the credential for a token that satisfies the JSON `claims`; see [claims](crate::package_preview_2021_04#claims)."]
+            pub fn claims(mut self, claims: impl Into<String>) -> Self {
+                self.claims = Some(claims.into());
+                self
+            }
+            #[doc = "Send this request with `accept_language` as its `Accept-Language` header, such as `fr-FR`, overriding `ClientBuilder::accept_language`."]
+            pub fn accept_language(mut self, accept_language: impl Into<String>) -> Self {
+                self.accept_language = Some(accept_language.into());
+                self
+            }
+            #[doc = "Send `key` in the `Repeatability-Request-ID` header, so that a service that deduplicates requests can recognize a retry of this one."]
+            #[doc = ""]
+            #[doc = "A service that does not deduplicate requests ignores the header, so a retry may still create the resource twice."]
+            #[doc = ""]
+            #[doc = "The time of this call is sent in `Repeatability-First-Sent` with every attempt, including retries by the pipeline and repeated calls of `send`; set a new key for a new request."]
+            pub fn idempotency_key(mut self, key: impl Into<String>) -> Self {
+                self.idempotency_key = Some((key.into(), time::OffsetDateTime::now_utc()));
+                self
+            }
+            #[doc = "Only apply the change if the resource still has the ETag of `token`, as read with `get`."]
+            #[doc = ""]
+            #[doc = "If the resource was changed in the meantime, the service answers `412 Precondition Failed` and the request fails with a `PreconditionFailed` error carrying the resource's current ETag, so an optimistic concurrency loop can read it again and retry."]
+            pub fn if_match(mut self, token: super::super::ConcurrencyToken) -> Self {
+                self.if_match = Some(token);
+                self
+            }
+            #[doc = "Append `key=value` to the query string; see [query parameters](crate::package_preview_2021_04#query-parameters)."]
+            pub fn query_param(mut self, key: impl Into<String>, value: impl Into<String>) -> Self {
+                self.query_params.push((key.into(), value.into()));
+                self
+            }
+            #[doc = "Send `body` as the JSON request body instead of the typed parameters, for fields the `models` type does not have yet."]
+            #[doc = ""]
+            #[doc = "Shorthand for `raw_body` with `content-type: application/json`; whichever of the two is called last wins."]
+            pub fn raw_json_body(self, body: serde_json::Value) -> Self {
+                self.raw_body(body.to_string(), "application/json")
+            }
+            #[doc = "Send `body` with the given `content-type` instead of the typed parameters."]
+            #[doc = ""]
+            #[doc = "The typed request body is not sent at all, so this is a stopgap for service changes the `models` types do not cover yet."]
+            pub fn raw_body(mut self, body: impl Into<bytes::Bytes>, content_type: impl Into<String>) -> Self {
+                self.raw_body = Some((body.into(), content_type.into()));
+                self
+            }
+            #[doc = "Build the URL the request is sent to, including the api-version and any query parameters set on the builder, without sending the request."]
+            pub fn url(&self) -> azure_core::Result<azure_core::Url> {
+                let mut url = azure_core::Url::parse(&format!(
+                    "{}/subscriptions/{}/resourceGroups/{}/providers/Microsoft.KeyVault/managedHSMs/{}",
+                    self.client.endpoint(),
+                    &azure_core::client::path_segment("subscription_id", &self.subscription_id)?,
+                    &azure_core::client::path_segment("resource_group_name", &self.resource_group_name)?,
+                    &azure_core::client::path_segment("name", &self.name)?
+                ))?;
+                url.query_pairs_mut()
+                    .append_pair(azure_core::query_param::API_VERSION, "2021-04-01-preview");
+                azure_core::client::append_query_params(&mut url, &self.query_params);
+                Ok(url)
+            }
             #[doc = "only the first response will be fetched as long running operations are not supported yet"]
             #[doc = "Send the request and returns the response."]
-            pub fn send(self) -> futures::future::BoxFuture<'static, azure_core::Result<Response>> {
-                Box::pin({
-                    let this = self.clone();
-                    async move {
-                        let url = azure_core::Url::parse(&format!(
-                            "{}/subscriptions/{}/resourceGroups/{}/providers/Microsoft.KeyVault/managedHSMs/{}",
-                            this.client.endpoint(),
-                            &this.subscription_id,
-                            &this.resource_group_name,
-                            &this.name
-                        ))?;
-                        let mut req = azure_core::Request::new(url, azure_core::Method::Put);
-                        let credential = this.client.token_credential();
-                        let token_response = credential.get_token(&this.client.scopes().join(" ")).await?;
-                        req.insert_header(
-                            azure_core::headers::AUTHORIZATION,
-                            format!("Bearer {}", token_response.token.secret()),
-                        );
-                        req.url_mut()
-                            .query_pairs_mut()
-                            .append_pair(azure_core::query_param::API_VERSION, "2021-04-01-preview");
-                        req.insert_header("content-type", "application/json");
-                        let req_body = azure_core::to_json(&this.parameters)?;
-                        req.set_body(req_body);
-                        Ok(Response(this.client.send(&mut req).await?))
+            #[doc = ""]
+            #[doc = "The builder is not consumed, so a polling loop can build it once and call `send` on every iteration; each call fetches a fresh token. The returned future is `'static` because it owns a clone of the builder, so it can be spawned or stored."]
+            pub fn send(&self) -> futures::future::BoxFuture<'static, azure_core::Result<Response>> {
+                let this = self.clone();
+                Box::pin(async move { this.send_ref().await })
+            }
+            #[doc = "Send the request without consuming the builder."]
+            #[doc = ""]
+            #[doc = "Unlike `send`, which clones the builder so that its future is `'static`, the request is built from borrowed fields, so a builder kept across calls, such as in a polling loop, is sent again without being cloned. The future borrows the builder in turn."]
+            pub async fn send_ref(&self) -> azure_core::Result<Response> {
+                let url = self.url()?;
+                let mut req = azure_core::Request::new(url, azure_core::Method::Put);
+                let token_response = self.client.token(self.claims.as_deref()).await?;
+                req.insert_header(
+                    azure_core::headers::AUTHORIZATION,
+                    format!("Bearer {}", token_response.token.secret()),
+                );
+                if let Some(accept_language) = &self.accept_language {
+                    req.insert_header(azure_core::headers::ACCEPT_LANGUAGE, accept_language.clone());
+                }
+                if let Some((key, first_sent)) = &self.idempotency_key {
+                    req.insert_header(azure_core::headers::REPEATABILITY_REQUEST_ID, key.clone());
+                    req.insert_header(
+                        azure_core::headers::REPEATABILITY_FIRST_SENT,
+                        azure_core::date::to_rfc1123(first_sent),
+                    );
+                }
+                if let Some(if_match) = &self.if_match {
+                    req.insert_header(azure_core::headers::IF_MATCH, if_match.etag().to_owned());
+                }
+                req.insert_header("content-type", "application/json");
+                let req_body = match &self.raw_body {
+                    Some((body, content_type)) => {
+                        req.insert_header("content-type", content_type.clone());
+                        body.clone()
                     }
-                })
+                    None => azure_core::to_json(&self.parameters)?,
+                };
+                req.set_body(req_body);
+                Ok(Response(
+                    self.client
+                        .send(&mut req, "managed_hsms::create_or_update")
+                        .await
+                        .map_err(azure_core::concurrency::precondition_failed)?,
+                ))
             }
             #[doc = "Send the request and return the response body."]
             pub fn into_future(self) -> futures::future::BoxFuture<'static, azure_core::Result<models::ManagedHsm>> {
                 Box::pin(async move { self.send().await?.into_body().await })
             }
+            #[doc = "Send the request and return the response body together with the response headers, such as the `ETag`."]
+            pub fn into_future_with_headers(
+                self,
+            ) -> futures::future::BoxFuture<'static, azure_core::Result<(models::ManagedHsm, azure_core::headers::Headers)>> {
+                Box::pin(async move {
+                    let rsp = self.send().await?;
+                    let headers = rsp.as_raw_response().headers().clone();
+                    Ok((rsp.into_body().await?, headers))
+                })
+            }
+            #[doc = "Send the request and return the response body, blocking the current thread."]
+            #[cfg(feature = "blocking")]
+            pub fn call(self) -> azure_core::Result<models::ManagedHsm> {
+                azure_core::blocking::block_on(self.into_future())
+            }
         }
     }
     pub mod update {
@@ -2079,16 +4663,48 @@ pub mod managed_hsms {
         pub struct Response(azure_core::Response);
         impl Response {
             pub async fn into_body(self) -> azure_core::Result<models::ManagedHsm> {
-                let bytes = self.0.into_body().collect().await?;
-                let body: models::ManagedHsm = serde_json::from_slice(&bytes)?;
+                let status = self.0.status();
+                let bytes = self.into_byte_stream().collect().await?;
+                let body: models::ManagedHsm = azure_core::client::from_json_body("managed_hsms::update", status, &bytes)?;
+                Ok(body)
+            }
+            #[doc = "Return the response body as untyped JSON; see [responses](crate::package_preview_2021_04#responses)."]
+            pub async fn into_value(self) -> azure_core::Result<serde_json::Value> {
+                let status = self.0.status();
+                let bytes = self.into_byte_stream().collect().await?;
+                let body: serde_json::Value = azure_core::client::from_json_body("managed_hsms::update", status, &bytes)?;
                 Ok(body)
             }
+            #[doc = "Stream the response body in chunks as they are received, instead of buffering all of it like `into_body`."]
+            pub fn into_byte_stream(self) -> azure_core::ResponseBody {
+                self.0.into_body()
+            }
+            pub fn status(&self) -> azure_core::StatusCode {
+                self.0.status()
+            }
             pub fn into_raw_response(self) -> azure_core::Response {
                 self.0
             }
+            #[doc = "Read the whole response and return its status, headers and body as received, for forwarding it verbatim without going through `models`."]
+            pub async fn into_raw_parts(self) -> azure_core::Result<(azure_core::StatusCode, azure_core::headers::Headers, bytes::Bytes)> {
+                let (status, headers, body) = self.0.deconstruct();
+                Ok((status, headers, body.collect().await?))
+            }
             pub fn as_raw_response(&self) -> &azure_core::Response {
                 &self.0
             }
+            #[doc = "The remaining request quota; see [responses](crate::package_preview_2021_04#responses)."]
+            pub fn rate_limit_remaining(&self) -> Option<u32> {
+                azure_core::client::rate_limit_remaining(self.0.headers())
+            }
+            #[doc = "The `x-ms-client-request-id` the request was sent with; see [responses](crate::package_preview_2021_04#responses)."]
+            pub fn client_request_id(&self) -> Option<&str> {
+                self.0.headers().get_optional_str(&azure_core::headers::CLIENT_REQUEST_ID)
+            }
+            #[doc = "How long the request took; see [responses](crate::package_preview_2021_04#responses)."]
+            pub fn elapsed(&self) -> std::time::Duration {
+                self.0.elapsed().unwrap_or_default()
+            }
             pub fn headers(&self) -> Headers {
                 Headers(self.0.headers())
             }
@@ -2117,83 +4733,280 @@ pub mod managed_hsms {
             pub(crate) name: String,
             pub(crate) parameters: models::ManagedHsm,
             pub(crate) subscription_id: String,
+            pub(crate) claims: Option<String>,
+            pub(crate) accept_language: Option<String>,
+            pub(crate) if_match: Option<super::super::ConcurrencyToken>,
+            pub(crate) idempotent: bool,
+            pub(crate) prefer_representation: bool,
+            pub(crate) query_params: Vec<(String, String)>,
+            pub(crate) raw_body: Option<(bytes::Bytes, String)>,
+        }
+        impl super::super::private::Sealed for RequestBuilder {}
+        impl super::super::Operation for RequestBuilder {
+            fn method() -> azure_core::Method {
+                azure_core::Method::Patch
+            }
+            fn path_template() -> &'static str {
+                "/subscriptions/{subscriptionId}/resourceGroups/{resourceGroupName}/providers/Microsoft.KeyVault/managedHSMs/{name}"
+            }
         }
         impl RequestBuilder {
+            #[doc = "Ask the credential for a token that satisfies the JSON `claims`; see [claims](crate::package_preview_2021_04#claims)."]
+            pub fn claims(mut self, claims: impl Into<String>) -> Self {
+                self.claims = Some(claims.into());
+                self
+            }
+            #[doc = "Send this request with `accept_language` as its `Accept-Language` header, such as `fr-FR`, overriding `ClientBuilder::accept_language`."]
+            pub fn accept_language(mut self, accept_language: impl Into<String>) -> Self {
+                self.accept_language = Some(accept_language.into());
+                self
+            }
+            #[doc = "Only apply the change if the resource still has the ETag of `token`, as read with `get`."]
+            #[doc = ""]
+            #[doc = "If the resource was changed in the meantime, the service answers `412 Precondition Failed` and the request fails with a `PreconditionFailed` error carrying the resource's current ETag, so an optimistic concurrency loop can read it again and retry."]
+            pub fn if_match(mut self, token: super::super::ConcurrencyToken) -> Self {
+                self.if_match = Some(token);
+                self
+            }
+            #[doc = "Mark the request as safe to repeat, so the retry policy retries it after a connection error."]
+            #[doc = ""]
+            #[doc = "This request is not idempotent by default, and repeating it may repeat its side effect, so it is not retried after a connection error or timed out attempt unless this is set."]
+            pub fn idempotent(mut self, idempotent: bool) -> Self {
+                self.idempotent = idempotent;
+                self
+            }
+            #[doc = "Ask the service to answer with the full updated resource by sending `Prefer: return=representation`."]
+            #[doc = ""]
+            #[doc = "Saves a follow-up `get` when the service would otherwise answer with a minimal body. Off by default; the service may ignore the preference."]
+            pub fn prefer_representation(mut self, prefer_representation: bool) -> Self {
+                self.prefer_representation = prefer_representation;
+                self
+            }
+            #[doc = "Append `key=value` to the query string; see [query parameters](crate::package_preview_2021_04#query-parameters)."]
+            pub fn query_param(mut self, key: impl Into<String>, value: impl Into<String>) -> Self {
+                self.query_params.push((key.into(), value.into()));
+                self
+            }
+            #[doc = "Send `body` as the JSON request body instead of the typed parameters, for fields the `models` type does not have yet."]
+            #[doc = ""]
+            #[doc = "Shorthand for `raw_body` with `content-type: application/json`; whichever of the two is called last wins."]
+            pub fn raw_json_body(self, body: serde_json::Value) -> Self {
+                self.raw_body(body.to_string(), "application/json")
+            }
+            #[doc = "Send `body` with the given `content-type` instead of the typed parameters."]
+            #[doc = ""]
+            #[doc = "The typed request body is not sent at all, so this is a stopgap for service changes the `models` types do not cover yet."]
+            pub fn raw_body(mut self, body: impl Into<bytes::Bytes>, content_type: impl Into<String>) -> Self {
+                self.raw_body = Some((body.into(), content_type.into()));
+                self
+            }
+            #[doc = "Build the URL the request is sent to, including the api-version and any query parameters set on the builder, without sending the request."]
+            pub fn url(&self) -> azure_core::Result<azure_core::Url> {
+                let mut url = azure_core::Url::parse(&format!(
+                    "{}/subscriptions/{}/resourceGroups/{}/providers/Microsoft.KeyVault/managedHSMs/{}",
+                    self.client.endpoint(),
+                    &azure_core::client::path_segment("subscription_id", &self.subscription_id)?,
+                    &azure_core::client::path_segment("resource_group_name", &self.resource_group_name)?,
+                    &azure_core::client::path_segment("name", &self.name)?
+                ))?;
+                url.query_pairs_mut()
+                    .append_pair(azure_core::query_param::API_VERSION, "2021-04-01-preview");
+                azure_core::client::append_query_params(&mut url, &self.query_params);
+                Ok(url)
+            }
             #[doc = "only the first response will be fetched as long running operations are not supported yet"]
             #[doc = "Send the request and returns the response."]
-            pub fn send(self) -> futures::future::BoxFuture<'static, azure_core::Result<Response>> {
-                Box::pin({
-                    let this = self.clone();
-                    async move {
-                        let url = azure_core::Url::parse(&format!(
-                            "{}/subscriptions/{}/resourceGroups/{}/providers/Microsoft.KeyVault/managedHSMs/{}",
-                            this.client.endpoint(),
-                            &this.subscription_id,
-                            &this.resource_group_name,
-                            &this.name
-                        ))?;
-                        let mut req = azure_core::Request::new(url, azure_core::Method::Patch);
-                        let credential = this.client.token_credential();
-                        let token_response = credential.get_token(&this.client.scopes().join(" ")).await?;
-                        req.insert_header(
-                            azure_core::headers::AUTHORIZATION,
-                            format!("Bearer {}", token_response.token.secret()),
-                        );
-                        req.url_mut()
-                            .query_pairs_mut()
-                            .append_pair(azure_core::query_param::API_VERSION, "2021-04-01-preview");
-                        req.insert_header("content-type", "application/json");
-                        let req_body = azure_core::to_json(&this.parameters)?;
-                        req.set_body(req_body);
-                        Ok(Response(this.client.send(&mut req).await?))
+            #[doc = ""]
+            #[doc = "The builder is not consumed, so a polling loop can build it once and call `send` on every iteration; each call fetches a fresh token. The returned future is `'static` because it owns a clone of the builder, so it can be spawned or stored."]
+            pub fn send(&self) -> futures::future::BoxFuture<'static, azure_core::Result<Response>> {
+                let this = self.clone();
+                Box::pin(async move { this.send_ref().await })
+            }
+            #[doc = "Send the request without consuming the builder."]
+            #[doc = ""]
+            #[doc = "Unlike `send`, which clones the builder so that its future is `'static`, the request is built from borrowed fields, so a builder kept across calls, such as in a polling loop, is sent again without being cloned. The future borrows the builder in turn."]
+            pub async fn send_ref(&self) -> azure_core::Result<Response> {
+                let url = self.url()?;
+                let mut req = azure_core::Request::new(url, azure_core::Method::Patch);
+                let token_response = self.client.token(self.claims.as_deref()).await?;
+                req.insert_header(
+                    azure_core::headers::AUTHORIZATION,
+                    format!("Bearer {}", token_response.token.secret()),
+                );
+                if let Some(accept_language) = &self.accept_language {
+                    req.insert_header(azure_core::headers::ACCEPT_LANGUAGE, accept_language.clone());
+                }
+                if let Some(if_match) = &self.if_match {
+                    req.insert_header(azure_core::headers::IF_MATCH, if_match.etag().to_owned());
+                }
+                req.insert_header("content-type", "application/json");
+                if self.prefer_representation {
+                    req.insert_header(azure_core::headers::PREFER, "return=representation");
+                }
+                let req_body = match &self.raw_body {
+                    Some((body, content_type)) => {
+                        req.insert_header("content-type", content_type.clone());
+                        body.clone()
                     }
-                })
+                    None => azure_core::to_json(&self.parameters)?,
+                };
+                req.set_body(req_body);
+                let mut context = azure_core::Context::new();
+                if self.idempotent {
+                    context.insert(azure_core::Idempotent);
+                }
+                Ok(Response(
+                    self.client
+                        .send_with_context(&mut req, "managed_hsms::update", context)
+                        .await
+                        .map_err(azure_core::concurrency::precondition_failed)?,
+                ))
             }
             #[doc = "Send the request and return the response body."]
             pub fn into_future(self) -> futures::future::BoxFuture<'static, azure_core::Result<models::ManagedHsm>> {
                 Box::pin(async move { self.send().await?.into_body().await })
             }
+            #[doc = "Send the request and return the response body together with the response headers, such as the `ETag`."]
+            pub fn into_future_with_headers(
+                self,
+            ) -> futures::future::BoxFuture<'static, azure_core::Result<(models::ManagedHsm, azure_core::headers::Headers)>> {
+                Box::pin(async move {
+                    let rsp = self.send().await?;
+                    let headers = rsp.as_raw_response().headers().clone();
+                    Ok((rsp.into_body().await?, headers))
+                })
+            }
+            #[doc = "Send the request and return the response body, blocking the current thread."]
+            #[cfg(feature = "blocking")]
+            pub fn call(self) -> azure_core::Result<models::ManagedHsm> {
+                azure_core::blocking::block_on(self.into_future())
+            }
         }
     }
     pub mod delete {
         use super::models;
         pub struct Response(azure_core::Response);
+        impl Response {
+            #[doc = "Stream the response body in chunks as they are received, instead of buffering all of it like `into_body`."]
+            pub fn into_byte_stream(self) -> azure_core::ResponseBody {
+                self.0.into_body()
+            }
+            pub fn status(&self) -> azure_core::StatusCode {
+                self.0.status()
+            }
+            pub fn into_raw_response(self) -> azure_core::Response {
+                self.0
+            }
+            #[doc = "Read the whole response and return its status, headers and body as received, for forwarding it verbatim without going through `models`."]
+            pub async fn into_raw_parts(self) -> azure_core::Result<(azure_core::StatusCode, azure_core::headers::Headers, bytes::Bytes)> {
+                let (status, headers, body) = self.0.deconstruct();
+                Ok((status, headers, body.collect().await?))
+            }
+            pub fn as_raw_response(&self) -> &azure_core::Response {
+                &self.0
+            }
+            #[doc = "The remaining request quota; see [responses](crate::package_preview_2021_04#responses)."]
+            pub fn rate_limit_remaining(&self) -> Option<u32> {
+                azure_core::client::rate_limit_remaining(self.0.headers())
+            }
+            #[doc = "The `x-ms-client-request-id` the request was sent with; see [responses](crate::package_preview_2021_04#responses)."]
+            pub fn client_request_id(&self) -> Option<&str> {
+                self.0.headers().get_optional_str(&azure_core::headers::CLIENT_REQUEST_ID)
+            }
+            #[doc = "How long the request took; see [responses](crate::package_preview_2021_04#responses)."]
+            pub fn elapsed(&self) -> std::time::Duration {
+                self.0.elapsed().unwrap_or_default()
+            }
+        }
+        impl From<Response> for azure_core::Response {
+            fn from(rsp: Response) -> Self {
+                rsp.into_raw_response()
+            }
+        }
+        impl AsRef<azure_core::Response> for Response {
+            fn as_ref(&self) -> &azure_core::Response {
+                self.as_raw_response()
+            }
+        }
         #[derive(Clone)]
         pub struct RequestBuilder {
             pub(crate) client: super::super::Client,
             pub(crate) resource_group_name: String,
             pub(crate) name: String,
             pub(crate) subscription_id: String,
+            pub(crate) claims: Option<String>,
+            pub(crate) accept_language: Option<String>,
+            pub(crate) query_params: Vec<(String, String)>,
+        }
+        impl super::super::private::Sealed for RequestBuilder {}
+        impl super::super::Operation for RequestBuilder {
+            fn method() -> azure_core::Method {
+                azure_core::Method::Delete
+            }
+            fn path_template() -> &'static str {
+                "/subscriptions/{subscriptionId}/resourceGroups/{resourceGroupName}/providers/Microsoft.KeyVault/managedHSMs/{name}"
+            }
         }
         impl RequestBuilder {
+            #[doc = "Ask the credential for a token that satisfies the JSON `claims`; see [claims](crate::package_preview_2021_04#claims)."]
+            pub fn claims(mut self, claims: impl Into<String>) -> Self {
+                self.claims = Some(claims.into());
+                self
+            }
+            #[doc = "Send this request with `accept_language` as its `Accept-Language` header, such as `fr-FR`, overriding `ClientBuilder::accept_language`."]
+            pub fn accept_language(mut self, accept_language: impl Into<String>) -> Self {
+                self.accept_language = Some(accept_language.into());
+                self
+            }
+            #[doc = "Append `key=value` to the query string; see [query parameters](crate::package_preview_2021_04#query-parameters)."]
+            pub fn query_param(mut self, key: impl Into<String>, value: impl Into<String>) -> Self {
+                self.query_params.push((key.into(), value.into()));
+                self
+            }
+            #[doc = "Build the URL the request is sent to, including the api-version and any query parameters set on the builder, without sending the request."]
+            pub fn url(&self) -> azure_core::Result<azure_core::Url> {
+                let mut url = azure_core::Url::parse(&format!(
+                    "{}/subscriptions/{}/resourceGroups/{}/providers/Microsoft.KeyVault/managedHSMs/{}",
+                    self.client.endpoint(),
+                    &azure_core::client::path_segment("subscription_id", &self.subscription_id)?,
+                    &azure_core::client::path_segment("resource_group_name", &self.resource_group_name)?,
+                    &azure_core::client::path_segment("name", &self.name)?
+                ))?;
+                url.query_pairs_mut()
+                    .append_pair(azure_core::query_param::API_VERSION, "2021-04-01-preview");
+                azure_core::client::append_query_params(&mut url, &self.query_params);
+                Ok(url)
+            }
             #[doc = "only the first response will be fetched as long running operations are not supported yet"]
             #[doc = "Send the request and returns the response."]
-            pub fn send(self) -> futures::future::BoxFuture<'static, azure_core::Result<Response>> {
-                Box::pin({
-                    let this = self.clone();
-                    async move {
-                        let url = azure_core::Url::parse(&format!(
-                            "{}/subscriptions/{}/resourceGroups/{}/providers/Microsoft.KeyVault/managedHSMs/{}",
-                            this.client.endpoint(),
-                            &this.subscription_id,
-                            &this.resource_group_name,
-                            &this.name
-                        ))?;
-                        let mut req = azure_core::Request::new(url, azure_core::Method::Delete);
-                        let credential = this.client.token_credential();
-                        let token_response = credential.get_token(&this.client.scopes().join(" ")).await?;
-                        req.insert_header(
-                            azure_core::headers::AUTHORIZATION,
-                            format!("Bearer {}", token_response.token.secret()),
-                        );
-                        req.url_mut()
-                            .query_pairs_mut()
-                            .append_pair(azure_core::query_param::API_VERSION, "2021-04-01-preview");
-                        let req_body = azure_core::EMPTY_BODY;
-                        req.set_body(req_body);
-                        Ok(Response(this.client.send(&mut req).await?))
-                    }
-                })
+            #[doc = ""]
+            #[doc = "The builder is not consumed, so a polling loop can build it once and call `send` on every iteration; each call fetches a fresh token. The returned future is `'static` because it owns a clone of the builder, so it can be spawned or stored."]
+            pub fn send(&self) -> futures::future::BoxFuture<'static, azure_core::Result<Response>> {
+                let this = self.clone();
+                Box::pin(async move { this.send_ref().await })
+            }
+            #[doc = "Send the request without consuming the builder."]
+            #[doc = ""]
+            #[doc = "Unlike `send`, which clones the builder so that its future is `'static`, the request is built from borrowed fields, so a builder kept across calls, such as in a polling loop, is sent again without being cloned. The future borrows the builder in turn."]
+            pub async fn send_ref(&self) -> azure_core::Result<Response> {
+                let url = self.url()?;
+                let mut req = azure_core::Request::new(url, azure_core::Method::Delete);
+                let token_response = self.client.token(self.claims.as_deref()).await?;
+                req.insert_header(
+                    azure_core::headers::AUTHORIZATION,
+                    format!("Bearer {}", token_response.token.secret()),
+                );
+                if let Some(accept_language) = &self.accept_language {
+                    req.insert_header(azure_core::headers::ACCEPT_LANGUAGE, accept_language.clone());
+                }
+                let req_body = azure_core::EMPTY_BODY;
+                req.set_body(req_body);
+                Ok(Response(self.client.send(&mut req, "managed_hsms::delete").await?))
+            }
+            #[doc = "Send the request and return the response, blocking the current thread."]
+            #[cfg(feature = "blocking")]
+            pub fn call(self) -> azure_core::Result<Response> {
+                azure_core::blocking::block_on(self.send())
             }
         }
     }
@@ -2202,16 +5015,53 @@ pub mod managed_hsms {
         pub struct Response(azure_core::Response);
         impl Response {
             pub async fn into_body(self) -> azure_core::Result<models::ManagedHsmListResult> {
-                let bytes = self.0.into_body().collect().await?;
-                let body: models::ManagedHsmListResult = serde_json::from_slice(&bytes)?;
+                let status = self.0.status();
+                let bytes = self.into_byte_stream().collect().await?;
+                let body: models::ManagedHsmListResult =
+                    azure_core::client::from_json_body("managed_hsms::list_by_resource_group", status, &bytes)?;
+                Ok(body)
+            }
+            #[doc = "Stream the items of the page as they are received, instead of buffering the whole body like `into_body`. Everything but the `value` array, including the link to the next page, is skipped."]
+            pub fn into_body_streaming(self) -> futures::stream::BoxStream<'static, azure_core::Result<models::ManagedHsm>> {
+                self.into_byte_stream().json_array_items("value")
+            }
+            #[doc = "Return the response body as untyped JSON; see [responses](crate::package_preview_2021_04#responses)."]
+            pub async fn into_value(self) -> azure_core::Result<serde_json::Value> {
+                let status = self.0.status();
+                let bytes = self.into_byte_stream().collect().await?;
+                let body: serde_json::Value = azure_core::client::from_json_body("managed_hsms::list_by_resource_group", status, &bytes)?;
                 Ok(body)
             }
+            #[doc = "Stream the response body in chunks as they are received, instead of buffering all of it like `into_body`."]
+            pub fn into_byte_stream(self) -> azure_core::ResponseBody {
+                self.0.into_body()
+            }
+            pub fn status(&self) -> azure_core::StatusCode {
+                self.0.status()
+            }
             pub fn into_raw_response(self) -> azure_core::Response {
                 self.0
             }
+            #[doc = "Read the whole response and return its status, headers and body as received, for forwarding it verbatim without going through `models`."]
+            pub async fn into_raw_parts(self) -> azure_core::Result<(azure_core::StatusCode, azure_core::headers::Headers, bytes::Bytes)> {
+                let (status, headers, body) = self.0.deconstruct();
+                Ok((status, headers, body.collect().await?))
+            }
             pub fn as_raw_response(&self) -> &azure_core::Response {
                 &self.0
             }
+            #[doc = "The remaining request quota; see [responses](crate::package_preview_2021_04#responses)."]
+            pub fn rate_limit_remaining(&self) -> Option<u32> {
+                azure_core::client::rate_limit_remaining(self.0.headers())
+            }
+            #[doc = "The `x-ms-client-request-id` the request was sent with; see [responses](crate::package_preview_2021_04#responses)."]
+            pub fn client_request_id(&self) -> Option<&str> {
+                self.0.headers().get_optional_str(&azure_core::headers::CLIENT_REQUEST_ID)
+            }
+            #[doc = "How long the request took; see [responses](crate::package_preview_2021_04#responses)."]
+            pub fn elapsed(&self) -> std::time::Duration {
+                self.0.elapsed().unwrap_or_default()
+            }
         }
         impl From<Response> for azure_core::Response {
             fn from(rsp: Response) -> Self {
@@ -2229,75 +5079,197 @@ pub mod managed_hsms {
             pub(crate) resource_group_name: String,
             pub(crate) subscription_id: String,
             pub(crate) top: Option<i32>,
+            pub(crate) claims: Option<String>,
+            pub(crate) accept_language: Option<String>,
+            pub(crate) query_params: Vec<(String, String)>,
+            pub(crate) select: Option<String>,
+            pub(crate) max_items: Option<usize>,
+            pub(crate) on_missing_empty: bool,
+            pub(crate) resume_from: Option<String>,
+        }
+        impl super::super::private::Sealed for RequestBuilder {}
+        impl super::super::Operation for RequestBuilder {
+            fn method() -> azure_core::Method {
+                azure_core::Method::Get
+            }
+            fn path_template() -> &'static str {
+                "/subscriptions/{subscriptionId}/resourceGroups/{resourceGroupName}/providers/Microsoft.KeyVault/managedHSMs"
+            }
+        }
+        impl super::super::BatchRead for RequestBuilder {
+            fn batch_url(&self) -> azure_core::Result<azure_core::Url> {
+                self.url()
+            }
         }
         impl RequestBuilder {
+            #[doc = "Ask the credential for a token that satisfies the JSON `claims`; see [claims](crate::package_preview_2021_04#claims)."]
+            pub fn claims(mut self, claims: impl Into<String>) -> Self {
+                self.claims = Some(claims.into());
+                self
+            }
+            #[doc = "Send this request with `accept_language` as its `Accept-Language` header, such as `fr-FR`, overriding `ClientBuilder::accept_language`."]
+            pub fn accept_language(mut self, accept_language: impl Into<String>) -> Self {
+                self.accept_language = Some(accept_language.into());
+                self
+            }
             #[doc = "Maximum number of results to return."]
+            #[doc = ""]
+            #[doc = "This is the page size. The service may cap it, so a page can hold fewer items than requested."]
             pub fn top(mut self, top: i32) -> Self {
                 self.top = Some(top);
                 self
             }
+            #[doc = "Append `key=value` to the query string; see [query parameters](crate::package_preview_2021_04#query-parameters)."]
+            pub fn query_param(mut self, key: impl Into<String>, value: impl Into<String>) -> Self {
+                self.query_params.push((key.into(), value.into()));
+                self
+            }
+            #[doc = "Only return the given properties of each item, such as `[\"id\", \"name\"]`, to reduce the work done by the service and the size of each page."]
+            #[doc = ""]
+            #[doc = "Fields of the returned models that were not selected are `None`."]
+            pub fn select(mut self, select: &[&str]) -> Self {
+                self.select = Some(select.join(","));
+                self
+            }
+            #[doc = "Limit the total number of items yielded by `into_item_stream`. No further pages are requested once the limit is reached."]
+            pub fn max_items(mut self, max_items: usize) -> Self {
+                self.max_items = Some(max_items);
+                self
+            }
+            #[doc = "Treat `404 Not Found` on the first page as an empty list instead of an error."]
+            #[doc = ""]
+            #[doc = "Useful when the parent resource may not exist yet, such as in a reconciliation loop racing its provisioning. The stream then yields a single empty page. Off by default, and a `404` on a later page is always an error."]
+            pub fn on_missing_empty(mut self, on_missing_empty: bool) -> Self {
+                self.on_missing_empty = on_missing_empty;
+                self
+            }
+            #[doc = "Start paging from `continuation`, a token saved from `into_checkpoint_stream` or `page`, instead of from the first page."]
+            #[doc = ""]
+            #[doc = "Lets a long listing be checkpointed and picked up where it stopped, such as after a restart."]
+            pub fn resume_from(mut self, continuation: impl Into<String>) -> Self {
+                self.resume_from = Some(continuation.into());
+                self
+            }
+            #[doc = "Build the URL the request is sent to, including the api-version and any query parameters set on the builder, without sending the request."]
+            pub fn url(&self) -> azure_core::Result<azure_core::Url> {
+                let mut url = azure_core::Url::parse(&format!(
+                    "{}/subscriptions/{}/resourceGroups/{}/providers/Microsoft.KeyVault/managedHSMs",
+                    self.client.endpoint(),
+                    &azure_core::client::path_segment("subscription_id", &self.subscription_id)?,
+                    &azure_core::client::path_segment("resource_group_name", &self.resource_group_name)?
+                ))?;
+                url.query_pairs_mut()
+                    .append_pair(azure_core::query_param::API_VERSION, "2021-04-01-preview");
+                if let Some(top) = &self.top {
+                    url.query_pairs_mut().append_pair("$top", &top.to_string());
+                }
+                if let Some(select) = &self.select {
+                    url.query_pairs_mut().append_pair("$select", select);
+                }
+                azure_core::client::append_query_params(&mut url, &self.query_params);
+                Ok(url)
+            }
+            #[doc = "Stream the items of every page, stopping early if `max_items` is set."]
+            pub fn into_item_stream(self) -> futures::stream::BoxStream<'static, azure_core::Result<models::ManagedHsm>> {
+                let max_items = self.max_items;
+                azure_core::client::item_stream(self.into_stream(), max_items, |page| page.value)
+            }
+            #[doc = "Stream the items of each page together with the continuation token that resumes after it, or `None` after the last page."]
+            #[doc = ""]
+            #[doc = "Save the token once a page has been processed and pass it to `resume_from` to carry on from there later. `max_items` is not applied."]
+            pub fn into_checkpoint_stream(
+                self,
+            ) -> futures::stream::BoxStream<'static, azure_core::Result<(Vec<models::ManagedHsm>, Option<String>)>> {
+                azure_core::client::checkpoint_stream(self.into_stream(), |page| page.value)
+            }
             pub fn into_stream(self) -> azure_core::Pageable<models::ManagedHsmListResult, azure_core::error::Error> {
                 let make_request = move |continuation: Option<String>| {
-                    let this = self.clone();
-                    async move {
-                        let mut url = azure_core::Url::parse(&format!(
-                            "{}/subscriptions/{}/resourceGroups/{}/providers/Microsoft.KeyVault/managedHSMs",
-                            this.client.endpoint(),
-                            &this.subscription_id,
-                            &this.resource_group_name
-                        ))?;
-                        let rsp = match continuation {
-                            Some(value) => {
-                                url.set_path("");
-                                url = url.join(&value)?;
-                                let mut req = azure_core::Request::new(url, azure_core::Method::Get);
-                                let credential = this.client.token_credential();
-                                let token_response = credential.get_token(&this.client.scopes().join(" ")).await?;
-                                req.insert_header(
-                                    azure_core::headers::AUTHORIZATION,
-                                    format!("Bearer {}", token_response.token.secret()),
-                                );
-                                let has_api_version_already =
-                                    req.url_mut().query_pairs().any(|(k, _)| k == azure_core::query_param::API_VERSION);
-                                if !has_api_version_already {
-                                    req.url_mut()
-                                        .query_pairs_mut()
-                                        .append_pair(azure_core::query_param::API_VERSION, "2021-04-01-preview");
-                                }
-                                let req_body = azure_core::EMPTY_BODY;
-                                req.set_body(req_body);
-                                this.client.send(&mut req).await?
+                    let continuation = continuation.or_else(|| self.resume_from.clone());
+                    self.clone().fetch_page(continuation)
+                };
+                azure_core::Pageable::new(make_request)
+            }
+            #[doc = "Fetch a single page and return its body together with the continuation token for the next page, if any."]
+            #[doc = ""]
+            #[doc = "Pass `None` to fetch the first page, or a token returned by a previous call to fetch the page that follows it."]
+            pub fn page(
+                &self,
+                continuation: Option<String>,
+            ) -> futures::future::BoxFuture<'static, azure_core::Result<(models::ManagedHsmListResult, Option<String>)>> {
+                let this = self.clone();
+                Box::pin(async move {
+                    let body = this.fetch_page(continuation).await?;
+                    let continuation = azure_core::Continuable::continuation(&body);
+                    Ok((body, continuation))
+                })
+            }
+            fn fetch_page(
+                self,
+                continuation: Option<String>,
+            ) -> futures::future::BoxFuture<'static, azure_core::Result<models::ManagedHsmListResult>> {
+                Box::pin(async move {
+                    let mut url = self.url()?;
+                    let rsp = match continuation {
+                        Some(value) => {
+                            let first_page_url = url;
+                            url = azure_core::next_page_url(&first_page_url, &value)?;
+                            azure_core::client::carry_query_params(&mut url, &first_page_url, &self.query_params);
+                            let mut req = azure_core::Request::new(url, azure_core::Method::Get);
+                            let token_response = self.client.token(self.claims.as_deref()).await?;
+                            req.insert_header(
+                                azure_core::headers::AUTHORIZATION,
+                                format!("Bearer {}", token_response.token.secret()),
+                            );
+                            if let Some(accept_language) = &self.accept_language {
+                                req.insert_header(azure_core::headers::ACCEPT_LANGUAGE, accept_language.clone());
+                            }
+                            if let Some(token) = azure_core::continuation_header(&value) {
+                                req.insert_header(azure_core::headers::CONTINUATION, token.to_owned());
                             }
-                            None => {
-                                let mut req = azure_core::Request::new(url, azure_core::Method::Get);
-                                let credential = this.client.token_credential();
-                                let token_response = credential.get_token(&this.client.scopes().join(" ")).await?;
-                                req.insert_header(
-                                    azure_core::headers::AUTHORIZATION,
-                                    format!("Bearer {}", token_response.token.secret()),
-                                );
-                                req.url_mut()
-                                    .query_pairs_mut()
-                                    .append_pair(azure_core::query_param::API_VERSION, "2021-04-01-preview");
-                                if let Some(top) = &this.top {
-                                    req.url_mut().query_pairs_mut().append_pair("$top", &top.to_string());
+                            let req_body = azure_core::EMPTY_BODY;
+                            req.set_body(req_body);
+                            self.client.send(&mut req, "managed_hsms::list_by_resource_group").await?
+                        }
+                        None => {
+                            let mut req = azure_core::Request::new(url, azure_core::Method::Get);
+                            let token_response = self.client.token(self.claims.as_deref()).await?;
+                            req.insert_header(
+                                azure_core::headers::AUTHORIZATION,
+                                format!("Bearer {}", token_response.token.secret()),
+                            );
+                            if let Some(accept_language) = &self.accept_language {
+                                req.insert_header(azure_core::headers::ACCEPT_LANGUAGE, accept_language.clone());
+                            }
+                            let req_body = azure_core::EMPTY_BODY;
+                            req.set_body(req_body);
+                            let rsp = self.client.send(&mut req, "managed_hsms::list_by_resource_group").await;
+                            if self.on_missing_empty {
+                                match azure_core::client::not_found_as_none(rsp)? {
+                                    Some(rsp) => rsp,
+                                    None => return Ok(Default::default()),
                                 }
-                                let req_body = azure_core::EMPTY_BODY;
-                                req.set_body(req_body);
-                                this.client.send(&mut req).await?
+                            } else {
+                                rsp?
                             }
-                        };
-                        let rsp = match rsp.status() {
-                            azure_core::StatusCode::Ok => Ok(Response(rsp)),
-                            status_code => Err(azure_core::error::Error::from(azure_core::error::ErrorKind::HttpResponse {
-                                status: status_code,
-                                error_code: None,
-                            })),
-                        };
-                        rsp?.into_body().await
+                        }
+                    };
+                    let rsp = match rsp.status() {
+                        azure_core::StatusCode::Ok => Ok(Response(rsp)),
+                        _ => Err(azure_core::client::unexpected_status(&rsp)),
+                    };
+                    let rsp = rsp?;
+                    let continuation = azure_core::continuation_from_headers(rsp.as_raw_response().headers());
+                    let mut body = rsp.into_body().await?;
+                    if body.next_link.is_none() {
+                        body.next_link = continuation;
                     }
-                };
-                azure_core::Pageable::new(make_request)
+                    Ok(body)
+                })
+            }
+            #[doc = "Fetch every page and return all items, blocking the current thread. Stops early if `max_items` is set."]
+            #[cfg(feature = "blocking")]
+            pub fn call(self) -> azure_core::Result<Vec<models::ManagedHsm>> {
+                azure_core::blocking::block_on(futures::TryStreamExt::try_collect(self.into_item_stream()))
             }
         }
     }
@@ -2306,16 +5278,53 @@ pub mod managed_hsms {
         pub struct Response(azure_core::Response);
         impl Response {
             pub async fn into_body(self) -> azure_core::Result<models::ManagedHsmListResult> {
-                let bytes = self.0.into_body().collect().await?;
-                let body: models::ManagedHsmListResult = serde_json::from_slice(&bytes)?;
+                let status = self.0.status();
+                let bytes = self.into_byte_stream().collect().await?;
+                let body: models::ManagedHsmListResult =
+                    azure_core::client::from_json_body("managed_hsms::list_by_subscription", status, &bytes)?;
+                Ok(body)
+            }
+            #[doc = "Stream the items of the page as they are received, instead of buffering the whole body like `into_body`. Everything but the `value` array, including the link to the next page, is skipped."]
+            pub fn into_body_streaming(self) -> futures::stream::BoxStream<'static, azure_core::Result<models::ManagedHsm>> {
+                self.into_byte_stream().json_array_items("value")
+            }
+            #[doc = "Return the response body as untyped JSON; see [responses](crate::package_preview_2021_04#responses)."]
+            pub async fn into_value(self) -> azure_core::Result<serde_json::Value> {
+                let status = self.0.status();
+                let bytes = self.into_byte_stream().collect().await?;
+                let body: serde_json::Value = azure_core::client::from_json_body("managed_hsms::list_by_subscription", status, &bytes)?;
                 Ok(body)
             }
+            #[doc = "Stream the response body in chunks as they are received, instead of buffering all of it like `into_body`."]
+            pub fn into_byte_stream(self) -> azure_core::ResponseBody {
+                self.0.into_body()
+            }
+            pub fn status(&self) -> azure_core::StatusCode {
+                self.0.status()
+            }
             pub fn into_raw_response(self) -> azure_core::Response {
                 self.0
             }
+            #[doc = "Read the whole response and return its status, headers and body as received, for forwarding it verbatim without going through `models`."]
+            pub async fn into_raw_parts(self) -> azure_core::Result<(azure_core::StatusCode, azure_core::headers::Headers, bytes::Bytes)> {
+                let (status, headers, body) = self.0.deconstruct();
+                Ok((status, headers, body.collect().await?))
+            }
             pub fn as_raw_response(&self) -> &azure_core::Response {
                 &self.0
             }
+            #[doc = "The remaining request quota; see [responses](crate::package_preview_2021_04#responses)."]
+            pub fn rate_limit_remaining(&self) -> Option<u32> {
+                azure_core::client::rate_limit_remaining(self.0.headers())
+            }
+            #[doc = "The `x-ms-client-request-id` the request was sent with; see [responses](crate::package_preview_2021_04#responses)."]
+            pub fn client_request_id(&self) -> Option<&str> {
+                self.0.headers().get_optional_str(&azure_core::headers::CLIENT_REQUEST_ID)
+            }
+            #[doc = "How long the request took; see [responses](crate::package_preview_2021_04#responses)."]
+            pub fn elapsed(&self) -> std::time::Duration {
+                self.0.elapsed().unwrap_or_default()
+            }
         }
         impl From<Response> for azure_core::Response {
             fn from(rsp: Response) -> Self {
@@ -2332,74 +5341,196 @@ pub mod managed_hsms {
             pub(crate) client: super::super::Client,
             pub(crate) subscription_id: String,
             pub(crate) top: Option<i32>,
+            pub(crate) claims: Option<String>,
+            pub(crate) accept_language: Option<String>,
+            pub(crate) query_params: Vec<(String, String)>,
+            pub(crate) select: Option<String>,
+            pub(crate) max_items: Option<usize>,
+            pub(crate) on_missing_empty: bool,
+            pub(crate) resume_from: Option<String>,
+        }
+        impl super::super::private::Sealed for RequestBuilder {}
+        impl super::super::Operation for RequestBuilder {
+            fn method() -> azure_core::Method {
+                azure_core::Method::Get
+            }
+            fn path_template() -> &'static str {
+                "/subscriptions/{subscriptionId}/providers/Microsoft.KeyVault/managedHSMs"
+            }
+        }
+        impl super::super::BatchRead for RequestBuilder {
+            fn batch_url(&self) -> azure_core::Result<azure_core::Url> {
+                self.url()
+            }
         }
         impl RequestBuilder {
+            #[doc = "Ask the credential for a token that satisfies the JSON `claims`; see [claims](crate::package_preview_2021_04#claims)."]
+            pub fn claims(mut self, claims: impl Into<String>) -> Self {
+                self.claims = Some(claims.into());
+                self
+            }
+            #[doc = "Send this request with `accept_language` as its `Accept-Language` header, such as `fr-FR`, overriding `ClientBuilder::accept_language`."]
+            pub fn accept_language(mut self, accept_language: impl Into<String>) -> Self {
+                self.accept_language = Some(accept_language.into());
+                self
+            }
             #[doc = "Maximum number of results to return."]
+            #[doc = ""]
+            #[doc = "This is the page size. The service may cap it, so a page can hold fewer items than requested."]
             pub fn top(mut self, top: i32) -> Self {
                 self.top = Some(top);
                 self
             }
+            #[doc = "Append `key=value` to the query string; see [query parameters](crate::package_preview_2021_04#query-parameters)."]
+            pub fn query_param(mut self, key: impl Into<String>, value: impl Into<String>) -> Self {
+                self.query_params.push((key.into(), value.into()));
+                self
+            }
+            #[doc = "Only return the given properties of each item, such as `[\"id\", \"name\"]`, to reduce the work done by the service and the size of each page."]
+            #[doc = ""]
+            #[doc = "Fields of the returned models that were not selected are `None`."]
+            pub fn select(mut self, select: &[&str]) -> Self {
+                self.select = Some(select.join(","));
+                self
+            }
+            #[doc = "Limit the total number of items yielded by `into_item_stream`. No further pages are requested once the limit is reached."]
+            pub fn max_items(mut self, max_items: usize) -> Self {
+                self.max_items = Some(max_items);
+                self
+            }
+            #[doc = "Treat `404 Not Found` on the first page as an empty list instead of an error."]
+            #[doc = ""]
+            #[doc = "Useful when the parent resource may not exist yet, such as in a reconciliation loop racing its provisioning. The stream then yields a single empty page. Off by default, and a `404` on a later page is always an error."]
+            pub fn on_missing_empty(mut self, on_missing_empty: bool) -> Self {
+                self.on_missing_empty = on_missing_empty;
+                self
+            }
+            #[doc = "Start paging from `continuation`, a token saved from `into_checkpoint_stream` or `page`, instead of from the first page."]
+            #[doc = ""]
+            #[doc = "Lets a long listing be checkpointed and picked up where it stopped, such as after a restart."]
+            pub fn resume_from(mut self, continuation: impl Into<String>) -> Self {
+                self.resume_from = Some(continuation.into());
+                self
+            }
+            #[doc = "Build the URL the request is sent to, including the api-version and any query parameters set on the builder, without sending the request."]
+            pub fn url(&self) -> azure_core::Result<azure_core::Url> {
+                let mut url = azure_core::Url::parse(&format!(
+                    "{}/subscriptions/{}/providers/Microsoft.KeyVault/managedHSMs",
+                    self.client.endpoint(),
+                    &azure_core::client::path_segment("subscription_id", &self.subscription_id)?
+                ))?;
+                url.query_pairs_mut()
+                    .append_pair(azure_core::query_param::API_VERSION, "2021-04-01-preview");
+                if let Some(top) = &self.top {
+                    url.query_pairs_mut().append_pair("$top", &top.to_string());
+                }
+                if let Some(select) = &self.select {
+                    url.query_pairs_mut().append_pair("$select", select);
+                }
+                azure_core::client::append_query_params(&mut url, &self.query_params);
+                Ok(url)
+            }
+            #[doc = "Stream the items of every page, stopping early if `max_items` is set."]
+            pub fn into_item_stream(self) -> futures::stream::BoxStream<'static, azure_core::Result<models::ManagedHsm>> {
+                let max_items = self.max_items;
+                azure_core::client::item_stream(self.into_stream(), max_items, |page| page.value)
+            }
+            #[doc = "Stream the items of each page together with the continuation token that resumes after it, or `None` after the last page."]
+            #[doc = ""]
+            #[doc = "Save the token once a page has been processed and pass it to `resume_from` to carry on from there later. `max_items` is not applied."]
+            pub fn into_checkpoint_stream(
+                self,
+            ) -> futures::stream::BoxStream<'static, azure_core::Result<(Vec<models::ManagedHsm>, Option<String>)>> {
+                azure_core::client::checkpoint_stream(self.into_stream(), |page| page.value)
+            }
             pub fn into_stream(self) -> azure_core::Pageable<models::ManagedHsmListResult, azure_core::error::Error> {
                 let make_request = move |continuation: Option<String>| {
-                    let this = self.clone();
-                    async move {
-                        let mut url = azure_core::Url::parse(&format!(
-                            "{}/subscriptions/{}/providers/Microsoft.KeyVault/managedHSMs",
-                            this.client.endpoint(),
-                            &this.subscription_id
-                        ))?;
-                        let rsp = match continuation {
-                            Some(value) => {
-                                url.set_path("");
-                                url = url.join(&value)?;
-                                let mut req = azure_core::Request::new(url, azure_core::Method::Get);
-                                let credential = this.client.token_credential();
-                                let token_response = credential.get_token(&this.client.scopes().join(" ")).await?;
-                                req.insert_header(
-                                    azure_core::headers::AUTHORIZATION,
-                                    format!("Bearer {}", token_response.token.secret()),
-                                );
-                                let has_api_version_already =
-                                    req.url_mut().query_pairs().any(|(k, _)| k == azure_core::query_param::API_VERSION);
-                                if !has_api_version_already {
-                                    req.url_mut()
-                                        .query_pairs_mut()
-                                        .append_pair(azure_core::query_param::API_VERSION, "2021-04-01-preview");
-                                }
-                                let req_body = azure_core::EMPTY_BODY;
-                                req.set_body(req_body);
-                                this.client.send(&mut req).await?
+                    let continuation = continuation.or_else(|| self.resume_from.clone());
+                    self.clone().fetch_page(continuation)
+                };
+                azure_core::Pageable::new(make_request)
+            }
+            #[doc = "Fetch a single page and return its body together with the continuation token for the next page, if any."]
+            #[doc = ""]
+            #[doc = "Pass `None` to fetch the first page, or a token returned by a previous call to fetch the page that follows it."]
+            pub fn page(
+                &self,
+                continuation: Option<String>,
+            ) -> futures::future::BoxFuture<'static, azure_core::Result<(models::ManagedHsmListResult, Option<String>)>> {
+                let this = self.clone();
+                Box::pin(async move {
+                    let body = this.fetch_page(continuation).await?;
+                    let continuation = azure_core::Continuable::continuation(&body);
+                    Ok((body, continuation))
+                })
+            }
+            fn fetch_page(
+                self,
+                continuation: Option<String>,
+            ) -> futures::future::BoxFuture<'static, azure_core::Result<models::ManagedHsmListResult>> {
+                Box::pin(async move {
+                    let mut url = self.url()?;
+                    let rsp = match continuation {
+                        Some(value) => {
+                            let first_page_url = url;
+                            url = azure_core::next_page_url(&first_page_url, &value)?;
+                            azure_core::client::carry_query_params(&mut url, &first_page_url, &self.query_params);
+                            let mut req = azure_core::Request::new(url, azure_core::Method::Get);
+                            let token_response = self.client.token(self.claims.as_deref()).await?;
+                            req.insert_header(
+                                azure_core::headers::AUTHORIZATION,
+                                format!("Bearer {}", token_response.token.secret()),
+                            );
+                            if let Some(accept_language) = &self.accept_language {
+                                req.insert_header(azure_core::headers::ACCEPT_LANGUAGE, accept_language.clone());
+                            }
+                            if let Some(token) = azure_core::continuation_header(&value) {
+                                req.insert_header(azure_core::headers::CONTINUATION, token.to_owned());
+                            }
+                            let req_body = azure_core::EMPTY_BODY;
+                            req.set_body(req_body);
+                            self.client.send(&mut req, "managed_hsms::list_by_subscription").await?
+                        }
+                        None => {
+                            let mut req = azure_core::Request::new(url, azure_core::Method::Get);
+                            let token_response = self.client.token(self.claims.as_deref()).await?;
+                            req.insert_header(
+                                azure_core::headers::AUTHORIZATION,
+                                format!("Bearer {}", token_response.token.secret()),
+                            );
+                            if let Some(accept_language) = &self.accept_language {
+                                req.insert_header(azure_core::headers::ACCEPT_LANGUAGE, accept_language.clone());
                             }
-                            None => {
-                                let mut req = azure_core::Request::new(url, azure_core::Method::Get);
-                                let credential = this.client.token_credential();
-                                let token_response = credential.get_token(&this.client.scopes().join(" ")).await?;
-                                req.insert_header(
-                                    azure_core::headers::AUTHORIZATION,
-                                    format!("Bearer {}", token_response.token.secret()),
-                                );
-                                req.url_mut()
-                                    .query_pairs_mut()
-                                    .append_pair(azure_core::query_param::API_VERSION, "2021-04-01-preview");
-                                if let Some(top) = &this.top {
-                                    req.url_mut().query_pairs_mut().append_pair("$top", &top.to_string());
+                            let req_body = azure_core::EMPTY_BODY;
+                            req.set_body(req_body);
+                            let rsp = self.client.send(&mut req, "managed_hsms::list_by_subscription").await;
+                            if self.on_missing_empty {
+                                match azure_core::client::not_found_as_none(rsp)? {
+                                    Some(rsp) => rsp,
+                                    None => return Ok(Default::default()),
                                 }
-                                let req_body = azure_core::EMPTY_BODY;
-                                req.set_body(req_body);
-                                this.client.send(&mut req).await?
+                            } else {
+                                rsp?
                             }
-                        };
-                        let rsp = match rsp.status() {
-                            azure_core::StatusCode::Ok => Ok(Response(rsp)),
-                            status_code => Err(azure_core::error::Error::from(azure_core::error::ErrorKind::HttpResponse {
-                                status: status_code,
-                                error_code: None,
-                            })),
-                        };
-                        rsp?.into_body().await
+                        }
+                    };
+                    let rsp = match rsp.status() {
+                        azure_core::StatusCode::Ok => Ok(Response(rsp)),
+                        _ => Err(azure_core::client::unexpected_status(&rsp)),
+                    };
+                    let rsp = rsp?;
+                    let continuation = azure_core::continuation_from_headers(rsp.as_raw_response().headers());
+                    let mut body = rsp.into_body().await?;
+                    if body.next_link.is_none() {
+                        body.next_link = continuation;
                     }
-                };
-                azure_core::Pageable::new(make_request)
+                    Ok(body)
+                })
+            }
+            #[doc = "Fetch every page and return all items, blocking the current thread. Stops early if `max_items` is set."]
+            #[cfg(feature = "blocking")]
+            pub fn call(self) -> azure_core::Result<Vec<models::ManagedHsm>> {
+                azure_core::blocking::block_on(futures::TryStreamExt::try_collect(self.into_item_stream()))
             }
         }
     }
@@ -2408,16 +5539,53 @@ pub mod managed_hsms {
         pub struct Response(azure_core::Response);
         impl Response {
             pub async fn into_body(self) -> azure_core::Result<models::DeletedManagedHsmListResult> {
-                let bytes = self.0.into_body().collect().await?;
-                let body: models::DeletedManagedHsmListResult = serde_json::from_slice(&bytes)?;
+                let status = self.0.status();
+                let bytes = self.into_byte_stream().collect().await?;
+                let body: models::DeletedManagedHsmListResult =
+                    azure_core::client::from_json_body("managed_hsms::list_deleted", status, &bytes)?;
                 Ok(body)
             }
+            #[doc = "Stream the items of the page as they are received, instead of buffering the whole body like `into_body`. Everything but the `value` array, including the link to the next page, is skipped."]
+            pub fn into_body_streaming(self) -> futures::stream::BoxStream<'static, azure_core::Result<models::DeletedManagedHsm>> {
+                self.into_byte_stream().json_array_items("value")
+            }
+            #[doc = "Return the response body as untyped JSON; see [responses](crate::package_preview_2021_04#responses)."]
+            pub async fn into_value(self) -> azure_core::Result<serde_json::Value> {
+                let status = self.0.status();
+                let bytes = self.into_byte_stream().collect().await?;
+                let body: serde_json::Value = azure_core::client::from_json_body("managed_hsms::list_deleted", status, &bytes)?;
+                Ok(body)
+            }
+            #[doc = "Stream the response body in chunks as they are received, instead of buffering all of it like `into_body`."]
+            pub fn into_byte_stream(self) -> azure_core::ResponseBody {
+                self.0.into_body()
+            }
+            pub fn status(&self) -> azure_core::StatusCode {
+                self.0.status()
+            }
             pub fn into_raw_response(self) -> azure_core::Response {
                 self.0
             }
+            #[doc = "Read the whole response and return its status, headers and body as received, for forwarding it verbatim without going through `models`."]
+            pub async fn into_raw_parts(self) -> azure_core::Result<(azure_core::StatusCode, azure_core::headers::Headers, bytes::Bytes)> {
+                let (status, headers, body) = self.0.deconstruct();
+                Ok((status, headers, body.collect().await?))
+            }
             pub fn as_raw_response(&self) -> &azure_core::Response {
                 &self.0
             }
+            #[doc = "The remaining request quota; see [responses](crate::package_preview_2021_04#responses)."]
+            pub fn rate_limit_remaining(&self) -> Option<u32> {
+                azure_core::client::rate_limit_remaining(self.0.headers())
+            }
+            #[doc = "The `x-ms-client-request-id` the request was sent with; see [responses](crate::package_preview_2021_04#responses)."]
+            pub fn client_request_id(&self) -> Option<&str> {
+                self.0.headers().get_optional_str(&azure_core::headers::CLIENT_REQUEST_ID)
+            }
+            #[doc = "How long the request took; see [responses](crate::package_preview_2021_04#responses)."]
+            pub fn elapsed(&self) -> std::time::Duration {
+                self.0.elapsed().unwrap_or_default()
+            }
         }
         impl From<Response> for azure_core::Response {
             fn from(rsp: Response) -> Self {
@@ -2433,66 +5601,187 @@ pub mod managed_hsms {
         pub struct RequestBuilder {
             pub(crate) client: super::super::Client,
             pub(crate) subscription_id: String,
+            pub(crate) claims: Option<String>,
+            pub(crate) accept_language: Option<String>,
+            pub(crate) query_params: Vec<(String, String)>,
+            pub(crate) select: Option<String>,
+            pub(crate) max_items: Option<usize>,
+            pub(crate) on_missing_empty: bool,
+            pub(crate) resume_from: Option<String>,
+        }
+        impl super::super::private::Sealed for RequestBuilder {}
+        impl super::super::Operation for RequestBuilder {
+            fn method() -> azure_core::Method {
+                azure_core::Method::Get
+            }
+            fn path_template() -> &'static str {
+                "/subscriptions/{subscriptionId}/providers/Microsoft.KeyVault/deletedManagedHSMs"
+            }
+        }
+        impl super::super::BatchRead for RequestBuilder {
+            fn batch_url(&self) -> azure_core::Result<azure_core::Url> {
+                self.url()
+            }
         }
         impl RequestBuilder {
+            #[doc = "Ask the credential for a token that satisfies the JSON `claims`; see [claims](crate::package_preview_2021_04#claims)."]
+            pub fn claims(mut self, claims: impl Into<String>) -> Self {
+                self.claims = Some(claims.into());
+                self
+            }
+            #[doc = "Send this request with `accept_language` as its `Accept-Language` header, such as `fr-FR`, overriding `ClientBuilder::accept_language`."]
+            pub fn accept_language(mut self, accept_language: impl Into<String>) -> Self {
+                self.accept_language = Some(accept_language.into());
+                self
+            }
+            #[doc = "Append `key=value` to the query string; see [query parameters](crate::package_preview_2021_04#query-parameters)."]
+            pub fn query_param(mut self, key: impl Into<String>, value: impl Into<String>) -> Self {
+                self.query_params.push((key.into(), value.into()));
+                self
+            }
+            #[doc = "Only return the given properties of each item, such as `[\"id\", \"name\"]`, to reduce the work done by the service and the size of each page."]
+            #[doc = ""]
+            #[doc = "Fields of the returned models that were not selected are `None`."]
+            pub fn select(mut self, select: &[&str]) -> Self {
+                self.select = Some(select.join(","));
+                self
+            }
+            #[doc = "Limit the total number of items yielded by `into_item_stream`. No further pages are requested once the limit is reached."]
+            pub fn max_items(mut self, max_items: usize) -> Self {
+                self.max_items = Some(max_items);
+                self
+            }
+            #[doc = "Treat `404 Not Found` on the first page as an empty list instead of an error."]
+            #[doc = ""]
+            #[doc = "Useful when the parent resource may not exist yet, such as in a reconciliation loop racing its provisioning. The stream then yields a single empty page. Off by default, and a `404` on a later page is always an error."]
+            pub fn on_missing_empty(mut self, on_missing_empty: bool) -> Self {
+                self.on_missing_empty = on_missing_empty;
+                self
+            }
+            #[doc = "Start paging from `continuation`, a token saved from `into_checkpoint_stream` or `page`, instead of from the first page."]
+            #[doc = ""]
+            #[doc = "Lets a long listing be checkpointed and picked up where it stopped, such as after a restart."]
+            pub fn resume_from(mut self, continuation: impl Into<String>) -> Self {
+                self.resume_from = Some(continuation.into());
+                self
+            }
+            #[doc = "Build the URL the request is sent to, including the api-version and any query parameters set on the builder, without sending the request."]
+            pub fn url(&self) -> azure_core::Result<azure_core::Url> {
+                let mut url = azure_core::Url::parse(&format!(
+                    "{}/subscriptions/{}/providers/Microsoft.KeyVault/deletedManagedHSMs",
+                    self.client.endpoint(),
+                    &azure_core::client::path_segment("subscription_id", &self.subscription_id)?
+                ))?;
+                url.query_pairs_mut()
+                    .append_pair(azure_core::query_param::API_VERSION, "2021-04-01-preview");
+                if let Some(select) = &self.select {
+                    url.query_pairs_mut().append_pair("$select", select);
+                }
+                azure_core::client::append_query_params(&mut url, &self.query_params);
+                Ok(url)
+            }
+            #[doc = "Stream the items of every page, stopping early if `max_items` is set."]
+            pub fn into_item_stream(self) -> futures::stream::BoxStream<'static, azure_core::Result<models::DeletedManagedHsm>> {
+                let max_items = self.max_items;
+                azure_core::client::item_stream(self.into_stream(), max_items, |page| page.value)
+            }
+            #[doc = "Stream the items of each page together with the continuation token that resumes after it, or `None` after the last page."]
+            #[doc = ""]
+            #[doc = "Save the token once a page has been processed and pass it to `resume_from` to carry on from there later. `max_items` is not applied."]
+            pub fn into_checkpoint_stream(
+                self,
+            ) -> futures::stream::BoxStream<'static, azure_core::Result<(Vec<models::DeletedManagedHsm>, Option<String>)>> {
+                azure_core::client::checkpoint_stream(self.into_stream(), |page| page.value)
+            }
             pub fn into_stream(self) -> azure_core::Pageable<models::DeletedManagedHsmListResult, azure_core::error::Error> {
                 let make_request = move |continuation: Option<String>| {
-                    let this = self.clone();
-                    async move {
-                        let mut url = azure_core::Url::parse(&format!(
-                            "{}/subscriptions/{}/providers/Microsoft.KeyVault/deletedManagedHSMs",
-                            this.client.endpoint(),
-                            &this.subscription_id
-                        ))?;
-                        let rsp = match continuation {
-                            Some(value) => {
-                                url.set_path("");
-                                url = url.join(&value)?;
-                                let mut req = azure_core::Request::new(url, azure_core::Method::Get);
-                                let credential = this.client.token_credential();
-                                let token_response = credential.get_token(&this.client.scopes().join(" ")).await?;
-                                req.insert_header(
-                                    azure_core::headers::AUTHORIZATION,
-                                    format!("Bearer {}", token_response.token.secret()),
-                                );
-                                let has_api_version_already =
-                                    req.url_mut().query_pairs().any(|(k, _)| k == azure_core::query_param::API_VERSION);
-                                if !has_api_version_already {
-                                    req.url_mut()
-                                        .query_pairs_mut()
-                                        .append_pair(azure_core::query_param::API_VERSION, "2021-04-01-preview");
-                                }
-                                let req_body = azure_core::EMPTY_BODY;
-                                req.set_body(req_body);
-                                this.client.send(&mut req).await?
+                    let continuation = continuation.or_else(|| self.resume_from.clone());
+                    self.clone().fetch_page(continuation)
+                };
+                azure_core::Pageable::new(make_request)
+            }
+            #[doc = "Fetch a single page and return its body together with the continuation token for the next page, if any."]
+            #[doc = ""]
+            #[doc = "Pass `None` to fetch the first page, or a token returned by a previous call to fetch the page that follows it."]
+            pub fn page(
+                &self,
+                continuation: Option<String>,
+            ) -> futures::future::BoxFuture<'static, azure_core::Result<(models::DeletedManagedHsmListResult, Option<String>)>>
+            {
+                let this = self.clone();
+                Box::pin(async move {
+                    let body = this.fetch_page(continuation).await?;
+                    let continuation = azure_core::Continuable::continuation(&body);
+                    Ok((body, continuation))
+                })
+            }
+            fn fetch_page(
+                self,
+                continuation: Option<String>,
+            ) -> futures::future::BoxFuture<'static, azure_core::Result<models::DeletedManagedHsmListResult>> {
+                Box::pin(async move {
+                    let mut url = self.url()?;
+                    let rsp = match continuation {
+                        Some(value) => {
+                            let first_page_url = url;
+                            url = azure_core::next_page_url(&first_page_url, &value)?;
+                            azure_core::client::carry_query_params(&mut url, &first_page_url, &self.query_params);
+                            let mut req = azure_core::Request::new(url, azure_core::Method::Get);
+                            let token_response = self.client.token(self.claims.as_deref()).await?;
+                            req.insert_header(
+                                azure_core::headers::AUTHORIZATION,
+                                format!("Bearer {}", token_response.token.secret()),
+                            );
+                            if let Some(accept_language) = &self.accept_language {
+                                req.insert_header(azure_core::headers::ACCEPT_LANGUAGE, accept_language.clone());
+                            }
+                            if let Some(token) = azure_core::continuation_header(&value) {
+                                req.insert_header(azure_core::headers::CONTINUATION, token.to_owned());
                             }
-                            None => {
-                                let mut req = azure_core::Request::new(url, azure_core::Method::Get);
-                                let credential = this.client.token_credential();
-                                let token_response = credential.get_token(&this.client.scopes().join(" ")).await?;
-                                req.insert_header(
-                                    azure_core::headers::AUTHORIZATION,
-                                    format!("Bearer {}", token_response.token.secret()),
-                                );
-                                req.url_mut()
-                                    .query_pairs_mut()
-                                    .append_pair(azure_core::query_param::API_VERSION, "2021-04-01-preview");
-                                let req_body = azure_core::EMPTY_BODY;
-                                req.set_body(req_body);
-                                this.client.send(&mut req).await?
+                            let req_body = azure_core::EMPTY_BODY;
+                            req.set_body(req_body);
+                            self.client.send(&mut req, "managed_hsms::list_deleted").await?
+                        }
+                        None => {
+                            let mut req = azure_core::Request::new(url, azure_core::Method::Get);
+                            let token_response = self.client.token(self.claims.as_deref()).await?;
+                            req.insert_header(
+                                azure_core::headers::AUTHORIZATION,
+                                format!("Bearer {}", token_response.token.secret()),
+                            );
+                            if let Some(accept_language) = &self.accept_language {
+                                req.insert_header(azure_core::headers::ACCEPT_LANGUAGE, accept_language.clone());
                             }
-                        };
-                        let rsp = match rsp.status() {
-                            azure_core::StatusCode::Ok => Ok(Response(rsp)),
-                            status_code => Err(azure_core::error::Error::from(azure_core::error::ErrorKind::HttpResponse {
-                                status: status_code,
-                                error_code: None,
-                            })),
-                        };
-                        rsp?.into_body().await
+                            let req_body = azure_core::EMPTY_BODY;
+                            req.set_body(req_body);
+                            let rsp = self.client.send(&mut req, "managed_hsms::list_deleted").await;
+                            if self.on_missing_empty {
+                                match azure_core::client::not_found_as_none(rsp)? {
+                                    Some(rsp) => rsp,
+                                    None => return Ok(Default::default()),
+                                }
+                            } else {
+                                rsp?
+                            }
+                        }
+                    };
+                    let rsp = match rsp.status() {
+                        azure_core::StatusCode::Ok => Ok(Response(rsp)),
+                        _ => Err(azure_core::client::unexpected_status(&rsp)),
+                    };
+                    let rsp = rsp?;
+                    let continuation = azure_core::continuation_from_headers(rsp.as_raw_response().headers());
+                    let mut body = rsp.into_body().await?;
+                    if body.next_link.is_none() {
+                        body.next_link = continuation;
                     }
-                };
-                azure_core::Pageable::new(make_request)
+                    Ok(body)
+                })
+            }
+            #[doc = "Fetch every page and return all items, blocking the current thread. Stops early if `max_items` is set."]
+            #[cfg(feature = "blocking")]
+            pub fn call(self) -> azure_core::Result<Vec<models::DeletedManagedHsm>> {
+                azure_core::blocking::block_on(futures::TryStreamExt::try_collect(self.into_item_stream()))
             }
         }
     }
@@ -2501,16 +5790,48 @@ pub mod managed_hsms {
         pub struct Response(azure_core::Response);
         impl Response {
             pub async fn into_body(self) -> azure_core::Result<models::DeletedManagedHsm> {
-                let bytes = self.0.into_body().collect().await?;
-                let body: models::DeletedManagedHsm = serde_json::from_slice(&bytes)?;
+                let status = self.0.status();
+                let bytes = self.into_byte_stream().collect().await?;
+                let body: models::DeletedManagedHsm = azure_core::client::from_json_body("managed_hsms::get_deleted", status, &bytes)?;
+                Ok(body)
+            }
+            #[doc = "Return the response body as untyped JSON; see [responses](crate::package_preview_2021_04#responses)."]
+            pub async fn into_value(self) -> azure_core::Result<serde_json::Value> {
+                let status = self.0.status();
+                let bytes = self.into_byte_stream().collect().await?;
+                let body: serde_json::Value = azure_core::client::from_json_body("managed_hsms::get_deleted", status, &bytes)?;
                 Ok(body)
             }
+            #[doc = "Stream the response body in chunks as they are received, instead of buffering all of it like `into_body`."]
+            pub fn into_byte_stream(self) -> azure_core::ResponseBody {
+                self.0.into_body()
+            }
+            pub fn status(&self) -> azure_core::StatusCode {
+                self.0.status()
+            }
             pub fn into_raw_response(self) -> azure_core::Response {
                 self.0
             }
+            #[doc = "Read the whole response and return its status, headers and body as received, for forwarding it verbatim without going through `models`."]
+            pub async fn into_raw_parts(self) -> azure_core::Result<(azure_core::StatusCode, azure_core::headers::Headers, bytes::Bytes)> {
+                let (status, headers, body) = self.0.deconstruct();
+                Ok((status, headers, body.collect().await?))
+            }
             pub fn as_raw_response(&self) -> &azure_core::Response {
                 &self.0
             }
+            #[doc = "The remaining request quota; see [responses](crate::package_preview_2021_04#responses)."]
+            pub fn rate_limit_remaining(&self) -> Option<u32> {
+                azure_core::client::rate_limit_remaining(self.0.headers())
+            }
+            #[doc = "The `x-ms-client-request-id` the request was sent with; see [responses](crate::package_preview_2021_04#responses)."]
+            pub fn client_request_id(&self) -> Option<&str> {
+                self.0.headers().get_optional_str(&azure_core::headers::CLIENT_REQUEST_ID)
+            }
+            #[doc = "How long the request took; see [responses](crate::package_preview_2021_04#responses)."]
+            pub fn elapsed(&self) -> std::time::Duration {
+                self.0.elapsed().unwrap_or_default()
+            }
         }
         impl From<Response> for azure_core::Response {
             fn from(rsp: Response) -> Self {
@@ -2528,82 +5849,242 @@ pub mod managed_hsms {
             pub(crate) name: String,
             pub(crate) location: String,
             pub(crate) subscription_id: String,
+            pub(crate) claims: Option<String>,
+            pub(crate) accept_language: Option<String>,
+            pub(crate) query_params: Vec<(String, String)>,
+        }
+        impl super::super::private::Sealed for RequestBuilder {}
+        impl super::super::Operation for RequestBuilder {
+            fn method() -> azure_core::Method {
+                azure_core::Method::Get
+            }
+            fn path_template() -> &'static str {
+                "/subscriptions/{subscriptionId}/providers/Microsoft.KeyVault/locations/{location}/deletedManagedHSMs/{name}"
+            }
+        }
+        impl super::super::BatchRead for RequestBuilder {
+            fn batch_url(&self) -> azure_core::Result<azure_core::Url> {
+                self.url()
+            }
         }
         impl RequestBuilder {
+            #[doc = "Ask the credential for a token that satisfies the JSON `claims`; see [claims](crate::package_preview_2021_04#claims)."]
+            pub fn claims(mut self, claims: impl Into<String>) -> Self {
+                self.claims = Some(claims.into());
+                self
+            }
+            #[doc = "Send this request with `accept_language` as its `Accept-Language` header, such as `fr-FR`, overriding `ClientBuilder::accept_language`."]
+            pub fn accept_language(mut self, accept_language: impl Into<String>) -> Self {
+                self.accept_language = Some(accept_language.into());
+                self
+            }
+            #[doc = "Append `key=value` to the query string; see [query parameters](crate::package_preview_2021_04#query-parameters)."]
+            pub fn query_param(mut self, key: impl Into<String>, value: impl Into<String>) -> Self {
+                self.query_params.push((key.into(), value.into()));
+                self
+            }
+            #[doc = "Build the URL the request is sent to, including the api-version and any query parameters set on the builder, without sending the request."]
+            pub fn url(&self) -> azure_core::Result<azure_core::Url> {
+                let mut url = azure_core::Url::parse(&format!(
+                    "{}/subscriptions/{}/providers/Microsoft.KeyVault/locations/{}/deletedManagedHSMs/{}",
+                    self.client.endpoint(),
+                    &azure_core::client::path_segment("subscription_id", &self.subscription_id)?,
+                    &azure_core::client::path_segment("location", &self.location)?,
+                    &azure_core::client::path_segment("name", &self.name)?
+                ))?;
+                url.query_pairs_mut()
+                    .append_pair(azure_core::query_param::API_VERSION, "2021-04-01-preview");
+                azure_core::client::append_query_params(&mut url, &self.query_params);
+                Ok(url)
+            }
             #[doc = "Send the request and returns the response."]
-            pub fn send(self) -> futures::future::BoxFuture<'static, azure_core::Result<Response>> {
-                Box::pin({
-                    let this = self.clone();
-                    async move {
-                        let url = azure_core::Url::parse(&format!(
-                            "{}/subscriptions/{}/providers/Microsoft.KeyVault/locations/{}/deletedManagedHSMs/{}",
-                            this.client.endpoint(),
-                            &this.subscription_id,
-                            &this.location,
-                            &this.name
-                        ))?;
-                        let mut req = azure_core::Request::new(url, azure_core::Method::Get);
-                        let credential = this.client.token_credential();
-                        let token_response = credential.get_token(&this.client.scopes().join(" ")).await?;
-                        req.insert_header(
-                            azure_core::headers::AUTHORIZATION,
-                            format!("Bearer {}", token_response.token.secret()),
-                        );
-                        req.url_mut()
-                            .query_pairs_mut()
-                            .append_pair(azure_core::query_param::API_VERSION, "2021-04-01-preview");
-                        let req_body = azure_core::EMPTY_BODY;
-                        req.set_body(req_body);
-                        Ok(Response(this.client.send(&mut req).await?))
-                    }
-                })
+            #[doc = ""]
+            #[doc = "The builder is not consumed, so a polling loop can build it once and call `send` on every iteration; each call fetches a fresh token. The returned future is `'static` because it owns a clone of the builder, so it can be spawned or stored."]
+            pub fn send(&self) -> futures::future::BoxFuture<'static, azure_core::Result<Response>> {
+                let this = self.clone();
+                Box::pin(async move { this.send_ref().await })
+            }
+            #[doc = "Send the request without consuming the builder."]
+            #[doc = ""]
+            #[doc = "Unlike `send`, which clones the builder so that its future is `'static`, the request is built from borrowed fields, so a builder kept across calls, such as in a polling loop, is sent again without being cloned. The future borrows the builder in turn."]
+            pub async fn send_ref(&self) -> azure_core::Result<Response> {
+                let url = self.url()?;
+                let mut req = azure_core::Request::new(url, azure_core::Method::Get);
+                let token_response = self.client.token(self.claims.as_deref()).await?;
+                req.insert_header(
+                    azure_core::headers::AUTHORIZATION,
+                    format!("Bearer {}", token_response.token.secret()),
+                );
+                if let Some(accept_language) = &self.accept_language {
+                    req.insert_header(azure_core::headers::ACCEPT_LANGUAGE, accept_language.clone());
+                }
+                let req_body = azure_core::EMPTY_BODY;
+                req.set_body(req_body);
+                Ok(Response(self.client.send(&mut req, "managed_hsms::get_deleted").await?))
             }
             #[doc = "Send the request and return the response body."]
             pub fn into_future(self) -> futures::future::BoxFuture<'static, azure_core::Result<models::DeletedManagedHsm>> {
                 Box::pin(async move { self.send().await?.into_body().await })
             }
+            #[doc = "Send the request and return the response body together with the response headers, such as the `ETag`."]
+            pub fn into_future_with_headers(
+                self,
+            ) -> futures::future::BoxFuture<'static, azure_core::Result<(models::DeletedManagedHsm, azure_core::headers::Headers)>>
+            {
+                Box::pin(async move {
+                    let rsp = self.send().await?;
+                    let headers = rsp.as_raw_response().headers().clone();
+                    Ok((rsp.into_body().await?, headers))
+                })
+            }
+            #[doc = "Send the request and return the response body, blocking the current thread."]
+            #[cfg(feature = "blocking")]
+            pub fn call(self) -> azure_core::Result<models::DeletedManagedHsm> {
+                azure_core::blocking::block_on(self.into_future())
+            }
         }
     }
     pub mod purge_deleted {
         use super::models;
         pub struct Response(azure_core::Response);
+        impl Response {
+            #[doc = "Stream the response body in chunks as they are received, instead of buffering all of it like `into_body`."]
+            pub fn into_byte_stream(self) -> azure_core::ResponseBody {
+                self.0.into_body()
+            }
+            pub fn status(&self) -> azure_core::StatusCode {
+                self.0.status()
+            }
+            pub fn into_raw_response(self) -> azure_core::Response {
+                self.0
+            }
+            #[doc = "Read the whole response and return its status, headers and body as received, for forwarding it verbatim without going through `models`."]
+            pub async fn into_raw_parts(self) -> azure_core::Result<(azure_core::StatusCode, azure_core::headers::Headers, bytes::Bytes)> {
+                let (status, headers, body) = self.0.deconstruct();
+                Ok((status, headers, body.collect().await?))
+            }
+            pub fn as_raw_response(&self) -> &azure_core::Response {
+                &self.0
+            }
+            #[doc = "The remaining request quota; see [responses](crate::package_preview_2021_04#responses)."]
+            pub fn rate_limit_remaining(&self) -> Option<u32> {
+                azure_core::client::rate_limit_remaining(self.0.headers())
+            }
+            #[doc = "The `x-ms-client-request-id` the request was sent with; see [responses](crate::package_preview_2021_04#responses)."]
+            pub fn client_request_id(&self) -> Option<&str> {
+                self.0.headers().get_optional_str(&azure_core::headers::CLIENT_REQUEST_ID)
+            }
+            #[doc = "How long the request took; see [responses](crate::package_preview_2021_04#responses)."]
+            pub fn elapsed(&self) -> std::time::Duration {
+                self.0.elapsed().unwrap_or_default()
+            }
+        }
+        impl From<Response> for azure_core::Response {
+            fn from(rsp: Response) -> Self {
+                rsp.into_raw_response()
+            }
+        }
+        impl AsRef<azure_core::Response> for Response {
+            fn as_ref(&self) -> &azure_core::Response {
+                self.as_raw_response()
+            }
+        }
         #[derive(Clone)]
         pub struct RequestBuilder {
             pub(crate) client: super::super::Client,
             pub(crate) name: String,
             pub(crate) location: String,
             pub(crate) subscription_id: String,
+            pub(crate) claims: Option<String>,
+            pub(crate) accept_language: Option<String>,
+            pub(crate) idempotent: bool,
+            pub(crate) query_params: Vec<(String, String)>,
+        }
+        impl super::super::private::Sealed for RequestBuilder {}
+        impl super::super::Operation for RequestBuilder {
+            fn method() -> azure_core::Method {
+                azure_core::Method::Post
+            }
+            fn path_template() -> &'static str {
+                "/subscriptions/{subscriptionId}/providers/Microsoft.KeyVault/locations/{location}/deletedManagedHSMs/{name}/purge"
+            }
         }
         impl RequestBuilder {
+            #[doc = "Ask the credential for a token that satisfies the JSON `claims`; see [claims](crate::package_preview_2021_04#claims)."]
+            pub fn claims(mut self, claims: impl Into<String>) -> Self {
+                self.claims = Some(claims.into());
+                self
+            }
+            #[doc = "Send this request with `accept_language` as its `Accept-Language` header, such as `fr-FR`, overriding `ClientBuilder::accept_language`."]
+            pub fn accept_language(mut self, accept_language: impl Into<String>) -> Self {
+                self.accept_language = Some(accept_language.into());
+                self
+            }
+            #[doc = "Mark the request as safe to repeat, so the retry policy retries it after a connection error."]
+            #[doc = ""]
+            #[doc = "This request is not idempotent by default, and repeating it may repeat its side effect, so it is not retried after a connection error or timed out attempt unless this is set."]
+            pub fn idempotent(mut self, idempotent: bool) -> Self {
+                self.idempotent = idempotent;
+                self
+            }
+            #[doc = "Append `key=value` to the query string; see [query parameters](crate::package_preview_2021_04#query-parameters)."]
+            pub fn query_param(mut self, key: impl Into<String>, value: impl Into<String>) -> Self {
+                self.query_params.push((key.into(), value.into()));
+                self
+            }
+            #[doc = "Build the URL the request is sent to, including the api-version and any query parameters set on the builder, without sending the request."]
+            pub fn url(&self) -> azure_core::Result<azure_core::Url> {
+                let mut url = azure_core::Url::parse(&format!(
+                    "{}/subscriptions/{}/providers/Microsoft.KeyVault/locations/{}/deletedManagedHSMs/{}/purge",
+                    self.client.endpoint(),
+                    &azure_core::client::path_segment("subscription_id", &self.subscription_id)?,
+                    &azure_core::client::path_segment("location", &self.location)?,
+                    &azure_core::client::path_segment("name", &self.name)?
+                ))?;
+                url.query_pairs_mut()
+                    .append_pair(azure_core::query_param::API_VERSION, "2021-04-01-preview");
+                azure_core::client::append_query_params(&mut url, &self.query_params);
+                Ok(url)
+            }
             #[doc = "only the first response will be fetched as long running operations are not supported yet"]
             #[doc = "Send the request and returns the response."]
-            pub fn send(self) -> futures::future::BoxFuture<'static, azure_core::Result<Response>> {
-                Box::pin({
-                    let this = self.clone();
-                    async move {
-                        let url = azure_core::Url::parse(&format!(
-                            "{}/subscriptions/{}/providers/Microsoft.KeyVault/locations/{}/deletedManagedHSMs/{}/purge",
-                            this.client.endpoint(),
-                            &this.subscription_id,
-                            &this.location,
-                            &this.name
-                        ))?;
-                        let mut req = azure_core::Request::new(url, azure_core::Method::Post);
-                        let credential = this.client.token_credential();
-                        let token_response = credential.get_token(&this.client.scopes().join(" ")).await?;
-                        req.insert_header(
-                            azure_core::headers::AUTHORIZATION,
-                            format!("Bearer {}", token_response.token.secret()),
-                        );
-                        req.url_mut()
-                            .query_pairs_mut()
-                            .append_pair(azure_core::query_param::API_VERSION, "2021-04-01-preview");
-                        let req_body = azure_core::EMPTY_BODY;
-                        req.insert_header(azure_core::headers::CONTENT_LENGTH, "0");
-                        req.set_body(req_body);
-                        Ok(Response(this.client.send(&mut req).await?))
-                    }
-                })
+            #[doc = ""]
+            #[doc = "The builder is not consumed, so a polling loop can build it once and call `send` on every iteration; each call fetches a fresh token. The returned future is `'static` because it owns a clone of the builder, so it can be spawned or stored."]
+            pub fn send(&self) -> futures::future::BoxFuture<'static, azure_core::Result<Response>> {
+                let this = self.clone();
+                Box::pin(async move { this.send_ref().await })
+            }
+            #[doc = "Send the request without consuming the builder."]
+            #[doc = ""]
+            #[doc = "Unlike `send`, which clones the builder so that its future is `'static`, the request is built from borrowed fields, so a builder kept across calls, such as in a polling loop, is sent again without being cloned. The future borrows the builder in turn."]
+            pub async fn send_ref(&self) -> azure_core::Result<Response> {
+                let url = self.url()?;
+                let mut req = azure_core::Request::new(url, azure_core::Method::Post);
+                let token_response = self.client.token(self.claims.as_deref()).await?;
+                req.insert_header(
+                    azure_core::headers::AUTHORIZATION,
+                    format!("Bearer {}", token_response.token.secret()),
+                );
+                if let Some(accept_language) = &self.accept_language {
+                    req.insert_header(azure_core::headers::ACCEPT_LANGUAGE, accept_language.clone());
+                }
+                let req_body = azure_core::EMPTY_BODY;
+                req.insert_header(azure_core::headers::CONTENT_LENGTH, "0");
+                req.set_body(req_body);
+                let mut context = azure_core::Context::new();
+                if self.idempotent {
+                    context.insert(azure_core::Idempotent);
+                }
+                Ok(Response(
+                    self.client
+                        .send_with_context(&mut req, "managed_hsms::purge_deleted", context)
+                        .await?,
+                ))
+            }
+            #[doc = "Send the request and return the response, blocking the current thread."]
+            #[cfg(feature = "blocking")]
+            pub fn call(self) -> azure_core::Result<Response> {
+                azure_core::blocking::block_on(self.send())
             }
         }
     }
@@ -2629,6 +6110,13 @@ pub mod mhsm_private_endpoint_connections {
                 subscription_id: subscription_id.into(),
                 resource_group_name: resource_group_name.into(),
                 name: name.into(),
+                claims: None,
+                accept_language: None,
+                query_params: Vec::new(),
+                select: None,
+                max_items: None,
+                on_missing_empty: false,
+                resume_from: None,
             }
         }
         #[doc = "Gets the specified private endpoint connection associated with the managed HSM Pool."]
@@ -2651,6 +6139,10 @@ pub mod mhsm_private_endpoint_connections {
                 resource_group_name: resource_group_name.into(),
                 name: name.into(),
                 private_endpoint_connection_name: private_endpoint_connection_name.into(),
+                claims: None,
+                accept_language: None,
+                if_none_match: None,
+                query_params: Vec::new(),
             }
         }
         #[doc = "Updates the specified private endpoint connection associated with the managed hsm pool."]
@@ -2676,6 +6168,10 @@ pub mod mhsm_private_endpoint_connections {
                 name: name.into(),
                 private_endpoint_connection_name: private_endpoint_connection_name.into(),
                 properties: properties.into(),
+                claims: None,
+                accept_language: None,
+                query_params: Vec::new(),
+                raw_body: None,
             }
         }
         #[doc = "Deletes the specified private endpoint connection associated with the managed hsm pool."]
@@ -2698,6 +6194,9 @@ pub mod mhsm_private_endpoint_connections {
                 resource_group_name: resource_group_name.into(),
                 name: name.into(),
                 private_endpoint_connection_name: private_endpoint_connection_name.into(),
+                claims: None,
+                accept_language: None,
+                query_params: Vec::new(),
             }
         }
     }
@@ -2706,16 +6205,56 @@ pub mod mhsm_private_endpoint_connections {
         pub struct Response(azure_core::Response);
         impl Response {
             pub async fn into_body(self) -> azure_core::Result<models::MhsmPrivateEndpointConnectionsListResult> {
-                let bytes = self.0.into_body().collect().await?;
-                let body: models::MhsmPrivateEndpointConnectionsListResult = serde_json::from_slice(&bytes)?;
+                let status = self.0.status();
+                let bytes = self.into_byte_stream().collect().await?;
+                let body: models::MhsmPrivateEndpointConnectionsListResult =
+                    azure_core::client::from_json_body("mhsm_private_endpoint_connections::list_by_resource", status, &bytes)?;
+                Ok(body)
+            }
+            #[doc = "Stream the items of the page as they are received, instead of buffering the whole body like `into_body`. Everything but the `value` array, including the link to the next page, is skipped."]
+            pub fn into_body_streaming(
+                self,
+            ) -> futures::stream::BoxStream<'static, azure_core::Result<models::MhsmPrivateEndpointConnection>> {
+                self.into_byte_stream().json_array_items("value")
+            }
+            #[doc = "Return the response body as untyped JSON; see [responses](crate::package_preview_2021_04#responses)."]
+            pub async fn into_value(self) -> azure_core::Result<serde_json::Value> {
+                let status = self.0.status();
+                let bytes = self.into_byte_stream().collect().await?;
+                let body: serde_json::Value =
+                    azure_core::client::from_json_body("mhsm_private_endpoint_connections::list_by_resource", status, &bytes)?;
                 Ok(body)
             }
+            #[doc = "Stream the response body in chunks as they are received, instead of buffering all of it like `into_body`."]
+            pub fn into_byte_stream(self) -> azure_core::ResponseBody {
+                self.0.into_body()
+            }
+            pub fn status(&self) -> azure_core::StatusCode {
+                self.0.status()
+            }
             pub fn into_raw_response(self) -> azure_core::Response {
                 self.0
             }
+            #[doc = "Read the whole response and return its status, headers and body as received, for forwarding it verbatim without going through `models`."]
+            pub async fn into_raw_parts(self) -> azure_core::Result<(azure_core::StatusCode, azure_core::headers::Headers, bytes::Bytes)> {
+                let (status, headers, body) = self.0.deconstruct();
+                Ok((status, headers, body.collect().await?))
+            }
             pub fn as_raw_response(&self) -> &azure_core::Response {
                 &self.0
             }
+            #[doc = "The remaining request quota; see [responses](crate::package_preview_2021_04#responses)."]
+            pub fn rate_limit_remaining(&self) -> Option<u32> {
+                azure_core::client::rate_limit_remaining(self.0.headers())
+            }
+            #[doc = "The `x-ms-client-request-id` the request was sent with; see [responses](crate::package_preview_2021_04#responses)."]
+            pub fn client_request_id(&self) -> Option<&str> {
+                self.0.headers().get_optional_str(&azure_core::headers::CLIENT_REQUEST_ID)
+            }
+            #[doc = "How long the request took; see [responses](crate::package_preview_2021_04#responses)."]
+            pub fn elapsed(&self) -> std::time::Duration {
+                self.0.elapsed().unwrap_or_default()
+            }
         }
         impl From<Response> for azure_core::Response {
             fn from(rsp: Response) -> Self {
@@ -2733,68 +6272,197 @@ pub mod mhsm_private_endpoint_connections {
             pub(crate) subscription_id: String,
             pub(crate) resource_group_name: String,
             pub(crate) name: String,
+            pub(crate) claims: Option<String>,
+            pub(crate) accept_language: Option<String>,
+            pub(crate) query_params: Vec<(String, String)>,
+            pub(crate) select: Option<String>,
+            pub(crate) max_items: Option<usize>,
+            pub(crate) on_missing_empty: bool,
+            pub(crate) resume_from: Option<String>,
+        }
+        impl super::super::private::Sealed for RequestBuilder {}
+        impl super::super::Operation for RequestBuilder {
+            fn method() -> azure_core::Method {
+                azure_core::Method::Get
+            }
+            fn path_template() -> &'static str {
+                "/subscriptions/{subscriptionId}/resourceGroups/{resourceGroupName}/providers/Microsoft.KeyVault/managedHSMs/{name}/privateEndpointConnections"
+            }
+        }
+        impl super::super::BatchRead for RequestBuilder {
+            fn batch_url(&self) -> azure_core::Result<azure_core::Url> {
+                self.url()
+            }
         }
         impl RequestBuilder {
+            #[doc = "Ask the credential for a token that satisfies the JSON `claims`; see [claims](crate::package_preview_2021_04#claims)."]
+            pub fn claims(mut self, claims: impl Into<String>) -> Self {
+                self.claims = Some(claims.into());
+                self
+            }
+            #[doc = "Send this request with `accept_language` as its `Accept-Language` header, such as `fr-FR`, overriding `ClientBuilder::accept_language`."]
+            pub fn accept_language(mut self, accept_language: impl Into<String>) -> Self {
+                self.accept_language = Some(accept_language.into());
+                self
+            }
+            #[doc = "Append `key=value` to the query string; see [query parameters](crate::package_preview_2021_04#query-parameters)."]
+            pub fn query_param(mut self, key: impl Into<String>, value: impl Into<String>) -> Self {
+                self.query_params.push((key.into(), value.into()));
+                self
+            }
+            #[doc = "Only return the given properties of each item, such as `[\"id\", \"name\"]`, to reduce the work done by the service and the size of each page."]
+            #[doc = ""]
+            #[doc = "Fields of the returned models that were not selected are `None`."]
+            pub fn select(mut self, select: &[&str]) -> Self {
+                self.select = Some(select.join(","));
+                self
+            }
+            #[doc = "Limit the total number of items yielded by `into_item_stream`. No further pages are requested once the limit is reached."]
+            pub fn max_items(mut self, max_items: usize) -> Self {
+                self.max_items = Some(max_items);
+                self
+            }
+            #[doc = "Treat `404 Not Found` on the first page as an empty list instead of an error."]
+            #[doc = ""]
+            #[doc = "Useful when the parent resource may not exist yet, such as in a reconciliation loop racing its provisioning. The stream then yields a single empty page. Off by default, and a `404` on a later page is always an error."]
+            pub fn on_missing_empty(mut self, on_missing_empty: bool) -> Self {
+                self.on_missing_empty = on_missing_empty;
+                self
+            }
+            #[doc = "Start paging from `continuation`, a token saved from `into_checkpoint_stream` or `page`, instead of from the first page."]
+            #[doc = ""]
+            #[doc = "Lets a long listing be checkpointed and picked up where it stopped, such as after a restart."]
+            pub fn resume_from(mut self, continuation: impl Into<String>) -> Self {
+                self.resume_from = Some(continuation.into());
+                self
+            }
+            #[doc = "Build the URL the request is sent to, including the api-version and any query parameters set on the builder, without sending the request."]
+            pub fn url(&self) -> azure_core::Result<azure_core::Url> {
+                let mut url = azure_core::Url::parse(&format!(
+                    "{}/subscriptions/{}/resourceGroups/{}/providers/Microsoft.KeyVault/managedHSMs/{}/privateEndpointConnections",
+                    self.client.endpoint(),
+                    &azure_core::client::path_segment("subscription_id", &self.subscription_id)?,
+                    &azure_core::client::path_segment("resource_group_name", &self.resource_group_name)?,
+                    &azure_core::client::path_segment("name", &self.name)?
+                ))?;
+                url.query_pairs_mut()
+                    .append_pair(azure_core::query_param::API_VERSION, "2021-04-01-preview");
+                if let Some(select) = &self.select {
+                    url.query_pairs_mut().append_pair("$select", select);
+                }
+                azure_core::client::append_query_params(&mut url, &self.query_params);
+                Ok(url)
+            }
+            #[doc = "Stream the items of every page, stopping early if `max_items` is set."]
+            pub fn into_item_stream(
+                self,
+            ) -> futures::stream::BoxStream<'static, azure_core::Result<models::MhsmPrivateEndpointConnection>> {
+                let max_items = self.max_items;
+                azure_core::client::item_stream(self.into_stream(), max_items, |page| page.value)
+            }
+            #[doc = "Stream the items of each page together with the continuation token that resumes after it, or `None` after the last page."]
+            #[doc = ""]
+            #[doc = "Save the token once a page has been processed and pass it to `resume_from` to carry on from there later. `max_items` is not applied."]
+            pub fn into_checkpoint_stream(
+                self,
+            ) -> futures::stream::BoxStream<'static, azure_core::Result<(Vec<models::MhsmPrivateEndpointConnection>, Option<String>)>>
+            {
+                azure_core::client::checkpoint_stream(self.into_stream(), |page| page.value)
+            }
             pub fn into_stream(self) -> azure_core::Pageable<models::MhsmPrivateEndpointConnectionsListResult, azure_core::error::Error> {
                 let make_request = move |continuation: Option<String>| {
-                    let this = self.clone();
-                    async move {
-                        let mut url = azure_core::Url::parse(&format!(
-                            "{}/subscriptions/{}/resourceGroups/{}/providers/Microsoft.KeyVault/managedHSMs/{}/privateEndpointConnections",
-                            this.client.endpoint(),
-                            &this.subscription_id,
-                            &this.resource_group_name,
-                            &this.name
-                        ))?;
-                        let rsp = match continuation {
-                            Some(value) => {
-                                url.set_path("");
-                                url = url.join(&value)?;
-                                let mut req = azure_core::Request::new(url, azure_core::Method::Get);
-                                let credential = this.client.token_credential();
-                                let token_response = credential.get_token(&this.client.scopes().join(" ")).await?;
-                                req.insert_header(
-                                    azure_core::headers::AUTHORIZATION,
-                                    format!("Bearer {}", token_response.token.secret()),
-                                );
-                                let has_api_version_already =
-                                    req.url_mut().query_pairs().any(|(k, _)| k == azure_core::query_param::API_VERSION);
-                                if !has_api_version_already {
-                                    req.url_mut()
-                                        .query_pairs_mut()
-                                        .append_pair(azure_core::query_param::API_VERSION, "2021-04-01-preview");
-                                }
-                                let req_body = azure_core::EMPTY_BODY;
-                                req.set_body(req_body);
-                                this.client.send(&mut req).await?
+                    let continuation = continuation.or_else(|| self.resume_from.clone());
+                    self.clone().fetch_page(continuation)
+                };
+                azure_core::Pageable::new(make_request)
+            }
+            #[doc = "Fetch a single page and return its body together with the continuation token for the next page, if any."]
+            #[doc = ""]
+            #[doc = "Pass `None` to fetch the first page, or a token returned by a previous call to fetch the page that follows it."]
+            pub fn page(
+                &self,
+                continuation: Option<String>,
+            ) -> futures::future::BoxFuture<'static, azure_core::Result<(models::MhsmPrivateEndpointConnectionsListResult, Option<String>)>>
+            {
+                let this = self.clone();
+                Box::pin(async move {
+                    let body = this.fetch_page(continuation).await?;
+                    let continuation = azure_core::Continuable::continuation(&body);
+                    Ok((body, continuation))
+                })
+            }
+            fn fetch_page(
+                self,
+                continuation: Option<String>,
+            ) -> futures::future::BoxFuture<'static, azure_core::Result<models::MhsmPrivateEndpointConnectionsListResult>> {
+                Box::pin(async move {
+                    let mut url = self.url()?;
+                    let rsp = match continuation {
+                        Some(value) => {
+                            let first_page_url = url;
+                            url = azure_core::next_page_url(&first_page_url, &value)?;
+                            azure_core::client::carry_query_params(&mut url, &first_page_url, &self.query_params);
+                            let mut req = azure_core::Request::new(url, azure_core::Method::Get);
+                            let token_response = self.client.token(self.claims.as_deref()).await?;
+                            req.insert_header(
+                                azure_core::headers::AUTHORIZATION,
+                                format!("Bearer {}", token_response.token.secret()),
+                            );
+                            if let Some(accept_language) = &self.accept_language {
+                                req.insert_header(azure_core::headers::ACCEPT_LANGUAGE, accept_language.clone());
+                            }
+                            if let Some(token) = azure_core::continuation_header(&value) {
+                                req.insert_header(azure_core::headers::CONTINUATION, token.to_owned());
                             }
-                            None => {
-                                let mut req = azure_core::Request::new(url, azure_core::Method::Get);
-                                let credential = this.client.token_credential();
-                                let token_response = credential.get_token(&this.client.scopes().join(" ")).await?;
-                                req.insert_header(
-                                    azure_core::headers::AUTHORIZATION,
-                                    format!("Bearer {}", token_response.token.secret()),
-                                );
-                                req.url_mut()
-                                    .query_pairs_mut()
-                                    .append_pair(azure_core::query_param::API_VERSION, "2021-04-01-preview");
-                                let req_body = azure_core::EMPTY_BODY;
-                                req.set_body(req_body);
-                                this.client.send(&mut req).await?
+                            let req_body = azure_core::EMPTY_BODY;
+                            req.set_body(req_body);
+                            self.client
+                                .send(&mut req, "mhsm_private_endpoint_connections::list_by_resource")
+                                .await?
+                        }
+                        None => {
+                            let mut req = azure_core::Request::new(url, azure_core::Method::Get);
+                            let token_response = self.client.token(self.claims.as_deref()).await?;
+                            req.insert_header(
+                                azure_core::headers::AUTHORIZATION,
+                                format!("Bearer {}", token_response.token.secret()),
+                            );
+                            if let Some(accept_language) = &self.accept_language {
+                                req.insert_header(azure_core::headers::ACCEPT_LANGUAGE, accept_language.clone());
                             }
-                        };
-                        let rsp = match rsp.status() {
-                            azure_core::StatusCode::Ok => Ok(Response(rsp)),
-                            status_code => Err(azure_core::error::Error::from(azure_core::error::ErrorKind::HttpResponse {
-                                status: status_code,
-                                error_code: None,
-                            })),
-                        };
-                        rsp?.into_body().await
+                            let req_body = azure_core::EMPTY_BODY;
+                            req.set_body(req_body);
+                            let rsp = self
+                                .client
+                                .send(&mut req, "mhsm_private_endpoint_connections::list_by_resource")
+                                .await;
+                            if self.on_missing_empty {
+                                match azure_core::client::not_found_as_none(rsp)? {
+                                    Some(rsp) => rsp,
+                                    None => return Ok(Default::default()),
+                                }
+                            } else {
+                                rsp?
+                            }
+                        }
+                    };
+                    let rsp = match rsp.status() {
+                        azure_core::StatusCode::Ok => Ok(Response(rsp)),
+                        _ => Err(azure_core::client::unexpected_status(&rsp)),
+                    };
+                    let rsp = rsp?;
+                    let continuation = azure_core::continuation_from_headers(rsp.as_raw_response().headers());
+                    let mut body = rsp.into_body().await?;
+                    if body.next_link.is_none() {
+                        body.next_link = continuation;
                     }
-                };
-                azure_core::Pageable::new(make_request)
+                    Ok(body)
+                })
+            }
+            #[doc = "Fetch every page and return all items, blocking the current thread. Stops early if `max_items` is set."]
+            #[cfg(feature = "blocking")]
+            pub fn call(self) -> azure_core::Result<Vec<models::MhsmPrivateEndpointConnection>> {
+                azure_core::blocking::block_on(futures::TryStreamExt::try_collect(self.into_item_stream()))
             }
         }
     }
@@ -2803,16 +6471,69 @@ pub mod mhsm_private_endpoint_connections {
         pub struct Response(azure_core::Response);
         impl Response {
             pub async fn into_body(self) -> azure_core::Result<models::MhsmPrivateEndpointConnection> {
-                let bytes = self.0.into_body().collect().await?;
-                let body: models::MhsmPrivateEndpointConnection = serde_json::from_slice(&bytes)?;
+                if self.is_not_modified() {
+                    return Err(azure_core::error::ErrorKind::HttpResponse {
+                        status: azure_core::StatusCode::NotModified,
+                        error_code: None,
+                    }
+                    .into_error());
+                }
+                let status = self.0.status();
+                let bytes = self.into_byte_stream().collect().await?;
+                let body: models::MhsmPrivateEndpointConnection =
+                    azure_core::client::from_json_body("mhsm_private_endpoint_connections::get", status, &bytes)?;
+                Ok(body)
+            }
+            #[doc = "Return the response body as untyped JSON; see [responses](crate::package_preview_2021_04#responses)."]
+            pub async fn into_value(self) -> azure_core::Result<serde_json::Value> {
+                if self.is_not_modified() {
+                    return Err(azure_core::error::ErrorKind::HttpResponse {
+                        status: azure_core::StatusCode::NotModified,
+                        error_code: None,
+                    }
+                    .into_error());
+                }
+                let status = self.0.status();
+                let bytes = self.into_byte_stream().collect().await?;
+                let body: serde_json::Value = azure_core::client::from_json_body("mhsm_private_endpoint_connections::get", status, &bytes)?;
                 Ok(body)
             }
+            #[doc = "Whether the service answered a request sent with `if_none_match` with `304 Not Modified`. Such a response has no body, but carries the headers the service sent, so `concurrency_token`, `client_request_id` and `elapsed` still report them."]
+            #[doc = ""]
+            #[doc = "Derived from the status code alone, so polling loops can count unchanged responses against transferred bodies to track their cache hit rate."]
+            pub fn is_not_modified(&self) -> bool {
+                self.0.status() == azure_core::StatusCode::NotModified
+            }
+            #[doc = "Stream the response body in chunks as they are received, instead of buffering all of it like `into_body`."]
+            pub fn into_byte_stream(self) -> azure_core::ResponseBody {
+                self.0.into_body()
+            }
+            pub fn status(&self) -> azure_core::StatusCode {
+                self.0.status()
+            }
             pub fn into_raw_response(self) -> azure_core::Response {
                 self.0
             }
+            #[doc = "Read the whole response and return its status, headers and body as received, for forwarding it verbatim without going through `models`."]
+            pub async fn into_raw_parts(self) -> azure_core::Result<(azure_core::StatusCode, azure_core::headers::Headers, bytes::Bytes)> {
+                let (status, headers, body) = self.0.deconstruct();
+                Ok((status, headers, body.collect().await?))
+            }
             pub fn as_raw_response(&self) -> &azure_core::Response {
                 &self.0
             }
+            #[doc = "The remaining request quota; see [responses](crate::package_preview_2021_04#responses)."]
+            pub fn rate_limit_remaining(&self) -> Option<u32> {
+                azure_core::client::rate_limit_remaining(self.0.headers())
+            }
+            #[doc = "The `x-ms-client-request-id` the request was sent with; see [responses](crate::package_preview_2021_04#responses)."]
+            pub fn client_request_id(&self) -> Option<&str> {
+                self.0.headers().get_optional_str(&azure_core::headers::CLIENT_REQUEST_ID)
+            }
+            #[doc = "How long the request took; see [responses](crate::package_preview_2021_04#responses)."]
+            pub fn elapsed(&self) -> std::time::Duration {
+                self.0.elapsed().unwrap_or_default()
+            }
         }
         impl From<Response> for azure_core::Response {
             fn from(rsp: Response) -> Self {
@@ -2831,34 +6552,124 @@ pub mod mhsm_private_endpoint_connections {
             pub(crate) resource_group_name: String,
             pub(crate) name: String,
             pub(crate) private_endpoint_connection_name: String,
+            pub(crate) claims: Option<String>,
+            pub(crate) accept_language: Option<String>,
+            pub(crate) if_none_match: Option<String>,
+            pub(crate) query_params: Vec<(String, String)>,
+        }
+        impl super::super::private::Sealed for RequestBuilder {}
+        impl super::super::Operation for RequestBuilder {
+            fn method() -> azure_core::Method {
+                azure_core::Method::Get
+            }
+            fn path_template() -> &'static str {
+                "/subscriptions/{subscriptionId}/resourceGroups/{resourceGroupName}/providers/Microsoft.KeyVault/managedHSMs/{name}/privateEndpointConnections/{privateEndpointConnectionName}"
+            }
+        }
+        impl super::super::BatchRead for RequestBuilder {
+            fn batch_url(&self) -> azure_core::Result<azure_core::Url> {
+                self.url()
+            }
         }
         impl RequestBuilder {
+            #[doc = "Ask the credential for a token that satisfies the JSON `claims`; see [claims](crate::package_preview_2021_04#claims)."]
+            pub fn claims(mut self, claims: impl Into<String>) -> Self {
+                self.claims = Some(claims.into());
+                self
+            }
+            #[doc = "Send this request with `accept_language` as its `Accept-Language` header, such as `fr-FR`, overriding `ClientBuilder::accept_language`."]
+            pub fn accept_language(mut self, accept_language: impl Into<String>) -> Self {
+                self.accept_language = Some(accept_language.into());
+                self
+            }
+            #[doc = "Only return the resource if its ETag does not match `if_none_match`. Otherwise the response has status `304 Not Modified` and no body."]
+            pub fn if_none_match(mut self, if_none_match: impl Into<String>) -> Self {
+                self.if_none_match = Some(if_none_match.into());
+                self
+            }
+            #[doc = "Append `key=value` to the query string; see [query parameters](crate::package_preview_2021_04#query-parameters)."]
+            pub fn query_param(mut self, key: impl Into<String>, value: impl Into<String>) -> Self {
+                self.query_params.push((key.into(), value.into()));
+                self
+            }
+            #[doc = "Build the URL the request is sent to, including the api-version and any query parameters set on the builder, without sending the request."]
+            pub fn url(&self) -> azure_core::Result<azure_core::Url> {
+                let mut url = azure_core::Url::parse(&format!(
+                    "{}/subscriptions/{}/resourceGroups/{}/providers/Microsoft.KeyVault/managedHSMs/{}/privateEndpointConnections/{}",
+                    self.client.endpoint(),
+                    &azure_core::client::path_segment("subscription_id", &self.subscription_id)?,
+                    &azure_core::client::path_segment("resource_group_name", &self.resource_group_name)?,
+                    &azure_core::client::path_segment("name", &self.name)?,
+                    &azure_core::client::path_segment("private_endpoint_connection_name", &self.private_endpoint_connection_name)?
+                ))?;
+                url.query_pairs_mut()
+                    .append_pair(azure_core::query_param::API_VERSION, "2021-04-01-preview");
+                azure_core::client::append_query_params(&mut url, &self.query_params);
+                Ok(url)
+            }
             #[doc = "Send the request and returns the response."]
-            pub fn send(self) -> futures::future::BoxFuture<'static, azure_core::Result<Response>> {
-                Box::pin({
-                    let this = self.clone();
-                    async move {
-                        let url = azure_core :: Url :: parse (& format ! ("{}/subscriptions/{}/resourceGroups/{}/providers/Microsoft.KeyVault/managedHSMs/{}/privateEndpointConnections/{}" , this . client . endpoint () , & this . subscription_id , & this . resource_group_name , & this . name , & this . private_endpoint_connection_name)) ? ;
-                        let mut req = azure_core::Request::new(url, azure_core::Method::Get);
-                        let credential = this.client.token_credential();
-                        let token_response = credential.get_token(&this.client.scopes().join(" ")).await?;
-                        req.insert_header(
-                            azure_core::headers::AUTHORIZATION,
-                            format!("Bearer {}", token_response.token.secret()),
-                        );
-                        req.url_mut()
-                            .query_pairs_mut()
-                            .append_pair(azure_core::query_param::API_VERSION, "2021-04-01-preview");
-                        let req_body = azure_core::EMPTY_BODY;
-                        req.set_body(req_body);
-                        Ok(Response(this.client.send(&mut req).await?))
-                    }
-                })
+            #[doc = ""]
+            #[doc = "The builder is not consumed, so a polling loop can build it once and call `send` on every iteration; each call fetches a fresh token. The returned future is `'static` because it owns a clone of the builder, so it can be spawned or stored."]
+            pub fn send(&self) -> futures::future::BoxFuture<'static, azure_core::Result<Response>> {
+                let this = self.clone();
+                Box::pin(async move { this.send_ref().await })
+            }
+            #[doc = "Send the request without consuming the builder."]
+            #[doc = ""]
+            #[doc = "Unlike `send`, which clones the builder so that its future is `'static`, the request is built from borrowed fields, so a builder kept across calls, such as in a polling loop, is sent again without being cloned. The future borrows the builder in turn."]
+            pub async fn send_ref(&self) -> azure_core::Result<Response> {
+                let url = self.url()?;
+                let mut req = azure_core::Request::new(url, azure_core::Method::Get);
+                let token_response = self.client.token(self.claims.as_deref()).await?;
+                req.insert_header(
+                    azure_core::headers::AUTHORIZATION,
+                    format!("Bearer {}", token_response.token.secret()),
+                );
+                if let Some(accept_language) = &self.accept_language {
+                    req.insert_header(azure_core::headers::ACCEPT_LANGUAGE, accept_language.clone());
+                }
+                if let Some(if_none_match) = &self.if_none_match {
+                    req.insert_header(azure_core::headers::IF_NONE_MATCH, if_none_match);
+                }
+                let req_body = azure_core::EMPTY_BODY;
+                req.set_body(req_body);
+                Ok(Response(
+                    self.client.send(&mut req, "mhsm_private_endpoint_connections::get").await?,
+                ))
             }
             #[doc = "Send the request and return the response body."]
             pub fn into_future(self) -> futures::future::BoxFuture<'static, azure_core::Result<models::MhsmPrivateEndpointConnection>> {
                 Box::pin(async move { self.send().await?.into_body().await })
             }
+            #[doc = "Send the request and return the response body together with the response headers, such as the `ETag`."]
+            pub fn into_future_with_headers(
+                self,
+            ) -> futures::future::BoxFuture<
+                'static,
+                azure_core::Result<(models::MhsmPrivateEndpointConnection, azure_core::headers::Headers)>,
+            > {
+                Box::pin(async move {
+                    let rsp = self.send().await?;
+                    let headers = rsp.as_raw_response().headers().clone();
+                    Ok((rsp.into_body().await?, headers))
+                })
+            }
+            #[doc = "Send the request and return the response body, or `None` if the resource does not exist."]
+            pub fn get_if_exists(
+                self,
+            ) -> futures::future::BoxFuture<'static, azure_core::Result<Option<models::MhsmPrivateEndpointConnection>>> {
+                Box::pin(async move {
+                    match azure_core::client::not_found_as_none(self.send().await)? {
+                        Some(rsp) => Ok(Some(rsp.into_body().await?)),
+                        None => Ok(None),
+                    }
+                })
+            }
+            #[doc = "Send the request and return the response body, blocking the current thread."]
+            #[cfg(feature = "blocking")]
+            pub fn call(self) -> azure_core::Result<models::MhsmPrivateEndpointConnection> {
+                azure_core::blocking::block_on(self.into_future())
+            }
         }
     }
     pub mod put {
@@ -2866,16 +6677,49 @@ pub mod mhsm_private_endpoint_connections {
         pub struct Response(azure_core::Response);
         impl Response {
             pub async fn into_body(self) -> azure_core::Result<models::MhsmPrivateEndpointConnection> {
-                let bytes = self.0.into_body().collect().await?;
-                let body: models::MhsmPrivateEndpointConnection = serde_json::from_slice(&bytes)?;
+                let status = self.0.status();
+                let bytes = self.into_byte_stream().collect().await?;
+                let body: models::MhsmPrivateEndpointConnection =
+                    azure_core::client::from_json_body("mhsm_private_endpoint_connections::put", status, &bytes)?;
+                Ok(body)
+            }
+            #[doc = "Return the response body as untyped JSON; see [responses](crate::package_preview_2021_04#responses)."]
+            pub async fn into_value(self) -> azure_core::Result<serde_json::Value> {
+                let status = self.0.status();
+                let bytes = self.into_byte_stream().collect().await?;
+                let body: serde_json::Value = azure_core::client::from_json_body("mhsm_private_endpoint_connections::put", status, &bytes)?;
                 Ok(body)
             }
+            #[doc = "Stream the response body in chunks as they are received, instead of buffering all of it like `into_body`."]
+            pub fn into_byte_stream(self) -> azure_core::ResponseBody {
+                self.0.into_body()
+            }
+            pub fn status(&self) -> azure_core::StatusCode {
+                self.0.status()
+            }
             pub fn into_raw_response(self) -> azure_core::Response {
                 self.0
             }
+            #[doc = "Read the whole response and return its status, headers and body as received, for forwarding it verbatim without going through `models`."]
+            pub async fn into_raw_parts(self) -> azure_core::Result<(azure_core::StatusCode, azure_core::headers::Headers, bytes::Bytes)> {
+                let (status, headers, body) = self.0.deconstruct();
+                Ok((status, headers, body.collect().await?))
+            }
             pub fn as_raw_response(&self) -> &azure_core::Response {
                 &self.0
             }
+            #[doc = "The remaining request quota; see [responses](crate::package_preview_2021_04#responses)."]
+            pub fn rate_limit_remaining(&self) -> Option<u32> {
+                azure_core::client::rate_limit_remaining(self.0.headers())
+            }
+            #[doc = "The `x-ms-client-request-id` the request was sent with; see [responses](crate::package_preview_2021_04#responses)."]
+            pub fn client_request_id(&self) -> Option<&str> {
+                self.0.headers().get_optional_str(&azure_core::headers::CLIENT_REQUEST_ID)
+            }
+            #[doc = "How long the request took; see [responses](crate::package_preview_2021_04#responses)."]
+            pub fn elapsed(&self) -> std::time::Duration {
+                self.0.elapsed().unwrap_or_default()
+            }
             pub fn headers(&self) -> Headers {
                 Headers(self.0.headers())
             }
@@ -2910,35 +6754,120 @@ pub mod mhsm_private_endpoint_connections {
             pub(crate) name: String,
             pub(crate) private_endpoint_connection_name: String,
             pub(crate) properties: models::MhsmPrivateEndpointConnection,
+            pub(crate) claims: Option<String>,
+            pub(crate) accept_language: Option<String>,
+            pub(crate) query_params: Vec<(String, String)>,
+            pub(crate) raw_body: Option<(bytes::Bytes, String)>,
+        }
+        impl super::super::private::Sealed for RequestBuilder {}
+        impl super::super::Operation for RequestBuilder {
+            fn method() -> azure_core::Method {
+                azure_core::Method::Put
+            }
+            fn path_template() -> &'static str {
+                "/subscriptions/{subscriptionId}/resourceGroups/{resourceGroupName}/providers/Microsoft.KeyVault/managedHSMs/{name}/privateEndpointConnections/{privateEndpointConnectionName}"
+            }
         }
         impl RequestBuilder {
+            #[doc = "Ask the credential for a token that satisfies the JSON `claims`; see [claims](crate::package_preview_2021_04#claims)."]
+            pub fn claims(mut self, claims: impl Into<String>) -> Self {
+                self.claims = Some(claims.into());
+                self
+            }
+            #[doc = "Send this request with `accept_language` as its `Accept-Language` header, such as `fr-FR`, overriding `ClientBuilder::accept_language`."]
+            pub fn accept_language(mut self, accept_language: impl Into<String>) -> Self {
+                self.accept_language = Some(accept_language.into());
+                self
+            }
+            #[doc = "Append `key=value` to the query string; see [query parameters](crate::package_preview_2021_04#query-parameters)."]
+            pub fn query_param(mut self, key: impl Into<String>, value: impl Into<String>) -> Self {
+                self.query_params.push((key.into(), value.into()));
+                self
+            }
+            #[doc = "Send `body` as the JSON request body instead of the typed parameters, for fields the `models` type does not have yet."]
+            #[doc = ""]
+            #[doc = "Shorthand for `raw_body` with `content-type: application/json`; whichever of the two is called last wins."]
+            pub fn raw_json_body(self, body: serde_json::Value) -> Self {
+                self.raw_body(body.to_string(), "application/json")
+            }
+            #[doc = "Send `body` with the given `content-type` instead of the typed parameters."]
+            #[doc = ""]
+            #[doc = "The typed request body is not sent at all, so this is a stopgap for service changes the `models` types do not cover yet."]
+            pub fn raw_body(mut self, body: impl Into<bytes::Bytes>, content_type: impl Into<String>) -> Self {
+                self.raw_body = Some((body.into(), content_type.into()));
+                self
+            }
+            #[doc = "Build the URL the request is sent to, including the api-version and any query parameters set on the builder, without sending the request."]
+            pub fn url(&self) -> azure_core::Result<azure_core::Url> {
+                let mut url = azure_core::Url::parse(&format!(
+                    "{}/subscriptions/{}/resourceGroups/{}/providers/Microsoft.KeyVault/managedHSMs/{}/privateEndpointConnections/{}",
+                    self.client.endpoint(),
+                    &azure_core::client::path_segment("subscription_id", &self.subscription_id)?,
+                    &azure_core::client::path_segment("resource_group_name", &self.resource_group_name)?,
+                    &azure_core::client::path_segment("name", &self.name)?,
+                    &azure_core::client::path_segment("private_endpoint_connection_name", &self.private_endpoint_connection_name)?
+                ))?;
+                url.query_pairs_mut()
+                    .append_pair(azure_core::query_param::API_VERSION, "2021-04-01-preview");
+                azure_core::client::append_query_params(&mut url, &self.query_params);
+                Ok(url)
+            }
             #[doc = "Send the request and returns the response."]
-            pub fn send(self) -> futures::future::BoxFuture<'static, azure_core::Result<Response>> {
-                Box::pin({
-                    let this = self.clone();
-                    async move {
-                        let url = azure_core :: Url :: parse (& format ! ("{}/subscriptions/{}/resourceGroups/{}/providers/Microsoft.KeyVault/managedHSMs/{}/privateEndpointConnections/{}" , this . client . endpoint () , & this . subscription_id , & this . resource_group_name , & this . name , & this . private_endpoint_connection_name)) ? ;
-                        let mut req = azure_core::Request::new(url, azure_core::Method::Put);
-                        let credential = this.client.token_credential();
-                        let token_response = credential.get_token(&this.client.scopes().join(" ")).await?;
-                        req.insert_header(
-                            azure_core::headers::AUTHORIZATION,
-                            format!("Bearer {}", token_response.token.secret()),
-                        );
-                        req.url_mut()
-                            .query_pairs_mut()
-                            .append_pair(azure_core::query_param::API_VERSION, "2021-04-01-preview");
-                        req.insert_header("content-type", "application/json");
-                        let req_body = azure_core::to_json(&this.properties)?;
-                        req.set_body(req_body);
-                        Ok(Response(this.client.send(&mut req).await?))
+            #[doc = ""]
+            #[doc = "The builder is not consumed, so a polling loop can build it once and call `send` on every iteration; each call fetches a fresh token. The returned future is `'static` because it owns a clone of the builder, so it can be spawned or stored."]
+            pub fn send(&self) -> futures::future::BoxFuture<'static, azure_core::Result<Response>> {
+                let this = self.clone();
+                Box::pin(async move { this.send_ref().await })
+            }
+            #[doc = "Send the request without consuming the builder."]
+            #[doc = ""]
+            #[doc = "Unlike `send`, which clones the builder so that its future is `'static`, the request is built from borrowed fields, so a builder kept across calls, such as in a polling loop, is sent again without being cloned. The future borrows the builder in turn."]
+            pub async fn send_ref(&self) -> azure_core::Result<Response> {
+                let url = self.url()?;
+                let mut req = azure_core::Request::new(url, azure_core::Method::Put);
+                let token_response = self.client.token(self.claims.as_deref()).await?;
+                req.insert_header(
+                    azure_core::headers::AUTHORIZATION,
+                    format!("Bearer {}", token_response.token.secret()),
+                );
+                if let Some(accept_language) = &self.accept_language {
+                    req.insert_header(azure_core::headers::ACCEPT_LANGUAGE, accept_language.clone());
+                }
+                req.insert_header("content-type", "application/json");
+                let req_body = match &self.raw_body {
+                    Some((body, content_type)) => {
+                        req.insert_header("content-type", content_type.clone());
+                        body.clone()
                     }
-                })
+                    None => azure_core::to_json(&self.properties)?,
+                };
+                req.set_body(req_body);
+                Ok(Response(
+                    self.client.send(&mut req, "mhsm_private_endpoint_connections::put").await?,
+                ))
             }
             #[doc = "Send the request and return the response body."]
             pub fn into_future(self) -> futures::future::BoxFuture<'static, azure_core::Result<models::MhsmPrivateEndpointConnection>> {
                 Box::pin(async move { self.send().await?.into_body().await })
             }
+            #[doc = "Send the request and return the response body together with the response headers, such as the `ETag`."]
+            pub fn into_future_with_headers(
+                self,
+            ) -> futures::future::BoxFuture<
+                'static,
+                azure_core::Result<(models::MhsmPrivateEndpointConnection, azure_core::headers::Headers)>,
+            > {
+                Box::pin(async move {
+                    let rsp = self.send().await?;
+                    let headers = rsp.as_raw_response().headers().clone();
+                    Ok((rsp.into_body().await?, headers))
+                })
+            }
+            #[doc = "Send the request and return the response body, blocking the current thread."]
+            #[cfg(feature = "blocking")]
+            pub fn call(self) -> azure_core::Result<models::MhsmPrivateEndpointConnection> {
+                azure_core::blocking::block_on(self.into_future())
+            }
         }
     }
     pub mod delete {
@@ -2946,16 +6875,50 @@ pub mod mhsm_private_endpoint_connections {
         pub struct Response(azure_core::Response);
         impl Response {
             pub async fn into_body(self) -> azure_core::Result<models::MhsmPrivateEndpointConnection> {
-                let bytes = self.0.into_body().collect().await?;
-                let body: models::MhsmPrivateEndpointConnection = serde_json::from_slice(&bytes)?;
+                let status = self.0.status();
+                let bytes = self.into_byte_stream().collect().await?;
+                let body: models::MhsmPrivateEndpointConnection =
+                    azure_core::client::from_json_body("mhsm_private_endpoint_connections::delete", status, &bytes)?;
                 Ok(body)
             }
+            #[doc = "Return the response body as untyped JSON; see [responses](crate::package_preview_2021_04#responses)."]
+            pub async fn into_value(self) -> azure_core::Result<serde_json::Value> {
+                let status = self.0.status();
+                let bytes = self.into_byte_stream().collect().await?;
+                let body: serde_json::Value =
+                    azure_core::client::from_json_body("mhsm_private_endpoint_connections::delete", status, &bytes)?;
+                Ok(body)
+            }
+            #[doc = "Stream the response body in chunks as they are received, instead of buffering all of it like `into_body`."]
+            pub fn into_byte_stream(self) -> azure_core::ResponseBody {
+                self.0.into_body()
+            }
+            pub fn status(&self) -> azure_core::StatusCode {
+                self.0.status()
+            }
             pub fn into_raw_response(self) -> azure_core::Response {
                 self.0
             }
+            #[doc = "Read the whole response and return its status, headers and body as received, for forwarding it verbatim without going through `models`."]
+            pub async fn into_raw_parts(self) -> azure_core::Result<(azure_core::StatusCode, azure_core::headers::Headers, bytes::Bytes)> {
+                let (status, headers, body) = self.0.deconstruct();
+                Ok((status, headers, body.collect().await?))
+            }
             pub fn as_raw_response(&self) -> &azure_core::Response {
                 &self.0
             }
+            #[doc = "The remaining request quota; see [responses](crate::package_preview_2021_04#responses)."]
+            pub fn rate_limit_remaining(&self) -> Option<u32> {
+                azure_core::client::rate_limit_remaining(self.0.headers())
+            }
+            #[doc = "The `x-ms-client-request-id` the request was sent with; see [responses](crate::package_preview_2021_04#responses)."]
+            pub fn client_request_id(&self) -> Option<&str> {
+                self.0.headers().get_optional_str(&azure_core::headers::CLIENT_REQUEST_ID)
+            }
+            #[doc = "How long the request took; see [responses](crate::package_preview_2021_04#responses)."]
+            pub fn elapsed(&self) -> std::time::Duration {
+                self.0.elapsed().unwrap_or_default()
+            }
             pub fn headers(&self) -> Headers {
                 Headers(self.0.headers())
             }
@@ -2988,35 +6951,100 @@ pub mod mhsm_private_endpoint_connections {
             pub(crate) resource_group_name: String,
             pub(crate) name: String,
             pub(crate) private_endpoint_connection_name: String,
+            pub(crate) claims: Option<String>,
+            pub(crate) accept_language: Option<String>,
+            pub(crate) query_params: Vec<(String, String)>,
+        }
+        impl super::super::private::Sealed for RequestBuilder {}
+        impl super::super::Operation for RequestBuilder {
+            fn method() -> azure_core::Method {
+                azure_core::Method::Delete
+            }
+            fn path_template() -> &'static str {
+                "/subscriptions/{subscriptionId}/resourceGroups/{resourceGroupName}/providers/Microsoft.KeyVault/managedHSMs/{name}/privateEndpointConnections/{privateEndpointConnectionName}"
+            }
         }
         impl RequestBuilder {
+            #[doc = "Ask the credential for a token that satisfies the JSON `claims`; see [claims](crate::package_preview_2021_04#claims)."]
+            pub fn claims(mut self, claims: impl Into<String>) -> Self {
+                self.claims = Some(claims.into());
+                self
+            }
+            #[doc = "Send this request with `accept_language` as its `Accept-Language` header, such as `fr-FR`, overriding `ClientBuilder::accept_language`."]
+            pub fn accept_language(mut self, accept_language: impl Into<String>) -> Self {
+                self.accept_language = Some(accept_language.into());
+                self
+            }
+            #[doc = "Append `key=value` to the query string; see [query parameters](crate::package_preview_2021_04#query-parameters)."]
+            pub fn query_param(mut self, key: impl Into<String>, value: impl Into<String>) -> Self {
+                self.query_params.push((key.into(), value.into()));
+                self
+            }
+            #[doc = "Build the URL the request is sent to, including the api-version and any query parameters set on the builder, without sending the request."]
+            pub fn url(&self) -> azure_core::Result<azure_core::Url> {
+                let mut url = azure_core::Url::parse(&format!(
+                    "{}/subscriptions/{}/resourceGroups/{}/providers/Microsoft.KeyVault/managedHSMs/{}/privateEndpointConnections/{}",
+                    self.client.endpoint(),
+                    &azure_core::client::path_segment("subscription_id", &self.subscription_id)?,
+                    &azure_core::client::path_segment("resource_group_name", &self.resource_group_name)?,
+                    &azure_core::client::path_segment("name", &self.name)?,
+                    &azure_core::client::path_segment("private_endpoint_connection_name", &self.private_endpoint_connection_name)?
+                ))?;
+                url.query_pairs_mut()
+                    .append_pair(azure_core::query_param::API_VERSION, "2021-04-01-preview");
+                azure_core::client::append_query_params(&mut url, &self.query_params);
+                Ok(url)
+            }
             #[doc = "only the first response will be fetched as long running operations are not supported yet"]
             #[doc = "Send the request and returns the response."]
-            pub fn send(self) -> futures::future::BoxFuture<'static, azure_core::Result<Response>> {
-                Box::pin({
-                    let this = self.clone();
-                    async move {
-                        let url = azure_core :: Url :: parse (& format ! ("{}/subscriptions/{}/resourceGroups/{}/providers/Microsoft.KeyVault/managedHSMs/{}/privateEndpointConnections/{}" , this . client . endpoint () , & this . subscription_id , & this . resource_group_name , & this . name , & this . private_endpoint_connection_name)) ? ;
-                        let mut req = azure_core::Request::new(url, azure_core::Method::Delete);
-                        let credential = this.client.token_credential();
-                        let token_response = credential.get_token(&this.client.scopes().join(" ")).await?;
-                        req.insert_header(
-                            azure_core::headers::AUTHORIZATION,
-                            format!("Bearer {}", token_response.token.secret()),
-                        );
-                        req.url_mut()
-                            .query_pairs_mut()
-                            .append_pair(azure_core::query_param::API_VERSION, "2021-04-01-preview");
-                        let req_body = azure_core::EMPTY_BODY;
-                        req.set_body(req_body);
-                        Ok(Response(this.client.send(&mut req).await?))
-                    }
-                })
+            #[doc = ""]
+            #[doc = "The builder is not consumed, so a polling loop can build it once and call `send` on every iteration; each call fetches a fresh token. The returned future is `'static` because it owns a clone of the builder, so it can be spawned or stored."]
+            pub fn send(&self) -> futures::future::BoxFuture<'static, azure_core::Result<Response>> {
+                let this = self.clone();
+                Box::pin(async move { this.send_ref().await })
+            }
+            #[doc = "Send the request without consuming the builder."]
+            #[doc = ""]
+            #[doc = "Unlike `send`, which clones the builder so that its future is `'static`, the request is built from borrowed fields, so a builder kept across calls, such as in a polling loop, is sent again without being cloned. The future borrows the builder in turn."]
+            pub async fn send_ref(&self) -> azure_core::Result<Response> {
+                let url = self.url()?;
+                let mut req = azure_core::Request::new(url, azure_core::Method::Delete);
+                let token_response = self.client.token(self.claims.as_deref()).await?;
+                req.insert_header(
+                    azure_core::headers::AUTHORIZATION,
+                    format!("Bearer {}", token_response.token.secret()),
+                );
+                if let Some(accept_language) = &self.accept_language {
+                    req.insert_header(azure_core::headers::ACCEPT_LANGUAGE, accept_language.clone());
+                }
+                let req_body = azure_core::EMPTY_BODY;
+                req.set_body(req_body);
+                Ok(Response(
+                    self.client.send(&mut req, "mhsm_private_endpoint_connections::delete").await?,
+                ))
             }
             #[doc = "Send the request and return the response body."]
             pub fn into_future(self) -> futures::future::BoxFuture<'static, azure_core::Result<models::MhsmPrivateEndpointConnection>> {
                 Box::pin(async move { self.send().await?.into_body().await })
             }
+            #[doc = "Send the request and return the response body together with the response headers, such as the `ETag`."]
+            pub fn into_future_with_headers(
+                self,
+            ) -> futures::future::BoxFuture<
+                'static,
+                azure_core::Result<(models::MhsmPrivateEndpointConnection, azure_core::headers::Headers)>,
+            > {
+                Box::pin(async move {
+                    let rsp = self.send().await?;
+                    let headers = rsp.as_raw_response().headers().clone();
+                    Ok((rsp.into_body().await?, headers))
+                })
+            }
+            #[doc = "Send the request and return the response body, blocking the current thread."]
+            #[cfg(feature = "blocking")]
+            pub fn call(self) -> azure_core::Result<models::MhsmPrivateEndpointConnection> {
+                azure_core::blocking::block_on(self.into_future())
+            }
         }
     }
 }
@@ -3041,6 +7069,9 @@ pub mod mhsm_private_link_resources {
                 subscription_id: subscription_id.into(),
                 resource_group_name: resource_group_name.into(),
                 name: name.into(),
+                claims: None,
+                accept_language: None,
+                query_params: Vec::new(),
             }
         }
     }
@@ -3049,16 +7080,54 @@ pub mod mhsm_private_link_resources {
         pub struct Response(azure_core::Response);
         impl Response {
             pub async fn into_body(self) -> azure_core::Result<models::MhsmPrivateLinkResourceListResult> {
-                let bytes = self.0.into_body().collect().await?;
-                let body: models::MhsmPrivateLinkResourceListResult = serde_json::from_slice(&bytes)?;
+                let status = self.0.status();
+                let bytes = self.into_byte_stream().collect().await?;
+                let body: models::MhsmPrivateLinkResourceListResult =
+                    azure_core::client::from_json_body("mhsm_private_link_resources::list_by_mhsm_resource", status, &bytes)?;
+                Ok(body)
+            }
+            #[doc = "Stream the items of the page as they are received, instead of buffering the whole body like `into_body`. Everything but the `value` array, including the link to the next page, is skipped."]
+            pub fn into_body_streaming(self) -> futures::stream::BoxStream<'static, azure_core::Result<models::MhsmPrivateLinkResource>> {
+                self.into_byte_stream().json_array_items("value")
+            }
+            #[doc = "Return the response body as untyped JSON; see [responses](crate::package_preview_2021_04#responses)."]
+            pub async fn into_value(self) -> azure_core::Result<serde_json::Value> {
+                let status = self.0.status();
+                let bytes = self.into_byte_stream().collect().await?;
+                let body: serde_json::Value =
+                    azure_core::client::from_json_body("mhsm_private_link_resources::list_by_mhsm_resource", status, &bytes)?;
                 Ok(body)
             }
+            #[doc = "Stream the response body in chunks as they are received, instead of buffering all of it like `into_body`."]
+            pub fn into_byte_stream(self) -> azure_core::ResponseBody {
+                self.0.into_body()
+            }
+            pub fn status(&self) -> azure_core::StatusCode {
+                self.0.status()
+            }
             pub fn into_raw_response(self) -> azure_core::Response {
                 self.0
             }
+            #[doc = "Read the whole response and return its status, headers and body as received, for forwarding it verbatim without going through `models`."]
+            pub async fn into_raw_parts(self) -> azure_core::Result<(azure_core::StatusCode, azure_core::headers::Headers, bytes::Bytes)> {
+                let (status, headers, body) = self.0.deconstruct();
+                Ok((status, headers, body.collect().await?))
+            }
             pub fn as_raw_response(&self) -> &azure_core::Response {
                 &self.0
             }
+            #[doc = "The remaining request quota; see [responses](crate::package_preview_2021_04#responses)."]
+            pub fn rate_limit_remaining(&self) -> Option<u32> {
+                azure_core::client::rate_limit_remaining(self.0.headers())
+            }
+            #[doc = "The `x-ms-client-request-id` the request was sent with; see [responses](crate::package_preview_2021_04#responses)."]
+            pub fn client_request_id(&self) -> Option<&str> {
+                self.0.headers().get_optional_str(&azure_core::headers::CLIENT_REQUEST_ID)
+            }
+            #[doc = "How long the request took; see [responses](crate::package_preview_2021_04#responses)."]
+            pub fn elapsed(&self) -> std::time::Duration {
+                self.0.elapsed().unwrap_or_default()
+            }
         }
         impl From<Response> for azure_core::Response {
             fn from(rsp: Response) -> Self {
@@ -3076,40 +7145,105 @@ pub mod mhsm_private_link_resources {
             pub(crate) subscription_id: String,
             pub(crate) resource_group_name: String,
             pub(crate) name: String,
+            pub(crate) claims: Option<String>,
+            pub(crate) accept_language: Option<String>,
+            pub(crate) query_params: Vec<(String, String)>,
+        }
+        impl super::super::private::Sealed for RequestBuilder {}
+        impl super::super::Operation for RequestBuilder {
+            fn method() -> azure_core::Method {
+                azure_core::Method::Get
+            }
+            fn path_template() -> &'static str {
+                "/subscriptions/{subscriptionId}/resourceGroups/{resourceGroupName}/providers/Microsoft.KeyVault/managedHSMs/{name}/privateLinkResources"
+            }
+        }
+        impl super::super::BatchRead for RequestBuilder {
+            fn batch_url(&self) -> azure_core::Result<azure_core::Url> {
+                self.url()
+            }
         }
         impl RequestBuilder {
+            #[doc = "Ask the credential for a token that satisfies the JSON `claims`; see [claims](crate::package_preview_2021_04#claims)."]
+            pub fn claims(mut self, claims: impl Into<String>) -> Self {
+                self.claims = Some(claims.into());
+                self
+            }
+            #[doc = "Send this request with `accept_language` as its `Accept-Language` header, such as `fr-FR`, overriding `ClientBuilder::accept_language`."]
+            pub fn accept_language(mut self, accept_language: impl Into<String>) -> Self {
+                self.accept_language = Some(accept_language.into());
+                self
+            }
+            #[doc = "Append `key=value` to the query string; see [query parameters](crate::package_preview_2021_04#query-parameters)."]
+            pub fn query_param(mut self, key: impl Into<String>, value: impl Into<String>) -> Self {
+                self.query_params.push((key.into(), value.into()));
+                self
+            }
+            #[doc = "Build the URL the request is sent to, including the api-version and any query parameters set on the builder, without sending the request."]
+            pub fn url(&self) -> azure_core::Result<azure_core::Url> {
+                let mut url = azure_core::Url::parse(&format!(
+                    "{}/subscriptions/{}/resourceGroups/{}/providers/Microsoft.KeyVault/managedHSMs/{}/privateLinkResources",
+                    self.client.endpoint(),
+                    &azure_core::client::path_segment("subscription_id", &self.subscription_id)?,
+                    &azure_core::client::path_segment("resource_group_name", &self.resource_group_name)?,
+                    &azure_core::client::path_segment("name", &self.name)?
+                ))?;
+                url.query_pairs_mut()
+                    .append_pair(azure_core::query_param::API_VERSION, "2021-04-01-preview");
+                azure_core::client::append_query_params(&mut url, &self.query_params);
+                Ok(url)
+            }
             #[doc = "Send the request and returns the response."]
-            pub fn send(self) -> futures::future::BoxFuture<'static, azure_core::Result<Response>> {
-                Box::pin({
-                    let this = self.clone();
-                    async move {
-                        let url = azure_core::Url::parse(&format!(
-                            "{}/subscriptions/{}/resourceGroups/{}/providers/Microsoft.KeyVault/managedHSMs/{}/privateLinkResources",
-                            this.client.endpoint(),
-                            &this.subscription_id,
-                            &this.resource_group_name,
-                            &this.name
-                        ))?;
-                        let mut req = azure_core::Request::new(url, azure_core::Method::Get);
-                        let credential = this.client.token_credential();
-                        let token_response = credential.get_token(&this.client.scopes().join(" ")).await?;
-                        req.insert_header(
-                            azure_core::headers::AUTHORIZATION,
-                            format!("Bearer {}", token_response.token.secret()),
-                        );
-                        req.url_mut()
-                            .query_pairs_mut()
-                            .append_pair(azure_core::query_param::API_VERSION, "2021-04-01-preview");
-                        let req_body = azure_core::EMPTY_BODY;
-                        req.set_body(req_body);
-                        Ok(Response(this.client.send(&mut req).await?))
-                    }
-                })
+            #[doc = ""]
+            #[doc = "The builder is not consumed, so a polling loop can build it once and call `send` on every iteration; each call fetches a fresh token. The returned future is `'static` because it owns a clone of the builder, so it can be spawned or stored."]
+            pub fn send(&self) -> futures::future::BoxFuture<'static, azure_core::Result<Response>> {
+                let this = self.clone();
+                Box::pin(async move { this.send_ref().await })
+            }
+            #[doc = "Send the request without consuming the builder."]
+            #[doc = ""]
+            #[doc = "Unlike `send`, which clones the builder so that its future is `'static`, the request is built from borrowed fields, so a builder kept across calls, such as in a polling loop, is sent again without being cloned. The future borrows the builder in turn."]
+            pub async fn send_ref(&self) -> azure_core::Result<Response> {
+                let url = self.url()?;
+                let mut req = azure_core::Request::new(url, azure_core::Method::Get);
+                let token_response = self.client.token(self.claims.as_deref()).await?;
+                req.insert_header(
+                    azure_core::headers::AUTHORIZATION,
+                    format!("Bearer {}", token_response.token.secret()),
+                );
+                if let Some(accept_language) = &self.accept_language {
+                    req.insert_header(azure_core::headers::ACCEPT_LANGUAGE, accept_language.clone());
+                }
+                let req_body = azure_core::EMPTY_BODY;
+                req.set_body(req_body);
+                Ok(Response(
+                    self.client
+                        .send(&mut req, "mhsm_private_link_resources::list_by_mhsm_resource")
+                        .await?,
+                ))
             }
             #[doc = "Send the request and return the response body."]
             pub fn into_future(self) -> futures::future::BoxFuture<'static, azure_core::Result<models::MhsmPrivateLinkResourceListResult>> {
                 Box::pin(async move { self.send().await?.into_body().await })
             }
+            #[doc = "Send the request and return the response body together with the response headers, such as the `ETag`."]
+            pub fn into_future_with_headers(
+                self,
+            ) -> futures::future::BoxFuture<
+                'static,
+                azure_core::Result<(models::MhsmPrivateLinkResourceListResult, azure_core::headers::Headers)>,
+            > {
+                Box::pin(async move {
+                    let rsp = self.send().await?;
+                    let headers = rsp.as_raw_response().headers().clone();
+                    Ok((rsp.into_body().await?, headers))
+                })
+            }
+            #[doc = "Send the request and return the response body, blocking the current thread."]
+            #[cfg(feature = "blocking")]
+            pub fn call(self) -> azure_core::Result<models::MhsmPrivateLinkResourceListResult> {
+                azure_core::blocking::block_on(self.into_future())
+            }
         }
     }
 }
@@ -3119,7 +7253,14 @@ pub mod operations {
     impl Client {
         #[doc = "Lists all of the available Key Vault Rest API operations."]
         pub fn list(&self) -> list::RequestBuilder {
-            list::RequestBuilder { client: self.0.clone() }
+            list::RequestBuilder {
+                client: self.0.clone(),
+                claims: None,
+                accept_language: None,
+                query_params: Vec::new(),
+                max_items: None,
+                resume_from: None,
+            }
         }
     }
     pub mod list {
@@ -3127,16 +7268,52 @@ pub mod operations {
         pub struct Response(azure_core::Response);
         impl Response {
             pub async fn into_body(self) -> azure_core::Result<models::OperationListResult> {
-                let bytes = self.0.into_body().collect().await?;
-                let body: models::OperationListResult = serde_json::from_slice(&bytes)?;
+                let status = self.0.status();
+                let bytes = self.into_byte_stream().collect().await?;
+                let body: models::OperationListResult = azure_core::client::from_json_body("operations::list", status, &bytes)?;
                 Ok(body)
             }
+            #[doc = "Stream the items of the page as they are received, instead of buffering the whole body like `into_body`. Everything but the `value` array, including the link to the next page, is skipped."]
+            pub fn into_body_streaming(self) -> futures::stream::BoxStream<'static, azure_core::Result<models::Operation>> {
+                self.into_byte_stream().json_array_items("value")
+            }
+            #[doc = "Return the response body as untyped JSON; see [responses](crate::package_preview_2021_04#responses)."]
+            pub async fn into_value(self) -> azure_core::Result<serde_json::Value> {
+                let status = self.0.status();
+                let bytes = self.into_byte_stream().collect().await?;
+                let body: serde_json::Value = azure_core::client::from_json_body("operations::list", status, &bytes)?;
+                Ok(body)
+            }
+            #[doc = "Stream the response body in chunks as they are received, instead of buffering all of it like `into_body`."]
+            pub fn into_byte_stream(self) -> azure_core::ResponseBody {
+                self.0.into_body()
+            }
+            pub fn status(&self) -> azure_core::StatusCode {
+                self.0.status()
+            }
             pub fn into_raw_response(self) -> azure_core::Response {
                 self.0
             }
+            #[doc = "Read the whole response and return its status, headers and body as received, for forwarding it verbatim without going through `models`."]
+            pub async fn into_raw_parts(self) -> azure_core::Result<(azure_core::StatusCode, azure_core::headers::Headers, bytes::Bytes)> {
+                let (status, headers, body) = self.0.deconstruct();
+                Ok((status, headers, body.collect().await?))
+            }
             pub fn as_raw_response(&self) -> &azure_core::Response {
                 &self.0
             }
+            #[doc = "The remaining request quota; see [responses](crate::package_preview_2021_04#responses)."]
+            pub fn rate_limit_remaining(&self) -> Option<u32> {
+                azure_core::client::rate_limit_remaining(self.0.headers())
+            }
+            #[doc = "The `x-ms-client-request-id` the request was sent with; see [responses](crate::package_preview_2021_04#responses)."]
+            pub fn client_request_id(&self) -> Option<&str> {
+                self.0.headers().get_optional_str(&azure_core::headers::CLIENT_REQUEST_ID)
+            }
+            #[doc = "How long the request took; see [responses](crate::package_preview_2021_04#responses)."]
+            pub fn elapsed(&self) -> std::time::Duration {
+                self.0.elapsed().unwrap_or_default()
+            }
         }
         impl From<Response> for azure_core::Response {
             fn from(rsp: Response) -> Self {
@@ -3151,63 +7328,155 @@ pub mod operations {
         #[derive(Clone)]
         pub struct RequestBuilder {
             pub(crate) client: super::super::Client,
+            pub(crate) claims: Option<String>,
+            pub(crate) accept_language: Option<String>,
+            pub(crate) query_params: Vec<(String, String)>,
+            pub(crate) max_items: Option<usize>,
+            pub(crate) resume_from: Option<String>,
+        }
+        impl super::super::private::Sealed for RequestBuilder {}
+        impl super::super::Operation for RequestBuilder {
+            fn method() -> azure_core::Method {
+                azure_core::Method::Get
+            }
+            fn path_template() -> &'static str {
+                "/providers/Microsoft.KeyVault/operations"
+            }
+        }
+        impl super::super::BatchRead for RequestBuilder {
+            fn batch_url(&self) -> azure_core::Result<azure_core::Url> {
+                self.url()
+            }
         }
         impl RequestBuilder {
+            #[doc = "Ask the credential for a token that satisfies the JSON `claims`; see [claims](crate::package_preview_2021_04#claims)."]
+            pub fn claims(mut self, claims: impl Into<String>) -> Self {
+                self.claims = Some(claims.into());
+                self
+            }
+            #[doc = "Send this request with `accept_language` as its `Accept-Language` header, such as `fr-FR`, overriding `ClientBuilder::accept_language`."]
+            pub fn accept_language(mut self, accept_language: impl Into<String>) -> Self {
+                self.accept_language = Some(accept_language.into());
+                self
+            }
+            #[doc = "Append `key=value` to the query string; see [query parameters](crate::package_preview_2021_04#query-parameters)."]
+            pub fn query_param(mut self, key: impl Into<String>, value: impl Into<String>) -> Self {
+                self.query_params.push((key.into(), value.into()));
+                self
+            }
+            #[doc = "Limit the total number of items yielded by `into_item_stream`. No further pages are requested once the limit is reached."]
+            pub fn max_items(mut self, max_items: usize) -> Self {
+                self.max_items = Some(max_items);
+                self
+            }
+            #[doc = "Start paging from `continuation`, a token saved from `into_checkpoint_stream` or `page`, instead of from the first page."]
+            #[doc = ""]
+            #[doc = "Lets a long listing be checkpointed and picked up where it stopped, such as after a restart."]
+            pub fn resume_from(mut self, continuation: impl Into<String>) -> Self {
+                self.resume_from = Some(continuation.into());
+                self
+            }
+            #[doc = "Build the URL the request is sent to, including the api-version and any query parameters set on the builder, without sending the request."]
+            pub fn url(&self) -> azure_core::Result<azure_core::Url> {
+                let mut url = azure_core::Url::parse(&format!("{}/providers/Microsoft.KeyVault/operations", self.client.endpoint(),))?;
+                url.query_pairs_mut()
+                    .append_pair(azure_core::query_param::API_VERSION, "2021-04-01-preview");
+                azure_core::client::append_query_params(&mut url, &self.query_params);
+                Ok(url)
+            }
+            #[doc = "Stream the items of every page, stopping early if `max_items` is set."]
+            pub fn into_item_stream(self) -> futures::stream::BoxStream<'static, azure_core::Result<models::Operation>> {
+                let max_items = self.max_items;
+                azure_core::client::item_stream(self.into_stream(), max_items, |page| page.value)
+            }
+            #[doc = "Stream the items of each page together with the continuation token that resumes after it, or `None` after the last page."]
+            #[doc = ""]
+            #[doc = "Save the token once a page has been processed and pass it to `resume_from` to carry on from there later. `max_items` is not applied."]
+            pub fn into_checkpoint_stream(
+                self,
+            ) -> futures::stream::BoxStream<'static, azure_core::Result<(Vec<models::Operation>, Option<String>)>> {
+                azure_core::client::checkpoint_stream(self.into_stream(), |page| page.value)
+            }
             pub fn into_stream(self) -> azure_core::Pageable<models::OperationListResult, azure_core::error::Error> {
                 let make_request = move |continuation: Option<String>| {
-                    let this = self.clone();
-                    async move {
-                        let mut url =
-                            azure_core::Url::parse(&format!("{}/providers/Microsoft.KeyVault/operations", this.client.endpoint(),))?;
-                        let rsp = match continuation {
-                            Some(value) => {
-                                url.set_path("");
-                                url = url.join(&value)?;
-                                let mut req = azure_core::Request::new(url, azure_core::Method::Get);
-                                let credential = this.client.token_credential();
-                                let token_response = credential.get_token(&this.client.scopes().join(" ")).await?;
-                                req.insert_header(
-                                    azure_core::headers::AUTHORIZATION,
-                                    format!("Bearer {}", token_response.token.secret()),
-                                );
-                                let has_api_version_already =
-                                    req.url_mut().query_pairs().any(|(k, _)| k == azure_core::query_param::API_VERSION);
-                                if !has_api_version_already {
-                                    req.url_mut()
-                                        .query_pairs_mut()
-                                        .append_pair(azure_core::query_param::API_VERSION, "2021-04-01-preview");
-                                }
-                                let req_body = azure_core::EMPTY_BODY;
-                                req.set_body(req_body);
-                                this.client.send(&mut req).await?
+                    let continuation = continuation.or_else(|| self.resume_from.clone());
+                    self.clone().fetch_page(continuation)
+                };
+                azure_core::Pageable::new(make_request)
+            }
+            #[doc = "Fetch a single page and return its body together with the continuation token for the next page, if any."]
+            #[doc = ""]
+            #[doc = "Pass `None` to fetch the first page, or a token returned by a previous call to fetch the page that follows it."]
+            pub fn page(
+                &self,
+                continuation: Option<String>,
+            ) -> futures::future::BoxFuture<'static, azure_core::Result<(models::OperationListResult, Option<String>)>> {
+                let this = self.clone();
+                Box::pin(async move {
+                    let body = this.fetch_page(continuation).await?;
+                    let continuation = azure_core::Continuable::continuation(&body);
+                    Ok((body, continuation))
+                })
+            }
+            fn fetch_page(
+                self,
+                continuation: Option<String>,
+            ) -> futures::future::BoxFuture<'static, azure_core::Result<models::OperationListResult>> {
+                Box::pin(async move {
+                    let mut url = self.url()?;
+                    let rsp = match continuation {
+                        Some(value) => {
+                            let first_page_url = url;
+                            url = azure_core::next_page_url(&first_page_url, &value)?;
+                            azure_core::client::carry_query_params(&mut url, &first_page_url, &self.query_params);
+                            let mut req = azure_core::Request::new(url, azure_core::Method::Get);
+                            let token_response = self.client.token(self.claims.as_deref()).await?;
+                            req.insert_header(
+                                azure_core::headers::AUTHORIZATION,
+                                format!("Bearer {}", token_response.token.secret()),
+                            );
+                            if let Some(accept_language) = &self.accept_language {
+                                req.insert_header(azure_core::headers::ACCEPT_LANGUAGE, accept_language.clone());
+                            }
+                            if let Some(token) = azure_core::continuation_header(&value) {
+                                req.insert_header(azure_core::headers::CONTINUATION, token.to_owned());
                             }
-                            None => {
-                                let mut req = azure_core::Request::new(url, azure_core::Method::Get);
-                                let credential = this.client.token_credential();
-                                let token_response = credential.get_token(&this.client.scopes().join(" ")).await?;
-                                req.insert_header(
-                                    azure_core::headers::AUTHORIZATION,
-                                    format!("Bearer {}", token_response.token.secret()),
-                                );
-                                req.url_mut()
-                                    .query_pairs_mut()
-                                    .append_pair(azure_core::query_param::API_VERSION, "2021-04-01-preview");
-                                let req_body = azure_core::EMPTY_BODY;
-                                req.set_body(req_body);
-                                this.client.send(&mut req).await?
+                            let req_body = azure_core::EMPTY_BODY;
+                            req.set_body(req_body);
+                            self.client.send(&mut req, "operations::list").await?
+                        }
+                        None => {
+                            let mut req = azure_core::Request::new(url, azure_core::Method::Get);
+                            let token_response = self.client.token(self.claims.as_deref()).await?;
+                            req.insert_header(
+                                azure_core::headers::AUTHORIZATION,
+                                format!("Bearer {}", token_response.token.secret()),
+                            );
+                            if let Some(accept_language) = &self.accept_language {
+                                req.insert_header(azure_core::headers::ACCEPT_LANGUAGE, accept_language.clone());
                             }
-                        };
-                        let rsp = match rsp.status() {
-                            azure_core::StatusCode::Ok => Ok(Response(rsp)),
-                            status_code => Err(azure_core::error::Error::from(azure_core::error::ErrorKind::HttpResponse {
-                                status: status_code,
-                                error_code: None,
-                            })),
-                        };
-                        rsp?.into_body().await
+                            let req_body = azure_core::EMPTY_BODY;
+                            req.set_body(req_body);
+                            self.client.send(&mut req, "operations::list").await?
+                        }
+                    };
+                    let rsp = match rsp.status() {
+                        azure_core::StatusCode::Ok => Ok(Response(rsp)),
+                        _ => Err(azure_core::client::unexpected_status(&rsp)),
+                    };
+                    let rsp = rsp?;
+                    let continuation = azure_core::continuation_from_headers(rsp.as_raw_response().headers());
+                    let mut body = rsp.into_body().await?;
+                    if body.next_link.is_none() {
+                        body.next_link = continuation;
                     }
-                };
-                azure_core::Pageable::new(make_request)
+                    Ok(body)
+                })
+            }
+            #[doc = "Fetch every page and return all items, blocking the current thread. Stops early if `max_items` is set."]
+            #[cfg(feature = "blocking")]
+            pub fn call(self) -> azure_core::Result<Vec<models::Operation>> {
+                azure_core::blocking::block_on(futures::TryStreamExt::try_collect(self.into_item_stream()))
             }
         }
     }
@@ -4,7 +4,7 @@ use serde::de::{value, Deserializer, IntoDeserializer};
 use serde::{Deserialize, Serialize, Serializer};
 use std::str::FromStr;
 #[doc = "An identity that have access to the key vault. All identities in the array must use the same tenant ID as the key vault's tenant ID."]
-#[derive(Clone, Debug, PartialEq, Serialize, Deserialize)]
+#[derive(Clone, Debug, PartialEq, Eq, Hash, Serialize, Deserialize)]
 pub struct AccessPolicyEntry {
     #[doc = "The Azure Active Directory tenant ID that should be used for authenticating requests to the key vault."]
     #[serde(rename = "tenantId")]
@@ -29,7 +29,7 @@ impl AccessPolicyEntry {
     }
 }
 #[doc = "The CheckNameAvailability operation response."]
-#[derive(Clone, Debug, PartialEq, Serialize, Deserialize, Default)]
+#[derive(Clone, Debug, PartialEq, Eq, Hash, Serialize, Deserialize, Default)]
 pub struct CheckNameAvailabilityResult {
     #[doc = "A boolean value that indicates whether the name is available for you to use. If true, the name is available. If false, the name has already been taken or is invalid and cannot be used."]
     #[serde(rename = "nameAvailable", default, skip_serializing_if = "Option::is_none")]
@@ -49,14 +49,14 @@ impl CheckNameAvailabilityResult {
 pub mod check_name_availability_result {
     use super::*;
     #[doc = "The reason that a vault name could not be used. The Reason element is only returned if NameAvailable is false."]
-    #[derive(Clone, Debug, PartialEq, Serialize, Deserialize)]
+    #[derive(Clone, Debug, PartialEq, Eq, Hash, Serialize, Deserialize)]
     pub enum Reason {
         AccountNameInvalid,
         AlreadyExists,
     }
 }
 #[doc = "An error response from Key Vault resource provider"]
-#[derive(Clone, Debug, PartialEq, Serialize, Deserialize, Default)]
+#[derive(Clone, Debug, PartialEq, Eq, Hash, Serialize, Deserialize, Default)]
 pub struct CloudError {
     #[doc = "An error response from Key Vault resource provider"]
     #[serde(default, skip_serializing_if = "Option::is_none")]
@@ -74,7 +74,7 @@ impl CloudError {
     }
 }
 #[doc = "An error response from Key Vault resource provider"]
-#[derive(Clone, Debug, PartialEq, Serialize, Deserialize, Default)]
+#[derive(Clone, Debug, PartialEq, Eq, Hash, Serialize, Deserialize, Default)]
 pub struct CloudErrorBody {
     #[doc = "Error code. This is a mnemonic that can be consumed programmatically."]
     #[serde(default, skip_serializing_if = "Option::is_none")]
@@ -132,6 +132,10 @@ impl DeletedManagedHsmListResult {
     pub fn new() -> Self {
         Self::default()
     }
+    #[doc = "The items in this page."]
+    pub fn items(&self) -> &[DeletedManagedHsm] {
+        &self.value
+    }
 }
 #[doc = "Properties of the deleted managed HSM."]
 #[derive(Clone, Debug, PartialEq, Serialize, Deserialize, Default)]
@@ -143,10 +147,20 @@ pub struct DeletedManagedHsmProperties {
     #[serde(default, skip_serializing_if = "Option::is_none")]
     pub location: Option<String>,
     #[doc = "The deleted date."]
-    #[serde(rename = "deletionDate", default, with = "azure_core::date::rfc3339::option")]
+    #[serde(
+        rename = "deletionDate",
+        default,
+        with = "azure_core::date::iso8601::option",
+        skip_serializing_if = "Option::is_none"
+    )]
     pub deletion_date: Option<time::OffsetDateTime>,
     #[doc = "The scheduled purged date."]
-    #[serde(rename = "scheduledPurgeDate", default, with = "azure_core::date::rfc3339::option")]
+    #[serde(
+        rename = "scheduledPurgeDate",
+        default,
+        with = "azure_core::date::iso8601::option",
+        skip_serializing_if = "Option::is_none"
+    )]
     pub scheduled_purge_date: Option<time::OffsetDateTime>,
     #[doc = "Purge protection status of the original managed HSM."]
     #[serde(rename = "purgeProtectionEnabled", default, skip_serializing_if = "Option::is_none")]
@@ -205,6 +219,10 @@ impl DeletedVaultListResult {
     pub fn new() -> Self {
         Self::default()
     }
+    #[doc = "The items in this page."]
+    pub fn items(&self) -> &[DeletedVault] {
+        &self.value
+    }
 }
 #[doc = "Properties of the deleted vault."]
 #[derive(Clone, Debug, PartialEq, Serialize, Deserialize, Default)]
@@ -216,10 +234,20 @@ pub struct DeletedVaultProperties {
     #[serde(default, skip_serializing_if = "Option::is_none")]
     pub location: Option<String>,
     #[doc = "The deleted date."]
-    #[serde(rename = "deletionDate", default, with = "azure_core::date::rfc3339::option")]
+    #[serde(
+        rename = "deletionDate",
+        default,
+        with = "azure_core::date::iso8601::option",
+        skip_serializing_if = "Option::is_none"
+    )]
     pub deletion_date: Option<time::OffsetDateTime>,
     #[doc = "The scheduled purged date."]
-    #[serde(rename = "scheduledPurgeDate", default, with = "azure_core::date::rfc3339::option")]
+    #[serde(
+        rename = "scheduledPurgeDate",
+        default,
+        with = "azure_core::date::iso8601::option",
+        skip_serializing_if = "Option::is_none"
+    )]
     pub scheduled_purge_date: Option<time::OffsetDateTime>,
     #[doc = "Tags of the original vault."]
     #[serde(default, skip_serializing_if = "Option::is_none")]
@@ -234,7 +262,7 @@ impl DeletedVaultProperties {
     }
 }
 #[doc = "Type of operation: get, read, delete, etc."]
-#[derive(Clone, Debug, PartialEq, Serialize, Deserialize, Default)]
+#[derive(Clone, Debug, PartialEq, Eq, Hash, Serialize, Deserialize, Default)]
 pub struct DimensionProperties {
     #[doc = "Name of dimension."]
     #[serde(default, skip_serializing_if = "Option::is_none")]
@@ -252,7 +280,7 @@ impl DimensionProperties {
     }
 }
 #[doc = "The server error."]
-#[derive(Clone, Debug, PartialEq, Serialize, Deserialize, Default)]
+#[derive(Clone, Debug, PartialEq, Eq, Hash, Serialize, Deserialize, Default)]
 pub struct Error {
     #[doc = "The error code."]
     #[serde(default, skip_serializing_if = "Option::is_none")]
@@ -270,7 +298,7 @@ impl Error {
     }
 }
 #[doc = "A rule governing the accessibility of a vault from a specific ip address or ip range."]
-#[derive(Clone, Debug, PartialEq, Serialize, Deserialize)]
+#[derive(Clone, Debug, PartialEq, Eq, Hash, Serialize, Deserialize)]
 pub struct IpRule {
     #[doc = "An IPv4 address range in CIDR notation, such as '124.56.78.91' (simple IP address) or '124.56.78.0/24' (all addresses that start with 124.56.78)."]
     pub value: String,
@@ -281,7 +309,7 @@ impl IpRule {
     }
 }
 #[doc = "The type of identity."]
-#[derive(Clone, Debug, PartialEq, Serialize, Deserialize)]
+#[derive(Clone, Debug, PartialEq, Eq, Hash, Serialize, Deserialize)]
 #[serde(remote = "IdentityType")]
 pub enum IdentityType {
     User,
@@ -321,8 +349,25 @@ impl Serialize for IdentityType {
         }
     }
 }
+impl IdentityType {
+    #[doc = "The value as it is sent on the wire."]
+    pub fn as_str(&self) -> &str {
+        match self {
+            Self::User => "User",
+            Self::Application => "Application",
+            Self::ManagedIdentity => "ManagedIdentity",
+            Self::Key => "Key",
+            Self::UnknownValue(s) => s.as_str(),
+        }
+    }
+}
+impl std::fmt::Display for IdentityType {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.write_str(self.as_str())
+    }
+}
 #[doc = "Log specification of operation."]
-#[derive(Clone, Debug, PartialEq, Serialize, Deserialize, Default)]
+#[derive(Clone, Debug, PartialEq, Eq, Hash, Serialize, Deserialize, Default)]
 pub struct LogSpecification {
     #[doc = "Name of log specification."]
     #[serde(default, skip_serializing_if = "Option::is_none")]
@@ -340,7 +385,7 @@ impl LogSpecification {
     }
 }
 #[doc = "A rule governing the accessibility of a managed hsm pool from a specific ip address or ip range."]
-#[derive(Clone, Debug, PartialEq, Serialize, Deserialize)]
+#[derive(Clone, Debug, PartialEq, Eq, Hash, Serialize, Deserialize)]
 pub struct MhsmipRule {
     #[doc = "An IPv4 address range in CIDR notation, such as '124.56.78.91' (simple IP address) or '124.56.78.0/24' (all addresses that start with 124.56.78)."]
     pub value: String,
@@ -351,7 +396,7 @@ impl MhsmipRule {
     }
 }
 #[doc = "A set of rules governing the network accessibility of a managed hsm pool."]
-#[derive(Clone, Debug, PartialEq, Serialize, Deserialize, Default)]
+#[derive(Clone, Debug, PartialEq, Eq, Hash, Serialize, Deserialize, Default)]
 pub struct MhsmNetworkRuleSet {
     #[doc = "Tells what traffic can bypass network rules. This can be 'AzureServices' or 'None'.  If not specified the default is 'AzureServices'."]
     #[serde(default, skip_serializing_if = "Option::is_none")]
@@ -384,7 +429,7 @@ impl MhsmNetworkRuleSet {
 pub mod mhsm_network_rule_set {
     use super::*;
     #[doc = "Tells what traffic can bypass network rules. This can be 'AzureServices' or 'None'.  If not specified the default is 'AzureServices'."]
-    #[derive(Clone, Debug, PartialEq, Serialize, Deserialize)]
+    #[derive(Clone, Debug, PartialEq, Eq, Hash, Serialize, Deserialize)]
     #[serde(remote = "Bypass")]
     pub enum Bypass {
         AzureServices,
@@ -420,8 +465,23 @@ pub mod mhsm_network_rule_set {
             }
         }
     }
+    impl Bypass {
+        #[doc = "The value as it is sent on the wire."]
+        pub fn as_str(&self) -> &str {
+            match self {
+                Self::AzureServices => "AzureServices",
+                Self::None => "None",
+                Self::UnknownValue(s) => s.as_str(),
+            }
+        }
+    }
+    impl std::fmt::Display for Bypass {
+        fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+            f.write_str(self.as_str())
+        }
+    }
     #[doc = "The default action when no rule from ipRules and from virtualNetworkRules match. This is only used after the bypass property has been evaluated."]
-    #[derive(Clone, Debug, PartialEq, Serialize, Deserialize)]
+    #[derive(Clone, Debug, PartialEq, Eq, Hash, Serialize, Deserialize)]
     #[serde(remote = "DefaultAction")]
     pub enum DefaultAction {
         Allow,
@@ -457,9 +517,24 @@ pub mod mhsm_network_rule_set {
             }
         }
     }
+    impl DefaultAction {
+        #[doc = "The value as it is sent on the wire."]
+        pub fn as_str(&self) -> &str {
+            match self {
+                Self::Allow => "Allow",
+                Self::Deny => "Deny",
+                Self::UnknownValue(s) => s.as_str(),
+            }
+        }
+    }
+    impl std::fmt::Display for DefaultAction {
+        fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+            f.write_str(self.as_str())
+        }
+    }
 }
 #[doc = "Private endpoint object properties."]
-#[derive(Clone, Debug, PartialEq, Serialize, Deserialize, Default)]
+#[derive(Clone, Debug, PartialEq, Eq, Hash, Serialize, Deserialize, Default)]
 pub struct MhsmPrivateEndpoint {
     #[doc = "Full identifier of the private endpoint resource."]
     #[serde(default, skip_serializing_if = "Option::is_none")]
@@ -488,7 +563,7 @@ impl MhsmPrivateEndpointConnection {
     }
 }
 #[doc = "Private endpoint connection item."]
-#[derive(Clone, Debug, PartialEq, Serialize, Deserialize, Default)]
+#[derive(Clone, Debug, PartialEq, Eq, Hash, Serialize, Deserialize, Default)]
 pub struct MhsmPrivateEndpointConnectionItem {
     #[doc = "Id of private endpoint connection."]
     #[serde(default, skip_serializing_if = "Option::is_none")]
@@ -506,7 +581,7 @@ impl MhsmPrivateEndpointConnectionItem {
     }
 }
 #[doc = "Properties of the private endpoint connection resource."]
-#[derive(Clone, Debug, PartialEq, Serialize, Deserialize, Default)]
+#[derive(Clone, Debug, PartialEq, Eq, Hash, Serialize, Deserialize, Default)]
 pub struct MhsmPrivateEndpointConnectionProperties {
     #[doc = "Private endpoint object properties."]
     #[serde(rename = "privateEndpoint", default, skip_serializing_if = "Option::is_none")]
@@ -524,7 +599,7 @@ impl MhsmPrivateEndpointConnectionProperties {
     }
 }
 #[doc = "The current provisioning state."]
-#[derive(Clone, Debug, PartialEq, Serialize, Deserialize)]
+#[derive(Clone, Debug, PartialEq, Eq, Hash, Serialize, Deserialize)]
 #[serde(remote = "MhsmPrivateEndpointConnectionProvisioningState")]
 pub enum MhsmPrivateEndpointConnectionProvisioningState {
     Succeeded,
@@ -568,6 +643,25 @@ impl Serialize for MhsmPrivateEndpointConnectionProvisioningState {
         }
     }
 }
+impl MhsmPrivateEndpointConnectionProvisioningState {
+    #[doc = "The value as it is sent on the wire."]
+    pub fn as_str(&self) -> &str {
+        match self {
+            Self::Succeeded => "Succeeded",
+            Self::Creating => "Creating",
+            Self::Updating => "Updating",
+            Self::Deleting => "Deleting",
+            Self::Failed => "Failed",
+            Self::Disconnected => "Disconnected",
+            Self::UnknownValue(s) => s.as_str(),
+        }
+    }
+}
+impl std::fmt::Display for MhsmPrivateEndpointConnectionProvisioningState {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.write_str(self.as_str())
+    }
+}
 #[doc = "List of private endpoint connections associated with a managed HSM Pools"]
 #[derive(Clone, Debug, PartialEq, Serialize, Deserialize, Default)]
 pub struct MhsmPrivateEndpointConnectionsListResult {
@@ -592,9 +686,13 @@ impl MhsmPrivateEndpointConnectionsListResult {
     pub fn new() -> Self {
         Self::default()
     }
+    #[doc = "The items in this page."]
+    pub fn items(&self) -> &[MhsmPrivateEndpointConnection] {
+        &self.value
+    }
 }
 #[doc = "The private endpoint connection status."]
-#[derive(Clone, Debug, PartialEq, Serialize, Deserialize)]
+#[derive(Clone, Debug, PartialEq, Eq, Hash, Serialize, Deserialize)]
 #[serde(remote = "MhsmPrivateEndpointServiceConnectionStatus")]
 pub enum MhsmPrivateEndpointServiceConnectionStatus {
     Pending,
@@ -634,6 +732,23 @@ impl Serialize for MhsmPrivateEndpointServiceConnectionStatus {
         }
     }
 }
+impl MhsmPrivateEndpointServiceConnectionStatus {
+    #[doc = "The value as it is sent on the wire."]
+    pub fn as_str(&self) -> &str {
+        match self {
+            Self::Pending => "Pending",
+            Self::Approved => "Approved",
+            Self::Rejected => "Rejected",
+            Self::Disconnected => "Disconnected",
+            Self::UnknownValue(s) => s.as_str(),
+        }
+    }
+}
+impl std::fmt::Display for MhsmPrivateEndpointServiceConnectionStatus {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.write_str(self.as_str())
+    }
+}
 #[doc = "A private link resource"]
 #[derive(Clone, Debug, PartialEq, Serialize, Deserialize, Default)]
 pub struct MhsmPrivateLinkResource {
@@ -663,9 +778,13 @@ impl MhsmPrivateLinkResourceListResult {
     pub fn new() -> Self {
         Self::default()
     }
+    #[doc = "The items in this page."]
+    pub fn items(&self) -> &[MhsmPrivateLinkResource] {
+        &self.value
+    }
 }
 #[doc = "Properties of a private link resource."]
-#[derive(Clone, Debug, PartialEq, Serialize, Deserialize, Default)]
+#[derive(Clone, Debug, PartialEq, Eq, Hash, Serialize, Deserialize, Default)]
 pub struct MhsmPrivateLinkResourceProperties {
     #[doc = "Group identifier of private link resource."]
     #[serde(rename = "groupId", default, skip_serializing_if = "Option::is_none")]
@@ -693,7 +812,7 @@ impl MhsmPrivateLinkResourceProperties {
     }
 }
 #[doc = "An object that represents the approval state of the private link connection."]
-#[derive(Clone, Debug, PartialEq, Serialize, Deserialize, Default)]
+#[derive(Clone, Debug, PartialEq, Eq, Hash, Serialize, Deserialize, Default)]
 pub struct MhsmPrivateLinkServiceConnectionState {
     #[doc = "The private endpoint connection status."]
     #[serde(default, skip_serializing_if = "Option::is_none")]
@@ -713,7 +832,7 @@ impl MhsmPrivateLinkServiceConnectionState {
 pub mod mhsm_private_link_service_connection_state {
     use super::*;
     #[doc = "A message indicating if changes on the service provider require any updates on the consumer."]
-    #[derive(Clone, Debug, PartialEq, Serialize, Deserialize)]
+    #[derive(Clone, Debug, PartialEq, Eq, Hash, Serialize, Deserialize)]
     #[serde(remote = "ActionsRequired")]
     pub enum ActionsRequired {
         None,
@@ -747,9 +866,23 @@ pub mod mhsm_private_link_service_connection_state {
             }
         }
     }
+    impl ActionsRequired {
+        #[doc = "The value as it is sent on the wire."]
+        pub fn as_str(&self) -> &str {
+            match self {
+                Self::None => "None",
+                Self::UnknownValue(s) => s.as_str(),
+            }
+        }
+    }
+    impl std::fmt::Display for ActionsRequired {
+        fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+            f.write_str(self.as_str())
+        }
+    }
 }
 #[doc = "A rule governing the accessibility of a managed hsm pool from a specific virtual network."]
-#[derive(Clone, Debug, PartialEq, Serialize, Deserialize)]
+#[derive(Clone, Debug, PartialEq, Eq, Hash, Serialize, Deserialize)]
 pub struct MhsmVirtualNetworkRule {
     #[doc = "Full resource id of a vnet subnet, such as '/subscriptions/subid/resourceGroups/rg1/providers/Microsoft.Network/virtualNetworks/test-vnet/subnets/subnet1'."]
     pub id: String,
@@ -774,7 +907,7 @@ impl ManagedHsm {
     }
 }
 #[doc = "The error exception."]
-#[derive(Clone, Debug, PartialEq, Serialize, Deserialize, Default)]
+#[derive(Clone, Debug, PartialEq, Eq, Hash, Serialize, Deserialize, Default)]
 pub struct ManagedHsmError {
     #[doc = "The server error."]
     #[serde(default, skip_serializing_if = "Option::is_none")]
@@ -815,9 +948,13 @@ impl ManagedHsmListResult {
     pub fn new() -> Self {
         Self::default()
     }
+    #[doc = "The items in this page."]
+    pub fn items(&self) -> &[ManagedHsm] {
+        &self.value
+    }
 }
 #[doc = "Properties of the managed HSM Pool"]
-#[derive(Clone, Debug, PartialEq, Serialize, Deserialize, Default)]
+#[derive(Clone, Debug, PartialEq, Eq, Hash, Serialize, Deserialize, Default)]
 pub struct ManagedHsmProperties {
     #[doc = "The Azure Active Directory tenant ID that should be used for authenticating requests to the managed HSM pool."]
     #[serde(rename = "tenantId", default, skip_serializing_if = "Option::is_none")]
@@ -866,7 +1003,12 @@ pub struct ManagedHsmProperties {
     #[serde(rename = "publicNetworkAccess", default, skip_serializing_if = "Option::is_none")]
     pub public_network_access: Option<managed_hsm_properties::PublicNetworkAccess>,
     #[doc = "The scheduled purge date in UTC."]
-    #[serde(rename = "scheduledPurgeDate", default, with = "azure_core::date::rfc3339::option")]
+    #[serde(
+        rename = "scheduledPurgeDate",
+        default,
+        with = "azure_core::date::iso8601::option",
+        skip_serializing_if = "Option::is_none"
+    )]
     pub scheduled_purge_date: Option<time::OffsetDateTime>,
 }
 impl ManagedHsmProperties {
@@ -877,7 +1019,7 @@ impl ManagedHsmProperties {
 pub mod managed_hsm_properties {
     use super::*;
     #[doc = "The create mode to indicate whether the resource is being created or is being recovered from a deleted resource."]
-    #[derive(Clone, Debug, PartialEq, Serialize, Deserialize)]
+    #[derive(Clone, Debug, PartialEq, Eq, Hash, Serialize, Deserialize)]
     pub enum CreateMode {
         #[serde(rename = "recover")]
         Recover,
@@ -885,7 +1027,7 @@ pub mod managed_hsm_properties {
         Default,
     }
     #[doc = "Provisioning state."]
-    #[derive(Clone, Debug, PartialEq, Serialize, Deserialize)]
+    #[derive(Clone, Debug, PartialEq, Eq, Hash, Serialize, Deserialize)]
     #[serde(remote = "ProvisioningState")]
     pub enum ProvisioningState {
         Succeeded,
@@ -933,8 +1075,29 @@ pub mod managed_hsm_properties {
             }
         }
     }
+    impl ProvisioningState {
+        #[doc = "The value as it is sent on the wire."]
+        pub fn as_str(&self) -> &str {
+            match self {
+                Self::Succeeded => "Succeeded",
+                Self::Provisioning => "Provisioning",
+                Self::Failed => "Failed",
+                Self::Updating => "Updating",
+                Self::Deleting => "Deleting",
+                Self::Activated => "Activated",
+                Self::SecurityDomainRestore => "SecurityDomainRestore",
+                Self::Restoring => "Restoring",
+                Self::UnknownValue(s) => s.as_str(),
+            }
+        }
+    }
+    impl std::fmt::Display for ProvisioningState {
+        fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+            f.write_str(self.as_str())
+        }
+    }
     #[doc = "Control permission for data plane traffic coming from public networks while private endpoint is enabled."]
-    #[derive(Clone, Debug, PartialEq, Serialize, Deserialize)]
+    #[derive(Clone, Debug, PartialEq, Eq, Hash, Serialize, Deserialize)]
     #[serde(remote = "PublicNetworkAccess")]
     pub enum PublicNetworkAccess {
         Enabled,
@@ -970,6 +1133,21 @@ pub mod managed_hsm_properties {
             }
         }
     }
+    impl PublicNetworkAccess {
+        #[doc = "The value as it is sent on the wire."]
+        pub fn as_str(&self) -> &str {
+            match self {
+                Self::Enabled => "Enabled",
+                Self::Disabled => "Disabled",
+                Self::UnknownValue(s) => s.as_str(),
+            }
+        }
+    }
+    impl std::fmt::Display for PublicNetworkAccess {
+        fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+            f.write_str(self.as_str())
+        }
+    }
 }
 #[doc = "Managed HSM resource"]
 #[derive(Clone, Debug, PartialEq, Serialize, Deserialize, Default)]
@@ -1002,7 +1180,7 @@ impl ManagedHsmResource {
     }
 }
 #[doc = "SKU details"]
-#[derive(Clone, Debug, PartialEq, Serialize, Deserialize)]
+#[derive(Clone, Debug, PartialEq, Eq, Hash, Serialize, Deserialize)]
 pub struct ManagedHsmSku {
     #[doc = "SKU Family of the managed HSM Pool"]
     pub family: managed_hsm_sku::Family,
@@ -1017,7 +1195,7 @@ impl ManagedHsmSku {
 pub mod managed_hsm_sku {
     use super::*;
     #[doc = "SKU Family of the managed HSM Pool"]
-    #[derive(Clone, Debug, PartialEq, Serialize, Deserialize)]
+    #[derive(Clone, Debug, PartialEq, Eq, Hash, Serialize, Deserialize)]
     #[serde(remote = "Family")]
     pub enum Family {
         B,
@@ -1051,8 +1229,22 @@ pub mod managed_hsm_sku {
             }
         }
     }
+    impl Family {
+        #[doc = "The value as it is sent on the wire."]
+        pub fn as_str(&self) -> &str {
+            match self {
+                Self::B => "B",
+                Self::UnknownValue(s) => s.as_str(),
+            }
+        }
+    }
+    impl std::fmt::Display for Family {
+        fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+            f.write_str(self.as_str())
+        }
+    }
     #[doc = "SKU of the managed HSM Pool"]
-    #[derive(Clone, Debug, PartialEq, Serialize, Deserialize)]
+    #[derive(Clone, Debug, PartialEq, Eq, Hash, Serialize, Deserialize)]
     pub enum Name {
         #[serde(rename = "Standard_B1")]
         StandardB1,
@@ -1061,7 +1253,7 @@ pub mod managed_hsm_sku {
     }
 }
 #[doc = "Metric specification of operation."]
-#[derive(Clone, Debug, PartialEq, Serialize, Deserialize, Default)]
+#[derive(Clone, Debug, PartialEq, Eq, Hash, Serialize, Deserialize, Default)]
 pub struct MetricSpecification {
     #[doc = "Name of metric specification."]
     #[serde(default, skip_serializing_if = "Option::is_none")]
@@ -1117,7 +1309,7 @@ impl MetricSpecification {
     }
 }
 #[doc = "A set of rules governing the network accessibility of a vault."]
-#[derive(Clone, Debug, PartialEq, Serialize, Deserialize, Default)]
+#[derive(Clone, Debug, PartialEq, Eq, Hash, Serialize, Deserialize, Default)]
 pub struct NetworkRuleSet {
     #[doc = "Tells what traffic can bypass network rules. This can be 'AzureServices' or 'None'.  If not specified the default is 'AzureServices'."]
     #[serde(default, skip_serializing_if = "Option::is_none")]
@@ -1150,7 +1342,7 @@ impl NetworkRuleSet {
 pub mod network_rule_set {
     use super::*;
     #[doc = "Tells what traffic can bypass network rules. This can be 'AzureServices' or 'None'.  If not specified the default is 'AzureServices'."]
-    #[derive(Clone, Debug, PartialEq, Serialize, Deserialize)]
+    #[derive(Clone, Debug, PartialEq, Eq, Hash, Serialize, Deserialize)]
     #[serde(remote = "Bypass")]
     pub enum Bypass {
         AzureServices,
@@ -1186,8 +1378,23 @@ pub mod network_rule_set {
             }
         }
     }
+    impl Bypass {
+        #[doc = "The value as it is sent on the wire."]
+        pub fn as_str(&self) -> &str {
+            match self {
+                Self::AzureServices => "AzureServices",
+                Self::None => "None",
+                Self::UnknownValue(s) => s.as_str(),
+            }
+        }
+    }
+    impl std::fmt::Display for Bypass {
+        fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+            f.write_str(self.as_str())
+        }
+    }
     #[doc = "The default action when no rule from ipRules and from virtualNetworkRules match. This is only used after the bypass property has been evaluated."]
-    #[derive(Clone, Debug, PartialEq, Serialize, Deserialize)]
+    #[derive(Clone, Debug, PartialEq, Eq, Hash, Serialize, Deserialize)]
     #[serde(remote = "DefaultAction")]
     pub enum DefaultAction {
         Allow,
@@ -1223,9 +1430,24 @@ pub mod network_rule_set {
             }
         }
     }
+    impl DefaultAction {
+        #[doc = "The value as it is sent on the wire."]
+        pub fn as_str(&self) -> &str {
+            match self {
+                Self::Allow => "Allow",
+                Self::Deny => "Deny",
+                Self::UnknownValue(s) => s.as_str(),
+            }
+        }
+    }
+    impl std::fmt::Display for DefaultAction {
+        fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+            f.write_str(self.as_str())
+        }
+    }
 }
 #[doc = "Key Vault REST API operation definition."]
-#[derive(Clone, Debug, PartialEq, Serialize, Deserialize, Default)]
+#[derive(Clone, Debug, PartialEq, Eq, Hash, Serialize, Deserialize, Default)]
 pub struct Operation {
     #[doc = "Operation name: {provider}/{resource}/{operation}"]
     #[serde(default, skip_serializing_if = "Option::is_none")]
@@ -1251,7 +1473,7 @@ impl Operation {
 pub mod operation {
     use super::*;
     #[doc = "Display metadata associated with the operation."]
-    #[derive(Clone, Debug, PartialEq, Serialize, Deserialize, Default)]
+    #[derive(Clone, Debug, PartialEq, Eq, Hash, Serialize, Deserialize, Default)]
     pub struct Display {
         #[doc = "Service provider: Microsoft Key Vault."]
         #[serde(default, skip_serializing_if = "Option::is_none")]
@@ -1273,7 +1495,7 @@ pub mod operation {
     }
 }
 #[doc = "Result of the request to list Storage operations. It contains a list of operations and a URL link to get the next set of results."]
-#[derive(Clone, Debug, PartialEq, Serialize, Deserialize, Default)]
+#[derive(Clone, Debug, PartialEq, Eq, Hash, Serialize, Deserialize, Default)]
 pub struct OperationListResult {
     #[doc = "List of Storage operations supported by the Storage resource provider."]
     #[serde(
@@ -1296,9 +1518,13 @@ impl OperationListResult {
     pub fn new() -> Self {
         Self::default()
     }
+    #[doc = "The items in this page."]
+    pub fn items(&self) -> &[Operation] {
+        &self.value
+    }
 }
 #[doc = "Properties of operation, include metric specifications."]
-#[derive(Clone, Debug, PartialEq, Serialize, Deserialize, Default)]
+#[derive(Clone, Debug, PartialEq, Eq, Hash, Serialize, Deserialize, Default)]
 pub struct OperationProperties {
     #[doc = "One property of operation, include log specifications."]
     #[serde(rename = "serviceSpecification", default, skip_serializing_if = "Option::is_none")]
@@ -1310,7 +1536,7 @@ impl OperationProperties {
     }
 }
 #[doc = "Permissions the identity has for keys, secrets, certificates and storage."]
-#[derive(Clone, Debug, PartialEq, Serialize, Deserialize, Default)]
+#[derive(Clone, Debug, PartialEq, Eq, Hash, Serialize, Deserialize, Default)]
 pub struct Permissions {
     #[doc = "Permissions to keys"]
     #[serde(
@@ -1347,7 +1573,7 @@ impl Permissions {
     }
 }
 #[doc = "Private endpoint object properties."]
-#[derive(Clone, Debug, PartialEq, Serialize, Deserialize, Default)]
+#[derive(Clone, Debug, PartialEq, Eq, Hash, Serialize, Deserialize, Default)]
 pub struct PrivateEndpoint {
     #[doc = "Full identifier of the private endpoint resource."]
     #[serde(default, skip_serializing_if = "Option::is_none")]
@@ -1376,7 +1602,7 @@ impl PrivateEndpointConnection {
     }
 }
 #[doc = "Private endpoint connection item."]
-#[derive(Clone, Debug, PartialEq, Serialize, Deserialize, Default)]
+#[derive(Clone, Debug, PartialEq, Eq, Hash, Serialize, Deserialize, Default)]
 pub struct PrivateEndpointConnectionItem {
     #[doc = "Id of private endpoint connection."]
     #[serde(default, skip_serializing_if = "Option::is_none")]
@@ -1417,9 +1643,13 @@ impl PrivateEndpointConnectionListResult {
     pub fn new() -> Self {
         Self::default()
     }
+    #[doc = "The items in this page."]
+    pub fn items(&self) -> &[PrivateEndpointConnection] {
+        &self.value
+    }
 }
 #[doc = "Properties of the private endpoint connection resource."]
-#[derive(Clone, Debug, PartialEq, Serialize, Deserialize, Default)]
+#[derive(Clone, Debug, PartialEq, Eq, Hash, Serialize, Deserialize, Default)]
 pub struct PrivateEndpointConnectionProperties {
     #[doc = "Private endpoint object properties."]
     #[serde(rename = "privateEndpoint", default, skip_serializing_if = "Option::is_none")]
@@ -1437,7 +1667,7 @@ impl PrivateEndpointConnectionProperties {
     }
 }
 #[doc = "The current provisioning state."]
-#[derive(Clone, Debug, PartialEq, Serialize, Deserialize)]
+#[derive(Clone, Debug, PartialEq, Eq, Hash, Serialize, Deserialize)]
 #[serde(remote = "PrivateEndpointConnectionProvisioningState")]
 pub enum PrivateEndpointConnectionProvisioningState {
     Succeeded,
@@ -1481,8 +1711,27 @@ impl Serialize for PrivateEndpointConnectionProvisioningState {
         }
     }
 }
+impl PrivateEndpointConnectionProvisioningState {
+    #[doc = "The value as it is sent on the wire."]
+    pub fn as_str(&self) -> &str {
+        match self {
+            Self::Succeeded => "Succeeded",
+            Self::Creating => "Creating",
+            Self::Updating => "Updating",
+            Self::Deleting => "Deleting",
+            Self::Failed => "Failed",
+            Self::Disconnected => "Disconnected",
+            Self::UnknownValue(s) => s.as_str(),
+        }
+    }
+}
+impl std::fmt::Display for PrivateEndpointConnectionProvisioningState {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.write_str(self.as_str())
+    }
+}
 #[doc = "The private endpoint connection status."]
-#[derive(Clone, Debug, PartialEq, Serialize, Deserialize)]
+#[derive(Clone, Debug, PartialEq, Eq, Hash, Serialize, Deserialize)]
 #[serde(remote = "PrivateEndpointServiceConnectionStatus")]
 pub enum PrivateEndpointServiceConnectionStatus {
     Pending,
@@ -1522,6 +1771,23 @@ impl Serialize for PrivateEndpointServiceConnectionStatus {
         }
     }
 }
+impl PrivateEndpointServiceConnectionStatus {
+    #[doc = "The value as it is sent on the wire."]
+    pub fn as_str(&self) -> &str {
+        match self {
+            Self::Pending => "Pending",
+            Self::Approved => "Approved",
+            Self::Rejected => "Rejected",
+            Self::Disconnected => "Disconnected",
+            Self::UnknownValue(s) => s.as_str(),
+        }
+    }
+}
+impl std::fmt::Display for PrivateEndpointServiceConnectionStatus {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.write_str(self.as_str())
+    }
+}
 #[doc = "A private link resource"]
 #[derive(Clone, Debug, PartialEq, Serialize, Deserialize, Default)]
 pub struct PrivateLinkResource {
@@ -1551,9 +1817,13 @@ impl PrivateLinkResourceListResult {
     pub fn new() -> Self {
         Self::default()
     }
+    #[doc = "The items in this page."]
+    pub fn items(&self) -> &[PrivateLinkResource] {
+        &self.value
+    }
 }
 #[doc = "Properties of a private link resource."]
-#[derive(Clone, Debug, PartialEq, Serialize, Deserialize, Default)]
+#[derive(Clone, Debug, PartialEq, Eq, Hash, Serialize, Deserialize, Default)]
 pub struct PrivateLinkResourceProperties {
     #[doc = "Group identifier of private link resource."]
     #[serde(rename = "groupId", default, skip_serializing_if = "Option::is_none")]
@@ -1581,7 +1851,7 @@ impl PrivateLinkResourceProperties {
     }
 }
 #[doc = "An object that represents the approval state of the private link connection."]
-#[derive(Clone, Debug, PartialEq, Serialize, Deserialize, Default)]
+#[derive(Clone, Debug, PartialEq, Eq, Hash, Serialize, Deserialize, Default)]
 pub struct PrivateLinkServiceConnectionState {
     #[doc = "The private endpoint connection status."]
     #[serde(default, skip_serializing_if = "Option::is_none")]
@@ -1601,7 +1871,7 @@ impl PrivateLinkServiceConnectionState {
 pub mod private_link_service_connection_state {
     use super::*;
     #[doc = "A message indicating if changes on the service provider require any updates on the consumer."]
-    #[derive(Clone, Debug, PartialEq, Serialize, Deserialize)]
+    #[derive(Clone, Debug, PartialEq, Eq, Hash, Serialize, Deserialize)]
     #[serde(remote = "ActionsRequired")]
     pub enum ActionsRequired {
         None,
@@ -1635,6 +1905,20 @@ pub mod private_link_service_connection_state {
             }
         }
     }
+    impl ActionsRequired {
+        #[doc = "The value as it is sent on the wire."]
+        pub fn as_str(&self) -> &str {
+            match self {
+                Self::None => "None",
+                Self::UnknownValue(s) => s.as_str(),
+            }
+        }
+    }
+    impl std::fmt::Display for ActionsRequired {
+        fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+            f.write_str(self.as_str())
+        }
+    }
 }
 #[doc = "Key Vault resource"]
 #[derive(Clone, Debug, PartialEq, Serialize, Deserialize, Default)]
@@ -1684,9 +1968,13 @@ impl ResourceListResult {
     pub fn new() -> Self {
         Self::default()
     }
+    #[doc = "The items in this page."]
+    pub fn items(&self) -> &[Resource] {
+        &self.value
+    }
 }
 #[doc = "One property of operation, include log specifications."]
-#[derive(Clone, Debug, PartialEq, Serialize, Deserialize, Default)]
+#[derive(Clone, Debug, PartialEq, Eq, Hash, Serialize, Deserialize, Default)]
 pub struct ServiceSpecification {
     #[doc = "Log specifications of operation."]
     #[serde(
@@ -1711,7 +1999,7 @@ impl ServiceSpecification {
     }
 }
 #[doc = "SKU details"]
-#[derive(Clone, Debug, PartialEq, Serialize, Deserialize)]
+#[derive(Clone, Debug, PartialEq, Eq, Hash, Serialize, Deserialize)]
 pub struct Sku {
     #[doc = "SKU family name"]
     pub family: sku::Family,
@@ -1726,7 +2014,7 @@ impl Sku {
 pub mod sku {
     use super::*;
     #[doc = "SKU family name"]
-    #[derive(Clone, Debug, PartialEq, Serialize, Deserialize)]
+    #[derive(Clone, Debug, PartialEq, Eq, Hash, Serialize, Deserialize)]
     #[serde(remote = "Family")]
     pub enum Family {
         A,
@@ -1760,8 +2048,22 @@ pub mod sku {
             }
         }
     }
+    impl Family {
+        #[doc = "The value as it is sent on the wire."]
+        pub fn as_str(&self) -> &str {
+            match self {
+                Self::A => "A",
+                Self::UnknownValue(s) => s.as_str(),
+            }
+        }
+    }
+    impl std::fmt::Display for Family {
+        fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+            f.write_str(self.as_str())
+        }
+    }
     #[doc = "SKU name to specify whether the key vault is a standard vault or a premium vault."]
-    #[derive(Clone, Debug, PartialEq, Serialize, Deserialize)]
+    #[derive(Clone, Debug, PartialEq, Eq, Hash, Serialize, Deserialize)]
     pub enum Name {
         #[serde(rename = "standard")]
         Standard,
@@ -1770,7 +2072,7 @@ pub mod sku {
     }
 }
 #[doc = "Metadata pertaining to creation and last modification of the key vault resource."]
-#[derive(Clone, Debug, PartialEq, Serialize, Deserialize, Default)]
+#[derive(Clone, Debug, PartialEq, Eq, Hash, Serialize, Deserialize, Default)]
 pub struct SystemData {
     #[doc = "The identity that created the key vault resource."]
     #[serde(rename = "createdBy", default, skip_serializing_if = "Option::is_none")]
@@ -1779,7 +2081,12 @@ pub struct SystemData {
     #[serde(rename = "createdByType", default, skip_serializing_if = "Option::is_none")]
     pub created_by_type: Option<IdentityType>,
     #[doc = "The timestamp of the key vault resource creation (UTC)."]
-    #[serde(rename = "createdAt", default, with = "azure_core::date::rfc3339::option")]
+    #[serde(
+        rename = "createdAt",
+        default,
+        with = "azure_core::date::iso8601::option",
+        skip_serializing_if = "Option::is_none"
+    )]
     pub created_at: Option<time::OffsetDateTime>,
     #[doc = "The identity that last modified the key vault resource."]
     #[serde(rename = "lastModifiedBy", default, skip_serializing_if = "Option::is_none")]
@@ -1788,7 +2095,12 @@ pub struct SystemData {
     #[serde(rename = "lastModifiedByType", default, skip_serializing_if = "Option::is_none")]
     pub last_modified_by_type: Option<IdentityType>,
     #[doc = "The timestamp of the key vault resource last modification (UTC)."]
-    #[serde(rename = "lastModifiedAt", default, with = "azure_core::date::rfc3339::option")]
+    #[serde(
+        rename = "lastModifiedAt",
+        default,
+        with = "azure_core::date::iso8601::option",
+        skip_serializing_if = "Option::is_none"
+    )]
     pub last_modified_at: Option<time::OffsetDateTime>,
 }
 impl SystemData {
@@ -1834,7 +2146,7 @@ impl Vault {
     }
 }
 #[doc = "Parameters for updating the access policy in a vault"]
-#[derive(Clone, Debug, PartialEq, Serialize, Deserialize)]
+#[derive(Clone, Debug, PartialEq, Eq, Hash, Serialize, Deserialize)]
 pub struct VaultAccessPolicyParameters {
     #[doc = "The resource id of the access policy."]
     #[serde(default, skip_serializing_if = "Option::is_none")]
@@ -1863,7 +2175,7 @@ impl VaultAccessPolicyParameters {
     }
 }
 #[doc = "Properties of the vault access policy"]
-#[derive(Clone, Debug, PartialEq, Serialize, Deserialize)]
+#[derive(Clone, Debug, PartialEq, Eq, Hash, Serialize, Deserialize)]
 pub struct VaultAccessPolicyProperties {
     #[doc = "An array of 0 to 16 identities that have access to the key vault. All identities in the array must use the same tenant ID as the key vault's tenant ID."]
     #[serde(rename = "accessPolicies")]
@@ -1875,7 +2187,7 @@ impl VaultAccessPolicyProperties {
     }
 }
 #[doc = "The parameters used to check the availability of the vault name."]
-#[derive(Clone, Debug, PartialEq, Serialize, Deserialize)]
+#[derive(Clone, Debug, PartialEq, Eq, Hash, Serialize, Deserialize)]
 pub struct VaultCheckNameAvailabilityParameters {
     #[doc = "The vault name."]
     pub name: String,
@@ -1891,7 +2203,7 @@ impl VaultCheckNameAvailabilityParameters {
 pub mod vault_check_name_availability_parameters {
     use super::*;
     #[doc = "The type of resource, Microsoft.KeyVault/vaults"]
-    #[derive(Clone, Debug, PartialEq, Serialize, Deserialize)]
+    #[derive(Clone, Debug, PartialEq, Eq, Hash, Serialize, Deserialize)]
     pub enum Type {
         #[serde(rename = "Microsoft.KeyVault/vaults")]
         MicrosoftKeyVaultVaults,
@@ -1941,6 +2253,10 @@ impl VaultListResult {
     pub fn new() -> Self {
         Self::default()
     }
+    #[doc = "The items in this page."]
+    pub fn items(&self) -> &[Vault] {
+        &self.value
+    }
 }
 #[doc = "Parameters for creating or updating a vault"]
 #[derive(Clone, Debug, PartialEq, Serialize, Deserialize, Default)]
@@ -1958,7 +2274,7 @@ impl VaultPatchParameters {
     }
 }
 #[doc = "Properties of the vault"]
-#[derive(Clone, Debug, PartialEq, Serialize, Deserialize, Default)]
+#[derive(Clone, Debug, PartialEq, Eq, Hash, Serialize, Deserialize, Default)]
 pub struct VaultPatchProperties {
     #[doc = "The Azure Active Directory tenant ID that should be used for authenticating requests to the key vault."]
     #[serde(rename = "tenantId", default, skip_serializing_if = "Option::is_none")]
@@ -2010,7 +2326,7 @@ impl VaultPatchProperties {
 pub mod vault_patch_properties {
     use super::*;
     #[doc = "The vault's create mode to indicate whether the vault need to be recovered or not."]
-    #[derive(Clone, Debug, PartialEq, Serialize, Deserialize)]
+    #[derive(Clone, Debug, PartialEq, Eq, Hash, Serialize, Deserialize)]
     pub enum CreateMode {
         #[serde(rename = "recover")]
         Recover,
@@ -2019,7 +2335,7 @@ pub mod vault_patch_properties {
     }
 }
 #[doc = "Properties of the vault"]
-#[derive(Clone, Debug, PartialEq, Serialize, Deserialize)]
+#[derive(Clone, Debug, PartialEq, Eq, Hash, Serialize, Deserialize)]
 pub struct VaultProperties {
     #[doc = "The Azure Active Directory tenant ID that should be used for authenticating requests to the key vault."]
     #[serde(rename = "tenantId")]
@@ -2104,7 +2420,7 @@ impl VaultProperties {
 pub mod vault_properties {
     use super::*;
     #[doc = "The vault's create mode to indicate whether the vault need to be recovered or not."]
-    #[derive(Clone, Debug, PartialEq, Serialize, Deserialize)]
+    #[derive(Clone, Debug, PartialEq, Eq, Hash, Serialize, Deserialize)]
     pub enum CreateMode {
         #[serde(rename = "recover")]
         Recover,
@@ -2112,7 +2428,7 @@ pub mod vault_properties {
         Default,
     }
     #[doc = "Provisioning state of the vault."]
-    #[derive(Clone, Debug, PartialEq, Serialize, Deserialize)]
+    #[derive(Clone, Debug, PartialEq, Eq, Hash, Serialize, Deserialize)]
     #[serde(remote = "ProvisioningState")]
     pub enum ProvisioningState {
         Succeeded,
@@ -2148,9 +2464,24 @@ pub mod vault_properties {
             }
         }
     }
+    impl ProvisioningState {
+        #[doc = "The value as it is sent on the wire."]
+        pub fn as_str(&self) -> &str {
+            match self {
+                Self::Succeeded => "Succeeded",
+                Self::RegisteringDns => "RegisteringDns",
+                Self::UnknownValue(s) => s.as_str(),
+            }
+        }
+    }
+    impl std::fmt::Display for ProvisioningState {
+        fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+            f.write_str(self.as_str())
+        }
+    }
 }
 #[doc = "A rule governing the accessibility of a vault from a specific virtual network."]
-#[derive(Clone, Debug, PartialEq, Serialize, Deserialize)]
+#[derive(Clone, Debug, PartialEq, Eq, Hash, Serialize, Deserialize)]
 pub struct VirtualNetworkRule {
     #[doc = "Full resource id of a vnet subnet, such as '/subscriptions/subid/resourceGroups/rg1/providers/Microsoft.Network/virtualNetworks/test-vnet/subnets/subnet1'."]
     pub id: String,
@@ -4,7 +4,7 @@ use serde::de::{value, Deserializer, IntoDeserializer};
 use serde::{Deserialize, Serialize, Serializer};
 use std::str::FromStr;
 #[doc = "An identity that have access to the key vault. All identities in the array must use the same tenant ID as the key vault's tenant ID."]
-#[derive(Clone, Debug, PartialEq, Serialize, Deserialize)]
+#[derive(Clone, Debug, PartialEq, Eq, Hash, Serialize, Deserialize)]
 pub struct AccessPolicyEntry {
     #[doc = "The Azure Active Directory tenant ID that should be used for authenticating requests to the key vault."]
     #[serde(rename = "tenantId")]
@@ -28,7 +28,7 @@ impl AccessPolicyEntry {
         }
     }
 }
-#[derive(Clone, Debug, PartialEq, Serialize, Deserialize, Default)]
+#[derive(Clone, Debug, PartialEq, Eq, Hash, Serialize, Deserialize, Default)]
 pub struct Action {
     #[doc = "The type of action."]
     #[serde(rename = "type", default, skip_serializing_if = "Option::is_none")]
@@ -42,7 +42,7 @@ impl Action {
 pub mod action {
     use super::*;
     #[doc = "The type of action."]
-    #[derive(Clone, Debug, PartialEq, Serialize, Deserialize)]
+    #[derive(Clone, Debug, PartialEq, Eq, Hash, Serialize, Deserialize)]
     pub enum Type {
         #[serde(rename = "rotate")]
         Rotate,
@@ -51,7 +51,7 @@ pub mod action {
     }
 }
 #[doc = "The object attributes managed by the KeyVault service."]
-#[derive(Clone, Debug, PartialEq, Serialize, Deserialize, Default)]
+#[derive(Clone, Debug, PartialEq, Eq, Hash, Serialize, Deserialize, Default)]
 pub struct Attributes {
     #[doc = "Determines whether the object is enabled."]
     #[serde(default, skip_serializing_if = "Option::is_none")]
@@ -75,7 +75,7 @@ impl Attributes {
     }
 }
 #[doc = "The CheckNameAvailability operation response."]
-#[derive(Clone, Debug, PartialEq, Serialize, Deserialize, Default)]
+#[derive(Clone, Debug, PartialEq, Eq, Hash, Serialize, Deserialize, Default)]
 pub struct CheckNameAvailabilityResult {
     #[doc = "A boolean value that indicates whether the name is available for you to use. If true, the name is available. If false, the name has already been taken or is invalid and cannot be used."]
     #[serde(rename = "nameAvailable", default, skip_serializing_if = "Option::is_none")]
@@ -95,14 +95,14 @@ impl CheckNameAvailabilityResult {
 pub mod check_name_availability_result {
     use super::*;
     #[doc = "The reason that a vault name could not be used. The Reason element is only returned if NameAvailable is false."]
-    #[derive(Clone, Debug, PartialEq, Serialize, Deserialize)]
+    #[derive(Clone, Debug, PartialEq, Eq, Hash, Serialize, Deserialize)]
     pub enum Reason {
         AccountNameInvalid,
         AlreadyExists,
     }
 }
 #[doc = "An error response from Key Vault resource provider"]
-#[derive(Clone, Debug, PartialEq, Serialize, Deserialize, Default)]
+#[derive(Clone, Debug, PartialEq, Eq, Hash, Serialize, Deserialize, Default)]
 pub struct CloudError {
     #[doc = "An error response from Key Vault resource provider"]
     #[serde(default, skip_serializing_if = "Option::is_none")]
@@ -120,7 +120,7 @@ impl CloudError {
     }
 }
 #[doc = "An error response from Key Vault resource provider"]
-#[derive(Clone, Debug, PartialEq, Serialize, Deserialize, Default)]
+#[derive(Clone, Debug, PartialEq, Eq, Hash, Serialize, Deserialize, Default)]
 pub struct CloudErrorBody {
     #[doc = "Error code. This is a mnemonic that can be consumed programmatically."]
     #[serde(default, skip_serializing_if = "Option::is_none")]
@@ -178,6 +178,10 @@ impl DeletedManagedHsmListResult {
     pub fn new() -> Self {
         Self::default()
     }
+    #[doc = "The items in this page."]
+    pub fn items(&self) -> &[DeletedManagedHsm] {
+        &self.value
+    }
 }
 #[doc = "Properties of the deleted managed HSM."]
 #[derive(Clone, Debug, PartialEq, Serialize, Deserialize, Default)]
@@ -189,10 +193,20 @@ pub struct DeletedManagedHsmProperties {
     #[serde(default, skip_serializing_if = "Option::is_none")]
     pub location: Option<String>,
     #[doc = "The deleted date."]
-    #[serde(rename = "deletionDate", default, with = "azure_core::date::rfc3339::option")]
+    #[serde(
+        rename = "deletionDate",
+        default,
+        with = "azure_core::date::iso8601::option",
+        skip_serializing_if = "Option::is_none"
+    )]
     pub deletion_date: Option<time::OffsetDateTime>,
     #[doc = "The scheduled purged date."]
-    #[serde(rename = "scheduledPurgeDate", default, with = "azure_core::date::rfc3339::option")]
+    #[serde(
+        rename = "scheduledPurgeDate",
+        default,
+        with = "azure_core::date::iso8601::option",
+        skip_serializing_if = "Option::is_none"
+    )]
     pub scheduled_purge_date: Option<time::OffsetDateTime>,
     #[doc = "Purge protection status of the original managed HSM."]
     #[serde(rename = "purgeProtectionEnabled", default, skip_serializing_if = "Option::is_none")]
@@ -251,6 +265,10 @@ impl DeletedVaultListResult {
     pub fn new() -> Self {
         Self::default()
     }
+    #[doc = "The items in this page."]
+    pub fn items(&self) -> &[DeletedVault] {
+        &self.value
+    }
 }
 #[doc = "Properties of the deleted vault."]
 #[derive(Clone, Debug, PartialEq, Serialize, Deserialize, Default)]
@@ -262,10 +280,20 @@ pub struct DeletedVaultProperties {
     #[serde(default, skip_serializing_if = "Option::is_none")]
     pub location: Option<String>,
     #[doc = "The deleted date."]
-    #[serde(rename = "deletionDate", default, with = "azure_core::date::rfc3339::option")]
+    #[serde(
+        rename = "deletionDate",
+        default,
+        with = "azure_core::date::iso8601::option",
+        skip_serializing_if = "Option::is_none"
+    )]
     pub deletion_date: Option<time::OffsetDateTime>,
     #[doc = "The scheduled purged date."]
-    #[serde(rename = "scheduledPurgeDate", default, with = "azure_core::date::rfc3339::option")]
+    #[serde(
+        rename = "scheduledPurgeDate",
+        default,
+        with = "azure_core::date::iso8601::option",
+        skip_serializing_if = "Option::is_none"
+    )]
     pub scheduled_purge_date: Option<time::OffsetDateTime>,
     #[doc = "Tags of the original vault."]
     #[serde(default, skip_serializing_if = "Option::is_none")]
@@ -280,7 +308,7 @@ impl DeletedVaultProperties {
     }
 }
 #[doc = "Type of operation: get, read, delete, etc."]
-#[derive(Clone, Debug, PartialEq, Serialize, Deserialize, Default)]
+#[derive(Clone, Debug, PartialEq, Eq, Hash, Serialize, Deserialize, Default)]
 pub struct DimensionProperties {
     #[doc = "Name of dimension."]
     #[serde(default, skip_serializing_if = "Option::is_none")]
@@ -298,7 +326,7 @@ impl DimensionProperties {
     }
 }
 #[doc = "The server error."]
-#[derive(Clone, Debug, PartialEq, Serialize, Deserialize, Default)]
+#[derive(Clone, Debug, PartialEq, Eq, Hash, Serialize, Deserialize, Default)]
 pub struct Error {
     #[doc = "The error code."]
     #[serde(default, skip_serializing_if = "Option::is_none")]
@@ -316,7 +344,7 @@ impl Error {
     }
 }
 #[doc = "A rule governing the accessibility of a vault from a specific ip address or ip range."]
-#[derive(Clone, Debug, PartialEq, Serialize, Deserialize)]
+#[derive(Clone, Debug, PartialEq, Eq, Hash, Serialize, Deserialize)]
 pub struct IpRule {
     #[doc = "An IPv4 address range in CIDR notation, such as '124.56.78.91' (simple IP address) or '124.56.78.0/24' (all addresses that start with 124.56.78)."]
     pub value: String,
@@ -327,7 +355,7 @@ impl IpRule {
     }
 }
 #[doc = "The type of identity."]
-#[derive(Clone, Debug, PartialEq, Serialize, Deserialize)]
+#[derive(Clone, Debug, PartialEq, Eq, Hash, Serialize, Deserialize)]
 #[serde(remote = "IdentityType")]
 pub enum IdentityType {
     User,
@@ -367,6 +395,23 @@ impl Serialize for IdentityType {
         }
     }
 }
+impl IdentityType {
+    #[doc = "The value as it is sent on the wire."]
+    pub fn as_str(&self) -> &str {
+        match self {
+            Self::User => "User",
+            Self::Application => "Application",
+            Self::ManagedIdentity => "ManagedIdentity",
+            Self::Key => "Key",
+            Self::UnknownValue(s) => s.as_str(),
+        }
+    }
+}
+impl std::fmt::Display for IdentityType {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.write_str(self.as_str())
+    }
+}
 #[doc = "The key resource."]
 #[derive(Clone, Debug, PartialEq, Serialize, Deserialize)]
 pub struct Key {
@@ -384,7 +429,7 @@ impl Key {
     }
 }
 #[doc = "The object attributes managed by the Azure Key Vault service."]
-#[derive(Clone, Debug, PartialEq, Serialize, Deserialize, Default)]
+#[derive(Clone, Debug, PartialEq, Eq, Hash, Serialize, Deserialize, Default)]
 pub struct KeyAttributes {
     #[doc = "Determines whether or not the object is enabled."]
     #[serde(default, skip_serializing_if = "Option::is_none")]
@@ -413,7 +458,7 @@ impl KeyAttributes {
 pub mod key_attributes {
     use super::*;
     #[doc = "The deletion recovery level currently in effect for the object. If it contains 'Purgeable', then the object can be permanently deleted by a privileged user; otherwise, only the system can purge the object at the end of the retention interval."]
-    #[derive(Clone, Debug, PartialEq, Serialize, Deserialize)]
+    #[derive(Clone, Debug, PartialEq, Eq, Hash, Serialize, Deserialize)]
     #[serde(remote = "RecoveryLevel")]
     pub enum RecoveryLevel {
         Purgeable,
@@ -457,6 +502,23 @@ pub mod key_attributes {
             }
         }
     }
+    impl RecoveryLevel {
+        #[doc = "The value as it is sent on the wire."]
+        pub fn as_str(&self) -> &str {
+            match self {
+                Self::Purgeable => "Purgeable",
+                Self::RecoverablePurgeable => "Recoverable+Purgeable",
+                Self::Recoverable => "Recoverable",
+                Self::RecoverableProtectedSubscription => "Recoverable+ProtectedSubscription",
+                Self::UnknownValue(s) => s.as_str(),
+            }
+        }
+    }
+    impl std::fmt::Display for RecoveryLevel {
+        fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+            f.write_str(self.as_str())
+        }
+    }
 }
 #[doc = "The parameters used to create a key."]
 #[derive(Clone, Debug, PartialEq, Serialize, Deserialize)]
@@ -496,9 +558,13 @@ impl KeyListResult {
     pub fn new() -> Self {
         Self::default()
     }
+    #[doc = "The items in this page."]
+    pub fn items(&self) -> &[Key] {
+        &self.value
+    }
 }
 #[doc = "The properties of the key."]
-#[derive(Clone, Debug, PartialEq, Serialize, Deserialize, Default)]
+#[derive(Clone, Debug, PartialEq, Eq, Hash, Serialize, Deserialize, Default)]
 pub struct KeyProperties {
     #[doc = "The object attributes managed by the Azure Key Vault service."]
     #[serde(default, skip_serializing_if = "Option::is_none")]
@@ -536,7 +602,7 @@ impl KeyProperties {
 pub mod key_properties {
     use super::*;
     #[doc = "The type of the key. For valid values, see JsonWebKeyType."]
-    #[derive(Clone, Debug, PartialEq, Serialize, Deserialize)]
+    #[derive(Clone, Debug, PartialEq, Eq, Hash, Serialize, Deserialize)]
     #[serde(remote = "Kty")]
     pub enum Kty {
         #[serde(rename = "EC")]
@@ -580,8 +646,25 @@ pub mod key_properties {
             }
         }
     }
+    impl Kty {
+        #[doc = "The value as it is sent on the wire."]
+        pub fn as_str(&self) -> &str {
+            match self {
+                Self::Ec => "EC",
+                Self::EcHsm => "EC-HSM",
+                Self::Rsa => "RSA",
+                Self::RsaHsm => "RSA-HSM",
+                Self::UnknownValue(s) => s.as_str(),
+            }
+        }
+    }
+    impl std::fmt::Display for Kty {
+        fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+            f.write_str(self.as_str())
+        }
+    }
     #[doc = "The elliptic curve name. For valid values, see JsonWebKeyCurveName."]
-    #[derive(Clone, Debug, PartialEq, Serialize, Deserialize)]
+    #[derive(Clone, Debug, PartialEq, Eq, Hash, Serialize, Deserialize)]
     #[serde(remote = "CurveName")]
     pub enum CurveName {
         #[serde(rename = "P-256")]
@@ -625,8 +708,25 @@ pub mod key_properties {
             }
         }
     }
+    impl CurveName {
+        #[doc = "The value as it is sent on the wire."]
+        pub fn as_str(&self) -> &str {
+            match self {
+                Self::P256 => "P-256",
+                Self::P384 => "P-384",
+                Self::P521 => "P-521",
+                Self::P256k => "P-256K",
+                Self::UnknownValue(s) => s.as_str(),
+            }
+        }
+    }
+    impl std::fmt::Display for CurveName {
+        fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+            f.write_str(self.as_str())
+        }
+    }
 }
-#[derive(Clone, Debug, PartialEq, Serialize, Deserialize, Default)]
+#[derive(Clone, Debug, PartialEq, Eq, Hash, Serialize, Deserialize, Default)]
 pub struct KeyRotationPolicyAttributes {
     #[doc = "Creation time in seconds since 1970-01-01T00:00:00Z."]
     #[serde(default, skip_serializing_if = "Option::is_none")]
@@ -643,7 +743,7 @@ impl KeyRotationPolicyAttributes {
         Self::default()
     }
 }
-#[derive(Clone, Debug, PartialEq, Serialize, Deserialize, Default)]
+#[derive(Clone, Debug, PartialEq, Eq, Hash, Serialize, Deserialize, Default)]
 pub struct LifetimeAction {
     #[serde(default, skip_serializing_if = "Option::is_none")]
     pub trigger: Option<Trigger>,
@@ -656,7 +756,7 @@ impl LifetimeAction {
     }
 }
 #[doc = "Log specification of operation."]
-#[derive(Clone, Debug, PartialEq, Serialize, Deserialize, Default)]
+#[derive(Clone, Debug, PartialEq, Eq, Hash, Serialize, Deserialize, Default)]
 pub struct LogSpecification {
     #[doc = "Name of log specification."]
     #[serde(default, skip_serializing_if = "Option::is_none")]
@@ -674,7 +774,7 @@ impl LogSpecification {
     }
 }
 #[doc = "A rule governing the accessibility of a managed hsm pool from a specific ip address or ip range."]
-#[derive(Clone, Debug, PartialEq, Serialize, Deserialize)]
+#[derive(Clone, Debug, PartialEq, Eq, Hash, Serialize, Deserialize)]
 pub struct MhsmipRule {
     #[doc = "An IPv4 address range in CIDR notation, such as '124.56.78.91' (simple IP address) or '124.56.78.0/24' (all addresses that start with 124.56.78)."]
     pub value: String,
@@ -685,7 +785,7 @@ impl MhsmipRule {
     }
 }
 #[doc = "A set of rules governing the network accessibility of a managed hsm pool."]
-#[derive(Clone, Debug, PartialEq, Serialize, Deserialize, Default)]
+#[derive(Clone, Debug, PartialEq, Eq, Hash, Serialize, Deserialize, Default)]
 pub struct MhsmNetworkRuleSet {
     #[doc = "Tells what traffic can bypass network rules. This can be 'AzureServices' or 'None'.  If not specified the default is 'AzureServices'."]
     #[serde(default, skip_serializing_if = "Option::is_none")]
@@ -718,7 +818,7 @@ impl MhsmNetworkRuleSet {
 pub mod mhsm_network_rule_set {
     use super::*;
     #[doc = "Tells what traffic can bypass network rules. This can be 'AzureServices' or 'None'.  If not specified the default is 'AzureServices'."]
-    #[derive(Clone, Debug, PartialEq, Serialize, Deserialize)]
+    #[derive(Clone, Debug, PartialEq, Eq, Hash, Serialize, Deserialize)]
     #[serde(remote = "Bypass")]
     pub enum Bypass {
         AzureServices,
@@ -754,8 +854,23 @@ pub mod mhsm_network_rule_set {
             }
         }
     }
+    impl Bypass {
+        #[doc = "The value as it is sent on the wire."]
+        pub fn as_str(&self) -> &str {
+            match self {
+                Self::AzureServices => "AzureServices",
+                Self::None => "None",
+                Self::UnknownValue(s) => s.as_str(),
+            }
+        }
+    }
+    impl std::fmt::Display for Bypass {
+        fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+            f.write_str(self.as_str())
+        }
+    }
     #[doc = "The default action when no rule from ipRules and from virtualNetworkRules match. This is only used after the bypass property has been evaluated."]
-    #[derive(Clone, Debug, PartialEq, Serialize, Deserialize)]
+    #[derive(Clone, Debug, PartialEq, Eq, Hash, Serialize, Deserialize)]
     #[serde(remote = "DefaultAction")]
     pub enum DefaultAction {
         Allow,
@@ -791,9 +906,24 @@ pub mod mhsm_network_rule_set {
             }
         }
     }
+    impl DefaultAction {
+        #[doc = "The value as it is sent on the wire."]
+        pub fn as_str(&self) -> &str {
+            match self {
+                Self::Allow => "Allow",
+                Self::Deny => "Deny",
+                Self::UnknownValue(s) => s.as_str(),
+            }
+        }
+    }
+    impl std::fmt::Display for DefaultAction {
+        fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+            f.write_str(self.as_str())
+        }
+    }
 }
 #[doc = "Private endpoint object properties."]
-#[derive(Clone, Debug, PartialEq, Serialize, Deserialize, Default)]
+#[derive(Clone, Debug, PartialEq, Eq, Hash, Serialize, Deserialize, Default)]
 pub struct MhsmPrivateEndpoint {
     #[doc = "Full identifier of the private endpoint resource."]
     #[serde(default, skip_serializing_if = "Option::is_none")]
@@ -822,7 +952,7 @@ impl MhsmPrivateEndpointConnection {
     }
 }
 #[doc = "Private endpoint connection item."]
-#[derive(Clone, Debug, PartialEq, Serialize, Deserialize, Default)]
+#[derive(Clone, Debug, PartialEq, Eq, Hash, Serialize, Deserialize, Default)]
 pub struct MhsmPrivateEndpointConnectionItem {
     #[doc = "Id of private endpoint connection."]
     #[serde(default, skip_serializing_if = "Option::is_none")]
@@ -840,7 +970,7 @@ impl MhsmPrivateEndpointConnectionItem {
     }
 }
 #[doc = "Properties of the private endpoint connection resource."]
-#[derive(Clone, Debug, PartialEq, Serialize, Deserialize, Default)]
+#[derive(Clone, Debug, PartialEq, Eq, Hash, Serialize, Deserialize, Default)]
 pub struct MhsmPrivateEndpointConnectionProperties {
     #[doc = "Private endpoint object properties."]
     #[serde(rename = "privateEndpoint", default, skip_serializing_if = "Option::is_none")]
@@ -858,7 +988,7 @@ impl MhsmPrivateEndpointConnectionProperties {
     }
 }
 #[doc = "The current provisioning state."]
-#[derive(Clone, Debug, PartialEq, Serialize, Deserialize)]
+#[derive(Clone, Debug, PartialEq, Eq, Hash, Serialize, Deserialize)]
 #[serde(remote = "MhsmPrivateEndpointConnectionProvisioningState")]
 pub enum MhsmPrivateEndpointConnectionProvisioningState {
     Succeeded,
@@ -902,6 +1032,25 @@ impl Serialize for MhsmPrivateEndpointConnectionProvisioningState {
         }
     }
 }
+impl MhsmPrivateEndpointConnectionProvisioningState {
+    #[doc = "The value as it is sent on the wire."]
+    pub fn as_str(&self) -> &str {
+        match self {
+            Self::Succeeded => "Succeeded",
+            Self::Creating => "Creating",
+            Self::Updating => "Updating",
+            Self::Deleting => "Deleting",
+            Self::Failed => "Failed",
+            Self::Disconnected => "Disconnected",
+            Self::UnknownValue(s) => s.as_str(),
+        }
+    }
+}
+impl std::fmt::Display for MhsmPrivateEndpointConnectionProvisioningState {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.write_str(self.as_str())
+    }
+}
 #[doc = "List of private endpoint connections associated with a managed HSM Pools"]
 #[derive(Clone, Debug, PartialEq, Serialize, Deserialize, Default)]
 pub struct MhsmPrivateEndpointConnectionsListResult {
@@ -926,9 +1075,13 @@ impl MhsmPrivateEndpointConnectionsListResult {
     pub fn new() -> Self {
         Self::default()
     }
+    #[doc = "The items in this page."]
+    pub fn items(&self) -> &[MhsmPrivateEndpointConnection] {
+        &self.value
+    }
 }
 #[doc = "The private endpoint connection status."]
-#[derive(Clone, Debug, PartialEq, Serialize, Deserialize)]
+#[derive(Clone, Debug, PartialEq, Eq, Hash, Serialize, Deserialize)]
 #[serde(remote = "MhsmPrivateEndpointServiceConnectionStatus")]
 pub enum MhsmPrivateEndpointServiceConnectionStatus {
     Pending,
@@ -968,6 +1121,23 @@ impl Serialize for MhsmPrivateEndpointServiceConnectionStatus {
         }
     }
 }
+impl MhsmPrivateEndpointServiceConnectionStatus {
+    #[doc = "The value as it is sent on the wire."]
+    pub fn as_str(&self) -> &str {
+        match self {
+            Self::Pending => "Pending",
+            Self::Approved => "Approved",
+            Self::Rejected => "Rejected",
+            Self::Disconnected => "Disconnected",
+            Self::UnknownValue(s) => s.as_str(),
+        }
+    }
+}
+impl std::fmt::Display for MhsmPrivateEndpointServiceConnectionStatus {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.write_str(self.as_str())
+    }
+}
 #[doc = "A private link resource"]
 #[derive(Clone, Debug, PartialEq, Serialize, Deserialize, Default)]
 pub struct MhsmPrivateLinkResource {
@@ -997,9 +1167,13 @@ impl MhsmPrivateLinkResourceListResult {
     pub fn new() -> Self {
         Self::default()
     }
+    #[doc = "The items in this page."]
+    pub fn items(&self) -> &[MhsmPrivateLinkResource] {
+        &self.value
+    }
 }
 #[doc = "Properties of a private link resource."]
-#[derive(Clone, Debug, PartialEq, Serialize, Deserialize, Default)]
+#[derive(Clone, Debug, PartialEq, Eq, Hash, Serialize, Deserialize, Default)]
 pub struct MhsmPrivateLinkResourceProperties {
     #[doc = "Group identifier of private link resource."]
     #[serde(rename = "groupId", default, skip_serializing_if = "Option::is_none")]
@@ -1027,7 +1201,7 @@ impl MhsmPrivateLinkResourceProperties {
     }
 }
 #[doc = "An object that represents the approval state of the private link connection."]
-#[derive(Clone, Debug, PartialEq, Serialize, Deserialize, Default)]
+#[derive(Clone, Debug, PartialEq, Eq, Hash, Serialize, Deserialize, Default)]
 pub struct MhsmPrivateLinkServiceConnectionState {
     #[doc = "The private endpoint connection status."]
     #[serde(default, skip_serializing_if = "Option::is_none")]
@@ -1047,7 +1221,7 @@ impl MhsmPrivateLinkServiceConnectionState {
 pub mod mhsm_private_link_service_connection_state {
     use super::*;
     #[doc = "A message indicating if changes on the service provider require any updates on the consumer."]
-    #[derive(Clone, Debug, PartialEq, Serialize, Deserialize)]
+    #[derive(Clone, Debug, PartialEq, Eq, Hash, Serialize, Deserialize)]
     #[serde(remote = "ActionsRequired")]
     pub enum ActionsRequired {
         None,
@@ -1081,9 +1255,23 @@ pub mod mhsm_private_link_service_connection_state {
             }
         }
     }
+    impl ActionsRequired {
+        #[doc = "The value as it is sent on the wire."]
+        pub fn as_str(&self) -> &str {
+            match self {
+                Self::None => "None",
+                Self::UnknownValue(s) => s.as_str(),
+            }
+        }
+    }
+    impl std::fmt::Display for ActionsRequired {
+        fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+            f.write_str(self.as_str())
+        }
+    }
 }
 #[doc = "A rule governing the accessibility of a managed hsm pool from a specific virtual network."]
-#[derive(Clone, Debug, PartialEq, Serialize, Deserialize)]
+#[derive(Clone, Debug, PartialEq, Eq, Hash, Serialize, Deserialize)]
 pub struct MhsmVirtualNetworkRule {
     #[doc = "Full resource id of a vnet subnet, such as '/subscriptions/subid/resourceGroups/rg1/providers/Microsoft.Network/virtualNetworks/test-vnet/subnets/subnet1'."]
     pub id: String,
@@ -1108,7 +1296,7 @@ impl ManagedHsm {
     }
 }
 #[doc = "The error exception."]
-#[derive(Clone, Debug, PartialEq, Serialize, Deserialize, Default)]
+#[derive(Clone, Debug, PartialEq, Eq, Hash, Serialize, Deserialize, Default)]
 pub struct ManagedHsmError {
     #[doc = "The server error."]
     #[serde(default, skip_serializing_if = "Option::is_none")]
@@ -1149,9 +1337,13 @@ impl ManagedHsmListResult {
     pub fn new() -> Self {
         Self::default()
     }
+    #[doc = "The items in this page."]
+    pub fn items(&self) -> &[ManagedHsm] {
+        &self.value
+    }
 }
 #[doc = "Properties of the managed HSM Pool"]
-#[derive(Clone, Debug, PartialEq, Serialize, Deserialize, Default)]
+#[derive(Clone, Debug, PartialEq, Eq, Hash, Serialize, Deserialize, Default)]
 pub struct ManagedHsmProperties {
     #[doc = "The Azure Active Directory tenant ID that should be used for authenticating requests to the managed HSM pool."]
     #[serde(rename = "tenantId", default, skip_serializing_if = "Option::is_none")]
@@ -1200,7 +1392,12 @@ pub struct ManagedHsmProperties {
     #[serde(rename = "publicNetworkAccess", default, skip_serializing_if = "Option::is_none")]
     pub public_network_access: Option<managed_hsm_properties::PublicNetworkAccess>,
     #[doc = "The scheduled purge date in UTC."]
-    #[serde(rename = "scheduledPurgeDate", default, with = "azure_core::date::rfc3339::option")]
+    #[serde(
+        rename = "scheduledPurgeDate",
+        default,
+        with = "azure_core::date::iso8601::option",
+        skip_serializing_if = "Option::is_none"
+    )]
     pub scheduled_purge_date: Option<time::OffsetDateTime>,
 }
 impl ManagedHsmProperties {
@@ -1211,7 +1408,7 @@ impl ManagedHsmProperties {
 pub mod managed_hsm_properties {
     use super::*;
     #[doc = "The create mode to indicate whether the resource is being created or is being recovered from a deleted resource."]
-    #[derive(Clone, Debug, PartialEq, Serialize, Deserialize)]
+    #[derive(Clone, Debug, PartialEq, Eq, Hash, Serialize, Deserialize)]
     pub enum CreateMode {
         #[serde(rename = "recover")]
         Recover,
@@ -1219,7 +1416,7 @@ pub mod managed_hsm_properties {
         Default,
     }
     #[doc = "Provisioning state."]
-    #[derive(Clone, Debug, PartialEq, Serialize, Deserialize)]
+    #[derive(Clone, Debug, PartialEq, Eq, Hash, Serialize, Deserialize)]
     #[serde(remote = "ProvisioningState")]
     pub enum ProvisioningState {
         Succeeded,
@@ -1267,8 +1464,29 @@ pub mod managed_hsm_properties {
             }
         }
     }
+    impl ProvisioningState {
+        #[doc = "The value as it is sent on the wire."]
+        pub fn as_str(&self) -> &str {
+            match self {
+                Self::Succeeded => "Succeeded",
+                Self::Provisioning => "Provisioning",
+                Self::Failed => "Failed",
+                Self::Updating => "Updating",
+                Self::Deleting => "Deleting",
+                Self::Activated => "Activated",
+                Self::SecurityDomainRestore => "SecurityDomainRestore",
+                Self::Restoring => "Restoring",
+                Self::UnknownValue(s) => s.as_str(),
+            }
+        }
+    }
+    impl std::fmt::Display for ProvisioningState {
+        fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+            f.write_str(self.as_str())
+        }
+    }
     #[doc = "Control permission for data plane traffic coming from public networks while private endpoint is enabled."]
-    #[derive(Clone, Debug, PartialEq, Serialize, Deserialize)]
+    #[derive(Clone, Debug, PartialEq, Eq, Hash, Serialize, Deserialize)]
     #[serde(remote = "PublicNetworkAccess")]
     pub enum PublicNetworkAccess {
         Enabled,
@@ -1304,6 +1522,21 @@ pub mod managed_hsm_properties {
             }
         }
     }
+    impl PublicNetworkAccess {
+        #[doc = "The value as it is sent on the wire."]
+        pub fn as_str(&self) -> &str {
+            match self {
+                Self::Enabled => "Enabled",
+                Self::Disabled => "Disabled",
+                Self::UnknownValue(s) => s.as_str(),
+            }
+        }
+    }
+    impl std::fmt::Display for PublicNetworkAccess {
+        fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+            f.write_str(self.as_str())
+        }
+    }
 }
 #[doc = "Managed HSM resource"]
 #[derive(Clone, Debug, PartialEq, Serialize, Deserialize, Default)]
@@ -1336,7 +1569,7 @@ impl ManagedHsmResource {
     }
 }
 #[doc = "SKU details"]
-#[derive(Clone, Debug, PartialEq, Serialize, Deserialize)]
+#[derive(Clone, Debug, PartialEq, Eq, Hash, Serialize, Deserialize)]
 pub struct ManagedHsmSku {
     #[doc = "SKU Family of the managed HSM Pool"]
     pub family: managed_hsm_sku::Family,
@@ -1351,7 +1584,7 @@ impl ManagedHsmSku {
 pub mod managed_hsm_sku {
     use super::*;
     #[doc = "SKU Family of the managed HSM Pool"]
-    #[derive(Clone, Debug, PartialEq, Serialize, Deserialize)]
+    #[derive(Clone, Debug, PartialEq, Eq, Hash, Serialize, Deserialize)]
     #[serde(remote = "Family")]
     pub enum Family {
         B,
@@ -1385,8 +1618,22 @@ pub mod managed_hsm_sku {
             }
         }
     }
+    impl Family {
+        #[doc = "The value as it is sent on the wire."]
+        pub fn as_str(&self) -> &str {
+            match self {
+                Self::B => "B",
+                Self::UnknownValue(s) => s.as_str(),
+            }
+        }
+    }
+    impl std::fmt::Display for Family {
+        fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+            f.write_str(self.as_str())
+        }
+    }
     #[doc = "SKU of the managed HSM Pool"]
-    #[derive(Clone, Debug, PartialEq, Serialize, Deserialize)]
+    #[derive(Clone, Debug, PartialEq, Eq, Hash, Serialize, Deserialize)]
     pub enum Name {
         #[serde(rename = "Standard_B1")]
         StandardB1,
@@ -1395,7 +1642,7 @@ pub mod managed_hsm_sku {
     }
 }
 #[doc = "Metric specification of operation."]
-#[derive(Clone, Debug, PartialEq, Serialize, Deserialize, Default)]
+#[derive(Clone, Debug, PartialEq, Eq, Hash, Serialize, Deserialize, Default)]
 pub struct MetricSpecification {
     #[doc = "Name of metric specification."]
     #[serde(default, skip_serializing_if = "Option::is_none")]
@@ -1451,7 +1698,7 @@ impl MetricSpecification {
     }
 }
 #[doc = "A set of rules governing the network accessibility of a vault."]
-#[derive(Clone, Debug, PartialEq, Serialize, Deserialize, Default)]
+#[derive(Clone, Debug, PartialEq, Eq, Hash, Serialize, Deserialize, Default)]
 pub struct NetworkRuleSet {
     #[doc = "Tells what traffic can bypass network rules. This can be 'AzureServices' or 'None'.  If not specified the default is 'AzureServices'."]
     #[serde(default, skip_serializing_if = "Option::is_none")]
@@ -1484,7 +1731,7 @@ impl NetworkRuleSet {
 pub mod network_rule_set {
     use super::*;
     #[doc = "Tells what traffic can bypass network rules. This can be 'AzureServices' or 'None'.  If not specified the default is 'AzureServices'."]
-    #[derive(Clone, Debug, PartialEq, Serialize, Deserialize)]
+    #[derive(Clone, Debug, PartialEq, Eq, Hash, Serialize, Deserialize)]
     #[serde(remote = "Bypass")]
     pub enum Bypass {
         AzureServices,
@@ -1520,8 +1767,23 @@ pub mod network_rule_set {
             }
         }
     }
+    impl Bypass {
+        #[doc = "The value as it is sent on the wire."]
+        pub fn as_str(&self) -> &str {
+            match self {
+                Self::AzureServices => "AzureServices",
+                Self::None => "None",
+                Self::UnknownValue(s) => s.as_str(),
+            }
+        }
+    }
+    impl std::fmt::Display for Bypass {
+        fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+            f.write_str(self.as_str())
+        }
+    }
     #[doc = "The default action when no rule from ipRules and from virtualNetworkRules match. This is only used after the bypass property has been evaluated."]
-    #[derive(Clone, Debug, PartialEq, Serialize, Deserialize)]
+    #[derive(Clone, Debug, PartialEq, Eq, Hash, Serialize, Deserialize)]
     #[serde(remote = "DefaultAction")]
     pub enum DefaultAction {
         Allow,
@@ -1557,9 +1819,24 @@ pub mod network_rule_set {
             }
         }
     }
+    impl DefaultAction {
+        #[doc = "The value as it is sent on the wire."]
+        pub fn as_str(&self) -> &str {
+            match self {
+                Self::Allow => "Allow",
+                Self::Deny => "Deny",
+                Self::UnknownValue(s) => s.as_str(),
+            }
+        }
+    }
+    impl std::fmt::Display for DefaultAction {
+        fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+            f.write_str(self.as_str())
+        }
+    }
 }
 #[doc = "Key Vault REST API operation definition."]
-#[derive(Clone, Debug, PartialEq, Serialize, Deserialize, Default)]
+#[derive(Clone, Debug, PartialEq, Eq, Hash, Serialize, Deserialize, Default)]
 pub struct Operation {
     #[doc = "Operation name: {provider}/{resource}/{operation}"]
     #[serde(default, skip_serializing_if = "Option::is_none")]
@@ -1585,7 +1862,7 @@ impl Operation {
 pub mod operation {
     use super::*;
     #[doc = "Display metadata associated with the operation."]
-    #[derive(Clone, Debug, PartialEq, Serialize, Deserialize, Default)]
+    #[derive(Clone, Debug, PartialEq, Eq, Hash, Serialize, Deserialize, Default)]
     pub struct Display {
         #[doc = "Service provider: Microsoft Key Vault."]
         #[serde(default, skip_serializing_if = "Option::is_none")]
@@ -1607,7 +1884,7 @@ pub mod operation {
     }
 }
 #[doc = "Result of the request to list Storage operations. It contains a list of operations and a URL link to get the next set of results."]
-#[derive(Clone, Debug, PartialEq, Serialize, Deserialize, Default)]
+#[derive(Clone, Debug, PartialEq, Eq, Hash, Serialize, Deserialize, Default)]
 pub struct OperationListResult {
     #[doc = "List of Storage operations supported by the Storage resource provider."]
     #[serde(
@@ -1630,9 +1907,13 @@ impl OperationListResult {
     pub fn new() -> Self {
         Self::default()
     }
+    #[doc = "The items in this page."]
+    pub fn items(&self) -> &[Operation] {
+        &self.value
+    }
 }
 #[doc = "Properties of operation, include metric specifications."]
-#[derive(Clone, Debug, PartialEq, Serialize, Deserialize, Default)]
+#[derive(Clone, Debug, PartialEq, Eq, Hash, Serialize, Deserialize, Default)]
 pub struct OperationProperties {
     #[doc = "One property of operation, include log specifications."]
     #[serde(rename = "serviceSpecification", default, skip_serializing_if = "Option::is_none")]
@@ -1644,7 +1925,7 @@ impl OperationProperties {
     }
 }
 #[doc = "Permissions the identity has for keys, secrets, certificates and storage."]
-#[derive(Clone, Debug, PartialEq, Serialize, Deserialize, Default)]
+#[derive(Clone, Debug, PartialEq, Eq, Hash, Serialize, Deserialize, Default)]
 pub struct Permissions {
     #[doc = "Permissions to keys"]
     #[serde(
@@ -1681,7 +1962,7 @@ impl Permissions {
     }
 }
 #[doc = "Private endpoint object properties."]
-#[derive(Clone, Debug, PartialEq, Serialize, Deserialize, Default)]
+#[derive(Clone, Debug, PartialEq, Eq, Hash, Serialize, Deserialize, Default)]
 pub struct PrivateEndpoint {
     #[doc = "Full identifier of the private endpoint resource."]
     #[serde(default, skip_serializing_if = "Option::is_none")]
@@ -1710,7 +1991,7 @@ impl PrivateEndpointConnection {
     }
 }
 #[doc = "Private endpoint connection item."]
-#[derive(Clone, Debug, PartialEq, Serialize, Deserialize, Default)]
+#[derive(Clone, Debug, PartialEq, Eq, Hash, Serialize, Deserialize, Default)]
 pub struct PrivateEndpointConnectionItem {
     #[doc = "Id of private endpoint connection."]
     #[serde(default, skip_serializing_if = "Option::is_none")]
@@ -1751,9 +2032,13 @@ impl PrivateEndpointConnectionListResult {
     pub fn new() -> Self {
         Self::default()
     }
+    #[doc = "The items in this page."]
+    pub fn items(&self) -> &[PrivateEndpointConnection] {
+        &self.value
+    }
 }
 #[doc = "Properties of the private endpoint connection resource."]
-#[derive(Clone, Debug, PartialEq, Serialize, Deserialize, Default)]
+#[derive(Clone, Debug, PartialEq, Eq, Hash, Serialize, Deserialize, Default)]
 pub struct PrivateEndpointConnectionProperties {
     #[doc = "Private endpoint object properties."]
     #[serde(rename = "privateEndpoint", default, skip_serializing_if = "Option::is_none")]
@@ -1771,7 +2056,7 @@ impl PrivateEndpointConnectionProperties {
     }
 }
 #[doc = "The current provisioning state."]
-#[derive(Clone, Debug, PartialEq, Serialize, Deserialize)]
+#[derive(Clone, Debug, PartialEq, Eq, Hash, Serialize, Deserialize)]
 #[serde(remote = "PrivateEndpointConnectionProvisioningState")]
 pub enum PrivateEndpointConnectionProvisioningState {
     Succeeded,
@@ -1815,8 +2100,27 @@ impl Serialize for PrivateEndpointConnectionProvisioningState {
         }
     }
 }
+impl PrivateEndpointConnectionProvisioningState {
+    #[doc = "The value as it is sent on the wire."]
+    pub fn as_str(&self) -> &str {
+        match self {
+            Self::Succeeded => "Succeeded",
+            Self::Creating => "Creating",
+            Self::Updating => "Updating",
+            Self::Deleting => "Deleting",
+            Self::Failed => "Failed",
+            Self::Disconnected => "Disconnected",
+            Self::UnknownValue(s) => s.as_str(),
+        }
+    }
+}
+impl std::fmt::Display for PrivateEndpointConnectionProvisioningState {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.write_str(self.as_str())
+    }
+}
 #[doc = "The private endpoint connection status."]
-#[derive(Clone, Debug, PartialEq, Serialize, Deserialize)]
+#[derive(Clone, Debug, PartialEq, Eq, Hash, Serialize, Deserialize)]
 #[serde(remote = "PrivateEndpointServiceConnectionStatus")]
 pub enum PrivateEndpointServiceConnectionStatus {
     Pending,
@@ -1856,6 +2160,23 @@ impl Serialize for PrivateEndpointServiceConnectionStatus {
         }
     }
 }
+impl PrivateEndpointServiceConnectionStatus {
+    #[doc = "The value as it is sent on the wire."]
+    pub fn as_str(&self) -> &str {
+        match self {
+            Self::Pending => "Pending",
+            Self::Approved => "Approved",
+            Self::Rejected => "Rejected",
+            Self::Disconnected => "Disconnected",
+            Self::UnknownValue(s) => s.as_str(),
+        }
+    }
+}
+impl std::fmt::Display for PrivateEndpointServiceConnectionStatus {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.write_str(self.as_str())
+    }
+}
 #[doc = "A private link resource"]
 #[derive(Clone, Debug, PartialEq, Serialize, Deserialize, Default)]
 pub struct PrivateLinkResource {
@@ -1885,9 +2206,13 @@ impl PrivateLinkResourceListResult {
     pub fn new() -> Self {
         Self::default()
     }
+    #[doc = "The items in this page."]
+    pub fn items(&self) -> &[PrivateLinkResource] {
+        &self.value
+    }
 }
 #[doc = "Properties of a private link resource."]
-#[derive(Clone, Debug, PartialEq, Serialize, Deserialize, Default)]
+#[derive(Clone, Debug, PartialEq, Eq, Hash, Serialize, Deserialize, Default)]
 pub struct PrivateLinkResourceProperties {
     #[doc = "Group identifier of private link resource."]
     #[serde(rename = "groupId", default, skip_serializing_if = "Option::is_none")]
@@ -1915,7 +2240,7 @@ impl PrivateLinkResourceProperties {
     }
 }
 #[doc = "An object that represents the approval state of the private link connection."]
-#[derive(Clone, Debug, PartialEq, Serialize, Deserialize, Default)]
+#[derive(Clone, Debug, PartialEq, Eq, Hash, Serialize, Deserialize, Default)]
 pub struct PrivateLinkServiceConnectionState {
     #[doc = "The private endpoint connection status."]
     #[serde(default, skip_serializing_if = "Option::is_none")]
@@ -1935,7 +2260,7 @@ impl PrivateLinkServiceConnectionState {
 pub mod private_link_service_connection_state {
     use super::*;
     #[doc = "A message indicating if changes on the service provider require any updates on the consumer."]
-    #[derive(Clone, Debug, PartialEq, Serialize, Deserialize)]
+    #[derive(Clone, Debug, PartialEq, Eq, Hash, Serialize, Deserialize)]
     #[serde(remote = "ActionsRequired")]
     pub enum ActionsRequired {
         None,
@@ -1969,6 +2294,20 @@ pub mod private_link_service_connection_state {
             }
         }
     }
+    impl ActionsRequired {
+        #[doc = "The value as it is sent on the wire."]
+        pub fn as_str(&self) -> &str {
+            match self {
+                Self::None => "None",
+                Self::UnknownValue(s) => s.as_str(),
+            }
+        }
+    }
+    impl std::fmt::Display for ActionsRequired {
+        fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+            f.write_str(self.as_str())
+        }
+    }
 }
 #[doc = "Key Vault resource"]
 #[derive(Clone, Debug, PartialEq, Serialize, Deserialize, Default)]
@@ -2018,8 +2357,12 @@ impl ResourceListResult {
     pub fn new() -> Self {
         Self::default()
     }
+    #[doc = "The items in this page."]
+    pub fn items(&self) -> &[Resource] {
+        &self.value
+    }
 }
-#[derive(Clone, Debug, PartialEq, Serialize, Deserialize, Default)]
+#[derive(Clone, Debug, PartialEq, Eq, Hash, Serialize, Deserialize, Default)]
 pub struct RotationPolicy {
     #[serde(default, skip_serializing_if = "Option::is_none")]
     pub attributes: Option<KeyRotationPolicyAttributes>,
@@ -2054,7 +2397,7 @@ impl Secret {
     }
 }
 #[doc = "The secret management attributes."]
-#[derive(Clone, Debug, PartialEq, Serialize, Deserialize, Default)]
+#[derive(Clone, Debug, PartialEq, Eq, Hash, Serialize, Deserialize, Default)]
 pub struct SecretAttributes {
     #[serde(flatten)]
     pub attributes: Attributes,
@@ -2102,6 +2445,10 @@ impl SecretListResult {
     pub fn new() -> Self {
         Self::default()
     }
+    #[doc = "The items in this page."]
+    pub fn items(&self) -> &[Secret] {
+        &self.value
+    }
 }
 #[doc = "Parameters for patching a secret"]
 #[derive(Clone, Debug, PartialEq, Serialize, Deserialize, Default)]
@@ -2119,7 +2466,7 @@ impl SecretPatchParameters {
     }
 }
 #[doc = "Properties of the secret"]
-#[derive(Clone, Debug, PartialEq, Serialize, Deserialize, Default)]
+#[derive(Clone, Debug, PartialEq, Eq, Hash, Serialize, Deserialize, Default)]
 pub struct SecretPatchProperties {
     #[doc = "The value of the secret."]
     #[serde(default, skip_serializing_if = "Option::is_none")]
@@ -2137,7 +2484,7 @@ impl SecretPatchProperties {
     }
 }
 #[doc = "Properties of the secret"]
-#[derive(Clone, Debug, PartialEq, Serialize, Deserialize, Default)]
+#[derive(Clone, Debug, PartialEq, Eq, Hash, Serialize, Deserialize, Default)]
 pub struct SecretProperties {
     #[doc = "The value of the secret. NOTE: 'value' will never be returned from the service, as APIs using this model are is intended for internal use in ARM deployments. Users should use the data-plane REST service for interaction with vault secrets."]
     #[serde(default, skip_serializing_if = "Option::is_none")]
@@ -2161,7 +2508,7 @@ impl SecretProperties {
     }
 }
 #[doc = "One property of operation, include log specifications."]
-#[derive(Clone, Debug, PartialEq, Serialize, Deserialize, Default)]
+#[derive(Clone, Debug, PartialEq, Eq, Hash, Serialize, Deserialize, Default)]
 pub struct ServiceSpecification {
     #[doc = "Log specifications of operation."]
     #[serde(
@@ -2186,7 +2533,7 @@ impl ServiceSpecification {
     }
 }
 #[doc = "SKU details"]
-#[derive(Clone, Debug, PartialEq, Serialize, Deserialize)]
+#[derive(Clone, Debug, PartialEq, Eq, Hash, Serialize, Deserialize)]
 pub struct Sku {
     #[doc = "SKU family name"]
     pub family: sku::Family,
@@ -2201,7 +2548,7 @@ impl Sku {
 pub mod sku {
     use super::*;
     #[doc = "SKU family name"]
-    #[derive(Clone, Debug, PartialEq, Serialize, Deserialize)]
+    #[derive(Clone, Debug, PartialEq, Eq, Hash, Serialize, Deserialize)]
     #[serde(remote = "Family")]
     pub enum Family {
         A,
@@ -2235,8 +2582,22 @@ pub mod sku {
             }
         }
     }
+    impl Family {
+        #[doc = "The value as it is sent on the wire."]
+        pub fn as_str(&self) -> &str {
+            match self {
+                Self::A => "A",
+                Self::UnknownValue(s) => s.as_str(),
+            }
+        }
+    }
+    impl std::fmt::Display for Family {
+        fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+            f.write_str(self.as_str())
+        }
+    }
     #[doc = "SKU name to specify whether the key vault is a standard vault or a premium vault."]
-    #[derive(Clone, Debug, PartialEq, Serialize, Deserialize)]
+    #[derive(Clone, Debug, PartialEq, Eq, Hash, Serialize, Deserialize)]
     pub enum Name {
         #[serde(rename = "standard")]
         Standard,
@@ -2245,7 +2606,7 @@ pub mod sku {
     }
 }
 #[doc = "Metadata pertaining to creation and last modification of the key vault resource."]
-#[derive(Clone, Debug, PartialEq, Serialize, Deserialize, Default)]
+#[derive(Clone, Debug, PartialEq, Eq, Hash, Serialize, Deserialize, Default)]
 pub struct SystemData {
     #[doc = "The identity that created the key vault resource."]
     #[serde(rename = "createdBy", default, skip_serializing_if = "Option::is_none")]
@@ -2254,7 +2615,12 @@ pub struct SystemData {
     #[serde(rename = "createdByType", default, skip_serializing_if = "Option::is_none")]
     pub created_by_type: Option<IdentityType>,
     #[doc = "The timestamp of the key vault resource creation (UTC)."]
-    #[serde(rename = "createdAt", default, with = "azure_core::date::rfc3339::option")]
+    #[serde(
+        rename = "createdAt",
+        default,
+        with = "azure_core::date::iso8601::option",
+        skip_serializing_if = "Option::is_none"
+    )]
     pub created_at: Option<time::OffsetDateTime>,
     #[doc = "The identity that last modified the key vault resource."]
     #[serde(rename = "lastModifiedBy", default, skip_serializing_if = "Option::is_none")]
@@ -2263,7 +2629,12 @@ pub struct SystemData {
     #[serde(rename = "lastModifiedByType", default, skip_serializing_if = "Option::is_none")]
     pub last_modified_by_type: Option<IdentityType>,
     #[doc = "The timestamp of the key vault resource last modification (UTC)."]
-    #[serde(rename = "lastModifiedAt", default, with = "azure_core::date::rfc3339::option")]
+    #[serde(
+        rename = "lastModifiedAt",
+        default,
+        with = "azure_core::date::iso8601::option",
+        skip_serializing_if = "Option::is_none"
+    )]
     pub last_modified_at: Option<time::OffsetDateTime>,
 }
 impl SystemData {
@@ -2271,7 +2642,7 @@ impl SystemData {
         Self::default()
     }
 }
-#[derive(Clone, Debug, PartialEq, Serialize, Deserialize, Default)]
+#[derive(Clone, Debug, PartialEq, Eq, Hash, Serialize, Deserialize, Default)]
 pub struct Trigger {
     #[doc = "The time duration after key creation to rotate the key. It should be in ISO8601 format. Eg: 'P90D', 'P1Y'."]
     #[serde(rename = "timeAfterCreate", default, skip_serializing_if = "Option::is_none")]
@@ -2323,7 +2694,7 @@ impl Vault {
     }
 }
 #[doc = "Parameters for updating the access policy in a vault"]
-#[derive(Clone, Debug, PartialEq, Serialize, Deserialize)]
+#[derive(Clone, Debug, PartialEq, Eq, Hash, Serialize, Deserialize)]
 pub struct VaultAccessPolicyParameters {
     #[doc = "The resource id of the access policy."]
     #[serde(default, skip_serializing_if = "Option::is_none")]
@@ -2352,7 +2723,7 @@ impl VaultAccessPolicyParameters {
     }
 }
 #[doc = "Properties of the vault access policy"]
-#[derive(Clone, Debug, PartialEq, Serialize, Deserialize)]
+#[derive(Clone, Debug, PartialEq, Eq, Hash, Serialize, Deserialize)]
 pub struct VaultAccessPolicyProperties {
     #[doc = "An array of 0 to 16 identities that have access to the key vault. All identities in the array must use the same tenant ID as the key vault's tenant ID."]
     #[serde(rename = "accessPolicies")]
@@ -2364,7 +2735,7 @@ impl VaultAccessPolicyProperties {
     }
 }
 #[doc = "The parameters used to check the availability of the vault name."]
-#[derive(Clone, Debug, PartialEq, Serialize, Deserialize)]
+#[derive(Clone, Debug, PartialEq, Eq, Hash, Serialize, Deserialize)]
 pub struct VaultCheckNameAvailabilityParameters {
     #[doc = "The vault name."]
     pub name: String,
@@ -2380,7 +2751,7 @@ impl VaultCheckNameAvailabilityParameters {
 pub mod vault_check_name_availability_parameters {
     use super::*;
     #[doc = "The type of resource, Microsoft.KeyVault/vaults"]
-    #[derive(Clone, Debug, PartialEq, Serialize, Deserialize)]
+    #[derive(Clone, Debug, PartialEq, Eq, Hash, Serialize, Deserialize)]
     pub enum Type {
         #[serde(rename = "Microsoft.KeyVault/vaults")]
         MicrosoftKeyVaultVaults,
@@ -2430,6 +2801,10 @@ impl VaultListResult {
     pub fn new() -> Self {
         Self::default()
     }
+    #[doc = "The items in this page."]
+    pub fn items(&self) -> &[Vault] {
+        &self.value
+    }
 }
 #[doc = "Parameters for creating or updating a vault"]
 #[derive(Clone, Debug, PartialEq, Serialize, Deserialize, Default)]
@@ -2447,7 +2822,7 @@ impl VaultPatchParameters {
     }
 }
 #[doc = "Properties of the vault"]
-#[derive(Clone, Debug, PartialEq, Serialize, Deserialize, Default)]
+#[derive(Clone, Debug, PartialEq, Eq, Hash, Serialize, Deserialize, Default)]
 pub struct VaultPatchProperties {
     #[doc = "The Azure Active Directory tenant ID that should be used for authenticating requests to the key vault."]
     #[serde(rename = "tenantId", default, skip_serializing_if = "Option::is_none")]
@@ -2499,7 +2874,7 @@ impl VaultPatchProperties {
 pub mod vault_patch_properties {
     use super::*;
     #[doc = "The vault's create mode to indicate whether the vault need to be recovered or not."]
-    #[derive(Clone, Debug, PartialEq, Serialize, Deserialize)]
+    #[derive(Clone, Debug, PartialEq, Eq, Hash, Serialize, Deserialize)]
     pub enum CreateMode {
         #[serde(rename = "recover")]
         Recover,
@@ -2508,7 +2883,7 @@ pub mod vault_patch_properties {
     }
 }
 #[doc = "Properties of the vault"]
-#[derive(Clone, Debug, PartialEq, Serialize, Deserialize)]
+#[derive(Clone, Debug, PartialEq, Eq, Hash, Serialize, Deserialize)]
 pub struct VaultProperties {
     #[doc = "The Azure Active Directory tenant ID that should be used for authenticating requests to the key vault."]
     #[serde(rename = "tenantId")]
@@ -2593,7 +2968,7 @@ impl VaultProperties {
 pub mod vault_properties {
     use super::*;
     #[doc = "The vault's create mode to indicate whether the vault need to be recovered or not."]
-    #[derive(Clone, Debug, PartialEq, Serialize, Deserialize)]
+    #[derive(Clone, Debug, PartialEq, Eq, Hash, Serialize, Deserialize)]
     pub enum CreateMode {
         #[serde(rename = "recover")]
         Recover,
@@ -2601,7 +2976,7 @@ pub mod vault_properties {
         Default,
     }
     #[doc = "Provisioning state of the vault."]
-    #[derive(Clone, Debug, PartialEq, Serialize, Deserialize)]
+    #[derive(Clone, Debug, PartialEq, Eq, Hash, Serialize, Deserialize)]
     #[serde(remote = "ProvisioningState")]
     pub enum ProvisioningState {
         Succeeded,
@@ -2637,9 +3012,24 @@ pub mod vault_properties {
             }
         }
     }
+    impl ProvisioningState {
+        #[doc = "The value as it is sent on the wire."]
+        pub fn as_str(&self) -> &str {
+            match self {
+                Self::Succeeded => "Succeeded",
+                Self::RegisteringDns => "RegisteringDns",
+                Self::UnknownValue(s) => s.as_str(),
+            }
+        }
+    }
+    impl std::fmt::Display for ProvisioningState {
+        fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+            f.write_str(self.as_str())
+        }
+    }
 }
 #[doc = "A rule governing the accessibility of a vault from a specific virtual network."]
-#[derive(Clone, Debug, PartialEq, Serialize, Deserialize)]
+#[derive(Clone, Debug, PartialEq, Eq, Hash, Serialize, Deserialize)]
 pub struct VirtualNetworkRule {
     #[doc = "Full resource id of a vnet subnet, such as '/subscriptions/subid/resourceGroups/rg1/providers/Microsoft.Network/virtualNetworks/test-vnet/subnets/subnet1'."]
     pub id: String,
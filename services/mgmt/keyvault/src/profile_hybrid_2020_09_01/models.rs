@@ -4,7 +4,7 @@ use serde::de::{value, Deserializer, IntoDeserializer};
 use serde::{Deserialize, Serialize, Serializer};
 use std::str::FromStr;
 #[doc = "An identity that have access to the key vault. All identities in the array must use the same tenant ID as the key vault's tenant ID."]
-#[derive(Clone, Debug, PartialEq, Serialize, Deserialize)]
+#[derive(Clone, Debug, PartialEq, Eq, Hash, Serialize, Deserialize)]
 pub struct AccessPolicyEntry {
     #[doc = "The Azure Active Directory tenant ID that should be used for authenticating requests to the key vault."]
     #[serde(rename = "tenantId")]
@@ -29,7 +29,7 @@ impl AccessPolicyEntry {
     }
 }
 #[doc = "The object attributes managed by the KeyVault service."]
-#[derive(Clone, Debug, PartialEq, Serialize, Deserialize, Default)]
+#[derive(Clone, Debug, PartialEq, Eq, Hash, Serialize, Deserialize, Default)]
 pub struct Attributes {
     #[doc = "Determines whether the object is enabled."]
     #[serde(default, skip_serializing_if = "Option::is_none")]
@@ -53,7 +53,7 @@ impl Attributes {
     }
 }
 #[doc = "The CheckNameAvailability operation response."]
-#[derive(Clone, Debug, PartialEq, Serialize, Deserialize, Default)]
+#[derive(Clone, Debug, PartialEq, Eq, Hash, Serialize, Deserialize, Default)]
 pub struct CheckNameAvailabilityResult {
     #[doc = "A boolean value that indicates whether the name is available for you to use. If true, the name is available. If false, the name has already been taken or is invalid and cannot be used."]
     #[serde(rename = "nameAvailable", default, skip_serializing_if = "Option::is_none")]
@@ -73,14 +73,14 @@ impl CheckNameAvailabilityResult {
 pub mod check_name_availability_result {
     use super::*;
     #[doc = "The reason that a vault name could not be used. The Reason element is only returned if NameAvailable is false."]
-    #[derive(Clone, Debug, PartialEq, Serialize, Deserialize)]
+    #[derive(Clone, Debug, PartialEq, Eq, Hash, Serialize, Deserialize)]
     pub enum Reason {
         AccountNameInvalid,
         AlreadyExists,
     }
 }
 #[doc = "An error response from Key Vault resource provider"]
-#[derive(Clone, Debug, PartialEq, Serialize, Deserialize, Default)]
+#[derive(Clone, Debug, PartialEq, Eq, Hash, Serialize, Deserialize, Default)]
 pub struct CloudError {
     #[doc = "An error response from Key Vault resource provider"]
     #[serde(default, skip_serializing_if = "Option::is_none")]
@@ -98,7 +98,7 @@ impl CloudError {
     }
 }
 #[doc = "An error response from Key Vault resource provider"]
-#[derive(Clone, Debug, PartialEq, Serialize, Deserialize, Default)]
+#[derive(Clone, Debug, PartialEq, Eq, Hash, Serialize, Deserialize, Default)]
 pub struct CloudErrorBody {
     #[doc = "Error code. This is a mnemonic that can be consumed programmatically."]
     #[serde(default, skip_serializing_if = "Option::is_none")]
@@ -157,6 +157,10 @@ impl DeletedVaultListResult {
     pub fn new() -> Self {
         Self::default()
     }
+    #[doc = "The items in this page."]
+    pub fn items(&self) -> &[DeletedVault] {
+        &self.value
+    }
 }
 #[doc = "Properties of the deleted vault."]
 #[derive(Clone, Debug, PartialEq, Serialize, Deserialize, Default)]
@@ -168,10 +172,20 @@ pub struct DeletedVaultProperties {
     #[serde(default, skip_serializing_if = "Option::is_none")]
     pub location: Option<String>,
     #[doc = "The deleted date."]
-    #[serde(rename = "deletionDate", default, with = "azure_core::date::rfc3339::option")]
+    #[serde(
+        rename = "deletionDate",
+        default,
+        with = "azure_core::date::iso8601::option",
+        skip_serializing_if = "Option::is_none"
+    )]
     pub deletion_date: Option<time::OffsetDateTime>,
     #[doc = "The scheduled purged date."]
-    #[serde(rename = "scheduledPurgeDate", default, with = "azure_core::date::rfc3339::option")]
+    #[serde(
+        rename = "scheduledPurgeDate",
+        default,
+        with = "azure_core::date::iso8601::option",
+        skip_serializing_if = "Option::is_none"
+    )]
     pub scheduled_purge_date: Option<time::OffsetDateTime>,
     #[doc = "Tags of the original vault."]
     #[serde(default, skip_serializing_if = "Option::is_none")]
@@ -186,7 +200,7 @@ impl DeletedVaultProperties {
     }
 }
 #[doc = "Type of operation: get, read, delete, etc."]
-#[derive(Clone, Debug, PartialEq, Serialize, Deserialize, Default)]
+#[derive(Clone, Debug, PartialEq, Eq, Hash, Serialize, Deserialize, Default)]
 pub struct DimensionProperties {
     #[doc = "Name of dimension."]
     #[serde(default, skip_serializing_if = "Option::is_none")]
@@ -204,7 +218,7 @@ impl DimensionProperties {
     }
 }
 #[doc = "A rule governing the accessibility of a vault from a specific ip address or ip range."]
-#[derive(Clone, Debug, PartialEq, Serialize, Deserialize)]
+#[derive(Clone, Debug, PartialEq, Eq, Hash, Serialize, Deserialize)]
 pub struct IpRule {
     #[doc = "An IPv4 address range in CIDR notation, such as '124.56.78.91' (simple IP address) or '124.56.78.0/24' (all addresses that start with 124.56.78)."]
     pub value: String,
@@ -215,7 +229,7 @@ impl IpRule {
     }
 }
 #[doc = "Log specification of operation."]
-#[derive(Clone, Debug, PartialEq, Serialize, Deserialize, Default)]
+#[derive(Clone, Debug, PartialEq, Eq, Hash, Serialize, Deserialize, Default)]
 pub struct LogSpecification {
     #[doc = "Name of log specification."]
     #[serde(default, skip_serializing_if = "Option::is_none")]
@@ -233,7 +247,7 @@ impl LogSpecification {
     }
 }
 #[doc = "Metric specification of operation."]
-#[derive(Clone, Debug, PartialEq, Serialize, Deserialize, Default)]
+#[derive(Clone, Debug, PartialEq, Eq, Hash, Serialize, Deserialize, Default)]
 pub struct MetricSpecification {
     #[doc = "Name of metric specification."]
     #[serde(default, skip_serializing_if = "Option::is_none")]
@@ -289,7 +303,7 @@ impl MetricSpecification {
     }
 }
 #[doc = "A set of rules governing the network accessibility of a vault."]
-#[derive(Clone, Debug, PartialEq, Serialize, Deserialize, Default)]
+#[derive(Clone, Debug, PartialEq, Eq, Hash, Serialize, Deserialize, Default)]
 pub struct NetworkRuleSet {
     #[doc = "Tells what traffic can bypass network rules. This can be 'AzureServices' or 'None'.  If not specified the default is 'AzureServices'."]
     #[serde(default, skip_serializing_if = "Option::is_none")]
@@ -322,7 +336,7 @@ impl NetworkRuleSet {
 pub mod network_rule_set {
     use super::*;
     #[doc = "Tells what traffic can bypass network rules. This can be 'AzureServices' or 'None'.  If not specified the default is 'AzureServices'."]
-    #[derive(Clone, Debug, PartialEq, Serialize, Deserialize)]
+    #[derive(Clone, Debug, PartialEq, Eq, Hash, Serialize, Deserialize)]
     #[serde(remote = "Bypass")]
     pub enum Bypass {
         AzureServices,
@@ -358,8 +372,23 @@ pub mod network_rule_set {
             }
         }
     }
+    impl Bypass {
+        #[doc = "The value as it is sent on the wire."]
+        pub fn as_str(&self) -> &str {
+            match self {
+                Self::AzureServices => "AzureServices",
+                Self::None => "None",
+                Self::UnknownValue(s) => s.as_str(),
+            }
+        }
+    }
+    impl std::fmt::Display for Bypass {
+        fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+            f.write_str(self.as_str())
+        }
+    }
     #[doc = "The default action when no rule from ipRules and from virtualNetworkRules match. This is only used after the bypass property has been evaluated."]
-    #[derive(Clone, Debug, PartialEq, Serialize, Deserialize)]
+    #[derive(Clone, Debug, PartialEq, Eq, Hash, Serialize, Deserialize)]
     #[serde(remote = "DefaultAction")]
     pub enum DefaultAction {
         Allow,
@@ -395,9 +424,24 @@ pub mod network_rule_set {
             }
         }
     }
+    impl DefaultAction {
+        #[doc = "The value as it is sent on the wire."]
+        pub fn as_str(&self) -> &str {
+            match self {
+                Self::Allow => "Allow",
+                Self::Deny => "Deny",
+                Self::UnknownValue(s) => s.as_str(),
+            }
+        }
+    }
+    impl std::fmt::Display for DefaultAction {
+        fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+            f.write_str(self.as_str())
+        }
+    }
 }
 #[doc = "Key Vault REST API operation definition."]
-#[derive(Clone, Debug, PartialEq, Serialize, Deserialize, Default)]
+#[derive(Clone, Debug, PartialEq, Eq, Hash, Serialize, Deserialize, Default)]
 pub struct Operation {
     #[doc = "Operation name: {provider}/{resource}/{operation}"]
     #[serde(default, skip_serializing_if = "Option::is_none")]
@@ -423,7 +467,7 @@ impl Operation {
 pub mod operation {
     use super::*;
     #[doc = "Display metadata associated with the operation."]
-    #[derive(Clone, Debug, PartialEq, Serialize, Deserialize, Default)]
+    #[derive(Clone, Debug, PartialEq, Eq, Hash, Serialize, Deserialize, Default)]
     pub struct Display {
         #[doc = "Service provider: Microsoft Key Vault."]
         #[serde(default, skip_serializing_if = "Option::is_none")]
@@ -445,7 +489,7 @@ pub mod operation {
     }
 }
 #[doc = "Result of the request to list Storage operations. It contains a list of operations and a URL link to get the next set of results."]
-#[derive(Clone, Debug, PartialEq, Serialize, Deserialize, Default)]
+#[derive(Clone, Debug, PartialEq, Eq, Hash, Serialize, Deserialize, Default)]
 pub struct OperationListResult {
     #[doc = "List of Storage operations supported by the Storage resource provider."]
     #[serde(
@@ -468,9 +512,13 @@ impl OperationListResult {
     pub fn new() -> Self {
         Self::default()
     }
+    #[doc = "The items in this page."]
+    pub fn items(&self) -> &[Operation] {
+        &self.value
+    }
 }
 #[doc = "Properties of operation, include metric specifications."]
-#[derive(Clone, Debug, PartialEq, Serialize, Deserialize, Default)]
+#[derive(Clone, Debug, PartialEq, Eq, Hash, Serialize, Deserialize, Default)]
 pub struct OperationProperties {
     #[doc = "One property of operation, include log specifications."]
     #[serde(rename = "serviceSpecification", default, skip_serializing_if = "Option::is_none")]
@@ -482,7 +530,7 @@ impl OperationProperties {
     }
 }
 #[doc = "Permissions the identity has for keys, secrets, certificates and storage."]
-#[derive(Clone, Debug, PartialEq, Serialize, Deserialize, Default)]
+#[derive(Clone, Debug, PartialEq, Eq, Hash, Serialize, Deserialize, Default)]
 pub struct Permissions {
     #[doc = "Permissions to keys"]
     #[serde(
@@ -519,7 +567,7 @@ impl Permissions {
     }
 }
 #[doc = "Private endpoint object properties."]
-#[derive(Clone, Debug, PartialEq, Serialize, Deserialize, Default)]
+#[derive(Clone, Debug, PartialEq, Eq, Hash, Serialize, Deserialize, Default)]
 pub struct PrivateEndpoint {
     #[doc = "Full identifier of the private endpoint resource."]
     #[serde(default, skip_serializing_if = "Option::is_none")]
@@ -548,7 +596,7 @@ impl PrivateEndpointConnection {
     }
 }
 #[doc = "Private endpoint connection item."]
-#[derive(Clone, Debug, PartialEq, Serialize, Deserialize, Default)]
+#[derive(Clone, Debug, PartialEq, Eq, Hash, Serialize, Deserialize, Default)]
 pub struct PrivateEndpointConnectionItem {
     #[doc = "Id of private endpoint connection."]
     #[serde(default, skip_serializing_if = "Option::is_none")]
@@ -566,7 +614,7 @@ impl PrivateEndpointConnectionItem {
     }
 }
 #[doc = "Properties of the private endpoint connection resource."]
-#[derive(Clone, Debug, PartialEq, Serialize, Deserialize, Default)]
+#[derive(Clone, Debug, PartialEq, Eq, Hash, Serialize, Deserialize, Default)]
 pub struct PrivateEndpointConnectionProperties {
     #[doc = "Private endpoint object properties."]
     #[serde(rename = "privateEndpoint", default, skip_serializing_if = "Option::is_none")]
@@ -584,7 +632,7 @@ impl PrivateEndpointConnectionProperties {
     }
 }
 #[doc = "The current provisioning state."]
-#[derive(Clone, Debug, PartialEq, Serialize, Deserialize)]
+#[derive(Clone, Debug, PartialEq, Eq, Hash, Serialize, Deserialize)]
 #[serde(remote = "PrivateEndpointConnectionProvisioningState")]
 pub enum PrivateEndpointConnectionProvisioningState {
     Succeeded,
@@ -628,8 +676,27 @@ impl Serialize for PrivateEndpointConnectionProvisioningState {
         }
     }
 }
+impl PrivateEndpointConnectionProvisioningState {
+    #[doc = "The value as it is sent on the wire."]
+    pub fn as_str(&self) -> &str {
+        match self {
+            Self::Succeeded => "Succeeded",
+            Self::Creating => "Creating",
+            Self::Updating => "Updating",
+            Self::Deleting => "Deleting",
+            Self::Failed => "Failed",
+            Self::Disconnected => "Disconnected",
+            Self::UnknownValue(s) => s.as_str(),
+        }
+    }
+}
+impl std::fmt::Display for PrivateEndpointConnectionProvisioningState {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.write_str(self.as_str())
+    }
+}
 #[doc = "The private endpoint connection status."]
-#[derive(Clone, Debug, PartialEq, Serialize, Deserialize)]
+#[derive(Clone, Debug, PartialEq, Eq, Hash, Serialize, Deserialize)]
 #[serde(remote = "PrivateEndpointServiceConnectionStatus")]
 pub enum PrivateEndpointServiceConnectionStatus {
     Pending,
@@ -669,6 +736,23 @@ impl Serialize for PrivateEndpointServiceConnectionStatus {
         }
     }
 }
+impl PrivateEndpointServiceConnectionStatus {
+    #[doc = "The value as it is sent on the wire."]
+    pub fn as_str(&self) -> &str {
+        match self {
+            Self::Pending => "Pending",
+            Self::Approved => "Approved",
+            Self::Rejected => "Rejected",
+            Self::Disconnected => "Disconnected",
+            Self::UnknownValue(s) => s.as_str(),
+        }
+    }
+}
+impl std::fmt::Display for PrivateEndpointServiceConnectionStatus {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.write_str(self.as_str())
+    }
+}
 #[doc = "A private link resource"]
 #[derive(Clone, Debug, PartialEq, Serialize, Deserialize, Default)]
 pub struct PrivateLinkResource {
@@ -698,9 +782,13 @@ impl PrivateLinkResourceListResult {
     pub fn new() -> Self {
         Self::default()
     }
+    #[doc = "The items in this page."]
+    pub fn items(&self) -> &[PrivateLinkResource] {
+        &self.value
+    }
 }
 #[doc = "Properties of a private link resource."]
-#[derive(Clone, Debug, PartialEq, Serialize, Deserialize, Default)]
+#[derive(Clone, Debug, PartialEq, Eq, Hash, Serialize, Deserialize, Default)]
 pub struct PrivateLinkResourceProperties {
     #[doc = "Group identifier of private link resource."]
     #[serde(rename = "groupId", default, skip_serializing_if = "Option::is_none")]
@@ -728,7 +816,7 @@ impl PrivateLinkResourceProperties {
     }
 }
 #[doc = "An object that represents the approval state of the private link connection."]
-#[derive(Clone, Debug, PartialEq, Serialize, Deserialize, Default)]
+#[derive(Clone, Debug, PartialEq, Eq, Hash, Serialize, Deserialize, Default)]
 pub struct PrivateLinkServiceConnectionState {
     #[doc = "The private endpoint connection status."]
     #[serde(default, skip_serializing_if = "Option::is_none")]
@@ -793,6 +881,10 @@ impl ResourceListResult {
     pub fn new() -> Self {
         Self::default()
     }
+    #[doc = "The items in this page."]
+    pub fn items(&self) -> &[Resource] {
+        &self.value
+    }
 }
 #[doc = "Resource information with extended details."]
 #[derive(Clone, Debug, PartialEq, Serialize, Deserialize)]
@@ -811,7 +903,7 @@ impl Secret {
     }
 }
 #[doc = "The secret management attributes."]
-#[derive(Clone, Debug, PartialEq, Serialize, Deserialize, Default)]
+#[derive(Clone, Debug, PartialEq, Eq, Hash, Serialize, Deserialize, Default)]
 pub struct SecretAttributes {
     #[serde(flatten)]
     pub attributes: Attributes,
@@ -859,6 +951,10 @@ impl SecretListResult {
     pub fn new() -> Self {
         Self::default()
     }
+    #[doc = "The items in this page."]
+    pub fn items(&self) -> &[Secret] {
+        &self.value
+    }
 }
 #[doc = "Parameters for patching a secret"]
 #[derive(Clone, Debug, PartialEq, Serialize, Deserialize, Default)]
@@ -876,7 +972,7 @@ impl SecretPatchParameters {
     }
 }
 #[doc = "Properties of the secret"]
-#[derive(Clone, Debug, PartialEq, Serialize, Deserialize, Default)]
+#[derive(Clone, Debug, PartialEq, Eq, Hash, Serialize, Deserialize, Default)]
 pub struct SecretPatchProperties {
     #[doc = "The value of the secret."]
     #[serde(default, skip_serializing_if = "Option::is_none")]
@@ -894,7 +990,7 @@ impl SecretPatchProperties {
     }
 }
 #[doc = "Properties of the secret"]
-#[derive(Clone, Debug, PartialEq, Serialize, Deserialize, Default)]
+#[derive(Clone, Debug, PartialEq, Eq, Hash, Serialize, Deserialize, Default)]
 pub struct SecretProperties {
     #[doc = "The value of the secret. NOTE: 'value' will never be returned from the service, as APIs using this model are is intended for internal use in ARM deployments. Users should use the data-plane REST service for interaction with vault secrets."]
     #[serde(default, skip_serializing_if = "Option::is_none")]
@@ -918,7 +1014,7 @@ impl SecretProperties {
     }
 }
 #[doc = "One property of operation, include log specifications."]
-#[derive(Clone, Debug, PartialEq, Serialize, Deserialize, Default)]
+#[derive(Clone, Debug, PartialEq, Eq, Hash, Serialize, Deserialize, Default)]
 pub struct ServiceSpecification {
     #[doc = "Log specifications of operation."]
     #[serde(
@@ -943,7 +1039,7 @@ impl ServiceSpecification {
     }
 }
 #[doc = "SKU details"]
-#[derive(Clone, Debug, PartialEq, Serialize, Deserialize)]
+#[derive(Clone, Debug, PartialEq, Eq, Hash, Serialize, Deserialize)]
 pub struct Sku {
     #[doc = "SKU family name"]
     pub family: sku::Family,
@@ -958,7 +1054,7 @@ impl Sku {
 pub mod sku {
     use super::*;
     #[doc = "SKU family name"]
-    #[derive(Clone, Debug, PartialEq, Serialize, Deserialize)]
+    #[derive(Clone, Debug, PartialEq, Eq, Hash, Serialize, Deserialize)]
     #[serde(remote = "Family")]
     pub enum Family {
         A,
@@ -992,8 +1088,22 @@ pub mod sku {
             }
         }
     }
+    impl Family {
+        #[doc = "The value as it is sent on the wire."]
+        pub fn as_str(&self) -> &str {
+            match self {
+                Self::A => "A",
+                Self::UnknownValue(s) => s.as_str(),
+            }
+        }
+    }
+    impl std::fmt::Display for Family {
+        fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+            f.write_str(self.as_str())
+        }
+    }
     #[doc = "SKU name to specify whether the key vault is a standard vault or a premium vault."]
-    #[derive(Clone, Debug, PartialEq, Serialize, Deserialize)]
+    #[derive(Clone, Debug, PartialEq, Eq, Hash, Serialize, Deserialize)]
     pub enum Name {
         #[serde(rename = "standard")]
         Standard,
@@ -1035,7 +1145,7 @@ impl Vault {
     }
 }
 #[doc = "Parameters for updating the access policy in a vault"]
-#[derive(Clone, Debug, PartialEq, Serialize, Deserialize)]
+#[derive(Clone, Debug, PartialEq, Eq, Hash, Serialize, Deserialize)]
 pub struct VaultAccessPolicyParameters {
     #[doc = "The resource id of the access policy."]
     #[serde(default, skip_serializing_if = "Option::is_none")]
@@ -1064,7 +1174,7 @@ impl VaultAccessPolicyParameters {
     }
 }
 #[doc = "Properties of the vault access policy"]
-#[derive(Clone, Debug, PartialEq, Serialize, Deserialize)]
+#[derive(Clone, Debug, PartialEq, Eq, Hash, Serialize, Deserialize)]
 pub struct VaultAccessPolicyProperties {
     #[doc = "An array of 0 to 16 identities that have access to the key vault. All identities in the array must use the same tenant ID as the key vault's tenant ID."]
     #[serde(rename = "accessPolicies")]
@@ -1076,7 +1186,7 @@ impl VaultAccessPolicyProperties {
     }
 }
 #[doc = "The parameters used to check the availability of the vault name."]
-#[derive(Clone, Debug, PartialEq, Serialize, Deserialize)]
+#[derive(Clone, Debug, PartialEq, Eq, Hash, Serialize, Deserialize)]
 pub struct VaultCheckNameAvailabilityParameters {
     #[doc = "The vault name."]
     pub name: String,
@@ -1092,7 +1202,7 @@ impl VaultCheckNameAvailabilityParameters {
 pub mod vault_check_name_availability_parameters {
     use super::*;
     #[doc = "The type of resource, Microsoft.KeyVault/vaults"]
-    #[derive(Clone, Debug, PartialEq, Serialize, Deserialize)]
+    #[derive(Clone, Debug, PartialEq, Eq, Hash, Serialize, Deserialize)]
     pub enum Type {
         #[serde(rename = "Microsoft.KeyVault/vaults")]
         MicrosoftKeyVaultVaults,
@@ -1142,6 +1252,10 @@ impl VaultListResult {
     pub fn new() -> Self {
         Self::default()
     }
+    #[doc = "The items in this page."]
+    pub fn items(&self) -> &[Vault] {
+        &self.value
+    }
 }
 #[doc = "Parameters for creating or updating a vault"]
 #[derive(Clone, Debug, PartialEq, Serialize, Deserialize, Default)]
@@ -1159,7 +1273,7 @@ impl VaultPatchParameters {
     }
 }
 #[doc = "Properties of the vault"]
-#[derive(Clone, Debug, PartialEq, Serialize, Deserialize, Default)]
+#[derive(Clone, Debug, PartialEq, Eq, Hash, Serialize, Deserialize, Default)]
 pub struct VaultPatchProperties {
     #[doc = "The Azure Active Directory tenant ID that should be used for authenticating requests to the key vault."]
     #[serde(rename = "tenantId", default, skip_serializing_if = "Option::is_none")]
@@ -1211,7 +1325,7 @@ impl VaultPatchProperties {
 pub mod vault_patch_properties {
     use super::*;
     #[doc = "The vault's create mode to indicate whether the vault need to be recovered or not."]
-    #[derive(Clone, Debug, PartialEq, Serialize, Deserialize)]
+    #[derive(Clone, Debug, PartialEq, Eq, Hash, Serialize, Deserialize)]
     pub enum CreateMode {
         #[serde(rename = "recover")]
         Recover,
@@ -1220,7 +1334,7 @@ pub mod vault_patch_properties {
     }
 }
 #[doc = "Properties of the vault"]
-#[derive(Clone, Debug, PartialEq, Serialize, Deserialize)]
+#[derive(Clone, Debug, PartialEq, Eq, Hash, Serialize, Deserialize)]
 pub struct VaultProperties {
     #[doc = "The Azure Active Directory tenant ID that should be used for authenticating requests to the key vault."]
     #[serde(rename = "tenantId")]
@@ -1305,7 +1419,7 @@ impl VaultProperties {
 pub mod vault_properties {
     use super::*;
     #[doc = "The vault's create mode to indicate whether the vault need to be recovered or not."]
-    #[derive(Clone, Debug, PartialEq, Serialize, Deserialize)]
+    #[derive(Clone, Debug, PartialEq, Eq, Hash, Serialize, Deserialize)]
     pub enum CreateMode {
         #[serde(rename = "recover")]
         Recover,
@@ -1313,7 +1427,7 @@ pub mod vault_properties {
         Default,
     }
     #[doc = "Provisioning state of the vault."]
-    #[derive(Clone, Debug, PartialEq, Serialize, Deserialize)]
+    #[derive(Clone, Debug, PartialEq, Eq, Hash, Serialize, Deserialize)]
     #[serde(remote = "ProvisioningState")]
     pub enum ProvisioningState {
         Succeeded,
@@ -1349,9 +1463,24 @@ pub mod vault_properties {
             }
         }
     }
+    impl ProvisioningState {
+        #[doc = "The value as it is sent on the wire."]
+        pub fn as_str(&self) -> &str {
+            match self {
+                Self::Succeeded => "Succeeded",
+                Self::RegisteringDns => "RegisteringDns",
+                Self::UnknownValue(s) => s.as_str(),
+            }
+        }
+    }
+    impl std::fmt::Display for ProvisioningState {
+        fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+            f.write_str(self.as_str())
+        }
+    }
 }
 #[doc = "A rule governing the accessibility of a vault from a specific virtual network."]
-#[derive(Clone, Debug, PartialEq, Serialize, Deserialize)]
+#[derive(Clone, Debug, PartialEq, Eq, Hash, Serialize, Deserialize)]
 pub struct VirtualNetworkRule {
     #[doc = "Full resource id of a vnet subnet, such as '/subscriptions/subid/resourceGroups/rg1/providers/Microsoft.Network/virtualNetworks/test-vnet/subnets/subnet1'."]
     pub id: String,